@@ -1,5 +1,5 @@
 pub mod zero_value {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
     #[derive(Debug, Default, Clone, PartialEq)]
     pub struct A {
         pub str_map: ::pilota::AHashMap<::pilota::FastStr, ::pilota::FastStr>,
@@ -22,6 +22,10 @@ pub mod zero_value {
                 })
         }
 
+        fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {
+            ("A", &[(2, "s1")])
+        }
+
         #[allow(unused_variables)]
         fn encode_raw<B>(&self, buf: &mut B)
         where
@@ -9,24 +9,27 @@ use ahash::{AHashMap, AHashSet};
 use dashmap::{mapref::one::RefMut, DashMap};
 use faststr::FastStr;
 use itertools::Itertools;
-use normpath::PathExt;
 use pkg_tree::PkgNode;
-use quote::quote;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
+use rustc_hash::{FxHashMap, FxHashSet};
 use traits::CodegenBackend;
 
 use self::workspace::Workspace;
 use crate::{
     db::RirDatabase,
     dedup::def_id_equal,
-    fmt::fmt_file,
+    fmt::fmt_file_with,
     middle::{
         self,
-        context::{tls::CUR_ITEM, Mode},
+        context::{tls::CUR_ITEM, Mode, PackageFacade, SourceType, WorkspaceLayout},
         rir,
+        ty::{AdtDef, AdtKind, CodegenTy},
     },
     rir::{Item, NodeKind},
-    symbol::{DefId, EnumRepr, FileId},
+    symbol::{DefId, EnumRepr, FileId, IdentName},
+    tags::protobuf::OneOf,
+    ty::TyKind,
+    util::{no_std_paths, normalize_path},
     Context, Symbol,
 };
 
@@ -89,13 +92,15 @@ where
     pub fn write_struct(&self, def_id: DefId, stream: &mut String, s: &rir::Message) {
         let name = self.rust_name(def_id);
 
-        let mut fields = s
+        let field_tys = s
             .fields
             .iter()
             .map(|f| {
-                let name = self.rust_name(f.did);
                 self.with_adjust(f.did, |adjust| {
-                    let ty = self.codegen_item_ty(f.ty.kind.clone());
+                    let ty = match self.flattened_wrapper(f) {
+                        Some(inner) => self.codegen_item_ty(inner.ty.kind.clone()),
+                        None => self.codegen_item_ty(f.ty.kind.clone()),
+                    };
                     let mut ty = format!("{ty}");
 
                     if let Some(adjust) = adjust {
@@ -107,29 +112,552 @@ where
                     if f.is_optional() {
                         ty = format!("::std::option::Option<{ty}>")
                     }
+                    ty
+                })
+            })
+            .collect::<Vec<_>>();
 
-                    let attrs = adjust.iter().flat_map(|a| a.attrs()).join("");
+        let mut fields = s
+            .fields
+            .iter()
+            .zip(&field_tys)
+            .map(|(f, ty)| {
+                let name = self.rust_name(f.did);
+                let attrs = self.with_adjust(f.did, |adjust| {
+                    adjust.iter().flat_map(|a| a.attrs()).join("")
+                });
 
-                    format! {
+                if self.presence_bit(f).is_some() {
+                    let ty = self.codegen_item_ty(f.ty.kind.clone());
+                    return format! {
                         r#"{attrs}
-                        pub {name}: {ty},"#
-                    }
-                })
+                        {name}: {ty},"#
+                    };
+                }
+                let vis = if self.value_range(f).is_some() {
+                    ""
+                } else {
+                    "pub "
+                };
+                format! {
+                    r#"{attrs}
+                    {vis}{name}: {ty},"#
+                }
             })
             .join("\n");
 
+        if let Some(ty) = self.presence_ty(s) {
+            fields.push_str(&format!("_presence: {ty},"));
+        }
         if self.keep_unknown_fields.contains(&def_id) {
             fields.push_str("pub _unknown_fields: ::pilota::LinkedBytes,");
         }
 
+        let generics = self.item_generics(def_id);
+        let eq_ignored = s.fields.iter().any(|f| self.is_eq_ignored(f));
+        let manual_clone = self.is_manual_clone(def_id);
+        let derives = match (manual_clone, eq_ignored) {
+            (false, false) => "#[derive(Clone, PartialEq)]",
+            (false, true) => "#[derive(Clone)]",
+            (true, false) => "#[derive(PartialEq)]",
+            (true, true) => "",
+        };
         stream.push_str(&format! {
-            r#"#[derive(Clone, PartialEq)]
-                pub struct {name} {{
+            r#"{derives}
+                pub struct {name}{generics} {{
                     {fields}
                 }}"#
         });
+        if manual_clone {
+            self.write_struct_clone(def_id, stream, s);
+        }
+
+        self.write_range_accessors(def_id, stream, s, &field_tys);
+        self.write_presence_accessors(def_id, stream, s);
+        self.write_iter_accessors(def_id, stream, s);
+        self.write_str_accessors(def_id, stream, s);
+        self.write_field_number_consts(def_id, stream, s);
+        if eq_ignored {
+            self.write_partial_eq(def_id, stream, s);
+        }
 
         self.backend.codegen_struct_impl(def_id, stream, s);
+        self.write_roundtrip_test(def_id, stream);
+    }
+
+    /// A test module with `roundtrip_tests`, encoding the default value of the
+    /// message and decoding it back.
+    fn write_roundtrip_test(&self, def_id: DefId, stream: &mut String) {
+        if !self.options.roundtrip_tests || !self.item_generics(def_id).is_empty() {
+            return;
+        }
+        let name = self.rust_name(def_id);
+        let module = format!("{}_roundtrip", (&*name.0).snake_ident());
+        let (import, decoded) = match self.source_type {
+            SourceType::Thrift => (
+                "use ::pilota::thrift::{binary::TBinaryProtocol, Message as _};",
+                format!(
+                    r#"let mut buf = ::pilota::BytesMut::new();
+                    msg.encode(&mut TBinaryProtocol::new(&mut buf, true)).unwrap();
+                    let mut data = buf.freeze();
+                    let decoded = super::{name}::decode(&mut TBinaryProtocol::new(&mut data, false)).unwrap();"#
+                ),
+            ),
+            SourceType::Protobuf => (
+                "use ::pilota::prost::Message as _;",
+                format!("let decoded = super::{name}::decode(&*msg.encode_to_vec()).unwrap();"),
+            ),
+        };
+        stream.push_str(&format! {
+            r#"
+            #[cfg(test)]
+            mod {module} {{
+                {import}
+
+                #[test]
+                fn roundtrip() {{
+                    let msg = <super::{name} as ::std::default::Default>::default();
+                    {decoded}
+                    assert_eq!(decoded, msg);
+                }}
+            }}
+            "#
+        });
+    }
+
+    /// Implements `Clone` cloning the fields of `s` one by one.
+    fn write_struct_clone(&self, def_id: DefId, stream: &mut String, s: &rir::Message) {
+        let mut fields = s
+            .fields
+            .iter()
+            .map(|f| {
+                let name = self.rust_name(f.did);
+                format!("{name}: ::std::clone::Clone::clone(&self.{name}),")
+            })
+            .join("\n");
+        if self.presence_ty(s).is_some() {
+            fields.push_str("_presence: self._presence,");
+        }
+        if self.keep_unknown_fields.contains(&def_id) {
+            fields.push_str("_unknown_fields: ::std::clone::Clone::clone(&self._unknown_fields),");
+        }
+        self.write_clone(
+            def_id,
+            stream,
+            &format!(
+                r#"Self {{
+                    {fields}
+                }}"#
+            ),
+        );
+    }
+
+    fn write_clone(&self, def_id: DefId, stream: &mut String, body: &str) {
+        let name = self.rust_name(def_id);
+        let generics = self.item_generics(def_id);
+        stream.push_str(&format! {
+            r#"
+            impl{generics} ::std::clone::Clone for {name}{generics} {{
+                fn clone(&self) -> Self {{
+                    {body}
+                }}
+            }}
+            "#
+        });
+    }
+
+    /// Implements `PartialEq` comparing the fields of `s` which aren't
+    /// ignored with `pilota.eq_ignore`.
+    fn write_partial_eq(&self, def_id: DefId, stream: &mut String, s: &rir::Message) {
+        let name = self.rust_name(def_id);
+        let generics = self.item_generics(def_id);
+        let mut eqs = s
+            .fields
+            .iter()
+            .filter(|f| !self.is_eq_ignored(f))
+            .map(|f| match self.packed_value(f, "self") {
+                Some(value) => format!("{value} == {}", self.packed_value(f, "other").unwrap()),
+                None => {
+                    let name = self.rust_name(f.did);
+                    format!("self.{name} == other.{name}")
+                }
+            })
+            .collect::<Vec<_>>();
+        if self.keep_unknown_fields.contains(&def_id) {
+            eqs.push("self._unknown_fields == other._unknown_fields".into());
+        }
+        let eq = if eqs.is_empty() {
+            "true".into()
+        } else {
+            eqs.join(" && ")
+        };
+        stream.push_str(&format! {
+            r#"impl{generics} ::std::cmp::PartialEq for {name}{generics} {{
+                fn eq(&self, other: &Self) -> bool {{
+                    {eq}
+                }}
+            }}"#
+        });
+    }
+
+    fn value_range(&self, f: &rir::Field) -> Option<crate::tags::ValueRange> {
+        let range = self
+            .tags(f.tags_id)
+            .and_then(|tags| tags.get::<crate::tags::ValueRange>().copied())?;
+        if !matches!(
+            f.ty.kind,
+            TyKind::U8
+                | TyKind::I8
+                | TyKind::I16
+                | TyKind::I32
+                | TyKind::I64
+                | TyKind::UInt32
+                | TyKind::UInt64
+        ) {
+            panic!(
+                "`pilota.range` is only valid on integer fields, but `{}` is `{}`",
+                self.def_id_info(f.did),
+                self.codegen_item_ty(f.ty.kind.clone())
+            )
+        }
+        Some(range)
+    }
+
+    /// Fields with a `pilota.range` are private, so the struct gets a
+    /// validating constructor, and a getter and a setter for each of them.
+    fn write_range_accessors(
+        &self,
+        def_id: DefId,
+        stream: &mut String,
+        s: &rir::Message,
+        field_tys: &[String],
+    ) {
+        let ranges = s
+            .fields
+            .iter()
+            .map(|f| self.value_range(f))
+            .collect::<Vec<_>>();
+        if ranges.iter().all(Option::is_none) {
+            return;
+        }
+
+        let check = |f: &rir::Field, range: &crate::tags::ValueRange| {
+            let name = self.rust_name(f.did);
+            let check = Self::range_check(range, &f.name, "value as i128");
+            if f.is_optional() {
+                format!("if let ::std::option::Option::Some(value) = {name} {{ {check} }}")
+            } else {
+                format!("{{ let value = {name}; {check} }}")
+            }
+        };
+
+        let params = s
+            .fields
+            .iter()
+            .zip(field_tys)
+            .map(|(f, ty)| format!("{}: {ty}", self.rust_name(f.did)))
+            .join(", ");
+        let checks = s
+            .fields
+            .iter()
+            .zip(&ranges)
+            .filter_map(|(f, range)| Some(check(f, range.as_ref()?)))
+            .join("\n");
+        let mut values = s
+            .fields
+            .iter()
+            .map(|f| {
+                let name = self.rust_name(f.did);
+                if self.presence_bit(f).is_some() {
+                    format!("{name}: {name}.unwrap_or_default(),")
+                } else {
+                    format!("{name},")
+                }
+            })
+            .join("");
+        if let Some(presence) = self.presence_init(s, |f| self.rust_name(f.did).to_string()) {
+            values.push_str(&format!("{presence},"));
+        }
+        if self.keep_unknown_fields.contains(&def_id) {
+            values.push_str("_unknown_fields: ::pilota::LinkedBytes::new(),");
+        }
+
+        let accessors = s
+            .fields
+            .iter()
+            .zip(field_tys)
+            .zip(&ranges)
+            .filter_map(|((f, ty), range)| {
+                let range = range.as_ref()?;
+                let name = self.rust_name(f.did);
+                let setter = format!("set_{}", name.trim_start_matches("r#"));
+                let check = check(f, range);
+                let must_use = self.must_use_attr();
+                Some(format!(
+                    r#"{must_use}
+                    pub fn {name}(&self) -> {ty} {{
+                        self.{name}
+                    }}
+
+                    pub fn {setter}(&mut self, {name}: {ty}) -> ::std::result::Result<(), ::pilota::RangeError> {{
+                        {check}
+                        self.{name} = {name};
+                        ::std::result::Result::Ok(())
+                    }}"#
+                ))
+            })
+            .join("\n\n");
+
+        let name = self.rust_name(def_id);
+        let generics = self.item_generics(def_id);
+        stream.push_str(&format!(
+            r#"
+            impl{generics} {name}{generics} {{
+                pub fn new({params}) -> ::std::result::Result<Self, ::pilota::RangeError> {{
+                    {checks}
+                    ::std::result::Result::Ok(Self {{ {values} }})
+                }}
+
+                {accessors}
+            }}
+            "#
+        ));
+    }
+
+    /// Fields packed by `packed_presence` are private, with a getter, a
+    /// presence check, a setter and a clearer each.
+    fn write_presence_accessors(&self, def_id: DefId, stream: &mut String, s: &rir::Message) {
+        let accessors = s
+            .fields
+            .iter()
+            .filter_map(|f| {
+                let bit = self.presence_bit(f)?;
+                let value = self.packed_value(f, "self")?;
+                let name = self.rust_name(f.did);
+                let ident = name.trim_start_matches("r#");
+                let ty = self.codegen_item_ty(f.ty.kind.clone());
+                let must_use = self.must_use_attr();
+                Some(format!(
+                    r#"{must_use}
+                    pub fn {name}(&self) -> ::std::option::Option<{ty}> {{
+                        {value}
+                    }}
+
+                    {must_use}
+                    pub fn has_{ident}(&self) -> bool {{
+                        self._presence & (1 << {bit}) != 0
+                    }}
+
+                    pub fn set_{ident}(&mut self, {name}: {ty}) {{
+                        self.{name} = {name};
+                        self._presence |= 1 << {bit};
+                    }}
+
+                    pub fn clear_{ident}(&mut self) {{
+                        self.{name} = ::std::default::Default::default();
+                        self._presence &= !(1 << {bit});
+                    }}"#
+                ))
+            })
+            .join("\n\n");
+        if accessors.is_empty() {
+            return;
+        }
+
+        let name = self.rust_name(def_id);
+        let generics = self.item_generics(def_id);
+        stream.push_str(&format!(
+            r#"
+            impl{generics} {name}{generics} {{
+                {accessors}
+            }}
+            "#
+        ));
+    }
+
+    /// The `field_iter()` accessors of the list, set and map fields of `s`
+    /// with `iter_accessors`.
+    fn write_iter_accessors(&self, def_id: DefId, stream: &mut String, s: &rir::Message) {
+        if !self.options.iter_accessors {
+            return;
+        }
+
+        let accessors = s
+            .fields
+            .iter()
+            .filter_map(|f| {
+                let ty = match self.flattened_wrapper(f) {
+                    Some(inner) => self.codegen_item_ty(inner.ty.kind.clone()),
+                    None => self.codegen_item_ty(f.ty.kind.clone()),
+                };
+                let item = Self::iter_item(&ty)?;
+                let name = self.rust_name(f.did);
+                let ident = name.trim_start_matches("r#");
+                let iter = if f.is_optional() {
+                    format!("self.{name}.iter().flat_map(|value| value.iter())")
+                } else {
+                    format!("self.{name}.iter()")
+                };
+                let must_use = self.must_use_attr();
+                Some(format!(
+                    r#"{must_use}
+                    pub fn {ident}_iter(&self) -> impl ::std::iter::Iterator<Item = {item}> {{
+                        {iter}
+                    }}"#
+                ))
+            })
+            .join("\n\n");
+        if accessors.is_empty() {
+            return;
+        }
+
+        let name = self.rust_name(def_id);
+        let generics = self.item_generics(def_id);
+        stream.push_str(&format!(
+            r#"
+            impl{generics} {name}{generics} {{
+                {accessors}
+            }}
+            "#
+        ));
+    }
+
+    /// The item of the iterator over the elements of a value of `ty`, seen
+    /// through the `Arc`s and typedefs wrapping it, if it is a collection.
+    fn iter_item(ty: &CodegenTy) -> Option<String> {
+        match ty {
+            CodegenTy::Vec(el)
+            | CodegenTy::Array(el, _)
+            | CodegenTy::Set(el)
+            | CodegenTy::BTreeSet(el) => Some(format!("&{el}")),
+            CodegenTy::Map(k, v) | CodegenTy::BTreeMap(k, v) => Some(format!("(&{k}, &{v})")),
+            CodegenTy::Arc(ty) | CodegenTy::Cow(ty) | CodegenTy::BoxedSlice(ty) => {
+                Self::iter_item(ty)
+            }
+            CodegenTy::Adt(AdtDef {
+                kind: AdtKind::NewType(inner),
+                ..
+            }) => Self::iter_item(inner),
+            _ => None,
+        }
+    }
+
+    fn write_str_accessors(&self, def_id: DefId, stream: &mut String, s: &rir::Message) {
+        if !self.options.str_accessors {
+            return;
+        }
+
+        let accessors = s
+            .fields
+            .iter()
+            .filter_map(|f| {
+                let ty = match self.flattened_wrapper(f) {
+                    Some(inner) => self.codegen_item_ty(inner.ty.kind.clone()),
+                    None => self.codegen_item_ty(f.ty.kind.clone()),
+                };
+                let (target, derefs) = Self::str_target(&ty)?;
+                let name = self.rust_name(f.did);
+                let (ret, body) = if f.is_optional() {
+                    (
+                        format!("::std::option::Option<&{target}>"),
+                        format!(
+                            "self.{name}.as_ref().map(|value| &*{}value)",
+                            "*".repeat(derefs)
+                        ),
+                    )
+                } else {
+                    (format!("&{target}"), format!("&self.{name}"))
+                };
+                let must_use = self.must_use_attr();
+                Some(format!(
+                    r#"{must_use}
+                    pub fn {name}(&self) -> {ret} {{
+                        {body}
+                    }}"#
+                ))
+            })
+            .join("\n\n");
+        if accessors.is_empty() {
+            return;
+        }
+
+        let name = self.rust_name(def_id);
+        let generics = self.item_generics(def_id);
+        stream.push_str(&format!(
+            r#"
+            impl{generics} {name}{generics} {{
+                {accessors}
+            }}
+            "#
+        ));
+    }
+
+    /// The ids of the fields with `field_number_consts`, in the impl of the
+    /// message so they don't collide with the consts of the module.
+    fn write_field_number_consts(&self, def_id: DefId, stream: &mut String, s: &rir::Message) {
+        if !self.options.field_number_consts || s.fields.is_empty() {
+            return;
+        }
+
+        let ty = match self.source_type {
+            SourceType::Thrift => "i16",
+            SourceType::Protobuf => "u32",
+        };
+        // the fields of a oneof are the variants of its enum, the oneof
+        // itself has no number
+        let numbers = s.fields.iter().flat_map(|f| match &f.ty.kind {
+            TyKind::Path(path)
+                if self
+                    .node(path.did)
+                    .is_some_and(|node| self.contains_tag::<OneOf>(node.tags)) =>
+            {
+                match &*self.expect_item(path.did) {
+                    rir::Item::Enum(e) => e
+                        .variants
+                        .iter()
+                        .map(|v| (v.did, v.id.unwrap() as i64))
+                        .collect(),
+                    _ => unreachable!(),
+                }
+            }
+            _ => vec![(f.did, f.id as i64)],
+        });
+        let consts = numbers
+            .map(|(did, id)| {
+                let name = self.rust_name(did);
+                let name = name.trim_start_matches("r#").shouty_snake_case();
+                format!("pub const {name}_FIELD_NUMBER: {ty} = {id};")
+            })
+            .join("\n");
+
+        let name = self.rust_name(def_id);
+        let generics = self.item_generics(def_id);
+        stream.push_str(&format!(
+            r#"
+            impl{generics} {name}{generics} {{
+                {consts}
+            }}
+            "#
+        ));
+    }
+
+    /// The `str` or `[u8]` a value of `ty` derefs to, with the number of
+    /// derefs, seen through the `Arc`s and typedefs wrapping it.
+    fn str_target(ty: &CodegenTy) -> Option<(&'static str, usize)> {
+        match ty {
+            CodegenTy::FastStr | CodegenTy::String => Some(("str", 1)),
+            CodegenTy::Bytes => Some(("[u8]", 1)),
+            CodegenTy::Vec(el) | CodegenTy::BoxedSlice(el) if matches!(**el, CodegenTy::U8) => {
+                Some(("[u8]", 1))
+            }
+            CodegenTy::Cow(ty) => Self::str_target(ty),
+            CodegenTy::Arc(ty)
+            | CodegenTy::Adt(AdtDef {
+                kind: AdtKind::NewType(ty),
+                ..
+            }) => Self::str_target(ty).map(|(target, derefs)| (target, derefs + 1)),
+            _ => None,
+        }
     }
 
     pub fn write_item(
@@ -137,10 +665,27 @@ where
         stream: &mut String,
         item: CodegenItem,
         dup: &mut AHashMap<FastStr, Vec<DefId>>,
+    ) {
+        if self.options.no_std {
+            let mut item_stream = String::new();
+            self.write_item_with_std(&mut item_stream, item, dup);
+            stream.push_str(&no_std_paths(&item_stream));
+        } else {
+            self.write_item_with_std(stream, item, dup);
+        }
+    }
+
+    fn write_item_with_std(
+        &self,
+        stream: &mut String,
+        item: CodegenItem,
+        dup: &mut AHashMap<FastStr, Vec<DefId>>,
     ) {
         CUR_ITEM.set(&item.def_id, || match item.kind {
             CodegenKind::Direct => {
-                if !self.duplicate(dup, item.def_id) {
+                if let Some(path) = self.external_path(item.def_id) {
+                    self.write_external(stream, item.def_id, &path);
+                } else if !self.duplicate(dup, item.def_id) {
                     let def_id = item.def_id;
                     let item = self.item(def_id).unwrap();
                     tracing::trace!("write item {}", item.symbol_name());
@@ -180,20 +725,79 @@ where
                     }
                 };
             }
+            // the external items are referred to by their own path
+            CodegenKind::RePub if self.external_path(item.def_id).is_some() => {}
             CodegenKind::RePub => {
-                let path = self
-                    .item_path(item.def_id)
-                    .iter()
-                    .map(|item| item.to_string())
-                    .join("::");
-                stream.push_str(format!("pub use ::{};", path).as_str());
+                stream.push_str(format!("pub use {};", self.re_pub_path(item.def_id)).as_str());
             }
         })
     }
 
+    /// Check the hand-implemented type at `path` of the item `def_id` can be
+    /// encoded and decoded in place of the generated one.
+    fn write_external(&self, stream: &mut String, def_id: DefId, path: &str) {
+        let item = self.item(def_id).unwrap();
+        let mut checks = Vec::new();
+        let bound = match (&*item, self.source_type) {
+            (Item::Message(_) | Item::Enum(_), SourceType::Thrift) => {
+                Some("::pilota::thrift::Message")
+            }
+            (Item::Message(_), SourceType::Protobuf) => Some("::pilota::prost::Message"),
+            _ => None,
+        };
+        if let Some(bound) = bound {
+            checks.push(format!(
+                "fn assert_message<T: {bound}>() {{}}
+                let _ = assert_message::<{path}>;"
+            ));
+        }
+        // the i32 enums are written and converted through their discriminant
+        if matches!(&*item, Item::Enum(e) if e.repr.is_some()) {
+            checks.push(format!(
+                "let _: fn(&{path}) -> i32 = <{path}>::inner;
+                let _: fn(i32) -> {path} = <{path} as ::std::convert::From<i32>>::from;"
+            ));
+        }
+        if checks.is_empty() {
+            return;
+        }
+        let checks = checks.join("\n");
+        stream.push_str(&format! {
+            r#"const _: () = {{
+                {checks}
+            }};"#
+        });
+    }
+
+    fn re_pub_path(&self, def_id: DefId) -> String {
+        let path = self.item_path_str(def_id);
+        let root = match &*self.mode {
+            Mode::Workspace(info) if info.layout == WorkspaceLayout::Flat => "crate",
+            _ => "",
+        };
+        format!("{root}::{path}")
+    }
+
+    /// The `use` items of the prelude imports for a module with `items`,
+    /// without the repeated ones and those it re-exports.
+    fn write_prelude(&self, stream: &mut String, items: &[CodegenItem]) {
+        let normalize = |path: &str| path.trim_start_matches("::").to_string();
+        let re_pubs = items
+            .iter()
+            .filter(|item| matches!(item.kind, CodegenKind::RePub))
+            .map(|item| normalize(&self.re_pub_path(item.def_id)))
+            .collect::<AHashSet<_>>();
+        self.options
+            .prelude_imports
+            .iter()
+            .unique_by(|path| normalize(path))
+            .filter(|path| !re_pubs.contains(&normalize(path)))
+            .for_each(|path| stream.push_str(&format!("use {path};\n")));
+    }
+
     fn duplicate(&self, dup: &mut AHashMap<FastStr, Vec<DefId>>, def_id: DefId) -> bool {
         let name = self.rust_name(def_id);
-        if !self.dedups.contains(&name.0) {
+        if !self.options.dedups.contains(&name.0) {
             return false;
         }
         let dup = dup.entry(name.0).or_default();
@@ -214,10 +818,7 @@ where
     ) {
         let name = self.rust_name(def_id);
 
-        let repr = match e.repr {
-            Some(EnumRepr::I32) => quote!(i32),
-            _ => panic!(),
-        };
+        let repr = self.enum_repr(def_id);
 
         let variants = e
             .variants
@@ -238,6 +839,8 @@ where
             .collect::<Vec<_>>();
         let variants_const = variants.iter().map(|(v, _)| v).join("");
         let variants_as_str_fields = variants.iter().map(|(_, v)| v).join("");
+        let inline = self.inline_attr(def_id);
+        let must_use = self.must_use_attr();
 
         stream.push_str(&format! {
             r#"#[derive(Clone, PartialEq, Copy)]
@@ -247,10 +850,12 @@ where
             impl {name} {{
                 {variants_const}
 
+                {inline}{must_use}
                 pub fn inner(&self) -> {repr} {{
                     self.0
                 }}
 
+                {inline}{must_use}
                 pub fn to_string(&self) -> ::std::string::String {{
                     match self {{
                         {variants_as_str_fields}
@@ -317,14 +922,53 @@ where
         if self.keep_unknown_fields.contains(&def_id) && keep {
             variants.push_str("_UnknownFields(::pilota::LinkedBytes),");
         }
+        if self.has_unknown_variant(def_id) {
+            variants.push_str("__Unknown,");
+        }
+        let generics = self.item_generics(def_id);
+        let manual_clone = self.is_manual_clone(def_id);
+        let derives = if manual_clone {
+            "PartialEq"
+        } else {
+            "Clone, PartialEq"
+        };
         stream.push_str(&format! {
             r#"
-            #[derive(Clone, PartialEq)]
-            pub enum {name} {{
+            #[derive({derives})]
+            pub enum {name}{generics} {{
                 {variants}
             }}
             "#
         });
+        if manual_clone {
+            let mut arms = e
+                .variants
+                .iter()
+                .map(|v| {
+                    let name = self.rust_name(v.did);
+                    if v.fields.is_empty() {
+                        format!("Self::{name} => Self::{name},")
+                    } else {
+                        format!("Self::{name}(value) => Self::{name}(::std::clone::Clone::clone(value)),")
+                    }
+                })
+                .join("\n");
+            if self.keep_unknown_fields.contains(&def_id) && keep {
+                arms.push_str("Self::_UnknownFields(value) => Self::_UnknownFields(::std::clone::Clone::clone(value)),");
+            }
+            if self.has_unknown_variant(def_id) {
+                arms.push_str("Self::__Unknown => Self::__Unknown,");
+            }
+            self.write_clone(
+                def_id,
+                stream,
+                &format!(
+                    r#"match self {{
+                        {arms}
+                    }}"#
+                ),
+            );
+        }
 
         self.backend.codegen_enum_impl(def_id, stream, e);
     }
@@ -377,16 +1021,13 @@ where
     // pick first service as init service from idlservice
     pub fn pick_init_service(&self, path: PathBuf) -> anyhow::Result<(String, String)> {
         // convert path to absolute path to match with file_id_map
-        let path = path
-            .normalize()
-            .map_err(|e| {
-                anyhow::Error::msg(format!(
-                    "Normalize path {} failed: {}, please check service path",
-                    path.display(),
-                    e
-                ))
-            })?
-            .into_path_buf();
+        let path = normalize_path(&path).map_err(|e| {
+            anyhow::Error::msg(format!(
+                "Normalize path {} failed: {}, please check service path",
+                path.display(),
+                e
+            ))
+        })?;
         tracing::debug!("path {:?}", path);
         let file_id: FileId = self.file_id(path).unwrap();
         let item = self
@@ -410,13 +1051,20 @@ where
 
     pub fn write_new_type(&self, def_id: DefId, stream: &mut String, t: &middle::rir::NewType) {
         let name = self.rust_name(def_id);
+        let generics = self.item_generics(def_id);
         let ty = self.codegen_item_ty(t.ty.kind.clone());
+        let manual_clone = self.is_manual_clone(def_id);
+        let derives = if manual_clone {
+            "PartialEq"
+        } else {
+            "Clone, PartialEq"
+        };
         stream.push_str(&format! {
             r#"
-            #[derive(Clone, PartialEq)]
-            pub struct {name}(pub {ty});
+            #[derive({derives})]
+            pub struct {name}{generics}(pub {ty});
 
-            impl ::std::ops::Deref for {name} {{
+            impl{generics} ::std::ops::Deref for {name}{generics} {{
                 type Target = {ty};
 
                 fn deref(&self) -> &Self::Target {{
@@ -424,7 +1072,7 @@ where
                 }}
             }}
 
-            impl From<{ty}> for {name} {{
+            impl{generics} From<{ty}> for {name}{generics} {{
                 fn from(v: {ty}) -> Self {{
                     Self(v)
                 }}
@@ -432,9 +1080,96 @@ where
 
             "#
         });
+        if manual_clone {
+            self.write_clone(def_id, stream, "Self(::std::clone::Clone::clone(&self.0))");
+        }
+        self.write_newtype_from_str(def_id, stream, t);
+        self.write_newtype_as_ref(def_id, &ty, stream);
         self.backend.codegen_newtype_impl(def_id, stream, t);
     }
 
+    /// `AsRef` of the wrapped `ty` with `newtype_as_ref`, and of the `str` or
+    /// `[u8]` it derefs to, if any.
+    fn write_newtype_as_ref(&self, def_id: DefId, ty: &CodegenTy, stream: &mut String) {
+        if !self.options.newtype_as_ref {
+            return;
+        }
+        let name = self.rust_name(def_id);
+        let generics = self.item_generics(def_id);
+        let as_ref = |target: &str, value: &str| {
+            format!(
+                r#"
+                impl{generics} ::std::convert::AsRef<{target}> for {name}{generics} {{
+                    fn as_ref(&self) -> &{target} {{
+                        {value}
+                    }}
+                }}
+                "#
+            )
+        };
+        stream.push_str(&as_ref(&ty.to_string(), "&self.0"));
+        if let Some((target, derefs)) = Self::str_target(ty) {
+            stream.push_str(&as_ref(target, &format!("&{}self.0", "*".repeat(derefs))));
+        }
+    }
+
+    /// Returns a `RangeError` of `field` when `value` is out of `range`.
+    fn range_check(range: &crate::tags::ValueRange, field: &str, value: &str) -> String {
+        let bound = |b: Option<i128>| b.map(|b| format!("{b}i128")).unwrap_or_default();
+        let op = if range.inclusive { "..=" } else { ".." };
+        format!(
+            r#"if !({}{op}{}).contains(&({value})) {{
+                return ::std::result::Result::Err(::pilota::RangeError {{
+                    field: "{field}",
+                    range: "{range}",
+                    value: {value},
+                }});
+            }}"#,
+            bound(range.start),
+            bound(range.end),
+        )
+    }
+
+    /// `FromStr` and `Display` for the typedefs of strings with
+    /// `newtype_from_str`, checking the length of the string against the
+    /// `pilota.range` of the typedef.
+    fn write_newtype_from_str(&self, def_id: DefId, stream: &mut String, t: &rir::NewType) {
+        if !self.options.newtype_from_str {
+            return;
+        }
+        let value = match t.ty.kind {
+            TyKind::FastStr => "::pilota::FastStr::new(s)",
+            TyKind::String => "::std::string::String::from(s)",
+            _ => return,
+        };
+        let name = self.rust_name(def_id);
+        let (err, check) = match self.node_tag::<crate::tags::ValueRange>(def_id) {
+            Some(range) => (
+                "::pilota::RangeError",
+                Self::range_check(&range, &t.name, "s.len() as i128"),
+            ),
+            None => ("::std::convert::Infallible", String::new()),
+        };
+        stream.push_str(&format! {
+            r#"
+            impl ::std::str::FromStr for {name} {{
+                type Err = {err};
+
+                fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {{
+                    {check}
+                    ::std::result::Result::Ok(Self({value}))
+                }}
+            }}
+
+            impl ::std::fmt::Display for {name} {{
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {{
+                    ::std::fmt::Display::fmt(&self.0, f)
+                }}
+            }}
+            "#
+        });
+    }
+
     pub fn write_const(&self, did: DefId, stream: &mut String, c: &middle::rir::Const) {
         let mut ty = self.codegen_ty(did);
 
@@ -469,6 +1204,17 @@ where
 
         let mut pkgs: DashMap<Arc<[FastStr]>, String> = Default::default();
 
+        let names = mods
+            .iter()
+            .map(|(p, def_ids)| {
+                let names = def_ids
+                    .iter()
+                    .map(|item| self.rust_name(item.def_id))
+                    .collect_vec();
+                (p.clone(), names)
+            })
+            .collect::<FxHashMap<_, _>>();
+
         let this = self.clone();
 
         mods.par_iter().for_each_with(this, |this, (p, def_ids)| {
@@ -479,8 +1225,34 @@ where
             let _enter = span.enter();
             let mut dup = AHashMap::default();
 
+            // the modules themselves are sorted by their path when written
+            let sorted;
+            let def_ids = if this.options.sort_items {
+                sorted = def_ids
+                    .iter()
+                    .copied()
+                    .sorted_by_cached_key(|item| {
+                        (
+                            this.rust_name(item.def_id),
+                            matches!(item.kind, CodegenKind::RePub),
+                        )
+                    })
+                    .collect_vec();
+                &sorted
+            } else {
+                def_ids
+            };
+
+            this.write_prelude(&mut stream, def_ids);
+
             if this.split {
                 Self::write_split_mod(this, base_dir, p, def_ids, &mut stream, &mut dup);
+            } else if let Some(max) = this
+                .options
+                .max_items_per_file
+                .filter(|max| def_ids.len() > *max)
+            {
+                Self::write_parts_mod(this, base_dir, p, def_ids, max, &mut stream, &mut dup);
             } else {
                 for def_id in def_ids.iter() {
                     this.write_item(&mut stream, *def_id, &mut dup)
@@ -488,10 +1260,71 @@ where
             }
         });
 
+        /// The items of the packages nested in `node`, by their path relative
+        /// to it.
+        fn nested_items(
+            node: &PkgNode,
+            names: &FxHashMap<Arc<[FastStr]>, Vec<Symbol>>,
+        ) -> Vec<(Vec<Symbol>, Symbol)> {
+            node.children
+                .iter()
+                .flat_map(|child| {
+                    let ident = Symbol::from(child.ident().unwrap());
+                    let own = names
+                        .get(&child.path)
+                        .into_iter()
+                        .flatten()
+                        .map(|name| (vec![], name.clone()));
+                    own.chain(nested_items(child, names))
+                        .map(move |(mut path, name)| {
+                            path.insert(0, ident.clone());
+                            (path, name)
+                        })
+                        .collect_vec()
+                })
+                .collect()
+        }
+
+        /// The `pub use` of the items of the packages nested in `node` which
+        /// don't collide with each other or with the names of `node`.
+        fn write_facade(
+            stream: &mut String,
+            node: &PkgNode,
+            names: &FxHashMap<Arc<[FastStr]>, Vec<Symbol>>,
+        ) {
+            let taken = names
+                .get(&node.path)
+                .into_iter()
+                .flatten()
+                .cloned()
+                .chain(
+                    node.children
+                        .iter()
+                        .map(|c| Symbol::from(c.ident().unwrap())),
+                )
+                .collect::<FxHashSet<_>>();
+            let uses = nested_items(node, names)
+                .into_iter()
+                .into_group_map_by(|(_, name)| name.clone())
+                .into_iter()
+                .filter(|(name, items)| items.len() == 1 && !taken.contains(name))
+                .sorted_by(|(a, _), (b, _)| a.cmp(b))
+                .map(|(name, items)| {
+                    let path = items[0].0.iter().join("::");
+                    format!("pub use self::{path}::{name};")
+                })
+                .join("\n");
+            if !uses.is_empty() {
+                stream.push_str(&format!("\n{uses}\n"));
+            }
+        }
+
         fn write_stream(
             pkgs: &mut DashMap<Arc<[FastStr]>, String>,
             stream: &mut String,
             nodes: &[PkgNode],
+            facade: PackageFacade,
+            names: &FxHashMap<Arc<[FastStr]>, Vec<Symbol>>,
         ) {
             for node in nodes.iter().sorted_by_key(|x| &x.path) {
                 let mut inner_stream = String::default();
@@ -499,7 +1332,15 @@ where
                     inner_stream.push_str(&node_stream);
                 }
 
-                write_stream(pkgs, &mut inner_stream, &node.children);
+                match facade {
+                    PackageFacade::Flatten => write_facade(&mut inner_stream, node, names),
+                    PackageFacade::Root if node.path.is_empty() => {
+                        write_facade(&mut inner_stream, node, names)
+                    }
+                    _ => {}
+                }
+
+                write_stream(pkgs, &mut inner_stream, &node.children, facade, names);
                 let name = node.ident();
                 if name.clone().unwrap_or_default() == "" {
                     stream.push_str(&inner_stream);
@@ -521,7 +1362,13 @@ where
         let pkg_node = PkgNode::from_pkgs(&keys.iter().map(|s| &**s).collect_vec());
         tracing::debug!(?pkg_node);
 
-        write_stream(&mut pkgs, stream, &pkg_node);
+        write_stream(
+            &mut pkgs,
+            stream,
+            &pkg_node,
+            self.options.package_facade,
+            &names,
+        );
     }
 
     fn write_split_mod(
@@ -564,29 +1411,59 @@ where
             let file_name = format!("{}.rs", unique_name);
             this.write_item(&mut item_stream, *def_id, dup);
 
-            let full_path = mod_dir.join(file_name.clone());
-            std::fs::create_dir_all(mod_dir).unwrap();
-
-            let item_stream = item_stream.lines().map(|s| s.trim_end()).join("\n");
-            let mut file =
-                std::io::BufWriter::new(std::fs::File::create(full_path.clone()).unwrap());
-            file.write_all(item_stream.as_bytes()).unwrap();
-            file.flush().unwrap();
-            fmt_file(full_path);
+            std::fs::create_dir_all(&mod_dir).unwrap();
+            this.write_included_file(&mod_dir.join(&file_name), &item_stream);
 
             mod_stream.push_str(format!("include!(\"{}\");\n", file_name).as_str());
         }
 
-        let mod_path = base_dir.join(&mod_file_name);
-        let mod_stream = mod_stream.lines().map(|s| s.trim_end()).join("\n");
-        let mut mod_file = std::io::BufWriter::new(std::fs::File::create(&mod_path).unwrap());
-        mod_file.write_all(mod_stream.as_bytes()).unwrap();
-        mod_file.flush().unwrap();
-        fmt_file(&mod_path);
+        this.write_included_file(&base_dir.join(&mod_file_name), &mod_stream);
 
         stream.push_str(format!("include!(\"{}\");\n", mod_file_name).as_str());
     }
 
+    /// Writes the items of the module `p` to `part_N.rs` files of `max`
+    /// items at most, included by its `mod.rs`. The items stay in the same
+    /// module, so they refer to each other across the parts as before.
+    fn write_parts_mod(
+        this: &mut Codegen<B>,
+        base_dir: &Path,
+        p: &Arc<[FastStr]>,
+        def_ids: &[CodegenItem],
+        max: usize,
+        stream: &mut RefMut<Arc<[FastStr]>, String>,
+        dup: &mut AHashMap<FastStr, Vec<DefId>>,
+    ) {
+        let base_mod_name = p.iter().map(|s| s.to_string()).join("/");
+        let mod_file_name = format!("{}/mod.rs", base_mod_name);
+        let mod_dir = base_dir.join(&base_mod_name);
+        std::fs::create_dir_all(&mod_dir).unwrap();
+
+        let mut mod_stream = String::new();
+        for (i, part) in def_ids.chunks(max).enumerate() {
+            let mut part_stream = String::new();
+            for def_id in part {
+                this.write_item(&mut part_stream, *def_id, dup);
+            }
+
+            let file_name = format!("part_{}.rs", i + 1);
+            this.write_included_file(&mod_dir.join(&file_name), &part_stream);
+            mod_stream.push_str(&format!("include!(\"{file_name}\");\n"));
+        }
+
+        this.write_included_file(&base_dir.join(&mod_file_name), &mod_stream);
+
+        stream.push_str(&format!("include!(\"{mod_file_name}\");\n"));
+    }
+
+    fn write_included_file(&self, path: &Path, stream: &str) {
+        let stream = stream.lines().map(|s| s.trim_end()).join("\n");
+        let mut file = std::io::BufWriter::new(std::fs::File::create(path).unwrap());
+        file.write_all(stream.as_bytes()).unwrap();
+        file.flush().unwrap();
+        fmt_file_with(path, self.options.fmt);
+    }
+
     /**
         On Windows and macOS, files names are case-insensitive
         To avoid problems when generating files for services with similar names, e.g.
@@ -602,6 +1479,64 @@ where
         name
     }
 
+    /// Writes `message_registry()`, registering the messages among
+    /// `def_ids`. The items are at their path without its first `skip`
+    /// segments from the module it is written in.
+    pub(crate) fn write_message_registry(
+        &self,
+        stream: &mut String,
+        def_ids: impl Iterator<Item = DefId>,
+        skip: usize,
+    ) {
+        // the args and results of the methods are not part of the idl
+        let method_items = self
+            .nodes()
+            .keys()
+            .flat_map(|def_id| self.related_nodes(*def_id))
+            .collect::<AHashSet<_>>();
+
+        let entries = def_ids
+            .filter(|def_id| !method_items.contains(def_id))
+            .filter(|def_id| matches!(self.item(*def_id).as_deref(), Some(Item::Message(_))))
+            .filter(|def_id| self.external_path(*def_id).is_none())
+            .map(|def_id| {
+                let fqn = self.fqn(def_id);
+                let path = self.item_path(def_id)[skip..]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .join("::");
+                let decode = match self.source_type {
+                    SourceType::Thrift => format!(
+                        "<self::{path} as ::pilota::thrift::Message>::decode_binary_shared(bytes)"
+                    ),
+                    SourceType::Protobuf => {
+                        format!("<self::{path} as ::pilota::prost::Message>::decode(bytes)")
+                    }
+                };
+                let entry = format! {
+                    r#"registry.register(::pilota::registry::MessageDescriptor {{
+                        name: "{fqn}",
+                        new: || ::std::boxed::Box::new(<self::{path} as ::std::default::Default>::default()),
+                        decode: |bytes| ::std::result::Result::Ok(::std::boxed::Box::new({decode}?)),
+                    }});"#
+                };
+                (fqn, entry)
+            })
+            .sorted()
+            .map(|(_, entry)| entry)
+            .join("\n");
+
+        stream.push_str(&format! {
+            r#"
+            pub fn message_registry() -> ::pilota::registry::MessageRegistry {{
+                let mut registry = ::pilota::registry::MessageRegistry::new();
+                {entries}
+                registry
+            }}
+            "#
+        });
+    }
+
     pub fn write_file(self, ns_name: Symbol, file_name: impl AsRef<Path>) {
         let base_dir = file_name.as_ref().parent().unwrap();
         let mut stream = String::default();
@@ -610,16 +1545,26 @@ where
             self.codegen_items.iter().map(|def_id| (*def_id).into()),
             base_dir,
         );
+        if self.options.message_registry {
+            self.write_message_registry(&mut stream, self.codegen_items.iter().copied(), 0);
+        }
+        if let Some(fingerprints) = &self.idl_fingerprints {
+            // every parsed idl, the unused items included
+            if let Some(fingerprint) = self.idl_fingerprint_const(fingerprints.keys().copied()) {
+                stream.push_str(&fingerprint);
+            }
+        }
 
+        let allows = self.lint_allows_attr();
         stream = format! {r#"pub mod {ns_name} {{
-                #![allow(warnings, clippy::all)]
+                {allows}
                 {stream}
             }}"#};
         let stream = stream.lines().map(|s| s.trim_end()).join("\n");
         let mut file = std::io::BufWriter::new(std::fs::File::create(&file_name).unwrap());
         file.write_all(stream.as_bytes()).unwrap();
         file.flush().unwrap();
-        fmt_file(file_name)
+        fmt_file_with(file_name, self.options.fmt)
     }
 
     pub fn gen(self) -> anyhow::Result<()> {
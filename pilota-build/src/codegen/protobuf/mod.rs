@@ -9,9 +9,9 @@ use crate::{
     db::RirDatabase,
     middle::ty::{self},
     rir::{self, Field, FieldKind, Item, NodeKind},
-    tags::protobuf::{OneOf, ProstType},
+    tags::protobuf::{Group, OneOf, ProstType, Required},
     ty::Ty,
-    CodegenBackend, Context, DefId,
+    CodegenBackend, Context, DefId, UnknownEnum,
 };
 
 #[derive(Clone)]
@@ -66,9 +66,10 @@ impl ProtobufBackend {
                 }
             }
             Category::Message => {
+                let module = self.ty_module(ty);
                 if let ty::TyKind::Vec(_) = ty.kind {
                     format!(
-                        "::pilota::prost::encoding::message::encoded_len_repeated({tag}, &{ident})"
+                        "::pilota::prost::encoding::{module}::encoded_len_repeated({tag}, &{ident})"
                     )
                     .into()
                 } else {
@@ -79,7 +80,7 @@ impl ProtobufBackend {
                             FieldKind::Required => format!("&{ident}").into(),
                             FieldKind::Optional => "msg".into(),
                         };
-                        format!("::pilota::prost::encoding::message::encoded_len({tag}, {ident})")
+                        format!("::pilota::prost::encoding::{module}::encoded_len({tag}, {ident})")
                             .into()
                     };
 
@@ -195,7 +196,13 @@ impl ProtobufBackend {
                 ty::TyKind::UInt64 => "uint64",
                 ty::TyKind::F32 => "float",
                 ty::TyKind::F64 => "double",
-                ty::TyKind::Path(ref path) if self.is_plain_enum(path.did) => "int32",
+                ty::TyKind::Path(ref path) if self.is_plain_enum(path.did) => {
+                    match self.cx.options.unknown_enum {
+                        UnknownEnum::Preserve => "int32",
+                        _ => "enumeration",
+                    }
+                }
+                ty::TyKind::Path(_) if self.cx.contains_tag::<Group>(ty.tags_id) => "group",
                 ty::TyKind::Path(_) => "message",
                 _ => unreachable!("{:?}", ty.kind),
             },
@@ -230,10 +237,11 @@ impl ProtobufBackend {
                 }
             }
             Category::Message => {
+                let module = self.ty_module(ty);
                 if let ty::TyKind::Vec(_) = ty.kind {
                     format!(
                         r#"for msg in &{ident} {{
-                            ::pilota::prost::encoding::message::encode({tag}, msg, buf);
+                            ::pilota::prost::encoding::{module}::encode({tag}, msg, buf);
                         }};"#
                     )
                     .into()
@@ -245,7 +253,7 @@ impl ProtobufBackend {
                             FieldKind::Required => format!("(&{ident})").into(),
                             FieldKind::Optional => "_pilota_inner_value".into(),
                         };
-                        format!("::pilota::prost::encoding::message::encode({tag}, {ident}, buf);")
+                        format!("::pilota::prost::encoding::{module}::encode({tag}, {ident}, buf);")
                             .into()
                     };
 
@@ -320,13 +328,15 @@ impl ProtobufBackend {
                 } else {
                     let module = self.ty_module(ty);
                     let merge_fn = format!("::pilota::prost::encoding::{module}::{merge_fn}");
+                    // groups need the tag to find their end
+                    let tag = if module == "group" { "tag, " } else { "" };
 
                     match kind {
                         FieldKind::Required => {
-                            format!("{merge_fn}(wire_type, {ident}, buf, ctx)").into()
+                            format!("{merge_fn}({tag}wire_type, {ident}, buf, ctx)").into()
                         }
                         FieldKind::Optional => format!(
-                            r#"{merge_fn}(wire_type, {ident}.get_or_insert_with(::core::default::Default::default), buf, ctx)"#
+                            r#"{merge_fn}({tag}wire_type, {ident}.get_or_insert_with(::core::default::Default::default), buf, ctx)"#
                         )
                         .into(),
                     }
@@ -349,6 +359,97 @@ impl ProtobufBackend {
     }
 }
 
+impl ProtobufBackend {
+    /// Implements `DecodeEnum` with the `unknown_enum` policy, the enums are
+    /// decoded with it instead of `From<i32>` unless the unknown values are
+    /// kept.
+    fn codegen_decode_enum(&self, def_id: DefId, stream: &mut String, e: &rir::Enum) {
+        let name = self.cx.rust_name(def_id);
+        let unknown = match (self.cx.options.unknown_enum, e.variants.first()) {
+            (UnknownEnum::Preserve, _) => return,
+            (UnknownEnum::Default, Some(first)) => format!(
+                "::core::result::Result::Ok(Self::{})",
+                self.cx.rust_name(first.did)
+            ),
+            (UnknownEnum::Default, None) => "::core::result::Result::Ok(Self(value))".into(),
+            (UnknownEnum::Error, _) => format!(
+                r#"::core::result::Result::Err(::pilota::prost::DecodeError::new(format!("unknown enum value for {name}, value: {{}}", value)))"#
+            ),
+        };
+        let known = e
+            .variants
+            .iter()
+            .map(|v| self.cx.variant_discr(v.did))
+            .sorted()
+            .dedup()
+            .map(|discr| format!("{discr} => ::core::result::Result::Ok(Self(value)),"))
+            .join("");
+        stream.push_str(&format!(
+            r#"
+            impl ::pilota::prost::encoding::enumeration::DecodeEnum for {name} {{
+                fn decode_value(value: i32) -> ::core::result::Result<Self, ::pilota::prost::DecodeError> {{
+                    match value {{
+                        {known}
+                        _ => {unknown},
+                    }}
+                }}
+            }}
+            "#
+        ));
+    }
+
+    fn codegen_wrapper_encoded_len(&self, ident: FastStr, f: &Field, inner: &Field) -> FastStr {
+        let tag = f.id;
+        let inner_tag = inner.id;
+        let module = self.ty_module(&inner.ty);
+        let encoded_len = format!(
+            "::pilota::prost::encoding::wrapper::encoded_len({tag}, value, |value| ::pilota::prost::encoding::{module}::encoded_len({inner_tag}, value))"
+        );
+        match f.kind {
+            FieldKind::Required => format!("{{ let value = &{ident}; {encoded_len} }}").into(),
+            FieldKind::Optional => {
+                format!("{ident}.as_ref().map_or(0, |value| {encoded_len})").into()
+            }
+        }
+    }
+
+    fn codegen_wrapper_encode(&self, ident: FastStr, f: &Field, inner: &Field) -> FastStr {
+        let tag = f.id;
+        let inner_tag = inner.id;
+        let module = self.ty_module(&inner.ty);
+        let encode = format!(
+            r#"::pilota::prost::encoding::wrapper::encode(
+                {tag},
+                value,
+                buf,
+                |value, buf| ::pilota::prost::encoding::{module}::encode({inner_tag}, value, buf),
+                |value| ::pilota::prost::encoding::{module}::encoded_len({inner_tag}, value),
+            );"#
+        );
+        match f.kind {
+            FieldKind::Required => format!("{{ let value = &{ident}; {encode} }}").into(),
+            FieldKind::Optional => {
+                format!("if let Some(value) = {ident}.as_ref() {{ {encode} }}").into()
+            }
+        }
+    }
+
+    fn codegen_wrapper_merge(&self, ident: FastStr, f: &Field, inner: &Field) -> FastStr {
+        let inner_tag = inner.id;
+        let module = self.ty_module(&inner.ty);
+        let value: FastStr = match f.kind {
+            FieldKind::Required => ident,
+            FieldKind::Optional => {
+                format!("{ident}.get_or_insert_with(::core::default::Default::default)").into()
+            }
+        };
+        format!(
+            "::pilota::prost::encoding::wrapper::merge({inner_tag}, wire_type, {value}, buf, ctx, ::pilota::prost::encoding::{module}::merge)"
+        )
+        .into()
+    }
+}
+
 impl CodegenBackend for ProtobufBackend {
     const PROTOCOL: &'static str = "protobuf";
 
@@ -359,6 +460,14 @@ impl CodegenBackend for ProtobufBackend {
             .iter()
             .map(|field| {
                 let field_name = self.cx.rust_name(field.did);
+                if let Some(inner) = self.cx.flattened_wrapper(field) {
+                    let len = self.codegen_wrapper_encoded_len(
+                        format!("self.{field_name}").into(),
+                        field,
+                        &inner,
+                    );
+                    return FastStr::from(format!("+ {len}"));
+                }
                 let len = self.codegen_encoded_len(
                     format!("self.{field_name}").into(),
                     &field.ty,
@@ -373,6 +482,13 @@ impl CodegenBackend for ProtobufBackend {
             .iter()
             .map(|field| {
                 let field_name = self.cx.rust_name(field.did);
+                if let Some(inner) = self.cx.flattened_wrapper(field) {
+                    return self.codegen_wrapper_encode(
+                        format!("self.{field_name}").into(),
+                        field,
+                        &inner,
+                    );
+                }
                 self.codegen_encode(
                     format!("self.{field_name}").into(),
                     &field.ty,
@@ -387,8 +503,16 @@ impl CodegenBackend for ProtobufBackend {
             .iter()
             .map(|field| {
                 let field_ident = self.cx.rust_name(field.did);
-                let merge =
-                    self.codegen_merge_field("_inner_pilota_value".into(), &field.ty, field.kind);
+                let merge = match self.cx.flattened_wrapper(field) {
+                    Some(inner) => {
+                        self.codegen_wrapper_merge("_inner_pilota_value".into(), field, &inner)
+                    }
+                    None => self.codegen_merge_field(
+                        "_inner_pilota_value".into(),
+                        &field.ty,
+                        field.kind,
+                    ),
+                };
                 let mut tags = self.field_tags(field).map(|tag| tag.to_string());
                 let tags = tags.join("|");
 
@@ -404,26 +528,56 @@ impl CodegenBackend for ProtobufBackend {
             })
             .join("");
 
+        let required_fields = s
+            .fields
+            .iter()
+            .filter(|field| self.cx.contains_tag::<Required>(field.tags_id))
+            .map(|field| format!(r#"({}, "{}")"#, field.id, field.name))
+            .join(", ");
+        let required_fields = if required_fields.is_empty() {
+            String::new()
+        } else {
+            format!(
+                r#"
+                fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {{
+                    ("{}", &[{required_fields}])
+                }}
+                "#,
+                s.name
+            )
+        };
+
         let struct_name = if s.fields.is_empty() {
             "".into()
         } else {
             format!("const STRUCT_NAME: &'static str = stringify!({name});")
         };
 
+        let inline = self.cx.inline_attr(def_id);
+        // `encoded_len` was always inlined, keep it that way when the option is off
+        let len_inline = if inline.is_empty() {
+            "#[inline]"
+        } else {
+            inline
+        };
+
         stream.push_str(&format!(
             r#"
             impl ::pilota::prost::Message for {name} {{
 
-                #[inline]
+                {len_inline}
                 fn encoded_len(&self) -> usize {{
                     0 {encoded_len}
                 }}
+                {required_fields}
 
+                {inline}
                 #[allow(unused_variables)]
                 fn encode_raw<B>(&self, buf: &mut B) where B: ::pilota::prost::bytes::BufMut {{
                     {encode}
                 }}
 
+                {inline}
                 #[allow(unused_variables)]
                 fn merge_field<B>(
                     &mut self,
@@ -451,7 +605,7 @@ impl CodegenBackend for ProtobufBackend {
     fn codegen_enum_impl(&self, def_id: DefId, stream: &mut String, e: &rir::Enum) {
         let node = self.cx.node(def_id).unwrap();
         if !self.cx.contains_tag::<OneOf>(node.tags) {
-            return;
+            return self.codegen_decode_enum(def_id, stream, e);
         }
         let name = self.cx.rust_name(def_id);
 
@@ -510,22 +664,30 @@ impl CodegenBackend for ProtobufBackend {
             }
         }).join("");
 
+        let inline = self.cx.inline_attr(def_id);
+        let len_inline = if inline.is_empty() {
+            "#[inline]"
+        } else {
+            inline
+        };
+
         stream.push_str(&format! {
             r#"impl {name} {{
+                {inline}
                 pub fn encode<B>(&self, buf: &mut B) where B: ::pilota::prost::bytes::BufMut {{
                     match self {{
                         {encode}
                     }}
                 }}
 
-                #[inline]
+                {len_inline}
                 pub fn encoded_len(&self) -> usize {{
                     match self {{
                         {encoded_len}
                     }}
                 }}
 
-                #[inline]
+                {len_inline}
                 pub fn merge<B>(
                     field: &mut ::core::option::Option<Self>,
                     tag: u32,
@@ -7,8 +7,8 @@ use super::traits::CodegenBackend;
 use crate::{
     db::RirDatabase,
     middle::{
-        context::Context,
-        rir::{self, Enum, Field, Message, Method, NewType, Service},
+        context::{Context, UnknownEnum},
+        rir::{self, Enum, Field, Message, NewType, Service},
     },
     rir::EnumVariant,
     symbol::{DefId, EnumRepr, Symbol},
@@ -57,7 +57,10 @@ impl ThriftBackend {
             };
 
             if is_optional {
-                format!("self.{field_name}.as_ref().map_or(0, |value| {write_field})").into()
+                let value = self
+                    .packed_value(f, "self")
+                    .unwrap_or_else(|| format!("self.{field_name}"));
+                format!("{value}.as_ref().map_or(0, |value| {write_field})").into()
             } else {
                 write_field
             }
@@ -79,8 +82,11 @@ impl ThriftBackend {
             };
 
             if is_optional {
+                let value = self
+                    .packed_value(f, "self")
+                    .unwrap_or_else(|| format!("self.{field_name}"));
                 format! {
-                    r#"if let Some(value) = self.{field_name}.as_ref() {{
+                    r#"if let Some(value) = {value}.as_ref() {{
                         {write_field}
                     }}"#
                 }
@@ -91,9 +97,69 @@ impl ThriftBackend {
         })
     }
 
+    fn codegen_encode_fields_owned<'a>(
+        &'a self,
+        fields: &'a [Arc<rir::Field>],
+    ) -> impl Iterator<Item = FastStr> + 'a {
+        fields.iter().map(|f| {
+            let field_name = self.rust_name(f.did);
+            let field_id = f.id as i16;
+            let boxed = self.field_is_box(f);
+            if f.is_optional() {
+                let write_field =
+                    self.codegen_encode_field_owned(field_id, &f.ty, "value".into(), boxed);
+                let value = self
+                    .packed_value(f, "self")
+                    .unwrap_or_else(|| format!("self.{field_name}"));
+                format! {
+                    r#"if let Some(value) = {value} {{
+                        {write_field}
+                    }}"#
+                }
+                .into()
+            } else {
+                self.codegen_encode_field_owned(
+                    field_id,
+                    &f.ty,
+                    format!("self.{field_name}").into(),
+                    boxed,
+                )
+            }
+        })
+    }
+
+    /// The inherent `encode_owned` of `owned_encode`.
+    fn codegen_impl_encode_owned(&self, def_id: DefId, name: &Symbol, encode: &str) -> String {
+        let inline = self.inline_attr(def_id);
+        let generics = self.item_generics(def_id);
+        format! {
+            r#"
+            impl{generics} {name}{generics} {{
+                /// Encodes the message like [`::pilota::thrift::Message::encode`],
+                /// moving its `FastStr` and `Bytes` into `__protocol` instead of
+                /// cloning them.
+                {inline}
+                pub fn encode_owned<T: ::pilota::thrift::TOutputProtocol>(
+                    self,
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {{
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TOutputProtocolExt;
+                    __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {{
+                        name: "{name}",
+                    }})?;
+                    {encode}
+                    __protocol.write_field_stop()?;
+                    __protocol.write_struct_end()?;
+                    ::std::result::Result::Ok(())
+                }}
+            }}"#
+        }
+    }
+
     fn codegen_impl_message(
         &self,
-        _def_id: DefId,
+        def_id: DefId,
         name: Symbol,
         encode: String,
         size: String,
@@ -130,8 +196,11 @@ impl ThriftBackend {
             }})
         }}"#
         );
+        let inline = self.inline_attr(def_id);
+        let generics = self.item_generics(def_id);
         format! {r#"
-            impl ::pilota::thrift::Message for {name} {{
+            impl{generics} ::pilota::thrift::Message for {name}{generics} {{
+                {inline}
                 fn encode<T: ::pilota::thrift::TOutputProtocol>(
                     &self,
                     __protocol: &mut T,
@@ -141,6 +210,7 @@ impl ThriftBackend {
                     {encode}
                 }}
 
+                {inline}
                 fn decode<T: ::pilota::thrift::TInputProtocol>(
                     __protocol: &mut T,
                 ) -> ::std::result::Result<Self,::pilota::thrift::ThriftException>  {{
@@ -151,6 +221,7 @@ impl ThriftBackend {
 
                 {decode_async_fn}
 
+                {inline}
                 fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {{
                     #[allow(unused_imports)]
                     use ::pilota::thrift::TLengthProtocolExt;
@@ -251,7 +322,7 @@ impl ThriftBackend {
 
         let read_struct_begin = helper.codegen_read_struct_begin();
         let read_struct_end = helper.codegen_read_struct_end();
-        let read_fields = self.codegen_decode_fields(helper, &s.fields, keep, is_arg);
+        let read_fields = self.codegen_decode_fields(helper, &name, &s.fields, keep, is_arg);
 
         let required_without_default_fields = s
             .fields
@@ -292,13 +363,30 @@ impl ThriftBackend {
             }
         };
 
-        let format_msg = format!("decode struct `{}` field(#{{}}) failed", name);
+        let trace_err = self.decode_trace(&format!(r#""failed to decode `{name}`: {{}}", err"#));
+
+        let field_names = s
+            .fields
+            .iter()
+            .map(|f| format!("{} => ::std::option::Option::Some(\"{}\"),", f.id, f.name))
+            .join("");
 
         let mut fields = s
             .fields
             .iter()
-            .map(|f| format!("{}: {}", self.rust_name(f.did), f.local_var_name()))
+            .map(|f| {
+                let name = self.rust_name(f.did);
+                let var = f.local_var_name();
+                if self.presence_bit(f).is_some() {
+                    format!("{name}: {var}.unwrap_or_default()")
+                } else {
+                    format!("{name}: {var}")
+                }
+            })
             .join(",");
+        if let Some(presence) = self.presence_init(s, |f| f.local_var_name()) {
+            fields.push_str(&format!(", {presence}"));
+        }
 
         if keep {
             if !fields.is_empty() {
@@ -321,9 +409,16 @@ impl ThriftBackend {
             {read_struct_begin};
             if let ::std::result::Result::Err(mut err) = {read_fields} {{
                 if let Some(field_id) = __pilota_decoding_field_id {{
-                    err.prepend_msg(&format!("{format_msg}, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {{
+                        message: "{name}",
+                        name: match field_id {{
+                            {field_names}
+                            _ => ::std::option::Option::None,
+                        }},
+                        id: field_id,
+                    }});
                 }}
-                return ::std::result::Result::Err(err);
+                {trace_err}return ::std::result::Result::Err(err);
             }};
             {read_struct_end};
 
@@ -354,6 +449,7 @@ impl ThriftBackend {
     fn codegen_decode_fields<'a>(
         &'a self,
         helper: &DecodeHelper,
+        name: &str,
         fields: &'a [Arc<Field>],
         keep: bool,
         is_arg: bool,
@@ -393,12 +489,23 @@ impl ThriftBackend {
                     ""
                 };
 
-                format!(
+                let mut arm = format!(
                     r#"Some({field_id}) if field_ident.field_type == {ttype}  => {{
                     {field_ident} = {read_field};
                     {fields_num}
                 }},"#
-                )
+                );
+                if self.options.strict_decode {
+                    arm.push_str(&format!(
+                        r#"Some({field_id}) => {{
+                            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("unexpected field type {{:?}}, expected {{:?}}", field_ident.field_type, {ttype})
+                            ));
+                        }},"#
+                    ));
+                }
+                arm
             })
             .join("");
         let mut skip_ttype = helper.codegen_skip_ttype("field_ident.field_type".into());
@@ -412,6 +519,10 @@ impl ThriftBackend {
             ""
         };
 
+        let trace_skip = self.decode_trace(&format!(
+            r#""skipped unknown field {{:?}} of `{name}`", field_ident.id"#
+        ));
+
         let read_field_end = helper.codegen_read_field_end();
         let field_end_len = helper.codegen_field_end_len(keep);
         let field_stop_len = helper.codegen_field_stop_len(keep);
@@ -441,7 +552,7 @@ impl ThriftBackend {
                 match field_ident.id {{
                     {match_fields}
                     _ => {{
-                        {skip_ttype};
+                        {trace_skip}{skip_ttype};
                         {write_unknown_field}
                     }},
                 }}
@@ -497,16 +608,25 @@ impl CodegenBackend for ThriftBackend {
             },
             |helper| self.codegen_decode(helper, s, name.clone(), keep, self.is_arg(def_id)),
         ));
+
+        if self.options.owned_encode {
+            let mut encode_fields = self.codegen_encode_fields_owned(&s.fields).join("");
+            if keep {
+                encode_fields.push_str(
+                    r#"for bytes in self._unknown_fields.list {
+                        __protocol.write_bytes_without_len(bytes)?;
+                    }"#,
+                );
+            }
+            stream.push_str(&self.codegen_impl_encode_owned(def_id, &name, &encode_fields));
+        }
     }
 
     fn codegen_service_impl(&self, _def_id: DefId, _stream: &mut String, _s: &Service) {}
 
-    fn codegen_service_method(&self, _service_def_id: DefId, _m: &Method) -> String {
-        Default::default()
-    }
-
     fn codegen_enum_impl(&self, def_id: DefId, stream: &mut String, e: &Enum) {
         let keep = self.keep_unknown_fields.contains(&def_id);
+        let unknown_variant = self.has_unknown_variant(def_id);
         let name = self.rust_name(def_id);
         let is_entry_message = self.node_contains_tag::<EntryMessage>(def_id);
         let v = "self.inner()";
@@ -523,8 +643,41 @@ impl CodegenBackend for ThriftBackend {
                 |helper| {
                     let read_i32 = helper.codegen_read_i32();
                     let err_msg_tmpl = format!("invalid enum value for {}, value: {{}}", name);
+                    let values = e
+                        .variants
+                        .iter()
+                        .map(|v| self.variant_discr(v.did))
+                        .join(", ");
+                    let policy = if self.options.strict_decode {
+                        UnknownEnum::Error
+                    } else {
+                        self.options.unknown_enum
+                    };
+                    let check_known = match policy {
+                        UnknownEnum::Preserve => None,
+                        UnknownEnum::Error => Some(format!(
+                            r#"return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("unknown enum value for {name}, value: {{}}", value)
+                            ));"#
+                        )),
+                        UnknownEnum::Default => e.variants.first().map(|v| {
+                            format!(
+                                "return ::std::result::Result::Ok(Self::{});",
+                                self.rust_name(v.did)
+                            )
+                        }),
+                    }
+                    .map(|unknown| {
+                        format! {
+                            r#"if ![{values}].contains(&value) {{
+                                {unknown}
+                            }}"#
+                        }
+                    })
+                    .unwrap_or_default();
                     format! {
-                        r#"let value = {read_i32};
+                        r#"let value = {read_i32};{check_known}
                         ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(|err|
                             ::pilota::thrift::new_protocol_exception(
                                 ::pilota::thrift::ProtocolExceptionKind::InvalidData,
@@ -562,7 +715,9 @@ impl CodegenBackend for ThriftBackend {
                     });
                 }
 
-                if e.variants.is_empty() {
+                if unknown_variant {
+                    encode_variants.push_str(&format!("{name}::__Unknown => {{}},"));
+                } else if e.variants.is_empty() {
                     encode_variants.push_str("_ => {},");
                 }
 
@@ -590,7 +745,9 @@ impl CodegenBackend for ThriftBackend {
                     })
                 }
 
-                if e.variants.is_empty() {
+                if unknown_variant {
+                    variants_size.push_str(&format!("{name}::__Unknown => 0,"));
+                } else if e.variants.is_empty() {
                     variants_size.push_str("_ => 0,");
                 }
 
@@ -635,6 +792,9 @@ impl CodegenBackend for ThriftBackend {
                         if keep && !helper.is_async {
                             skip = format!("__pilota_offset += {skip}")
                         }
+                        let trace_skip = self.decode_trace(&format!(
+                            r#""skipped unknown field {{:?}} of `{name}`", field_ident.id"#
+                        ));
                         let fields = e
                             .variants
                             .iter()
@@ -702,6 +862,8 @@ impl CodegenBackend for ThriftBackend {
                         let handle_none_ret: FastStr =
                             if e.variants.first().filter(|v| variant_is_void(v)).is_some() {
                                 format!("::std::result::Result::Ok({name}::Ok(()))").into()
+                            } else if unknown_variant {
+                                format!("::std::result::Result::Ok({name}::__Unknown)").into()
                             } else {
                                 r#"::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
                                     ::pilota::thrift::ProtocolExceptionKind::InvalidData,
@@ -724,7 +886,7 @@ impl CodegenBackend for ThriftBackend {
                                 match field_ident.id {{
                                     {fields}
                                     _ => {{
-                                        {skip};
+                                        {trace_skip}{skip};
                                         {write_unknown_field}
                                     }},
                                 }}
@@ -738,7 +900,49 @@ impl CodegenBackend for ThriftBackend {
                             }}"#
                         }
                     },
-                ))
+                ));
+
+                if self.options.owned_encode {
+                    let mut encode_variants = e
+                        .variants
+                        .iter()
+                        .map(|v| {
+                            let variant_name = self.rust_name(v.did);
+                            let variant_id = v.id.unwrap() as i16;
+                            let boxed = self.with_adjust(v.did, |adj| adj.is_some_and(|adj| adj.boxed()));
+                            let encode = self.codegen_encode_field_owned(
+                                variant_id,
+                                &v.fields[0],
+                                "value".into(),
+                                boxed,
+                            );
+                            format! {
+                                r#"{name}::{variant_name}(value) => {{
+                                {encode}
+                            }},"#
+                            }
+                        })
+                        .join("");
+                    if keep {
+                        encode_variants.push_str(&format! {
+                            r#"{name}::_UnknownFields(value) => {{
+                                for bytes in value.list {{
+                                    __protocol.write_bytes_without_len(bytes)?;
+                                }}
+                            }},"#
+                        });
+                    }
+                    if unknown_variant {
+                        encode_variants.push_str(&format!("{name}::__Unknown => {{}},"));
+                    } else if e.variants.is_empty() {
+                        encode_variants.push_str("_ => {},");
+                    }
+                    stream.push_str(&self.codegen_impl_encode_owned(
+                        def_id,
+                        &name,
+                        &format!("match self {{ {encode_variants} }}"),
+                    ));
+                }
             }
             #[allow(unreachable_patterns)]
             _ => {}
@@ -5,6 +5,7 @@ use crate::{
     db::RirDatabase,
     middle::{rir, ty, ty::Ty},
     symbol::EnumRepr,
+    tags::thrift::EntryMessage,
     DefId,
 };
 
@@ -40,7 +41,7 @@ impl ThriftBackend {
                     _ => panic!("unsupported type {:?}", item),
                 }
             }
-            ty::Arc(ty) => self.ttype(ty),
+            ty::Arc(ty) | ty::Cow(ty) | ty::BoxedSlice(ty) => self.ttype(ty),
             _ => unimplemented!(),
         }
     }
@@ -86,7 +87,7 @@ impl ThriftBackend {
                 self.encode_map(k, v, ident, "btree_map")
             }
             ty::Path(_) => format!("__protocol.write_struct({ident})?;").into(),
-            ty::Arc(ty) => self.codegen_encode_ty(ty, ident),
+            ty::Arc(ty) | ty::Cow(ty) | ty::BoxedSlice(ty) => self.codegen_encode_ty(ty, ident),
             _ => unimplemented!(),
         }
     }
@@ -188,11 +189,43 @@ impl ThriftBackend {
                 )
                 .into(),
             },
-            ty::Arc(ty) => self.codegen_encode_field(id, ty, ident),
+            ty::Arc(ty) | ty::Cow(ty) | ty::BoxedSlice(ty) => self.codegen_encode_field(id, ty, ident),
             _ => unimplemented!(),
         }
     }
 
+    /// Like `codegen_encode_field` for an owned `ident`: the `FastStr`,
+    /// `Bytes` and unboxed structs and unions are moved into the protocol.
+    pub(crate) fn codegen_encode_field_owned(
+        &self,
+        id: i16,
+        ty: &Ty,
+        ident: FastStr,
+        boxed: bool,
+    ) -> FastStr {
+        match &ty.kind {
+            ty::FastStr => format!("__protocol.write_faststr_field({id}, {ident})?;").into(),
+            ty::Bytes => format!("__protocol.write_bytes_field({id}, {ident})?;").into(),
+            ty::Path(p) if !boxed && self.has_encode_owned(p.did) => format! {
+                r#"__protocol.write_field_begin(::pilota::thrift::TType::Struct, {id})?;
+                    {ident}.encode_owned(__protocol)?;
+                    __protocol.write_field_end()?;"#
+            }
+            .into(),
+            _ => self.codegen_encode_field(id, ty, format!("&{ident}").into()),
+        }
+    }
+
+    fn has_encode_owned(&self, def_id: DefId) -> bool {
+        match &*self.expect_item(def_id) {
+            rir::Item::Message(_) => true,
+            rir::Item::Enum(e) => {
+                e.repr.is_none() && !self.node_contains_tag::<EntryMessage>(def_id)
+            }
+            _ => false,
+        }
+    }
+
     #[inline]
     fn encode_set_field(&self, ty: &Ty, id: i16, ident: FastStr, name: &str) -> FastStr {
         let write_el = self.codegen_encode_ty(ty, "val".into());
@@ -256,7 +289,7 @@ impl ThriftBackend {
             ty::Map(k, v) => self.map_size(k, v, ident, "map"),
             ty::BTreeMap(k, v) => self.map_size(k, v, ident, "btree_map"),
             ty::Path(_) => format!("__protocol.struct_len({ident})").into(),
-            ty::Arc(ty) => self.codegen_ty_size(ty, ident),
+            ty::Arc(ty) | ty::Cow(ty) | ty::BoxedSlice(ty) => self.codegen_ty_size(ty, ident),
             _ => unimplemented!(),
         }
     }
@@ -324,7 +357,9 @@ impl ThriftBackend {
                 format!("__protocol.i32_field_len(Some({id}), ({ident}).inner())").into()
             }
             ty::Path(_) => format!("__protocol.struct_field_len(Some({id}), {ident})").into(),
-            ty::Arc(ty) => self.codegen_field_size(ty, id, ident),
+            ty::Arc(ty) | ty::Cow(ty) | ty::BoxedSlice(ty) => {
+                self.codegen_field_size(ty, id, ident)
+            }
             _ => unimplemented!(),
         }
     }
@@ -393,14 +428,22 @@ impl ThriftBackend {
                 let read_list_end = helper.codegen_read_list_end();
                 let read_el = self.codegen_decode_ty(helper, ty);
                 let ty_rust_name = self.codegen_item_ty(ty.kind.clone());
+                let check_len = self.codegen_check_len("list_ident", "list");
                 if !helper.is_async {
+                    let read_els = self.codegen_decode_elements(
+                        helper,
+                        &format! {
+                            r#"for i in 0..list_ident.size {{
+                                __pilota_decoding_index = i;
+                                val.as_mut_ptr().offset(i as isize).write({read_el});
+                            }}"#
+                        },
+                    );
                     format! {
                         r#"unsafe {{
-                            let list_ident = {read_list_begin};
+                            let list_ident = {read_list_begin};{check_len}
                             let mut val: ::std::vec::Vec<{ty_rust_name}> = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for i in 0..list_ident.size {{
-                                val.as_mut_ptr().offset(i as isize).write({read_el});
-                            }};
+                            {read_els}
                             val.set_len(list_ident.size);
                             {read_list_end};
                             val
@@ -408,13 +451,20 @@ impl ThriftBackend {
                     }
                     .into()
                 } else {
+                    let read_els = self.codegen_decode_elements(
+                        helper,
+                        &format! {
+                            r#"for i in 0..list_ident.size {{
+                                __pilota_decoding_index = i;
+                                val.push({read_el});
+                            }}"#
+                        },
+                    );
                     format! {
                         r#"{{
-                            let list_ident = {read_list_begin};
+                            let list_ident = {read_list_begin};{check_len}
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {{
-                                val.push({read_el});
-                            }};
+                            {read_els}
                             {read_list_end};
                             val
                         }}"#
@@ -446,20 +496,81 @@ impl ThriftBackend {
                 let inner = self.codegen_decode_ty(helper, ty);
                 format!("::std::sync::Arc::new({inner})").into()
             }
+            ty::Cow(ty) => {
+                let inner = self.codegen_decode_ty(helper, ty);
+                format!("::std::borrow::Cow::Owned({inner})").into()
+            }
+            ty::BoxedSlice(ty) => {
+                let inner = self.codegen_decode_ty(helper, ty);
+                format!("::std::vec::Vec::into_boxed_slice({inner})").into()
+            }
             _ => unimplemented!(),
         }
     }
 
+    /// Runs `read_els`, the loop decoding the elements of a list or set or
+    /// the entries of a map, which sets `__pilota_decoding_index` to the
+    /// index of each. The index of the failing one is prepended to the path
+    /// of the error.
+    fn codegen_decode_elements(&self, helper: &DecodeHelper, read_els: &str) -> String {
+        let read_els = if helper.is_async {
+            format! {
+                r#"async {{
+                    {read_els}
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }}.await"#
+            }
+        } else {
+            format! {
+                r#"(|| {{
+                    {read_els}
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }})()"#
+            }
+        };
+        format! {
+            r#"let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = {read_els} {{
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }}"#
+        }
+    }
+
+    /// With `strict_decode`, fails on the negative lengths read as a huge
+    /// `usize`.
+    fn codegen_check_len(&self, ident: &str, kind: &str) -> String {
+        if !self.options.strict_decode {
+            return Default::default();
+        }
+        format! {
+            r#"if {ident}.size > i32::MAX as usize {{
+                return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    format!("negative {kind} length {{}}", {ident}.size as i32)
+                ));
+            }}"#
+        }
+    }
+
     #[inline]
     fn decode_set(&self, ty: &Ty, helper: &DecodeHelper, new: &str) -> FastStr {
         let read_set_begin = helper.codegen_read_set_begin();
         let read_set_end = helper.codegen_read_set_end();
         let read_el = self.codegen_decode_ty(helper, ty);
-        format! {r#"{{let list_ident = {read_set_begin};
+        let check_len = self.codegen_check_len("list_ident", "set");
+        let read_els = self.codegen_decode_elements(
+            helper,
+            &format! {
+                r#"for i in 0..list_ident.size {{
+                    __pilota_decoding_index = i;
+                    val.insert({read_el});
+                }}"#
+            },
+        );
+        format! {r#"{{let list_ident = {read_set_begin};{check_len}
                     let mut val = {new};
-                    for _ in 0..list_ident.size {{
-                        val.insert({read_el});
-                    }};
+                    {read_els}
                     {read_set_end};
                     val}}"#}
         .into()
@@ -471,13 +582,21 @@ impl ThriftBackend {
         let read_el_val = self.codegen_decode_ty(helper, val_ty);
         let read_map_begin = helper.codegen_read_map_begin();
         let read_map_end = helper.codegen_read_map_end();
+        let check_len = self.codegen_check_len("map_ident", "map");
+        let read_els = self.codegen_decode_elements(
+            helper,
+            &format! {
+                r#"for i in 0..map_ident.size {{
+                    __pilota_decoding_index = i;
+                    val.insert({read_el_key}, {read_el_val});
+                }}"#
+            },
+        );
         format! {
                     r#"{{
-                        let map_ident = {read_map_begin};
+                        let map_ident = {read_map_begin};{check_len}
                         let mut val = {new};
-                        for _ in 0..map_ident.size {{
-                            val.insert({read_el_key}, {read_el_val});
-                        }}
+                        {read_els}
                         {read_map_end};
                         val
                     }}"#
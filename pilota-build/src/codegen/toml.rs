@@ -1,3 +1,9 @@
+use std::{io::ErrorKind, path::Path};
+
+pub(crate) const GENERATED_BEGIN: &str =
+    "# @generated by pilota-build, do not edit until the end marker";
+pub(crate) const GENERATED_END: &str = "# @generated end";
+
 pub fn merge_tomls(a: &mut toml::Value, b: toml::Value) {
     match (a, b) {
         (toml::Value::Boolean(a), toml::Value::Boolean(b)) => {
@@ -21,3 +27,71 @@ pub fn merge_tomls(a: &mut toml::Value, b: toml::Value) {
         pair => panic!("can not merge {pair:?}"),
     }
 }
+
+/// Remove the keys of `generated` from `a`, dropping the tables left empty.
+fn remove_generated(a: &mut toml::Value, generated: &toml::Value) {
+    let (toml::Value::Table(a), toml::Value::Table(generated)) = (a, generated) else {
+        return;
+    };
+    for (k, v) in generated {
+        let Some(old) = a.get_mut(k) else {
+            continue;
+        };
+        if old.is_table() && v.is_table() {
+            remove_generated(old, v);
+            if !old.as_table().unwrap().is_empty() {
+                continue;
+            }
+        }
+        a.remove(k);
+    }
+}
+
+/// Write `generated` between the markers of the manifest at `path`, keeping
+/// the rest of it as is. A missing manifest starts from `template`. The
+/// markers are appended to a manifest without them, e.g. from an older
+/// version, after taking the generated keys out of it.
+pub(crate) fn write_manifest(
+    path: &Path,
+    template: &str,
+    generated: &toml::Value,
+) -> anyhow::Result<()> {
+    let manifest = match std::fs::read_to_string(path) {
+        Ok(manifest) => manifest,
+        Err(e) if e.kind() == ErrorKind::NotFound => template.to_string(),
+        Err(e) => return Err(e.into()),
+    };
+    let section = format!(
+        "{GENERATED_BEGIN}\n{}\n{GENERATED_END}\n",
+        toml::to_string_pretty(generated)?.trim()
+    );
+
+    let manifest = match (manifest.find(GENERATED_BEGIN), manifest.find(GENERATED_END)) {
+        (Some(begin), Some(end)) if begin < end => {
+            let rest = &manifest[end + GENERATED_END.len()..];
+            let rest = rest.strip_prefix('\n').unwrap_or(rest);
+            format!("{}{section}{rest}", &manifest[..begin])
+        }
+        _ if manifest.trim().is_empty() => section,
+        _ => {
+            let parsed = toml::from_str::<toml::Value>(&manifest)?;
+            let mut user = parsed.clone();
+            remove_generated(&mut user, generated);
+            // rewritten only when it defines generated keys, which loses
+            // the comments
+            let user = if user == parsed {
+                manifest
+            } else {
+                toml::to_string_pretty(&user)?
+            };
+            if user.trim().is_empty() {
+                section
+            } else {
+                format!("{}\n\n{section}", user.trim_end())
+            }
+        }
+    };
+
+    std::fs::write(path, manifest)?;
+    Ok(())
+}
@@ -10,8 +10,8 @@ pub trait CodegenBackend: Clone {
     fn cx(&self) -> &Context;
     fn codegen_struct_impl(&self, _def_id: DefId, _stream: &mut String, _s: &rir::Message) {}
     fn codegen_service_impl(&self, _def_id: DefId, _stream: &mut String, _s: &rir::Service) {}
-    fn codegen_service_method(&self, _service_def_id: DefId, _method: &Method) -> String {
-        Default::default()
+    fn codegen_service_method(&self, _service_def_id: DefId, method: &Method) -> String {
+        self.cx().service_method_sig(method).unwrap_or_default()
     }
     /// Generate methods for the service trait and use global paths to generate
     /// the types of the arguments and outputs.
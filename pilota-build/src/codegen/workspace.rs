@@ -1,6 +1,5 @@
-use std::{borrow::Cow, path::PathBuf, process::Command, sync::Arc};
+use std::{borrow::Cow, path::PathBuf, sync::Arc};
 
-use anyhow::bail;
 use faststr::FastStr;
 use itertools::Itertools;
 use rayon::prelude::{IntoParallelRefIterator, ParallelIterator};
@@ -8,8 +7,11 @@ use rustc_hash::FxHashMap;
 
 use super::CodegenItem;
 use crate::{
-    fmt::fmt_file, middle::context::DefLocation, rir::ItemPath, Codegen, CodegenBackend, Context,
-    DefId,
+    db::RirDatabase,
+    fmt::fmt_file_with,
+    middle::context::{DefLocation, WorkspaceLayout},
+    rir::ItemPath,
+    Codegen, CodegenBackend, Context, DefId, Symbol,
 };
 
 #[derive(Clone)]
@@ -18,16 +20,6 @@ pub struct Workspace<B> {
     cg: Codegen<B>,
 }
 
-fn run_cmd(cmd: &mut Command) -> Result<(), anyhow::Error> {
-    let status = cmd.status()?;
-
-    if !status.success() {
-        bail!("run cmd {:?} failed", cmd)
-    }
-
-    Ok(())
-}
-
 struct CrateInfo {
     name: FastStr,
     main_mod_path: Option<ItemPath>,
@@ -53,6 +45,29 @@ where
         }
     }
 
+    fn layout(&self) -> WorkspaceLayout {
+        self.cx().workspace_info().layout
+    }
+
+    /// The directory of the crate, relative to the workspace root. Unused for
+    /// the flat layout, which has no crates.
+    fn crate_dir(&self, name: &str) -> PathBuf {
+        match self.layout() {
+            WorkspaceLayout::Root | WorkspaceLayout::Flat => PathBuf::from(name),
+            WorkspaceLayout::Crates => PathBuf::from("crates").join(name),
+        }
+    }
+
+    /// The directory holding `gen.rs` of the crate.
+    fn src_dir(&self, name: &str) -> PathBuf {
+        match self.layout() {
+            WorkspaceLayout::Root | WorkspaceLayout::Crates => {
+                self.base_dir.join(self.crate_dir(name)).join("src")
+            }
+            WorkspaceLayout::Flat => self.base_dir.join("src").join(name),
+        }
+    }
+
     pub fn group_defs(&self, entry_def_ids: &[DefId]) -> Result<(), anyhow::Error> {
         let location_map = self.collect_def_ids(entry_def_ids, None);
         let entry_map = location_map.iter().into_group_map_by(|item| item.1);
@@ -75,60 +90,74 @@ where
 
         let this = self.clone();
 
-        let members = entry_map
+        let crate_names = entry_map
             .keys()
-            .map(|k| {
-                let name = self.cx().crate_name(k);
-                format!("    \"{name}\"")
-            })
-            .dedup()
+            .map(|k| self.cx().crate_name(k))
             .sorted()
+            .dedup()
+            .collect_vec();
+
+        let members = crate_names
+            .iter()
+            .map(|name| format!("    \"{}\"", self.crate_dir(name).display()))
             .join(",\n");
 
-        let mut cargo_toml = toml::from_str::<toml::Value>(&unsafe {
-            String::from_utf8_unchecked(std::fs::read(self.base_dir.join("Cargo.toml")).unwrap())
-        })
-        .unwrap();
+        let dependencies = format!(
+            r#"pilota = "*"
+    anyhow = "1"
+    volo = "*"
+    volo-{} = "*""#,
+            if B::PROTOCOL == "thrift" {
+                "thrift"
+            } else if B::PROTOCOL == "protobuf" {
+                "grpc"
+            } else {
+                panic!("unknown protocol")
+            }
+        );
+
+        let cargo_toml_path = self.base_dir.join("Cargo.toml");
+        let workspace_deps = if self.layout() == WorkspaceLayout::Flat {
+            // all the generated code lives in the root crate
+            crate::codegen::toml::write_manifest(
+                &cargo_toml_path,
+                "",
+                &toml::from_str(&format!("[dependencies]\n    {dependencies}")).unwrap(),
+            )?;
 
-        crate::codegen::toml::merge_tomls(
-            &mut cargo_toml,
-            toml::from_str::<toml::Value>(&format!(
-                r#"[workspace]
+            vec![]
+        } else {
+            crate::codegen::toml::write_manifest(
+                &cargo_toml_path,
+                "",
+                &toml::from_str(&format!(
+                    r#"[workspace]
     members = [
     {members}
     ]
 
     [workspace.dependencies]
-    pilota = "*"
-    anyhow = "1"
-    volo = "*"
-    volo-{} = "*""#,
-                if B::PROTOCOL == "thrift" {
-                    "thrift"
-                } else if B::PROTOCOL == "protobuf" {
-                    "grpc"
-                } else {
-                    panic!("unknown protocol")
-                }
-            ))
-            .unwrap(),
-        );
+    {dependencies}"#
+                ))
+                .unwrap(),
+            )?;
 
-        let workspace_deps = cargo_toml
-            .get("workspace")
-            .unwrap()
-            .get("dependencies")
-            .unwrap()
-            .as_table()
-            .unwrap()
-            .keys()
-            .map(FastStr::new)
-            .collect_vec();
+            // the crates inherit the dependencies added by the user as well
+            let cargo_toml =
+                toml::from_str::<toml::Value>(&std::fs::read_to_string(&cargo_toml_path)?)?;
+            cargo_toml
+                .get("workspace")
+                .unwrap()
+                .get("dependencies")
+                .unwrap()
+                .as_table()
+                .unwrap()
+                .keys()
+                .map(FastStr::new)
+                .collect_vec()
+        };
 
-        std::fs::write(
-            self.base_dir.join("Cargo.toml"),
-            toml::to_string_pretty(&cargo_toml).unwrap(),
-        )?;
+        let crate_deps = self.cx().crate_dependencies();
 
         entry_deps
             .par_iter()
@@ -139,10 +168,12 @@ where
                     DefLocation::Fixed(_, path) => (
                         Some(path.clone()),
                         deps.iter().map(|v| v.0).collect_vec(),
-                        deps.iter()
-                            .map(|dep| this.cx().crate_name(&dep.1))
+                        crate_deps
+                            .get(&name)
+                            .into_iter()
+                            .flatten()
+                            .cloned()
                             .sorted()
-                            .dedup()
                             .collect_vec(),
                     ),
                     DefLocation::Dynamic => (None, vec![], vec![]),
@@ -161,6 +192,16 @@ where
                 )
             })?;
 
+        if self.layout() == WorkspaceLayout::Flat {
+            let lib_rs_stream = crate_names
+                .iter()
+                .map(|name| format!("pub mod {};\n", Symbol::from(name.clone())))
+                .join("");
+            let lib_rs = self.base_dir.join("src/lib.rs");
+            std::fs::write(&lib_rs, lib_rs_stream)?;
+            fmt_file_with(lib_rs, self.cx().options.fmt);
+        }
+
         Ok(())
     }
 
@@ -177,62 +218,39 @@ where
         base_dir: impl AsRef<std::path::Path>,
         info: CrateInfo,
     ) -> anyhow::Result<()> {
-        if !base_dir.as_ref().join(&*info.name).exists() {
-            run_cmd(
-                Command::new("cargo")
-                    .arg("init")
-                    .arg("--lib")
-                    .arg("--vcs")
-                    .arg("none")
-                    .current_dir(base_dir.as_ref())
-                    .arg(&*info.name),
-            )?;
-        };
-
-        let cargo_toml_path = base_dir.as_ref().join(&*info.name).join("Cargo.toml");
-
-        let mut cargo_toml = toml::from_str::<toml::Value>(&unsafe {
-            String::from_utf8_unchecked(std::fs::read(&cargo_toml_path)?)
-        })
-        .unwrap();
-
-        let deps = info
-            .deps
-            .iter()
-            .map(|s| Cow::from(format!(r#"{} = {{ path = "../{}" }}"#, s, s)))
-            .chain(
-                info.workspace_deps
-                    .iter()
-                    .map(|s| Cow::from(format!(r#"{s}.workspace = true"#))),
+        let src_dir = self.src_dir(&info.name);
+        // the flat layout has no crate to init, the module file sits next to
+        // its source directory
+        let (lib_rs, include_prefix) = if self.layout() == WorkspaceLayout::Flat {
+            std::fs::create_dir_all(&src_dir)?;
+            (
+                base_dir
+                    .as_ref()
+                    .join("src")
+                    .join(format!("{}.rs", info.name)),
+                format!("{}/", info.name),
             )
-            .join("\n");
-
-        super::toml::merge_tomls(
-            &mut cargo_toml,
-            toml::from_str::<toml::Value>(&format!("[dependencies]\n{deps}")).unwrap(),
-        );
-
-        std::fs::write(
-            &cargo_toml_path,
-            toml::to_string_pretty(&cargo_toml).unwrap(),
-        )?;
+        } else {
+            self.init_crate(base_dir.as_ref(), &info)?;
+            (src_dir.join("lib.rs"), String::new())
+        };
 
         let mut lib_rs_stream = String::default();
-        lib_rs_stream.push_str("include!(\"gen.rs\");\n");
+        lib_rs_stream.push_str(&format!("include!(\"{include_prefix}gen.rs\");\n"));
         lib_rs_stream.push_str("pub use gen::*;\n\n");
 
         if let Some(user_gen) = info.user_gen {
             if !user_gen.is_empty() {
-                lib_rs_stream.push_str("include!(\"custom.rs\");\n");
+                lib_rs_stream.push_str(&format!("include!(\"{include_prefix}custom.rs\");\n"));
 
                 let mut custom_rs_stream = String::default();
                 custom_rs_stream.push_str(&user_gen);
 
-                let custom_rs = base_dir.as_ref().join(&*info.name).join("src/custom.rs");
+                let custom_rs = src_dir.join("custom.rs");
 
                 std::fs::write(&custom_rs, custom_rs_stream)?;
 
-                fmt_file(custom_rs);
+                fmt_file_with(custom_rs, self.cx().options.fmt);
             }
         }
 
@@ -246,34 +264,79 @@ where
                     def_id,
                     kind: super::CodegenKind::RePub,
                 })),
-            base_dir.as_ref().join(&*info.name).join("src").as_path(),
+            src_dir.as_path(),
         );
+        if self.cg.options.message_registry {
+            // the first segment of the item paths is the crate
+            self.cg
+                .write_message_registry(&mut gen_rs_stream, info.items.iter().copied(), 1);
+        }
+        // the items of the crate, the others have their own fingerprint
+        if let Some(fingerprint) = self.cg.idl_fingerprint_const(
+            info.items
+                .iter()
+                .map(|def_id| self.cg.node(*def_id).unwrap().file_id),
+        ) {
+            gen_rs_stream.push_str(&fingerprint);
+        }
         if let Some(main_mod_path) = info.main_mod_path {
             gen_rs_stream.push_str(&format!(
                 "pub use {}::*;",
                 main_mod_path.iter().map(|item| item.to_string()).join("::")
             ));
         }
+        let allows = self.cg.lint_allows_attr();
         gen_rs_stream = format! {r#"pub mod gen {{
-            #![allow(warnings, clippy::all)]
+            {allows}
             {gen_rs_stream}
         }}"#};
 
         let lib_rs_stream = lib_rs_stream.lines().map(|s| s.trim_end()).join("\n");
         let gen_rs_stream = gen_rs_stream.lines().map(|s| s.trim_end()).join("\n");
 
-        let lib_rs = base_dir.as_ref().join(&*info.name).join("src/lib.rs");
-        let gen_rs = base_dir.as_ref().join(&*info.name).join("src/gen.rs");
+        let gen_rs = src_dir.join("gen.rs");
 
         std::fs::write(&lib_rs, lib_rs_stream)?;
         std::fs::write(&gen_rs, gen_rs_stream)?;
 
-        fmt_file(lib_rs);
-        fmt_file(gen_rs);
+        fmt_file_with(lib_rs, self.cx().options.fmt);
+        fmt_file_with(gen_rs, self.cx().options.fmt);
 
         Ok(())
     }
 
+    /// Write the manifest of the crate, from the manifest template if it
+    /// doesn't exist yet.
+    fn init_crate(&self, base_dir: &std::path::Path, info: &CrateInfo) -> anyhow::Result<()> {
+        let crate_dir = base_dir.join(self.crate_dir(&info.name));
+        std::fs::create_dir_all(crate_dir.join("src"))?;
+
+        let template = match &self.cx().workspace_info().manifest_template {
+            Some(template) => template.replace("{name}", &info.name),
+            None => format!(
+                "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+                info.name
+            ),
+        };
+
+        let deps = info
+            .deps
+            .iter()
+            .map(|s| Cow::from(format!(r#"{} = {{ path = "../{}" }}"#, s, s)))
+            .chain(
+                info.workspace_deps
+                    .iter()
+                    .map(|s| Cow::from(format!(r#"{s}.workspace = true"#))),
+            )
+            .join("\n");
+
+        super::toml::write_manifest(
+            &crate_dir.join("Cargo.toml"),
+            &template,
+            &toml::from_str(&format!("[dependencies]\n{deps}")).unwrap(),
+        )
+    }
+
     pub(crate) fn write_crates(self) -> anyhow::Result<()> {
         self.group_defs(&self.cx().codegen_items)
     }
@@ -154,7 +154,9 @@ fn ty_equal(nodes: &Nodes, ty1: &Ty, ty2: &Ty) -> bool {
         }
         (TyKind::Vec(t1), TyKind::Vec(t2))
         | (TyKind::Set(t1), TyKind::Set(t2))
-        | (TyKind::Arc(t1), TyKind::Arc(t2)) => ty_equal(nodes, t1, t2),
+        | (TyKind::Arc(t1), TyKind::Arc(t2))
+        | (TyKind::Cow(t1), TyKind::Cow(t2))
+        | (TyKind::BoxedSlice(t1), TyKind::BoxedSlice(t2)) => ty_equal(nodes, t1, t2),
         (TyKind::Path(p1), TyKind::Path(p2)) => def_id_equal(nodes, p1.did, p2.did),
         _ => false,
     }
@@ -1,4 +1,7 @@
+use std::{cell::RefCell, fmt::Display};
+
 use itertools::Itertools;
+use rustc_hash::FxHashSet;
 
 #[derive(Default)]
 pub(crate) struct Handler {
@@ -36,16 +39,61 @@ impl Handler {
             return;
         }
 
-        eprintln!(
-            "Errors: {}",
-            self.errors
-                .iter()
-                .map(|s| match s {
-                    Message::Str(s) => s,
-                })
-                .join("\n")
-        );
-
-        std::panic::resume_unwind(Box::new(()));
+        let errors = self
+            .errors
+            .iter()
+            .map(|s| match s {
+                Message::Str(s) => s,
+            })
+            .join("\n");
+        eprintln!("Errors: {errors}");
+
+        // the errors are already printed, so the panic hook is skipped, but
+        // they are the payload for those catching the unwind
+        std::panic::resume_unwind(Box::new(errors));
+    }
+}
+
+/// The categories of the warnings emitted while generating code, to turn
+/// into errors with `Builder::deny_warnings`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarningKind {
+    /// An item of `touches` or `item_tag` doesn't exist
+    MissingItem,
+    /// Several thrift files have the same namespace
+    DuplicateNamespace,
+    /// Extra or trailing separators in a thrift file
+    Separator,
+    /// A legacy thrift `xsd_*` modifier that is ignored
+    LegacyXsd,
+    /// A service without methods, even inherited ones
+    EmptyService,
+    /// A deprecated thrift type, like `slist`
+    DeprecatedType,
+}
+
+thread_local! {
+    static DENIED_WARNINGS: RefCell<FxHashSet<WarningKind>> = RefCell::default();
+}
+
+/// Runs `f`, turning the warnings of the `denied` kinds into errors.
+pub(crate) fn deny_warnings<T>(denied: &FxHashSet<WarningKind>, f: impl FnOnce() -> T) -> T {
+    struct Reset(FxHashSet<WarningKind>);
+
+    impl Drop for Reset {
+        fn drop(&mut self) {
+            DENIED_WARNINGS.set(std::mem::take(&mut self.0));
+        }
+    }
+
+    let _reset = Reset(DENIED_WARNINGS.replace(denied.clone()));
+    f()
+}
+
+/// Prints `msg` as a cargo warning, or panics with it if `kind` is denied.
+pub(crate) fn warn(kind: WarningKind, msg: impl Display) {
+    if DENIED_WARNINGS.with_borrow(|denied| denied.contains(&kind)) {
+        panic!("{msg} (denied `{kind:?}` warning)");
     }
+    println!("cargo:warning={msg}");
 }
@@ -4,7 +4,30 @@ use std::{
     process::{exit, Command},
 };
 
+/// How the generated files are formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FmtOptions {
+    /// Format with rustfmt. The builtin printer of [`pretty_print`] is used
+    /// without it, or if rustfmt isn't installed.
+    pub rustfmt: bool,
+    /// The indent width of the builtin printer
+    pub indent_width: usize,
+}
+
+impl Default for FmtOptions {
+    fn default() -> Self {
+        Self {
+            rustfmt: true,
+            indent_width: 4,
+        }
+    }
+}
+
 pub fn fmt_file<P: AsRef<Path>>(file: P) {
+    fmt_file_with(file, FmtOptions::default())
+}
+
+pub fn fmt_file_with<P: AsRef<Path>>(file: P, options: FmtOptions) {
     let file = file.as_ref();
     if let Some(a) = file.extension() {
         if a != "rs" {
@@ -12,6 +35,10 @@ pub fn fmt_file<P: AsRef<Path>>(file: P) {
         }
     };
 
+    if !options.rustfmt {
+        return pretty_print_file(file, options.indent_width);
+    }
+
     let result = Command::new(std::env::var("RUSTFMT").unwrap_or_else(|_| "rustfmt".to_owned()))
         .arg("--emit")
         .arg("files")
@@ -21,6 +48,9 @@ pub fn fmt_file<P: AsRef<Path>>(file: P) {
         .output();
 
     match result {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            pretty_print_file(file, options.indent_width)
+        }
         Err(e) => eprintln!("{}", e),
         Ok(output) => {
             if !output.status.success() {
@@ -30,3 +60,373 @@ pub fn fmt_file<P: AsRef<Path>>(file: P) {
         }
     }
 }
+
+fn pretty_print_file(file: &Path, indent_width: usize) {
+    let src = std::fs::read_to_string(file).unwrap();
+    std::fs::write(file, pretty_print(&src, indent_width)).unwrap();
+}
+
+/// The width the builtin printer wraps the lines longer than.
+const MAX_WIDTH: usize = 100;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// `(` or `[`
+    Open,
+    /// `)` or `]`
+    Close,
+    /// The `<` of generics
+    GenericOpen,
+    GenericClose,
+    LineComment,
+    /// Literals, block comments, idents and other punctuations
+    Other,
+}
+
+#[derive(Clone, Copy)]
+struct Token<'a> {
+    text: &'a str,
+    /// Whether it was preceded by whitespace
+    space: bool,
+    kind: Kind,
+}
+
+fn lex(src: &str) -> Vec<Token<'_>> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut space = false;
+    let mut i = 0;
+
+    // the end of the string literal starting with the quote at `i`
+    let str_end = |mut i: usize| {
+        i += 1;
+        while i < bytes.len() && bytes[i] != b'"' {
+            i += if bytes[i] == b'\\' { 2 } else { 1 };
+        }
+        (i + 1).min(bytes.len())
+    };
+    // the end of the raw string literal starting with the `r` at `i`
+    let raw_str_end = |i: usize| {
+        let hashes = bytes[i + 1..].iter().take_while(|b| **b == b'#').count();
+        let closing = format!("\"{}", "#".repeat(hashes));
+        let start = i + 2 + hashes;
+        src[start..]
+            .find(&closing)
+            .map_or(bytes.len(), |end| start + end + closing.len())
+    };
+    let is_raw_str = |i: usize| {
+        bytes.get(i) == Some(&b'r')
+            && bytes[i + 1..]
+                .iter()
+                .find(|b| **b != b'#')
+                .is_some_and(|b| *b == b'"')
+    };
+
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b.is_ascii_whitespace() {
+            space = true;
+            i += 1;
+            continue;
+        }
+
+        let (end, kind) = if src[i..].starts_with("//") {
+            (
+                src[i..].find('\n').map_or(bytes.len(), |end| i + end),
+                Kind::LineComment,
+            )
+        } else if src[i..].starts_with("/*") {
+            (
+                src[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + end + 4),
+                Kind::Other,
+            )
+        } else if b == b'"' {
+            (str_end(i), Kind::Other)
+        } else if is_raw_str(i) {
+            (raw_str_end(i), Kind::Other)
+        } else if b == b'b' && bytes.get(i + 1) == Some(&b'"') {
+            (str_end(i + 1), Kind::Other)
+        } else if b == b'b' && is_raw_str(i + 1) {
+            (raw_str_end(i + 1), Kind::Other)
+        } else if b == b'\'' || (b == b'b' && bytes.get(i + 1) == Some(&b'\'')) {
+            let start = if b == b'b' { i + 1 } else { i };
+            match bytes.get(start + 1) {
+                // an escaped char
+                Some(b'\\') => {
+                    let end = src[start + 3..]
+                        .find('\'')
+                        .map_or(bytes.len(), |end| start + 3 + end + 1);
+                    (end, Kind::Other)
+                }
+                // a char
+                Some(_) if bytes.get(start + 2) == Some(&b'\'') => (start + 3, Kind::Other),
+                // a lifetime
+                _ => {
+                    let len = src[start + 1..]
+                        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                        .unwrap_or(src.len() - start - 1);
+                    (start + 1 + len, Kind::Other)
+                }
+            }
+        } else if b.is_ascii_alphanumeric() || b == b'_' || !b.is_ascii() {
+            let number = b.is_ascii_digit();
+            let mut end = i;
+            while end < bytes.len() {
+                let c = bytes[end];
+                let fraction =
+                    number && c == b'.' && bytes.get(end + 1).is_some_and(|c| c.is_ascii_digit());
+                if c.is_ascii_alphanumeric() || c == b'_' || !c.is_ascii() || fraction {
+                    end += 1;
+                } else {
+                    break;
+                }
+            }
+            (end, Kind::Other)
+        } else {
+            let prev = tokens.last().map(|t: &Token| t.text);
+            let kind = match b {
+                b'(' | b'[' => Kind::Open,
+                b')' | b']' => Kind::Close,
+                // `Vec<T>` but not `a < b`
+                b'<' if !space
+                    && prev.is_some_and(|p| {
+                        p.ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == ':')
+                    }) =>
+                {
+                    Kind::GenericOpen
+                }
+                // `Vec<T>` but not `->` nor `=>`
+                b'>' if space || !matches!(prev, Some("-" | "=")) => Kind::GenericClose,
+                _ => Kind::Other,
+            };
+            (i + 1, kind)
+        };
+
+        tokens.push(Token {
+            text: &src[i..end],
+            space,
+            kind,
+        });
+        space = false;
+        i = end;
+    }
+    tokens
+}
+
+struct Line<'a> {
+    depth: usize,
+    tokens: Vec<Token<'a>>,
+}
+
+impl Line<'_> {
+    fn render(&self, indent_width: usize) -> String {
+        let mut s = " ".repeat(self.depth * indent_width);
+        for (i, t) in self.tokens.iter().enumerate() {
+            if i > 0 && t.space {
+                s.push(' ');
+            }
+            s.push_str(t.text);
+        }
+        s
+    }
+}
+
+/// Splits the tokens into lines: after `{`, `;`, `,` of the blocks, the
+/// closing `}` and the attributes, indented by the nesting of the braces.
+fn split_lines(tokens: Vec<Token<'_>>) -> Vec<Line<'_>> {
+    let mut lines = Vec::new();
+    let mut cur = Vec::new();
+    let mut depth = 0usize;
+    // the open `{`, `(`, `[` and `<`
+    let mut stack = Vec::new();
+    // the nesting of the attribute being written, if any
+    let mut attr = None;
+
+    macro_rules! flush {
+        () => {
+            if !cur.is_empty() {
+                lines.push(Line {
+                    depth,
+                    tokens: std::mem::take(&mut cur),
+                });
+            }
+        };
+    }
+
+    let mut iter = tokens.into_iter().peekable();
+    while let Some(t) = iter.next() {
+        let next = iter.peek().map(|t| t.text);
+        match (t.text, t.kind) {
+            (_, Kind::LineComment) => {
+                cur.push(t);
+                flush!();
+            }
+            ("{", _) => {
+                while stack.last() == Some(&'<') {
+                    stack.pop();
+                }
+                cur.push(t);
+                if next == Some("}") {
+                    cur.push(iter.next().unwrap());
+                    let next = iter.peek().map(|t| t.text);
+                    if !matches!(next, Some("else" | ";" | "," | ")" | "]" | "." | "?")) {
+                        flush!();
+                    }
+                } else {
+                    stack.push('{');
+                    flush!();
+                    depth += 1;
+                }
+            }
+            ("}", _) => {
+                while stack.last() == Some(&'<') {
+                    stack.pop();
+                }
+                stack.pop();
+                flush!();
+                depth = depth.saturating_sub(1);
+                cur.push(t);
+                if !matches!(next, Some("else" | ";" | "," | ")" | "]" | "." | "?")) {
+                    flush!();
+                }
+            }
+            (";" | ",", _) => {
+                cur.push(t);
+                let block = matches!(stack.last(), None | Some('{'));
+                if block && (t.text == ";" || stack.last() == Some(&'{')) {
+                    flush!();
+                }
+            }
+            ("#", _) if matches!(next, Some("[" | "!")) => {
+                cur.push(t);
+                attr = Some(stack.len());
+            }
+            (_, Kind::Open) => {
+                cur.push(t);
+                stack.push('(');
+            }
+            (_, Kind::Close) => {
+                while stack.last() == Some(&'<') {
+                    stack.pop();
+                }
+                stack.pop();
+                cur.push(t);
+                if t.text == "]" && attr == Some(stack.len()) {
+                    attr = None;
+                    flush!();
+                }
+            }
+            (_, Kind::GenericOpen) => {
+                cur.push(t);
+                stack.push('<');
+            }
+            (_, Kind::GenericClose) => {
+                if stack.last() == Some(&'<') {
+                    stack.pop();
+                }
+                cur.push(t);
+            }
+            _ => cur.push(t),
+        }
+    }
+    flush!();
+    lines
+}
+
+/// Wraps `line` if it's longer than [`MAX_WIDTH`], putting the elements
+/// between the first `(` or `[` of it on their own lines.
+fn wrap<'a>(line: Line<'a>, indent_width: usize, out: &mut Vec<Line<'a>>) {
+    if line.render(indent_width).len() <= MAX_WIDTH {
+        out.push(line);
+        return;
+    }
+
+    let tokens = &line.tokens;
+    let mut nesting = 0usize;
+    let mut open = None;
+    let mut commas = Vec::new();
+    let mut close = None;
+    for (i, t) in tokens.iter().enumerate() {
+        match t.kind {
+            Kind::Open | Kind::GenericOpen => {
+                if open.is_none() && t.kind == Kind::Open {
+                    open = Some(i);
+                    nesting = 0;
+                }
+                nesting += 1;
+            }
+            Kind::Close | Kind::GenericClose if open.is_some() => {
+                nesting = nesting.saturating_sub(1);
+                if nesting == 0 {
+                    if t.kind == Kind::Close {
+                        close = Some(i);
+                        break;
+                    }
+                    // a stray `>` of a comparison
+                    nesting = 1;
+                }
+            }
+            _ if open.is_some() && nesting == 1 && t.text == "," => commas.push(i),
+            _ => {}
+        }
+    }
+    let (Some(open), Some(close)) = (open, close) else {
+        out.push(line);
+        return;
+    };
+    if close == open + 1 {
+        out.push(line);
+        return;
+    }
+
+    let depth = line.depth;
+    wrap(
+        Line {
+            depth,
+            tokens: tokens[..=open].to_vec(),
+        },
+        indent_width,
+        out,
+    );
+    let mut start = open + 1;
+    for end in commas.into_iter().map(|c| c + 1).chain([close]) {
+        if start < end {
+            wrap(
+                Line {
+                    depth: depth + 1,
+                    tokens: tokens[start..end].to_vec(),
+                },
+                indent_width,
+                out,
+            );
+        }
+        start = end;
+    }
+    wrap(
+        Line {
+            depth,
+            tokens: tokens[close..].to_vec(),
+        },
+        indent_width,
+        out,
+    );
+}
+
+/// Formats the rust code `src` without rustfmt: a line per statement, item,
+/// field and attribute, indented by `indent_width` spaces per block, and the
+/// lines longer than 100 chars wrapped at the commas of their first
+/// parentheses or brackets. The spaces between the tokens are kept.
+pub fn pretty_print(src: &str, indent_width: usize) -> String {
+    let mut lines = Vec::new();
+    for line in split_lines(lex(src)) {
+        wrap(line, indent_width, &mut lines);
+    }
+    let mut out = String::with_capacity(src.len());
+    for line in lines {
+        out.push_str(&line.render(indent_width));
+        out.push('\n');
+    }
+    out
+}
@@ -13,11 +13,14 @@ pub mod fmt;
 mod index;
 mod ir;
 pub mod middle;
+mod options;
 pub mod parser;
 mod resolve;
 mod symbol;
 
+pub use errors::WarningKind;
 use faststr::FastStr;
+use itertools::Itertools;
 pub use symbol::Symbol;
 pub mod tags;
 use std::{path::PathBuf, sync::Arc};
@@ -36,15 +39,27 @@ use middle::{
     workspace_graph::WorkspaceGraph,
 };
 pub use middle::{
-    context::{Context, SourceType},
+    context::{
+        Collision, Context, DefaultStrategy, EnumMode, InlineMode, MethodKind, MethodSig,
+        PackageFacade, SerdeUnionRepr, ServiceResult, SourceType, TraceBackend, UnknownEnum,
+        WorkspaceLayout,
+    },
     rir, ty,
 };
+pub use options::BuildOptions;
 use parser::{protobuf::ProtobufParser, thrift::ThriftParser, ParseResult, Parser};
-use plugin::{AutoDerivePlugin, BoxedPlugin, ImplDefaultPlugin, PredicateResult, WithAttrsPlugin};
+pub use parser::{MethodItemNaming, OptionalDefault, ParseMode};
+use plugin::{
+    derive_hash_predicate, AutoDerivePlugin, BoxedPlugin, CfgDerivePlugin, ClosedEnumPlugin,
+    ConstEnumFnsPlugin, DocLinksPlugin, EnumTryFromI64Plugin, EnumVariantsPlugin,
+    FieldIdBaselinePlugin, ImplDefaultPlugin, ManualHashPlugin, PredicateResult, WithAttrsPlugin,
+};
 pub use plugin::{BoxClonePlugin, ClonePlugin, Plugin};
 use resolve::{ResolveResult, Resolver};
+use rustc_hash::FxHashSet;
 use salsa::Durability;
 pub use symbol::{DefId, IdentName};
+use tags::SerdeBytes;
 pub use tags::TagId;
 
 pub trait MakeBackend: Sized {
@@ -73,38 +88,48 @@ impl MakeBackend for MkProtobufBackend {
 }
 
 pub struct Builder<MkB, P> {
-    source_type: SourceType,
+    options: BuildOptions,
     mk_backend: MkB,
     parser: P,
     plugins: Vec<Box<dyn Plugin>>,
-    ignore_unused: bool,
-    split: bool,
-    touches: Vec<(std::path::PathBuf, Vec<String>)>,
-    change_case: bool,
-    keep_unknown_fields: Vec<std::path::PathBuf>,
-    dedups: Vec<FastStr>,
-    special_namings: Vec<FastStr>,
-    common_crate_name: FastStr,
+    deny_name_collisions: bool,
+    service_stream: Option<FastStr>,
+    cfg_derives: Vec<(FastStr, FastStr)>,
+    field_id_baseline: Option<PathBuf>,
+    deny_warnings: FxHashSet<WarningKind>,
+    manual_hash: bool,
+    enum_try_from_i64: bool,
+    closed_enums: bool,
+    enum_variants: bool,
+    const_enum_fns: bool,
+    doc_links: bool,
+    services: Vec<IdlService>,
+    codegen_threads: usize,
 }
 
 impl Builder<MkThriftBackend, ThriftParser> {
     pub fn thrift() -> Self {
         Builder {
-            source_type: SourceType::Thrift,
+            options: BuildOptions::default(),
             mk_backend: MkThriftBackend,
             parser: ThriftParser::default(),
             plugins: vec![
                 Box::new(WithAttrsPlugin(Arc::from(["#[derive(Debug)]".into()]))),
                 Box::new(ImplDefaultPlugin),
             ],
-            touches: Vec::default(),
-            ignore_unused: true,
-            change_case: true,
-            keep_unknown_fields: Vec::default(),
-            dedups: Vec::default(),
-            special_namings: Vec::default(),
-            common_crate_name: "common".into(),
-            split: false,
+            deny_name_collisions: false,
+            service_stream: None,
+            cfg_derives: Vec::default(),
+            field_id_baseline: None,
+            deny_warnings: FxHashSet::default(),
+            manual_hash: false,
+            enum_try_from_i64: false,
+            closed_enums: false,
+            enum_variants: false,
+            const_enum_fns: false,
+            doc_links: false,
+            services: Vec::default(),
+            codegen_threads: 0,
         }
     }
 }
@@ -112,25 +137,107 @@ impl Builder<MkThriftBackend, ThriftParser> {
 impl Builder<MkProtobufBackend, ProtobufParser> {
     pub fn protobuf() -> Self {
         Builder {
-            source_type: SourceType::Protobuf,
+            options: BuildOptions {
+                source_type: SourceType::Protobuf,
+                ..Default::default()
+            },
             mk_backend: MkProtobufBackend,
             parser: ProtobufParser::default(),
             plugins: vec![
                 Box::new(WithAttrsPlugin(Arc::from(["#[derive(Debug)]".into()]))),
                 Box::new(ImplDefaultPlugin),
             ],
-            touches: Vec::default(),
-            ignore_unused: true,
-            change_case: true,
-            keep_unknown_fields: Vec::default(),
-            dedups: Vec::default(),
-            special_namings: Vec::default(),
-            common_crate_name: "common".into(),
-            split: false,
+            deny_name_collisions: false,
+            service_stream: None,
+            cfg_derives: Vec::default(),
+            field_id_baseline: None,
+            deny_warnings: FxHashSet::default(),
+            manual_hash: false,
+            enum_try_from_i64: false,
+            closed_enums: false,
+            enum_variants: false,
+            const_enum_fns: false,
+            doc_links: false,
+            services: Vec::default(),
+            codegen_threads: 0,
         }
     }
 }
 
+impl<MkB> Builder<MkB, ThriftParser> {
+    /**
+     * How the args, result and exception items generated for each method are
+     * named. `pilota.method_item_name` on a method overrides it.
+     */
+    pub fn method_item_naming(mut self, naming: MethodItemNaming) -> Self {
+        self.parser.method_item_naming(naming);
+        self
+    }
+
+    /**
+     * Generate the strings, binaries and lists of message fields as
+     * `Cow<'cow, str>`, `Cow<'cow, [u8]>` and `Cow<'cow, [T]>`, so messages
+     * built from borrowed data are cloned without copying it until they are
+     * mutated.
+     *
+     * The items with such fields, and the ones containing them, take the
+     * `'cow` lifetime parameter, e.g. `Doc<'cow>`. Decoded messages own their
+     * data, so they can have any lifetime, and consts borrow `'static` data.
+     */
+    pub fn cow_fields(mut self, cow_fields: bool) -> Self {
+        self.parser.cow_fields(cow_fields);
+        self
+    }
+
+    /**
+     * How the fields which aren't `required` but have a default value are
+     * generated, as thrift implementations disagree on them. Required fields
+     * with a default are always `T` initialized to it.
+     */
+    pub fn optional_default(mut self, optional_default: OptionalDefault) -> Self {
+        self.parser.optional_default(optional_default);
+        self
+    }
+
+    /**
+     * How the separators of the idls are parsed. The default lenient mode
+     * skips the repeated and trailing ones with a warning, while the strict
+     * mode fails on them.
+     */
+    pub fn parse_mode(mut self, mode: ParseMode) -> Self {
+        self.parser.parse_mode(mode);
+        self
+    }
+
+    /**
+     * Read the idl files, the inputs and those they include, with `loader`
+     * instead of from the disk, e.g. for idls embedded in the binary or
+     * fetched from a registry. The include is resolved to the first path
+     * the loader succeeds on. The paths are normalized lexically when they
+     * don't exist on the disk.
+     */
+    pub fn file_loader(
+        mut self,
+        loader: impl Fn(&std::path::Path) -> std::io::Result<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.parser.file_loader(Arc::new(loader));
+        self
+    }
+}
+
+impl<MkB> Builder<MkB, ProtobufParser> {
+    /**
+     * Use the names of the proto3 JSON mapping with serde: `SerdePlugin`
+     * renames the fields to their `json_name` option, or to the
+     * lowerCamelCase of their name. Fields of a message sharing a JSON name
+     * are rejected when parsing.
+     */
+    pub fn proto_json(mut self, flag: bool) -> Self {
+        self.parser.proto_json(flag);
+        self
+    }
+}
+
 impl<MkB, P> Builder<MkB, P>
 where
     P: Parser,
@@ -144,18 +251,23 @@ where
 impl<MkB, P> Builder<MkB, P> {
     pub fn with_backend<B: MakeBackend>(self, mk_backend: B) -> Builder<B, P> {
         Builder {
-            source_type: self.source_type,
+            options: self.options,
             mk_backend,
             parser: self.parser,
             plugins: self.plugins,
-            ignore_unused: self.ignore_unused,
-            touches: self.touches,
-            change_case: self.change_case,
-            keep_unknown_fields: self.keep_unknown_fields,
-            dedups: self.dedups,
-            special_namings: self.special_namings,
-            common_crate_name: self.common_crate_name,
-            split: self.split,
+            deny_name_collisions: self.deny_name_collisions,
+            service_stream: self.service_stream,
+            cfg_derives: self.cfg_derives,
+            field_id_baseline: self.field_id_baseline,
+            deny_warnings: self.deny_warnings,
+            manual_hash: self.manual_hash,
+            enum_try_from_i64: self.enum_try_from_i64,
+            closed_enums: self.closed_enums,
+            enum_variants: self.enum_variants,
+            const_enum_fns: self.const_enum_fns,
+            doc_links: self.doc_links,
+            services: self.services,
+            codegen_threads: self.codegen_threads,
         }
     }
 
@@ -166,12 +278,36 @@ impl<MkB, P> Builder<MkB, P> {
     }
 
     pub fn split_generated_files(mut self, split: bool) -> Self {
-        self.split = split;
+        self.options.split = split;
         self
     }
 
     pub fn change_case(mut self, change_case: bool) -> Self {
-        self.change_case = change_case;
+        self.options.change_case = change_case;
+        self
+    }
+
+    /**
+     * Overrides `change_case` for the items of `package`, e.g. `a.b`, such
+     * as keeping the original casing of the idl for one package only. The
+     * names which are rust keywords are still escaped. The packages no item
+     * is in are warned about.
+     */
+    pub fn package_change_case(mut self, package: impl Into<FastStr>, change_case: bool) -> Self {
+        self.options
+            .package_change_case
+            .insert(package.into(), change_case);
+        self
+    }
+
+    /**
+     * Re-exports the items of the packages with `pub use` in the root module,
+     * or in the module of every package containing them. The names defined
+     * in several of the re-exported packages, or already in the module, are
+     * left out.
+     */
+    pub fn package_facade(mut self, facade: PackageFacade) -> Self {
+        self.options.package_facade = facade;
         self
     }
 
@@ -179,7 +315,7 @@ impl<MkB, P> Builder<MkB, P> {
      * Don't generate items which are unused by the main service
      */
     pub fn ignore_unused(mut self, flag: bool) -> Self {
-        self.ignore_unused = flag;
+        self.options.ignore_unused = flag;
         self
     }
 
@@ -192,7 +328,7 @@ impl<MkB, P> Builder<MkB, P> {
         mut self,
         item: impl IntoIterator<Item = (PathBuf, Vec<impl Into<String>>)>,
     ) -> Self {
-        self.touches.extend(
+        self.options.touches.extend(
             item.into_iter()
                 .map(|s| (s.0, s.1.into_iter().map(|s| s.into()).collect())),
         );
@@ -200,22 +336,595 @@ impl<MkB, P> Builder<MkB, P> {
     }
 
     pub fn keep_unknown_fields(mut self, item: impl IntoIterator<Item = PathBuf>) -> Self {
-        self.keep_unknown_fields.extend(item);
+        self.options.keep_unknown_fields.extend(item);
         self
     }
 
     pub fn dedup(mut self, item: impl IntoIterator<Item = FastStr>) -> Self {
-        self.dedups.extend(item);
+        self.options.dedups.extend(item);
         self
     }
 
     pub fn special_namings(mut self, item: impl IntoIterator<Item = FastStr>) -> Self {
-        self.special_namings.extend(item);
+        self.options.special_namings.extend(item);
         self
     }
 
     pub fn common_crate_name(mut self, name: FastStr) -> Self {
-        self.common_crate_name = name;
+        self.options.common_crate_name = name;
+        self
+    }
+
+    /**
+     * Lints allowed at the root of every generated file, emitted as
+     * `#![allow(...)]`. This replaces the default set.
+     */
+    pub fn lint_allows(mut self, lints: impl IntoIterator<Item = FastStr>) -> Self {
+        self.options.lint_allows = lints.into_iter().collect();
+        self
+    }
+
+    /**
+     * Emit `#[inline]` or `#[inline(always)]` on the generated encode, decode
+     * and size methods and accessors.
+     */
+    pub fn inline_methods(mut self, mode: InlineMode) -> Self {
+        self.options.inline_mode = mode;
+        self
+    }
+
+    /**
+     * Items with more fields (or variants) than this are never inlined.
+     */
+    pub fn inline_max_fields(mut self, max: usize) -> Self {
+        self.options.inline_max_fields = max;
+        self
+    }
+
+    /**
+     * Where the generated crates are placed when the output is a workspace.
+     */
+    pub fn workspace_layout(mut self, layout: WorkspaceLayout) -> Self {
+        self.options.workspace_layout = layout;
+        self
+    }
+
+    /**
+     * The manifest a generated crate starts from when its `Cargo.toml` is
+     * missing, with `{name}` replaced by the crate name. It must not define
+     * the `[dependencies]`, which are generated. Defaults to a bare
+     * `[package]` section.
+     */
+    pub fn manifest_template(mut self, template: impl Into<FastStr>) -> Self {
+        self.options.manifest_template = Some(template.into());
+        self
+    }
+
+    /**
+     * Fail instead of keeping the idl spelling when distinct names collide
+     * after case change.
+     */
+    pub fn deny_name_collisions(mut self, deny: bool) -> Self {
+        self.deny_name_collisions = deny;
+        self
+    }
+
+    /**
+     * Protobuf only: use the type of the field of single-field messages, like
+     * `google.protobuf.StringValue`, directly for the fields referencing
+     * them. The wire format is unchanged, the wrapper message is still
+     * encoded around the value.
+     */
+    pub fn flatten_wrappers(mut self, flatten: bool) -> Self {
+        self.options.flatten_wrappers = flatten;
+        self
+    }
+
+    /**
+     * Thrift only: add a `__Unknown` variant to the generated union enums.
+     * Unions without any known field set, either empty or holding only
+     * unknown fields, decode to it instead of failing. The unknown fields
+     * are still kept in `_UnknownFields` if `keep_unknown_fields` is set.
+     */
+    pub fn union_unknown_variant(mut self, flag: bool) -> Self {
+        self.options.union_unknown_variant = flag;
+        self
+    }
+
+    /**
+     * Generate a `message_registry()` function returning a
+     * `pilota::registry::MessageRegistry` of the messages defined in the
+     * idls, to build and decode them by fully qualified name. Each crate of
+     * a workspace has its own, the registries can be merged.
+     */
+    pub fn message_registry(mut self, flag: bool) -> Self {
+        self.options.message_registry = flag;
+        self
+    }
+
+    /**
+     * Thrift only: check invariants of the data when decoding, returning an
+     * error instead of building a value that couldn't have been encoded:
+     * i32 enums must hold a known value, collections must have a
+     * non-negative length and known fields must have their declared type,
+     * instead of being skipped. Required fields are always checked.
+     *
+     * Meant for debugging malformed data, as it slows down decoding.
+     */
+    pub fn strict_decode(mut self, flag: bool) -> Self {
+        self.options.strict_decode = flag;
+        self
+    }
+
+    /**
+     * Thrift only: generate an `encode_owned(self, protocol)` method on the
+     * structs and unions besides `Message::encode`. It moves the `FastStr`
+     * and `Bytes` fields, and the unknown fields, into the protocol instead
+     * of cloning them, which saves the clones when a message is encoded once.
+     */
+    pub fn owned_encode(mut self, flag: bool) -> Self {
+        self.options.owned_encode = flag;
+        self
+    }
+
+    /**
+     * Mark the generated getters, like the accessors of `pilota.range`
+     * fields and `inner` of i32 enums, with `#[must_use]`. The constructors
+     * and setters of `pilota.range` fields return a `Result`, which is
+     * already must-use, so they are left as is.
+     */
+    pub fn must_use(mut self, flag: bool) -> Self {
+        self.options.must_use = flag;
+        self
+    }
+
+    /**
+     * Declare the methods of the generated service traits, returning
+     * `{wrapper}<T, {error}>`, e.g. `::my_rpc::Result<T, ::my_rpc::Error>`.
+     * The error type must implement `From` the exceptions of the methods
+     * throwing some. Oneway methods return `()`.
+     */
+    pub fn service_result(
+        mut self,
+        wrapper: impl Into<FastStr>,
+        error: impl Into<FastStr>,
+    ) -> Self {
+        self.options.service_result = Some(ServiceResult {
+            wrapper: wrapper.into(),
+            error: error.into(),
+            stream: None,
+        });
+        self
+    }
+
+    /**
+     * Declare the streaming methods of the generated service traits along
+     * with `service_result`, taking and returning `{stream}<T>`s for their
+     * streaming requests and responses, e.g. `::my_rpc::Streaming<T>`.
+     */
+    pub fn service_stream(mut self, stream: impl Into<FastStr>) -> Self {
+        self.service_stream = Some(stream.into());
+        self
+    }
+
+    /**
+     * Format the generated files with rustfmt, `true` by default. Without it,
+     * or if rustfmt isn't installed, they're formatted by a builtin printer,
+     * indented with `indent_width`.
+     */
+    pub fn rustfmt(mut self, flag: bool) -> Self {
+        self.options.fmt.rustfmt = flag;
+        self
+    }
+
+    /**
+     * The indent width of the builtin printer formatting the generated files
+     * without rustfmt, 4 by default.
+     */
+    pub fn indent_width(mut self, width: usize) -> Self {
+        self.options.fmt.indent_width = width;
+        self
+    }
+
+    /**
+     * Generate code for `no_std` crates with `alloc`: the items are referred
+     * to from `::core` and `::alloc` instead of `::std`, maps and sets are
+     * `BTreeMap`s and `BTreeSet`s, and the lazily initialized constants use
+     * `lazy_static` which needs its `spin_no_std` feature. The codecs still
+     * come from `pilota`.
+     */
+    pub fn no_std(mut self, flag: bool) -> Self {
+        self.options.no_std = flag;
+        self
+    }
+
+    /**
+     * Pack the presence of the optional scalar fields of thrift messages
+     * into bits instead of wrapping them in `Option`. The packed fields are
+     * private, with `field()`, `has_field()`, `set_field()` and
+     * `clear_field()` accessors. Fields with a default value and fields
+     * past the 64th packed one stay `Option`s.
+     */
+    pub fn packed_presence(mut self, flag: bool) -> Self {
+        self.options.packed_presence = flag;
+        self
+    }
+
+    /**
+     * Generate `field_iter()` accessors on the messages for their list, set
+     * and map fields, iterating over the references to their elements, or
+     * to the `(key, value)` pairs of the maps, through the boxes and
+     * typedefs wrapping them. Absent optional fields are empty.
+     */
+    pub fn iter_accessors(mut self, flag: bool) -> Self {
+        self.options.iter_accessors = flag;
+        self
+    }
+
+    /**
+     * Generate `pub const IDL_FINGERPRINT: &str` in each generated file, the
+     * hash of the sources of the idls it's generated from, so the services
+     * can report which version of the idls they were built from. It only
+     * changes with the content of the idls, not with their paths. In
+     * workspace mode each crate hashes the idls of its own items.
+     */
+    pub fn idl_fingerprint(mut self, flag: bool) -> Self {
+        self.options.idl_fingerprint = flag;
+        self
+    }
+
+    /**
+     * Emit the items of each module ordered by their name instead of the
+     * order they are collected in, so the output doesn't change between
+     * runs and platforms.
+     */
+    pub fn sort_items(mut self, flag: bool) -> Self {
+        self.options.sort_items = flag;
+        self
+    }
+
+    /**
+     * Write the items of the modules with more than `max` items to
+     * `part_N.rs` files of at most `max` items each, next to the output
+     * and included by the `mod.rs` of their module, to keep the files
+     * small enough for IDEs. Ignored with `split_generated_files`, which
+     * writes a file per item.
+     */
+    pub fn max_items_per_file(mut self, max: usize) -> Self {
+        assert!(max > 0, "`max_items_per_file` must be positive");
+        self.options.max_items_per_file = Some(max);
+        self
+    }
+
+    /**
+     * Import each path, e.g. `std::sync::Arc`, at the top of every
+     * generated module with items, for the custom types and plugins which
+     * refer to them unqualified. The paths the module already re-exports
+     * and the repeated ones are imported once.
+     */
+    pub fn prelude_imports(mut self, paths: impl IntoIterator<Item = FastStr>) -> Self {
+        self.options.prelude_imports = paths.into_iter().collect();
+        self
+    }
+
+    /**
+     * Thrift only: emit trace points in the generated decoders, logging the
+     * unknown fields the structs and unions skip and the errors the structs
+     * return with the `trace!` macro of `backend`. The generated crate must
+     * depend on `tracing` or `log`. Without it, the decoders contain no
+     * logging at all.
+     */
+    pub fn decode_trace(mut self, backend: TraceBackend) -> Self {
+        self.options.decode_trace = Some(backend);
+        self
+    }
+
+    /**
+     * Don't generate the item with the fully qualified idl name `fqn`, e.g.
+     * `ledger.Money`, and refer to it by the rust `path` instead, for the
+     * types implemented by hand. The type at `path` must implement the same
+     * traits as the generated one would, which is checked for `Message`.
+     */
+    pub fn external_type(mut self, fqn: impl Into<FastStr>, path: impl Into<FastStr>) -> Self {
+        self.options.external_types.insert(fqn.into(), path.into());
+        self
+    }
+
+    /**
+     * The serde representation of the thrift unions and protobuf oneofs
+     * with `SerdePlugin`, externally tagged by default. Internal tagging
+     * only works for variants holding a struct, and `untagged` picks the
+     * first variant the value deserializes as, so the variants need
+     * distinguishable types.
+     */
+    pub fn serde_union_repr(mut self, repr: SerdeUnionRepr) -> Self {
+        self.options.serde_union_repr = repr;
+        self
+    }
+
+    /**
+     * Serialize the bytes fields with the adapter `bytes` of
+     * `pilota::serde` with `SerdePlugin`, instead of as arrays of numbers.
+     * A field annotated with `pilota.serde_bytes = "base64" | "hex" | "raw"`
+     * uses its own.
+     */
+    pub fn serde_bytes(mut self, bytes: SerdeBytes) -> Self {
+        self.options.serde_bytes = Some(bytes);
+        self
+    }
+
+    /**
+     * Derive each `(derive, cfg)` pair on the generated messages, enums and
+     * newtypes only when `cfg` holds, e.g. `("proptest_derive::Arbitrary",
+     * "test")` emits `#[cfg_attr(test, derive(proptest_derive::Arbitrary))]`.
+     * Derives an item already has unconditionally are skipped.
+     */
+    pub fn cfg_derives(
+        mut self,
+        items: impl IntoIterator<Item = (impl Into<FastStr>, impl Into<FastStr>)>,
+    ) -> Self {
+        self.cfg_derives
+            .extend(items.into_iter().map(|(d, cfg)| (d.into(), cfg.into())));
+        self
+    }
+
+    /**
+     * Check the ids of the fields of the generated messages against the
+     * baseline at `path`, failing the compilation of the generated code if
+     * one changed or was removed, and the build if a message was removed.
+     * Adding fields is fine. If there's no file at `path`, the current ids
+     * are recorded there: delete it to accept the changed ids.
+     */
+    pub fn field_id_baseline(mut self, path: impl Into<PathBuf>) -> Self {
+        self.field_id_baseline = Some(path.into());
+        self
+    }
+
+    /**
+     * Fail the build on the warnings of the given kinds instead of printing
+     * them, e.g. `[WarningKind::MissingItem]` to catch stale `touches` in CI.
+     */
+    pub fn deny_warnings(mut self, kinds: impl IntoIterator<Item = WarningKind>) -> Self {
+        self.deny_warnings.extend(kinds);
+        self
+    }
+
+    /**
+     * Implement `Hash` for the types with `AHashMap` or `AHashSet` fields,
+     * which can't derive it, by hashing the entries of their maps and sets
+     * in a canonical order with `pilota::hash::hash_unordered`. Types with
+     * floats still don't implement `Hash`.
+     */
+    pub fn manual_hash(mut self, flag: bool) -> Self {
+        self.manual_hash = flag;
+        self
+    }
+
+    /**
+     * Implement `Clone` by hand for the recursive types instead of deriving
+     * it, cloning them field by field, as deriving it on deeply recursive
+     * types can hit the limits of the compiler.
+     */
+    pub fn manual_clone(mut self, flag: bool) -> Self {
+        self.options.manual_clone = flag;
+        self
+    }
+
+    /**
+     * Only generate the data types, without the services and the args and
+     * result types of their methods. The types the methods use are still
+     * generated.
+     */
+    pub fn skip_services(mut self, flag: bool) -> Self {
+        self.options.skip_services = flag;
+        self
+    }
+
+    /**
+     * Implement `FromStr` and `Display` for the typedefs of strings. Parsing
+     * checks the length of the string against the `pilota.range` of the
+     * typedef, if any, like `typedef string Email (pilota.range = "3..=254")`,
+     * and can't fail otherwise.
+     */
+    pub fn newtype_from_str(mut self, flag: bool) -> Self {
+        self.options.newtype_from_str = flag;
+        self
+    }
+
+    /**
+     * Generate a `#[cfg(test)]` module next to each message, with a test
+     * encoding its default value and asserting that it decodes back equal.
+     */
+    pub fn roundtrip_tests(mut self, flag: bool) -> Self {
+        self.options.roundtrip_tests = flag;
+        self
+    }
+
+    /**
+     * What decoding does with the unknown values of the i32 enums, which
+     * are kept by default. `strict_decode` still rejects them in thrift.
+     */
+    pub fn unknown_enum(mut self, policy: UnknownEnum) -> Self {
+        self.options.unknown_enum = policy;
+        self
+    }
+
+    /**
+     * Generate accessors named after the string and bytes fields of the
+     * messages, returning `&str` and `&[u8]`, or an `Option` of them for the
+     * optional fields, whatever the types storing them, through the boxes
+     * and typedefs wrapping them.
+     */
+    pub fn str_accessors(mut self, flag: bool) -> Self {
+        self.options.str_accessors = flag;
+        self
+    }
+
+    /**
+     * Implement `AsRef` of the wrapped type for the newtypes, which already
+     * deref to it, and of `str` or `[u8]` for the typedefs of strings and
+     * bytes, to pass them to the functions taking an `impl AsRef<..>`.
+     */
+    pub fn newtype_as_ref(mut self, flag: bool) -> Self {
+        self.options.newtype_as_ref = flag;
+        self
+    }
+
+    /**
+     * Generate an associated const on the messages per field, named after
+     * it like `USER_ID_FIELD_NUMBER`, holding its thrift id as an `i16` or
+     * its protobuf number as a `u32`, for the tools working with the wire
+     * format. Each field of a protobuf oneof gets its own.
+     */
+    pub fn field_number_consts(mut self, flag: bool) -> Self {
+        self.options.field_number_consts = flag;
+        self
+    }
+
+    /**
+     * How the messages implement `Default`. By default it is derived, unless
+     * some fields have a default in the idl.
+     */
+    pub fn default_strategy(mut self, strategy: DefaultStrategy) -> Self {
+        self.options.default_strategy = strategy;
+        self
+    }
+
+    /**
+     * Generate the items in the modules returned by `remap` for the paths
+     * of their modules, e.g. to collapse `a::b::c` into `api`, and refer to
+     * them there. The paths include the modules of the nested protobuf
+     * messages, so `remap` should keep the end of the paths it doesn't
+     * know. Only applies to the single file outputs, as the crates of the
+     * workspaces are laid out from the packages. The build fails if items of
+     * distinct modules end up with the same path.
+     */
+    pub fn remap_mods(
+        mut self,
+        remap: impl Fn(&rir::ItemPath) -> rir::ItemPath + Send + Sync + 'static,
+    ) -> Self {
+        self.options.mod_remap = Some(Arc::new(remap));
+        self
+    }
+
+    /**
+     * Annotate the item, field, variant or method with the fully qualified
+     * idl name `fqn`, e.g. `ledger.Money.amount`, as if `key = "value"`
+     * was written in the idl, to tag the idls that can't be edited. The
+     * annotations are the ones the idls support, like `pilota.name` or
+     * `pilota.rust_wrapper_arc`.
+     */
+    pub fn item_tag(
+        mut self,
+        fqn: impl Into<FastStr>,
+        key: impl Into<FastStr>,
+        value: impl Into<FastStr>,
+    ) -> Self {
+        let (fqn, key, value) = (fqn.into(), key.into(), value.into());
+        if let Err(e) = tags::Tags::default().insert_annotation(&key, &value) {
+            panic!("invalid tag of `{fqn}`: {e}")
+        }
+        self.options.item_tags.push((fqn, key, value));
+        self
+    }
+
+    /**
+     * `item_tag` each annotation of a toml file, whose tables are the fully
+     * qualified names of the tagged items:
+     *
+     * ```toml
+     * ["ledger.Money"]
+     * "pilota.name" = "Amount"
+     * ```
+     */
+    pub fn item_tags_file(self, path: impl AsRef<std::path::Path>) -> Self {
+        let path = path.as_ref();
+        let tables: std::collections::BTreeMap<String, std::collections::BTreeMap<String, String>> =
+            std::fs::read_to_string(path)
+                .map_err(anyhow::Error::from)
+                .and_then(|text| Ok(toml::from_str(&text)?))
+                .unwrap_or_else(|e| panic!("read item tags {} failed: {e}", path.display()));
+        tables
+            .into_iter()
+            .fold(self, |builder, (fqn, annotations)| {
+                annotations
+                    .into_iter()
+                    .fold(builder, |builder, (key, value)| {
+                        builder.item_tag(fqn.clone(), key, value)
+                    })
+            })
+    }
+
+    /**
+     * Implement `TryFrom<i64>` for the enums besides `From<i32>`, failing
+     * with `pilota::EnumConvertError` for the values which aren't the
+     * discriminant of a variant, e.g. `i64`s read from json.
+     */
+    pub fn enum_try_from_i64(mut self, flag: bool) -> Self {
+        self.enum_try_from_i64 = flag;
+        self
+    }
+
+    /**
+     * Generate a closed rust enum `{Name}Enum` next to each enum, with a
+     * variant per discriminant, and convert between them with `as_enum`,
+     * which is `None` for the unknown values, and `From`. The enums without
+     * variants get none.
+     */
+    pub fn closed_enums(mut self, flag: bool) -> Self {
+        self.closed_enums = flag;
+        self
+    }
+
+    /**
+     * List the variants of each enum in declaration order in
+     * `const ALL: &[Self]`, and iterate over them with `iter()`, e.g. to
+     * fill a select box. Aliases are listed too, and the unknown values
+     * aren't. Conflicts with a variant named `ALL`.
+     */
+    pub fn enum_variants(mut self, flag: bool) -> Self {
+        self.enum_variants = flag;
+        self
+    }
+
+    /**
+     * Generate the `const fn`s `as_i32` and `from_i32_checked` on the enums
+     * for const contexts, where `From` and `TryFrom` can't be used.
+     * `from_i32_checked` is `None` for the values which aren't the
+     * discriminant of a variant.
+     */
+    pub fn const_enum_fns(mut self, flag: bool) -> Self {
+        self.const_enum_fns = flag;
+        self
+    }
+
+    /**
+     * Document the fields and union variants referencing generated types
+     * with intra-doc links to them, so rustdoc can navigate between them.
+     */
+    pub fn doc_links(mut self, flag: bool) -> Self {
+        self.doc_links = flag;
+        self
+    }
+
+    /**
+     * Compile `service` along with the services given to `compile`, e.g. a
+     * service synthesized by tooling. Its idl can include the files of the
+     * other services to use their types, which must exist.
+     */
+    pub fn add_service(mut self, service: IdlService) -> Self {
+        self.services.push(service);
+        self
+    }
+
+    /**
+     * The number of threads generating code. Modules, and crates of a
+     * workspace, are generated in parallel, and the output is the same
+     * whatever the number of threads. `1` generates sequentially, `0` (the
+     * default) uses one thread per cpu.
+     */
+    pub fn codegen_threads(mut self, threads: usize) -> Self {
+        self.codegen_threads = threads;
         self
     }
 }
@@ -262,27 +971,20 @@ where
         self.compile_with_config(services, out)
     }
 
-    #[allow(clippy::too_many_arguments)]
     pub fn build_cx(
         services: Vec<IdlService>,
         out: Option<Output>,
         mut parser: P,
-        touches: Vec<(PathBuf, Vec<String>)>,
-        ignore_unused: bool,
-        source_type: SourceType,
-        change_case: bool,
-        keep_unknown_fields: Vec<PathBuf>,
-        dedups: Vec<FastStr>,
-        special_namings: Vec<FastStr>,
-        common_crate_name: FastStr,
-        split: bool,
+        options: BuildOptions,
     ) -> Context {
         let mut db = RootDatabase::default();
         parser.inputs(services.iter().map(|s| &s.path));
+        parser.file_texts(options.idl_fingerprint);
         let ParseResult {
             files,
             input_files,
             file_ids_map,
+            file_texts,
         } = parser.parse();
         db.set_file_ids_map_with_durability(Arc::new(file_ids_map), Durability::HIGH);
 
@@ -291,7 +993,12 @@ where
             nodes,
             tags,
             args,
-        } = Resolver::default().resolve_files(&files);
+        } = {
+            let mut resolver = Resolver::default();
+            resolver.no_std = options.no_std;
+            resolver.item_tags = options.item_tags.clone();
+            resolver.resolve_files(&files)
+        };
 
         db.set_files_with_durability(Arc::new(files), Durability::HIGH);
         let items = nodes.iter().filter_map(|(k, v)| {
@@ -326,6 +1033,8 @@ where
             match out {
                 Some(Output::Workspace(dir)) => Mode::Workspace(WorkspaceInfo {
                     dir,
+                    layout: options.workspace_layout,
+                    manifest_template: options.manifest_template.clone(),
                     location_map: Default::default(),
                 }),
                 Some(Output::File(p)) => Mode::SingleFile { file_path: p },
@@ -336,48 +1045,75 @@ where
             input,
         );
 
-        cx.collect(if ignore_unused {
-            CollectMode::OnlyUsed { touches }
-        } else {
-            CollectMode::All
-        });
+        cx.collect(
+            if options.ignore_unused {
+                CollectMode::OnlyUsed {
+                    touches: options.touches.clone(),
+                }
+            } else {
+                CollectMode::All
+            },
+            options.skip_services,
+        );
 
-        cx.keep(keep_unknown_fields);
+        cx.keep(options.keep_unknown_fields.clone());
 
-        cx.build(
-            Arc::from(services),
-            source_type,
-            change_case,
-            dedups,
-            special_namings,
-            common_crate_name,
-            split,
-        )
+        let idl_fingerprints = options
+            .idl_fingerprint
+            .then(|| Arc::new(util::fingerprints(&file_texts)));
+        let cx = cx.build(Arc::from(services), Arc::new(options), idl_fingerprints);
+
+        for def_id in cx.empty_services() {
+            errors::warn(
+                WarningKind::EmptyService,
+                format_args!("service `{}` has no methods", cx.def_id_info(def_id)),
+            );
+        }
+
+        cx
     }
 
-    pub fn compile_with_config(self, services: Vec<IdlService>, out: Output) {
+    /// The options the context is built with, with the stream type of
+    /// `service_stream` merged into `service_result`.
+    fn options(&self) -> BuildOptions {
+        let mut options = self.options.clone();
+        if let Some(result) = &mut options.service_result {
+            result.stream = self.service_stream.clone();
+        }
+        options
+    }
+
+    fn deny_collisions(cx: &Context) -> anyhow::Result<()> {
+        let collisions = cx.check_name_collisions();
+        if !collisions.is_empty() {
+            anyhow::bail!(
+                "name collisions after case change:\n{}",
+                collisions.iter().join("\n")
+            );
+        }
+        Ok(())
+    }
+
+    pub fn compile_with_config(self, mut services: Vec<IdlService>, out: Output) {
         let _ = tracing_subscriber::fmt::try_init();
 
-        let cx = Self::build_cx(
-            services,
-            Some(out),
-            self.parser,
-            self.touches,
-            self.ignore_unused,
-            self.source_type,
-            self.change_case,
-            self.keep_unknown_fields,
-            self.dedups,
-            self.special_namings,
-            self.common_crate_name,
-            self.split,
-        );
+        let options = self.options();
+        services.extend(self.services);
+
+        let cx = errors::deny_warnings(&self.deny_warnings, || {
+            Self::build_cx(services, Some(out), self.parser, options)
+        });
+
+        if self.deny_name_collisions {
+            if let Err(e) = Self::deny_collisions(&cx) {
+                panic!("{e}")
+            }
+        }
 
         cx.exec_plugin(BoxedPlugin);
 
-        cx.exec_plugin(AutoDerivePlugin::new(
-            Arc::from(["#[derive(PartialOrd)]".into()]),
-            |ty| {
+        cx.exec_plugin(
+            AutoDerivePlugin::new(Arc::from(["#[derive(PartialOrd)]".into()]), |ty| {
                 let mut ty = ty;
                 while let ty::Vec(_ty) = &ty.kind {
                     ty = _ty;
@@ -387,28 +1123,62 @@ where
                 } else {
                     PredicateResult::GoOn
                 }
-            },
-        ));
+            })
+            .skip_eq_ignored(),
+        );
 
-        cx.exec_plugin(AutoDerivePlugin::new(
-            Arc::from(["#[derive(Hash, Eq, Ord)]".into()]),
-            |ty| {
-                let mut ty = ty;
-                while let ty::Vec(_ty) = &ty.kind {
-                    ty = _ty;
-                }
-                if matches!(ty.kind, ty::Map(_, _) | ty::Set(_) | ty::F64 | ty::F32) {
-                    PredicateResult::No
-                } else {
-                    PredicateResult::GoOn
-                }
-            },
-        ));
+        cx.exec_plugin(
+            AutoDerivePlugin::new(
+                Arc::from(["#[derive(Hash, Eq, Ord)]".into()]),
+                derive_hash_predicate,
+            )
+            .skip_eq_ignored(),
+        );
+
+        if self.manual_hash {
+            cx.exec_plugin(ManualHashPlugin::default());
+        }
+
+        if self.enum_try_from_i64 {
+            cx.exec_plugin(EnumTryFromI64Plugin);
+        }
+
+        if self.const_enum_fns {
+            cx.exec_plugin(ConstEnumFnsPlugin);
+        }
+
+        if self.closed_enums {
+            cx.exec_plugin(ClosedEnumPlugin);
+        }
+
+        if self.enum_variants {
+            cx.exec_plugin(EnumVariantsPlugin);
+        }
+
+        if self.doc_links {
+            cx.exec_plugin(DocLinksPlugin);
+        }
 
         self.plugins.into_iter().for_each(|p| cx.exec_plugin(p));
 
+        if !self.cfg_derives.is_empty() {
+            cx.exec_plugin(CfgDerivePlugin(self.cfg_derives));
+        }
+
+        if let Some(path) = &self.field_id_baseline {
+            println!("cargo:rerun-if-changed={}", path.display());
+            match FieldIdBaselinePlugin::read(path) {
+                Some(baseline) => cx.exec_plugin(baseline),
+                None => {
+                    std::fs::write(path, FieldIdBaselinePlugin::snapshot(&cx)).unwrap_or_else(|e| {
+                        panic!("write field id baseline {} failed: {e}", path.display())
+                    })
+                }
+            }
+        }
+
         std::thread::scope(|scope| {
-            let pool = rayon::ThreadPoolBuilder::new();
+            let pool = rayon::ThreadPoolBuilder::new().num_threads(self.codegen_threads);
             let pool = pool
                 .spawn_handler(|thread| {
                     let mut builder = std::thread::Builder::new();
@@ -441,20 +1211,16 @@ where
     pub fn init_service(self, service: IdlService) -> anyhow::Result<(String, String)> {
         let _ = tracing_subscriber::fmt::try_init();
         let path = service.path.clone();
-        let cx = Self::build_cx(
-            vec![service],
-            None,
-            self.parser,
-            self.touches,
-            self.ignore_unused,
-            self.source_type,
-            self.change_case,
-            self.keep_unknown_fields,
-            self.dedups,
-            self.special_namings,
-            self.common_crate_name,
-            self.split,
-        );
+        let options = self.options();
+        let mut services = vec![service];
+        services.extend(self.services);
+        let cx = errors::deny_warnings(&self.deny_warnings, || {
+            Self::build_cx(services, None, self.parser, options)
+        });
+
+        if self.deny_name_collisions {
+            Self::deny_collisions(&cx)?;
+        }
 
         std::thread::scope(|_scope| {
             CONTEXT.set(&cx.clone(), move || {
@@ -4,23 +4,27 @@ use anyhow::Context as _;
 use dashmap::DashMap;
 use faststr::FastStr;
 use itertools::Itertools;
-use normpath::PathExt;
 use rustc_hash::{FxHashMap, FxHashSet};
 use salsa::ParallelDatabase;
 
 use self::tls::with_cur_item;
 use super::{
     adjust::Adjust,
-    resolver::{DefaultPathResolver, PathResolver, WorkspacePathResolver},
+    resolver::{DefaultPathResolver, PathResolver, RemappedPathResolver, WorkspacePathResolver},
     rir::NodeKind,
 };
 use crate::{
     db::{RirDatabase, RootDatabase},
+    errors::{warn, WarningKind},
     rir::{self, Field, Item, ItemPath, Literal},
-    symbol::{DefId, FileId, IdentName, Symbol, SPECIAL_NAMINGS},
-    tags::{TagId, Tags},
-    ty::{AdtDef, AdtKind, CodegenTy, Visitor},
-    Plugin,
+    symbol::{DefId, EnumRepr, FileId, IdentName, Symbol, SPECIAL_NAMINGS},
+    tags::{
+        protobuf::{ClientStreaming, Group, OneOf, ProstType, ServerStreaming},
+        TagId, Tags,
+    },
+    ty::{self, AdtDef, AdtKind, CodegenTy, Visitor},
+    util::normalize_path,
+    BuildOptions, Plugin,
 };
 
 #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
@@ -41,9 +45,194 @@ pub enum CollectMode {
     },
 }
 
+/// Controls the `#[inline]` attribute emitted on generated encode, decode and
+/// size methods and accessors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InlineMode {
+    #[default]
+    Off,
+    Inline,
+    Always,
+}
+
+/// How an enum is generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnumMode {
+    /// A rust enum with a variant per value, like the thrift unions and the
+    /// protobuf oneofs
+    Enum,
+    /// A newtype of its `i32` value with a const per variant, which keeps
+    /// the unknown values
+    NewType,
+}
+
+/// The logging crate the trace points of the generated decoders use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceBackend {
+    Tracing,
+    Log,
+}
+
+/// The re-exports of the items of the packages in the modules of the
+/// packages containing them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PackageFacade {
+    #[default]
+    Off,
+    /// The root module re-exports the items of every package, e.g.
+    /// `a::api::Item` as `Item`.
+    Root,
+    /// The module of every package also re-exports the items of its nested
+    /// packages, e.g. `a::api::Item` as `a::Item`.
+    Flatten,
+}
+
+/// How the messages implement `Default`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DefaultStrategy {
+    /// Derives it, ignoring the defaults of the fields in the idl.
+    Derive,
+    /// Implements it by hand, with the defaults of the fields in the idl.
+    Manual,
+    /// Derives it, unless some fields have a default in the idl, which it
+    /// is then implemented by hand with.
+    #[default]
+    FromIdl,
+}
+
+/// What decoding does with the values of the i32 enums which aren't the
+/// discriminant of a variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnknownEnum {
+    /// Keeps the value, as the enums are newtypes of their `i32`, like
+    /// `Level(7)`.
+    #[default]
+    Preserve,
+    /// Fails to decode.
+    Error,
+    /// Replaces the value with the first variant, the default of protobuf.
+    Default,
+}
+
+/// The serde representation of the unions, see
+/// <https://serde.rs/enum-representations.html>.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum SerdeUnionRepr {
+    /// `{"Variant": value}`
+    #[default]
+    External,
+    /// `{"tag": "Variant", ..value}`
+    Internal { tag: FastStr },
+    /// `{"tag": "Variant", "content": value}`
+    Adjacent { tag: FastStr, content: FastStr },
+    /// `value`
+    Untagged,
+}
+
+impl SerdeUnionRepr {
+    /// The container attribute of the representation, if it isn't the
+    /// default one.
+    pub(crate) fn attr(&self) -> Option<String> {
+        match self {
+            SerdeUnionRepr::External => None,
+            SerdeUnionRepr::Internal { tag } => Some(format!(r#"#[serde(tag = "{tag}")]"#)),
+            SerdeUnionRepr::Adjacent { tag, content } => {
+                Some(format!(r#"#[serde(tag = "{tag}", content = "{content}")]"#))
+            }
+            SerdeUnionRepr::Untagged => Some("#[serde(untagged)]".into()),
+        }
+    }
+}
+
+/// Where the crates of a workspace output are placed, relative to the
+/// workspace root.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WorkspaceLayout {
+    /// `<name>/src/lib.rs`
+    #[default]
+    Root,
+    /// `crates/<name>/src/lib.rs`
+    Crates,
+    /// One module per crate at `src/<name>.rs`, inside the crate at the
+    /// workspace root
+    Flat,
+}
+
+/// Distinct idl names that map to the same rust identifier once their case is
+/// changed. The colliding names keep their idl spelling in the generated code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    /// The full rust path of the colliding names, e.g. `a::B::c` for the
+    /// fields `c` and `C` of `a::B`
+    pub rust_name: FastStr,
+    pub def_ids: Vec<DefId>,
+    /// `def_id_info` of each entry in `def_ids`
+    pub infos: Vec<FastStr>,
+}
+
+impl std::fmt::Display for Collision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "`{}` is generated for {}",
+            self.rust_name,
+            self.infos.iter().map(|info| format!("`{info}`")).join(", ")
+        )
+    }
+}
+
+/// Whether the request and the response of a service method are streams, from
+/// the `stream` keywords of protobuf methods. Thrift methods are unary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MethodKind {
+    #[default]
+    Unary,
+    ClientStreaming,
+    ServerStreaming,
+    Bidi,
+}
+
+impl MethodKind {
+    pub fn client_streaming(self) -> bool {
+        matches!(self, Self::ClientStreaming | Self::Bidi)
+    }
+
+    pub fn server_streaming(self) -> bool {
+        matches!(self, Self::ServerStreaming | Self::Bidi)
+    }
+}
+
+/// The signature of a service method, with the types as they are generated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodSig {
+    pub def_id: DefId,
+    pub name: Symbol,
+    pub args: Vec<(Symbol, CodegenTy)>,
+    pub ret: CodegenTy,
+    pub is_oneway: bool,
+    pub kind: MethodKind,
+    /// The variants of the exception enum of the method
+    pub exceptions: Vec<(Symbol, CodegenTy)>,
+    /// The service the method is inherited from through `extends`, if any
+    pub extended_from: Option<DefId>,
+}
+
+/// The result type of the methods of the generated service traits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceResult {
+    /// The path of a `Result<T, E>` like type, e.g. `::my_rpc::Result`
+    pub wrapper: FastStr,
+    pub error: FastStr,
+    /// The path of a stream of `T`s like type, e.g. `::my_rpc::Streaming`,
+    /// for the streaming requests and responses
+    pub stream: Option<FastStr>,
+}
+
 #[derive(Debug)]
 pub struct WorkspaceInfo {
     pub dir: PathBuf,
+    pub layout: WorkspaceLayout,
+    pub(crate) manifest_template: Option<FastStr>,
     pub(crate) location_map: FxHashMap<DefId, DefLocation>,
 }
 
@@ -58,7 +247,7 @@ pub struct Context {
     pub db: salsa::Snapshot<RootDatabase>,
     pub adjusts: Arc<DashMap<DefId, Adjust>>,
     pub services: Arc<[crate::IdlService]>,
-    pub(crate) change_case: bool,
+    pub(crate) options: Arc<BuildOptions>,
     pub(crate) codegen_items: Arc<[DefId]>,
     pub(crate) path_resolver: Arc<dyn PathResolver>,
     pub mode: Arc<Mode>,
@@ -67,9 +256,12 @@ pub struct Context {
     pub location_map: Arc<FxHashMap<DefId, DefLocation>>,
     pub entry_map: Arc<HashMap<DefLocation, Vec<(DefId, DefLocation)>>>,
     pub plugin_gen: Arc<DashMap<DefLocation, String>>,
-    pub(crate) dedups: Vec<FastStr>,
-    pub(crate) common_crate_name: FastStr,
+    pub(crate) idl_fingerprints: Option<Arc<FxHashMap<FileId, u64>>>,
     pub names: FxHashMap<DefId, usize>,
+    pub(crate) collisions: Arc<[(FastStr, Vec<DefId>)]>,
+    /// The items taking the lifetime of their `Cow` fields, see
+    /// [`Context::item_generics`]
+    pub(crate) cow_items: Arc<FxHashSet<DefId>>,
 }
 
 impl Clone for Context {
@@ -78,7 +270,7 @@ impl Clone for Context {
             source_type: self.source_type,
             db: self.db.snapshot(),
             adjusts: self.adjusts.clone(),
-            change_case: self.change_case,
+            options: self.options.clone(),
             codegen_items: self.codegen_items.clone(),
             path_resolver: self.path_resolver.clone(),
             mode: self.mode.clone(),
@@ -88,9 +280,10 @@ impl Clone for Context {
             location_map: self.location_map.clone(),
             entry_map: self.entry_map.clone(),
             plugin_gen: self.plugin_gen.clone(),
-            dedups: self.dedups.clone(),
-            common_crate_name: self.common_crate_name.clone(),
+            idl_fingerprints: self.idl_fingerprints.clone(),
             names: self.names.clone(),
+            collisions: self.collisions.clone(),
+            cow_items: self.cow_items.clone(),
         }
     }
 }
@@ -117,7 +310,7 @@ impl ContextBuilder {
             entry_map: Default::default(),
         }
     }
-    pub(crate) fn collect(&mut self, mode: CollectMode) {
+    pub(crate) fn collect(&mut self, mode: CollectMode, skip_services: bool) {
         match mode {
             CollectMode::All => {
                 let nodes = self.db.nodes();
@@ -137,7 +330,7 @@ impl ContextBuilder {
                 let extra_def_ids = touches
                     .into_iter()
                     .flat_map(|s| {
-                        let path = s.0.normalize().unwrap().into_path_buf();
+                        let path = normalize_path(&s.0).unwrap();
                         let file_id = *self.db.file_ids_map().get(&path).unwrap();
                         s.1.into_iter()
                             .filter_map(|item_name| {
@@ -155,10 +348,13 @@ impl ContextBuilder {
                                 if let Some(def_id) = def_id {
                                     Some(def_id)
                                 } else {
-                                    println!(
-                                        "cargo:warning=item `{}` of `{}` not exists",
-                                        item_name,
-                                        path.display(),
+                                    warn(
+                                        WarningKind::MissingItem,
+                                        format_args!(
+                                            "item `{}` of `{}` not exists",
+                                            item_name,
+                                            path.display(),
+                                        ),
                                     );
                                     None
                                 }
@@ -173,6 +369,20 @@ impl ContextBuilder {
                 self.codegen_items.extend(def_ids.iter());
             }
         }
+        if skip_services {
+            // the types used by the services are collected through them first
+            let services = self
+                .codegen_items
+                .iter()
+                .filter(|def_id| matches!(&*self.db.item(**def_id).unwrap(), Item::Service(_)))
+                .flat_map(|def_id| {
+                    let node = self.db.node(*def_id).unwrap();
+                    std::iter::once(*def_id).chain(node.related_nodes)
+                })
+                .collect::<FxHashSet<_>>();
+            self.codegen_items
+                .retain(|def_id| !services.contains(def_id));
+        }
         if matches!(self.mode, Mode::Workspace(_)) {
             let location_map = self.workspace_collect_def_ids(&self.codegen_items);
             self.location_map = location_map.clone();
@@ -278,7 +488,7 @@ impl ContextBuilder {
     pub(crate) fn keep(&mut self, keep_unknown_fields: Vec<PathBuf>) {
         let mut file_ids = FxHashSet::default();
         keep_unknown_fields.into_iter().for_each(|p| {
-            let path = p.normalize().unwrap().into_path_buf();
+            let path = normalize_path(&p).unwrap();
             let file_ids_map = self.db.file_ids_map();
             let file_id = file_ids_map.get(&path).unwrap();
             keep_files(self, file_id, &mut file_ids);
@@ -319,35 +529,42 @@ impl ContextBuilder {
     pub(crate) fn build(
         self,
         services: Arc<[crate::IdlService]>,
-        source_type: SourceType,
-        change_case: bool,
-        dedups: Vec<FastStr>,
-        special_namings: Vec<FastStr>,
-        common_crate_name: FastStr,
-        split: bool,
+        options: Arc<BuildOptions>,
+        idl_fingerprints: Option<Arc<FxHashMap<FileId, u64>>>,
     ) -> Context {
-        SPECIAL_NAMINGS.get_or_init(|| special_namings);
+        SPECIAL_NAMINGS.get_or_init(|| options.special_namings.clone());
         let mut cx = Context {
             adjusts: Default::default(),
-            source_type,
+            source_type: options.source_type,
             db: self.db.snapshot(),
-            change_case,
             services,
             codegen_items: Arc::from(self.codegen_items),
-            path_resolver: match &self.mode {
-                Mode::Workspace(_) => Arc::new(WorkspacePathResolver),
-                Mode::SingleFile { .. } => Arc::new(DefaultPathResolver),
+            path_resolver: match (&self.mode, options.mod_remap.clone()) {
+                (Mode::Workspace(_), Some(_)) => {
+                    panic!("`remap_mods` only applies to the single file outputs")
+                }
+                (Mode::Workspace(info), None) => Arc::new(WorkspacePathResolver {
+                    layout: info.layout,
+                }),
+                (Mode::SingleFile { .. }, None) => Arc::new(DefaultPathResolver),
+                (Mode::SingleFile { .. }, Some(remap)) => Arc::new(RemappedPathResolver {
+                    inner: Arc::new(DefaultPathResolver),
+                    remap,
+                }),
             },
             mode: Arc::new(self.mode),
-            split,
+            split: options.split,
             keep_unknown_fields: Arc::new(self.keep_unknown_fields),
             location_map: Arc::new(self.location_map),
             entry_map: Arc::new(self.entry_map),
             plugin_gen: Default::default(),
-            dedups,
-            common_crate_name,
+            idl_fingerprints,
+            options,
             names: Default::default(),
+            collisions: Default::default(),
+            cow_items: Default::default(),
         };
+        cx.cow_items = Arc::new(cx.collect_cow_items());
         let mut map: FxHashMap<(Vec<DefId>, String), Vec<DefId>> = FxHashMap::default();
         cx.nodes()
             .iter()
@@ -374,17 +591,169 @@ impl ContextBuilder {
                         .push(*def_id);
                 }
             });
+        let collisions = map
+            .into_iter()
+            .filter(|(_, v)| v.len() > 1)
+            .map(|((item_def_ids, name), mut v)| {
+                v.sort();
+                (cx.collision_path(&item_def_ids, &name), v)
+            })
+            .sorted()
+            .collect::<Vec<_>>();
+        if cx.options.mod_remap.is_some() {
+            cx.check_remap_clashes(&collisions);
+        }
+        cx.check_external_types();
+        cx.check_package_change_case();
         cx.names.extend(
-            map.into_iter()
-                .filter(|(_, v)| v.len() > 1)
-                .map(|(_, v)| v)
-                .flat_map(|v| v.into_iter().enumerate().map(|(i, def_id)| (def_id, i)))
+            collisions
+                .iter()
+                .flat_map(|(_, v)| v.iter().enumerate().map(|(i, def_id)| (*def_id, i)))
                 .collect::<HashMap<DefId, usize>>(),
         );
+        cx.collisions = Arc::from(collisions);
         cx
     }
 }
 
+impl Context {
+    /// The full rust path of a colliding `name`, which is nested in
+    /// `item_def_ids`, the parents from the innermost up to the item, or is
+    /// already the path of an item if there are none.
+    fn collision_path(&self, item_def_ids: &[DefId], name: &str) -> FastStr {
+        let Some((item_def_id, nested)) = item_def_ids.split_last() else {
+            return FastStr::new(name);
+        };
+        std::iter::once(self.item_path_str(*item_def_id))
+            .chain(nested.iter().rev().map(|d| self.rust_name(*d).0))
+            .chain(std::iter::once(FastStr::new(name)))
+            .join("::")
+            .into()
+    }
+
+    /// The items with `Cow` fields and the items containing them.
+    fn collect_cow_items(&self) -> FxHashSet<DefId> {
+        let nodes = self.nodes();
+        let items = nodes.iter().filter_map(|(def_id, node)| {
+            let NodeKind::Item(item) = &node.kind else {
+                return None;
+            };
+            let has_cow = match &**item {
+                Item::Message(m) => m.fields.iter().any(|f| self.borrows(&f.ty)),
+                Item::Enum(e) => e
+                    .variants
+                    .iter()
+                    .flat_map(|v| &v.fields)
+                    .any(|ty| self.borrows(ty)),
+                Item::NewType(t) => self.borrows(&t.ty),
+                _ => false,
+            };
+            has_cow.then_some(*def_id)
+        });
+        self.workspace_graph().containing(items)
+    }
+
+    /// Whether `ty` borrows for [`ty::COW_LIFETIME`], as a `Cow` or an item
+    /// of [`Context::item_generics`], directly or through the collections.
+    fn borrows(&self, ty: &ty::Ty) -> bool {
+        struct CowFinder<'a>(&'a Context, bool);
+
+        impl Visitor for CowFinder<'_> {
+            fn visit_path(&mut self, path: &crate::rir::Path) {
+                self.1 |= self.0.cow_items.contains(&path.did);
+            }
+
+            fn visit(&mut self, ty: &ty::Ty) {
+                self.1 |= matches!(ty.kind, ty::Cow(_));
+                ty::walk_ty(self, ty)
+            }
+        }
+
+        let mut finder = CowFinder(self, false);
+        finder.visit(ty);
+        finder.1
+    }
+
+    /// Warns about the fqns of `external_type` which no item has.
+    fn check_external_types(&self) {
+        if self.options.external_types.is_empty() {
+            return;
+        }
+        let fqns = self
+            .nodes()
+            .keys()
+            .map(|def_id| self.fqn(*def_id))
+            .collect::<FxHashSet<_>>();
+        self.options
+            .external_types
+            .keys()
+            .filter(|fqn| !fqns.contains(*fqn))
+            .sorted()
+            .for_each(|fqn| {
+                crate::errors::warn(
+                    crate::errors::WarningKind::MissingItem,
+                    format_args!("external type `{fqn}` doesn't exist"),
+                )
+            });
+    }
+
+    /// Warns about the packages of `package_change_case` which no item is in.
+    fn check_package_change_case(&self) {
+        if self.options.package_change_case.is_empty() {
+            return;
+        }
+        let packages = self
+            .nodes()
+            .keys()
+            .map(|def_id| self.package_of(*def_id).iter().join("."))
+            .collect::<FxHashSet<_>>();
+        self.options
+            .package_change_case
+            .keys()
+            .filter(|package| !packages.contains(package.as_str()))
+            .sorted()
+            .for_each(|package| {
+                crate::errors::warn(
+                    crate::errors::WarningKind::MissingItem,
+                    format_args!("package `{package}` of `package_change_case` doesn't exist"),
+                )
+            });
+    }
+
+    /// Panics if `remap_mods` moved generated items of distinct modules to
+    /// the same path, which the idl spelling can't tell apart.
+    fn check_remap_clashes(&self, collisions: &[(FastStr, Vec<DefId>)]) {
+        let clashes = collisions
+            .iter()
+            .filter_map(|(path, def_ids)| {
+                let def_ids = def_ids
+                    .iter()
+                    .filter(|def_id| self.codegen_items.contains(def_id))
+                    .collect::<Vec<_>>();
+                let moved = !def_ids
+                    .iter()
+                    .map(|def_id| DefaultPathResolver.mod_prefix(self, **def_id))
+                    .all_equal();
+                moved.then(|| {
+                    format!(
+                        "`{path}` is generated for {}",
+                        def_ids
+                            .iter()
+                            .map(|def_id| format!("`{}`", self.def_id_info(**def_id)))
+                            .join(", ")
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        if !clashes.is_empty() {
+            panic!(
+                "`remap_mods` moved items to the same path:\n{}",
+                clashes.join("\n")
+            )
+        }
+    }
+}
+
 impl Deref for Context {
     type Target = salsa::Snapshot<RootDatabase>;
 
@@ -439,11 +808,134 @@ impl Context {
             .is_some()
     }
 
+    /// The value of the tag `T` of the node, e.g. a
+    /// [`PilotaName`](crate::tags::PilotaName) set by annotation.
+    pub fn node_tag<T: Clone + 'static>(&self, def_id: DefId) -> Option<T> {
+        self.node_tags(def_id)
+            .and_then(|tags| tags.get::<T>().cloned())
+    }
+
+    /// The items generated along with `def_id` without being referenced by
+    /// its types, which are collected with it: the `ArgsSend`, `ArgsRecv`,
+    /// `ResultSend`, `ResultRecv` and `Exception` items of the methods of a
+    /// thrift service. Other items have none, protobuf map entries are
+    /// lowered to maps instead of messages.
+    pub fn related_nodes(&self, def_id: DefId) -> Vec<DefId> {
+        self.node(def_id)
+            .map(|node| node.related_nodes)
+            .unwrap_or_default()
+    }
+
+    /// The values of the `key` annotations of the thrift method `def_id`, in
+    /// their order, e.g. the `http.path` of a method to route it.
+    pub fn method_annotations(&self, def_id: DefId, key: &str) -> Vec<FastStr> {
+        self.node_tag::<crate::tags::thrift::Annotations>(def_id)
+            .map(|annotations| {
+                annotations
+                    .0
+                    .iter()
+                    .filter(|(k, _)| k == key)
+                    .map(|(_, v)| v.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn symbol_name(&self, def_id: DefId) -> Symbol {
         let item = self.item(def_id).unwrap();
         item.symbol_name()
     }
 
+    /// Returns the consts reachable from `def_id` through default values,
+    /// type references and the values of other consts.
+    pub fn consts_referenced_by(&self, def_id: DefId) -> Vec<DefId> {
+        struct PathCollector<'a> {
+            visited: &'a mut FxHashSet<DefId>,
+            consts: &'a mut Vec<DefId>,
+            cx: &'a Context,
+        }
+
+        impl super::ty::Visitor for PathCollector<'_> {
+            fn visit_path(&mut self, path: &crate::rir::Path) {
+                self.collect(path.did)
+            }
+        }
+
+        impl PathCollector<'_> {
+            fn collect_lit(&mut self, lit: &Literal) {
+                match lit {
+                    Literal::Path(p) => self.collect(p.did),
+                    Literal::List(l) => l.iter().for_each(|lit| self.collect_lit(lit)),
+                    Literal::Map(m) => m.iter().for_each(|(k, v)| {
+                        self.collect_lit(k);
+                        self.collect_lit(v);
+                    }),
+                    _ => {}
+                }
+            }
+
+            fn collect(&mut self, def_id: DefId) {
+                let node = self.cx.node(def_id).unwrap();
+                let NodeKind::Item(item) = &node.kind else {
+                    return self.collect(node.parent.unwrap());
+                };
+
+                if !self.visited.insert(def_id) {
+                    return;
+                }
+
+                node.related_nodes
+                    .iter()
+                    .for_each(|def_id| self.collect(*def_id));
+
+                match &**item {
+                    rir::Item::Message(m) => m.fields.iter().for_each(|f| {
+                        self.visit(&f.ty);
+                        if let Some(lit) = &f.default {
+                            self.collect_lit(lit);
+                        }
+                    }),
+                    rir::Item::Enum(e) => e
+                        .variants
+                        .iter()
+                        .flat_map(|v| &v.fields)
+                        .for_each(|ty| self.visit(ty)),
+                    rir::Item::Service(s) => {
+                        s.extend.iter().for_each(|p| self.collect(p.did));
+                        s.methods
+                            .iter()
+                            .flat_map(|m| {
+                                m.args.iter().map(|f| &f.ty).chain(std::iter::once(&m.ret))
+                            })
+                            .for_each(|ty| self.visit(ty));
+                    }
+                    rir::Item::NewType(n) => self.visit(&n.ty),
+                    rir::Item::Const(c) => {
+                        self.visit(&c.ty);
+                        self.collect_lit(&c.lit);
+                    }
+                    rir::Item::Mod(m) => m.items.iter().for_each(|i| self.collect(*i)),
+                }
+
+                if let rir::Item::Const(_) = &**item {
+                    self.consts.push(def_id);
+                }
+            }
+        }
+
+        let mut visited = FxHashSet::default();
+        let mut consts = Vec::new();
+        PathCollector {
+            visited: &mut visited,
+            consts: &mut consts,
+            cx: self,
+        }
+        .collect(def_id);
+
+        consts.retain(|c| *c != def_id);
+        consts
+    }
+
     pub fn default_val(&self, f: &Field) -> Option<(FastStr, bool /* const? */)> {
         f.default.as_ref().map(|d| {
             let ty = self.codegen_item_ty(f.ty.kind.clone());
@@ -459,6 +951,15 @@ impl Context {
         })
     }
 
+    /// `::alloc` for `no_std` code, else `::std`.
+    pub(crate) fn alloc_crate(&self) -> &'static str {
+        if self.options.no_std {
+            "::alloc"
+        } else {
+            "::std"
+        }
+    }
+
     fn lit_as_rvalue(
         &self,
         lit: &Literal,
@@ -481,7 +982,7 @@ impl Context {
                 .try_collect::<_, Vec<_>, _>()?
                 .join("");
             let new = if btree {
-                "::std::collections::BTreeMap::new()".to_string()
+                format!("{}::collections::BTreeMap::new()", self.alloc_crate())
             } else {
                 format!("::pilota::AHashMap::with_capacity({len})")
             };
@@ -509,24 +1010,124 @@ impl Context {
                 assert!(l.is_empty());
                 match &**map {
                     CodegenTy::Map(_, _) => ("::pilota::AHashMap::new()".into(), false),
-                    CodegenTy::BTreeMap(_, _) => {
-                        ("::std::collections::BTreeMap::new()".into(), false)
-                    }
+                    CodegenTy::BTreeMap(_, _) => (
+                        format!("{}::collections::BTreeMap::new()", self.alloc_crate()).into(),
+                        false,
+                    ),
                     _ => panic!("invalid map type {:?}", map),
                 }
             }
+            (_, CodegenTy::Cow(inner)) => {
+                let (inner, _) = self.lit_as_rvalue(lit, inner)?;
+                (
+                    format!("{}::borrow::Cow::Owned({inner})", self.alloc_crate()).into(),
+                    false,
+                )
+            }
+            (_, CodegenTy::BoxedSlice(inner)) => {
+                let (inner, _) = self.lit_as_rvalue(lit, inner)?;
+                (
+                    format!(
+                        "{}::vec::Vec::into_boxed_slice({inner})",
+                        self.alloc_crate()
+                    )
+                    .into(),
+                    false,
+                )
+            }
             (Literal::List(l), CodegenTy::Map(_, _)) => {
                 assert!(l.is_empty());
                 ("::pilota::AHashMap::new()".into(), false)
             }
             (Literal::List(l), CodegenTy::BTreeMap(_, _)) => {
                 assert!(l.is_empty());
-                ("::std::collections::BTreeMap::new()".into(), false)
+                (
+                    format!("{}::collections::BTreeMap::new()", self.alloc_crate()).into(),
+                    false,
+                )
             }
             _ => self.lit_into_ty(lit, ty)?,
         })
     }
 
+    /// Whether a value of type `from` converts into `to` as the constant and
+    /// default values do: strings into `FastStr`, `String` and `Bytes`,
+    /// integers into the other numbers, `bool`s and enums, enums into their
+    /// integer, floats into `OrderedFloat`, values into the newtypes of their
+    /// type, and lists, sets and maps element-wise. ADTs are identified by
+    /// their `DefId`.
+    pub fn is_assignable(&self, from: &CodegenTy, to: &CodegenTy) -> bool {
+        use CodegenTy::*;
+
+        let is_int = |ty: &CodegenTy| matches!(ty, U8 | I8 | I16 | I32 | I64 | UInt32 | UInt64);
+        let is_list = |ty: &CodegenTy| match ty {
+            Vec(el) | Array(el, _) | Set(el) | BTreeSet(el) => Some(el.clone()),
+            _ => None,
+        };
+        let is_enum = |ty: &CodegenTy| {
+            matches!(
+                ty,
+                Adt(AdtDef {
+                    kind: AdtKind::Enum,
+                    ..
+                })
+            )
+        };
+
+        match (from, to) {
+            (Adt(from), Adt(to)) if from.did == to.did => true,
+            (
+                from,
+                Adt(AdtDef {
+                    kind: AdtKind::NewType(inner),
+                    ..
+                }),
+            ) => self.is_assignable(from, inner),
+            (from, StaticRef(to) | LazyStaticRef(to)) => self.is_assignable(from, to),
+            (Str, FastStr | String | Bytes) => true,
+            (from, to) if is_int(from) && (is_int(to) || matches!(to, F32 | F64 | Bool)) => true,
+            (from, to) if is_int(from) && is_enum(to) => true,
+            (from, I8 | I16 | I32 | I64) if is_enum(from) => true,
+            (F32 | F64, F32 | F64 | OrderedF64) => true,
+            (Map(fk, fv) | BTreeMap(fk, fv), Map(tk, tv) | BTreeMap(tk, tv)) => {
+                self.is_assignable(fk, tk) && self.is_assignable(fv, tv)
+            }
+            (from, to) => match (is_list(from), is_list(to)) {
+                (Some(from), Some(to)) => self.is_assignable(&from, &to),
+                _ => from == to,
+            },
+        }
+    }
+
+    /// `ty` with the newtypes of the typedefs replaced by the type they
+    /// wrap, recursively, e.g. a list of a typedef of a map is the list of
+    /// the map. The messages and enums are kept, so the recursive types stay
+    /// finite, and the type of a typedef never contains itself.
+    pub fn resolve_aliases(&self, ty: &CodegenTy) -> CodegenTy {
+        use CodegenTy::*;
+
+        let resolve =
+            |ty: &std::sync::Arc<CodegenTy>| std::sync::Arc::new(self.resolve_aliases(ty));
+        match ty {
+            Adt(AdtDef {
+                kind: AdtKind::NewType(inner),
+                ..
+            }) => self.resolve_aliases(inner),
+            LazyStaticRef(ty) => LazyStaticRef(resolve(ty)),
+            StaticRef(ty) => StaticRef(resolve(ty)),
+            Vec(ty) => Vec(resolve(ty)),
+            Array(ty, len) => Array(resolve(ty), *len),
+            Set(ty) => Set(resolve(ty)),
+            BTreeSet(ty) => BTreeSet(resolve(ty)),
+            Map(k, v) => Map(resolve(k), resolve(v)),
+            BTreeMap(k, v) => BTreeMap(resolve(k), resolve(v)),
+            Arc(ty) => Arc(resolve(ty)),
+            Cow(ty) => Cow(resolve(ty)),
+            BoxedSlice(ty) => BoxedSlice(resolve(ty)),
+            ty => ty.clone(),
+        }
+    }
+
     fn ident_into_ty(
         &self,
         did: DefId,
@@ -545,6 +1146,17 @@ impl Context {
                     true,
                 )
             }
+            (CodegenTy::Str, CodegenTy::String) => {
+                let stream = self.cur_related_item_path(did);
+                if self.options.no_std {
+                    (
+                        format!("::alloc::string::String::from({stream})").into(),
+                        false,
+                    )
+                } else {
+                    (format!("{stream}.to_string()").into(), false)
+                }
+            }
             (
                 CodegenTy::Adt(AdtDef {
                     did: _,
@@ -599,6 +1211,10 @@ impl Context {
                 self.ident_into_ty(p.did, &ident_ty, ty)
             }
             (Literal::String(s), CodegenTy::Str) => (format!("\"{s}\"").into(), true),
+            (Literal::String(s), CodegenTy::String) if self.options.no_std => (
+                format! {"::alloc::string::String::from(\"{s}\")"}.into(),
+                false,
+            ),
             (Literal::String(s), CodegenTy::String) => {
                 (format! {"\"{s}\".to_string()"}.into(), false)
             }
@@ -606,10 +1222,17 @@ impl Context {
                 format! { "::pilota::FastStr::from_static_str(\"{s}\")" }.into(),
                 true,
             ),
+            (Literal::String(s), CodegenTy::Cow(_)) => (
+                format! { "{}::borrow::Cow::Borrowed(\"{s}\")", self.alloc_crate() }.into(),
+                true,
+            ),
+            (_, CodegenTy::Cow(_)) => self.lit_as_rvalue(lit, ty)?,
             (Literal::Int(i), CodegenTy::I8) => (format! { "{i}i8" }.into(), true),
             (Literal::Int(i), CodegenTy::I16) => (format! { "{i}i16" }.into(), true),
             (Literal::Int(i), CodegenTy::I32) => (format! { "{i}i32" }.into(), true),
             (Literal::Int(i), CodegenTy::I64) => (format! { "{i}i64" }.into(), true),
+            (Literal::Int(i), CodegenTy::UInt32) => (format! { "{i}u32" }.into(), true),
+            (Literal::Int(i), CodegenTy::UInt64) => (format! { "{}u64", *i as u64 }.into(), true),
             (Literal::Int(i), CodegenTy::F32) => {
                 let f = (*i) as f32;
                 (format!("{f}f32").into(), true)
@@ -639,14 +1262,12 @@ impl Context {
                     true,
                 )
             }
-            (Literal::Float(f), CodegenTy::F64) => {
-                let f = f.parse::<f64>().unwrap();
-                (format! { "{f}f64" }.into(), true)
-            }
-            (Literal::Float(f), CodegenTy::OrderedF64) => {
-                let f = f.parse::<f64>().unwrap();
-                (format! { "::pilota::OrderedFloat({f}f64)" }.into(), true)
-            }
+            (Literal::Float(f), CodegenTy::F32) => (float_lit(f, "f32").into(), true),
+            (Literal::Float(f), CodegenTy::F64) => (float_lit(f, "f64").into(), true),
+            (Literal::Float(f), CodegenTy::OrderedF64) => (
+                format! { "::pilota::OrderedFloat({})", float_lit(f, "f64") }.into(),
+                true,
+            ),
             (
                 l,
                 CodegenTy::Adt(AdtDef {
@@ -685,7 +1306,10 @@ impl Context {
             }
             (Literal::List(els), CodegenTy::Vec(inner)) => {
                 let stream = self.list_stream(els, inner)?;
-                (format! { "::std::vec![{stream}]" }.into(), false)
+                (
+                    format! { "{}::vec![{stream}]", self.alloc_crate() }.into(),
+                    false,
+                )
             }
             (Literal::List(els), CodegenTy::Set(inner)) => {
                 let stream = self.list_stream(els, inner)?;
@@ -697,7 +1321,8 @@ impl Context {
             (Literal::List(els), CodegenTy::BTreeSet(inner)) => {
                 let stream = self.list_stream(els, inner)?;
                 (
-                    format! { "::std::collections::BTreeSet::from([{stream}])" }.into(),
+                    format! { "{}::collections::BTreeSet::from([{stream}])", self.alloc_crate() }
+                        .into(),
                     false,
                 )
             }
@@ -706,6 +1331,19 @@ impl Context {
                 let b = *i != 0;
                 (format! { "{b}" }.into(), true)
             }
+            (Literal::List(els), CodegenTy::Bytes) => {
+                let bytes = els
+                    .iter()
+                    .map(|el| match el {
+                        Literal::Int(b) => u8::try_from(*b).map_err(anyhow::Error::from),
+                        _ => Err(anyhow::anyhow!("invalid byte {el:?}")),
+                    })
+                    .try_collect::<_, Vec<_>, _>()?;
+                (
+                    format! { "::pilota::Bytes::from_static(&{bytes:?})" }.into(),
+                    true,
+                )
+            }
             (Literal::String(s), CodegenTy::Bytes) => {
                 let s = &**s;
                 (
@@ -789,6 +1427,138 @@ impl Context {
             .join(","))
     }
 
+    /// Whether a constant of `ty` is initialized lazily, because its value
+    /// can't be built in a const context.
+    pub fn needs_lazy_static(&self, ty: &CodegenTy) -> bool {
+        match ty {
+            CodegenTy::Array(el, _) => self.needs_lazy_static(el),
+            CodegenTy::Set(_)
+            | CodegenTy::BTreeSet(_)
+            | CodegenTy::Arc(_)
+            | CodegenTy::Cow(_)
+            | CodegenTy::BoxedSlice(_) => true,
+            _ => ty.should_lazy_static(),
+        }
+    }
+
+    /// The generic parameters and where-clause of the type generated for
+    /// `def_id`, as written after its name, e.g. `pub struct Name{generics}`.
+    /// Plugins implementing traits for the type declare the same generics
+    /// with it, e.g. `impl{generics} Trait for Name{generics}`.
+    ///
+    /// The types with `Cow` fields, and the types containing them, take the
+    /// lifetime of the borrowed data as [`ty::COW_LIFETIME`]. The others,
+    /// including all the services and consts, have none.
+    pub fn item_generics(&self, def_id: DefId) -> FastStr {
+        if self.cow_items.contains(&def_id) {
+            format!("<{}>", ty::COW_LIFETIME).into()
+        } else {
+            FastStr::empty()
+        }
+    }
+
+    /// Whether `Clone` is implemented by hand for `def_id` instead of
+    /// derived, which `manual_clone` does for the recursive and generic
+    /// types.
+    pub(crate) fn is_manual_clone(&self, def_id: DefId) -> bool {
+        self.options.manual_clone
+            && (self.workspace_graph().is_cycled(def_id) || !self.item_generics(def_id).is_empty())
+    }
+
+    /// The wire type `f` is encoded with: the protobuf wire type, or the
+    /// `TType` of thrift.
+    ///
+    /// Repeated protobuf scalars are encoded unpacked by the generated code,
+    /// so they have the wire type of their elements. Oneof fields have no
+    /// single wire type and panic.
+    pub fn wire_type_of(&self, f: &Field) -> u8 {
+        match self.source_type {
+            SourceType::Protobuf => {
+                assert!(
+                    !self.contains_tag::<OneOf>(f.tags_id),
+                    "oneof field `{}` has no single wire type",
+                    f.name
+                );
+                self.protobuf_wire_type(&f.ty)
+            }
+            SourceType::Thrift => self.thrift_ttype(&f.ty),
+        }
+    }
+
+    fn protobuf_wire_type(&self, ty: &ty::Ty) -> u8 {
+        const VARINT: u8 = 0;
+        const I64: u8 = 1;
+        const LEN: u8 = 2;
+        const SGROUP: u8 = 3;
+        const I32: u8 = 5;
+
+        let prost_type = self
+            .tags(ty.tags_id)
+            .and_then(|tags| tags.get::<ProstType>().copied());
+        match &ty.kind {
+            ty::Vec(inner) => self.protobuf_wire_type(inner),
+            ty::I32 | ty::UInt32 => match prost_type {
+                Some(ProstType::SFixed32 | ProstType::Fixed32) => I32,
+                _ => VARINT,
+            },
+            ty::I64 | ty::UInt64 => match prost_type {
+                Some(ProstType::SFixed64 | ProstType::Fixed64) => I64,
+                _ => VARINT,
+            },
+            ty::Bool => VARINT,
+            ty::F32 => I32,
+            ty::F64 => I64,
+            ty::String | ty::FastStr | ty::BytesVec | ty::Bytes => LEN,
+            ty::Map(..) | ty::BTreeMap(..) => LEN,
+            ty::Path(_) if self.contains_tag::<Group>(ty.tags_id) => SGROUP,
+            ty::Path(path) => match &*self.item(path.did).unwrap() {
+                Item::Enum(e) if e.repr.is_some() => VARINT,
+                _ => LEN,
+            },
+            ty::Arc(ty) | ty::Cow(ty) | ty::BoxedSlice(ty) => self.protobuf_wire_type(ty),
+            kind => unreachable!("unexpected protobuf type {kind:?}"),
+        }
+    }
+
+    fn thrift_ttype(&self, ty: &ty::Ty) -> u8 {
+        // the values of `pilota::thrift::TType`
+        const VOID: u8 = 1;
+        const BOOL: u8 = 2;
+        const I8: u8 = 3;
+        const DOUBLE: u8 = 4;
+        const I16: u8 = 6;
+        const I32: u8 = 8;
+        const I64: u8 = 10;
+        const BINARY: u8 = 11;
+        const STRUCT: u8 = 12;
+        const MAP: u8 = 13;
+        const SET: u8 = 14;
+        const LIST: u8 = 15;
+        const UUID: u8 = 16;
+
+        match &ty.kind {
+            ty::String | ty::FastStr | ty::BytesVec | ty::Bytes => BINARY,
+            ty::Void => VOID,
+            ty::Bool => BOOL,
+            ty::U8 | ty::I8 => I8,
+            ty::I16 => I16,
+            ty::I32 => I32,
+            ty::I64 => I64,
+            ty::F64 | ty::OrderedF64 => DOUBLE,
+            ty::Uuid => UUID,
+            ty::Vec(_) => LIST,
+            ty::Set(_) | ty::BTreeSet(_) => SET,
+            ty::Map(..) | ty::BTreeMap(..) => MAP,
+            ty::Path(path) => match &*self.item(path.did).unwrap() {
+                Item::Enum(e) if e.repr.is_some() => I32,
+                Item::NewType(t) => self.thrift_ttype(&t.ty),
+                _ => STRUCT,
+            },
+            ty::Arc(ty) | ty::Cow(ty) | ty::BoxedSlice(ty) => self.thrift_ttype(ty),
+            kind => unreachable!("unexpected thrift type {kind:?}"),
+        }
+    }
+
     pub(crate) fn def_lit(
         &self,
         name: &str,
@@ -799,23 +1569,35 @@ impl Context {
         if let (Literal::List(lit), CodegenTy::Array(_, size)) = (lit, &mut *ty) {
             *size = lit.len()
         }
-        Ok(if should_lazy_static {
-            let lit = self.lit_as_rvalue(lit, ty)?.0;
-            format! {r#"
-                pub static {name}: ::std::sync::LazyLock<{ty}> = ::std::sync::LazyLock::new(|| {{
+        let ty = &*ty;
+        // the data of the `Cow` fields of a const is static
+        let ty_name = ty.to_string().replace(ty::COW_LIFETIME, "'static");
+        // `LazyLock` is only in `std`
+        let lazy_static = |lit: FastStr| {
+            if self.options.no_std {
+                format! {r#"
+                ::pilota::lazy_static::lazy_static! {{
+                    pub static ref {name}: {ty_name} = {{
+                        {lit}
+                    }};
+                }}
+            "#}
+            } else {
+                format! {r#"
+                pub static {name}: ::std::sync::LazyLock<{ty_name}> = ::std::sync::LazyLock::new(|| {{
                     {lit}
                 }});
             "#}
+            }
+        };
+        Ok(if should_lazy_static {
+            lazy_static(self.lit_as_rvalue(lit, ty)?.0)
         } else {
             let (lit, is_const) = self.lit_into_ty(lit, ty)?;
             if is_const {
-                format!(r#"pub const {name}: {ty} = {lit};"#)
+                format!(r#"pub const {name}: {ty_name} = {lit};"#)
             } else {
-                format! {r#"
-                pub static {name}: ::std::sync::LazyLock<{ty}> = ::std::sync::LazyLock::new(|| {{
-                    {lit}
-                }});
-            "#}
+                lazy_static(lit)
             }
         })
     }
@@ -830,7 +1612,7 @@ impl Context {
             return name.0.into();
         }
 
-        if !self.change_case || self.names.contains_key(&def_id) {
+        if !self.change_case_of(def_id) || self.names.contains_key(&def_id) {
             return node.name();
         }
 
@@ -845,16 +1627,10 @@ impl Context {
             },
             NodeKind::Variant(v) => {
                 let parent = self.node(def_id).unwrap().parent.unwrap();
-                let item = self.expect_item(parent);
-                match &*item {
-                    rir::Item::Enum(e) => {
-                        if e.repr.is_some() {
-                            (&**v.name).const_ident()
-                        } else {
-                            (&**v.name).variant_ident()
-                        }
-                    }
-                    _ => unreachable!(),
+                match self.enum_mode(parent) {
+                    Some(EnumMode::NewType) => (&**v.name).const_ident(),
+                    Some(EnumMode::Enum) => (&**v.name).variant_ident(),
+                    None => unreachable!(),
                 }
             }
             NodeKind::Field(f) => (&**f.name).field_ident(),
@@ -872,6 +1648,18 @@ impl Context {
         self.path_resolver.path_for_def_id(self, def_id)
     }
 
+    /// The [`mod_path`](Self::mod_path) joined with `::`, like
+    /// `article::image`, which is empty for the items at the root.
+    pub fn mod_path_str(&self, def_id: DefId) -> FastStr {
+        self.mod_path(def_id).iter().join("::").into()
+    }
+
+    /// The [`item_path`](Self::item_path) joined with `::`, like
+    /// `article::image::Cdn`.
+    pub fn item_path_str(&self, def_id: DefId) -> FastStr {
+        self.item_path(def_id).iter().join("::").into()
+    }
+
     fn related_path(&self, p1: &[Symbol], p2: &[Symbol]) -> FastStr {
         self.path_resolver.related_path(p1, p2)
     }
@@ -882,6 +1670,9 @@ impl Context {
     }
 
     pub fn related_item_path(&self, a: DefId, b: DefId) -> FastStr {
+        if let Some(path) = self.external_path(b) {
+            return path;
+        }
         let cur_item_path = self.item_path(a);
         let mut mod_segs = vec![];
 
@@ -909,25 +1700,631 @@ impl Context {
         info
     }
 
-    // pub fn def_id_info(&self, def_id: DefId) -> FastStr {
-    //     let file_path = self
-    //         .file(self.node(def_id).unwrap().file_id)
-    //         .unwrap()
-    //         .package
-    //         .clone();
-    //     file_path
-    //         .iter()
-    //         .chain(&[self.node(def_id).unwrap().name()])
-    //         .join("::")
-    //         .into()
-    // }
+    /// The package qualified idl path of `def_id`, e.g. `pkg::Item::field`.
+    pub fn def_id_info(&self, def_id: DefId) -> FastStr {
+        self.idl_names(def_id).join("::").into()
+    }
+
+    /// The dotted fully qualified idl name of `def_id`, e.g. `pkg.Item`.
+    pub fn fqn(&self, def_id: DefId) -> FastStr {
+        self.idl_names(def_id).join(".").into()
+    }
+
+    /// The package of `def_id` followed by the names of its parents and its
+    /// own name.
+    fn idl_names(&self, def_id: DefId) -> Vec<Symbol> {
+        let node = self.node(def_id).unwrap();
+        let mut names = vec![node.name()];
+        let mut parent = node.parent;
+        while let Some(p) = parent {
+            let node = self.node(p).unwrap();
+            names.push(node.name());
+            parent = node.parent;
+        }
+        self.package_of(def_id)
+            .iter()
+            .cloned()
+            .chain(names.into_iter().rev())
+            .collect()
+    }
+
+    /// The path of the hand-implemented type of the item `def_id`, which is
+    /// referred to instead of being generated.
+    pub fn external_path(&self, def_id: DefId) -> Option<FastStr> {
+        if self.options.external_types.is_empty() {
+            return None;
+        }
+        self.options.external_types.get(&self.fqn(def_id)).cloned()
+    }
+
+    /// Whether the item `def_id`, or the item of the field, variant, method or
+    /// argument `def_id`, is generated in this build. The items pruned as
+    /// unused, skipped, or replaced with an external type aren't.
+    pub fn is_emitted(&self, def_id: DefId) -> bool {
+        let Some(mut node) = self.node(def_id) else {
+            return false;
+        };
+        let mut item = def_id;
+        while !matches!(node.kind, NodeKind::Item(_)) {
+            item = node.parent.unwrap();
+            node = self.node(item).unwrap();
+        }
+        self.codegen_items.contains(&item) && self.external_path(item).is_none()
+    }
+
+    /// The paths of the external types referred to by the fields, variants,
+    /// typedefs and method signatures of the generated items, e.g. to know
+    /// the crates they need.
+    pub fn external_type_refs(&self) -> FxHashSet<FastStr> {
+        struct ExternalCollector<'a> {
+            cx: &'a Context,
+            paths: FxHashSet<FastStr>,
+        }
+
+        impl Visitor for ExternalCollector<'_> {
+            fn visit_path(&mut self, path: &crate::rir::Path) {
+                if let Some(path) = self.cx.external_path(path.did) {
+                    self.paths.insert(path);
+                }
+            }
+        }
+
+        let mut collector = ExternalCollector {
+            cx: self,
+            paths: FxHashSet::default(),
+        };
+        if self.options.external_types.is_empty() {
+            return collector.paths;
+        }
+
+        for def_id in self.codegen_items.iter() {
+            let Some(item) = self.item(*def_id) else {
+                continue;
+            };
+            match &*item {
+                Item::Message(m) => m.fields.iter().for_each(|f| collector.visit(&f.ty)),
+                Item::Enum(e) => e
+                    .variants
+                    .iter()
+                    .flat_map(|v| v.fields.iter())
+                    .for_each(|ty| collector.visit(ty)),
+                Item::NewType(t) => collector.visit(&t.ty),
+                Item::Service(s) => s.methods.iter().for_each(|m| {
+                    m.args.iter().for_each(|a| collector.visit(&a.ty));
+                    collector.visit(&m.ret);
+                    if let Some(exceptions) = &m.exceptions {
+                        collector.visit_path(exceptions);
+                    }
+                }),
+                Item::Const(c) => collector.visit(&c.ty),
+                Item::Mod(_) => {}
+            }
+        }
+        collector.paths
+    }
+
+    /// Whether the name of `def_id` is converted to the rust casing, which is
+    /// `change_case` unless it is overridden for the package of `def_id`.
+    pub fn change_case_of(&self, def_id: DefId) -> bool {
+        if self.options.package_change_case.is_empty() {
+            return self.options.change_case;
+        }
+        let package = self.package_of(def_id).iter().join(".");
+        self.options
+            .package_change_case
+            .get(package.as_str())
+            .copied()
+            .unwrap_or(self.options.change_case)
+    }
+
+    /// The package of the file `def_id` is defined in. Fields, variants and
+    /// other nested nodes share the package of their item.
+    pub fn package_of(&self, def_id: DefId) -> ItemPath {
+        let file_id = self.node(def_id).unwrap().file_id;
+        self.file(file_id).unwrap().package.clone()
+    }
+
+    /// The methods of the service `def_id`. Methods inherited through
+    /// `extends` come first, and only if `include_extends` is set.
+    pub fn method_sigs(&self, def_id: DefId, include_extends: bool) -> Vec<MethodSig> {
+        self.service_methods(def_id)
+            .iter()
+            .filter(|m| include_extends || m.source == rir::MethodSource::Own)
+            .map(|m| {
+                let exceptions = m
+                    .exceptions
+                    .as_ref()
+                    .map(|p| match &*self.item(p.did).unwrap() {
+                        Item::Enum(e) => e
+                            .variants
+                            .iter()
+                            .map(|v| {
+                                (
+                                    self.rust_name(v.did),
+                                    self.codegen_item_ty(v.fields[0].kind.clone()),
+                                )
+                            })
+                            .collect(),
+                        _ => unreachable!(),
+                    })
+                    .unwrap_or_default();
+                MethodSig {
+                    def_id: m.def_id,
+                    name: self.rust_name(m.def_id),
+                    args: m
+                        .args
+                        .iter()
+                        .map(|a| {
+                            (
+                                self.rust_name(a.def_id),
+                                self.codegen_item_ty(a.ty.kind.clone()),
+                            )
+                        })
+                        .collect(),
+                    ret: self.codegen_item_ty(m.ret.kind.clone()),
+                    is_oneway: m.oneway,
+                    kind: self.method_kind(m.def_id),
+                    exceptions,
+                    extended_from: match m.source {
+                        rir::MethodSource::Extend(did) => Some(did),
+                        rir::MethodSource::Own => None,
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// The streaming kind of the service method `def_id`.
+    pub fn method_kind(&self, def_id: DefId) -> MethodKind {
+        match (
+            self.node_contains_tag::<ClientStreaming>(def_id),
+            self.node_contains_tag::<ServerStreaming>(def_id),
+        ) {
+            (false, false) => MethodKind::Unary,
+            (true, false) => MethodKind::ClientStreaming,
+            (false, true) => MethodKind::ServerStreaming,
+            (true, true) => MethodKind::Bidi,
+        }
+    }
+
+    /// `ty` wrapped in the stream type of `Builder::service_stream`, `None`
+    /// if it isn't set.
+    fn service_stream_ty(&self, ty: impl std::fmt::Display) -> Option<String> {
+        let stream = self.options.service_result.as_ref()?.stream.as_ref()?;
+        Some(format!("{stream}<{ty}>"))
+    }
+
+    /// The type the service method `m` returns with `Builder::service_result`,
+    /// or `None` if it isn't set. Oneway methods aren't wrapped, server
+    /// streaming methods return a stream, and are `None` without
+    /// `Builder::service_stream`.
+    pub fn method_result_ty(&self, m: &rir::Method) -> Option<FastStr> {
+        let result = self.options.service_result.as_ref()?;
+        let mut ret = self.codegen_item_ty(m.ret.kind.clone()).to_string();
+        if self.method_kind(m.def_id).server_streaming() {
+            ret = self.service_stream_ty(ret)?;
+        }
+        Some(if m.oneway {
+            ret.to_string().into()
+        } else {
+            format!("{}<{ret}, {}>", result.wrapper, result.error).into()
+        })
+    }
+
+    /// The signature of the service method `m` in the service trait with
+    /// `Builder::service_result`. The exceptions of `m` are required to
+    /// convert into the error type. The streaming requests and responses are
+    /// the stream type of `Builder::service_stream`, and the streaming
+    /// methods are skipped without it.
+    pub fn service_method_sig(&self, m: &rir::Method) -> Option<String> {
+        let ret = self.method_result_ty(m)?;
+        let name = self.rust_name(m.def_id);
+        // the data borrowed by the arguments, the result and the exceptions
+        // lives as long as the call
+        let borrows = m
+            .args
+            .iter()
+            .map(|a| &a.ty)
+            .chain([&m.ret])
+            .any(|ty| self.borrows(ty))
+            || m.exceptions
+                .as_ref()
+                .is_some_and(|p| self.cow_items.contains(&p.did));
+        let generics = if borrows {
+            format!("<{}>", ty::COW_LIFETIME)
+        } else {
+            String::new()
+        };
+        let client_streaming = self.method_kind(m.def_id).client_streaming();
+        let args = m
+            .args
+            .iter()
+            .map(|a| {
+                let ty = self.codegen_item_ty(a.ty.kind.clone());
+                let ty = if client_streaming {
+                    self.service_stream_ty(ty)?
+                } else {
+                    ty.to_string()
+                };
+                Some(format!("{}: {ty}", self.rust_name(a.def_id)))
+            })
+            .collect::<Option<Vec<_>>>()?
+            .join(", ");
+        let bound = match (&m.exceptions, &self.options.service_result) {
+            (Some(p), Some(result)) if !m.oneway => format!(
+                " where {}: ::std::convert::From<{}>",
+                result.error,
+                self.codegen_item_ty(ty::TyKind::Path(p.clone()))
+            ),
+            _ => String::new(),
+        };
+        Some(format!(
+            "fn {name}{generics}(&self, {args}) -> impl ::std::future::Future<Output = {ret}> + Send{bound};"
+        ))
+    }
+
+    /// The discriminant of the enum variant `def_id`. Variants without an
+    /// explicit value follow the previous one, starting from 0.
+    pub fn variant_discr(&self, def_id: DefId) -> i64 {
+        let parent = self.node(def_id).unwrap().parent.unwrap();
+        let Item::Enum(e) = &*self.item(parent).unwrap() else {
+            panic!("{} is not an enum variant", self.def_id_info(def_id))
+        };
+        let mut next_discr = 0;
+        for v in &e.variants {
+            let discr = v.discr.unwrap_or(next_discr);
+            if v.did == def_id {
+                return discr;
+            }
+            next_discr = discr + 1;
+        }
+        unreachable!()
+    }
+
+    /// The integer type representing the enum `def_id`, the inner type of
+    /// the newtype it is generated as. Panics for unions, which have no
+    /// discriminant.
+    pub fn enum_repr(&self, def_id: DefId) -> CodegenTy {
+        match &*self.item(def_id).unwrap() {
+            Item::Enum(e) => match e.repr {
+                Some(EnumRepr::I32) => CodegenTy::I32,
+                None => panic!("{} has no discriminant", self.def_id_info(def_id)),
+            },
+            _ => panic!("{} is not an enum", self.def_id_info(def_id)),
+        }
+    }
+
+    /// The enums with a discriminant among the generated items, with the
+    /// rust name and discriminant of each of their variants, in definition
+    /// order. The enums generated as newtypes are included, unions aren't.
+    /// Aliases, variants sharing a discriminant, are kept.
+    pub fn enums(&self) -> Vec<(DefId, Vec<(Symbol, i64)>)> {
+        self.codegen_items
+            .iter()
+            .filter_map(|def_id| match &*self.item(*def_id)? {
+                Item::Enum(e) if e.repr.is_some() => Some((
+                    *def_id,
+                    e.variants
+                        .iter()
+                        .map(|v| (self.rust_name(v.did), self.variant_discr(v.did)))
+                        .collect(),
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The generated services without methods. The methods inherited
+    /// through `extends` count, so a service only extending another isn't
+    /// empty.
+    pub fn empty_services(&self) -> Vec<DefId> {
+        self.codegen_items
+            .iter()
+            .copied()
+            .filter(|def_id| matches!(&*self.expect_item(*def_id), Item::Service(_)))
+            .filter(|def_id| self.service_methods(*def_id).is_empty())
+            .collect()
+    }
+
+    /// The fields of the generated messages, with the message they belong
+    /// to, e.g. to find all the fields of some type.
+    pub fn all_fields(&self) -> impl Iterator<Item = (DefId, Arc<Field>)> + '_ {
+        self.codegen_items.iter().flat_map(|def_id| {
+            let fields = match &*self.expect_item(*def_id) {
+                Item::Message(m) => m.fields.clone(),
+                _ => Vec::new(),
+            };
+            fields.into_iter().map(|f| (*def_id, f))
+        })
+    }
+
+    /// The variants of the generated enums and unions, with the item they
+    /// belong to. The fields of the variants are their types.
+    pub fn all_variants(&self) -> impl Iterator<Item = (DefId, Arc<rir::EnumVariant>)> + '_ {
+        self.codegen_items.iter().flat_map(|def_id| {
+            let variants = match &*self.expect_item(*def_id) {
+                Item::Enum(e) => e.variants.clone(),
+                _ => Vec::new(),
+            };
+            variants.into_iter().map(|v| (*def_id, v))
+        })
+    }
+
+    /// The arguments of the methods of the generated services, with the
+    /// method they belong to. The methods inherited through `extends` are
+    /// only seen in the service defining them.
+    pub fn all_args(&self) -> impl Iterator<Item = (DefId, Arc<rir::Arg>)> + '_ {
+        self.codegen_items.iter().flat_map(|def_id| {
+            let methods = match &*self.expect_item(*def_id) {
+                Item::Service(s) => s.methods.clone(),
+                _ => Vec::new(),
+            };
+            methods.into_iter().flat_map(|m| {
+                m.args
+                    .iter()
+                    .map(|a| (m.def_id, a.clone()))
+                    .collect::<Vec<_>>()
+            })
+        })
+    }
+
+    /// Names that collide in the same scope after case change, sorted by
+    /// the generated name.
+    pub fn check_name_collisions(&self) -> Vec<Collision> {
+        self.collisions
+            .iter()
+            .map(|(rust_name, def_ids)| Collision {
+                rust_name: rust_name.clone(),
+                def_ids: def_ids.clone(),
+                infos: def_ids.iter().map(|d| self.def_id_info(*d)).collect(),
+            })
+            .collect()
+    }
+
+    /// The `#![allow(...)]` attribute emitted at the root of each generated
+    /// file, or an empty string if no lint is allowed.
+    pub(crate) fn lint_allows_attr(&self) -> String {
+        if self.options.lint_allows.is_empty() {
+            return String::new();
+        }
+        format!("#![allow({})]", self.options.lint_allows.iter().join(", "))
+    }
+
+    /// The inline attribute for the generated methods of `def_id`, or an empty
+    /// string if inlining is off or the item has more fields than allowed.
+    pub(crate) fn inline_attr(&self, def_id: DefId) -> &'static str {
+        let fields = match &*self.item(def_id).unwrap() {
+            Item::Message(m) => m.fields.len(),
+            Item::Enum(e) => e.variants.len(),
+            _ => 1,
+        };
+        if fields > self.options.inline_max_fields {
+            return "";
+        }
+        match self.options.inline_mode {
+            InlineMode::Off => "",
+            InlineMode::Inline => "#[inline]",
+            InlineMode::Always => "#[inline(always)]",
+        }
+    }
+
+    /// The `IDL_FINGERPRINT` const of the code generated from `files` with
+    /// `idl_fingerprint`, hashing their sources regardless of their order.
+    pub(crate) fn idl_fingerprint_const(
+        &self,
+        files: impl IntoIterator<Item = FileId>,
+    ) -> Option<String> {
+        let fingerprints = self.idl_fingerprints.as_ref()?;
+        let hashes = files
+            .into_iter()
+            .filter_map(|file_id| fingerprints.get(&file_id).copied())
+            .sorted()
+            .dedup()
+            .flat_map(u64::to_le_bytes)
+            .collect::<Vec<_>>();
+        Some(format!(
+            r#"pub const IDL_FINGERPRINT: &str = "{:016x}";"#,
+            crate::util::fnv1a(&hashes)
+        ))
+    }
+
+    /// The attribute marking the generated getters with `must_use`.
+    pub(crate) fn must_use_attr(&self) -> &'static str {
+        if self.options.must_use {
+            "#[must_use]"
+        } else {
+            ""
+        }
+    }
+
+    /// A `trace!` of the decode trace backend with the format `args`, or an
+    /// empty string if the decoders aren't traced.
+    pub(crate) fn decode_trace(&self, args: &str) -> String {
+        match self.options.decode_trace {
+            Some(TraceBackend::Tracing) => format!("::tracing::trace!({args});"),
+            Some(TraceBackend::Log) => format!("::log::trace!({args});"),
+            None => String::new(),
+        }
+    }
+
+    /// The field of the single-field message referenced by `f`, if `f` holds
+    /// the value of that field directly because of `flatten_wrappers`.
+    pub(crate) fn flattened_wrapper(&self, f: &Field) -> Option<Arc<Field>> {
+        if !self.options.flatten_wrappers || !matches!(self.source_type, SourceType::Protobuf) {
+            return None;
+        }
+        let ty::TyKind::Path(path) = &f.ty.kind else {
+            return None;
+        };
+        if self.keep_unknown_fields.contains(&path.did)
+            || self.contains_tag::<crate::tags::protobuf::Group>(f.ty.tags_id)
+        {
+            return None;
+        }
+        let Item::Message(m) = &*self.item(path.did)? else {
+            return None;
+        };
+        let [inner] = &*m.fields else {
+            return None;
+        };
+        let scalar = matches!(
+            inner.ty.kind,
+            ty::TyKind::String
+                | ty::TyKind::FastStr
+                | ty::TyKind::Bool
+                | ty::TyKind::I32
+                | ty::TyKind::I64
+                | ty::TyKind::UInt32
+                | ty::TyKind::UInt64
+                | ty::TyKind::F32
+                | ty::TyKind::F64
+                | ty::TyKind::Bytes
+                | ty::TyKind::BytesVec
+        );
+        (scalar && !inner.is_optional()).then(|| inner.clone())
+    }
+
+    /// Whether `f` is left out of the `PartialEq` of its message with
+    /// `pilota.eq_ignore`.
+    pub(crate) fn is_eq_ignored(&self, f: &Field) -> bool {
+        matches!(
+            self.tags(f.tags_id)
+                .and_then(|tags| tags.get::<crate::tags::EqIgnore>().copied()),
+            Some(crate::tags::EqIgnore(true))
+        )
+    }
+
+    /// The bit of the optional field `f` in the `_presence` of its message
+    /// if its presence is packed because of `packed_presence`, `None` if
+    /// it's an `Option`. The first 64 optional scalar fields of a thrift
+    /// message without a default value or a `pilota.range` are packed.
+    pub(crate) fn presence_bit(&self, f: &Field) -> Option<usize> {
+        let packable = |f: &Field| {
+            f.is_optional()
+                && matches!(
+                    f.ty.kind,
+                    ty::TyKind::Bool
+                        | ty::TyKind::U8
+                        | ty::TyKind::I8
+                        | ty::TyKind::I16
+                        | ty::TyKind::I32
+                        | ty::TyKind::I64
+                        | ty::TyKind::UInt32
+                        | ty::TyKind::UInt64
+                        | ty::TyKind::F32
+                        | ty::TyKind::F64
+                )
+                && self.default_val(f).is_none()
+                && !self.contains_tag::<crate::tags::ValueRange>(f.tags_id)
+        };
+        if !self.options.packed_presence
+            || !matches!(self.source_type, SourceType::Thrift)
+            || !packable(f)
+        {
+            return None;
+        }
+        let parent = self.node(f.did)?.parent?;
+        let Item::Message(m) = &*self.item(parent)? else {
+            return None;
+        };
+        m.fields
+            .iter()
+            .filter(|f| packable(f))
+            .position(|packed| packed.did == f.did)
+            .filter(|bit| *bit < 64)
+    }
+
+    /// The integer type of the `_presence` of `m`, `None` if none of its
+    /// fields is packed.
+    pub(crate) fn presence_ty(&self, m: &rir::Message) -> Option<&'static str> {
+        let packed = m
+            .fields
+            .iter()
+            .filter(|f| self.presence_bit(f).is_some())
+            .count();
+        Some(match packed {
+            0 => return None,
+            1..=8 => "u8",
+            9..=16 => "u16",
+            17..=32 => "u32",
+            _ => "u64",
+        })
+    }
+
+    /// The value of the packed field `f` of `recv` as an `Option`. Only the
+    /// fields are read, so `recv` may be partially moved.
+    pub(crate) fn packed_value(&self, f: &Field, recv: &str) -> Option<String> {
+        let bit = self.presence_bit(f)?;
+        let name = self.rust_name(f.did);
+        Some(format!(
+            "({recv}._presence & (1 << {bit}) != 0).then_some({recv}.{name})"
+        ))
+    }
+
+    /// The `_presence` of a message of `m` built from the `Option`s `value`
+    /// of its fields, `None` if none of its fields is packed.
+    pub(crate) fn presence_init(
+        &self,
+        m: &rir::Message,
+        value: impl Fn(&Field) -> String,
+    ) -> Option<String> {
+        let ty = self.presence_ty(m)?;
+        let bits = m
+            .fields
+            .iter()
+            .filter_map(|f| {
+                let bit = self.presence_bit(f)?;
+                Some(format!("({}.is_some() as {ty}) << {bit}", value(f)))
+            })
+            .join(" | ");
+        Some(format!("_presence: {bits}"))
+    }
+
+    /// How the enum `def_id` is generated, `None` if it isn't an enum. The
+    /// enums without a repr are rust enums.
+    pub fn enum_mode(&self, def_id: DefId) -> Option<EnumMode> {
+        match &*self.item(def_id)? {
+            Item::Enum(e) => Some(match e.repr {
+                Some(EnumRepr::I32) => EnumMode::NewType,
+                None => EnumMode::Enum,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Whether the enum generated for the thrift union `def_id` has a
+    /// `__Unknown` variant.
+    pub(crate) fn has_unknown_variant(&self, def_id: DefId) -> bool {
+        self.options.union_unknown_variant
+            && self.node_contains_tag::<crate::tags::thrift::Union>(def_id)
+    }
 
     pub fn config(&self, crate_id: &CrateId) -> &serde_yaml::Value {
         &self.find_service(crate_id.main_file).config
     }
 
+    /// The crates of the workspace output, and the crates each of them
+    /// references. The `Dynamic` items all live in the common crate. Empty
+    /// outside the workspace mode.
+    pub fn crate_dependencies(&self) -> FxHashMap<FastStr, FxHashSet<FastStr>> {
+        let mut deps = FxHashMap::<FastStr, FxHashSet<FastStr>>::default();
+        for (location, items) in self.location_map.iter().into_group_map_by(|item| item.1) {
+            let name = self.crate_name(location);
+            let def_ids = items.iter().map(|(def_id, _)| **def_id).collect_vec();
+            let referenced = self
+                .db
+                .collect_def_ids(&def_ids, Some(&self.location_map))
+                .into_values()
+                .map(|location| self.crate_name(&location))
+                .filter(|dep| *dep != name)
+                .collect::<FxHashSet<_>>();
+            deps.entry(name).or_default().extend(referenced);
+        }
+        deps
+    }
+
     pub(crate) fn crate_name(&self, location: &DefLocation) -> FastStr {
-        match location {
+        let name = match location {
             DefLocation::Fixed(crate_id, _) => {
                 let main_file = crate_id.main_file;
                 let service = self.find_service(main_file);
@@ -935,31 +2332,40 @@ impl Context {
                     .get("crate_name")
                     .and_then(|s| s.as_str().map(FastStr::new))
                     .unwrap_or_else(|| {
-                        service
-                            .path
-                            .file_stem()
-                            .unwrap()
-                            .to_str()
-                            .unwrap()
-                            .replace('.', "_")
-                            .into()
+                        FastStr::new(service.path.file_stem().unwrap().to_str().unwrap())
                     })
             }
-            DefLocation::Dynamic => self.common_crate_name.clone(),
+            DefLocation::Dynamic => self.options.common_crate_name.clone(),
+        };
+
+        // crate names double as directory and module names, so keep them to
+        // valid identifier characters
+        if name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+        {
+            return name;
+        }
+        let mut sanitized = String::with_capacity(name.len() + 1);
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            sanitized.push('_');
         }
+        sanitized.extend(name.chars().map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        }));
+        sanitized.into()
     }
 
     fn find_service(&self, file_id: FileId) -> &crate::IdlService {
         self.services
             .iter()
             .find(|s| {
-                let path = s
-                    .path
-                    .normalize()
-                    .unwrap_or_else(|err| {
-                        panic!("normalize path {} failed: {:?}", s.path.display(), err)
-                    })
-                    .into_path_buf();
+                let path = normalize_path(&s.path).unwrap_or_else(|err| {
+                    panic!("normalize path {} failed: {:?}", s.path.display(), err)
+                });
                 self.file_id(path.clone()).unwrap_or_else(|| {
                     panic!(
                         "file_id not found for path {} in file_ids_map {:?}",
@@ -972,6 +2378,21 @@ impl Context {
     }
 }
 
+/// The rust literal of the float `f` of type `ty`, `f32` or `f64`. Infinities
+/// and NaN are the constants of `ty`.
+fn float_lit(f: &str, ty: &str) -> String {
+    let f = f.parse::<f64>().unwrap();
+    if f.is_nan() {
+        format!("{ty}::NAN")
+    } else if f == f64::INFINITY {
+        format!("{ty}::INFINITY")
+    } else if f == f64::NEG_INFINITY {
+        format!("{ty}::NEG_INFINITY")
+    } else {
+        format!("{f}{ty}")
+    }
+}
+
 pub mod tls {
 
     use scoped_tls::scoped_thread_local;
@@ -3,7 +3,17 @@ use std::sync::Arc;
 use faststr::FastStr;
 use itertools::Itertools;
 
-use crate::{db::RirDatabase, rir::NodeKind, symbol::Symbol, Context, DefId, IdentName};
+use super::context::WorkspaceLayout;
+use crate::{
+    db::RirDatabase,
+    rir::{ItemPath, NodeKind},
+    symbol::Symbol,
+    Context, DefId, IdentName,
+};
+
+/// Maps the module path of the items to the one they are generated in, see
+/// `Builder::remap_mods`.
+pub type ModRemap = Arc<dyn Fn(&ItemPath) -> ItemPath + Send + Sync>;
 
 pub trait PathResolver: Sync + Send {
     fn path_for_def_id(&self, cx: &Context, def_id: DefId) -> Arc<[Symbol]> {
@@ -101,7 +111,9 @@ impl PathResolver for DefaultPathResolver {
     }
 }
 
-pub struct WorkspacePathResolver;
+pub struct WorkspacePathResolver {
+    pub(crate) layout: WorkspaceLayout,
+}
 
 impl PathResolver for WorkspacePathResolver {
     fn mod_prefix(&self, cx: &Context, def_id: DefId) -> Arc<[Symbol]> {
@@ -118,11 +130,13 @@ impl PathResolver for WorkspacePathResolver {
                 path.extend(prefix.iter().cloned());
                 path
             }
-            Some(super::context::DefLocation::Dynamic) => [cx.common_crate_name.clone().into()]
-                .iter()
-                .chain(DefaultPathResolver.mod_prefix(cx, def_id).iter())
-                .cloned()
-                .collect_vec(),
+            Some(super::context::DefLocation::Dynamic) => {
+                [cx.options.common_crate_name.clone().into()]
+                    .iter()
+                    .chain(DefaultPathResolver.mod_prefix(cx, def_id).iter())
+                    .cloned()
+                    .collect_vec()
+            }
             None => {
                 panic!(
                     "no location found for \"{}\" in file \"{}\"",
@@ -142,7 +156,29 @@ impl PathResolver for WorkspacePathResolver {
         if p2[0] == p1[0] {
             DefaultPathResolver.related_path(p1, p2)
         } else {
-            format!("::{}", p2.iter().map(|s| s.to_string()).join("::")).into()
+            let root = match self.layout {
+                WorkspaceLayout::Flat => "crate",
+                WorkspaceLayout::Root | WorkspaceLayout::Crates => "",
+            };
+            format!("{root}::{}", p2.iter().map(|s| s.to_string()).join("::")).into()
         }
     }
 }
+
+/// Remaps the module paths of `inner`. The items are generated in the
+/// modules of their paths, so the references to them follow.
+pub struct RemappedPathResolver {
+    pub(crate) inner: Arc<dyn PathResolver>,
+    pub(crate) remap: ModRemap,
+}
+
+impl PathResolver for RemappedPathResolver {
+    fn mod_prefix(&self, cx: &Context, def_id: DefId) -> Arc<[Symbol]> {
+        let path = ItemPath::from(self.inner.mod_prefix(cx, def_id));
+        Arc::from(&*(self.remap)(&path))
+    }
+
+    fn related_path(&self, p1: &[Symbol], p2: &[Symbol]) -> FastStr {
+        self.inner.related_path(p1, p2)
+    }
+}
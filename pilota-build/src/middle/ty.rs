@@ -4,7 +4,7 @@ pub use std::sync::Arc;
 use itertools::Itertools;
 pub use TyKind::*;
 
-use super::context::tls::with_cx;
+use super::context::tls::{with_cx, CONTEXT};
 pub use super::rir::Path;
 use crate::{db::RirDatabase, symbol::DefId, tags::TagId};
 
@@ -33,6 +33,10 @@ pub enum TyKind {
     Map(Arc<Ty>, Arc<Ty>),
     BTreeMap(Arc<Ty>, Arc<Ty>),
     Arc(Arc<Ty>),
+    /// A `Cow` of a string, binary or list, borrowing for [`COW_LIFETIME`]
+    Cow(Arc<Ty>),
+    /// A `Box<[_]>` of a list
+    BoxedSlice(Arc<Ty>),
     Path(Path),
 }
 
@@ -55,6 +59,10 @@ pub enum AdtKind {
     NewType(Arc<CodegenTy>),
 }
 
+/// The lifetime of the data borrowed by the `Cow` fields, which the types
+/// with such fields are generic over.
+pub const COW_LIFETIME: &str = "'cow";
+
 #[derive(Hash, PartialEq, Eq, Clone, Debug)]
 pub enum CodegenTy {
     FastStr,
@@ -84,6 +92,10 @@ pub enum CodegenTy {
     BTreeMap(Arc<CodegenTy>, Arc<CodegenTy>),
     Adt(AdtDef),
     Arc(Arc<CodegenTy>),
+    /// Borrows `str` for strings and slices for vecs
+    Cow(Arc<CodegenTy>),
+    /// The boxed slice of a vec
+    BoxedSlice(Arc<CodegenTy>),
 }
 
 impl CodegenTy {
@@ -106,7 +118,7 @@ impl CodegenTy {
     /// get the global path for ty.
     pub fn global_path(&self, adt_prefix: &str) -> faststr::FastStr {
         match self {
-            CodegenTy::String => "::std::string::String".into(),
+            CodegenTy::String => format!("{}::string::String", alloc_crate()).into(),
             CodegenTy::FastStr => "::pilota::FastStr".into(),
             CodegenTy::Str => "&'static str".into(),
             CodegenTy::Void => "()".into(),
@@ -128,7 +140,12 @@ impl CodegenTy {
             }
             CodegenTy::Vec(ty) => {
                 let ty = &**ty;
-                format!("::std::vec::Vec<{}>", ty.global_path(adt_prefix)).into()
+                format!(
+                    "{}::vec::Vec<{}>",
+                    alloc_crate(),
+                    ty.global_path(adt_prefix)
+                )
+                .into()
             }
             CodegenTy::Array(ty, size) => {
                 let ty = &**ty;
@@ -141,7 +158,8 @@ impl CodegenTy {
             CodegenTy::BTreeSet(ty) => {
                 let ty = &**ty;
                 format!(
-                    "::std::collections::BTreeSet<{}>",
+                    "{}::collections::BTreeSet<{}>",
+                    alloc_crate(),
                     ty.global_path(adt_prefix)
                 )
                 .into()
@@ -160,35 +178,70 @@ impl CodegenTy {
                 let k = &**k;
                 let v = &**v;
                 format!(
-                    "::std::collections::BTreeMap<{}, {}>",
+                    "{}::collections::BTreeMap<{}, {}>",
+                    alloc_crate(),
                     k.global_path(adt_prefix),
                     v.global_path(adt_prefix)
                 )
                 .into()
             }
             CodegenTy::Adt(def) => with_cx(|cx| {
+                if let Some(path) = cx.external_path(def.did) {
+                    return path;
+                }
                 let path = cx
                     .item_path(def.did)
                     .iter()
                     .map(|item| item.to_string())
                     .join("::");
 
-                format!("{adt_prefix}::{path}").into()
+                format!("{adt_prefix}::{path}{}", cx.item_generics(def.did)).into()
             }),
             CodegenTy::Arc(ty) => {
                 let ty = &**ty;
-                format!("::std::sync::Arc<{}>", ty.global_path(adt_prefix)).into()
+                format!(
+                    "{}::sync::Arc<{}>",
+                    alloc_crate(),
+                    ty.global_path(adt_prefix)
+                )
+                .into()
             }
+            CodegenTy::Cow(ty) => {
+                let borrowed = match &**ty {
+                    CodegenTy::String | CodegenTy::FastStr => "str".into(),
+                    CodegenTy::Vec(el) => format!("[{}]", el.global_path(adt_prefix)),
+                    _ => panic!("`{ty}` can't be wrapped by Cow"),
+                };
+                format!("{}::borrow::Cow<{COW_LIFETIME}, {borrowed}>", alloc_crate()).into()
+            }
+            CodegenTy::BoxedSlice(ty) => match &**ty {
+                CodegenTy::Vec(el) => format!(
+                    "{}::boxed::Box<[{}]>",
+                    alloc_crate(),
+                    el.global_path(adt_prefix)
+                )
+                .into(),
+                _ => panic!("`{ty}` can't be a boxed slice"),
+            },
             CodegenTy::LazyStaticRef(ty) => ty.global_path(adt_prefix),
             CodegenTy::Bytes => "::pilota::Bytes".into(),
         }
     }
 }
 
+/// The crate of the strings and collections of the code being generated.
+fn alloc_crate() -> &'static str {
+    if CONTEXT.is_set() {
+        with_cx(|cx| cx.alloc_crate())
+    } else {
+        "::std"
+    }
+}
+
 impl Display for CodegenTy {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            CodegenTy::String => f.write_str("::std::string::String"),
+            CodegenTy::String => write!(f, "{}::string::String", alloc_crate()),
             CodegenTy::FastStr => f.write_str("::pilota::FastStr"),
             CodegenTy::Str => f.write_str("&'static str"),
             CodegenTy::Void => f.write_str("()"),
@@ -210,7 +263,7 @@ impl Display for CodegenTy {
             }
             CodegenTy::Vec(ty) => {
                 let ty = &**ty;
-                write!(f, "::std::vec::Vec<{ty}>")
+                write!(f, "{}::vec::Vec<{ty}>", alloc_crate())
             }
             CodegenTy::Array(ty, size) => {
                 let ty = &**ty;
@@ -222,7 +275,7 @@ impl Display for CodegenTy {
             }
             CodegenTy::BTreeSet(ty) => {
                 let ty = &**ty;
-                write!(f, "::std::collections::BTreeSet<{ty}>")
+                write!(f, "{}::collections::BTreeSet<{ty}>", alloc_crate())
             }
             CodegenTy::Map(k, v) => {
                 let k = &**k;
@@ -232,17 +285,31 @@ impl Display for CodegenTy {
             CodegenTy::BTreeMap(k, v) => {
                 let k = &**k;
                 let v = &**v;
-                write!(f, "::std::collections::BTreeMap<{k}, {v}>")
+                write!(f, "{}::collections::BTreeMap<{k}, {v}>", alloc_crate())
             }
             CodegenTy::Adt(def) => with_cx(|cx| {
                 let path = cx.cur_related_item_path(def.did);
+                let generics = cx.item_generics(def.did);
 
-                write!(f, "{path}")
+                write!(f, "{path}{generics}")
             }),
             CodegenTy::Arc(ty) => {
                 let ty = &**ty;
-                write!(f, "::std::sync::Arc<{ty}>")
+                write!(f, "{}::sync::Arc<{ty}>", alloc_crate())
             }
+            CodegenTy::Cow(ty) => match &**ty {
+                CodegenTy::String | CodegenTy::FastStr => {
+                    write!(f, "{}::borrow::Cow<{COW_LIFETIME}, str>", alloc_crate())
+                }
+                CodegenTy::Vec(el) => {
+                    write!(f, "{}::borrow::Cow<{COW_LIFETIME}, [{el}]>", alloc_crate())
+                }
+                _ => panic!("`{ty}` can't be wrapped by Cow"),
+            },
+            CodegenTy::BoxedSlice(ty) => match &**ty {
+                CodegenTy::Vec(el) => write!(f, "{}::boxed::Box<[{el}]>", alloc_crate()),
+                _ => panic!("`{ty}` can't be a boxed slice"),
+            },
             CodegenTy::LazyStaticRef(ty) => ty.fmt(f),
             CodegenTy::Bytes => f.write_str("::pilota::Bytes"),
         }
@@ -352,6 +419,16 @@ pub trait TyTransformer {
         CodegenTy::Arc(Arc::from(self.codegen_item_ty(&ty.kind)))
     }
 
+    #[inline]
+    fn cow(&self, ty: &Ty) -> CodegenTy {
+        CodegenTy::Cow(Arc::from(self.codegen_item_ty(&ty.kind)))
+    }
+
+    #[inline]
+    fn boxed_slice(&self, ty: &Ty) -> CodegenTy {
+        CodegenTy::BoxedSlice(Arc::from(self.codegen_item_ty(&ty.kind)))
+    }
+
     #[inline]
     fn vec(&self, ty: &Ty) -> CodegenTy {
         CodegenTy::Vec(Arc::from(self.codegen_item_ty(&ty.kind)))
@@ -420,6 +497,8 @@ pub trait TyTransformer {
             UInt64 => self.uint64(),
             F32 => self.f32(),
             Arc(ty) => self.arc(ty),
+            Cow(ty) => self.cow(ty),
+            BoxedSlice(ty) => self.boxed_slice(ty),
         }
     }
 }
@@ -459,7 +538,12 @@ impl TyTransformer for ConstTyTransformer<'_> {
 
     #[inline]
     fn vec(&self, ty: &Ty) -> CodegenTy {
-        CodegenTy::Array(Arc::from(self.codegen_item_ty(&ty.kind)), 0)
+        CodegenTy::Array(Arc::from(self.dyn_codegen_item_ty(&ty.kind)), 0)
+    }
+
+    #[inline]
+    fn boxed_slice(&self, ty: &Ty) -> CodegenTy {
+        self.dyn_codegen_item_ty(&ty.kind)
     }
 
     #[inline]
@@ -560,6 +644,8 @@ pub(crate) fn fold_ty<F: Folder>(f: &mut F, ty: &Ty) -> Ty {
         UInt64 => TyKind::UInt64,
         F32 => TyKind::F32,
         Arc(ty) => TyKind::Arc(f.fold_ty(ty).into()),
+        Cow(ty) => TyKind::Cow(f.fold_ty(ty).into()),
+        BoxedSlice(ty) => TyKind::BoxedSlice(f.fold_ty(ty).into()),
     };
 
     Ty {
@@ -576,7 +662,7 @@ pub(crate) fn walk_ty<V: Visitor>(v: &mut V, ty: &Ty) {
         Map(key, value) => v.visit_map(key, value),
         BTreeMap(key, value) => v.visit_btree_map(key, value),
         Path(p) => v.visit_path(p),
-        Arc(p) => v.visit(p),
+        Arc(p) | Cow(p) | BoxedSlice(p) => v.visit(p),
         _ => {}
     }
 }
@@ -624,5 +710,11 @@ mod tests {
             ty.global_path("adt_prefix"),
             "::std::sync::Arc<::std::sync::Arc<u8>>"
         );
+
+        let ty = Cow(std::sync::Arc::new(Vec(std::sync::Arc::new(U8))));
+        assert_eq!(
+            ty.global_path("adt_prefix"),
+            "::std::borrow::Cow<'cow, [u8]>"
+        );
     }
 }
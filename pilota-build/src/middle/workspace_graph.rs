@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use petgraph::{algo::has_path_connecting, graph::NodeIndex, Graph};
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::{
     rir::Item,
@@ -37,10 +37,10 @@ impl WorkspaceGraph {
                 ty::Path(p) => {
                     graph.add_edge(idx, node_map[&p.did], ());
                 }
-                ty::Vec(ty) | ty::Set(ty) => {
+                ty::Vec(ty) | ty::Set(ty) | ty::BTreeSet(ty) | ty::Arc(ty) | ty::Cow(ty) => {
                     visit(graph, idx, node_map, ty);
                 }
-                ty::Map(ty1, ty2) => {
+                ty::Map(ty1, ty2) | ty::BTreeMap(ty1, ty2) => {
                     visit(graph, idx, node_map, ty1);
                     visit(graph, idx, node_map, ty2);
                 }
@@ -77,4 +77,29 @@ impl WorkspaceGraph {
         let b = self.node_map[&b];
         has_path_connecting(&self.graph, a, b, None)
     }
+
+    /// Whether `a` contains itself, directly or through the collections.
+    pub fn is_cycled(&self, a: DefId) -> bool {
+        let a = self.node_map[&a];
+        self.graph
+            .neighbors_directed(a, petgraph::Direction::Outgoing)
+            .any(|n| has_path_connecting(&self.graph, n, a, None))
+    }
+
+    /// `items` and the items containing any of them, directly or through the
+    /// collections.
+    pub fn containing(&self, items: impl IntoIterator<Item = DefId>) -> FxHashSet<DefId> {
+        let mut stack = items.into_iter().collect::<Vec<_>>();
+        let mut set = FxHashSet::default();
+        while let Some(def_id) = stack.pop() {
+            if set.insert(def_id) {
+                stack.extend(
+                    self.graph
+                        .neighbors_directed(self.node_map[&def_id], petgraph::Direction::Incoming)
+                        .map(|n| self.id_map[&n]),
+                );
+            }
+        }
+        set
+    }
 }
@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use faststr::FastStr;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    fmt::FmtOptions,
+    middle::{
+        context::{
+            DefaultStrategy, InlineMode, PackageFacade, SerdeUnionRepr, ServiceResult, SourceType,
+            TraceBackend, UnknownEnum, WorkspaceLayout,
+        },
+        resolver::ModRemap,
+    },
+    tags::SerdeBytes,
+};
+
+fn default_lint_allows() -> Vec<FastStr> {
+    ["warnings", "clippy::all", "non_snake_case", "unused"]
+        .into_iter()
+        .map(FastStr::from_static_str)
+        .collect()
+}
+
+/// The options of a build, set through the methods of [`crate::Builder`] and
+/// shared by the [`crate::Context`] the code is generated from.
+#[derive(Clone)]
+pub struct BuildOptions {
+    pub(crate) source_type: SourceType,
+    pub(crate) ignore_unused: bool,
+    pub(crate) split: bool,
+    pub(crate) touches: Vec<(PathBuf, Vec<String>)>,
+    pub(crate) change_case: bool,
+    pub(crate) keep_unknown_fields: Vec<PathBuf>,
+    pub(crate) dedups: Vec<FastStr>,
+    pub(crate) special_namings: Vec<FastStr>,
+    pub(crate) common_crate_name: FastStr,
+    pub(crate) lint_allows: Vec<FastStr>,
+    pub(crate) inline_mode: InlineMode,
+    pub(crate) inline_max_fields: usize,
+    pub(crate) workspace_layout: WorkspaceLayout,
+    pub(crate) manifest_template: Option<FastStr>,
+    pub(crate) flatten_wrappers: bool,
+    pub(crate) union_unknown_variant: bool,
+    pub(crate) message_registry: bool,
+    pub(crate) strict_decode: bool,
+    pub(crate) owned_encode: bool,
+    pub(crate) must_use: bool,
+    pub(crate) service_result: Option<ServiceResult>,
+    pub(crate) fmt: FmtOptions,
+    pub(crate) no_std: bool,
+    pub(crate) packed_presence: bool,
+    pub(crate) sort_items: bool,
+    pub(crate) max_items_per_file: Option<usize>,
+    pub(crate) prelude_imports: Vec<FastStr>,
+    pub(crate) decode_trace: Option<TraceBackend>,
+    /// The rust paths of the external types, by the fqn of their item
+    pub(crate) external_types: FxHashMap<FastStr, FastStr>,
+    pub(crate) serde_union_repr: SerdeUnionRepr,
+    pub(crate) iter_accessors: bool,
+    pub(crate) idl_fingerprint: bool,
+    pub(crate) serde_bytes: Option<SerdeBytes>,
+    pub(crate) package_change_case: FxHashMap<FastStr, bool>,
+    pub(crate) package_facade: PackageFacade,
+    pub(crate) manual_clone: bool,
+    pub(crate) newtype_from_str: bool,
+    pub(crate) roundtrip_tests: bool,
+    pub(crate) unknown_enum: UnknownEnum,
+    pub(crate) str_accessors: bool,
+    pub(crate) newtype_as_ref: bool,
+    pub(crate) field_number_consts: bool,
+    pub(crate) default_strategy: DefaultStrategy,
+    pub(crate) mod_remap: Option<ModRemap>,
+    /// The `(fqn, key, value)` annotations of `Builder::item_tag`
+    pub(crate) item_tags: Vec<(FastStr, FastStr, FastStr)>,
+    pub(crate) skip_services: bool,
+}
+
+impl Default for BuildOptions {
+    fn default() -> Self {
+        BuildOptions {
+            source_type: SourceType::Thrift,
+            ignore_unused: true,
+            split: false,
+            touches: Vec::default(),
+            change_case: true,
+            keep_unknown_fields: Vec::default(),
+            dedups: Vec::default(),
+            special_namings: Vec::default(),
+            common_crate_name: "common".into(),
+            lint_allows: default_lint_allows(),
+            inline_mode: InlineMode::Off,
+            inline_max_fields: 16,
+            workspace_layout: WorkspaceLayout::Root,
+            manifest_template: None,
+            flatten_wrappers: false,
+            union_unknown_variant: false,
+            message_registry: false,
+            strict_decode: false,
+            owned_encode: false,
+            must_use: false,
+            service_result: None,
+            fmt: FmtOptions::default(),
+            no_std: false,
+            packed_presence: false,
+            sort_items: false,
+            max_items_per_file: None,
+            prelude_imports: Vec::default(),
+            decode_trace: None,
+            external_types: FxHashMap::default(),
+            serde_union_repr: SerdeUnionRepr::default(),
+            iter_accessors: false,
+            idl_fingerprint: false,
+            serde_bytes: None,
+            package_change_case: FxHashMap::default(),
+            package_facade: PackageFacade::default(),
+            manual_clone: false,
+            newtype_from_str: false,
+            roundtrip_tests: false,
+            unknown_enum: UnknownEnum::Preserve,
+            str_accessors: false,
+            newtype_as_ref: false,
+            field_number_consts: false,
+            default_strategy: DefaultStrategy::FromIdl,
+            mod_remap: None,
+            item_tags: Vec::default(),
+            skip_services: false,
+        }
+    }
+}
@@ -9,7 +9,7 @@ pub(crate) mod protobuf;
 pub(crate) mod thrift;
 
 use rustc_hash::FxHashMap;
-pub use thrift::ThriftParser;
+pub use thrift::{MethodItemNaming, OptionalDefault, ParseMode, ThriftParser};
 
 pub use self::protobuf::ProtobufParser;
 
@@ -17,6 +17,8 @@ pub struct ParseResult {
     pub files: Vec<Arc<File>>,
     pub(crate) input_files: Vec<FileId>,
     pub(crate) file_ids_map: FxHashMap<Arc<PathBuf>, FileId>,
+    /// The source of each file, if the parser keeps them
+    pub(crate) file_texts: FxHashMap<FileId, Arc<str>>,
 }
 
 pub trait Parser {
@@ -28,5 +30,9 @@ pub trait Parser {
 
     fn include_dirs(&mut self, dirs: Vec<PathBuf>);
 
+    /// Keep the sources of the files in the [`ParseResult`], which
+    /// `idl_fingerprint` hashes.
+    fn file_texts(&mut self, _keep: bool) {}
+
     fn parse(self) -> ParseResult;
 }
@@ -2,11 +2,12 @@ use std::{collections::HashMap, path::PathBuf, sync::Arc};
 
 use ahash::AHashMap;
 use faststr::FastStr;
+use heck::ToLowerCamelCase;
 use itertools::Itertools;
 use normpath::PathExt;
 use protobuf::descriptor::{
     field_descriptor_proto::{Label, Type},
-    DescriptorProto, EnumDescriptorProto, ServiceDescriptorProto,
+    DescriptorProto, EnumDescriptorProto, FieldDescriptorProto, ServiceDescriptorProto,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -16,17 +17,79 @@ use crate::{
     ir::{self, FieldKind, Item, Path, TyKind},
     symbol::{EnumRepr, FileId, Ident},
     tags::{
-        protobuf::{ClientStreaming, OneOf, ProstType, Repeated, ServerStreaming},
+        protobuf::{
+            ClientStreaming, Group, JsonName, OneOf, ProstType, Repeated, Required, ServerStreaming,
+        },
         PilotaName, Tags,
     },
     IdentName,
 };
 
+/// Unescapes the c escaped `s`, the form of the default values of bytes in
+/// the descriptors.
+fn unescape_c(s: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut chars = s.bytes().peekable();
+    while let Some(b) = chars.next() {
+        if b != b'\\' {
+            bytes.push(b);
+            continue;
+        }
+        let b = match chars.next()? {
+            b'n' => b'\n',
+            b'r' => b'\r',
+            b't' => b'\t',
+            b'a' => 0x07,
+            b'b' => 0x08,
+            b'f' => 0x0c,
+            b'v' => 0x0b,
+            b @ (b'\\' | b'\'' | b'"' | b'?') => b,
+            b'x' => {
+                let mut value = 0u8;
+                for _ in 0..2 {
+                    match chars.peek().and_then(|b| (*b as char).to_digit(16)) {
+                        Some(d) => {
+                            value = value * 16 + d as u8;
+                            chars.next();
+                        }
+                        None => break,
+                    }
+                }
+                value
+            }
+            b @ b'0'..=b'7' => {
+                let mut value = (b - b'0') as u32;
+                for _ in 0..2 {
+                    match chars.peek() {
+                        Some(b @ b'0'..=b'7') => {
+                            value = value * 8 + (b - b'0') as u32;
+                            chars.next();
+                        }
+                        _ => break,
+                    }
+                }
+                u8::try_from(value).ok()?
+            }
+            _ => return None,
+        };
+        bytes.push(b);
+    }
+    Some(bytes)
+}
+
 #[derive(Default)]
 pub struct ProtobufParser {
     inner: protobuf_parse::Parser,
     include_dirs: Vec<PathBuf>,
     input_files: FxHashSet<PathBuf>,
+    proto_json: bool,
+    file_texts: bool,
+}
+
+impl ProtobufParser {
+    pub fn proto_json(&mut self, proto_json: bool) {
+        self.proto_json = proto_json;
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -40,6 +103,7 @@ struct Lower {
     files: FxHashMap<String, FileId>,
     cur_package: Option<String>,
     cur_syntax: Syntax,
+    proto_json: bool,
 }
 
 impl Default for Lower {
@@ -49,6 +113,7 @@ impl Default for Lower {
             files: Default::default(),
             cur_package: None,
             cur_syntax: Syntax::Proto3,
+            proto_json: false,
         }
     }
 }
@@ -93,9 +158,14 @@ impl Lower {
 
             assert_eq!(".", &name[..1]);
 
+            let mut tags = Tags::default();
+            if type_.and_then(|ty| ty.enum_value().ok()) == Some(Type::TYPE_GROUP) {
+                tags.insert(Group);
+            }
+
             return ir::Ty {
                 kind: ir::TyKind::Path(self.str2path(&name[1..])),
-                tags: Default::default(),
+                tags: Arc::new(tags),
             };
         }
         let Some(ty) = type_ else { panic!() };
@@ -117,7 +187,6 @@ impl Lower {
             }
             protobuf::descriptor::field_descriptor_proto::Type::TYPE_BOOL => ir::TyKind::Bool,
             protobuf::descriptor::field_descriptor_proto::Type::TYPE_STRING => ir::TyKind::String,
-            protobuf::descriptor::field_descriptor_proto::Type::TYPE_GROUP => todo!(),
             protobuf::descriptor::field_descriptor_proto::Type::TYPE_BYTES => ir::TyKind::Bytes,
             protobuf::descriptor::field_descriptor_proto::Type::TYPE_UINT32 => ir::TyKind::UInt32,
             protobuf::descriptor::field_descriptor_proto::Type::TYPE_SFIXED32 => {
@@ -138,7 +207,8 @@ impl Lower {
             }
 
             protobuf::descriptor::field_descriptor_proto::Type::TYPE_MESSAGE
-            | protobuf::descriptor::field_descriptor_proto::Type::TYPE_ENUM => unreachable!(),
+            | protobuf::descriptor::field_descriptor_proto::Type::TYPE_ENUM
+            | protobuf::descriptor::field_descriptor_proto::Type::TYPE_GROUP => unreachable!(),
         };
 
         ir::Ty {
@@ -169,6 +239,73 @@ impl Lower {
         }
     }
 
+    /// The proto2 `[default = ...]` of a field. Strings are escaped for a rust
+    /// literal, bytes are too if they are valid utf-8, else a list of bytes.
+    fn lower_default(&self, f: &FieldDescriptorProto, ty: &ir::Ty) -> Option<ir::Literal> {
+        if !f.has_default_value() {
+            return None;
+        }
+        let value = f.default_value();
+        let invalid = || -> ! {
+            panic!(
+                "invalid default value `{value}` for the field `{}`",
+                f.name()
+            )
+        };
+        Some(match f.type_() {
+            Type::TYPE_DOUBLE | Type::TYPE_FLOAT => ir::Literal::Float(value.into()),
+            Type::TYPE_UINT64 | Type::TYPE_FIXED64 => {
+                ir::Literal::Int(value.parse::<u64>().unwrap_or_else(|_| invalid()) as i64)
+            }
+            Type::TYPE_INT64
+            | Type::TYPE_INT32
+            | Type::TYPE_UINT32
+            | Type::TYPE_FIXED32
+            | Type::TYPE_SFIXED32
+            | Type::TYPE_SFIXED64
+            | Type::TYPE_SINT32
+            | Type::TYPE_SINT64 => ir::Literal::Int(value.parse().unwrap_or_else(|_| invalid())),
+            Type::TYPE_BOOL => ir::Literal::Bool(value.parse().unwrap_or_else(|_| invalid())),
+            Type::TYPE_STRING => ir::Literal::String(value.escape_default().to_string().into()),
+            Type::TYPE_BYTES => {
+                let bytes = unescape_c(value).unwrap_or_else(|| invalid());
+                match std::str::from_utf8(&bytes) {
+                    Ok(s) => ir::Literal::String(s.escape_default().to_string().into()),
+                    Err(_) => ir::Literal::List(
+                        bytes
+                            .into_iter()
+                            .map(|b| ir::Literal::Int(b as i64))
+                            .collect(),
+                    ),
+                }
+            }
+            Type::TYPE_ENUM => {
+                let TyKind::Path(path) = &ty.kind else {
+                    unreachable!()
+                };
+                let mut segments = path.segments.to_vec();
+                segments.push(FastStr::new(value).into());
+                ir::Literal::Path(Path {
+                    segments: Arc::from(segments),
+                })
+            }
+            Type::TYPE_MESSAGE | Type::TYPE_GROUP => return None,
+        })
+    }
+
+    /// The `JsonName` tag of a field in `proto_json` mode.
+    fn json_name_tag(&self, tags: &mut Tags, f: &FieldDescriptorProto) {
+        if !self.proto_json {
+            return;
+        }
+        let name = if f.has_json_name() {
+            FastStr::new(f.json_name())
+        } else {
+            f.name().to_lower_camel_case().into()
+        };
+        tags.insert(JsonName(name));
+    }
+
     fn lower_message(
         &self,
         message: &DescriptorProto,
@@ -225,16 +362,20 @@ impl Lower {
                         repr: None,
                         variants: fields
                             .iter()
-                            .map(|(_, f)| ir::EnumVariant {
-                                discr: None,
-                                id: f.number,
-                                name: FastStr::new(f.name()).into(),
-                                fields: vec![self.lower_ty(
-                                    f.type_,
-                                    f.type_name.as_deref(),
-                                    &nested_messages,
-                                )],
-                                tags: Default::default(),
+                            .map(|(_, f)| {
+                                let mut tags = Tags::default();
+                                self.json_name_tag(&mut tags, f);
+                                ir::EnumVariant {
+                                    discr: None,
+                                    id: f.number,
+                                    name: FastStr::new(f.name()).into(),
+                                    fields: vec![self.lower_ty(
+                                        f.type_,
+                                        f.type_name.as_deref(),
+                                        &nested_messages,
+                                    )],
+                                    tags: Arc::new(tags),
+                                }
                             })
                             .collect_vec(),
                     }),
@@ -264,10 +405,14 @@ impl Lower {
 
         parent_messages.push(message.name().into());
 
-        nested_messages
+        // in their order in the idl, which `nested_messages` doesn't keep, so
+        // the nested items, e.g. the messages of groups, are generated in the
+        // same order on each build
+        message
+            .nested_type
             .iter()
-            .filter(|(_, m)| !m.options.has_map_entry())
-            .for_each(|(_, m)| {
+            .filter(|m| !m.options.has_map_entry())
+            .for_each(|m| {
                 self.lower_message(m, parent_messages)
                     .into_iter()
                     .for_each(|item| nested_items.push(Arc::new(item)))
@@ -318,11 +463,17 @@ impl Lower {
                         if repeated {
                             tags.insert(Repeated);
                         }
+                        if self.cur_syntax == Syntax::Proto2 && f.label() == Label::LABEL_REQUIRED {
+                            tags.insert(Required);
+                        }
+                        self.json_name_tag(&mut tags, f);
+
+                        let default = self.lower_default(f, &ty);
 
                         (
                             *idx,
                             ir::Field {
-                                default: None,
+                                default,
                                 id: f.number(),
                                 name: FastStr::new(f.name()).into(),
                                 ty,
@@ -480,26 +631,39 @@ impl Parser for ProtobufParser {
         self.inner.includes(dirs);
     }
 
+    fn file_texts(&mut self, keep: bool) {
+        self.file_texts = keep;
+    }
+
     fn parse(self) -> super::ParseResult {
         let descriptors = self.inner.parse_and_typecheck().unwrap().file_descriptors;
 
         let mut input_file_ids = vec![];
 
-        let mut lower = Lower::default();
+        let mut lower = Lower {
+            proto_json: self.proto_json,
+            ..Default::default()
+        };
 
         let files = lower.lower(&descriptors);
 
         let mut file_ids = FxHashMap::default();
+        let mut file_texts = FxHashMap::default();
 
         descriptors.iter().for_each(|f| {
             self.include_dirs.iter().for_each(|p| {
                 let path = p.join(f.name());
                 if path.exists() {
                     println!("cargo:rerun-if-changed={}", path.display());
+                    let file_id = *lower.files.get(f.name()).unwrap();
                     file_ids.insert(
                         Arc::from(path.normalize().unwrap().into_path_buf()),
-                        *lower.files.get(f.name()).unwrap(),
+                        file_id,
                     );
+                    if self.file_texts {
+                        file_texts
+                            .insert(file_id, Arc::from(std::fs::read_to_string(&path).unwrap()));
+                    }
                     if self
                         .input_files
                         .contains(path.normalize().unwrap().as_path())
@@ -514,6 +678,7 @@ impl Parser for ProtobufParser {
             files,
             input_files: input_file_ids,
             file_ids_map: file_ids,
+            file_texts,
         }
     }
 }
@@ -1,37 +1,78 @@
-use std::{path::PathBuf, str::FromStr, sync::Arc};
+use std::{
+    path::{Path as StdPath, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
 
 use faststr::FastStr;
 use heck::ToUpperCamelCase;
 use itertools::Itertools;
-use normpath::PathExt;
 use pilota_thrift_parser as thrift_parser;
-use pilota_thrift_parser::parser::Parser as _;
+pub use pilota_thrift_parser::parser::ParseMode;
 use rustc_hash::{FxHashMap, FxHashSet};
 use salsa::ParallelDatabase;
 use thrift_parser::Annotations;
 
 use crate::{
+    errors::{warn, WarningKind},
     index::Idx,
     ir::{self, Arg, Enum, EnumVariant, FieldKind, File, Item, ItemKind, Path},
     symbol::{EnumRepr, FileId, Ident},
-    tags::{Annotation, PilotaName, RustWrapperArc, Tags},
-    util::error_abort,
+    tags::{
+        Annotation, DefaultRequiredness, MethodItemName, PilotaName, RustWrapperArc, StringType,
+        Tags,
+    },
+    util::{error_abort, normalize_loader_path, normalize_path},
     IdentName,
 };
 
+/// Loads the content of the idl file at a path, see
+/// [`crate::Builder::file_loader`].
+pub type FileLoader = Arc<dyn Fn(&StdPath) -> std::io::Result<String> + Send + Sync>;
+
+trait HasFileLoader {
+    fn file_loader(&self) -> Option<&FileLoader>;
+}
+
+trait HasParseMode {
+    fn parse_mode(&self) -> ParseMode;
+}
+
 #[salsa::query_group(SourceDatabaseStorage)]
-trait SourceDatabase {
+trait SourceDatabase: HasFileLoader + HasParseMode {
+    /// The text the file loader loads for `path`, or the message of its
+    /// error.
+    fn load_file(&self, path: PathBuf) -> Result<Arc<str>, FastStr>;
     fn file_text(&self, path: PathBuf) -> Arc<str>;
     fn parse(&self, path: PathBuf) -> Arc<thrift_parser::File>;
 }
 
-fn file_text(_db: &dyn SourceDatabase, path: PathBuf) -> Arc<str> {
-    Arc::from(unsafe { String::from_utf8_unchecked(std::fs::read(path).unwrap()) })
+fn load_file(db: &dyn SourceDatabase, path: PathBuf) -> Result<Arc<str>, FastStr> {
+    let load = db.file_loader().expect("no file loader");
+    load(&path).map(Arc::from).map_err(|e| e.to_string().into())
+}
+
+fn file_text(db: &dyn SourceDatabase, path: PathBuf) -> Arc<str> {
+    match db.file_loader() {
+        Some(_) => db
+            .load_file(path.clone())
+            .unwrap_or_else(|e| error_abort(format!("{}: {e}", path.display()))),
+        None => Arc::from(unsafe { String::from_utf8_unchecked(std::fs::read(path).unwrap()) }),
+    }
 }
 
 fn parse(db: &dyn SourceDatabase, path: PathBuf) -> Arc<thrift_parser::File> {
     let text = db.file_text(path.clone());
-    let mut ast = thrift_parser::File::parse(&text).unwrap().1;
+    let mode = db.parse_mode();
+    let (mut ast, warnings) = thrift_parser::File::parse_with_mode(&text, mode)
+        .unwrap_or_else(|_| panic!("{}: parse failed in {mode:?} mode", path.display()))
+        .1;
+    for warning in warnings {
+        warn(
+            WarningKind::Separator,
+            format_args!("{}:{}: {}", path.display(), warning.line, warning.message),
+        );
+    }
     ast.path = Arc::from(path);
     Arc::from(ast)
 }
@@ -40,6 +81,8 @@ fn parse(db: &dyn SourceDatabase, path: PathBuf) -> Arc<thrift_parser::File> {
 #[salsa::database(SourceDatabaseStorage)]
 struct ThriftSourceDatabase {
     storage: salsa::Storage<ThriftSourceDatabase>,
+    file_loader: Option<FileLoader>,
+    parse_mode: ParseMode,
 }
 
 impl salsa::Database for ThriftSourceDatabase {}
@@ -48,10 +91,33 @@ impl salsa::ParallelDatabase for ThriftSourceDatabase {
     fn snapshot(&self) -> salsa::Snapshot<ThriftSourceDatabase> {
         salsa::Snapshot::new(ThriftSourceDatabase {
             storage: self.storage.snapshot(),
+            file_loader: self.file_loader.clone(),
+            parse_mode: self.parse_mode,
         })
     }
 }
 
+impl HasFileLoader for ThriftSourceDatabase {
+    fn file_loader(&self) -> Option<&FileLoader> {
+        self.file_loader.as_ref()
+    }
+}
+
+impl HasParseMode for ThriftSourceDatabase {
+    fn parse_mode(&self) -> ParseMode {
+        self.parse_mode
+    }
+}
+
+/// The normalized `path` of an idl, see [`normalize_loader_path`] for the
+/// paths of a file loader.
+fn normalize_idl_path(db: &dyn HasFileLoader, path: &StdPath) -> std::io::Result<PathBuf> {
+    match db.file_loader() {
+        Some(_) => normalize_loader_path(path),
+        None => normalize_path(path),
+    }
+}
+
 #[derive(Debug)]
 pub struct LowerResult {
     pub files: Vec<Arc<File>>,
@@ -73,10 +139,23 @@ pub struct ThriftLower {
     include_dirs: Vec<PathBuf>,
     packages: FxHashMap<Path, Vec<Arc<PathBuf>>>,
     service_name_duplicates: FxHashSet<String>,
+    method_item_naming: MethodItemNaming,
+    /// Method names shared by several services of the current file
+    method_name_duplicates: FxHashSet<FastStr>,
+    cow_fields: bool,
+    optional_default: OptionalDefault,
+    /// The tags of the document annotations of the current file
+    document_tags: Arc<Tags>,
 }
 
 impl ThriftLower {
-    fn new(db: salsa::Snapshot<ThriftSourceDatabase>, include_dirs: Vec<PathBuf>) -> Self {
+    fn new(
+        db: salsa::Snapshot<ThriftSourceDatabase>,
+        include_dirs: Vec<PathBuf>,
+        method_item_naming: MethodItemNaming,
+        cow_fields: bool,
+        optional_default: OptionalDefault,
+    ) -> Self {
         ThriftLower {
             cur_file: None,
             next_file_id: FileId::from_u32(0),
@@ -86,6 +165,11 @@ impl ThriftLower {
             include_dirs,
             packages: Default::default(),
             service_name_duplicates: Default::default(),
+            method_item_naming,
+            method_name_duplicates: Default::default(),
+            cow_fields,
+            optional_default,
+            document_tags: Default::default(),
         }
     }
 
@@ -94,10 +178,15 @@ impl ThriftLower {
         F: FnOnce(&mut Self) -> ir::File,
     {
         let old_file = self.cur_file.clone();
+        let old_document_tags = std::mem::replace(
+            &mut self.document_tags,
+            Arc::new(Self::extract_document_tags(&file.document_annotations)),
+        );
         self.cur_file = Some(file);
 
         let f = Arc::from(f(self));
         self.cur_file = old_file;
+        self.document_tags = old_document_tags;
         self.files.insert(f.id, f.clone());
         f
     }
@@ -185,20 +274,9 @@ impl ThriftLower {
                 })
                 .collect::<Vec<_>>();
 
-            let tags = self.extract_tags(&f.annotations);
-            let name = tags
-                .get::<PilotaName>()
-                .map(|name| name.0.clone())
-                .unwrap_or_else(|| FastStr::new(f.name.0.clone()));
-
-            let upper_camel_ident = name.as_str().upper_camel_ident();
-            let method_name = if function_name_duplicates.contains(upper_camel_ident.as_str()) {
-                name
-            } else {
-                upper_camel_ident
-            };
+            let prefix = self.method_item_prefix(&service_name, f, &function_name_duplicates);
 
-            let name: Ident = format!("{}{}ResultRecv", service_name, method_name).into();
+            let name: Ident = format!("{prefix}ResultRecv").into();
             let mut tags = self.extract_tags(&f.result_type.1);
             tags.remove::<RustWrapperArc>();
             let kind = ir::ItemKind::Enum(ir::Enum {
@@ -220,7 +298,7 @@ impl ThriftLower {
             tags.insert(crate::tags::PilotaName(name.sym.0));
             result.push(self.mk_item(kind, tags.into()));
 
-            let name: Ident = format!("{}{}ResultSend", service_name, method_name).into();
+            let name: Ident = format!("{prefix}ResultSend").into();
             let kind = ir::ItemKind::Enum(ir::Enum {
                 name: name.clone(),
                 variants: std::iter::once(ir::EnumVariant {
@@ -241,7 +319,7 @@ impl ThriftLower {
             result.push(self.mk_item(kind, tags.into()));
 
             if !exception.is_empty() {
-                let name: Ident = format!("{}{}Exception", service_name, method_name).into();
+                let name: Ident = format!("{prefix}Exception").into();
                 let kind = ir::ItemKind::Enum(ir::Enum {
                     name: name.clone(),
                     variants: exception,
@@ -254,7 +332,7 @@ impl ThriftLower {
                 result.push(self.mk_item(kind, tags.into()));
             }
 
-            let name: Ident = format!("{}{}ArgsSend", service_name, method_name).into();
+            let name: Ident = format!("{prefix}ArgsSend").into();
             let kind = ir::ItemKind::Message(ir::Message {
                 name: name.clone(),
                 fields: f.arguments.iter().map(|a| self.lower_field(a)).collect(),
@@ -265,7 +343,7 @@ impl ThriftLower {
             tags.insert(crate::tags::PilotaName(name.sym.0));
             result.push(self.mk_item(kind, tags.into()));
 
-            let name: Ident = format!("{}{}ArgsRecv", service_name, method_name).into();
+            let name: Ident = format!("{prefix}ArgsRecv").into();
             let kind = ir::ItemKind::Message(ir::Message {
                 name: name.clone(),
                 fields: f
@@ -290,18 +368,28 @@ impl ThriftLower {
         result
     }
 
-    fn lower_method(
+    /// The name of `method` as used in the names of its args and result
+    /// items.
+    fn method_name(method: &thrift_parser::Function, tags: &Tags) -> FastStr {
+        tags.get::<PilotaName>()
+            .map(|name| name.0.clone())
+            .unwrap_or_else(|| FastStr::new(method.name.0.clone()))
+    }
+
+    /// The prefix of the names of the items generated for `method`, like
+    /// `{prefix}ArgsSend`.
+    fn method_item_prefix(
         &self,
-        service_name: &String,
+        service_name: &str,
         method: &thrift_parser::Function,
         function_name_duplicates: &FxHashSet<&str>,
-    ) -> ir::Method {
+    ) -> String {
         let tags = self.extract_tags(&method.annotations);
-        let name = tags
-            .get::<PilotaName>()
-            .map(|name| name.0.clone())
-            .unwrap_or_else(|| FastStr::new(method.name.0.clone()));
+        if let Some(name) = tags.get::<MethodItemName>() {
+            return name.0.to_string();
+        }
 
+        let name = Self::method_name(method, &tags);
         let upper_camel_ident = name.as_str().upper_camel_ident();
         let method_name = if function_name_duplicates.contains(upper_camel_ident.as_str()) {
             name
@@ -309,6 +397,30 @@ impl ThriftLower {
             upper_camel_ident
         };
 
+        match self.method_item_naming {
+            MethodItemNaming::Method if !self.method_name_duplicates.contains(&method_name) => {
+                method_name.to_string()
+            }
+            _ => format!("{service_name}{method_name}"),
+        }
+    }
+
+    fn lower_method(
+        &self,
+        service_name: &str,
+        method: &thrift_parser::Function,
+        function_name_duplicates: &FxHashSet<&str>,
+    ) -> ir::Method {
+        let mut tags = self.extract_tags(&method.annotations);
+        tags.insert(crate::tags::thrift::Annotations(
+            method
+                .annotations
+                .iter()
+                .map(|a| (FastStr::new(&a.key), FastStr::new(&*a.value)))
+                .collect(),
+        ));
+        let prefix = self.method_item_prefix(service_name, method, function_name_duplicates);
+
         ir::Method {
             name: self.lower_ident(&method.name),
             args: method
@@ -328,10 +440,7 @@ impl ThriftLower {
                 None
             } else {
                 Some(Path {
-                    segments: Arc::from([Ident::from(format!(
-                        "{}{}Exception",
-                        service_name, method_name,
-                    ))]),
+                    segments: Arc::from([Ident::from(format!("{prefix}Exception"))]),
                 })
             },
         }
@@ -413,12 +522,18 @@ impl ThriftLower {
             _ => &empty_annotations,
         };
 
-        let tags = self.extract_tags(annotations);
+        let mut tags = self.extract_tags(annotations);
+        if let thrift_parser::Item::Union(_) = item {
+            tags.insert(crate::tags::thrift::Union);
+        }
 
         vec![self.mk_item(single, tags.into())]
     }
 
     fn lower_union(&self, union: &thrift_parser::Union) -> Enum {
+        if union.xsd_all {
+            self.warn_xsd(format_args!("`xsd_all` of `{}` is ignored", union.name.0));
+        }
         Enum {
             name: self.lower_ident(&union.name),
             variants: union
@@ -458,6 +573,20 @@ impl ThriftLower {
             thrift_parser::Ty::I64 => ir::TyKind::I64,
             thrift_parser::Ty::Double => ir::TyKind::F64,
             thrift_parser::Ty::Uuid => ir::TyKind::Uuid,
+            thrift_parser::Ty::Slist => {
+                let path = self.cur_file.as_ref().unwrap().path.display();
+                warn(
+                    WarningKind::DeprecatedType,
+                    format_args!("{path}: `slist` is deprecated, use `list<string>` instead"),
+                );
+                ir::TyKind::Vec(
+                    ir::Ty {
+                        kind: ir::TyKind::String,
+                        tags: Default::default(),
+                    }
+                    .into(),
+                )
+            }
             thrift_parser::Ty::List { value, .. } => ir::TyKind::Vec(self.lower_ty(value).into()),
             thrift_parser::Ty::Set { value, .. } => ir::TyKind::Set(self.lower_ty(value).into()),
             thrift_parser::Ty::Map { key, value, .. } => {
@@ -472,21 +601,57 @@ impl ThriftLower {
         }
     }
 
+    fn warn_xsd(&self, msg: std::fmt::Arguments) {
+        let path = self.cur_file.as_ref().unwrap().path.display();
+        warn(WarningKind::LegacyXsd, format_args!("{path}: {msg}"));
+    }
+
     fn lower_field(&self, f: &thrift_parser::Field) -> ir::Field {
         let tags = self.extract_tags(&f.annotations);
         self.lower_field_with_tags(f, tags)
     }
 
-    fn lower_field_with_tags(&self, f: &thrift_parser::Field, tags: Tags) -> ir::Field {
+    fn lower_field_with_tags(&self, f: &thrift_parser::Field, mut tags: Tags) -> ir::Field {
+        if self.cow_fields {
+            tags.insert(crate::tags::thrift::CowWrapper);
+        }
+        if !tags.contains::<StringType>() {
+            if let Some(string_type) = self.document_tags.get::<StringType>() {
+                tags.insert(*string_type);
+            }
+        }
+        let default_kind = match self.document_tags.get::<DefaultRequiredness>() {
+            Some(DefaultRequiredness::Required) => FieldKind::Required,
+            _ => FieldKind::Optional,
+        };
+        let kind = match f.attribute {
+            thrift_parser::Attribute::Required => {
+                if f.xsd_optional {
+                    self.warn_xsd(format_args!(
+                        "`xsd_optional` of the required field `{}` is ignored",
+                        f.name.0
+                    ));
+                }
+                FieldKind::Required
+            }
+            thrift_parser::Attribute::Optional => FieldKind::Optional,
+            thrift_parser::Attribute::Default if f.xsd_optional => FieldKind::Optional,
+            thrift_parser::Attribute::Default => default_kind,
+        };
+        let default = f.default.as_ref().map(|c| self.lower_lit(c));
+        let (kind, default) = match (kind, self.optional_default) {
+            (FieldKind::Optional, OptionalDefault::OptionNone) => (FieldKind::Optional, None),
+            (FieldKind::Optional, OptionalDefault::Value) if default.is_some() => {
+                (FieldKind::Required, default)
+            }
+            (kind, _) => (kind, default),
+        };
         ir::Field {
             name: self.lower_ident(&f.name),
             id: f.id,
             ty: self.lower_ty(&f.ty),
-            kind: match f.attribute {
-                thrift_parser::Attribute::Required => FieldKind::Required,
-                _ => FieldKind::Optional,
-            },
-            default: f.default.as_ref().map(|c| self.lower_lit(c)),
+            kind,
+            default,
             tags: tags.into(),
         }
     }
@@ -505,13 +670,32 @@ impl ThriftLower {
         }
 
         annotations.iter().for_each(
-            |annotation| with_tags!(annotation -> crate::tags::PilotaName | crate::tags::RustType | crate::tags::RustWrapperArc | crate::tags::SerdeAttribute),
+            |annotation| with_tags!(annotation -> crate::tags::PilotaName | crate::tags::RustType | crate::tags::RustWrapperArc | crate::tags::SerdeAttribute | crate::tags::SerdeFlatten | crate::tags::SerdeBytes | crate::tags::EqIgnore | crate::tags::MethodItemName | crate::tags::ValueRange | crate::tags::StringType),
         );
 
         tags
     }
 
+    /// The defaults of the items of a file, overridden by the annotations of
+    /// the items themselves.
+    fn extract_document_tags(annotations: &Annotations) -> Tags {
+        let mut tags = Tags::default();
+        annotations
+            .iter()
+            .for_each(|annotation| match annotation.key.as_str() {
+                StringType::KEY => tags.insert(StringType::from_str(&annotation.value).unwrap()),
+                DefaultRequiredness::KEY => {
+                    tags.insert(DefaultRequiredness::from_str(&annotation.value).unwrap())
+                }
+                _ => {}
+            });
+        tags
+    }
+
     fn lower_struct(&self, s: &thrift_parser::StructLike) -> ir::Message {
+        if s.xsd_all {
+            self.warn_xsd(format_args!("`xsd_all` of `{}` is ignored", s.name.0));
+        }
         ir::Message {
             name: self.lower_ident(&s.name),
             fields: s.fields.iter().map(|f| self.lower_field(f)).collect(),
@@ -527,7 +711,13 @@ impl ThriftLower {
         // search for the first existing include path
         let target_dir = include_dirs.into_iter().find(|p| {
             let path = p.join(&s.path.0);
-            path.exists()
+            match self.db.file_loader() {
+                // the loaded text is kept for parsing the include
+                Some(_) => {
+                    normalize_loader_path(&path).is_ok_and(|path| self.db.load_file(path).is_ok())
+                }
+                None => path.exists(),
+            }
         });
         let target_path = match target_dir {
             Some(dir) => dir.join(&s.path.0),
@@ -538,7 +728,7 @@ impl ThriftLower {
 
         let ast = self
             .db
-            .parse(target_path.normalize().unwrap().into_path_buf());
+            .parse(normalize_idl_path(&*self.db, &target_path).unwrap());
 
         let file_id = self.lower(ast);
 
@@ -552,7 +742,10 @@ impl Lower<Arc<thrift_parser::File>> for ThriftLower {
             return *file_id;
         }
 
-        println!("cargo:rerun-if-changed={}", f.path.display());
+        // the files of a loader may not be on the disk
+        if f.path.exists() {
+            println!("cargo:rerun-if-changed={}", f.path.display());
+        }
 
         let file_id = self.next_file_id.inc_one();
         self.file_ids_map.insert(f.path.clone(), file_id);
@@ -642,6 +835,27 @@ impl Lower<Arc<thrift_parser::File>> for ThriftLower {
                     .map(|(k, _)| k),
             );
 
+            let mut method_services: FxHashMap<FastStr, usize> = FxHashMap::default();
+            f.items.iter().for_each(|item| {
+                if let thrift_parser::Item::Service(service) = item {
+                    service
+                        .functions
+                        .iter()
+                        .map(|func| {
+                            let tags = this.extract_tags(&func.annotations);
+                            Self::method_name(func, &tags).as_str().upper_camel_ident()
+                        })
+                        .unique()
+                        .for_each(|name| *method_services.entry(name).or_default() += 1);
+                }
+            });
+            this.method_name_duplicates.extend(
+                method_services
+                    .into_iter()
+                    .filter(|(_, n)| *n > 1)
+                    .map(|(k, _)| k),
+            );
+
             let ret = ir::File {
                 package: file_package,
                 items: f
@@ -656,6 +870,7 @@ impl Lower<Arc<thrift_parser::File>> for ThriftLower {
             };
 
             this.service_name_duplicates.clear();
+            this.method_name_duplicates.clear();
             ret
         });
 
@@ -665,10 +880,13 @@ impl Lower<Arc<thrift_parser::File>> for ThriftLower {
     fn finish(self) -> LowerResult {
         self.packages.iter().for_each(|(k, v)| {
             if v.len() > 1 {
-                println!(
-                    "cargo:warning={:?} has the same namespace `{}`, you may need to set namespace for these file \n",
-                    v,
-                    k.segments.iter().join(".")
+                warn(
+                    WarningKind::DuplicateNamespace,
+                    format_args!(
+                        "{:?} has the same namespace `{}`, you may need to set namespace for these file",
+                        v,
+                        k.segments.iter().join(".")
+                    ),
                 )
             }
         });
@@ -679,11 +897,62 @@ impl Lower<Arc<thrift_parser::File>> for ThriftLower {
     }
 }
 
+/// How the items generated for the methods of a thrift service are named.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MethodItemNaming {
+    /// `{Service}{Method}ArgsSend`, `{Service}{Method}ResultRecv`, ...
+    #[default]
+    ServiceMethod,
+    /// `{Method}ArgsSend`, `{Method}ResultRecv`, ... The service name is kept
+    /// for methods whose name is shared by several services of the same file.
+    Method,
+}
+
+/// How the thrift fields which aren't required but have a default value are
+/// generated.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OptionalDefault {
+    /// `Option<T>`, `Some(default)` when the field is missing.
+    #[default]
+    OptionSome,
+    /// `Option<T>`, `None` when the field is missing: the default is ignored.
+    OptionNone,
+    /// `T`, the default when the field is missing, like a required field
+    /// with a default.
+    Value,
+}
+
 #[derive(Default)]
 pub struct ThriftParser {
     files: Vec<PathBuf>,
     db: ThriftSourceDatabase,
     include_dirs: Vec<PathBuf>,
+    method_item_naming: MethodItemNaming,
+    cow_fields: bool,
+    optional_default: OptionalDefault,
+    file_texts: bool,
+}
+
+impl ThriftParser {
+    pub fn method_item_naming(&mut self, naming: MethodItemNaming) {
+        self.method_item_naming = naming;
+    }
+
+    pub fn cow_fields(&mut self, cow_fields: bool) {
+        self.cow_fields = cow_fields;
+    }
+
+    pub fn optional_default(&mut self, optional_default: OptionalDefault) {
+        self.optional_default = optional_default;
+    }
+
+    pub fn file_loader(&mut self, loader: FileLoader) {
+        self.db.file_loader = Some(loader);
+    }
+
+    pub fn parse_mode(&mut self, mode: ParseMode) {
+        self.db.parse_mode = mode;
+    }
 }
 
 impl super::Parser for ThriftParser {
@@ -695,29 +964,47 @@ impl super::Parser for ThriftParser {
         self.include_dirs.extend(dirs);
     }
 
+    fn file_texts(&mut self, keep: bool) {
+        self.file_texts = keep;
+    }
+
     fn parse(self) -> super::ParseResult {
-        let mut lower = ThriftLower::new(self.db.snapshot(), self.include_dirs.clone());
+        let mut lower = ThriftLower::new(
+            self.db.snapshot(),
+            self.include_dirs.clone(),
+            self.method_item_naming,
+            self.cow_fields,
+            self.optional_default,
+        );
         let mut input_files = Vec::default();
 
         self.files.iter().for_each(|f| {
             input_files.push(
                 lower.lower(
                     self.db.parse(
-                        f.to_path_buf()
-                            .normalize()
-                            .unwrap_or_else(|_| panic!("normalize path failed: {}", f.display()))
-                            .into_path_buf(),
+                        normalize_idl_path(&self.db, f)
+                            .unwrap_or_else(|_| panic!("normalize path failed: {}", f.display())),
                     ),
                 ),
             );
         });
 
         let result = lower.finish();
+        let file_texts = if self.file_texts {
+            result
+                .file_ids_map
+                .iter()
+                .map(|(path, file_id)| (*file_id, self.db.file_text(path.to_path_buf())))
+                .collect()
+        } else {
+            Default::default()
+        };
 
         super::ParseResult {
             files: result.files,
             input_files,
             file_ids_map: result.file_ids_map,
+            file_texts,
         }
     }
 }
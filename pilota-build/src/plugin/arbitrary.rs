@@ -0,0 +1,260 @@
+use std::sync::Arc;
+
+use faststr::FastStr;
+use itertools::Itertools;
+
+use crate::{
+    db::RirDatabase,
+    rir::{Field, Item},
+    ty::CodegenTy,
+    Context, DefId, Plugin,
+};
+
+const ARBITRARY: &str = "::pilota::arbitrary::Arbitrary::arbitrary";
+
+/// Implements `arbitrary::Arbitrary` for the generated messages, enums and
+/// newtypes, for fuzzing and property testing of encode/decode.
+///
+/// Items are derived when possible, which requires the `arbitrary` crate (with
+/// the `derive` feature) as a dependency of the crate including the generated
+/// code. Fields of types that don't implement `Arbitrary`, like `FastStr` or
+/// `Bytes`, are generated with the helpers in `pilota::arbitrary`, so the
+/// `arbitrary` feature of `pilota` must be enabled too. Items that can't be
+/// derived, e.g. messages keeping unknown fields, get a manual impl instead.
+///
+/// Recursive types are bounded by the input: once the data is exhausted,
+/// optional fields are `None` and lists are empty.
+#[derive(Clone, Default)]
+pub struct ArbitraryPlugin {
+    feature: Option<FastStr>,
+}
+
+impl ArbitraryPlugin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only implement `Arbitrary` if `feature` of the crate including the
+    /// generated code is enabled.
+    pub fn feature(mut self, feature: impl Into<FastStr>) -> Self {
+        self.feature = Some(feature.into());
+        self
+    }
+
+    fn attr(&self, attr: &str) -> FastStr {
+        match &self.feature {
+            Some(feature) => format!(r#"#[cfg_attr(feature = "{feature}", {attr})]"#).into(),
+            None => format!("#[{attr}]").into(),
+        }
+    }
+
+    // `u` is left to inference: an annotated `Unstructured<'_>` would make the
+    // closure generic over any lifetime, while the derived impls of the items
+    // borrowing for `'cow` need the input to outlive it
+    fn closure(body: String) -> String {
+        format!("|u| {body}")
+    }
+
+    fn call(f: &str) -> String {
+        if f.starts_with('|') {
+            format!("({f})(u)?")
+        } else {
+            format!("{f}(u)?")
+        }
+    }
+
+    /// The function generating `ty`, or `None` if `ty` implements `Arbitrary`.
+    fn arbitrary_fn(&self, ty: &CodegenTy) -> Option<String> {
+        match ty {
+            CodegenTy::FastStr => Some("::pilota::arbitrary::faststr".into()),
+            CodegenTy::Bytes => Some("::pilota::arbitrary::bytes".into()),
+            CodegenTy::OrderedF64 => Some("::pilota::arbitrary::ordered_f64".into()),
+            // `AHashSet` and `AHashMap` are wrappers that don't implement
+            // `Arbitrary` either
+            CodegenTy::Set(ty) => {
+                let f = self.arbitrary_fn(ty).unwrap_or_else(|| ARBITRARY.into());
+                Some(Self::closure(format!(
+                    "::pilota::arbitrary::collect(u, {f})"
+                )))
+            }
+            CodegenTy::Vec(ty) | CodegenTy::BTreeSet(ty) => {
+                let f = self.arbitrary_fn(ty)?;
+                Some(Self::closure(format!(
+                    "::pilota::arbitrary::collect(u, {f})"
+                )))
+            }
+            CodegenTy::Map(k, v) | CodegenTy::BTreeMap(k, v) => {
+                let (k, v) = (self.arbitrary_fn(k), self.arbitrary_fn(v));
+                if matches!(ty, CodegenTy::BTreeMap(..)) && k.is_none() && v.is_none() {
+                    return None;
+                }
+                let k = k.unwrap_or_else(|| ARBITRARY.into());
+                let v = v.unwrap_or_else(|| ARBITRARY.into());
+                Some(Self::closure(format!(
+                    "::pilota::arbitrary::collect_map(u, {k}, {v})"
+                )))
+            }
+            CodegenTy::Arc(ty) => {
+                let f = self.arbitrary_fn(ty)?;
+                Some(Self::closure(format!("::pilota::arbitrary::arc(u, {f})")))
+            }
+            // the `Cow` impl of `arbitrary` borrows from the input, for the
+            // lifetime of `Arbitrary` rather than the one of the item
+            CodegenTy::Cow(ty) => {
+                let f = self.arbitrary_fn(ty).unwrap_or_else(|| ARBITRARY.into());
+                Some(Self::closure(format!("::pilota::arbitrary::cow(u, {f})")))
+            }
+            _ => None,
+        }
+    }
+
+    fn field_fn(&self, cx: &Context, f: &Field) -> Option<String> {
+        // boxed fields are always adts, which implement `Arbitrary`
+        let ty = cx.codegen_item_ty(f.ty.kind.clone());
+        let inner = self.arbitrary_fn(&ty)?;
+        if f.is_optional() {
+            Some(Self::closure(format!(
+                "::pilota::arbitrary::option(u, {inner})"
+            )))
+        } else {
+            Some(inner)
+        }
+    }
+
+    fn impl_arbitrary(&self, cx: &Context, def_id: DefId, body: &str) -> FastStr {
+        let cfg = self
+            .feature
+            .as_ref()
+            .map(|feature| format!(r#"#[cfg(feature = "{feature}")]"#))
+            .unwrap_or_default();
+        let name = cx.rust_name(def_id);
+        let generics = cx.item_generics(def_id);
+        // the lifetime of `Arbitrary` goes before the generics of the item
+        let params = match generics.strip_prefix('<') {
+            Some(params) => format!("<'a, {params}"),
+            None => "<'a>".into(),
+        };
+        format!(
+            r#"
+            {cfg}
+            impl{params} ::pilota::arbitrary::Arbitrary<'a> for {name}{generics} {{
+                fn arbitrary(u: &mut ::pilota::arbitrary::Unstructured<'a>) -> ::pilota::arbitrary::Result<Self> {{
+                    {body}
+                }}
+            }}
+            "#
+        )
+        .into()
+    }
+
+    fn derive(&self, cx: &Context, def_id: DefId) {
+        cx.with_adjust_mut(def_id, |adj| {
+            adj.add_attrs(&[self.attr("derive(::pilota::arbitrary::Arbitrary)")])
+        });
+    }
+}
+
+impl Plugin for ArbitraryPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        match &*item {
+            Item::Message(m) if cx.keep_unknown_fields.contains(&def_id) => {
+                let fields = m
+                    .fields
+                    .iter()
+                    .map(|f| {
+                        let f_name = cx.rust_name(f.did);
+                        let f = self.field_fn(cx, f).unwrap_or_else(|| ARBITRARY.into());
+                        format!("{f_name}: {},", Self::call(&f))
+                    })
+                    .join("\n");
+                let body = format!(
+                    "::std::result::Result::Ok(Self {{ {fields} _unknown_fields: ::pilota::LinkedBytes::new() }})"
+                );
+                cx.with_adjust_mut(def_id, |adj| {
+                    adj.add_nested_item(self.impl_arbitrary(cx, def_id, &body))
+                });
+            }
+            Item::Message(m) => {
+                self.derive(cx, def_id);
+                for f in &m.fields {
+                    if let Some(with) = self.field_fn(cx, f) {
+                        cx.with_adjust_mut(f.did, |adj| {
+                            adj.add_attrs(&[self.attr(&format!("arbitrary(with = {with})"))])
+                        });
+                    }
+                }
+            }
+            Item::Enum(e) if e.repr.is_some() => {
+                // only generate the known values, so that the decoded enum is
+                // the same as the encoded one
+                let variants = e
+                    .variants
+                    .iter()
+                    .map(|v| format!("Self::{}", cx.rust_name(v.did)))
+                    .join(", ");
+                let body = format!("u.choose(&[{variants}]).cloned()");
+                cx.with_adjust_mut(def_id, |adj| {
+                    adj.add_nested_item(self.impl_arbitrary(cx, def_id, &body))
+                });
+            }
+            Item::Enum(e) => {
+                let fns = e
+                    .variants
+                    .iter()
+                    .map(|v| {
+                        v.fields
+                            .iter()
+                            .map(|ty| self.arbitrary_fn(&cx.codegen_item_ty(ty.kind.clone())))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+                let has_unknown_fields = cx.keep_unknown_fields.contains(&def_id)
+                    && e.variants.iter().all(|v| !v.fields.is_empty());
+
+                if !has_unknown_fields && fns.iter().flatten().all(Option::is_none) {
+                    self.derive(cx, def_id);
+                } else {
+                    let arms = e
+                        .variants
+                        .iter()
+                        .zip(fns)
+                        .enumerate()
+                        .map(|(i, (v, fns))| {
+                            let v_name = cx.rust_name(v.did);
+                            if fns.is_empty() {
+                                return format!("{i} => Self::{v_name},");
+                            }
+                            let fields = fns
+                                .into_iter()
+                                .map(|f| Self::call(&f.unwrap_or_else(|| ARBITRARY.into())))
+                                .join(", ");
+                            format!("{i} => Self::{v_name}({fields}),")
+                        })
+                        .join("\n");
+                    let len = e.variants.len();
+                    let body = format!(
+                        r#"::std::result::Result::Ok(match u.choose_index({len})? {{
+                            {arms}
+                            _ => unreachable!(),
+                        }})"#
+                    );
+                    cx.with_adjust_mut(def_id, |adj| {
+                        adj.add_nested_item(self.impl_arbitrary(cx, def_id, &body))
+                    });
+                }
+            }
+            Item::NewType(t) => match self.arbitrary_fn(&cx.codegen_item_ty(t.ty.kind.clone())) {
+                Some(f) => {
+                    let body = format!("::std::result::Result::Ok(Self({}))", Self::call(&f));
+                    cx.with_adjust_mut(def_id, |adj| {
+                        adj.add_nested_item(self.impl_arbitrary(cx, def_id, &body))
+                    });
+                }
+                None => self.derive(cx, def_id),
+            },
+            _ => {}
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
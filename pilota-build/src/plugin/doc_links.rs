@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use faststr::FastStr;
+use itertools::Itertools;
+
+use crate::{
+    rir::{Item, Path},
+    ty::{Ty, Visitor},
+    Context, DefId, Plugin,
+};
+
+/// Documents the fields and variants referencing generated types with
+/// intra-doc links to them, e.g. ``[`Other`](super::other::Other)``. The
+/// links are relative to the module of the item, or go through the crate
+/// name in workspaces. Primitive types aren't linked.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct DocLinksPlugin;
+
+impl DocLinksPlugin {
+    fn doc(cx: &Context, def_id: DefId, ty: &Ty) -> Option<FastStr> {
+        struct PathCollector(Vec<DefId>);
+
+        impl Visitor for PathCollector {
+            fn visit_path(&mut self, path: &Path) {
+                self.0.push(path.did)
+            }
+        }
+
+        let mut paths = PathCollector(Vec::new());
+        paths.visit(ty);
+        let links = paths
+            .0
+            .into_iter()
+            .unique()
+            .map(|did| {
+                format!(
+                    "[`{}`]({})",
+                    cx.rust_name(did),
+                    cx.related_item_path(def_id, did)
+                )
+            })
+            .join(", ");
+        (!links.is_empty()).then(|| format!(r#"#[doc = "{links}"]"#).into())
+    }
+}
+
+impl Plugin for DocLinksPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        match &*item {
+            Item::Message(m) => m.fields.iter().for_each(|f| {
+                if let Some(doc) = Self::doc(cx, def_id, &f.ty) {
+                    cx.with_adjust_mut(f.did, |adj| adj.add_attrs(&[doc]));
+                }
+            }),
+            Item::Enum(e) => e.variants.iter().for_each(|v| {
+                if let Some(doc) = v.fields.iter().find_map(|ty| Self::doc(cx, def_id, ty)) {
+                    cx.with_adjust_mut(v.did, |adj| adj.add_attrs(&[doc]));
+                }
+            }),
+            _ => {}
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
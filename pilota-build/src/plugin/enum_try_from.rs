@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use faststr::FastStr;
+use itertools::Itertools;
+
+use crate::{rir::Item, symbol::IdentName, Context, DefId, Plugin};
+
+/// The pattern matching the discriminants of the variants of `e`, `None` if
+/// it has no variant.
+fn discrs_pattern(cx: &Context, e: &crate::rir::Enum) -> Option<String> {
+    let discrs = e
+        .variants
+        .iter()
+        .map(|v| cx.variant_discr(v.did))
+        .sorted()
+        .dedup()
+        .join(" | ");
+    (!discrs.is_empty()).then_some(discrs)
+}
+
+/// Implements `TryFrom<i64>` for the enums, accepting only the discriminants
+/// of their variants. The values out of the `i32` range are never one.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct EnumTryFromI64Plugin;
+
+impl EnumTryFromI64Plugin {
+    fn impl_try_from(cx: &Context, def_id: DefId, e: &crate::rir::Enum) -> FastStr {
+        let name = cx.rust_name(def_id);
+        let known = discrs_pattern(cx, e)
+            .map(|discrs| format!("{discrs} => ::std::result::Result::Ok(Self(value as i32)),"))
+            .unwrap_or_default();
+        format!(
+            r#"
+            impl ::std::convert::TryFrom<i64> for {name} {{
+                type Error = ::pilota::EnumConvertError<i64>;
+
+                fn try_from(value: i64) -> ::std::result::Result<Self, Self::Error> {{
+                    match value {{
+                        {known}
+                        _ => ::std::result::Result::Err(::pilota::EnumConvertError::InvalidNum(value, "{name}")),
+                    }}
+                }}
+            }}
+            "#
+        )
+        .into()
+    }
+}
+
+impl Plugin for EnumTryFromI64Plugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        if let Item::Enum(e) = &*item {
+            if e.repr.is_some() {
+                let nested = Self::impl_try_from(cx, def_id, e);
+                cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(nested));
+            }
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
+
+/// Generates `const fn`s converting the enums from and to their `i32`:
+/// `as_i32`, and `from_i32_checked` which is `None` for the values which
+/// aren't the discriminant of a variant, as `TryFrom` can't be used in const
+/// contexts.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ConstEnumFnsPlugin;
+
+impl ConstEnumFnsPlugin {
+    fn const_fns(cx: &Context, def_id: DefId, e: &crate::rir::Enum) -> FastStr {
+        let name = cx.rust_name(def_id);
+        let known = discrs_pattern(cx, e)
+            .map(|discrs| format!("{discrs} => ::std::option::Option::Some(Self(value)),"))
+            .unwrap_or_default();
+        format!(
+            r#"
+            impl {name} {{
+                pub const fn as_i32(&self) -> i32 {{
+                    self.0
+                }}
+
+                pub const fn from_i32_checked(value: i32) -> ::std::option::Option<Self> {{
+                    match value {{
+                        {known}
+                        _ => ::std::option::Option::None,
+                    }}
+                }}
+            }}
+            "#
+        )
+        .into()
+    }
+}
+
+impl Plugin for ConstEnumFnsPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        if let Item::Enum(e) = &*item {
+            if e.repr.is_some() {
+                let nested = Self::const_fns(cx, def_id, e);
+                cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(nested));
+            }
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
+
+/// Generates a closed rust enum `{Name}Enum` for each enum with variants,
+/// with one variant per discriminant, and converts the enum to it with
+/// `as_enum`, which is `None` for the unknown values, and back with `From`.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ClosedEnumPlugin;
+
+impl ClosedEnumPlugin {
+    fn closed_enum(cx: &Context, def_id: DefId, e: &crate::rir::Enum) -> Option<FastStr> {
+        let name = cx.rust_name(def_id);
+        let closed = format!("{name}Enum");
+        // the aliases share the variant of the first one
+        let variants = e
+            .variants
+            .iter()
+            .map(|v| {
+                let variant: FastStr = if cx.change_case_of(def_id) {
+                    (&**v.name).variant_ident()
+                } else {
+                    v.name.0.clone()
+                };
+                (cx.variant_discr(v.did), variant)
+            })
+            .unique_by(|(discr, _)| *discr)
+            .collect_vec();
+        if variants.is_empty() {
+            return None;
+        }
+
+        let decl = variants
+            .iter()
+            .map(|(discr, v)| format!("{v} = {discr},"))
+            .join("\n");
+        let arms = variants
+            .iter()
+            .map(|(discr, v)| format!("{discr} => ::std::option::Option::Some({closed}::{v}),"))
+            .join("\n");
+        Some(
+            format!(
+                r#"
+                #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+                #[repr(i32)]
+                pub enum {closed} {{
+                    {decl}
+                }}
+
+                impl {name} {{
+                    pub fn as_enum(&self) -> ::std::option::Option<{closed}> {{
+                        match self.0 {{
+                            {arms}
+                            _ => ::std::option::Option::None,
+                        }}
+                    }}
+                }}
+
+                impl ::std::convert::From<{closed}> for {name} {{
+                    fn from(value: {closed}) -> Self {{
+                        Self(value as i32)
+                    }}
+                }}
+                "#
+            )
+            .into(),
+        )
+    }
+}
+
+impl Plugin for ClosedEnumPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        if let Item::Enum(e) = &*item {
+            if e.repr.is_some() {
+                if let Some(nested) = Self::closed_enum(cx, def_id, e) {
+                    cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(nested));
+                }
+            }
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
+
+/// Lists the variants of the enums in declaration order, aliases included,
+/// in `const ALL: &[Self]`, and iterates over them with `iter`. The unknown
+/// values aren't variants.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct EnumVariantsPlugin;
+
+impl EnumVariantsPlugin {
+    fn variants(cx: &Context, def_id: DefId, e: &crate::rir::Enum) -> FastStr {
+        let name = cx.rust_name(def_id);
+        let all = e
+            .variants
+            .iter()
+            .map(|v| format!("Self::{}", cx.rust_name(v.did)))
+            .join(", ");
+        format!(
+            r#"
+            impl {name} {{
+                pub const ALL: &'static [Self] = &[{all}];
+
+                pub fn iter() -> impl ::std::iter::Iterator<Item = Self> {{
+                    Self::ALL.iter().copied()
+                }}
+            }}
+            "#
+        )
+        .into()
+    }
+}
+
+impl Plugin for EnumVariantsPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        if let Item::Enum(e) = &*item {
+            if e.repr.is_some() {
+                let nested = Self::variants(cx, def_id, e);
+                cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(nested));
+            }
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
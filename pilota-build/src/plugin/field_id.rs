@@ -0,0 +1,128 @@
+use std::{path::Path, sync::Arc};
+
+use faststr::FastStr;
+use itertools::Itertools;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::{
+    db::RirDatabase,
+    rir::{Item, NodeKind},
+    Context, DefId, Plugin,
+};
+
+/// Asserts at compile time that the fields recorded in a baseline kept their
+/// ids. The baseline has a `pkg::Message::field = id` line per field; fields
+/// added since are fine, but a changed or removed id fails the compilation of
+/// the generated code, and a removed message fails the build.
+pub(crate) struct FieldIdBaselinePlugin {
+    /// The recorded `(field, id)`s, by message
+    baseline: FxHashMap<String, Vec<(String, i32)>>,
+}
+
+impl FieldIdBaselinePlugin {
+    /// Reads the baseline at `path`, `None` if there's none yet.
+    pub(crate) fn read(path: &Path) -> Option<Self> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return None,
+            Err(e) => panic!("read field id baseline {} failed: {e}", path.display()),
+        };
+
+        let mut baseline = FxHashMap::<_, Vec<_>>::default();
+        for (i, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let entry = line.split_once('=').and_then(|(path, id)| {
+                let (message, field) = path.trim().rsplit_once("::")?;
+                Some((
+                    message.to_string(),
+                    field.to_string(),
+                    id.trim().parse().ok()?,
+                ))
+            });
+            let Some((message, field, id)) = entry else {
+                panic!(
+                    "{}:{}: expected `pkg::Message::field = id`, found `{line}`",
+                    path.display(),
+                    i + 1
+                )
+            };
+            baseline.entry(message).or_default().push((field, id));
+        }
+        Some(Self { baseline })
+    }
+
+    /// The baseline of the ids of the fields of the generated messages.
+    pub(crate) fn snapshot(cx: &Context) -> String {
+        cx.codegen_items
+            .iter()
+            .filter_map(|def_id| match cx.item(*def_id).as_deref() {
+                Some(Item::Message(m)) => Some(
+                    m.fields
+                        .iter()
+                        .map(|f| format!("{} = {}\n", cx.def_id_info(f.did), f.id))
+                        .collect::<Vec<_>>(),
+                ),
+                _ => None,
+            })
+            .flatten()
+            .sorted()
+            .collect()
+    }
+}
+
+impl Plugin for FieldIdBaselinePlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        if let Item::Message(m) = &*item {
+            let message = cx.def_id_info(def_id);
+            if let Some(recorded) = self.baseline.remove(&*message) {
+                let asserts = recorded
+                    .iter()
+                    .filter_map(|(name, id)| {
+                        match m.fields.iter().find(|f| &**f.name == name) {
+                            Some(f) if f.id == *id => None,
+                            Some(f) => Some(format!(
+                                r#"const _: () = ::std::assert!({} == {id}, "the id of `{message}::{name}` changed from {id} to {}");"#,
+                                f.id, f.id
+                            )),
+                            None => Some(format!(
+                                r#"const _: () = ::std::assert!(false, "`{message}::{name}` with id {id} was removed or renamed");"#
+                            )),
+                        }
+                    })
+                    .join("\n");
+                if !asserts.is_empty() {
+                    let asserts = FastStr::from(asserts);
+                    cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(asserts));
+                }
+            }
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+
+    fn on_emit(&mut self, cx: &Context) {
+        // the messages which are still in the idls but aren't generated have
+        // nothing to check
+        let messages = cx
+            .nodes()
+            .iter()
+            .filter(|(_, node)| matches!(node.kind, NodeKind::Item(ref item) if matches!(**item, Item::Message(_))))
+            .map(|(def_id, _)| cx.def_id_info(*def_id))
+            .collect::<FxHashSet<_>>();
+        let removed = self
+            .baseline
+            .keys()
+            .filter(|message| !messages.contains(message.as_str()))
+            .sorted()
+            .collect::<Vec<_>>();
+        if !removed.is_empty() {
+            panic!(
+                "the messages {} of the field id baseline were removed or renamed, delete the baseline to accept it",
+                removed.iter().map(|message| format!("`{message}`")).join(", ")
+            )
+        }
+    }
+}
@@ -0,0 +1,302 @@
+use std::sync::Arc;
+
+use faststr::FastStr;
+use itertools::Itertools;
+
+use crate::{rir::Item, Context, DefId, Plugin};
+
+const FIELD_MAP: &str = "::pilota::field_map";
+
+/// Generates `to_field_map` and `from_field_map` on the messages, converting
+/// them from and to a map of their field names to `pilota::field_map::Value`s,
+/// and implements `ToValue` and `FromValue` for the messages, enums and
+/// newtypes they contain.
+///
+/// Enums are the name of their variant, or their value if it is unknown.
+/// Unions are a message with a single entry, the name of the set variant.
+/// The unset fields packed by `packed_presence` have no entry, as the unset
+/// `Option`s.
+#[derive(Clone, Copy, Default)]
+pub struct FieldMapPlugin;
+
+impl FieldMapPlugin {
+    fn impl_value(cx: &Context, def_id: DefId, to_value: &str, from_value: &str) -> FastStr {
+        let name = cx.rust_name(def_id);
+        let generics = cx.item_generics(def_id);
+        format!(
+            r#"
+            impl{generics} {FIELD_MAP}::ToValue for {name}{generics} {{
+                fn to_value(&self) -> {FIELD_MAP}::Value {{
+                    {to_value}
+                }}
+            }}
+
+            impl{generics} {FIELD_MAP}::FromValue for {name}{generics} {{
+                fn from_value(value: {FIELD_MAP}::Value) -> ::std::result::Result<Self, {FIELD_MAP}::FieldMapError> {{
+                    {from_value}
+                }}
+            }}
+            "#
+        )
+        .into()
+    }
+
+    fn message(&self, cx: &Context, def_id: DefId, m: &crate::rir::Message) -> FastStr {
+        let name = cx.rust_name(def_id);
+
+        let inserts = m
+            .fields
+            .iter()
+            .map(|f| {
+                let f_name = cx.rust_name(f.did);
+                let key = &f.name;
+                if let Some(value) = cx.packed_value(f, "self") {
+                    format!(
+                        r#"if let ::std::option::Option::Some(value) = {value} {{
+                            map.insert("{key}".to_string(), {FIELD_MAP}::ToValue::to_value(&value));
+                        }}"#
+                    )
+                } else if f.is_optional() {
+                    format!(
+                        r#"if let ::std::option::Option::Some(value) = &self.{f_name} {{
+                            map.insert("{key}".to_string(), {FIELD_MAP}::ToValue::to_value(value));
+                        }}"#
+                    )
+                } else {
+                    format!(
+                        r#"map.insert("{key}".to_string(), {FIELD_MAP}::ToValue::to_value(&self.{f_name}));"#
+                    )
+                }
+            })
+            .join("\n");
+
+        let mut fields = m
+            .fields
+            .iter()
+            .map(|f| {
+                let f_name = cx.rust_name(f.did);
+                let get = if f.is_optional() {
+                    "optional"
+                } else {
+                    "required"
+                };
+                if cx.presence_bit(f).is_some() {
+                    format!(r#"{f_name}: {f_name}.unwrap_or_default(),"#)
+                } else {
+                    format!(r#"{f_name}: {FIELD_MAP}::{get}(&mut map, "{}")?,"#, f.name)
+                }
+            })
+            .join("\n");
+        // the packed fields are read first, as their presence is built from them
+        let packed = m
+            .fields
+            .iter()
+            .filter(|f| cx.presence_bit(f).is_some())
+            .map(|f| {
+                format!(
+                    r#"let {} = {FIELD_MAP}::optional(&mut map, "{}")?;"#,
+                    cx.rust_name(f.did),
+                    f.name
+                )
+            })
+            .join("\n");
+        if let Some(presence) = cx.presence_init(m, |f| cx.rust_name(f.did).to_string()) {
+            fields.push_str(&format!("{presence},"));
+        }
+        if cx.keep_unknown_fields.contains(&def_id) {
+            fields.push_str("_unknown_fields: ::pilota::LinkedBytes::new(),");
+        }
+
+        let len = m.fields.len();
+        let generics = cx.item_generics(def_id);
+        let methods = format!(
+            r#"
+            impl{generics} {name}{generics} {{
+                pub fn to_field_map(&self) -> {FIELD_MAP}::FieldMap {{
+                    let mut map = {FIELD_MAP}::FieldMap::with_capacity({len});
+                    {inserts}
+                    map
+                }}
+
+                pub fn from_field_map(mut map: {FIELD_MAP}::FieldMap) -> ::std::result::Result<Self, {FIELD_MAP}::FieldMapError> {{
+                    {packed}
+                    ::std::result::Result::Ok(Self {{
+                        {fields}
+                    }})
+                }}
+            }}
+            "#
+        );
+
+        let impl_value = Self::impl_value(
+            cx,
+            def_id,
+            &format!("{FIELD_MAP}::Value::Message(self.to_field_map())"),
+            &format!(
+                r#"match value {{
+                    {FIELD_MAP}::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err({FIELD_MAP}::FieldMapError::invalid("a message", value)),
+                }}"#
+            ),
+        );
+
+        format!("{methods}{impl_value}").into()
+    }
+
+    fn enum_by_name(&self, cx: &Context, def_id: DefId, e: &crate::rir::Enum) -> FastStr {
+        let name = cx.rust_name(def_id);
+
+        let to_names = e
+            .variants
+            .iter()
+            .map(|v| {
+                format!(
+                    r#"{} => {FIELD_MAP}::Value::String("{}".to_string()),"#,
+                    v.discr.unwrap(),
+                    v.name
+                )
+            })
+            .join("\n");
+        let from_names = e
+            .variants
+            .iter()
+            .map(|v| {
+                format!(
+                    r#""{}" => ::std::result::Result::Ok(Self::{}),"#,
+                    v.name,
+                    cx.rust_name(v.did)
+                )
+            })
+            .join("\n");
+
+        Self::impl_value(
+            cx,
+            def_id,
+            &format!(
+                r#"match self.inner() {{
+                    {to_names}
+                    value => {FIELD_MAP}::Value::I64(value as i64),
+                }}"#
+            ),
+            &format!(
+                r#"match value {{
+                    {FIELD_MAP}::Value::String(variant) => match variant.as_str() {{
+                        {from_names}
+                        _ => ::std::result::Result::Err({FIELD_MAP}::FieldMapError::invalid(
+                            "a variant of `{name}`",
+                            {FIELD_MAP}::Value::String(variant),
+                        )),
+                    }},
+                    value => <i32 as {FIELD_MAP}::FromValue>::from_value(value).map(Self::from),
+                }}"#
+            ),
+        )
+    }
+
+    fn union(&self, cx: &Context, def_id: DefId, e: &crate::rir::Enum) -> FastStr {
+        let name = cx.rust_name(def_id);
+        let message = |key: &str, value: &str| {
+            format!(
+                r#"{FIELD_MAP}::Value::Message(::std::iter::once(("{key}".to_string(), {value})).collect())"#
+            )
+        };
+
+        let mut to_arms = e
+            .variants
+            .iter()
+            .map(|v| {
+                let v_name = cx.rust_name(v.did);
+                if v.fields.is_empty() {
+                    let empty =
+                        format!("{FIELD_MAP}::Value::Message({FIELD_MAP}::FieldMap::new())");
+                    format!("Self::{v_name} => {},", message(&v.name, &empty))
+                } else {
+                    let value = format!("{FIELD_MAP}::ToValue::to_value(value)");
+                    format!("Self::{v_name}(value) => {},", message(&v.name, &value))
+                }
+            })
+            .join("\n");
+        let has_unknown_fields = cx.keep_unknown_fields.contains(&def_id)
+            && e.variants.iter().all(|v| !v.fields.is_empty());
+        let has_unknown_variant = cx.has_unknown_variant(def_id);
+        if has_unknown_fields || has_unknown_variant {
+            to_arms.push_str(&format!(
+                "_ => {FIELD_MAP}::Value::Message({FIELD_MAP}::FieldMap::new()),"
+            ));
+        }
+
+        let from_arms = e
+            .variants
+            .iter()
+            .map(|v| {
+                let v_name = cx.rust_name(v.did);
+                let key = &v.name;
+                if v.fields.is_empty() {
+                    format!(r#""{key}" => ::std::result::Result::Ok(Self::{v_name}),"#)
+                } else {
+                    format!(
+                        r#""{key}" => ::std::result::Result::Ok(Self::{v_name}({FIELD_MAP}::from_field("{key}", value)?)),"#
+                    )
+                }
+            })
+            .join("\n");
+        let empty = if has_unknown_variant {
+            "if map.is_empty() { return ::std::result::Result::Ok(Self::__Unknown); }"
+        } else {
+            ""
+        };
+
+        Self::impl_value(
+            cx,
+            def_id,
+            &format!(
+                r#"match self {{
+                    {to_arms}
+                }}"#
+            ),
+            &format!(
+                r#"let map = match value {{
+                    {FIELD_MAP}::Value::Message(map) => map,
+                    value => return ::std::result::Result::Err({FIELD_MAP}::FieldMapError::invalid("a message", value)),
+                }};
+                {empty}
+                if map.len() != 1 {{
+                    return ::std::result::Result::Err({FIELD_MAP}::FieldMapError::invalid(
+                        "one variant of `{name}`",
+                        {FIELD_MAP}::Value::Message(map),
+                    ));
+                }}
+                let (variant, value) = map.into_iter().next().unwrap();
+                match variant.as_str() {{
+                    {from_arms}
+                    _ => ::std::result::Result::Err({FIELD_MAP}::FieldMapError::invalid(
+                        "a variant of `{name}`",
+                        {FIELD_MAP}::Value::Message(::std::iter::once((variant, value)).collect()),
+                    )),
+                }}"#
+            ),
+        )
+    }
+}
+
+impl Plugin for FieldMapPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        let nested = match &*item {
+            Item::Message(m) => Some(self.message(cx, def_id, m)),
+            Item::Enum(e) if e.repr.is_some() => Some(self.enum_by_name(cx, def_id, e)),
+            Item::Enum(e) => Some(self.union(cx, def_id, e)),
+            Item::NewType(_) => Some(Self::impl_value(
+                cx,
+                def_id,
+                &format!("{FIELD_MAP}::ToValue::to_value(&self.0)"),
+                &format!("{FIELD_MAP}::FromValue::from_value(value).map(Self)"),
+            )),
+            _ => None,
+        };
+        if let Some(nested) = nested {
+            cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(nested));
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
@@ -0,0 +1,199 @@
+use std::{collections::HashSet, sync::Arc};
+
+use faststr::FastStr;
+use itertools::Itertools;
+
+use super::{derive_hash_predicate, walk_item, AutoDerivePlugin, CanDerive, PredicateResult};
+use crate::{
+    db::RirDatabase,
+    rir::Item,
+    ty::{self, Ty},
+    Context, DefId, Plugin,
+};
+
+type Predicate = fn(&Ty) -> PredicateResult;
+
+/// Implements `Hash` for the types that can't derive it only because of their
+/// `AHashMap` and `AHashSet` fields, hashing their entries with
+/// `pilota::hash::hash_unordered`. The types only containing such types
+/// derive it.
+pub(crate) struct ManualHashPlugin {
+    /// Whether a type implements `Hash`, derived or not
+    hashable: AutoDerivePlugin<Predicate>,
+    /// Whether a type derives `Hash` without this plugin
+    derivable: AutoDerivePlugin<Predicate>,
+}
+
+impl Default for ManualHashPlugin {
+    fn default() -> Self {
+        Self {
+            hashable: AutoDerivePlugin::new(Arc::from([]), Self::hashable as Predicate)
+                .skip_eq_ignored(),
+            derivable: AutoDerivePlugin::new(Arc::from([]), derive_hash_predicate),
+        }
+    }
+}
+
+impl ManualHashPlugin {
+    /// Maps and sets are hashable at the top of a field, if their entries
+    /// are.
+    fn hashable(ty: &Ty) -> PredicateResult {
+        fn has_entries(ty: &Ty) -> bool {
+            match &ty.kind {
+                ty::F32 | ty::F64 | ty::Map(_, _) | ty::Set(_) => false,
+                ty::Vec(ty) | ty::BTreeSet(ty) | ty::Arc(ty) | ty::Cow(ty) | ty::BoxedSlice(ty) => {
+                    has_entries(ty)
+                }
+                ty::BTreeMap(k, v) => has_entries(k) && has_entries(v),
+                _ => true,
+            }
+        }
+
+        let hashable = match &Self::strip_arc(ty).kind {
+            ty::Map(k, v) => has_entries(k) && has_entries(v),
+            ty::Set(ty) => has_entries(ty),
+            _ => has_entries(ty),
+        };
+        if hashable {
+            PredicateResult::GoOn
+        } else {
+            PredicateResult::No
+        }
+    }
+
+    fn strip_arc(mut ty: &Ty) -> &Ty {
+        while let ty::Arc(inner) = &ty.kind {
+            ty = inner;
+        }
+        ty
+    }
+
+    fn is_unordered(ty: &Ty) -> bool {
+        matches!(Self::strip_arc(ty).kind, ty::Map(_, _) | ty::Set(_))
+    }
+
+    fn hash(value: &str, ty: &Ty) -> String {
+        if Self::is_unordered(ty) {
+            format!("::pilota::hash::hash_unordered({value}.iter(), state);")
+        } else {
+            format!("::std::hash::Hash::hash(&{value}, state);")
+        }
+    }
+
+    fn impl_hash(cx: &Context, def_id: DefId, body: &str) -> FastStr {
+        let name = cx.rust_name(def_id);
+        let generics = cx.item_generics(def_id);
+        format!(
+            r#"
+            impl{generics} ::std::hash::Hash for {name}{generics} {{
+                fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {{
+                    {body}
+                }}
+            }}
+            "#
+        )
+        .into()
+    }
+
+    fn message(cx: &Context, def_id: DefId, m: &crate::rir::Message) -> FastStr {
+        let mut body = m
+            .fields
+            .iter()
+            .map(|f| {
+                let name = cx.rust_name(f.did);
+                if f.is_optional() && Self::is_unordered(&f.ty) {
+                    format!(
+                        r#"::std::hash::Hash::hash(&self.{name}.is_some(), state);
+                        if let ::std::option::Option::Some(value) = &self.{name} {{
+                            {}
+                        }}"#,
+                        Self::hash("value", &f.ty)
+                    )
+                } else {
+                    Self::hash(&format!("self.{name}"), &f.ty)
+                }
+            })
+            .join("\n");
+        if cx.keep_unknown_fields.contains(&def_id) {
+            body.push_str("::std::hash::Hash::hash(&self._unknown_fields, state);");
+        }
+        Self::impl_hash(cx, def_id, &body)
+    }
+
+    fn union(cx: &Context, def_id: DefId, e: &crate::rir::Enum) -> FastStr {
+        let mut arms = e
+            .variants
+            .iter()
+            .map(|v| {
+                let name = cx.rust_name(v.did);
+                match &*v.fields {
+                    [] => format!("Self::{name} => {{}}"),
+                    [ty] => format!("Self::{name}(value) => {{ {} }}", Self::hash("value", ty)),
+                    _ => unreachable!("a variant has one field at most"),
+                }
+            })
+            .join("\n");
+        let has_unknown_fields = cx.keep_unknown_fields.contains(&def_id)
+            && e.variants.iter().all(|v| !v.fields.is_empty());
+        if has_unknown_fields || cx.has_unknown_variant(def_id) {
+            arms.push_str("_ => {}");
+        }
+        Self::impl_hash(
+            cx,
+            def_id,
+            &format!(
+                r#"::std::hash::Hash::hash(&::std::mem::discriminant(self), state);
+                match self {{
+                    {arms}
+                }}"#
+            ),
+        )
+    }
+}
+
+impl Plugin for ManualHashPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        self.hashable
+            .can_derive(cx, def_id, &mut HashSet::default(), &mut HashSet::default());
+        self.derivable
+            .can_derive(cx, def_id, &mut HashSet::default(), &mut HashSet::default());
+        walk_item(self, cx, def_id, item)
+    }
+
+    fn on_emit(&mut self, cx: &Context) {
+        let manual = self
+            .hashable
+            .can_derive
+            .iter()
+            .filter(|(def_id, hashable)| {
+                **hashable != CanDerive::No
+                    && self.derivable.can_derive.get(def_id) == Some(&CanDerive::No)
+            })
+            .map(|(def_id, _)| *def_id)
+            .collect::<Vec<_>>();
+
+        for def_id in manual {
+            let item = cx.expect_item(def_id);
+            let tys = match &*item {
+                Item::Message(m) => m.fields.iter().map(|f| &f.ty).collect::<Vec<_>>(),
+                Item::Enum(e) => e.variants.iter().flat_map(|v| &v.fields).collect(),
+                Item::NewType(t) => vec![&t.ty],
+                _ => continue,
+            };
+            // the types containing maps and sets only through other types
+            // derive it
+            if !tys.iter().any(|ty| Self::is_unordered(ty)) {
+                cx.with_adjust_mut(def_id, |adj| adj.add_attrs(&["#[derive(Hash)]".into()]));
+                continue;
+            }
+
+            let nested = match &*item {
+                Item::Message(m) => Self::message(cx, def_id, m),
+                Item::Enum(e) => Self::union(cx, def_id, e),
+                Item::NewType(t) => Self::impl_hash(cx, def_id, &Self::hash("self.0", &t.ty)),
+                _ => unreachable!(),
+            };
+            cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(nested));
+        }
+    }
+}
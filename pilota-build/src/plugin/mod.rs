@@ -6,17 +6,38 @@ use rustc_hash::FxHashMap;
 
 use crate::{
     db::RirDatabase,
-    middle::context::tls::CUR_ITEM,
+    middle::context::{tls::CUR_ITEM, DefaultStrategy},
     rir::{EnumVariant, Field, Item, NodeKind},
     symbol::DefId,
     ty::{self, Ty, Visitor},
     Context,
 };
 
+mod arbitrary;
+mod doc_links;
+mod enum_try_from;
+mod field_id;
+mod field_map;
+mod hash;
+mod prost;
+mod schema;
 mod serde;
+mod stable_hash;
+mod visit;
 mod workspace;
 
-pub use self::serde::SerdePlugin;
+pub use self::{
+    arbitrary::ArbitraryPlugin, field_map::FieldMapPlugin, prost::ProstConvertPlugin,
+    schema::SchemaPlugin, serde::SerdePlugin, stable_hash::StableHashPlugin, visit::VisitPlugin,
+};
+pub(crate) use self::{
+    doc_links::DocLinksPlugin,
+    enum_try_from::{
+        ClosedEnumPlugin, ConstEnumFnsPlugin, EnumTryFromI64Plugin, EnumVariantsPlugin,
+    },
+    field_id::FieldIdBaselinePlugin,
+    hash::ManualHashPlugin,
+};
 
 pub trait Plugin: Sync + Send {
     fn on_codegen_uint(&mut self, cx: &Context, items: &[DefId]) {
@@ -167,6 +188,7 @@ pub struct AutoDerivePlugin<F> {
     can_derive: FxHashMap<DefId, CanDerive>,
     predicate: F,
     attrs: Arc<[FastStr]>,
+    skip_eq_ignored: bool,
 }
 
 impl<F> AutoDerivePlugin<F>
@@ -178,8 +200,17 @@ where
             can_derive: FxHashMap::default(),
             predicate: f,
             attrs,
+            skip_eq_ignored: false,
         }
     }
+
+    /// Don't derive for the messages with fields ignored by their
+    /// `PartialEq`, and the types containing them, for the derives which
+    /// must agree with it like `Hash` and `Ord`.
+    pub(crate) fn skip_eq_ignored(mut self) -> Self {
+        self.skip_eq_ignored = true;
+        self
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -194,6 +225,20 @@ pub enum PredicateResult {
     GoOn, // can derive, but need more pass
 }
 
+/// Whether the types of the fields of `ty` can derive `Hash`, `Eq` and `Ord`:
+/// `AHashMap`, `AHashSet` and floats can't.
+pub(crate) fn derive_hash_predicate(ty: &Ty) -> PredicateResult {
+    let mut ty = ty;
+    while let ty::Vec(_ty) = &ty.kind {
+        ty = _ty;
+    }
+    if matches!(ty.kind, ty::Map(_, _) | ty::Set(_) | ty::F64 | ty::F32) {
+        PredicateResult::No
+    } else {
+        PredicateResult::GoOn
+    }
+}
+
 #[derive(Default)]
 pub struct PathCollector {
     paths: Vec<crate::rir::Path>,
@@ -237,9 +282,12 @@ where
             Item::Mod(_) => return CanDerive::No,
         };
 
-        let can_derive = if deps
-            .iter()
-            .any(|t| matches!((self.predicate)(t), PredicateResult::No))
+        let eq_ignored = self.skip_eq_ignored
+            && matches!(&*item, Item::Message(s) if s.fields.iter().any(|f| cx.is_eq_ignored(f)));
+        let can_derive = if eq_ignored
+            || deps
+                .iter()
+                .any(|t| matches!((self.predicate)(t), PredicateResult::No))
         {
             CanDerive::No
         } else {
@@ -332,6 +380,66 @@ impl Plugin for WithAttrsPlugin {
     }
 }
 
+/// Adds `#[cfg_attr(cfg, derive(...))]` for each `(derive, cfg)` pair,
+/// skipping the derives the item already has unconditionally.
+pub(crate) struct CfgDerivePlugin(pub(crate) Vec<(FastStr, FastStr)>);
+
+impl CfgDerivePlugin {
+    /// `::std::clone::Clone` and `Clone` are the same derive
+    fn normalize(derive: &str) -> &str {
+        let derive = derive.trim().trim_start_matches("::");
+        if derive.starts_with("std::") || derive.starts_with("core::") {
+            derive.rsplit("::").next().unwrap()
+        } else {
+            derive
+        }
+    }
+
+    fn derives(attr: &str) -> impl Iterator<Item = &str> {
+        attr.trim()
+            .strip_prefix("#[derive(")
+            .and_then(|attr| attr.strip_suffix(")]"))
+            .into_iter()
+            .flat_map(|derives| derives.split(','))
+            .map(Self::normalize)
+    }
+}
+
+impl Plugin for CfgDerivePlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        let builtin: &[&str] = match &*item {
+            Item::Enum(e) if e.repr.is_some() => &["Clone", "PartialEq", "Copy"],
+            Item::Message(_) | Item::Enum(_) | Item::NewType(_) => &["Clone", "PartialEq"],
+            _ => return walk_item(self, cx, def_id, item),
+        };
+        let existing = cx.with_adjust(def_id, |adj| {
+            adj.iter()
+                .flat_map(|adj| adj.attrs())
+                .flat_map(|attr| Self::derives(attr).map(String::from).collect::<Vec<_>>())
+                .chain(builtin.iter().map(|d| d.to_string()))
+                .collect::<HashSet<_>>()
+        });
+
+        let attrs = self
+            .0
+            .iter()
+            .filter(|(derive, _)| !existing.contains(Self::normalize(derive)))
+            .into_group_map_by(|(_, cfg)| cfg.clone())
+            .into_iter()
+            .sorted_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(cfg, derives)| {
+                let derives = derives.iter().map(|(derive, _)| derive).unique().join(", ");
+                FastStr::from(format!("#[cfg_attr({cfg}, derive({derives}))]"))
+            })
+            .collect::<Vec<_>>();
+        if !attrs.is_empty() {
+            cx.with_adjust_mut(def_id, |adj| adj.add_attrs(&attrs));
+        }
+
+        walk_item(self, cx, def_id, item)
+    }
+}
+
 pub struct ImplDefaultPlugin;
 
 impl Plugin for ImplDefaultPlugin {
@@ -340,7 +448,14 @@ impl Plugin for ImplDefaultPlugin {
             Item::Message(m) => {
                 let name = cx.rust_name(def_id);
 
-                if m.fields.iter().all(|f| cx.default_val(f).is_none()) {
+                let derive = match cx.options.default_strategy {
+                    DefaultStrategy::Derive => true,
+                    DefaultStrategy::Manual => false,
+                    DefaultStrategy::FromIdl => {
+                        m.fields.iter().all(|f| cx.default_val(f).is_none())
+                    }
+                };
+                if derive {
                     cx.with_adjust_mut(def_id, |adj| adj.add_attrs(&["#[derive(Default)]".into()]));
                 } else {
                     #[allow(unused_mut)]
@@ -362,6 +477,9 @@ impl Plugin for ImplDefaultPlugin {
                         })
                         .join(",\n");
 
+                    if cx.presence_ty(m).is_some() {
+                        fields.push_str(",\n_presence: 0");
+                    }
                     if cx.keep_unknown_fields.contains(&def_id) {
                         if !fields.is_empty() {
                             fields.push_str(",\n");
@@ -369,11 +487,12 @@ impl Plugin for ImplDefaultPlugin {
                         fields.push_str("_unknown_fields: ::pilota::LinkedBytes::new()");
                     }
 
+                    let generics = cx.item_generics(def_id);
                     cx.with_adjust_mut(def_id, |adj| {
                         adj.add_nested_item(
                             format!(
                                 r#"
-                                impl ::std::default::Default for {name} {{
+                                impl{generics} ::std::default::Default for {name}{generics} {{
                                     fn default() -> Self {{
                                         {name} {{
                                             {fields}
@@ -412,12 +531,13 @@ impl Plugin for ImplDefaultPlugin {
                             .iter()
                             .map(|_| "::std::default::Default::default()".to_string())
                             .join(",\n");
+                        let generics = cx.item_generics(def_id);
 
                         cx.with_adjust_mut(def_id, |adj| {
                             adj.add_nested_item(
                                 format!(
                                     r#"
-                                    impl ::std::default::Default for {enum_name} {{
+                                    impl{generics} ::std::default::Default for {enum_name}{generics} {{
                                         fn default() -> Self {{
                                             {enum_name}::{variant_name} ({fields})
                                         }}
@@ -428,6 +548,22 @@ impl Plugin for ImplDefaultPlugin {
                             )
                         });
                     }
+                } else if cx.has_unknown_variant(def_id) {
+                    let enum_name = cx.rust_name(def_id);
+                    cx.with_adjust_mut(def_id, |adj| {
+                        adj.add_nested_item(
+                            format!(
+                                r#"
+                                impl ::std::default::Default for {enum_name} {{
+                                    fn default() -> Self {{
+                                        {enum_name}::__Unknown
+                                    }}
+                                }}
+                            "#
+                            )
+                            .into(),
+                        )
+                    });
                 }
             }
             _ => {}
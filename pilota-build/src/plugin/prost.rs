@@ -0,0 +1,230 @@
+use std::sync::Arc;
+
+use faststr::FastStr;
+use itertools::Itertools;
+
+use crate::{
+    db::RirDatabase,
+    rir::{Field, Item, NodeKind},
+    tags::protobuf::OneOf,
+    ty::{Ty, TyKind},
+    Context, DefId, Plugin,
+};
+
+/// Generates `From` conversions between the generated protobuf messages and
+/// the types generated by prost for the same idl.
+///
+/// `prost_mod` is the path of the module where the prost generated code for
+/// the package root lives, e.g. `crate::prost_gen`. Items are matched by
+/// their relative path inside the package and fields by their order, so both
+/// sides must be generated from the same idl.
+#[derive(Clone)]
+pub struct ProstConvertPlugin {
+    prost_mod: FastStr,
+}
+
+impl ProstConvertPlugin {
+    pub fn new(prost_mod: impl Into<FastStr>) -> Self {
+        Self {
+            prost_mod: prost_mod.into(),
+        }
+    }
+
+    fn prost_path(&self, cx: &Context, def_id: DefId) -> String {
+        let mut depth = 1;
+        let mut parent = cx.node(def_id).unwrap().parent;
+        while let Some(p) = parent {
+            let node = cx.node(p).unwrap();
+            if let NodeKind::Item(item) = &node.kind {
+                if let Item::Mod(_) = &**item {
+                    depth += 1;
+                }
+            }
+            parent = node.parent;
+        }
+
+        let path = cx.item_path(def_id);
+        let rel = path[path.len().saturating_sub(depth)..]
+            .iter()
+            .map(|s| s.to_string())
+            .join("::");
+
+        format!("{}::{rel}", self.prost_mod)
+    }
+
+    fn convert_ty(&self, ty: &Ty, expr: &str, boxed: bool) -> String {
+        match &ty.kind {
+            TyKind::Vec(_) | TyKind::Set(_) | TyKind::BTreeSet(_) => {
+                format!("{expr}.into_iter().map(::std::convert::Into::into).collect()")
+            }
+            TyKind::Map(_, _) | TyKind::BTreeMap(_, _) => {
+                format!("{expr}.into_iter().map(|(k, v)| (k.into(), v.into())).collect()")
+            }
+            _ if boxed => format!("::std::boxed::Box::new((*{expr}).into())"),
+            _ => format!("{expr}.into()"),
+        }
+    }
+
+    /// prost always wraps message typed fields in an `Option`, even if they
+    /// are required.
+    fn is_required_message(&self, cx: &Context, f: &Field) -> bool {
+        if f.is_optional() {
+            return false;
+        }
+        match &f.ty.kind {
+            TyKind::Path(p) => matches!(&*cx.item(p.did).unwrap(), Item::Message(_)),
+            _ => false,
+        }
+    }
+
+    /// `f` holds the value of the field `inner` of a wrapper message, which
+    /// prost still generates, see `flatten_wrappers`.
+    fn convert_flattened(&self, cx: &Context, f: &Field, inner: &Field, to_prost: bool) -> String {
+        let name = cx.rust_name(f.did);
+        let src = format!("value.{name}");
+        let TyKind::Path(path) = &f.ty.kind else {
+            unreachable!("flattened wrappers are messages")
+        };
+        let inner_name = cx.rust_name(inner.did);
+
+        let value = if to_prost {
+            let wrap = |expr: &str| {
+                format!(
+                    "{} {{ {inner_name}: {} }}",
+                    self.prost_path(cx, path.did),
+                    self.convert_ty(&inner.ty, expr, false)
+                )
+            };
+            if f.is_optional() {
+                format!("{src}.map(|v| {})", wrap("v"))
+            } else {
+                format!("::std::option::Option::Some({})", wrap(&src))
+            }
+        } else {
+            let unwrap = self.convert_ty(&inner.ty, &format!("v.{inner_name}"), false);
+            if f.is_optional() {
+                format!("{src}.map(|v| {unwrap})")
+            } else {
+                format!("{src}.map(|v| {unwrap}).unwrap_or_default()")
+            }
+        };
+
+        format!("{name}: {value}")
+    }
+
+    fn convert_field(&self, cx: &Context, f: &Field, to_prost: bool) -> String {
+        if let Some(inner) = cx.flattened_wrapper(f) {
+            return self.convert_flattened(cx, f, &inner, to_prost);
+        }
+        let name = cx.rust_name(f.did);
+        let boxed = cx.with_adjust(f.did, |adj| adj.is_some_and(|adj| adj.boxed()));
+        let src = format!("value.{name}");
+
+        let value = if f.is_optional() {
+            format!("{src}.map(|v| {})", self.convert_ty(&f.ty, "v", boxed))
+        } else if self.is_required_message(cx, f) {
+            if to_prost {
+                format!(
+                    "::std::option::Option::Some({})",
+                    self.convert_ty(&f.ty, &src, boxed)
+                )
+            } else {
+                format!(
+                    "{src}.map(|v| {}).unwrap_or_default()",
+                    self.convert_ty(&f.ty, "v", boxed)
+                )
+            }
+        } else {
+            self.convert_ty(&f.ty, &src, boxed)
+        };
+
+        format!("{name}: {value}")
+    }
+
+    fn impl_from(&self, from: &str, to: &str, body: &str) -> FastStr {
+        format!(
+            r#"
+            impl ::std::convert::From<{from}> for {to} {{
+                fn from(value: {from}) -> Self {{
+                    {body}
+                }}
+            }}
+            "#
+        )
+        .into()
+    }
+}
+
+impl Plugin for ProstConvertPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        match &*item {
+            Item::Message(m) => {
+                let name = cx.rust_name(def_id);
+                let prost_path = self.prost_path(cx, def_id);
+
+                let fields = |to_prost| {
+                    m.fields
+                        .iter()
+                        .map(|f| self.convert_field(cx, f, to_prost))
+                        .join(",\n")
+                };
+
+                let mut from_prost = fields(false);
+                if cx.keep_unknown_fields.contains(&def_id) {
+                    if !from_prost.is_empty() {
+                        from_prost.push_str(",\n");
+                    }
+                    from_prost.push_str("_unknown_fields: ::pilota::LinkedBytes::new()");
+                }
+                let to_prost = fields(true);
+
+                cx.with_adjust_mut(def_id, |adj| {
+                    adj.add_nested_item(self.impl_from(
+                        &prost_path,
+                        &name,
+                        &format!("Self {{ {from_prost} }}"),
+                    ));
+                    adj.add_nested_item(self.impl_from(
+                        &name,
+                        &prost_path,
+                        &format!("Self {{ {to_prost} }}"),
+                    ));
+                });
+            }
+            Item::Enum(e) if cx.node_contains_tag::<OneOf>(def_id) => {
+                let name = cx.rust_name(def_id);
+                let prost_path = self.prost_path(cx, def_id);
+
+                let arms = |from: &str, to: &str| {
+                    e.variants
+                        .iter()
+                        .map(|v| {
+                            let variant = cx.rust_name(v.did);
+                            let value = self.convert_ty(&v.fields[0], "v", false);
+                            format!("{from}::{variant}(v) => {to}::{variant}({value}),")
+                        })
+                        .join("\n")
+                };
+
+                let from_prost = arms(&prost_path, "Self");
+                let to_prost = arms(&name, "Self");
+
+                cx.with_adjust_mut(def_id, |adj| {
+                    adj.add_nested_item(self.impl_from(
+                        &prost_path,
+                        &name,
+                        &format!("match value {{ {from_prost} }}"),
+                    ));
+                    adj.add_nested_item(self.impl_from(
+                        &name,
+                        &prost_path,
+                        &format!("match value {{ {to_prost} }}"),
+                    ));
+                });
+            }
+            _ => {}
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
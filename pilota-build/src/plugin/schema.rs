@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use faststr::FastStr;
+use itertools::Itertools;
+
+use crate::{
+    db::RirDatabase,
+    middle::ty::{AdtDef, CodegenTy},
+    rir::Item,
+    Context, DefId, Plugin,
+};
+
+const SCHEMA: &str = "::pilota::schema";
+
+/// Implements `pilota::schema::Schema` for the messages and unions,
+/// describing the names, ids, types and requiredness of their fields. The
+/// variants of the unions are optional fields. The messages and unions of
+/// the fields are referred to by their own schema, and the typedefs are
+/// resolved.
+#[derive(Clone, Copy, Default)]
+pub struct SchemaPlugin;
+
+impl SchemaPlugin {
+    /// The `TypeSchema` of `ty` for a field of `def_id`.
+    fn ty(cx: &Context, def_id: DefId, ty: &CodegenTy) -> String {
+        let el = |ty: &CodegenTy| format!("&{}", Self::ty(cx, def_id, ty));
+        let ty = match ty {
+            CodegenTy::FastStr | CodegenTy::String | CodegenTy::Str => "String".into(),
+            CodegenTy::Void => "Void".into(),
+            CodegenTy::U8 => "U8".into(),
+            CodegenTy::Bool => "Bool".into(),
+            CodegenTy::I8 => "I8".into(),
+            CodegenTy::I16 => "I16".into(),
+            CodegenTy::I32 => "I32".into(),
+            CodegenTy::I64 => "I64".into(),
+            CodegenTy::UInt32 => "U32".into(),
+            CodegenTy::UInt64 => "U64".into(),
+            CodegenTy::F32 => "F32".into(),
+            CodegenTy::F64 | CodegenTy::OrderedF64 => "F64".into(),
+            CodegenTy::Uuid => "Uuid".into(),
+            CodegenTy::Bytes => "Bytes".into(),
+            CodegenTy::Vec(ty) | CodegenTy::Array(ty, _) => format!("List({})", el(ty)),
+            CodegenTy::Set(ty) | CodegenTy::BTreeSet(ty) => format!("Set({})", el(ty)),
+            CodegenTy::Map(k, v) | CodegenTy::BTreeMap(k, v) => {
+                format!("Map({}, {})", el(k), el(v))
+            }
+            CodegenTy::Arc(ty)
+            | CodegenTy::Cow(ty)
+            | CodegenTy::BoxedSlice(ty)
+            | CodegenTy::StaticRef(ty)
+            | CodegenTy::LazyStaticRef(ty) => return Self::ty(cx, def_id, ty),
+            CodegenTy::Adt(AdtDef { did, .. }) => match &*cx.expect_item(*did) {
+                Item::Enum(e) if e.repr.is_some() => {
+                    format!(r#"Enum("{}")"#, cx.node(*did).unwrap().name())
+                }
+                _ => format!(
+                    "Message(<{} as {SCHEMA}::Schema>::schema)",
+                    cx.related_item_path(def_id, *did)
+                ),
+            },
+        };
+        format!("{SCHEMA}::TypeSchema::{ty}")
+    }
+
+    fn field(name: &str, id: i32, ty: &str, required: bool) -> String {
+        format!(
+            r#"{SCHEMA}::FieldSchema {{
+                name: "{name}",
+                id: {id},
+                ty: {ty},
+                required: {required},
+            }},"#
+        )
+    }
+
+    fn impl_schema(cx: &Context, def_id: DefId, fields: &str) -> FastStr {
+        let name = cx.rust_name(def_id);
+        let generics = cx.item_generics(def_id);
+        let idl_name = cx.node(def_id).unwrap().name();
+        format!(
+            r#"
+            impl{generics} {SCHEMA}::Schema for {name}{generics} {{
+                fn schema() -> &'static {SCHEMA}::MessageSchema {{
+                    static SCHEMA: {SCHEMA}::MessageSchema = {SCHEMA}::MessageSchema {{
+                        name: "{idl_name}",
+                        fields: &[{fields}],
+                    }};
+                    &SCHEMA
+                }}
+            }}
+            "#
+        )
+        .into()
+    }
+}
+
+impl Plugin for SchemaPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        let resolved = |ty: &crate::ty::Ty| {
+            let ty = cx.resolve_aliases(&cx.codegen_item_ty(ty.kind.clone()));
+            Self::ty(cx, def_id, &ty)
+        };
+        let fields = match &*item {
+            Item::Message(m) => Some(
+                m.fields
+                    .iter()
+                    .map(|f| {
+                        // the flattened wrappers are their field
+                        let ty = match cx.flattened_wrapper(f) {
+                            Some(inner) => resolved(&inner.ty),
+                            None => resolved(&f.ty),
+                        };
+                        Self::field(&f.name, f.id, &ty, !f.is_optional())
+                    })
+                    .join("\n"),
+            ),
+            Item::Enum(e) if e.repr.is_none() => Some(
+                e.variants
+                    .iter()
+                    .map(|v| {
+                        let ty = match &*v.fields {
+                            [ty] => resolved(ty),
+                            _ => format!("{SCHEMA}::TypeSchema::Void"),
+                        };
+                        let id = v.id.unwrap_or_default();
+                        Self::field(&v.name, id, &ty, false)
+                    })
+                    .join("\n"),
+            ),
+            _ => None,
+        };
+        if let Some(fields) = fields {
+            let nested = Self::impl_schema(cx, def_id, &fields);
+            cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(nested));
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
@@ -1,4 +1,52 @@
-use crate::tags::SerdeAttribute;
+use faststr::FastStr;
+use rustc_hash::FxHashMap;
+
+use crate::{
+    db::RirDatabase,
+    rir::Item,
+    tags::{protobuf::JsonName, SerdeAttribute, SerdeBytes, SerdeFlatten},
+    ty::{Ty, TyKind},
+};
+
+/// `#[serde(flatten)]` is only supported by serde on structs and maps.
+fn can_flatten(cx: &crate::Context, ty: &Ty) -> bool {
+    match &ty.kind {
+        TyKind::Path(p) => matches!(&*cx.item(p.did).unwrap(), Item::Message(_)),
+        TyKind::Map(_, _) | TyKind::BTreeMap(_, _) => true,
+        TyKind::Arc(ty) => can_flatten(cx, ty),
+        _ => false,
+    }
+}
+
+/// Panics if two fields of `m` are serialized under the same name, which
+/// the `json_name`s of `proto_json` can cause, e.g. with a oneof whose field
+/// has the name of the oneof.
+fn check_json_names(cx: &crate::Context, def_id: crate::DefId, m: &crate::rir::Message) {
+    if !m.fields.iter().any(|f| {
+        cx.tags(f.tags_id)
+            .is_some_and(|tags| tags.contains::<JsonName>())
+    }) {
+        return;
+    }
+    let mut names = FxHashMap::default();
+    for f in &m.fields {
+        let name = match cx
+            .tags(f.tags_id)
+            .and_then(|tags| tags.get::<JsonName>().cloned())
+        {
+            Some(JsonName(name)) => name,
+            None => FastStr::new(cx.rust_name(f.did).trim_start_matches("r#")),
+        };
+        if let Some(other) = names.insert(name.clone(), f.did) {
+            panic!(
+                "the json name `{name}` of `{}` is also the one of `{}` in `{}`",
+                cx.def_id_info(f.did),
+                cx.def_id_info(other),
+                cx.def_id_info(def_id),
+            )
+        }
+    }
+}
 
 #[derive(Clone, Copy)]
 pub struct SerdePlugin;
@@ -32,11 +80,24 @@ impl crate::Plugin for SerdePlugin {
             _ => {}
         };
 
+        if let crate::rir::Item::Message(m) = &*item {
+            // the derives would expose the packed fields as plain values
+            if cx.presence_ty(m).is_some() {
+                panic!(
+                    "`packed_presence` isn't supported by serde, but `{}` has packed fields",
+                    cx.def_id_info(def_id)
+                )
+            }
+            check_json_names(cx, def_id, m);
+        }
+
         if let crate::rir::Item::Enum(e) = &*item {
             if e.repr.is_some() {
                 cx.with_adjust_mut(def_id, |adj| {
                     adj.add_attrs(&["#[serde(transparent)]".into()]);
                 })
+            } else if let Some(attr) = cx.options.serde_union_repr.attr() {
+                cx.with_adjust_mut(def_id, |adj| adj.add_attrs(&[attr.into()]))
             }
         }
 
@@ -56,6 +117,54 @@ impl crate::Plugin for SerdePlugin {
             let attr = attribute.0.replace('\\', "");
             cx.with_adjust_mut(def_id, |adj| adj.add_attrs(&[attr.into()]))
         }
+
+        if let Some(JsonName(name)) = cx
+            .tags(f.tags_id)
+            .and_then(|tags| tags.get::<JsonName>().cloned())
+        {
+            cx.with_adjust_mut(def_id, |adj| {
+                adj.add_attrs(&[format!(r#"#[serde(rename = "{name}")]"#).into()])
+            })
+        }
+
+        if let Some(SerdeFlatten(true)) = cx
+            .tags(f.tags_id)
+            .and_then(|tags| tags.get::<SerdeFlatten>().copied())
+        {
+            if !can_flatten(cx, &f.ty) {
+                panic!(
+                    "`pilota.serde_flatten` is only valid on struct or map typed fields, but `{}` is `{}`",
+                    cx.def_id_info(def_id),
+                    cx.codegen_item_ty(f.ty.kind.clone())
+                )
+            }
+            cx.with_adjust_mut(def_id, |adj| adj.add_attrs(&["#[serde(flatten)]".into()]))
+        }
+
+        let is_bytes = matches!(f.ty.kind, TyKind::Bytes | TyKind::BytesVec);
+        let bytes = match cx
+            .tags(f.tags_id)
+            .and_then(|tags| tags.get::<SerdeBytes>().copied())
+        {
+            Some(_) if !is_bytes => panic!(
+                "`pilota.serde_bytes` is only valid on binary fields, but `{}` is `{}`",
+                cx.def_id_info(def_id),
+                cx.codegen_item_ty(f.ty.kind.clone())
+            ),
+            Some(bytes) => Some(bytes),
+            None if is_bytes => cx.options.serde_bytes,
+            None => None,
+        };
+        if let Some(bytes) = bytes {
+            let module = bytes.module();
+            // `with` doesn't default the missing optional fields to `None`
+            let attr = if f.is_optional() {
+                format!(r#"#[serde(default, with = "{module}::option")]"#)
+            } else {
+                format!(r#"#[serde(with = "{module}")]"#)
+            };
+            cx.with_adjust_mut(def_id, |adj| adj.add_attrs(&[attr.into()]))
+        }
     }
 
     fn on_variant(
@@ -71,5 +180,11 @@ impl crate::Plugin for SerdePlugin {
             let attr = attribute.0.replace('\\', "");
             cx.with_adjust_mut(def_id, |adj| adj.add_attrs(&[attr.into()]))
         }
+
+        if let Some(JsonName(name)) = cx.node_tag::<JsonName>(variant.did) {
+            cx.with_adjust_mut(def_id, |adj| {
+                adj.add_attrs(&[format!(r#"#[serde(rename = "{name}")]"#).into()])
+            })
+        }
     }
 }
@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use faststr::FastStr;
+use itertools::Itertools;
+
+use crate::{rir::Item, Context, DefId, Plugin};
+
+const HASH: &str = "::pilota::hash";
+
+/// Generates `stable_hash` on the messages, hashing the ids and values of
+/// their fields with a `pilota::hash::StableHasher`, and implements
+/// `StableHash` for the messages, enums and newtypes they contain.
+///
+/// The hash only depends on the values, e.g. not on the order the entries
+/// of the maps were inserted in, and is the same across runs and platforms.
+/// The unknown fields aren't hashed. The packed fields are hashed as the
+/// `Option`s they are read as, so an unset field differs from a zero one.
+#[derive(Clone, Copy, Default)]
+pub struct StableHashPlugin;
+
+impl StableHashPlugin {
+    fn impl_stable_hash(cx: &Context, def_id: DefId, body: &str) -> String {
+        let name = cx.rust_name(def_id);
+        let generics = cx.item_generics(def_id);
+        format!(
+            r#"
+            impl{generics} {HASH}::StableHash for {name}{generics} {{
+                fn write_stable(&self, hasher: &mut {HASH}::StableHasher) {{
+                    {body}
+                }}
+            }}
+            "#
+        )
+    }
+
+    fn message(&self, cx: &Context, def_id: DefId, m: &crate::rir::Message) -> FastStr {
+        let name = cx.rust_name(def_id);
+
+        let writes = m
+            .fields
+            .iter()
+            .map(|f| {
+                let value = cx
+                    .packed_value(f, "self")
+                    .unwrap_or_else(|| format!("self.{}", cx.rust_name(f.did)));
+                format!(
+                    r#"{HASH}::StableHash::write_stable(&{}i32, hasher);
+                    {HASH}::StableHash::write_stable(&{value}, hasher);"#,
+                    f.id,
+                )
+            })
+            .join("\n");
+
+        let generics = cx.item_generics(def_id);
+        let methods = format!(
+            r#"
+            impl{generics} {name}{generics} {{
+                pub fn stable_hash(&self) -> u64 {{
+                    {HASH}::stable_hash(self)
+                }}
+            }}
+            "#
+        );
+
+        let impl_stable_hash = Self::impl_stable_hash(cx, def_id, &writes);
+
+        format!("{methods}{impl_stable_hash}").into()
+    }
+
+    /// The variants are told apart by their ids in the idl.
+    fn union(&self, cx: &Context, def_id: DefId, e: &crate::rir::Enum) -> FastStr {
+        let mut arms = e
+            .variants
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let v_name = cx.rust_name(v.did);
+                let id = format!(
+                    "{HASH}::StableHash::write_stable(&{}i32, hasher);",
+                    v.id.unwrap_or(i as i32)
+                );
+                if v.fields.is_empty() {
+                    format!("Self::{v_name} => {{ {id} }}")
+                } else {
+                    format!(
+                        "Self::{v_name}(value) => {{ {id} {HASH}::StableHash::write_stable(value, hasher); }}"
+                    )
+                }
+            })
+            .join("\n");
+        let has_unknown_fields = cx.keep_unknown_fields.contains(&def_id)
+            && e.variants.iter().all(|v| !v.fields.is_empty());
+        if has_unknown_fields || cx.has_unknown_variant(def_id) {
+            arms.push_str("_ => {}");
+        }
+
+        Self::impl_stable_hash(
+            cx,
+            def_id,
+            &format!(
+                r#"match self {{
+                    {arms}
+                }}"#
+            ),
+        )
+        .into()
+    }
+}
+
+impl Plugin for StableHashPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        let nested = match &*item {
+            Item::Message(m) => Some(self.message(cx, def_id, m)),
+            Item::Enum(e) if e.repr.is_some() => Some(
+                Self::impl_stable_hash(
+                    cx,
+                    def_id,
+                    &format!("{HASH}::StableHash::write_stable(&self.inner(), hasher)"),
+                )
+                .into(),
+            ),
+            Item::Enum(e) => Some(self.union(cx, def_id, e)),
+            Item::NewType(_) => Some(
+                Self::impl_stable_hash(
+                    cx,
+                    def_id,
+                    &format!("{HASH}::StableHash::write_stable(&self.0, hasher)"),
+                )
+                .into(),
+            ),
+            _ => None,
+        };
+        if let Some(nested) = nested {
+            cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(nested));
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
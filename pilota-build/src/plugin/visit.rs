@@ -0,0 +1,144 @@
+use std::sync::Arc;
+
+use faststr::FastStr;
+use itertools::Itertools;
+
+use crate::{rir::Item, Context, DefId, Plugin};
+
+const VISIT: &str = "::pilota::visit";
+
+/// Generates `visit_fields` on the messages, calling a
+/// `pilota::visit::FieldVisitor` with each set field, and implements
+/// `VisitValue` for the messages, enums and newtypes they contain.
+///
+/// Nested messages and unions are visited between `enter_message` and
+/// `exit_message`, and enums are their value.
+#[derive(Clone, Copy, Default)]
+pub struct VisitPlugin;
+
+impl VisitPlugin {
+    fn impl_visit_value(cx: &Context, def_id: DefId, body: &str) -> String {
+        let name = cx.rust_name(def_id);
+        let generics = cx.item_generics(def_id);
+        format!(
+            r#"
+            impl{generics} {VISIT}::VisitValue for {name}{generics} {{
+                fn visit_value<V: {VISIT}::FieldVisitor + ?::std::marker::Sized>(&self, name: &'static str, visitor: &mut V) {{
+                    {body}
+                }}
+            }}
+            "#
+        )
+    }
+
+    /// Visits the message or union between `enter_message` and
+    /// `exit_message`.
+    fn nested(body: &str) -> String {
+        format!(
+            r#"visitor.enter_message(name);
+            {body}
+            visitor.exit_message(name);"#
+        )
+    }
+
+    fn message(&self, cx: &Context, def_id: DefId, m: &crate::rir::Message) -> FastStr {
+        let name = cx.rust_name(def_id);
+
+        let visits = m
+            .fields
+            .iter()
+            .map(|f| {
+                let value = cx
+                    .packed_value(f, "self")
+                    .unwrap_or_else(|| format!("self.{}", cx.rust_name(f.did)));
+                format!(
+                    r#"{VISIT}::VisitValue::visit_value(&{value}, "{}", visitor);"#,
+                    f.name
+                )
+            })
+            .join("\n");
+
+        let generics = cx.item_generics(def_id);
+        let methods = format!(
+            r#"
+            impl{generics} {name}{generics} {{
+                pub fn visit_fields<V: {VISIT}::FieldVisitor + ?::std::marker::Sized>(&self, visitor: &mut V) {{
+                    {visits}
+                }}
+            }}
+            "#
+        );
+
+        let impl_visit_value =
+            Self::impl_visit_value(cx, def_id, &Self::nested("self.visit_fields(visitor);"));
+
+        format!("{methods}{impl_visit_value}").into()
+    }
+
+    fn union(&self, cx: &Context, def_id: DefId, e: &crate::rir::Enum) -> FastStr {
+        let mut arms = e
+            .variants
+            .iter()
+            .map(|v| {
+                let v_name = cx.rust_name(v.did);
+                if v.fields.is_empty() {
+                    format!("Self::{v_name} => {{}}")
+                } else {
+                    format!(
+                        r#"Self::{v_name}(value) => {VISIT}::VisitValue::visit_value(value, "{}", visitor),"#,
+                        v.name
+                    )
+                }
+            })
+            .join("\n");
+        let has_unknown_fields = cx.keep_unknown_fields.contains(&def_id)
+            && e.variants.iter().all(|v| !v.fields.is_empty());
+        if has_unknown_fields || cx.has_unknown_variant(def_id) {
+            arms.push_str("_ => {}");
+        }
+
+        Self::impl_visit_value(
+            cx,
+            def_id,
+            &Self::nested(&format!(
+                r#"match self {{
+                    {arms}
+                }}"#
+            )),
+        )
+        .into()
+    }
+}
+
+impl Plugin for VisitPlugin {
+    fn on_item(&mut self, cx: &Context, def_id: DefId, item: Arc<Item>) {
+        let nested = match &*item {
+            Item::Message(m) => Some(self.message(cx, def_id, m)),
+            Item::Enum(e) if e.repr.is_some() => Some(
+                Self::impl_visit_value(
+                    cx,
+                    def_id,
+                    &format!(
+                        "visitor.visit_field(name, {VISIT}::FieldValue::I64(self.inner() as i64))"
+                    ),
+                )
+                .into(),
+            ),
+            Item::Enum(e) => Some(self.union(cx, def_id, e)),
+            Item::NewType(_) => Some(
+                Self::impl_visit_value(
+                    cx,
+                    def_id,
+                    &format!("{VISIT}::VisitValue::visit_value(&self.0, name, visitor)"),
+                )
+                .into(),
+            ),
+            _ => None,
+        };
+        if let Some(nested) = nested {
+            cx.with_adjust_mut(def_id, |adj| adj.add_nested_item(nested));
+        }
+
+        crate::plugin::walk_item(self, cx, def_id, item)
+    }
+}
@@ -1,6 +1,7 @@
 use std::{ptr::NonNull, sync::Arc};
 
 use ahash::AHashMap;
+use faststr::FastStr;
 use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -18,7 +19,7 @@ use crate::{
     },
     rir::Mod,
     symbol::{DefId, EnumRepr, FileId, Ident, Symbol},
-    tags::{RustType, RustWrapperArc, TagId, Tags},
+    tags::{thrift::CowWrapper, RustType, RustWrapperArc, StringType, TagId, Tags},
     ty::{Folder, TyKind},
 };
 
@@ -196,6 +197,10 @@ pub struct Resolver {
     ir_files: FxHashMap<FileId, Arc<ir::File>>,
     errors: errors::Handler,
     args: FxHashSet<DefId>,
+    /// Lower maps and sets to `BTreeMap`s and `BTreeSet`s
+    pub(crate) no_std: bool,
+    /// The `(fqn, key, value)` annotations of `Builder::item_tag`
+    pub(crate) item_tags: Vec<(FastStr, FastStr, FastStr)>,
 }
 
 impl Default for Resolver {
@@ -213,6 +218,8 @@ impl Default for Resolver {
             cur_file: None,
             parent_node: None,
             args: Default::default(),
+            no_std: false,
+            item_tags: Default::default(),
         }
     }
 }
@@ -224,6 +231,63 @@ pub struct ResolveResult {
     pub args: FxHashSet<DefId>,
 }
 
+/// Adds the tags of `Builder::item_tag` to the items, fields, variants,
+/// methods and arguments of the idls, by their dotted names.
+#[derive(Default)]
+struct ItemTagger {
+    annotations: FxHashMap<FastStr, Vec<(FastStr, FastStr)>>,
+    tagged: FxHashSet<FastStr>,
+}
+
+impl ItemTagger {
+    fn tag(&mut self, tags: &mut Arc<Tags>, fqn: String) {
+        let Some(annotations) = self.annotations.get(fqn.as_str()) else {
+            return;
+        };
+        let tags = Arc::make_mut(tags);
+        for (key, value) in annotations {
+            tags.insert_annotation(key, value).unwrap();
+        }
+        self.tagged.insert(fqn.into());
+    }
+
+    fn tag_item(&mut self, item: &mut Arc<ir::Item>, prefix: &str) {
+        if let ir::ItemKind::Use(_) = item.kind {
+            return;
+        }
+        let fqn = join_fqn(prefix, &item.name());
+        let item = Arc::make_mut(item);
+        match &mut item.kind {
+            ir::ItemKind::Message(m) => m
+                .fields
+                .iter_mut()
+                .for_each(|f| self.tag(&mut f.tags, join_fqn(&fqn, &f.name))),
+            ir::ItemKind::Enum(e) => e
+                .variants
+                .iter_mut()
+                .for_each(|v| self.tag(&mut v.tags, join_fqn(&fqn, &v.name))),
+            ir::ItemKind::Service(s) => s.methods.iter_mut().for_each(|m| {
+                let method = join_fqn(&fqn, &m.name);
+                m.args
+                    .iter_mut()
+                    .for_each(|a| self.tag(&mut a.tags, join_fqn(&method, &a.name)));
+                self.tag(&mut m.tags, method);
+            }),
+            ir::ItemKind::Mod(m) => m.items.iter_mut().for_each(|i| self.tag_item(i, &fqn)),
+            _ => {}
+        }
+        self.tag(&mut item.tags, fqn);
+    }
+}
+
+fn join_fqn(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{prefix}.{name}")
+    }
+}
+
 pub struct ResolvedSymbols {
     ty: Vec<DefId>,
     value: Vec<DefId>,
@@ -251,6 +315,7 @@ impl Resolver {
     }
 
     pub fn resolve_files(mut self, files: &[Arc<ir::File>]) -> ResolveResult {
+        let files = &self.tag_items(files);
         files.iter().for_each(|f| {
             let mut collect = CollectDef::new(&mut self);
             collect.visit_file(f.clone());
@@ -264,6 +329,8 @@ impl Resolver {
             .map(|f| (f.id, Arc::from(self.lower_file(f))))
             .collect::<FxHashMap<_, _>>();
 
+        self.check_typedef_cycles();
+
         self.errors.abort_if_errors();
 
         ResolveResult {
@@ -274,6 +341,88 @@ impl Resolver {
         }
     }
 
+    /// Adds the tags of `item_tags` before the files are lowered, so the tags
+    /// changing the types apply too, warning about the names that don't
+    /// exist.
+    fn tag_items(&self, files: &[Arc<ir::File>]) -> Vec<Arc<ir::File>> {
+        if self.item_tags.is_empty() {
+            return files.to_vec();
+        }
+
+        let mut tagger = ItemTagger::default();
+        for (fqn, key, value) in &self.item_tags {
+            tagger
+                .annotations
+                .entry(fqn.clone())
+                .or_default()
+                .push((key.clone(), value.clone()));
+        }
+
+        let files = files
+            .iter()
+            .map(|file| {
+                let mut file = ir::File::clone(file);
+                let package = file.package.to_string();
+                file.items
+                    .iter_mut()
+                    .for_each(|item| tagger.tag_item(item, &package));
+                Arc::new(file)
+            })
+            .collect();
+
+        for fqn in self.item_tags.iter().map(|(fqn, ..)| fqn).unique() {
+            if !tagger.tagged.contains(fqn) {
+                errors::warn(
+                    errors::WarningKind::MissingItem,
+                    format_args!("tagged item `{fqn}` doesn't exist"),
+                );
+            }
+        }
+
+        files
+    }
+
+    /// Typedefs which alias each other have no type to resolve to.
+    fn check_typedef_cycles(&mut self) {
+        let alias_of = |def_id: DefId| match &self.nodes.get(&def_id)?.kind {
+            NodeKind::Item(item) => match &**item {
+                Item::NewType(t) => match &t.ty.kind {
+                    TyKind::Path(p) => Some((t.name.clone(), p.did)),
+                    _ => None,
+                },
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let mut errors = Vec::new();
+        let mut checked = FxHashSet::default();
+        for def_id in self.nodes.keys().copied().sorted() {
+            let mut chain: Vec<(DefId, Ident)> = Vec::new();
+            let mut cur = def_id;
+            while let Some((name, next)) = alias_of(cur) {
+                if !checked.insert(cur) {
+                    break;
+                }
+                chain.push((cur, name));
+                if let Some(pos) = chain.iter().position(|(did, _)| *did == next) {
+                    let names = chain[pos..]
+                        .iter()
+                        .chain(std::iter::once(&chain[pos]))
+                        .map(|(_, name)| format!("`{name}`"))
+                        .join(" -> ");
+                    errors.push(format!("typedef cycle: {names}"));
+                    break;
+                }
+                cur = next;
+            }
+        }
+
+        errors
+            .into_iter()
+            .for_each(|err| self.errors.emit_error(err));
+    }
+
     fn modify_ty_by_tags(&mut self, mut ty: Ty, tags: &Tags) -> Ty {
         match ty.kind {
             ty::FastStr
@@ -295,6 +444,12 @@ impl Resolver {
             _ => {}
         }
 
+        match (&ty.kind, tags.get::<StringType>()) {
+            (ty::FastStr, Some(StringType::String)) => ty.kind = ty::String,
+            (ty::String, Some(StringType::FastStr)) => ty.kind = ty::FastStr,
+            _ => {}
+        }
+
         if let Some(repr) = tags.get::<RustType>() {
             if repr == "btree" {
                 struct BTreeFolder<'a>(&'a mut Resolver);
@@ -350,10 +505,41 @@ impl Resolver {
                     }
                 }
             }
-            ArcFolder(self).fold_ty(&ty)
-        } else {
-            ty
+            ty = ArcFolder(self).fold_ty(&ty);
         }
+
+        if tags.contains::<CowWrapper>() {
+            let inner = match &ty.kind {
+                TyKind::String | TyKind::FastStr => Some(TyKind::String),
+                TyKind::Bytes | TyKind::BytesVec => Some(TyKind::BytesVec),
+                TyKind::Vec(_) => Some(ty.kind.clone()),
+                _ => None,
+            };
+            if let Some(kind) = inner {
+                ty = Ty {
+                    kind: TyKind::Cow(Arc::new(Ty {
+                        kind,
+                        tags_id: ty.tags_id,
+                    })),
+                    tags_id: self.tags_id_counter.inc_one(),
+                };
+            }
+        }
+
+        if tags
+            .get::<RustType>()
+            .map(|repr| repr == "boxed_slice")
+            .unwrap_or(false)
+        {
+            if !matches!(ty.kind, TyKind::Vec(_)) {
+                panic!("ty: `{:?}` can't be a boxed slice, only lists can", ty.kind)
+            }
+            ty = Ty {
+                kind: TyKind::BoxedSlice(Arc::new(ty)),
+                tags_id: self.tags_id_counter.inc_one(),
+            };
+        }
+        ty
     }
 
     #[tracing::instrument(level = "debug", skip_all, fields(name = &**f.name))]
@@ -408,11 +594,8 @@ impl Resolver {
             ir::TyKind::F64 => ty::F64,
             ir::TyKind::Uuid => ty::Uuid,
             ir::TyKind::Vec(ty) => ty::Vec(Arc::from(self.lower_type(ty, false))),
-            ir::TyKind::Set(ty) => ty::Set(Arc::from(self.lower_type_for_hash_key(ty, false))),
-            ir::TyKind::Map(k, v) => ty::Map(
-                Arc::from(self.lower_type_for_hash_key(k, false)),
-                Arc::from(self.lower_type(v, false)),
-            ),
+            ir::TyKind::Set(ty) => self.lower_set(ty),
+            ir::TyKind::Map(k, v) => self.lower_map(k, v),
             ir::TyKind::Path(p) => ty::Path(self.lower_path(p, Namespace::Ty, is_args)),
             ir::TyKind::UInt64 => ty::UInt64,
             ir::TyKind::UInt32 => ty::UInt32,
@@ -425,6 +608,25 @@ impl Resolver {
         Ty { kind, tags_id }
     }
 
+    fn lower_set(&mut self, ty: &ir::Ty) -> TyKind {
+        let ty = Arc::from(self.lower_type_for_hash_key(ty, false));
+        if self.no_std {
+            ty::BTreeSet(ty)
+        } else {
+            ty::Set(ty)
+        }
+    }
+
+    fn lower_map(&mut self, k: &ir::Ty, v: &ir::Ty) -> TyKind {
+        let k = Arc::from(self.lower_type_for_hash_key(k, false));
+        let v = Arc::from(self.lower_type(v, false));
+        if self.no_std {
+            ty::BTreeMap(k, v)
+        } else {
+            ty::Map(k, v)
+        }
+    }
+
     fn lower_type_for_hash_key(&mut self, ty: &ir::Ty, is_args: bool) -> Ty {
         let kind = match &ty.kind {
             ir::TyKind::String => ty::FastStr,
@@ -439,11 +641,8 @@ impl Resolver {
             ir::TyKind::F64 => ty::OrderedF64,
             ir::TyKind::Uuid => ty::Uuid,
             ir::TyKind::Vec(ty) => ty::Vec(Arc::from(self.lower_type_for_hash_key(ty, false))),
-            ir::TyKind::Set(ty) => ty::Set(Arc::from(self.lower_type_for_hash_key(ty, false))),
-            ir::TyKind::Map(k, v) => ty::Map(
-                Arc::from(self.lower_type_for_hash_key(k, false)),
-                Arc::from(self.lower_type(v, false)),
-            ),
+            ir::TyKind::Set(ty) => self.lower_set(ty),
+            ir::TyKind::Map(k, v) => self.lower_map(k, v),
             ir::TyKind::Path(p) => ty::Path(self.lower_path(p, Namespace::Ty, is_args)),
             ir::TyKind::UInt64 => ty::UInt64,
             ir::TyKind::UInt32 => ty::UInt32,
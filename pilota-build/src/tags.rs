@@ -5,16 +5,17 @@ use std::{
     hash::Hash,
     ops::{Deref, DerefMut},
     str::FromStr,
+    sync::Arc,
 };
 
 use faststr::FastStr;
 
-#[derive(Default, Debug)]
-pub struct TypeMap(HashMap<TypeId, Box<dyn Any + Sync + Send>>);
+#[derive(Default, Debug, Clone)]
+pub struct TypeMap(HashMap<TypeId, Arc<dyn Any + Sync + Send>>);
 
 impl TypeMap {
     pub fn insert<T: 'static + Sync + Send>(&mut self, v: T) {
-        self.0.insert(TypeId::of::<T>(), Box::new(v));
+        self.0.insert(TypeId::of::<T>(), Arc::new(v));
     }
 
     pub fn get<T: 'static>(&self) -> Option<&T> {
@@ -42,9 +43,38 @@ impl TypeMap {
 
 crate::newtype_index!(pub struct TagId { .. });
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct Tags(TypeMap);
 
+impl Tags {
+    /// Inserts the annotation `key` with `value`, failing for the keys which
+    /// aren't annotations of pilota.
+    pub(crate) fn insert_annotation(&mut self, key: &str, value: &str) -> anyhow::Result<()> {
+        macro_rules! with_tags {
+            ($($key: ty)|+) => {
+                match key {
+                    $(<$key>::KEY => self.insert(<$key>::from_str(value)?),)+
+                    _ => anyhow::bail!("`{key}` isn't a pilota annotation"),
+                }
+            };
+        }
+
+        with_tags!(
+            PilotaName
+                | RustType
+                | RustWrapperArc
+                | StringType
+                | SerdeAttribute
+                | SerdeFlatten
+                | SerdeBytes
+                | EqIgnore
+                | MethodItemName
+                | ValueRange
+        );
+        Ok(())
+    }
+}
+
 impl Deref for Tags {
     type Target = TypeMap;
 
@@ -110,6 +140,18 @@ macro_rules! new_type {
 
 pub mod thrift {
     pub struct EntryMessage;
+
+    /// Set on the enums lowered from thrift unions.
+    pub struct Union;
+
+    /// Set on the fields whose strings, binaries and lists are generated as
+    /// a `Cow`.
+    pub struct CowWrapper;
+
+    /// The annotations of a method in their order, including the repeated
+    /// ones and the ones pilota doesn't interpret, e.g. `http.path`.
+    #[derive(Clone, Debug, Default)]
+    pub struct Annotations(pub std::sync::Arc<[(faststr::FastStr, faststr::FastStr)]>);
 }
 
 #[derive(Clone)]
@@ -131,6 +173,8 @@ impl Annotation for PilotaName {
     const KEY: &'static str = "pilota.name";
 }
 
+/// The rust type of a field: `string`, `vec`, `btree` or `boxed_slice` for
+/// an immutable `Box<[T]>` list.
 #[derive(Debug)]
 pub struct RustType(pub FastStr);
 
@@ -152,6 +196,53 @@ impl Annotation for RustType {
     const KEY: &'static str = "pilota.rust_type";
 }
 
+/// The rust type of a string field, overriding the default one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringType {
+    String,
+    FastStr,
+}
+
+impl FromStr for StringType {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "String" => Ok(Self::String),
+            "FastStr" => Ok(Self::FastStr),
+            _ => anyhow::bail!("invalid string type `{s}`, expected `String` or `FastStr`"),
+        }
+    }
+}
+
+impl Annotation for StringType {
+    const KEY: &'static str = "pilota.string_type";
+}
+
+/// The requiredness of the thrift fields declared without `required` or
+/// `optional`, set on the whole document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultRequiredness {
+    Required,
+    Optional,
+}
+
+impl FromStr for DefaultRequiredness {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "required" => Ok(Self::Required),
+            "optional" => Ok(Self::Optional),
+            _ => anyhow::bail!("invalid requiredness `{s}`, expected `required` or `optional`"),
+        }
+    }
+}
+
+impl Annotation for DefaultRequiredness {
+    const KEY: &'static str = "pilota.default_requiredness";
+}
+
 #[derive(Debug)]
 pub struct RustWrapperArc(pub bool);
 
@@ -182,6 +273,157 @@ impl Annotation for SerdeAttribute {
     const KEY: &'static str = "pilota.serde_attribute";
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct SerdeFlatten(pub bool);
+
+impl FromStr for SerdeFlatten {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s == "true"))
+    }
+}
+
+impl Annotation for SerdeFlatten {
+    const KEY: &'static str = "pilota.serde_flatten";
+}
+
+/// The serde adapter of a bytes field among those of `pilota::serde`,
+/// overriding the one of [`crate::Builder::serde_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SerdeBytes {
+    /// A base64 string
+    Base64,
+    /// A hex string
+    Hex,
+    /// The bytes type of the format, an array of numbers in JSON
+    Raw,
+}
+
+impl SerdeBytes {
+    /// The path of the adapter module.
+    pub(crate) fn module(&self) -> &'static str {
+        match self {
+            SerdeBytes::Base64 => "::pilota::serde::base64",
+            SerdeBytes::Hex => "::pilota::serde::hex",
+            SerdeBytes::Raw => "::pilota::serde::raw",
+        }
+    }
+}
+
+impl FromStr for SerdeBytes {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "base64" => Ok(Self::Base64),
+            "hex" => Ok(Self::Hex),
+            "raw" => Ok(Self::Raw),
+            _ => anyhow::bail!("invalid serde bytes `{s}`, expected `base64`, `hex` or `raw`"),
+        }
+    }
+}
+
+impl Annotation for SerdeBytes {
+    const KEY: &'static str = "pilota.serde_bytes";
+}
+
+/// Leaves the field out of the `PartialEq` of its message, like a timestamp
+/// or a request id. The messages with such fields implement `PartialEq`
+/// comparing the others, so they are always equal if all their fields are
+/// ignored, and don't derive `Hash`, `Eq`, `PartialOrd` and `Ord`, which
+/// would disagree with it.
+#[derive(Debug, Clone, Copy)]
+pub struct EqIgnore(pub bool);
+
+impl FromStr for EqIgnore {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s == "true"))
+    }
+}
+
+impl Annotation for EqIgnore {
+    const KEY: &'static str = "pilota.eq_ignore";
+}
+
+/// Overrides the `{Service}{Method}` prefix of the items generated for a
+/// thrift method, like `{Service}{Method}ArgsSend`.
+#[derive(Debug, Clone)]
+pub struct MethodItemName(pub FastStr);
+
+impl FromStr for MethodItemName {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(FastStr::new(s)))
+    }
+}
+
+impl Annotation for MethodItemName {
+    const KEY: &'static str = "pilota.method_item_name";
+}
+
+/// The valid values of an integer field, like `1..=100`, `-10..0` or `1..`.
+/// The field is generated private, with a getter and a setter checking the
+/// range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValueRange {
+    pub start: Option<i128>,
+    pub end: Option<i128>,
+    pub inclusive: bool,
+}
+
+impl std::fmt::Display for ValueRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(start) = self.start {
+            write!(f, "{start}")?;
+        }
+        f.write_str(if self.inclusive { "..=" } else { ".." })?;
+        if let Some(end) = self.end {
+            write!(f, "{end}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ValueRange {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (start, end, inclusive) = match s.split_once("..=") {
+            Some((start, end)) => (start, end, true),
+            None => match s.split_once("..") {
+                Some((start, end)) => (start, end, false),
+                None => anyhow::bail!("invalid range `{s}`, expected `a..b` or `a..=b`"),
+            },
+        };
+        let bound = |b: &str| -> anyhow::Result<Option<i128>> {
+            let b = b.trim();
+            if b.is_empty() {
+                return Ok(None);
+            }
+            b.parse()
+                .map(Some)
+                .map_err(|_| anyhow::anyhow!("invalid bound `{b}` in range `{s}`"))
+        };
+        let range = Self {
+            start: bound(start)?,
+            end: bound(end)?,
+            inclusive,
+        };
+        if range.end.is_none() && (inclusive || range.start.is_none()) {
+            anyhow::bail!("invalid range `{s}`");
+        }
+        Ok(range)
+    }
+}
+
+impl Annotation for ValueRange {
+    const KEY: &'static str = "pilota.range";
+}
+
 #[derive(Debug)]
 pub struct KeepUnknownFields(pub bool);
 
@@ -213,5 +455,17 @@ pub mod protobuf {
 
         pub struct ClientStreaming;
         pub struct ServerStreaming;
+
+        /// proto2 group, encoded between start and end group tags instead of
+        /// being length delimited
+        pub struct Group;
+
+        /// proto2 `required` field, decoding fails without it
+        pub struct Required;
     }
+
+    /// The name of a field in the proto3 JSON mapping, from its `json_name`
+    /// option or the lowerCamelCase of its name.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct JsonName(pub faststr::FastStr);
 }
@@ -4,7 +4,41 @@ use std::{fs, fs::File, path::Path, process::Command};
 
 use tempfile::tempdir;
 
-use crate::{plugin::SerdePlugin, IdlService};
+use crate::{
+    db::RirDatabase,
+    middle::context::SourceType,
+    parser::Parser,
+    plugin::{ArbitraryPlugin, ProstConvertPlugin, SerdePlugin},
+    BuildOptions, Context, DefId, IdlService, Output,
+};
+
+/// The options of the tests building a context, which keep the unused items.
+fn all_items() -> BuildOptions {
+    BuildOptions {
+        ignore_unused: false,
+        ..Default::default()
+    }
+}
+
+/// Builds the context of `services` as `Builder::compile` would, without
+/// generating the code.
+fn build_cx<P: Parser>(
+    parser: P,
+    services: Vec<IdlService>,
+    out: Option<Output>,
+    options: BuildOptions,
+) -> Context {
+    crate::Builder::<crate::MkThriftBackend, P>::build_cx(services, out, parser, options)
+}
+
+/// The def id of the first node named `name` in the idls.
+fn def_id(cx: &Context, name: &str) -> DefId {
+    cx.nodes()
+        .iter()
+        .find(|(_, node)| *node.name() == *name)
+        .map(|(def_id, _)| *def_id)
+        .unwrap()
+}
 
 fn diff_file(old: impl AsRef<Path>, new: impl AsRef<Path>) {
     let old_content =
@@ -473,6 +507,125 @@ fn test_touch() {
     });
 }
 
+#[test]
+#[should_panic(expected = "item `Missing` of")]
+fn test_deny_missing_item() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("must_gen_items.thrift");
+
+    let dir = tempdir().unwrap();
+    crate::Builder::thrift()
+        .touch([(file_path.clone(), vec!["A", "Missing"])])
+        .deny_warnings([crate::WarningKind::MissingItem])
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(dir.path().join("must_gen_items.rs")),
+        );
+}
+
+#[test]
+fn test_deny_other_warnings() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("must_gen_items.thrift");
+
+    // a missing item is still only a warning
+    let dir = tempdir().unwrap();
+    let out_path = dir.path().join("must_gen_items.rs");
+    crate::Builder::thrift()
+        .touch([(file_path.clone(), vec!["A", "Missing"])])
+        .deny_warnings([
+            crate::WarningKind::Separator,
+            crate::WarningKind::DuplicateNamespace,
+        ])
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(out_path.clone()),
+        );
+    assert!(fs::read_to_string(out_path)
+        .unwrap()
+        .contains("pub struct A"));
+}
+
+#[test]
+#[should_panic(expected = "parse failed in Strict mode")]
+fn test_strict_parse_mode() {
+    let dir = tempdir().unwrap();
+    let file_path = dir.path().join("trailing.thrift");
+    fs::write(&file_path, "struct A {\n    1: i32 a,\n}\n").unwrap();
+
+    let compile = |mode| {
+        crate::Builder::thrift()
+            .parse_mode(mode)
+            .compile_with_config(
+                vec![IdlService::from_path(file_path.clone())],
+                crate::Output::File(dir.path().join("trailing.rs")),
+            )
+    };
+    // the trailing separator is allowed by default
+    compile(crate::ParseMode::Lenient);
+    compile(crate::ParseMode::Strict);
+}
+
+#[test]
+fn test_xsd_optional() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("xsd")
+        .join("legacy.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let legacy = cx
+        .codegen_items
+        .iter()
+        .find_map(|def_id| match &*cx.item(*def_id)? {
+            crate::rir::Item::Message(m) if &**m.name == "Legacy" => Some(m.clone()),
+            _ => None,
+        })
+        .unwrap();
+    let optional = legacy
+        .fields
+        .iter()
+        .map(|f| (f.name.to_string(), f.is_optional()))
+        .collect::<Vec<_>>();
+    // `xsd_optional` overrides the default requiredness, but not an explicit
+    // `required`
+    assert_eq!(
+        optional,
+        [
+            ("id".to_string(), true),
+            ("name".to_string(), false),
+            ("version".to_string(), true),
+            ("enabled".to_string(), false),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "`xsd_all` of `Legacy` is ignored")]
+fn test_deny_legacy_xsd() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("xsd")
+        .join("legacy.thrift");
+
+    let dir = tempdir().unwrap();
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .deny_warnings([crate::WarningKind::LegacyXsd])
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(dir.path().join("legacy.rs")),
+        );
+}
+
 #[test]
 fn test_unknown_fields() {
     let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
@@ -494,29 +647,5012 @@ fn test_unknown_fields() {
     });
 }
 
-mod tests {
-    use pilota::{
-        prost::bytes::BytesMut,
-        thrift::{binary::TBinaryProtocol, Message},
-    };
+#[test]
+fn test_arbitrary() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("arbitrary")
+        .join("fuzz.thrift");
 
-    use self::decode_error::decode_error::A;
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
 
-    include!("../../test_data/thrift/decode_error.rs");
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .keep_unknown_fields([source.with_file_name("unknown.thrift")])
+            .plugin(ArbitraryPlugin::new())
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
 
-    #[test]
-    fn test_decode_error() {
-        let mut data = BytesMut::from(&[
-            12_u8, 0, 1, 12, 0, 1, 11, 0, 1, 0, 0, 0, 10, 104, 101, 108, 108, 111, 32, 119, 111,
-            114, 108, 100, 0, 0, 0,
-        ] as &[u8])
-        .freeze();
+#[test]
+fn test_flatten_wrappers() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("flatten_wrappers")
+        .join("flatten_wrappers.proto");
 
-        let err = A::decode(&mut TBinaryProtocol::new(&mut data, false)).unwrap_err();
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
 
-        assert_eq!(
-            err.to_string(),
-            "Protocol(ProtocolException { kind: InvalidData, message: \"decode struct `A` field(#1) failed, caused by: decode struct `B` field(#1) failed, caused by: invalid ttype 100\" })"
-        )
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::protobuf()
+            .ignore_unused(false)
+            .flatten_wrappers(true)
+            .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+            .plugin(ProstConvertPlugin::new(
+                "crate::test::tests::flatten_wrappers_prost_stub",
+            ))
+            .plugin(crate::plugin::SchemaPlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_protobuf_group() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf_group")
+        .join("group.proto");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_protobuf(file_path, out_path);
+}
+
+#[test]
+fn test_union_unknown_variant() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("union_unknown")
+        .join("union_unknown.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .union_unknown_variant(true)
+            .keep_unknown_fields([source.with_file_name("kept.thrift")])
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_cow_fields() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("cow")
+        .join("cow.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .cow_fields(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_cow_fields_plugins() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("cow")
+        .join("cow.thrift");
+
+    let out_path = file_path.with_file_name("cow_plugins.rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .cow_fields(true)
+            .manual_clone(true)
+            .manual_hash(true)
+            .service_result("::std::result::Result", "crate::test::tests::RpcError")
+            .plugin(SerdePlugin)
+            .plugin(ArbitraryPlugin::new())
+            .plugin(crate::plugin::FieldMapPlugin)
+            .plugin(crate::plugin::VisitPlugin)
+            .plugin(crate::plugin::StableHashPlugin)
+            .plugin(crate::plugin::SchemaPlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_message_registry() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("registry")
+        .join("registry.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .message_registry(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_strict_decode() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("strict_decode")
+        .join("strict.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .strict_decode(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_owned_encode() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("owned_encode")
+        .join("owned.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .owned_encode(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_manual_hash() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("manual_hash")
+        .join("index.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .manual_hash(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_enum_try_from_i64() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("enum_try_from")
+        .join("status.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .enum_try_from_i64(true)
+            .const_enum_fns(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_closed_enums() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("enum_try_from")
+        .join("status.thrift");
+
+    let out_path = file_path.with_file_name("closed.rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .closed_enums(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_enum_variants() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("enum_try_from")
+        .join("status.thrift");
+
+    let out_path = file_path.with_file_name("variants.rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .enum_variants(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_no_std() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("no_std")
+        .join("defaults.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .no_std(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_packed_presence() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("packed_presence")
+        .join("scores.thrift");
+
+    for packed in [true, false] {
+        let out_path = file_path.with_file_name(if packed { "packed.rs" } else { "unpacked.rs" });
+        test_with_builder(file_path.clone(), out_path, |source, target| {
+            crate::Builder::thrift()
+                .ignore_unused(false)
+                .packed_presence(packed)
+                .plugin(crate::plugin::StableHashPlugin)
+                .plugin(crate::plugin::FieldMapPlugin)
+                .plugin(crate::plugin::VisitPlugin)
+                .compile_with_config(
+                    vec![IdlService::from_path(source.to_path_buf())],
+                    crate::Output::File(target.into()),
+                )
+        });
+    }
+}
+
+#[test]
+fn test_decode_trace() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("decode_trace")
+        .join("events.thrift");
+
+    for (backend, out) in [
+        (crate::TraceBackend::Tracing, "with_tracing.rs"),
+        (crate::TraceBackend::Log, "with_log.rs"),
+    ] {
+        let out_path = file_path.with_file_name(out);
+        test_with_builder(file_path.clone(), out_path, |source, target| {
+            crate::Builder::thrift()
+                .ignore_unused(false)
+                .decode_trace(backend)
+                .compile_with_config(
+                    vec![IdlService::from_path(source.to_path_buf())],
+                    crate::Output::File(target.into()),
+                )
+        });
+    }
+}
+
+#[test]
+fn test_external_type() {
+    let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("external");
+
+    // the hand-implemented `Money` the ledger refers to
+    test_with_builder(
+        dir.join("money.thrift"),
+        dir.join("money.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .ignore_unused(false)
+                .compile_with_config(
+                    vec![IdlService::from_path(source.to_path_buf())],
+                    crate::Output::File(target.into()),
+                )
+        },
+    );
+
+    test_with_builder(
+        dir.join("ledger.thrift"),
+        dir.join("ledger.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .ignore_unused(false)
+                .external_type("ledger.Money", "crate::test::tests::money::money::Money")
+                .external_type(
+                    "ledger.Currency",
+                    "crate::test::tests::money::money::Currency",
+                )
+                .compile_with_config(
+                    vec![IdlService::from_path(source.to_path_buf())],
+                    crate::Output::File(target.into()),
+                )
+        },
+    );
+
+    let ledger = fs::read_to_string(dir.join("ledger.rs")).unwrap();
+    assert!(!ledger.contains("pub struct Money"));
+    assert!(ledger.contains("pub total: crate::test::tests::money::money::Money,"));
+    assert!(ledger.contains("assert_message::<crate::test::tests::money::money::Money>"));
+    assert!(!ledger.contains("pub struct Currency"));
+    assert!(ledger.contains("<crate::test::tests::money::money::Currency>::inner"));
+}
+
+#[test]
+#[should_panic(expected = "external type `ledger.Missing` doesn't exist")]
+fn test_external_type_missing() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("external")
+        .join("ledger.thrift");
+
+    let dir = tempdir().unwrap();
+    crate::Builder::thrift()
+        .external_type("ledger.Missing", "crate::Missing")
+        .deny_warnings([crate::WarningKind::MissingItem])
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(dir.path().join("ledger.rs")),
+        );
+}
+
+#[test]
+fn test_file_loader() {
+    let files = std::collections::HashMap::from([
+        (
+            std::path::PathBuf::from("/virtual/idl/shop.thrift"),
+            "include \"../shared/money.thrift\"\n\nstruct Order {\n    1: required money.Money price,\n}\n",
+        ),
+        (
+            std::path::PathBuf::from("/virtual/shared/money.thrift"),
+            "struct Money {\n    1: required i64 cents,\n}\n",
+        ),
+    ]);
+    let dir = tempdir().unwrap();
+    let out = dir.path().join("shop.rs");
+    let loads = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+    let counter = loads.clone();
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .file_loader(move |path| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            files
+                .get(path)
+                .map(|text| text.to_string())
+                .ok_or_else(|| std::io::ErrorKind::NotFound.into())
+        })
+        .compile_with_config(
+            vec![IdlService::from_path("/virtual/idl/./shop.thrift".into())],
+            crate::Output::File(out.clone()),
+        );
+
+    let generated = fs::read_to_string(out).unwrap();
+    assert!(generated.contains("pub struct Order"));
+    assert!(generated.contains("pub price: super::money::Money"));
+    assert!(generated.contains("pub struct Money"));
+    // the include found by the probe isn't loaded again
+    assert_eq!(loads.load(std::sync::atomic::Ordering::Relaxed), 2);
+}
+
+fn idl_fingerprint(generated: &Path) -> String {
+    let generated = fs::read_to_string(generated).unwrap();
+    let (_, rest) = generated
+        .split_once(r#"pub const IDL_FINGERPRINT: &str = ""#)
+        .unwrap();
+    rest[..16].to_string()
+}
+
+#[test]
+fn test_idl_fingerprint() {
+    let dir = tempdir().unwrap();
+    let gen = |idl: &str, name: &str| {
+        let source = dir.path().join(name).join("shop.thrift");
+        fs::create_dir_all(source.parent().unwrap()).unwrap();
+        fs::write(&source, idl).unwrap();
+        let out = source.with_extension("rs");
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .idl_fingerprint(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source)],
+                crate::Output::File(out.clone()),
+            );
+        idl_fingerprint(&out)
+    };
+
+    let idl = "struct Order {\n    1: required i64 id,\n}\n";
+    let fingerprint = gen(idl, "a");
+    assert_eq!(gen(idl, "a"), fingerprint);
+    // the path of the idl doesn't matter
+    assert_eq!(gen(idl, "b"), fingerprint);
+    assert_ne!(
+        gen(&idl.replace("i64 id", "i64 order_id"), "a"),
+        fingerprint
+    );
+
+    let input_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_workspace")
+        .join("input");
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .idl_fingerprint(true)
+        .compile_with_config(
+            vec![
+                IdlService::from_path(input_dir.join("article.thrift")),
+                IdlService::from_path(input_dir.join("image.thrift")),
+            ],
+            crate::Output::Workspace(dir.path().join("workspace")),
+        );
+    let crate_fingerprint =
+        |name: &str| idl_fingerprint(&dir.path().join("workspace").join(name).join("src/gen.rs"));
+    assert_ne!(crate_fingerprint("article"), crate_fingerprint("image"));
+}
+
+fn compile_synthetic_service(service: &str) -> String {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("enums")
+        .join("enums.thrift");
+
+    let dir = tempdir().unwrap();
+    let synthetic = dir.path().join("synthetic.thrift");
+    fs::write(
+        &synthetic,
+        format!("include \"{}\"\n{service}", file_path.display()),
+    )
+    .unwrap();
+
+    let out_path = dir.path().join("out.rs");
+    crate::Builder::thrift()
+        .add_service(IdlService::from_path(synthetic))
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(out_path.clone()),
+        );
+    fs::read_to_string(out_path).unwrap()
+}
+
+#[test]
+fn test_add_service() {
+    let generated = compile_synthetic_service(
+        "service AccountService { enums.Account get(1: enums.Status status) }",
+    );
+    assert!(generated.contains("pub trait AccountService"));
+    // only used by the added service
+    assert!(generated.contains("pub struct Account"));
+}
+
+#[test]
+#[should_panic(expected = "can not find path")]
+fn test_add_service_unknown_type() {
+    compile_synthetic_service("service AccountService { enums.Missing get() }");
+}
+
+#[test]
+#[should_panic(expected = "can not find path")]
+fn test_init_service_add_service() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("enums")
+        .join("enums.thrift");
+
+    let dir = tempdir().unwrap();
+    let write = |name: &str, service: &str| {
+        let path = dir.path().join(name);
+        fs::write(
+            &path,
+            format!("include \"{}\"\n{service}", file_path.display()),
+        )
+        .unwrap();
+        path
+    };
+    let init = write("init.thrift", "service InitService { enums.Status get() }");
+    let added = write(
+        "added.thrift",
+        "service AddedService { enums.Missing get() }",
+    );
+
+    // the added services are built with the one to init
+    let _ = crate::Builder::thrift()
+        .add_service(IdlService::from_path(added))
+        .init_service(IdlService::from_path(init));
+}
+
+#[test]
+fn test_builtin_fmt() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift")
+        .join("const_val.thrift");
+
+    let dir = tempdir().unwrap();
+    let compile = |rustfmt: bool, out_path: &std::path::Path| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .rustfmt(rustfmt)
+            .indent_width(2)
+            .compile_with_config(
+                vec![IdlService::from_path(file_path.clone())],
+                crate::Output::File(out_path.into()),
+            );
+        fs::read_to_string(out_path).unwrap()
+    };
+    let builtin = compile(false, &dir.path().join("builtin.rs"));
+    let rustfmt = compile(true, &dir.path().join("rustfmt.rs"));
+
+    // only the whitespace changes
+    let tokens = |src: &str| src.parse::<proc_macro2::TokenStream>().unwrap().to_string();
+    assert_eq!(
+        tokens(&crate::fmt::pretty_print(&rustfmt, 2)),
+        tokens(&rustfmt)
+    );
+
+    // indented by the nesting of the lines
+    let mut depth = 0usize;
+    for line in builtin.lines() {
+        let code = line.trim_start();
+        if code.starts_with(['}', ')', ']']) {
+            depth -= 1;
+        }
+        assert_eq!(line.len() - code.len(), depth * 2, "line `{line}`");
+        if !code.starts_with("//") && code.ends_with(['{', '(', '[']) {
+            depth += 1;
+        }
+    }
+    assert_eq!(depth, 0);
+
+    let src = "fn f() { let list = ::std::vec![\"aaaaaaaaaaaaaaaaaaaa\", \"bbbbbbbbbbbbbbbbbbbb\", \"cccccccccccccccccccc\", \"dddddddddddddddddddd\"]; g(list); }";
+    assert_eq!(
+        crate::fmt::pretty_print(src, 2),
+        r#"fn f() {
+  let list = ::std::vec![
+    "aaaaaaaaaaaaaaaaaaaa",
+    "bbbbbbbbbbbbbbbbbbbb",
+    "cccccccccccccccccccc",
+    "dddddddddddddddddddd"
+  ];
+  g(list);
+}
+"#
+    );
+}
+
+#[test]
+fn test_sort_items() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("sort_items")
+        .join("sorted.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    let compile = |source: &Path, target: &Path| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .sort_items(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    };
+    test_with_builder(&file_path, &out_path, compile);
+
+    let dir = tempdir().unwrap();
+    let rerun = dir.path().join("sorted.rs");
+    compile(&file_path, &rerun);
+    let generated = fs::read_to_string(out_path).unwrap();
+    assert_eq!(generated, fs::read_to_string(rerun).unwrap());
+
+    let positions = [
+        "pub struct Animal",
+        "pub struct Keeper",
+        "pub struct Kind",
+        "pub const MAX_ITEMS",
+        "pub struct Park",
+        "pub struct Zoo ",
+        "pub trait ZooService",
+    ]
+    .map(|item| {
+        generated
+            .find(item)
+            .unwrap_or_else(|| panic!("`{item}` is not generated"))
+    });
+    assert!(positions.is_sorted(), "{positions:?}");
+}
+
+#[test]
+fn test_doc_links() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("doc_links")
+        .join("gallery.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path.clone(), |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .doc_links(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+
+    // the links resolve as the types of the fields do
+    let generated = fs::read_to_string(out_path).unwrap();
+    let image = "super::image::Image";
+    assert!(generated.contains(&format!("pub images: ::std::vec::Vec<{image}>")));
+    assert!(generated.contains(&format!(r#"#[doc = "[`Image`]({image})"]"#)));
+    assert!(generated.contains(r#"#[doc = "[`Layout`](Layout)"]"#));
+    assert!(generated.contains(&format!("Image({image})")));
+    // primitives aren't linked
+    assert_eq!(generated.matches("#[doc").count(), 4);
+}
+
+#[test]
+fn test_prelude_imports() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("prelude")
+        .join("shop.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path.clone(), |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .prelude_imports([
+                "std::sync::Arc".into(),
+                "::std::sync::Arc".into(),
+                "std::collections::BTreeMap".into(),
+            ])
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+
+    // once per module with items
+    let generated = fs::read_to_string(out_path).unwrap();
+    assert_eq!(generated.matches("use std::sync::Arc;").count(), 2);
+    assert_eq!(
+        generated.matches("use std::collections::BTreeMap;").count(),
+        2
+    );
+    assert!(!generated.contains("use ::std::sync::Arc;"));
+}
+
+#[test]
+fn test_max_items_per_file() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("max_items");
+    let file_path = test_data_dir.join("parts.thrift");
+    let gen_dir = test_data_dir.join("parts");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_split_builder(file_path, out_path, &gen_dir, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .sort_items(true)
+            .max_items_per_file(4)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+
+    let mod_rs = fs::read_to_string(gen_dir.join("mod.rs")).unwrap();
+    assert_eq!(
+        mod_rs.lines().collect::<Vec<_>>(),
+        [r#"include!("part_1.rs");"#, r#"include!("part_2.rs");"#]
+    );
+    let items = |i: usize| {
+        fs::read_to_string(gen_dir.join(format!("part_{i}.rs")))
+            .unwrap()
+            .lines()
+            .filter(|line| line.starts_with("pub struct "))
+            .count()
+    };
+    assert_eq!(items(1), 4);
+    assert_eq!(items(2), 2);
+}
+
+#[test]
+fn test_eq_ignore() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("eq_ignore")
+        .join("request.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path.clone(), |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .manual_hash(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+
+    let generated = fs::read_to_string(out_path).unwrap();
+    assert_eq!(
+        generated.matches("impl ::std::cmp::PartialEq for").count(),
+        2
+    );
+    // the types containing them don't hash either
+    assert!(!generated.contains("Hash for Batch"));
+    let compact = generated.split_whitespace().collect::<String>();
+    assert!(compact.contains(
+        "fneq(&self,other:&Self)->bool{self.path==other.path&&self.priority==other.priority}"
+    ));
+    // all the fields of `Trace` are ignored
+    assert!(compact.contains("PartialEqforTrace{fneq(&self,other:&Self)->bool{true}}"));
+}
+
+#[test]
+fn test_must_use() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("must_use")
+        .join("must_use.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path.clone(), |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .must_use(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+
+    let generated = fs::read_to_string(out_path).unwrap();
+    let must_use = |f: &str| {
+        let pos = generated.find(f).unwrap();
+        generated[..pos].trim_end().ends_with("#[must_use]")
+    };
+    for getter in [
+        "pub fn percent(&self)",
+        "pub fn offset(&self)",
+        "pub fn inner(&self)",
+        "pub fn to_string(&self)",
+    ] {
+        assert!(must_use(getter), "`{getter}` is not must_use");
+    }
+    for f in ["pub fn new(", "pub fn set_percent(", "pub fn set_offset("] {
+        assert!(!must_use(f), "`{f}` is must_use");
+    }
+}
+
+#[test]
+fn test_field_map() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("field_map")
+        .join("config.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .plugin(crate::plugin::FieldMapPlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_visit() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("visit")
+        .join("profile.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .plugin(crate::plugin::VisitPlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_stable_hash() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("stable_hash.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .plugin(crate::plugin::StableHashPlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_schema() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("schema")
+        .join("catalog.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .plugin(crate::plugin::SchemaPlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_service_result() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("service_result")
+        .join("store.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path.clone(), |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .service_result("::std::result::Result", "crate::test::tests::RpcError")
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+
+    let generated = fs::read_to_string(out_path).unwrap();
+    assert!(
+        generated.contains("Output = ::std::result::Result<Item, crate::test::tests::RpcError>")
+    );
+    assert!(generated
+        .contains("crate::test::tests::RpcError: ::std::convert::From<StoreGetException>;"));
+    assert!(generated.contains("fn ping(&self) -> impl ::std::future::Future<Output = ()> + Send;"));
+}
+
+#[test]
+fn test_service_stream() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("service_result")
+        .join("streaming.proto");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path.clone(), |source, target| {
+        crate::Builder::protobuf()
+            .ignore_unused(false)
+            .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+            .service_stream("crate::test::tests::Streaming")
+            .service_result("::std::result::Result", "crate::test::tests::RpcError")
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+
+    let generated = fs::read_to_string(out_path).unwrap();
+    let sig = |name: &str, req: &str, resp: &str| {
+        format!(
+            "fn {name}(&self, req: {req}) -> impl ::std::future::Future<Output = ::std::result::Result<{resp}, crate::test::tests::RpcError>> + Send;"
+        )
+    };
+    let stream = |ty: &str| format!("crate::test::tests::Streaming<{ty}>");
+    // rustfmt breaks and adds trailing commas to the long signatures
+    let normalize = |s: &str| {
+        s.split_whitespace()
+            .collect::<String>()
+            .replace(",>", ">")
+            .replace(",)", ")")
+    };
+    let generated = normalize(&generated);
+    for sig in [
+        sig("get", "Summary", "Chunk"),
+        sig("upload", &stream("Chunk"), "Summary"),
+        sig("download", "Summary", &stream("Chunk")),
+        sig("sync", &stream("Chunk"), &stream("Chunk")),
+    ] {
+        let sig = normalize(&sig);
+        assert!(generated.contains(&sig), "`{sig}` is not generated");
+    }
+}
+
+#[test]
+fn test_optional_default() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("optional_default")
+        .join("settings.thrift");
+
+    for (optional_default, out) in [
+        (crate::OptionalDefault::OptionNone, "settings_none.rs"),
+        (crate::OptionalDefault::Value, "settings_value.rs"),
+    ] {
+        let out_path = file_path.with_file_name(out);
+        test_with_builder(file_path.clone(), out_path, |source, target| {
+            crate::Builder::thrift()
+                .ignore_unused(false)
+                .optional_default(optional_default)
+                .compile_with_config(
+                    vec![IdlService::from_path(source.to_path_buf())],
+                    crate::Output::File(target.into()),
+                )
+        });
+    }
+}
+
+#[test]
+#[should_panic(expected = "`pilota.serde_flatten` is only valid on struct or map typed fields")]
+fn test_serde_flatten_invalid() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("serde_flatten_invalid.thrift");
+
+    let dir = tempdir().unwrap();
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .plugin(SerdePlugin)
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(dir.path().join("serde_flatten_invalid.rs")),
+        );
+}
+
+#[test]
+#[should_panic(
+    expected = "`packed_presence` isn't supported by serde, but `scores::Score` has packed fields"
+)]
+fn test_serde_packed_presence() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("packed_presence")
+        .join("scores.thrift");
+
+    let dir = tempdir().unwrap();
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .packed_presence(true)
+        .plugin(SerdePlugin)
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(dir.path().join("scores.rs")),
+        );
+}
+
+#[test]
+fn test_proto_json() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("proto_json")
+        .join("json_name.proto");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::protobuf()
+            .ignore_unused(false)
+            .proto_json(true)
+            .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+            .plugin(SerdePlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_serde_union_repr() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("serde_union")
+        .join("shape.thrift");
+
+    let out_path = file_path.with_file_name("internal.rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .serde_union_repr(crate::SerdeUnionRepr::Internal { tag: "type".into() })
+            .plugin(SerdePlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_serde_bytes() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("serde_bytes")
+        .join("blob.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .serde_bytes(crate::tags::SerdeBytes::Base64)
+            .plugin(SerdePlugin)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_iter_accessors() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("iter_accessors")
+        .join("tags.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .iter_accessors(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_package_change_case() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("change_case")
+        .join("main.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .package_change_case("legacy", false)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+#[should_panic(expected = "package `legacyy` of `package_change_case` doesn't exist")]
+fn test_package_change_case_missing() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("change_case")
+        .join("main.thrift");
+
+    let dir = tempdir().unwrap();
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .package_change_case("legacyy", false)
+        .deny_warnings([crate::WarningKind::MissingItem])
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(dir.path().join("main.rs")),
+        );
+}
+
+#[test]
+fn test_package_facade() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("package_facade")
+        .join("storefront.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .package_facade(crate::PackageFacade::Flatten)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_manual_clone() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("manual_clone")
+        .join("tree.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .manual_clone(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_skip_services() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("skip_services.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, &out_path, |source, target| {
+        crate::Builder::thrift()
+            .skip_services(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+
+    let generated = fs::read_to_string(out_path).unwrap();
+    assert!(!generated.contains("pub trait Echo"));
+    assert!(!generated.contains("EchoEchoArgsSend"));
+    // the types of the methods are only referenced by the service, and are
+    // still generated
+    assert!(generated.contains("pub struct Request"));
+    assert!(generated.contains("pub struct Response"));
+    assert!(generated.contains("pub struct EchoError"));
+    assert!(!generated.contains("pub struct Standalone"));
+}
+
+#[test]
+fn test_str_accessors() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("str_accessors.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .str_accessors(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_item_tags() {
+    let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("item_tags");
+
+    test_with_builder(
+        dir.join("catalog.thrift"),
+        dir.join("catalog.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .ignore_unused(false)
+                .item_tags_file(dir.join("catalog.toml"))
+                .compile_with_config(
+                    vec![IdlService::from_path(source.to_path_buf())],
+                    crate::Output::File(target.into()),
+                )
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "tagged item `catalog.Missing` doesn't exist")]
+fn test_item_tags_missing() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("item_tags")
+        .join("catalog.thrift");
+
+    let dir = tempdir().unwrap();
+    crate::Builder::thrift()
+        .item_tag("catalog.Missing", "pilota.name", "Gone")
+        .deny_warnings([crate::WarningKind::MissingItem])
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(dir.path().join("catalog.rs")),
+        );
+}
+
+#[test]
+#[should_panic(expected = "`pilota.rust_typ` isn't a pilota annotation")]
+fn test_item_tags_unsupported() {
+    let _ = crate::Builder::thrift().item_tag("catalog.Product.sku", "pilota.rust_typ", "string");
+}
+
+#[test]
+fn test_newtype_as_ref() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("newtype_as_ref.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .newtype_as_ref(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_unknown_enum() {
+    let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("unknown_enum");
+
+    for (name, policy) in [
+        ("preserve", crate::UnknownEnum::Preserve),
+        ("error", crate::UnknownEnum::Error),
+        ("default", crate::UnknownEnum::Default),
+    ] {
+        test_with_builder(
+            dir.join(format!("level_{name}.thrift")),
+            dir.join(format!("level_{name}.rs")),
+            |source, target| {
+                crate::Builder::thrift()
+                    .ignore_unused(false)
+                    .unknown_enum(policy)
+                    .compile_with_config(
+                        vec![IdlService::from_path(source.to_path_buf())],
+                        crate::Output::File(target.into()),
+                    )
+            },
+        );
+    }
+    for (name, policy) in [
+        ("error", crate::UnknownEnum::Error),
+        ("default", crate::UnknownEnum::Default),
+    ] {
+        test_with_builder(
+            dir.join(format!("status_{name}.proto")),
+            dir.join(format!("status_{name}.rs")),
+            |source, target| {
+                crate::Builder::protobuf()
+                    .ignore_unused(false)
+                    .unknown_enum(policy)
+                    .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                    .compile_with_config(
+                        vec![IdlService::from_path(source.to_path_buf())],
+                        crate::Output::File(target.into()),
+                    )
+            },
+        );
+    }
+}
+
+#[test]
+fn test_field_number_consts() {
+    let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("field_number_consts");
+
+    test_with_builder(
+        dir.join("member.thrift"),
+        dir.join("member.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .ignore_unused(false)
+                .field_number_consts(true)
+                .compile_with_config(
+                    vec![IdlService::from_path(source.to_path_buf())],
+                    crate::Output::File(target.into()),
+                )
+        },
+    );
+
+    test_with_builder(
+        dir.join("ticket.proto"),
+        dir.join("ticket.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .ignore_unused(false)
+                .field_number_consts(true)
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .compile_with_config(
+                    vec![IdlService::from_path(source.to_path_buf())],
+                    crate::Output::File(target.into()),
+                )
+        },
+    );
+}
+
+#[test]
+fn test_remap_mods() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("remap_mods")
+        .join("checkout.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path.clone(), |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .remap_mods(|path| {
+                let prefix: [crate::Symbol; 3] = ["a".into(), "b".into(), "c".into()];
+                match path.strip_prefix(&prefix[..]) {
+                    Some(rest) => std::iter::once("api".into())
+                        .chain(rest.iter().cloned())
+                        .collect::<Vec<_>>()
+                        .into(),
+                    None => path.clone(),
+                }
+            })
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+
+    let generated = std::fs::read_to_string(out_path).unwrap();
+    assert!(generated.contains("pub mod api {"));
+    assert!(!generated.contains("pub mod a {"));
+    assert!(generated.contains("super::api::Money"));
+}
+
+#[test]
+#[should_panic(
+    expected = "`remap_mods` moved items to the same path:\n`api::Money` is generated for `checkout::Money`, `a::b::c::Money`"
+)]
+fn test_remap_mods_clash() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("remap_mods")
+        .join("clash.thrift");
+    let dir = tempdir().unwrap();
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .remap_mods(|_| crate::rir::ItemPath::from(vec![crate::Symbol::from("api")]))
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(dir.path().join("clash.rs")),
+        )
+}
+
+#[test]
+#[should_panic(expected = "`remap_mods` only applies to the single file outputs")]
+fn test_remap_mods_workspace() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("remap_mods")
+        .join("checkout.thrift");
+    let dir = tempdir().unwrap();
+
+    crate::Builder::thrift()
+        .remap_mods(|path| path.clone())
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::Workspace(dir.path().into()),
+        )
+}
+
+#[test]
+fn test_default_strategy() {
+    let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("default_strategy");
+
+    for (name, strategy) in [
+        ("derive", crate::DefaultStrategy::Derive),
+        ("manual", crate::DefaultStrategy::Manual),
+        ("from_idl", crate::DefaultStrategy::FromIdl),
+    ] {
+        test_with_builder(
+            dir.join(format!("settings_{name}.thrift")),
+            dir.join(format!("settings_{name}.rs")),
+            |source, target| {
+                crate::Builder::thrift()
+                    .ignore_unused(false)
+                    .default_strategy(strategy)
+                    .compile_with_config(
+                        vec![IdlService::from_path(source.to_path_buf())],
+                        crate::Output::File(target.into()),
+                    )
+            },
+        );
+    }
+}
+
+#[test]
+fn test_roundtrip_tests() {
+    let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("roundtrip");
+
+    test_with_builder(
+        dir.join("account.thrift"),
+        dir.join("account.rs"),
+        |source, target| {
+            crate::Builder::thrift()
+                .ignore_unused(false)
+                .roundtrip_tests(true)
+                .compile_with_config(
+                    vec![IdlService::from_path(source.to_path_buf())],
+                    crate::Output::File(target.into()),
+                )
+        },
+    );
+    test_with_builder(
+        dir.join("session.proto"),
+        dir.join("session.rs"),
+        |source, target| {
+            crate::Builder::protobuf()
+                .ignore_unused(false)
+                .roundtrip_tests(true)
+                .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+                .compile_with_config(
+                    vec![IdlService::from_path(source.to_path_buf())],
+                    crate::Output::File(target.into()),
+                )
+        },
+    );
+
+    let generated = fs::read_to_string(dir.join("account.rs")).unwrap();
+    for module in [
+        "account_roundtrip",
+        "address_roundtrip",
+        "account_not_found_roundtrip",
+    ] {
+        assert!(generated.contains(&format!("mod {module}")), "{module}");
+    }
+    let generated = fs::read_to_string(dir.join("session.rs")).unwrap();
+    for module in ["session_roundtrip", "device_roundtrip"] {
+        assert!(generated.contains(&format!("mod {module}")), "{module}");
+    }
+}
+
+#[test]
+fn test_newtype_from_str() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("newtype_from_str.thrift");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .newtype_from_str(true)
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+#[should_panic(
+    expected = "the json name `contact` of `proto_json::User::address` is also the one of `proto_json::User::contact` in `proto_json::User`"
+)]
+fn test_proto_json_conflict() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("proto_json")
+        .join("json_name_conflict.proto");
+
+    let dir = tempdir().unwrap();
+
+    crate::Builder::protobuf()
+        .ignore_unused(false)
+        .proto_json(true)
+        .include_dirs(vec![file_path.parent().unwrap().to_path_buf()])
+        .plugin(SerdePlugin)
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(dir.path().join("json_name_conflict.rs")),
+        );
+}
+
+#[test]
+#[should_panic(expected = "typedef cycle: `Y` -> `X` -> `Y`")]
+fn test_typedef_cycle() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("typedef_cycle")
+        .join("cycle.thrift");
+
+    let dir = tempdir().unwrap();
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(dir.path().join("cycle.rs")),
+        );
+}
+
+#[test]
+fn test_prost_convert() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("prost_convert.proto");
+
+    let mut out_path = file_path.clone();
+    out_path.set_extension("rs");
+
+    test_with_builder(file_path, out_path, |source, target| {
+        crate::Builder::protobuf()
+            .ignore_unused(false)
+            .include_dirs(vec![source.parent().unwrap().to_path_buf()])
+            .plugin(ProstConvertPlugin::new("crate::test::tests::prost_stub"))
+            .compile_with_config(
+                vec![IdlService::from_path(source.to_path_buf())],
+                crate::Output::File(target.into()),
+            )
+    });
+}
+
+#[test]
+fn test_lint_allows() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift")
+        .join("multi.thrift");
+
+    let dir = tempdir().unwrap();
+    let out_path = dir.path().join("multi.rs");
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .lint_allows(["dead_code".into(), "clippy::all".into()])
+        .compile_with_config(
+            vec![IdlService::from_path(file_path.clone())],
+            crate::Output::File(out_path.clone()),
+        );
+
+    let content = fs::read_to_string(&out_path).unwrap();
+    assert_eq!(
+        content.matches("#![allow(dead_code, clippy::all)]").count(),
+        1
+    );
+    assert_eq!(content.matches("#![allow(").count(), 1);
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .lint_allows([])
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(out_path.clone()),
+        );
+
+    let content = fs::read_to_string(&out_path).unwrap();
+    assert!(!content.contains("#![allow("));
+}
+
+#[test]
+fn test_consts_referenced_by() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("const_ref.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    assert_eq!(
+        cx.consts_referenced_by(def_id(&cx, "A")),
+        vec![def_id(&cx, "OTHER")]
+    );
+
+    let mut consts = cx.consts_referenced_by(def_id(&cx, "B"));
+    consts.sort();
+    let mut expected = vec![
+        def_id(&cx, "BASE"),
+        def_id(&cx, "DERIVED"),
+        def_id(&cx, "OTHER"),
+    ];
+    expected.sort();
+    assert_eq!(consts, expected);
+
+    assert_eq!(cx.consts_referenced_by(def_id(&cx, "BASE")), vec![]);
+}
+
+#[test]
+fn test_name_collisions() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("name_collision.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path.clone())],
+        None,
+        all_items(),
+    );
+
+    let collisions = cx.check_name_collisions();
+    assert_eq!(collisions.len(), 2);
+
+    assert_eq!(collisions[0].rust_name, "name_collision::FooBar");
+    assert_eq!(
+        collisions[0].infos,
+        vec!["name_collision::foo_bar", "name_collision::FooBar"]
+    );
+    assert!(collisions[0]
+        .def_ids
+        .iter()
+        .all(|def_id| cx.codegen_items.contains(def_id)));
+
+    assert_eq!(collisions[1].rust_name, "name_collision::FooBar::my_field");
+    assert_eq!(
+        collisions[1].infos,
+        vec![
+            "name_collision::foo_bar::my_field",
+            "name_collision::foo_bar::myField"
+        ]
+    );
+
+    let err = crate::Builder::thrift()
+        .deny_name_collisions(true)
+        .init_service(IdlService::from_path(file_path))
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("`name_collision::FooBar::my_field` is generated for"));
+}
+
+#[test]
+fn test_package_of() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("package_of")
+        .join("a.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let package = |name: &str| {
+        cx.package_of(def_id(&cx, name))
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(package("A"), ["a", "api"]);
+    // items and fields of included files keep their own package
+    assert_eq!(package("B"), ["b"]);
+    assert_eq!(package("id"), ["b"]);
+    // items generated for services belong to the file of the service
+    assert_eq!(package("SPingArgsSend"), ["a", "api"]);
+}
+
+#[test]
+fn test_external_type_refs() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("external_refs.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        BuildOptions {
+            external_types: vec![
+                ("external_refs.Timestamp".into(), "::time::Timestamp".into()),
+                ("external_refs.Duration".into(), "::time::Duration".into()),
+            ]
+            .into_iter()
+            .collect(),
+            ..all_items()
+        },
+    );
+
+    // referred to by several items and the service, but listed once, and
+    // the unused `Duration` isn't
+    let refs = cx.external_type_refs();
+    assert_eq!(refs.len(), 1);
+    assert!(refs.contains("::time::Timestamp"));
+}
+
+#[test]
+fn test_is_emitted() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("is_emitted.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        BuildOptions {
+            ignore_unused: true,
+            external_types: vec![("is_emitted.Money".into(), "::money::Money".into())]
+                .into_iter()
+                .collect(),
+            ..all_items()
+        },
+    );
+
+    assert!(cx.is_emitted(def_id(&cx, "Used")));
+    assert!(cx.is_emitted(def_id(&cx, "S")));
+    // fields are emitted with their item
+    assert!(cx.is_emitted(def_id(&cx, "price")));
+    // pruned as unused
+    assert!(!cx.is_emitted(def_id(&cx, "Unused")));
+    // replaced with the external type
+    assert!(!cx.is_emitted(def_id(&cx, "Money")));
+    assert!(!cx.is_emitted(def_id(&cx, "cents")));
+}
+
+#[test]
+fn test_variant_discr() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("variant_discr.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let discr = |name: &str| cx.variant_discr(def_id(&cx, name));
+
+    assert_eq!(discr("Unset"), 0);
+    assert_eq!(discr("Active"), 5);
+    assert_eq!(discr("Paused"), 6);
+    assert_eq!(discr("Stopped"), 2);
+    assert_eq!(discr("Archived"), 3);
+
+    // union variants have no explicit discriminant
+    assert_eq!(discr("circle"), 0);
+    assert_eq!(discr("square"), 1);
+}
+
+#[test]
+fn test_needs_lazy_static() {
+    use std::sync::Arc;
+
+    use crate::ty::CodegenTy;
+
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("variant_discr.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let str = Arc::new(CodegenTy::Str);
+    assert!(cx.needs_lazy_static(&CodegenTy::Map(str.clone(), str.clone())));
+    assert!(cx.needs_lazy_static(&CodegenTy::Vec(str.clone())));
+    assert!(cx.needs_lazy_static(&CodegenTy::String));
+    assert!(!cx.needs_lazy_static(&CodegenTy::I32));
+    assert!(!cx.needs_lazy_static(&CodegenTy::Str));
+
+    // a const array is only lazy if its elements are
+    assert!(!cx.needs_lazy_static(&CodegenTy::Array(str.clone(), 2)));
+    let list = Arc::new(CodegenTy::Vec(str.clone()));
+    assert!(cx.needs_lazy_static(&CodegenTy::Array(list, 2)));
+    let set = Arc::new(CodegenTy::Set(str));
+    assert!(cx.needs_lazy_static(&CodegenTy::Array(set, 2)));
+}
+
+#[test]
+fn test_node_tag() {
+    use std::{path::PathBuf, sync::Arc};
+
+    use faststr::FastStr;
+
+    use crate::{
+        parser::{ParseResult, ThriftParser},
+        tags::{PilotaName, StringType},
+    };
+
+    /// A tag of the users, set by their parser.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Owner(FastStr);
+
+    struct OwnerParser(ThriftParser);
+
+    impl Parser for OwnerParser {
+        fn input<P: AsRef<Path>>(&mut self, path: P) {
+            self.0.input(path)
+        }
+
+        fn include_dirs(&mut self, dirs: Vec<PathBuf>) {
+            self.0.include_dirs(dirs)
+        }
+
+        fn parse(self) -> ParseResult {
+            let mut result = self.0.parse();
+            for file in &mut result.files {
+                for item in &mut Arc::make_mut(file).items {
+                    if let crate::ir::ItemKind::Message(m) = &mut Arc::make_mut(item).kind {
+                        m.fields
+                            .iter_mut()
+                            .filter(|f| &**f.name == "owned")
+                            .for_each(|f| Arc::make_mut(&mut f.tags).insert(Owner("me".into())));
+                    }
+                }
+            }
+            result
+        }
+    }
+
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift")
+        .join("string_type.thrift");
+
+    let cx = build_cx(
+        OwnerParser(ThriftParser::default()),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    assert_eq!(
+        cx.node_tag::<Owner>(def_id(&cx, "owned")),
+        Some(Owner("me".into()))
+    );
+    assert_eq!(cx.node_tag::<Owner>(def_id(&cx, "fast")), None);
+    assert_eq!(
+        cx.node_tag::<StringType>(def_id(&cx, "owned")),
+        Some(StringType::String)
+    );
+    assert_eq!(
+        cx.node_tag::<StringType>(def_id(&cx, "fast_default")),
+        Some(StringType::FastStr)
+    );
+    assert_eq!(cx.node_tag::<StringType>(def_id(&cx, "fast")), None);
+    assert!(cx.node_tag::<PilotaName>(def_id(&cx, "owned")).is_none());
+}
+
+#[test]
+fn test_item_generics() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("cow")
+        .join("cow.thrift");
+
+    let mut parser = crate::parser::ThriftParser::default();
+    parser.cow_fields(true);
+
+    let cx = build_cx(
+        parser,
+        vec![IdlService::from_path(file_path.clone())],
+        None,
+        all_items(),
+    );
+
+    let generated = fs::read_to_string(file_path.with_extension("rs")).unwrap();
+    for (name, expected) in [("Doc", "<'cow>"), ("Inner", "<'cow>"), ("Plain", "")] {
+        let generics = cx.item_generics(def_id(&cx, name));
+        assert_eq!(generics, expected);
+        assert!(generated.contains(&format!("pub struct {name}{generics} {{")));
+    }
+    // the items wrapping the borrowing ones borrow too
+    for name in ["Alias", "Part"] {
+        assert_eq!(cx.item_generics(def_id(&cx, name)), "<'cow>");
+    }
+}
+
+fn wire_types(cx: &crate::Context) -> std::collections::HashMap<String, u8> {
+    let crate::rir::Item::Message(m) = &*cx.item(def_id(cx, "Sample")).unwrap() else {
+        panic!("`Sample` is not a message")
+    };
+    m.fields
+        .iter()
+        .map(|f| (f.name.to_string(), cx.wire_type_of(f)))
+        .collect()
+}
+
+#[test]
+fn test_all_fields() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("all_fields.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let is_bytes = |ty: &crate::ty::Ty| matches!(ty.kind, crate::ty::TyKind::Bytes);
+    let mut bytes_fields = cx
+        .all_fields()
+        .filter(|(_, f)| is_bytes(&f.ty))
+        .map(|(def_id, f)| format!("{}.{}", cx.rust_name(def_id), f.name))
+        .collect::<Vec<_>>();
+    bytes_fields.sort();
+    // the arguments of the thrift methods are wrapped in messages too
+    assert_eq!(
+        bytes_fields,
+        [
+            "Attachment.content",
+            "Attachment.thumbnail",
+            "Mail.signature",
+            "MailServiceSendArgsRecv.key",
+            "MailServiceSendArgsSend.key"
+        ]
+    );
+    assert_eq!(
+        cx.all_variants()
+            .filter(|(_, v)| v.fields.iter().any(is_bytes))
+            .count(),
+        1
+    );
+    assert_eq!(cx.all_args().filter(|(_, a)| is_bytes(&a.ty)).count(), 1);
+}
+
+#[test]
+fn test_path_str() {
+    let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("protobuf");
+
+    let mut parser = crate::parser::ProtobufParser::default();
+    crate::parser::Parser::include_dirs(&mut parser, vec![dir.clone()]);
+
+    let cx = build_cx(
+        parser,
+        vec![IdlService::from_path(dir.join("nested_message.proto"))],
+        None,
+        BuildOptions {
+            source_type: SourceType::Protobuf,
+            ..all_items()
+        },
+    );
+
+    // the file has no package, its messages are at the root
+    assert_eq!(cx.mod_path_str(def_id(&cx, "TT1")), "");
+    assert_eq!(cx.item_path_str(def_id(&cx, "TT1")), "Tt1");
+    assert_eq!(cx.mod_path_str(def_id(&cx, "TT3")), "tt1::t2");
+    assert_eq!(cx.item_path_str(def_id(&cx, "TT3")), "tt1::t2::Tt3");
+}
+
+#[test]
+fn test_wire_type_of_protobuf() {
+    let dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("wire_type");
+
+    let mut parser = crate::parser::ProtobufParser::default();
+    crate::parser::Parser::include_dirs(&mut parser, vec![dir.clone()]);
+
+    let cx = build_cx(
+        parser,
+        vec![IdlService::from_path(dir.join("wire_type.proto"))],
+        None,
+        BuildOptions {
+            source_type: SourceType::Protobuf,
+            ..all_items()
+        },
+    );
+
+    let wire_types = wire_types(&cx);
+    for (name, wire_type) in [
+        ("id", 0),
+        ("flag", 0),
+        ("status", 0),
+        ("delta", 0),
+        ("hash", 1),
+        ("ratio", 1),
+        ("offset", 1),
+        ("crc", 5),
+        ("score", 5),
+        ("name", 2),
+        ("payload", 2),
+        ("inner", 2),
+        ("counts", 2),
+        ("tags", 2),
+        // repeated scalars are encoded unpacked
+        ("values", 0),
+        ("stamps", 1),
+    ] {
+        assert_eq!(wire_types[name], wire_type, "field `{name}`");
+    }
+}
+
+#[test]
+fn test_wire_type_of_thrift() {
+    use pilota::thrift::TType;
+
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("wire_type")
+        .join("wire_type.thrift");
+
+    let parser = crate::parser::ThriftParser::default();
+
+    let cx = build_cx(
+        parser,
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let wire_types = wire_types(&cx);
+    for (name, ttype) in [
+        ("flag", TType::Bool),
+        ("id", TType::I64),
+        ("at", TType::I64),
+        ("ratio", TType::Double),
+        ("name", TType::Binary),
+        ("payload", TType::Binary),
+        ("status", TType::I32),
+        ("inner", TType::Struct),
+        ("values", TType::List),
+        ("tags", TType::Set),
+        ("counts", TType::Map),
+    ] {
+        assert_eq!(wire_types[name], ttype as u8, "field `{name}`");
+    }
+}
+
+#[test]
+fn test_enums() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("enums")
+        .join("enums.thrift");
+
+    let parser = crate::parser::ThriftParser::default();
+
+    let cx = build_cx(
+        parser,
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    // the union `Payload` has no discriminants
+    let enums = cx.enums();
+    assert_eq!(enums.len(), 1);
+    let (def_id, variants) = &enums[0];
+    assert_eq!(&*cx.rust_name(*def_id), "Status");
+    assert_eq!(
+        variants
+            .iter()
+            .map(|(name, discr)| (name.to_string(), *discr))
+            .collect::<Vec<_>>(),
+        [
+            ("ACTIVE".to_string(), 0),
+            ("INACTIVE".to_string(), 1),
+            ("BANNED".to_string(), 10),
+            ("DELETED".to_string(), 11),
+            ("ALSO_BANNED".to_string(), 10),
+        ]
+    );
+    assert_eq!(cx.enum_repr(*def_id), crate::ty::CodegenTy::I32);
+
+    let payload = cx
+        .codegen_items
+        .iter()
+        .find(|def_id| &*cx.rust_name(**def_id) == "Payload")
+        .unwrap();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| cx.enum_repr(*payload)));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_is_assignable() {
+    use std::sync::Arc;
+
+    use crate::ty::{AdtDef, AdtKind, CodegenTy::*};
+
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("enums")
+        .join("enums.thrift");
+
+    let parser = crate::parser::ThriftParser::default();
+
+    let cx = build_cx(
+        parser,
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let adt = |name: &str, kind: AdtKind| {
+        Adt(AdtDef {
+            did: def_id(&cx, name),
+            kind,
+        })
+    };
+    let status = adt("Status", AdtKind::Enum);
+    let account = adt("Account", AdtKind::Struct);
+    let wrapper = adt("Payload", AdtKind::NewType(Arc::new(FastStr)));
+
+    for (from, to) in [
+        (Str, FastStr),
+        (Str, String),
+        (Str, Bytes),
+        (I64, I8),
+        (I32, F64),
+        (I32, Bool),
+        (I64, status.clone()),
+        (status.clone(), I32),
+        (F64, OrderedF64),
+        (Str, wrapper.clone()),
+        (account.clone(), account.clone()),
+        (Vec(Arc::new(Str)), Set(Arc::new(FastStr))),
+        (
+            Map(Arc::new(Str), Arc::new(I32)),
+            StaticRef(Arc::new(BTreeMap(Arc::new(FastStr), Arc::new(I64)))),
+        ),
+    ] {
+        assert!(cx.is_assignable(&from, &to), "{from:?} into {to:?}");
+    }
+
+    for (from, to) in [
+        (FastStr, Str),
+        (F64, I32),
+        (Bool, I32),
+        (status.clone(), F64),
+        (account.clone(), status.clone()),
+        (I32, wrapper),
+        (Vec(Arc::new(I32)), Vec(Arc::new(Str))),
+        (Vec(Arc::new(I32)), Map(Arc::new(I32), Arc::new(I32))),
+    ] {
+        assert!(!cx.is_assignable(&from, &to), "{from:?} into {to:?}");
+    }
+}
+
+#[test]
+fn test_resolve_aliases() {
+    use std::sync::Arc;
+
+    use crate::ty::CodegenTy::*;
+
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("resolve_aliases.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let ty = |name: &str| {
+        let def_id = cx
+            .codegen_items
+            .iter()
+            .find(|def_id| &*cx.rust_name(**def_id) == name)
+            .unwrap();
+        cx.codegen_ty(*def_id)
+    };
+    let scores = Map(Arc::new(FastStr), Arc::new(I32));
+
+    assert_eq!(cx.resolve_aliases(&ty("Scores")), scores);
+    assert_eq!(cx.resolve_aliases(&ty("Board")), scores);
+    assert_eq!(cx.resolve_aliases(&ty("Boards")), Vec(Arc::new(scores)));
+    // the messages are kept
+    assert_eq!(cx.resolve_aliases(&ty("Player")), ty("Player"));
+}
+
+#[test]
+fn test_empty_services() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("empty_services.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let names = cx
+        .empty_services()
+        .into_iter()
+        .map(|def_id| cx.rust_name(def_id).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["Empty"]);
+}
+
+#[test]
+fn test_enum_mode() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("enum_mode.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let mode = |name: &str| {
+        let def_id = cx
+            .codegen_items
+            .iter()
+            .find(|def_id| &*cx.rust_name(**def_id) == name)
+            .unwrap();
+        cx.enum_mode(*def_id)
+    };
+    assert_eq!(mode("Status"), Some(crate::EnumMode::NewType));
+    assert_eq!(mode("Payload"), Some(crate::EnumMode::Enum));
+    assert_eq!(mode("Event"), None);
+}
+
+#[test]
+fn test_method_sigs() {
+    use crate::ty::{AdtDef, CodegenTy};
+
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("method_sigs.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let is_adt = |ty: &CodegenTy, name: &str| matches!(ty, CodegenTy::Adt(AdtDef { did, .. }) if *did == def_id(&cx, name));
+
+    let sigs = cx.method_sigs(def_id(&cx, "S"), false);
+    assert_eq!(sigs.len(), 2);
+
+    let echo = &sigs[0];
+    assert_eq!(&*echo.name, "echo");
+    assert_eq!(echo.args.len(), 2);
+    assert_eq!(&*echo.args[0].0, "req");
+    assert!(is_adt(&echo.args[0].1, "Req"));
+    assert_eq!(&*echo.args[1].0, "times");
+    assert_eq!(echo.args[1].1, CodegenTy::I32);
+    assert!(is_adt(&echo.ret, "Req"));
+    assert!(!echo.is_oneway);
+    assert_eq!(echo.exceptions.len(), 1);
+    assert_eq!(&*echo.exceptions[0].0, "E");
+    assert!(is_adt(&echo.exceptions[0].1, "E"));
+    assert_eq!(echo.extended_from, None);
+
+    let fire = &sigs[1];
+    assert_eq!(&*fire.name, "fire");
+    assert_eq!(fire.args, [("id".into(), CodegenTy::I64)]);
+    assert_eq!(fire.ret, CodegenTy::Void);
+    assert!(fire.is_oneway);
+    assert!(fire.exceptions.is_empty());
+
+    let sigs = cx.method_sigs(def_id(&cx, "S"), true);
+    assert_eq!(sigs.len(), 3);
+    assert_eq!(&*sigs[0].name, "ping");
+    assert_eq!(sigs[0].extended_from, Some(def_id(&cx, "Base")));
+    assert_eq!(sigs[1], cx.method_sigs(def_id(&cx, "S"), false)[0]);
+}
+
+#[test]
+fn test_method_annotations() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("method_annotations.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let sigs = cx.method_sigs(def_id(&cx, "Gateway"), false);
+    let paths = |i: usize| cx.method_annotations(sigs[i].def_id, "http.path");
+
+    assert_eq!(paths(0), ["/items/:id"]);
+    assert_eq!(
+        cx.method_annotations(sigs[0].def_id, "http.method"),
+        ["GET"]
+    );
+    // repeated keys are all kept in order, along with the interpreted ones
+    assert_eq!(&*sigs[1].name, "find");
+    assert_eq!(paths(1), ["/search", "/find"]);
+    assert_eq!(
+        cx.method_annotations(sigs[1].def_id, "pilota.name"),
+        ["find"]
+    );
+    assert!(paths(2).is_empty());
+}
+
+#[test]
+fn test_related_nodes() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("method_sigs.thrift");
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![IdlService::from_path(file_path)],
+        None,
+        all_items(),
+    );
+
+    let related = |name: &str| {
+        let mut names = cx
+            .related_nodes(def_id(&cx, name))
+            .into_iter()
+            .map(|def_id| cx.node(def_id).unwrap().name().to_string())
+            .collect::<Vec<_>>();
+        names.sort();
+        names
+    };
+
+    assert_eq!(
+        related("S"),
+        [
+            "SEchoArgsRecv",
+            "SEchoArgsSend",
+            "SEchoException",
+            "SEchoResultRecv",
+            "SEchoResultSend",
+            "SFireArgsRecv",
+            "SFireArgsSend",
+            "SFireResultRecv",
+            "SFireResultSend",
+        ]
+    );
+    // the items of the extended service belong to it
+    assert_eq!(related("Base").len(), 4);
+    assert!(related("Req").is_empty());
+}
+
+#[test]
+fn test_method_item_naming() {
+    use itertools::Itertools;
+
+    use crate::MethodItemNaming;
+
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("method_item_naming.thrift");
+
+    let gen = |naming| {
+        let dir = tempdir().unwrap();
+        let out_path = dir.path().join("method_item_naming.rs");
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .method_item_naming(naming)
+            .compile_with_config(
+                vec![IdlService::from_path(file_path.clone())],
+                crate::Output::File(out_path.clone()),
+            );
+        std::fs::read_to_string(out_path).unwrap()
+    };
+    let items = |code: &str| {
+        code.lines()
+            .filter_map(|l| {
+                let l = l.trim();
+                l.strip_prefix("pub struct ")
+                    .or_else(|| l.strip_prefix("pub enum "))
+                    .map(|l| l.split([' ', '{']).next().unwrap().to_string())
+            })
+            .filter(|name| name != "E" && name != "A" && name != "B")
+            .sorted()
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        items(&gen(MethodItemNaming::ServiceMethod)),
+        [
+            "APingArgsRecv",
+            "APingArgsSend",
+            "APingResultRecv",
+            "APingResultSend",
+            "BOnlyBArgsRecv",
+            "BOnlyBArgsSend",
+            "BOnlyBException",
+            "BOnlyBResultRecv",
+            "BOnlyBResultSend",
+            "BPingArgsRecv",
+            "BPingArgsSend",
+            "BPingResultRecv",
+            "BPingResultSend",
+            "FetchArgsRecv",
+            "FetchArgsSend",
+            "FetchResultRecv",
+            "FetchResultSend",
+        ]
+    );
+    // `ping` is in both services, so it keeps the service name
+    assert_eq!(
+        items(&gen(MethodItemNaming::Method)),
+        [
+            "APingArgsRecv",
+            "APingArgsSend",
+            "APingResultRecv",
+            "APingResultSend",
+            "BPingArgsRecv",
+            "BPingArgsSend",
+            "BPingResultRecv",
+            "BPingResultSend",
+            "FetchArgsRecv",
+            "FetchArgsSend",
+            "FetchResultRecv",
+            "FetchResultSend",
+            "OnlyBArgsRecv",
+            "OnlyBArgsSend",
+            "OnlyBException",
+            "OnlyBResultRecv",
+            "OnlyBResultSend",
+        ]
+    );
+}
+
+#[test]
+fn test_inline_methods() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("inline.thrift");
+
+    let dir = tempdir().unwrap();
+    let out_path = dir.path().join("inline.rs");
+
+    let gen = |mode| {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .inline_methods(mode)
+            .inline_max_fields(2)
+            .compile_with_config(
+                vec![IdlService::from_path(file_path.clone())],
+                crate::Output::File(out_path.clone()),
+            );
+        fs::read_to_string(&out_path).unwrap()
+    };
+
+    let message_impl = |content: &str, name: &str| {
+        let start = content
+            .find(&format!("impl ::pilota::thrift::Message for {name} {{"))
+            .unwrap();
+        let end = start + content[start..].find("fn decode_async").unwrap();
+        content[start..end].to_string()
+    };
+
+    let content = gen(crate::InlineMode::Inline);
+    assert_eq!(
+        message_impl(&content, "Small").matches("#[inline]").count(),
+        2
+    );
+    assert!(!message_impl(&content, "Large").contains("#[inline"));
+
+    let content = gen(crate::InlineMode::Always);
+    assert_eq!(
+        message_impl(&content, "Small")
+            .matches("#[inline(always)]")
+            .count(),
+        2
+    );
+    assert!(!message_impl(&content, "Large").contains("#[inline"));
+
+    let content = gen(crate::InlineMode::Off);
+    assert!(!message_impl(&content, "Small").contains("#[inline"));
+}
+
+#[test]
+fn test_codegen_threads() {
+    let test_data_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("test_data");
+    let dir = tempdir().unwrap();
+
+    let gen = |threads: usize, split: bool| {
+        let out_dir = dir.path().join(format!("{threads}_{split}"));
+        fs::create_dir_all(&out_dir).unwrap();
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .split_generated_files(split)
+            .codegen_threads(threads)
+            .compile_with_config(
+                vec![
+                    IdlService::from_path(test_data_dir.join("thrift").join("multi.thrift")),
+                    IdlService::from_path(test_data_dir.join("method_sigs.thrift")),
+                ],
+                crate::Output::File(out_dir.join("out.rs")),
+            );
+        let mut files = Vec::new();
+        let mut dirs = vec![out_dir.clone()];
+        while let Some(d) = dirs.pop() {
+            for e in fs::read_dir(d).unwrap() {
+                let path = e.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else {
+                    let content = fs::read(&path).unwrap();
+                    files.push((path.strip_prefix(&out_dir).unwrap().to_owned(), content));
+                }
+            }
+        }
+        files.sort();
+        files
+    };
+
+    for split in [false, true] {
+        let sequential = gen(1, split);
+        assert_eq!(sequential.len() > 1, split);
+        for _ in 0..3 {
+            assert_eq!(gen(8, split), sequential);
+        }
+    }
+}
+
+#[test]
+fn test_cfg_derives() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("cfg_derive.thrift");
+
+    let dir = tempdir().unwrap();
+    let out_path = dir.path().join("cfg_derive.rs");
+
+    crate::Builder::thrift()
+        .ignore_unused(false)
+        .cfg_derives([
+            ("fake::Dummy", "test"),
+            ("serde::Serialize", r#"feature = "serde""#),
+            ("proptest_derive::Arbitrary", "test"),
+            // always derived
+            ("Debug", "test"),
+            ("::std::clone::Clone", "test"),
+            // only always derived for the enum
+            ("Copy", "test"),
+        ])
+        .compile_with_config(
+            vec![IdlService::from_path(file_path)],
+            crate::Output::File(out_path.clone()),
+        );
+    // rustfmt may wrap the attributes
+    let content = fs::read_to_string(&out_path)
+        .unwrap()
+        .split_whitespace()
+        .collect::<String>();
+
+    assert_eq!(
+        content
+            .matches("#[cfg_attr(test,derive(fake::Dummy,proptest_derive::Arbitrary,Copy))]")
+            .count(),
+        3
+    );
+    assert_eq!(
+        content
+            .matches("#[cfg_attr(test,derive(fake::Dummy,proptest_derive::Arbitrary))]")
+            .count(),
+        1
+    );
+    assert_eq!(
+        content
+            .matches(r#"#[cfg_attr(feature="serde",derive(serde::Serialize))]"#)
+            .count(),
+        4
+    );
+    // never derived unconditionally
+    assert_eq!(content.matches("fake::Dummy").count(), 4);
+    assert_eq!(content.matches("serde::Serialize").count(), 4);
+}
+
+#[test]
+fn test_workspace_layout() {
+    use crate::WorkspaceLayout;
+
+    let input_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_workspace")
+        .join("input");
+
+    let gen = |layout| {
+        let dir = tempdir().unwrap();
+        File::create(dir.path().join("Cargo.toml")).unwrap();
+
+        let mut article = IdlService::from_path(input_dir.join("article.thrift"));
+        article.config = serde_yaml::from_str("crate_name: my-article.v1").unwrap();
+
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .workspace_layout(layout)
+            .compile_with_config(
+                vec![
+                    article,
+                    IdlService::from_path(input_dir.join("image.thrift")),
+                ],
+                crate::Output::Workspace(dir.path().into()),
+            );
+        dir
+    };
+
+    let dir = gen(WorkspaceLayout::Root);
+    for name in ["my_article_v1", "image", "common"] {
+        assert!(dir.path().join(name).join("src/lib.rs").exists());
+        assert!(dir.path().join(name).join("src/gen.rs").exists());
+    }
+    let cargo_toml = fs::read_to_string(dir.path().join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("\"my_article_v1\""));
+
+    let dir = gen(WorkspaceLayout::Crates);
+    for name in ["my_article_v1", "image", "common"] {
+        assert!(dir
+            .path()
+            .join("crates")
+            .join(name)
+            .join("src/lib.rs")
+            .exists());
+        assert!(dir
+            .path()
+            .join("crates")
+            .join(name)
+            .join("src/gen.rs")
+            .exists());
+        assert!(!dir.path().join(name).exists());
+    }
+    let cargo_toml = fs::read_to_string(dir.path().join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("\"crates/my_article_v1\""));
+
+    let dir = gen(WorkspaceLayout::Flat);
+    for name in ["my_article_v1", "image", "common"] {
+        assert!(dir.path().join("src").join(format!("{name}.rs")).exists());
+        assert!(dir.path().join("src").join(name).join("gen.rs").exists());
+        assert!(!dir.path().join(name).exists());
+    }
+    let lib_rs = fs::read_to_string(dir.path().join("src/lib.rs")).unwrap();
+    assert!(lib_rs.contains("pub mod my_article_v1;"));
+    let gen_rs = fs::read_to_string(dir.path().join("src/my_article_v1/gen.rs")).unwrap();
+    assert!(gen_rs.contains("crate::common::"));
+    assert!(!gen_rs.contains(" ::common::"));
+    let cargo_toml = fs::read_to_string(dir.path().join("Cargo.toml")).unwrap();
+    assert!(!cargo_toml.contains("[workspace]"));
+}
+
+#[test]
+fn test_workspace_manifests() {
+    let input_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_workspace")
+        .join("input");
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("Cargo.toml"),
+        "# release profile\n[profile.release]\nlto = true\n",
+    )
+    .unwrap();
+
+    let gen = || {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .manifest_template(
+                "[package]\nname = \"{name}\"\nversion = \"1.2.0\"\nedition = \"2021\"\n",
+            )
+            .compile_with_config(
+                vec![
+                    IdlService::from_path(input_dir.join("article.thrift")),
+                    IdlService::from_path(input_dir.join("image.thrift")),
+                ],
+                crate::Output::Workspace(dir.path().into()),
+            )
+    };
+    gen();
+
+    let root = fs::read_to_string(dir.path().join("Cargo.toml")).unwrap();
+    assert!(root.starts_with("# release profile\n[profile.release]\nlto = true\n"));
+    let manifest = toml::from_str::<toml::Value>(&root).unwrap();
+    let members = manifest["workspace"]["members"].as_array().unwrap();
+    assert_eq!(
+        members
+            .iter()
+            .map(|m| m.as_str().unwrap())
+            .collect::<Vec<_>>(),
+        ["article", "common", "image"]
+    );
+
+    let article_path = dir.path().join("article").join("Cargo.toml");
+    let article =
+        toml::from_str::<toml::Value>(&fs::read_to_string(&article_path).unwrap()).unwrap();
+    assert_eq!(article["package"]["version"].as_str(), Some("1.2.0"));
+    let deps = article["dependencies"].as_table().unwrap();
+    assert_eq!(deps["common"]["path"].as_str(), Some("../common"));
+    assert!(!deps.contains_key("image"));
+    assert_eq!(deps["pilota"]["workspace"].as_bool(), Some(true));
+
+    // the edits around the markers survive the next generation
+    let edited = fs::read_to_string(&article_path)
+        .unwrap()
+        .replace("1.2.0", "1.3.0")
+        + "\n[features]\nextra = []\n";
+    fs::write(&article_path, &edited).unwrap();
+    gen();
+    assert_eq!(fs::read_to_string(&article_path).unwrap(), edited);
+    let root_again = fs::read_to_string(dir.path().join("Cargo.toml")).unwrap();
+    assert_eq!(root_again, root);
+
+    // the manifests without markers keep the keys which aren't generated
+    fs::write(
+        &article_path,
+        "[dependencies.common]\npath = \"../common\"\n\n[dependencies.serde]\nversion = \"1\"\n\n[package]\nname = \"article\"\nversion = \"0.1.0\"\nedition = \"2021\"\n",
+    )
+    .unwrap();
+    gen();
+    let migrated = fs::read_to_string(&article_path).unwrap();
+    assert_eq!(migrated.matches("[dependencies.common]").count(), 1);
+    let article = toml::from_str::<toml::Value>(&migrated).unwrap();
+    assert_eq!(
+        article["dependencies"]["serde"]["version"].as_str(),
+        Some("1")
+    );
+    assert_eq!(article["package"]["version"].as_str(), Some("0.1.0"));
+}
+
+#[test]
+fn test_crate_dependencies() {
+    use faststr::FastStr;
+    use itertools::Itertools;
+
+    let input_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("thrift_workspace")
+        .join("input");
+    let dir = tempdir().unwrap();
+
+    let cx = build_cx(
+        crate::parser::ThriftParser::default(),
+        vec![
+            IdlService::from_path(input_dir.join("article.thrift")),
+            IdlService::from_path(input_dir.join("image.thrift")),
+        ],
+        Some(crate::Output::Workspace(dir.path().into())),
+        all_items(),
+    );
+
+    let deps = cx
+        .crate_dependencies()
+        .into_iter()
+        .map(|(name, deps)| (name.to_string(), deps.into_iter().sorted().collect_vec()))
+        .sorted()
+        .collect_vec();
+    // the items shared by both inputs are dynamic and live in `common`
+    assert_eq!(
+        deps,
+        vec![
+            ("article".to_string(), vec![FastStr::from("common")]),
+            ("common".to_string(), vec![]),
+            ("image".to_string(), vec![FastStr::from("common")]),
+        ]
+    );
+}
+
+mod tests {
+    use pilota::{
+        prost::bytes::BytesMut,
+        thrift::{binary::TBinaryProtocol, Message},
+    };
+
+    use self::decode_error::decode_error::A;
+
+    include!("../../test_data/thrift/decode_error.rs");
+    include!("../../test_data/prost_convert.rs");
+    include!("../../test_data/thrift/void.rs");
+    include!("../../test_data/thrift/const_val.rs");
+    include!("../../test_data/thrift/map_keys.rs");
+    include!("../../test_data/arbitrary/fuzz.rs");
+    include!("../../test_data/plugin/serde_flatten.rs");
+    include!("../../test_data/flatten_wrappers/flatten_wrappers.rs");
+    include!("../../test_data/union_unknown/union_unknown.rs");
+    include!("../../test_data/protobuf_group/group.rs");
+    include!("../../test_data/protobuf/proto2_default.rs");
+    include!("../../test_data/protobuf/proto2_required.rs");
+    include!("../../test_data/thrift/value_range.rs");
+    include!("../../test_data/cow/cow.rs");
+    include!("../../test_data/cow/cow_plugins.rs");
+    include!("../../test_data/thrift/string_type.rs");
+    include!("../../test_data/registry/registry.rs");
+    include!("../../test_data/thrift/document_annotations.rs");
+    include!("../../test_data/thrift/typedef_chain.rs");
+    include!("../../test_data/strict_decode/strict.rs");
+    include!("../../test_data/owned_encode/owned.rs");
+    include!("../../test_data/proto_json/json_name.rs");
+    include!("../../test_data/optional_default/settings_none.rs");
+    include!("../../test_data/optional_default/settings_value.rs");
+    include!("../../test_data/must_use/must_use.rs");
+    include!("../../test_data/field_map/config.rs");
+    include!("../../test_data/service_result/store.rs");
+    include!("../../test_data/manual_hash/index.rs");
+    include!("../../test_data/enum_try_from/status.rs");
+    include!("../../test_data/sort_items/sorted.rs");
+    include!("../../test_data/doc_links/gallery.rs");
+    include!("../../test_data/max_items/parts.rs");
+    include!("../../test_data/prelude/shop.rs");
+    include!("../../test_data/eq_ignore/request.rs");
+    include!("../../test_data/schema/catalog.rs");
+    include!("../../test_data/packed_presence/packed.rs");
+    include!("../../test_data/packed_presence/unpacked.rs");
+    include!("../../test_data/decode_trace/with_tracing.rs");
+    include!("../../test_data/decode_trace/with_log.rs");
+    include!("../../test_data/external/money.rs");
+    include!("../../test_data/external/ledger.rs");
+    include!("../../test_data/enum_try_from/closed.rs");
+    include!("../../test_data/enum_try_from/variants.rs");
+    include!("../../test_data/serde_union/internal.rs");
+    include!("../../test_data/iter_accessors/tags.rs");
+    include!("../../test_data/serde_bytes/blob.rs");
+    include!("../../test_data/thrift/boxed_slice.rs");
+    include!("../../test_data/change_case/main.rs");
+    include!("../../test_data/visit/profile.rs");
+    include!("../../test_data/package_facade/storefront.rs");
+    include!("../../test_data/manual_clone/tree.rs");
+    include!("../../test_data/skip_services.rs");
+    include!("../../test_data/newtype_from_str.rs");
+    // the generated round-trip tests run with the tests of this module
+    include!("../../test_data/roundtrip/account.rs");
+    include!("../../test_data/roundtrip/session.rs");
+    include!("../../test_data/unknown_enum/level_preserve.rs");
+    include!("../../test_data/unknown_enum/level_error.rs");
+    include!("../../test_data/unknown_enum/level_default.rs");
+    include!("../../test_data/unknown_enum/status_error.rs");
+    include!("../../test_data/unknown_enum/status_default.rs");
+    include!("../../test_data/str_accessors.rs");
+    include!("../../test_data/newtype_as_ref.rs");
+    include!("../../test_data/stable_hash.rs");
+    include!("../../test_data/field_number_consts/member.rs");
+    include!("../../test_data/field_number_consts/ticket.rs");
+    include!("../../test_data/remap_mods/checkout.rs");
+    include!("../../test_data/default_strategy/settings_derive.rs");
+    include!("../../test_data/default_strategy/settings_manual.rs");
+    include!("../../test_data/default_strategy/settings_from_idl.rs");
+
+    include!("../../test_data/service_result/streaming.rs");
+
+    pub struct Streaming<T>(pub Vec<T>);
+
+    #[derive(Debug, PartialEq)]
+    pub enum RpcError {
+        NotFound(pilota::FastStr),
+    }
+
+    impl From<self::store::store::StoreGetException> for RpcError {
+        fn from(e: self::store::store::StoreGetException) -> Self {
+            match e {
+                self::store::store::StoreGetException::NotFound(e) => RpcError::NotFound(e.name),
+            }
+        }
+    }
+
+    /// Mirrors what prost generates for `test_data/prost_convert.proto`.
+    pub mod prost_stub {
+        #[derive(Clone, Debug, PartialEq, Default)]
+        pub struct Inner {
+            pub name: String,
+        }
+
+        #[derive(Clone, Debug, PartialEq, Default)]
+        pub struct Outer {
+            pub id: i32,
+            pub name: String,
+            pub data: Vec<u8>,
+            pub tags: Vec<String>,
+            pub counts: std::collections::HashMap<String, i64>,
+            pub kind: i32,
+            pub inner: Option<Inner>,
+            pub version: Option<i64>,
+            pub children: Vec<Inner>,
+            pub value: Option<outer::Value>,
+        }
+
+        pub mod outer {
+            #[derive(Clone, Debug, PartialEq)]
+            pub enum Value {
+                S(String),
+                Msg(super::Inner),
+            }
+        }
+    }
+
+    /// The prost types of `flatten_wrappers.proto`, wrappers included.
+    pub mod flatten_wrappers_prost_stub {
+        macro_rules! wrappers {
+            ($($name:ident($ty:ty),)*) => {$(
+                #[derive(Clone, Debug, PartialEq, Default)]
+                pub struct $name {
+                    pub value: $ty,
+                }
+            )*};
+        }
+
+        wrappers! {
+            DoubleValue(f64),
+            FloatValue(f32),
+            Int64Value(i64),
+            UInt64Value(u64),
+            Int32Value(i32),
+            UInt32Value(u32),
+            BoolValue(bool),
+            StringValue(String),
+            BytesValue(Vec<u8>),
+            Name(String),
+        }
+
+        #[derive(Clone, Debug, PartialEq, Default)]
+        pub struct Pair {
+            pub key: String,
+            pub value: String,
+        }
+
+        #[derive(Clone, Debug, PartialEq, Default)]
+        pub struct User {
+            pub nickname: Option<StringValue>,
+            pub age: Option<Int64Value>,
+            pub name: Option<Name>,
+            pub aliases: Vec<StringValue>,
+            pub pair: Option<Pair>,
+        }
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let mut data = BytesMut::from(&[
+            12_u8, 0, 1, 12, 0, 1, 11, 0, 1, 0, 0, 0, 10, 104, 101, 108, 108, 111, 32, 119, 111,
+            114, 108, 100, 0, 0, 0,
+        ] as &[u8])
+        .freeze();
+
+        let err = A::decode(&mut TBinaryProtocol::new(&mut data, false)).unwrap_err();
+
+        // `B` has no field 1
+        assert_eq!(
+            err.to_string(),
+            "Protocol(ProtocolException { kind: InvalidData, message: \"invalid ttype 100\", path: \"A.b.#1\" })"
+        )
+    }
+
+    #[test]
+    fn test_decode_error_list_path() {
+        use self::decode_error::decode_error::D;
+
+        let mut data = BytesMut::from(&[
+            15_u8, 0, 1, 12, 0, 0, 0, 2, // bs: list<B> of 2
+            12, 0, 2, 11, 0, 3, 0, 0, 0, 1, b'a', 0, 0, // B { c: C { a: "a" } }
+            12, 0, 2, 100, // B { c: C { invalid ttype } }
+        ] as &[u8])
+        .freeze();
+
+        let err = D::decode(&mut TBinaryProtocol::new(&mut data, false)).unwrap_err();
+
+        assert_eq!(err.path().to_string(), "D.bs[1].c");
+    }
+
+    #[test]
+    fn test_decode_error_map_and_set_paths() {
+        use self::decode_error::decode_error::E;
+
+        let mut data = BytesMut::from(&[
+            13_u8, 0, 1, 11, 12, 0, 0, 0, 2, // bm: map<string, B> of 2
+            0, 0, 0, 1, b'a', 12, 0, 2, 11, 0, 3, 0, 0, 0, 1, b'a', 0, 0, // "a": B { .. }
+            0, 0, 0, 1, b'b', 12, 0, 2, 100, // "b": B { c: C { invalid ttype } }
+        ] as &[u8])
+        .freeze();
+        let err = E::decode(&mut TBinaryProtocol::new(&mut data, false)).unwrap_err();
+        assert_eq!(err.path().to_string(), "E.bm[1].c");
+
+        let mut data = BytesMut::from(&[
+            13_u8, 0, 1, 11, 12, 0, 0, 0, 0, // bm: empty
+            14, 0, 2, 12, 0, 0, 0, 1, 100, // cs: set<C> of 1, invalid ttype
+        ] as &[u8])
+        .freeze();
+        let err = E::decode(&mut TBinaryProtocol::new(&mut data, false)).unwrap_err();
+        assert_eq!(err.path().to_string(), "E.cs[0]");
+        assert_eq!(err.path().segments().len(), 2);
+    }
+
+    #[test]
+    fn test_decode_trace_backends() {
+        use std::sync::{Arc, Mutex};
+
+        use self::{with_log::events as log, with_tracing::events as tracing};
+
+        #[derive(Clone, Default)]
+        struct Capture(Arc<Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Capture {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let v2 = log::EventV2 {
+            id: 3,
+            name: Some("deploy".into()),
+            labels: Some(vec!["prod".into()]),
+        };
+        let event: log::Event = roundtrip(&v2);
+        assert_eq!(event.id, 3);
+
+        let capture = Capture::default();
+        let writer = capture.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(::tracing::Level::TRACE)
+            .with_writer(move || writer.clone())
+            .without_time()
+            .with_target(false)
+            .with_ansi(false)
+            .finish();
+        ::tracing::subscriber::with_default(subscriber, || {
+            let v2 = tracing::EventV2 {
+                id: 3,
+                name: Some("deploy".into()),
+                labels: Some(vec!["prod".into()]),
+            };
+            let event: tracing::Event = roundtrip(&v2);
+            assert_eq!(event.name.as_deref(), Some("deploy"));
+
+            let mut data = pilota::Bytes::from_static(&[100]);
+            assert!(tracing::Event::decode(&mut TBinaryProtocol::new(&mut data, false)).is_err());
+        });
+
+        let logs = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            logs.lines().collect::<Vec<_>>(),
+            [
+                "TRACE skipped unknown field Some(3) of `Event`",
+                "TRACE failed to decode `Event`: Protocol(ProtocolException { kind: InvalidData, message: \"invalid ttype 100\", path: \"\" })"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_external_money() {
+        use self::{
+            ledger::ledger::Invoice,
+            money::money::{Currency, Money},
+        };
+
+        let eur = |cents| Money {
+            cents,
+            currency: "EUR".into(),
+        };
+        let invoice = Invoice {
+            total: eur(1250),
+            lines: vec![eur(1000), eur(250)],
+            fees: None,
+            currency: Currency::EUR,
+        };
+        let decoded: Invoice = roundtrip(&invoice);
+        assert_eq!(decoded, invoice);
+    }
+
+    #[test]
+    fn test_closed_enum_views() {
+        use self::closed::status::{Status, StatusEnum};
+
+        assert_eq!(Status::UNKNOWN.as_enum(), Some(StatusEnum::Unknown));
+        assert_eq!(Status::INACTIVE.as_enum(), Some(StatusEnum::Inactive));
+        // the alias has the variant of the first discriminant
+        assert_eq!(Status::ALSO_BANNED.as_enum(), Some(StatusEnum::Banned));
+        assert_eq!(Status::from(7).as_enum(), None);
+
+        assert_eq!(Status::from(StatusEnum::Active), Status::ACTIVE);
+        assert_eq!(Status::from(StatusEnum::Banned).inner(), 10);
+    }
+
+    #[test]
+    fn test_enum_variant_lists() {
+        use self::variants::status::{Empty, Status};
+
+        assert_eq!(
+            Status::ALL,
+            [
+                Status::UNKNOWN,
+                Status::ACTIVE,
+                Status::INACTIVE,
+                Status::BANNED,
+                Status::ALSO_BANNED,
+            ]
+        );
+        assert_eq!(
+            Status::iter().map(|s| s.inner()).collect::<Vec<_>>(),
+            [-1, 0, 1, 10, 10]
+        );
+        assert!(Empty::ALL.is_empty());
+        assert_eq!(Empty::iter().count(), 0);
+    }
+
+    #[test]
+    fn test_serde_union_internal_tag() {
+        use self::internal::shape::{Circle, Rect, Shape};
+
+        let shape = Shape::Rect(Rect {
+            width: 2,
+            height: 3,
+        });
+        let json = serde_json::to_value(&shape).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "type": "Rect", "width": 2, "height": 3 })
+        );
+        assert_eq!(serde_json::from_value::<Shape>(json).unwrap(), shape);
+
+        let json = serde_json::to_value(Shape::Circle(Circle { radius: 1.5 })).unwrap();
+        assert_eq!(json, serde_json::json!({ "type": "Circle", "radius": 1.5 }));
+    }
+
+    #[test]
+    fn test_iter_accessors() {
+        use self::tags::tags::{Labels, Tagged};
+
+        let mut tagged = Tagged {
+            ids: vec![1, 2, 3],
+            counts: [("a".into(), 1)].into_iter().collect(),
+            tags: None,
+            labels: Labels(vec!["x".into()]),
+            scores: Some(vec![7]),
+            name: "t".into(),
+        };
+        assert_eq!(tagged.ids_iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(
+            tagged.counts_iter().collect::<Vec<_>>(),
+            [(&::pilota::FastStr::from("a"), &1)]
+        );
+        assert_eq!(tagged.tags_iter().count(), 0);
+        assert_eq!(tagged.labels_iter().collect::<Vec<_>>(), ["x"]);
+        assert_eq!(tagged.scores_iter().sum::<i32>(), 7);
+
+        tagged.tags = Some(["y".into()].into_iter().collect());
+        assert_eq!(tagged.tags_iter().collect::<Vec<_>>(), ["y"]);
+    }
+
+    #[test]
+    fn test_serde_bytes() {
+        use self::blob::blob::Blob;
+
+        let blob = Blob {
+            data: ::pilota::Bytes::from_static(b"hi!"),
+            checksum: Some(::pilota::Bytes::from_static(b"\xff\x00")),
+            digest: ::pilota::Bytes::from_static(b"\xab"),
+            raw: None,
+        };
+        let json = serde_json::to_value(&blob).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "data": "aGkh", "checksum": "/wA=", "digest": "ab", "raw": null })
+        );
+        assert_eq!(serde_json::from_value::<Blob>(json).unwrap(), blob);
+
+        // the missing optional fields are `None`
+        let blob = serde_json::from_value::<Blob>(
+            serde_json::json!({ "data": "", "digest": "", "raw": [1, 2] }),
+        )
+        .unwrap();
+        assert_eq!(blob.checksum, None);
+        assert_eq!(blob.raw.as_deref(), Some(&[1, 2][..]));
+    }
+
+    #[test]
+    fn test_boxed_slice_roundtrip() {
+        use self::boxed_slice::boxed_slice::Sample;
+
+        let sample = Sample::default();
+        assert!(sample.values.is_empty());
+        assert_eq!(sample.names, None);
+        assert_eq!(&*sample.defaults, &[1, 2]);
+
+        let sample = Sample {
+            values: vec![1, -2, 3].into_boxed_slice(),
+            names: Some(Box::from(["a".into(), "b".into()])),
+            ..Default::default()
+        };
+        let decoded: Sample = roundtrip(&sample);
+        assert_eq!(decoded, sample);
+    }
+
+    #[test]
+    fn test_visit_fields() {
+        use pilota::visit::{FieldValue, FieldVisitor};
+
+        use self::profile::profile::{Address, Contact, Email, Profile, Role};
+
+        #[derive(Default)]
+        struct CountStrings {
+            strings: usize,
+            path: Vec<&'static str>,
+            seen: Vec<String>,
+        }
+
+        impl FieldVisitor for CountStrings {
+            fn visit_field(&mut self, name: &'static str, value: FieldValue<'_>) {
+                if let FieldValue::String(_) = value {
+                    self.strings += 1;
+                    let mut path = self.path.clone();
+                    path.push(name);
+                    self.seen.push(path.join("."));
+                }
+            }
+
+            fn enter_message(&mut self, name: &'static str) {
+                self.path.push(name);
+            }
+
+            fn exit_message(&mut self, _: &'static str) {
+                self.path.pop();
+            }
+        }
+
+        let profile = Profile {
+            name: "alice".into(),
+            age: 30,
+            address: Some(Address {
+                city: "paris".into(),
+                street: None,
+            }),
+            aliases: vec!["a".into(), "al".into()],
+            scores: [("math".into(), 1)].into_iter().collect(),
+            contact: Some(Contact::Email(Email("a@b.c".into()))),
+            role: Role::ADMIN,
+            avatar: "png".into(),
+        };
+        let mut visitor = CountStrings::default();
+        profile.visit_fields(&mut visitor);
+        // the unset street and the binary avatar are not strings
+        assert_eq!(visitor.strings, 6);
+        assert_eq!(
+            visitor.seen,
+            [
+                "name",
+                "address.city",
+                "aliases",
+                "aliases",
+                "scores",
+                "contact.email",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_package_facade_reexports() {
+        // the items of the nested packages are reachable from the package
+        // root and from the root module
+        let item = self::storefront::shop::Item { name: "pen".into() };
+        let request = self::storefront::shop::Request { item: item.clone() };
+        let order = self::storefront::Order {
+            request: request.clone(),
+        };
+        assert_eq!(
+            order.request.item,
+            self::storefront::shop::model::Item { name: "pen".into() }
+        );
+        let _: self::storefront::shop::api::Request = request;
+        let _: self::storefront::Item = item;
+    }
+
+    #[test]
+    fn test_manual_clone_recursive() {
+        use self::tree::tree::{Expr, Node};
+
+        let leaf = |name: &str| Node {
+            name: name.to_string().into(),
+            next: None,
+            children: vec![],
+        };
+        let node = Node {
+            name: "root".into(),
+            next: Some(Box::new(Node {
+                next: Some(Box::new(leaf("last"))),
+                ..leaf("next")
+            })),
+            children: vec![leaf("a"), leaf("b")],
+        };
+        let copy = node.clone();
+        assert_eq!(copy, node);
+        // the boxed fields are cloned deeply
+        assert!(!std::ptr::eq(
+            &**copy.next.as_ref().unwrap(),
+            &**node.next.as_ref().unwrap()
+        ));
+
+        let expr = Expr::Args(vec![Expr::Lit(1), Expr::Node(node), Expr::Args(vec![])]);
+        assert_eq!(expr.clone(), expr);
+    }
+
+    #[test]
+    fn test_newtype_from_str_roundtrip() {
+        use self::newtype_from_str::newtype_from_str::{Email, Nickname};
+
+        let email = "user@example.com".parse::<Email>().unwrap();
+        assert_eq!(email, Email("user@example.com".into()));
+        assert_eq!(email.to_string(), "user@example.com");
+
+        let err = "a@".parse::<Email>().unwrap_err();
+        assert_eq!(err.field, "Email");
+        assert_eq!(err.range, "3..=254");
+        assert_eq!(err.value, 2);
+
+        let nickname: Nickname = "".parse().unwrap();
+        assert_eq!(nickname.to_string(), "");
+    }
+
+    #[test]
+    fn test_newtype_as_ref() {
+        use self::newtype_as_ref::newtype_as_ref::{Age, Avatar, Email, PrimaryEmail, Scores};
+
+        fn len(s: impl AsRef<str>) -> usize {
+            s.as_ref().len()
+        }
+
+        let email = PrimaryEmail(Email("user@example.com".into()));
+        assert_eq!(*email, Email("user@example.com".into()));
+        assert_eq!(AsRef::<str>::as_ref(&email), "user@example.com");
+        assert_eq!(len(&email), 16);
+
+        let avatar = Avatar(::pilota::Bytes::from_static(b"png"));
+        assert_eq!(AsRef::<[u8]>::as_ref(&avatar), b"png");
+
+        let scores = Scores(vec![1, 2]);
+        assert_eq!(scores.as_ref(), &vec![1, 2]);
+        assert_eq!(*Age(42), 42);
+    }
+
+    #[test]
+    fn test_stable_hash() {
+        use self::stable_hash::stable_hash::{Fixture, Kind, Payload, Tag};
+
+        let fixture = |keys: &[&str]| Fixture {
+            name: "fixture".into(),
+            counts: keys
+                .iter()
+                .map(|k| (::pilota::FastStr::new(*k), vec![k.len() as i64]))
+                .collect(),
+            tags: keys
+                .iter()
+                .map(|k| Tag(::pilota::FastStr::new(*k)))
+                .collect(),
+            kind: Kind::BETA,
+            payload: Some(Payload::Raw(::pilota::Bytes::from_static(b"raw"))),
+        };
+
+        // the same entries inserted in another order
+        let a = fixture(&["x", "yy", "zzz"]);
+        let b = fixture(&["zzz", "x", "yy"]);
+        assert_eq!(a, b);
+        assert_eq!(a.stable_hash(), b.stable_hash());
+
+        let mut c = fixture(&["x", "yy", "zzz"]);
+        c.payload = Some(Payload::Text("raw".into()));
+        assert_ne!(a.stable_hash(), c.stable_hash());
+        c.payload = None;
+        assert_ne!(a.stable_hash(), c.stable_hash());
+    }
+
+    #[test]
+    fn test_field_number_consts() {
+        use self::{member::member::Member, ticket::ticket::Ticket};
+
+        assert_eq!(Member::USER_ID_FIELD_NUMBER, 3);
+        assert_eq!(Member::TYPE_FIELD_NUMBER, 7);
+        assert_eq!(Member::ROLES_FIELD_NUMBER, 12);
+
+        assert_eq!(Ticket::TITLE_FIELD_NUMBER, 1);
+        assert_eq!(Ticket::OWNER_ID_FIELD_NUMBER, 19000);
+        assert_eq!(Ticket::LABELS_FIELD_NUMBER, 536870911);
+        assert_eq!(Ticket::USER_ID_FIELD_NUMBER, 4);
+        assert_eq!(Ticket::TEAM_FIELD_NUMBER, 5);
+    }
+
+    #[test]
+    fn test_remap_mods() {
+        use self::checkout::{api::Money, checkout::Order};
+
+        let order = Order {
+            total: Money {
+                cents: 1250,
+                currency: "EUR".into(),
+            },
+            refunds: Some(vec![Money {
+                cents: 250,
+                currency: "EUR".into(),
+            }]),
+        };
+        assert_eq!(roundtrip::<_, Order>(&order), order);
+    }
+
+    #[test]
+    fn test_default_strategy() {
+        use self::{
+            settings_derive::settings_derive, settings_from_idl::settings_from_idl,
+            settings_manual::settings_manual,
+        };
+
+        let settings = settings_derive::Settings::default();
+        assert_eq!(settings.retries, 0);
+        assert_eq!(settings.region, "");
+        assert!(settings.limits.is_empty());
+        assert_eq!(settings.verbose, None);
+
+        let settings = settings_manual::Settings::default();
+        assert_eq!(settings.retries, 3);
+        assert_eq!(settings.region, "eu");
+        assert_eq!(settings.limits.get("requests"), Some(&100));
+        assert_eq!(settings.verbose, Some(true));
+        assert_eq!(settings.timeout, None);
+        assert_eq!(settings_manual::Plain::default().count, 0);
+
+        assert_eq!(
+            settings_from_idl::Settings::default().limits,
+            settings_manual::Settings::default().limits
+        );
+        assert_eq!(settings_from_idl::Plain::default().count, 0);
+    }
+
+    fn roundtrip<S: Message, R: Message>(msg: &S) -> R {
+        let mut buf = BytesMut::new();
+        msg.encode(&mut TBinaryProtocol::new(&mut buf, true))
+            .unwrap();
+        assert_eq!(
+            msg.size(&mut TBinaryProtocol::new(&mut BytesMut::new(), true)),
+            buf.len()
+        );
+        let mut data = buf.freeze();
+        R::decode(&mut TBinaryProtocol::new(&mut data, false)).unwrap()
+    }
+
+    #[test]
+    fn test_nested_const_collections() {
+        use self::const_val::const_val::{
+            TEST_LIST_OF_LIST, TEST_LIST_OF_MAP, TEST_MAP_OF_LIST, TEST_MAP_OF_LIST_OF_MAP,
+            TEST_NESTED,
+        };
+
+        assert_eq!(TEST_MAP_OF_LIST["a"], vec![1, 2]);
+        assert!(TEST_MAP_OF_LIST["b"].is_empty());
+
+        assert_eq!(TEST_LIST_OF_MAP.len(), 2);
+        assert_eq!(TEST_LIST_OF_MAP[0]["a"], 1);
+        assert_eq!(TEST_LIST_OF_MAP[0]["b"], 2);
+        assert!(TEST_LIST_OF_MAP[1].is_empty());
+
+        assert_eq!(TEST_NESTED["outer"]["inner"], vec![3]);
+
+        assert_eq!(TEST_MAP_OF_LIST_OF_MAP["a"][0]["x"], 1);
+
+        assert_eq!(*TEST_LIST_OF_LIST, [vec![1], vec![2, 3]]);
+    }
+
+    #[test]
+    fn test_map_key_consts() {
+        use self::map_keys::map_keys::{Level, Limits, NAMED_KEYS, NAMES, WEIGHTS};
+
+        assert_eq!(NAMES[&-1], "negative");
+        assert_eq!(NAMES[&0], "zero");
+        assert_eq!(NAMES[&4294967296], "big");
+        assert_eq!(NAMED_KEYS[&4294967296], "big");
+
+        // the keys are the variants whether they are written as paths or ints
+        assert_eq!(WEIGHTS[&Level::LOW], 1);
+        assert_eq!(WEIGHTS[&Level::HIGH], 10);
+
+        let limits = Limits::default();
+        assert_eq!(limits.names[&1], "one");
+        assert_eq!(limits.weights[&Level::HIGH], 3);
+    }
+
+    #[test]
+    fn test_void_method_roundtrip() {
+        use self::void::void::{
+            TestFireArgsRecv, TestFireArgsSend, TestPingArgsRecv, TestPingArgsSend,
+            TestPingResultRecv, TestPingResultSend,
+        };
+
+        let _: TestPingArgsRecv = roundtrip(&TestPingArgsSend {});
+
+        let TestPingResultRecv::Ok(()) = roundtrip(&TestPingResultSend::Ok(()));
+
+        let args: TestFireArgsRecv = roundtrip(&TestFireArgsSend { id: 1 });
+        assert_eq!(args.id, 1);
+    }
+
+    #[test]
+    fn test_arbitrary_roundtrip() {
+        use pilota::arbitrary::{Arbitrary, Unstructured};
+        use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+        use self::fuzz::fuzz::Msg;
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut data = vec![0; 4096];
+        for _ in 0..64 {
+            rng.fill_bytes(&mut data);
+            let msg = Msg::arbitrary(&mut Unstructured::new(&data)).unwrap();
+            let decoded: Msg = roundtrip(&msg);
+            assert_eq!(decoded, msg);
+        }
+    }
+
+    fn shares(src: &[u8], field: &[u8]) -> bool {
+        src.as_ptr_range().contains(&field.as_ptr())
+    }
+
+    #[test]
+    fn test_decode_shared() {
+        use pilota::{thrift::compact::TCompactOutputProtocol, Bytes, FastStr};
+
+        use self::fuzz::fuzz::Inner;
+
+        let msg = Inner {
+            s: FastStr::from("a string long enough not to be inlined by FastStr"),
+            b: Some(Bytes::from_static(b"shared bytes")),
+        };
+
+        let mut buf = BytesMut::new();
+        msg.encode(&mut TBinaryProtocol::new(&mut buf, true))
+            .unwrap();
+        let data = buf.freeze();
+        let decoded = Inner::decode_binary_shared(data.clone()).unwrap();
+        assert_eq!(decoded, msg);
+        assert!(shares(&data, decoded.s.as_bytes()));
+        assert!(shares(&data, decoded.b.as_ref().unwrap()));
+
+        let mut buf = BytesMut::new();
+        msg.encode(&mut TCompactOutputProtocol::new(&mut buf, true))
+            .unwrap();
+        let data = buf.freeze();
+        let decoded = Inner::decode_compact_shared(data.clone()).unwrap();
+        assert_eq!(decoded, msg);
+        assert!(shares(&data, decoded.s.as_bytes()));
+        assert!(shares(&data, decoded.b.as_ref().unwrap()));
+    }
+
+    #[test]
+    fn test_prost_decode_shared() {
+        use pilota::{prost::Message as _, Bytes, FastStr};
+
+        use self::prost_convert::Outer;
+
+        let msg = Outer {
+            name: FastStr::from("a string long enough not to be inlined by FastStr"),
+            data: Bytes::from_static(b"shared bytes"),
+            ..Default::default()
+        };
+
+        let data = Bytes::from(msg.encode_to_vec());
+        let decoded = Outer::decode(data.clone()).unwrap();
+        assert_eq!(decoded, msg);
+        assert!(shares(&data, decoded.name.as_bytes()));
+        assert!(shares(&data, &decoded.data));
+    }
+
+    #[test]
+    fn test_flatten_wrappers_roundtrip() {
+        use pilota::{prost::Message as _, FastStr};
+
+        use self::flatten_wrappers::{
+            flatten_wrappers::{Pair, User},
+            google::protobuf::StringValue,
+        };
+
+        // the wrapper message is still on the wire
+        let user = User {
+            nickname: Some(FastStr::from("bob")),
+            ..Default::default()
+        };
+        assert_eq!(user.encode_to_vec(), b"\x0a\x05\x0a\x03bob");
+
+        let user = User {
+            nickname: Some(FastStr::from("bob")),
+            age: Some(42),
+            name: Some(FastStr::from("Robert")),
+            aliases: vec![StringValue {
+                value: FastStr::from("bobby"),
+            }],
+            pair: Some(Pair {
+                key: FastStr::from("k"),
+                value: FastStr::from("v"),
+            }),
+        };
+        let decoded = User::decode(&*user.encode_to_vec()).unwrap();
+        assert_eq!(decoded, user);
+
+        let decoded = User::decode(&*User::default().encode_to_vec()).unwrap();
+        assert_eq!(decoded, User::default());
+
+        // prost still has the wrappers
+        let prost: flatten_wrappers_prost_stub::User = user.clone().into();
+        assert_eq!(
+            prost.nickname,
+            Some(flatten_wrappers_prost_stub::StringValue {
+                value: "bob".into()
+            })
+        );
+        assert_eq!(prost.age.as_ref().map(|age| age.value), Some(42));
+        assert_eq!(User::from(prost), user);
+
+        // the schema has the types of the wrapped fields
+        let schema = <User as pilota::schema::Schema>::schema();
+        assert!(matches!(
+            schema.fields[0].ty,
+            pilota::schema::TypeSchema::String
+        ));
+        assert!(matches!(
+            schema.fields[1].ty,
+            pilota::schema::TypeSchema::I64
+        ));
+        assert!(matches!(
+            schema.fields[3].ty,
+            pilota::schema::TypeSchema::List(_)
+        ));
+    }
+
+    #[test]
+    fn test_union_unknown_variant_roundtrip() {
+        use pilota::{Bytes, FastStr};
+
+        use self::union_unknown::{
+            kept::KeptUnion,
+            union_unknown::{Empty, Union, UnionV2},
+        };
+
+        for u in [
+            Union::A(FastStr::from("a")),
+            Union::B(Bytes::from_static(b"b")),
+            Union::C(3),
+            Union::__Unknown,
+        ] {
+            let decoded: Union = roundtrip(&u);
+            assert_eq!(decoded, u);
+        }
+
+        assert_eq!(Empty::default(), Empty::__Unknown);
+        let decoded: Empty = roundtrip(&Empty::__Unknown);
+        assert_eq!(decoded, Empty::__Unknown);
+        let decoded: Union = roundtrip(&Empty::__Unknown);
+        assert_eq!(decoded, Union::__Unknown);
+
+        // skipped without `keep_unknown_fields`
+        let decoded: Union = roundtrip(&UnionV2::D(4));
+        assert_eq!(decoded, Union::__Unknown);
+
+        // preserved with it
+        let kept: KeptUnion = roundtrip(&UnionV2::D(4));
+        assert!(matches!(kept, KeptUnion::_UnknownFields(_)));
+        let decoded: UnionV2 = roundtrip(&kept);
+        assert_eq!(decoded, UnionV2::D(4));
+    }
+
+    #[test]
+    fn test_protobuf_group_roundtrip() {
+        use pilota::{prost::Message as _, FastStr};
+
+        use self::group::group::{
+            search_response::{Meta, Result},
+            snippet::Range,
+            SearchResponse, Snippet,
+        };
+
+        // a group is delimited by start and end group tags instead of a length
+        let resp = SearchResponse {
+            meta: Some(Meta { total: Some(1) }),
+            ..Default::default()
+        };
+        assert_eq!(resp.encode_to_vec(), b"\x33\x38\x01\x34");
+
+        let resp = SearchResponse {
+            result: vec![
+                Result {
+                    url: FastStr::from("a"),
+                    title: Some(FastStr::from("A")),
+                    snippet: Some(Snippet {
+                        range: Some(Range {
+                            start: Some(1),
+                            end: Some(2),
+                        }),
+                    }),
+                },
+                Result {
+                    url: FastStr::from("b"),
+                    ..Default::default()
+                },
+            ],
+            meta: Some(Meta { total: Some(2) }),
+            query: Some(FastStr::from("q")),
+        };
+        let decoded = SearchResponse::decode(&*resp.encode_to_vec()).unwrap();
+        assert_eq!(decoded, resp);
+    }
+
+    #[test]
+    fn test_packed_presence_roundtrip() {
+        use self::{packed::scores as packed, unpacked::scores as unpacked};
+
+        let mut score = packed::Score::default();
+        score.comment = Some("ok".into());
+        score.id = 7;
+        // zero values are still present
+        score.set_points(0);
+        score.set_ratio(0.5);
+        assert!(score.has_points() && !score.has_verified());
+
+        let decoded: unpacked::Score = roundtrip(&score);
+        assert_eq!(decoded.stable_hash(), score.stable_hash());
+        assert_eq!(decoded.points, Some(0));
+        assert_eq!(decoded.verified, None);
+        assert_eq!(decoded.ratio, Some(0.5));
+        assert_eq!(decoded.bonus, Some(5));
+        assert_eq!(decoded.comment.as_deref(), Some("ok"));
+
+        let back: packed::Score = roundtrip(&decoded);
+        assert_eq!(back, score);
+        assert_eq!(back.timestamp(), None);
+
+        // the unset packed fields have no entry, as the unset options
+        let map = score.to_field_map();
+        assert_eq!(map, decoded.to_field_map());
+        assert!(map.contains_key("points") && !map.contains_key("verified"));
+        assert_eq!(packed::Score::from_field_map(map).unwrap(), score);
+
+        // and aren't visited
+        #[derive(Default)]
+        struct Names(Vec<&'static str>);
+
+        impl pilota::visit::FieldVisitor for Names {
+            fn visit_field(&mut self, name: &'static str, _: pilota::visit::FieldValue<'_>) {
+                self.0.push(name);
+            }
+        }
+
+        let (mut names, mut decoded_names) = (Names::default(), Names::default());
+        score.visit_fields(&mut names);
+        decoded.visit_fields(&mut decoded_names);
+        assert_eq!(names.0, decoded_names.0);
+        assert_eq!(names.0, ["points", "ratio", "comment", "bonus", "id"]);
+
+        score.clear_points();
+        assert_eq!(score.points(), None);
+        assert_ne!(back, score);
+        // an unset field doesn't hash as its zero value
+        assert_ne!(back.stable_hash(), score.stable_hash());
+
+        assert!(std::mem::size_of::<packed::Score>() < std::mem::size_of::<unpacked::Score>());
+    }
+
+    #[test]
+    fn test_enum_try_from_i64_values() {
+        use self::status::status::{Empty, Status};
+
+        assert_eq!(Status::try_from(-1i64).unwrap(), Status::UNKNOWN);
+        assert_eq!(Status::try_from(1i64).unwrap(), Status::INACTIVE);
+        assert_eq!(Status::try_from(10i64).unwrap(), Status::ALSO_BANNED);
+        assert!(Status::try_from(2i64).is_err());
+        // out of the i32 range, even if the truncated value is a discriminant
+        assert!(Status::try_from(1i64 << 32).is_err());
+        assert!(Status::try_from(i64::MIN).is_err());
+        assert!(Empty::try_from(0i64).is_err());
+    }
+
+    #[test]
+    fn test_eq_ignore_fields() {
+        use self::request::request::{Request, Trace};
+
+        let request = Request {
+            path: "/items".into(),
+            request_id: 1,
+            timestamp: Some(100),
+            priority: Some(1),
+        };
+        let retried = Request {
+            request_id: 2,
+            timestamp: None,
+            ..request.clone()
+        };
+        assert_eq!(request, retried);
+        assert_ne!(
+            request,
+            Request {
+                priority: None,
+                ..retried
+            }
+        );
+
+        assert_eq!(Trace { id: "a".into() }, Trace { id: "b".into() });
+    }
+
+    #[test]
+    fn test_schema_descriptors() {
+        use pilota::schema::{Schema, TypeSchema};
+
+        use self::catalog::catalog::{Media, Product};
+
+        let schema = Product::schema();
+        assert_eq!(schema.name, "Product");
+        let fields = schema
+            .fields
+            .iter()
+            .map(|f| (f.name, f.id, f.required))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fields,
+            [
+                ("name", 1, true),
+                ("kind", 2, false),
+                ("index", 3, true),
+                ("related", 4, false),
+                ("media", 5, false),
+            ]
+        );
+
+        assert!(matches!(
+            schema.field("name").unwrap().ty,
+            TypeSchema::String
+        ));
+        assert!(matches!(
+            schema.field("kind").unwrap().ty,
+            TypeSchema::Enum("Kind")
+        ));
+        // the typedef is resolved
+        let TypeSchema::Map(TypeSchema::String, TypeSchema::List(TypeSchema::I64)) =
+            schema.field_by_id(3).unwrap().ty
+        else {
+            panic!("{:?}", schema.field_by_id(3));
+        };
+        // the messages refer to their schema
+        let TypeSchema::List(TypeSchema::Message(related)) = schema.field("related").unwrap().ty
+        else {
+            panic!("{:?}", schema.field("related"));
+        };
+        assert!(std::ptr::eq(related(), schema));
+        let TypeSchema::Message(media) = schema.field("media").unwrap().ty else {
+            panic!("{:?}", schema.field("media"));
+        };
+        assert!(std::ptr::eq(media(), Media::schema()));
+
+        let media = Media::schema();
+        assert_eq!(media.name, "Media");
+        assert!(media.fields.iter().all(|f| !f.required));
+        assert!(matches!(
+            media.field_by_id(2).unwrap().ty,
+            TypeSchema::Bytes
+        ));
+    }
+
+    #[test]
+    fn test_const_enum_fns() {
+        use self::status::status::{Empty, Status};
+
+        const BANNED: i32 = Status::BANNED.as_i32();
+        const UNKNOWN: Option<Status> = Status::from_i32_checked(-1);
+        const INVALID: Option<Status> = Status::from_i32_checked(2);
+        assert_eq!(BANNED, 10);
+        assert_eq!(UNKNOWN, Some(Status::UNKNOWN));
+        assert_eq!(INVALID, None);
+        assert_eq!(Empty::from_i32_checked(0), None);
+    }
+
+    #[test]
+    fn test_manual_hash_roundtrip() {
+        use std::hash::{BuildHasher, RandomState};
+
+        use pilota::{AHashMap, AHashSet, FastStr};
+
+        use self::index::index::{Entry, Index, Key, Shard};
+
+        let hasher = RandomState::new();
+        let index = |keys: &[i32]| {
+            let mut index = Index {
+                counts: AHashMap::new(),
+                ids: AHashSet::new(),
+                by_key: Some(AHashMap::new()),
+                label: None,
+            };
+            for k in keys {
+                index.counts.insert(FastStr::from(k.to_string()), *k);
+                index.ids.insert(*k as i64);
+                index.by_key.as_mut().unwrap().insert(
+                    Key {
+                        name: FastStr::from("k"),
+                        version: *k,
+                    },
+                    vec![FastStr::from(k.to_string())],
+                );
+            }
+            index
+        };
+
+        // equal maps and sets inserted in different orders hash the same
+        let (a, b) = (index(&[1, 2, 3, 4]), index(&[4, 3, 2, 1]));
+        assert_eq!(a, b);
+        assert_eq!(hasher.hash_one(&a), hasher.hash_one(&b));
+        assert_ne!(hasher.hash_one(&a), hasher.hash_one(index(&[1, 2, 3])));
+
+        let shard = |index| Shard { id: 1, index };
+        assert_eq!(
+            hasher.hash_one(shard(index(&[1, 2]))),
+            hasher.hash_one(shard(index(&[2, 1])))
+        );
+
+        let tags =
+            |tags: &[&'static str]| Entry::Tags(tags.iter().copied().map(FastStr::from).collect());
+        assert_eq!(
+            hasher.hash_one(tags(&["a", "b"])),
+            hasher.hash_one(tags(&["b", "a"]))
+        );
+        assert_ne!(hasher.hash_one(tags(&[])), hasher.hash_one(Entry::Id(0)));
+    }
+
+    #[test]
+    fn test_proto2_default() {
+        use pilota::{prost::Message as _, Bytes, FastStr};
+
+        use self::proto2_default::proto2_default::{Defaults, Level};
+
+        let defaults = Defaults::default();
+        assert_eq!(defaults.retries, Some(3));
+        assert_eq!(defaults.offset, Some(-42));
+        assert_eq!(defaults.port, Some(8080));
+        assert_eq!(defaults.mask, Some(u64::MAX));
+        assert_eq!(defaults.ratio, Some(0.5));
+        assert_eq!(defaults.limit, Some(f64::INFINITY));
+        assert_eq!(defaults.enabled, Some(true));
+        assert_eq!(defaults.greeting, Some(FastStr::from("say \"hi\"\n")));
+        assert_eq!(defaults.magic, Some(Bytes::from_static(b"\x01\xffpb")));
+        assert_eq!(defaults.tag, Some(Bytes::from_static(b"a\tb")));
+        assert_eq!(defaults.level, Some(Level::HIGH));
+        assert_eq!(defaults.timeout, 30);
+        assert_eq!(defaults.plain, None);
+
+        // absent fields are decoded as their default, `timeout` is required
+        let decoded = Defaults::decode(&b"\x60\x1e"[..]).unwrap();
+        assert_eq!(decoded, defaults);
+
+        let set = Defaults {
+            retries: Some(0),
+            level: Some(Level::LOW),
+            timeout: 0,
+            ..Default::default()
+        };
+        let decoded = Defaults::decode(&*set.encode_to_vec()).unwrap();
+        assert_eq!(decoded, set);
+    }
+
+    #[test]
+    fn test_unknown_enum_thrift() {
+        use self::{
+            level_default::level_default as default,
+            level_error::level_error as error,
+            level_preserve::level_preserve::{Alert, Level},
+        };
+
+        let alert = Alert {
+            level: Level::from(7),
+            history: Some(vec![Level::LOW, Level::from(9)]),
+        };
+        let mut buf = BytesMut::new();
+        alert
+            .encode(&mut TBinaryProtocol::new(&mut buf, true))
+            .unwrap();
+        let data = buf.freeze();
+
+        let decoded = Alert::decode(&mut TBinaryProtocol::new(&mut data.clone(), false)).unwrap();
+        assert_eq!(decoded, alert);
+
+        let err =
+            error::Alert::decode(&mut TBinaryProtocol::new(&mut data.clone(), false)).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unknown enum value for Level, value: 7"),
+            "{err}"
+        );
+
+        let decoded =
+            default::Alert::decode(&mut TBinaryProtocol::new(&mut data.clone(), false)).unwrap();
+        assert_eq!(decoded.level, default::Level::LOW);
+        assert_eq!(
+            decoded.history,
+            Some(vec![default::Level::LOW, default::Level::LOW])
+        );
+    }
+
+    #[test]
+    fn test_unknown_enum_protobuf() {
+        use pilota::prost::Message as _;
+
+        use self::{
+            status_default::status_default as default, status_error::status_error as error,
+        };
+
+        // `status` 5, `history` [1, 5] packed and `tasks` {"a": 5}
+        let data = b"\x08\x05\x12\x02\x01\x05\x1a\x05\x0a\x01a\x10\x05";
+
+        let err = error::Job::decode(&data[..]).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unknown enum value for Status, value: 5"),
+            "{err}"
+        );
+
+        let decoded = default::Job::decode(&data[..]).unwrap();
+        assert_eq!(decoded.status, default::Status::UNKNOWN);
+        assert_eq!(
+            decoded.history,
+            vec![default::Status::ACTIVE, default::Status::UNKNOWN]
+        );
+        assert_eq!(decoded.tasks["a"], default::Status::UNKNOWN);
+        // the known values are kept
+        let decoded = error::Job::decode(&b"\x08\x01"[..]).unwrap();
+        assert_eq!(decoded.status, error::Status::ACTIVE);
+    }
+
+    #[test]
+    fn test_str_accessors() {
+        use self::str_accessors::str_accessors::{Email, Profile};
+
+        let mut profile = Profile {
+            name: "Ada".into(),
+            email: Email("ada@example.com".into()),
+            avatar: pilota::Bytes::from_static(b"\x89PNG"),
+            ..Default::default()
+        };
+        let name: &str = profile.name();
+        assert_eq!(name, "Ada");
+        assert_eq!(profile.nickname(), None);
+        assert_eq!(profile.email(), "ada@example.com");
+        assert_eq!(profile.backup_email(), None);
+        assert_eq!(profile.avatar(), b"\x89PNG");
+        assert_eq!(profile.thumbnail(), None);
+
+        profile.nickname = Some("ada".into());
+        profile.backup_email = Some(Email("ada@backup.com".into()));
+        profile.thumbnail = Some(vec![1, 2]);
+        assert_eq!(profile.nickname(), Some("ada"));
+        assert_eq!(profile.backup_email(), Some("ada@backup.com"));
+        assert_eq!(profile.thumbnail(), Some(&[1u8, 2][..]));
+    }
+
+    #[test]
+    fn test_proto2_required() {
+        use pilota::prost::Message as _;
+
+        use self::proto2_required::proto2_required::{Device, Login};
+
+        let login = Login {
+            user_id: 0,
+            device: Device {
+                id: "phone".into(),
+                model: None,
+            },
+            token: Some("secret".into()),
+            scopes: vec![],
+        };
+        let decoded = Login::decode(&*login.encode_to_vec()).unwrap();
+        assert_eq!(decoded, login);
+
+        let err = Login::decode(&b""[..]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to decode Protobuf message: Login.user_id: missing required field"
+        );
+
+        // `device` is present, without its required `id`
+        let err = Login::decode(&b"\x08\x01\x12\x00"[..]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to decode Protobuf message: Device.id: Login.device: missing required field"
+        );
+        let err = Login::decode_length_delimited(&b"\x04\x08\x01\x12\x00"[..]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "failed to decode Protobuf message: Device.id: Login.device: missing required field"
+        );
+
+        // merging into a message doesn't need the required fields
+        let mut merged = login.clone();
+        merged.merge(&b"\x1a\x03new"[..]).unwrap();
+        assert_eq!(merged.token.as_deref(), Some("new"));
+    }
+
+    #[test]
+    fn test_value_range() {
+        use pilota::{FastStr, RangeError};
+
+        use self::value_range::value_range::Limits;
+
+        let mut limits = Limits::new(0, Some(-10), None, FastStr::from("a")).unwrap();
+        assert_eq!(limits.percent(), 0);
+        assert_eq!(limits.offset(), Some(-10));
+
+        limits.set_percent(100).unwrap();
+        assert_eq!(
+            limits.set_percent(101),
+            Err(RangeError {
+                field: "percent",
+                range: "0..=100",
+                value: 101,
+            })
+        );
+        assert_eq!(limits.percent(), 100);
+
+        // exclusive end
+        assert!(limits.set_offset(Some(0)).is_err());
+        limits.set_offset(Some(-1)).unwrap();
+        limits.set_offset(None).unwrap();
+        assert!(limits.set_level(Some(0)).is_err());
+        limits.set_level(Some(i8::MAX)).unwrap();
+
+        assert!(Limits::new(-1, None, None, FastStr::from("a")).is_err());
+        assert!(Limits::new(1, Some(-11), None, FastStr::from("a")).is_err());
+
+        // encoded and decoded as the raw values
+        let decoded: Limits = roundtrip(&limits);
+        assert_eq!(decoded, limits);
+    }
+
+    #[test]
+    fn test_cow_fields_roundtrip() {
+        use std::borrow::Cow;
+
+        use pilota::FastStr;
+
+        use self::cow::cow::{Doc, Inner};
+
+        static TAGS: [FastStr; 2] = [FastStr::from_static_str("a"), FastStr::from_static_str("b")];
+
+        let doc = Doc {
+            title: Cow::Borrowed("title"),
+            body: Cow::Borrowed(b"body"),
+            tags: Cow::Borrowed(&TAGS),
+            version: 1,
+            inner: Some(Inner {
+                name: Cow::Borrowed("inner"),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(doc.author.as_deref(), Some("anonymous"));
+
+        // clones share the borrowed data
+        let mut copy = doc.clone();
+        assert!(matches!(copy.title, Cow::Borrowed(_)));
+        assert_eq!(copy.tags.as_ptr(), TAGS.as_ptr());
+
+        // until they are mutated
+        copy.title.to_mut().push_str(" (copy)");
+        copy.tags.to_mut().push(FastStr::from_static_str("c"));
+        assert!(matches!(copy.title, Cow::Owned(_)));
+        assert_ne!(copy.tags.as_ptr(), TAGS.as_ptr());
+        assert_eq!(doc.title, "title");
+        assert_eq!(&*doc.tags, &TAGS);
+        assert!(matches!(copy.body, Cow::Borrowed(_)));
+
+        let decoded: Doc = roundtrip(&copy);
+        assert_eq!(decoded, copy);
+        assert!(matches!(decoded.body, Cow::Owned(_)));
+    }
+
+    /// The messages borrow the data of a local buffer for their lifetime.
+    #[test]
+    fn test_cow_fields_borrowed() {
+        use std::borrow::Cow;
+
+        use self::cow::cow::{Doc, Inner, Part};
+
+        let buf = String::from("local title");
+        let bytes = buf.as_bytes().to_vec();
+        let doc = Doc {
+            title: Cow::Borrowed(&buf[..5]),
+            body: Cow::Borrowed(&bytes),
+            part: Some(Part::Inner(Inner {
+                name: Cow::Borrowed(&buf[6..]),
+            })),
+            ..Default::default()
+        };
+        assert_eq!(doc.title.as_ptr(), buf.as_ptr());
+
+        let mut copy = doc.clone();
+        assert_eq!(copy.title.as_ptr(), buf.as_ptr());
+        assert_eq!(copy.body.as_ptr(), bytes.as_ptr());
+
+        // the mutated fields are copied, the others keep borrowing the buffer
+        copy.title.to_mut().make_ascii_uppercase();
+        assert!(matches!(copy.title, Cow::Owned(_)));
+        assert_eq!(copy.title, "LOCAL");
+        assert_eq!(doc.title, "local");
+        assert_eq!(buf, "local title");
+        assert!(matches!(copy.body, Cow::Borrowed(_)));
+
+        let decoded: Doc = roundtrip(&copy);
+        assert_eq!(decoded, copy);
+        assert!(matches!(decoded.title, Cow::Owned(_)));
+        assert!(matches!(
+            decoded.part,
+            Some(Part::Inner(Inner {
+                name: Cow::Owned(_)
+            }))
+        ));
+
+        // the plugins treat the borrowed and the owned data alike
+        use self::cow_plugins::cow::{Doc as PluginDoc, Inner as PluginInner};
+
+        let borrowed = PluginDoc {
+            title: Cow::Borrowed(&buf),
+            inner: Some(PluginInner {
+                name: Cow::Borrowed(&buf),
+            }),
+            ..Default::default()
+        };
+        let owned = PluginDoc {
+            title: Cow::Owned(buf.clone()),
+            inner: Some(PluginInner {
+                name: Cow::Owned(buf.clone()),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(borrowed.stable_hash(), owned.stable_hash());
+        assert_eq!(borrowed.to_field_map(), owned.to_field_map());
+
+        use pilota::arbitrary::{Arbitrary, Unstructured};
+
+        let data = [7u8; 256];
+        let doc = PluginDoc::arbitrary(&mut Unstructured::new(&data)).unwrap();
+        assert!(matches!(doc.title, Cow::Owned(_)));
+        let decoded: PluginDoc = roundtrip(&doc);
+        assert_eq!(decoded, doc);
+    }
+
+    #[test]
+    fn test_string_type_roundtrip() {
+        use pilota::FastStr;
+
+        use self::string_type::string_type::Names;
+
+        let names = Names::default();
+        assert_eq!(names.fast_default, Some(FastStr::from_static_str("a")));
+        assert_eq!(names.owned_default, Some(String::from("hello")));
+
+        let names = Names {
+            fast: FastStr::from_static_str("fast"),
+            owned: String::from("owned"),
+            ..Default::default()
+        };
+        let decoded: Names = roundtrip(&names);
+        assert_eq!(decoded, names);
+    }
+
+    #[test]
+    fn test_message_registry_decode() {
+        use itertools::Itertools;
+        use pilota::{
+            registry::{MessageRegistry, RegistryError},
+            Bytes, FastStr,
+        };
+
+        use self::registry::{message_registry, registry::Doc};
+
+        let registry = message_registry();
+        assert_eq!(
+            registry.names().sorted().collect::<Vec<_>>(),
+            ["registry.Doc", "registry.NotFound", "registry.Page"]
+        );
+
+        let doc = Doc {
+            id: 7,
+            title: Some(FastStr::from_static_str("pilota")),
+        };
+        let mut buf = BytesMut::new();
+        doc.encode(&mut TBinaryProtocol::new(&mut buf, true))
+            .unwrap();
+
+        let decoded = registry.decode("registry.Doc", buf.freeze()).unwrap();
+        assert_eq!(decoded.downcast_ref::<Doc>(), Some(&doc));
+
+        let new = registry.new_message("registry.Page").unwrap();
+        assert!(new
+            .downcast_ref::<self::registry::registry::Page>()
+            .is_some());
+
+        assert!(matches!(
+            registry.decode("registry.Doc", Bytes::from_static(b"\x0a")),
+            Err(RegistryError::Decode("registry.Doc", _))
+        ));
+        assert!(matches!(
+            registry.decode("registry.Missing", Bytes::new()),
+            Err(RegistryError::UnknownMessage(name)) if name == "registry.Missing"
+        ));
+
+        let mut merged = MessageRegistry::new();
+        merged.merge(registry);
+        assert_eq!(merged.len(), 3);
+        assert!(merged.contains("registry.Page"));
+    }
+
+    #[test]
+    fn test_document_annotations() {
+        use pilota::FastStr;
+
+        use self::document_annotations::document_annotations::Profile;
+
+        // `name` has no annotation of its own, so it gets the string type and
+        // requiredness of the document
+        let profile = Profile {
+            name: String::from("pilota"),
+            nickname: FastStr::from_static_str("pi"),
+            bio: None,
+            tags: vec![FastStr::from_static_str("rust")],
+        };
+        let decoded: Profile = roundtrip(&profile);
+        assert_eq!(decoded, profile);
+    }
+
+    #[test]
+    fn test_strict_decode_errors() {
+        use pilota::thrift::{TOutputProtocol, TType};
+
+        use self::strict::strict::Palette;
+
+        fn decode_err(write: impl FnOnce(&mut TBinaryProtocol<&mut BytesMut>)) -> String {
+            let mut buf = BytesMut::new();
+            let mut protocol = TBinaryProtocol::new(&mut buf, true);
+            write(&mut protocol);
+            protocol.write_field_stop().unwrap();
+            let mut data = buf.freeze();
+            Palette::decode(&mut TBinaryProtocol::new(&mut data, false))
+                .unwrap_err()
+                .to_string()
+        }
+
+        fn name(p: &mut TBinaryProtocol<&mut BytesMut>) {
+            p.write_field_begin(TType::Binary, 1).unwrap();
+            p.write_string("warm").unwrap();
+        }
+
+        let err = decode_err(|p| {
+            name(p);
+            p.write_field_begin(TType::I32, 2).unwrap();
+            p.write_i32(7).unwrap();
+        });
+        assert!(
+            err.contains(r#"path: "Palette.primary""#)
+                && err.contains("unknown enum value for Color, value: 7"),
+            "{err}"
+        );
+
+        let err = decode_err(|p| {
+            name(p);
+            p.write_field_begin(TType::List, 3).unwrap();
+            p.write_byte(TType::I32 as u8).unwrap();
+            p.write_i32(-1).unwrap();
+        });
+        assert!(err.contains("negative list length -1"), "{err}");
+
+        let err = decode_err(|p| {
+            name(p);
+            p.write_field_begin(TType::Set, 4).unwrap();
+            p.write_byte(TType::Binary as u8).unwrap();
+            p.write_i32(-3).unwrap();
+        });
+        assert!(err.contains("negative set length -3"), "{err}");
+
+        let err = decode_err(|p| {
+            name(p);
+            p.write_field_begin(TType::Map, 5).unwrap();
+            p.write_byte(TType::Binary as u8).unwrap();
+            p.write_byte(TType::I32 as u8).unwrap();
+            p.write_i32(-2).unwrap();
+        });
+        assert!(err.contains("negative map length -2"), "{err}");
+
+        let err = decode_err(|p| {
+            name(p);
+            p.write_field_begin(TType::I64, 2).unwrap();
+            p.write_i64(1).unwrap();
+        });
+        assert!(
+            err.contains("unexpected field type I64, expected I32"),
+            "{err}"
+        );
+
+        let err = decode_err(|_| {});
+        assert!(err.contains("field name is required"), "{err}");
+
+        // well formed data still decodes
+        let palette = Palette {
+            name: "warm".into(),
+            primary: Some(self::strict::strict::Color::GREEN),
+            shades: Some(vec![1, 2]),
+            ..Default::default()
+        };
+        let decoded: Palette = roundtrip(&palette);
+        assert_eq!(decoded, palette);
+    }
+
+    /// Counts the allocations of the threads which enabled it.
+    struct CountingAlloc;
+
+    thread_local! {
+        static ALLOCS: std::cell::Cell<Option<usize>> = const { std::cell::Cell::new(None) };
+    }
+
+    unsafe impl std::alloc::GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+            let _ = ALLOCS.try_with(|allocs| {
+                if let Some(n) = allocs.get() {
+                    allocs.set(Some(n + 1));
+                }
+            });
+            std::alloc::System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+            std::alloc::System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+
+    fn count_allocs(f: impl FnOnce()) -> usize {
+        ALLOCS.with(|allocs| allocs.set(Some(0)));
+        f();
+        ALLOCS.with(|allocs| allocs.take()).unwrap()
+    }
+
+    #[test]
+    fn test_owned_encode_roundtrip() {
+        use pilota::{Bytes, FastStr};
+
+        use self::owned::owned::{Attachment, Body, Mail};
+
+        // `Bytes` built from a `Vec` allocate on their first clone
+        let mail = || Mail {
+            id: 1,
+            subject: FastStr::from(String::from("a subject longer than the inline size")),
+            signature: Some(Bytes::from(vec![1u8; 64])),
+            attachment: Attachment {
+                name: FastStr::from_static_str("a.txt"),
+                data: Bytes::from(vec![2u8; 64]),
+            },
+            to: Some(vec![FastStr::from_static_str("someone")]),
+            body: Some(Body::Raw(Bytes::from(vec![3u8; 16]))),
+        };
+
+        let mut borrowed = BytesMut::with_capacity(4096);
+        let msg = mail();
+        let borrowed_allocs = count_allocs(|| {
+            msg.encode(&mut TBinaryProtocol::new(&mut borrowed, true))
+                .unwrap()
+        });
+
+        let mut owned = BytesMut::with_capacity(4096);
+        let msg = mail();
+        let owned_allocs = count_allocs(|| {
+            msg.encode_owned(&mut TBinaryProtocol::new(&mut owned, true))
+                .unwrap()
+        });
+
+        assert_eq!(owned, borrowed);
+        assert!(borrowed_allocs > 0);
+        assert_eq!(owned_allocs, 0);
+
+        let mut data = owned.freeze();
+        let decoded = Mail::decode(&mut TBinaryProtocol::new(&mut data, false)).unwrap();
+        assert_eq!(decoded, mail());
+    }
+
+    #[test]
+    fn test_optional_default_roundtrip() {
+        use pilota::thrift::TOutputProtocol;
+
+        // a message with only the required field without default set
+        let mut buf = BytesMut::new();
+        let mut protocol = TBinaryProtocol::new(&mut buf, true);
+        protocol.write_field_stop().unwrap();
+        let empty = buf.freeze();
+
+        {
+            use self::settings_none::settings::Settings;
+
+            let settings = Settings::default();
+            assert_eq!(settings.retries, None);
+            assert_eq!(settings.region, None);
+            assert_eq!(settings.timeout, 100);
+
+            let decoded =
+                Settings::decode(&mut TBinaryProtocol::new(&mut empty.clone(), false)).unwrap();
+            assert_eq!(decoded, settings);
+        }
+
+        {
+            use self::settings_value::settings::Settings;
+
+            let settings = Settings::default();
+            assert_eq!(settings.retries, 3);
+            assert_eq!(settings.region, "cn");
+            assert_eq!(settings.timeout, 100);
+            assert_eq!(settings.label, None);
+
+            let decoded =
+                Settings::decode(&mut TBinaryProtocol::new(&mut empty.clone(), false)).unwrap();
+            assert_eq!(decoded, settings);
+            let decoded: Settings = roundtrip(&settings);
+            assert_eq!(decoded, settings);
+        }
+    }
+
+    #[test]
+    fn test_proto_json_rename() {
+        use self::json_name::proto_json::{user::Contact, User};
+
+        let user = User {
+            user_id: 1,
+            display_name: "pilota".into(),
+            email_addresses: vec!["a@b.c".into()],
+            contact: Some(Contact::PostalAddress("earth".into())),
+        };
+        let json = serde_json::to_value(&user).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "userId": 1,
+                "nick": "pilota",
+                "emailAddresses": ["a@b.c"],
+                "contact": { "address": "earth" },
+            })
+        );
+        assert_eq!(serde_json::from_value::<User>(json).unwrap(), user);
+    }
+
+    #[test]
+    fn test_serde_flatten() {
+        use self::serde_flatten::serde_flatten::{Extra, Inner, Outer};
+
+        let outer = Outer {
+            id: "1".into(),
+            inner: Inner {
+                x: "x".into(),
+                y: 2,
+            },
+        };
+        let json = serde_json::to_value(&outer).unwrap();
+        assert_eq!(json, serde_json::json!({ "id": "1", "x": "x", "y": 2 }));
+        assert_eq!(serde_json::from_value::<Outer>(json).unwrap(), outer);
+
+        let extra = Extra {
+            id: "1".into(),
+            rest: [("k".into(), "v".into())].into_iter().collect(),
+        };
+        let json = serde_json::to_value(&extra).unwrap();
+        assert_eq!(json, serde_json::json!({ "id": "1", "k": "v" }));
+    }
+
+    #[test]
+    fn test_prost_convert_roundtrip() {
+        use self::prost_convert::{outer::Value, Inner, Kind, Outer};
+
+        let msg = Outer {
+            id: 1,
+            name: "outer".into(),
+            data: pilota::Bytes::from_static(b"data"),
+            tags: vec!["a".into(), "b".into()],
+            counts: [("x".into(), 2)].into_iter().collect(),
+            kind: Kind::KIND_A,
+            inner: Some(Inner {
+                name: "inner".into(),
+            }),
+            version: Some(3),
+            children: vec![Inner {
+                name: "child".into(),
+            }],
+            value: Some(Value::Msg(Inner {
+                name: "oneof".into(),
+            })),
+        };
+
+        let prost: prost_stub::Outer = msg.clone().into();
+        assert_eq!(prost.id, 1);
+        assert_eq!(prost.name, "outer");
+        assert_eq!(prost.data, b"data");
+        assert_eq!(prost.tags, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(prost.counts.get("x"), Some(&2));
+        assert_eq!(prost.kind, 1);
+        assert_eq!(prost.inner.as_ref().unwrap().name, "inner");
+        assert_eq!(prost.version, Some(3));
+        assert_eq!(prost.children[0].name, "child");
+        assert_eq!(
+            prost.value,
+            Some(prost_stub::outer::Value::Msg(prost_stub::Inner {
+                name: "oneof".into()
+            }))
+        );
+
+        let back: Outer = prost.into();
+        assert_eq!(back, msg);
+    }
+
+    #[test]
+    fn test_field_map_roundtrip() {
+        use pilota::field_map::{FieldMapError, Value};
+
+        use self::config::config::{Backend, Config, Level, Limit, Millis};
+
+        let config = Config {
+            version: 3,
+            description: None,
+            level: Level::HIGH,
+            timeout: Millis(1500),
+            default_limit: Limit {
+                name: "default".into(),
+                max: Some(10),
+            },
+            limits: Some(vec![Limit {
+                name: "burst".into(),
+                max: None,
+            }]),
+            weights: [("a".into(), 0.5)].into_iter().collect(),
+            ports: Some([80, 443].into_iter().collect()),
+            token: pilota::Bytes::from_static(b"token"),
+            backend: Some(Backend::Limit(Limit {
+                name: "remote".into(),
+                max: Some(1),
+            })),
+            enabled: true,
+            levels: Some(vec![Level::LOW, Level::from(7)]),
+        };
+
+        let map = config.to_field_map();
+        assert!(!map.contains_key("description"));
+        assert_eq!(map["level"], Value::String("HIGH".into()));
+        assert_eq!(map["timeout"], Value::I64(1500));
+        assert_eq!(
+            map["levels"],
+            Value::List(vec![Value::String("LOW".into()), Value::I64(7)])
+        );
+        let Value::Message(limit) = &map["default_limit"] else {
+            panic!("`default_limit` is not a message")
+        };
+        assert_eq!(limit["name"], Value::String("default".into()));
+        let Value::Message(backend) = &map["backend"] else {
+            panic!("`backend` is not a message")
+        };
+        assert_eq!(backend.keys().collect::<Vec<_>>(), ["limit"]);
+
+        assert_eq!(Config::from_field_map(map.clone()).unwrap(), config);
+
+        let mut missing = map.clone();
+        missing.remove("version");
+        assert_eq!(
+            Config::from_field_map(missing).unwrap_err(),
+            FieldMapError::MissingField("version")
+        );
+
+        let mut unknown = map;
+        unknown.insert("level".into(), Value::String("MEDIUM".into()));
+        assert_eq!(
+            Config::from_field_map(unknown).unwrap_err().to_string(),
+            "invalid field `level`: expected a variant of `Level`, found a string"
+        );
+    }
+
+    #[test]
+    fn test_typedef_chain_roundtrip() {
+        use self::typedef_chain::typedef_chain::{A, B, C, LIMIT, S};
+
+        assert_eq!(***LIMIT, 5);
+
+        let mut s = S::default();
+        assert_eq!(s.c, C(B(A(3))));
+        s.d = Some(C(B(A(-1))));
+        s.cs = vec![C(B(A(1))), C(B(A(2)))];
+        s.m.insert(C(B(A(7))), B(A(8)));
+
+        let mut buf = BytesMut::new();
+        s.encode(&mut TBinaryProtocol::new(&mut buf, true)).unwrap();
+        let mut buf = buf.freeze();
+        let decoded = S::decode(&mut TBinaryProtocol::new(&mut buf, true)).unwrap();
+        assert_eq!(decoded, s);
+    }
+
+    #[test]
+    fn test_service_result_impl() {
+        use std::future::Future;
+
+        use self::store::store::{Item, NotFound, Store, StoreGetException};
+
+        struct Server;
+
+        impl Store for Server {
+            async fn get(&self, name: pilota::FastStr) -> Result<Item, RpcError> {
+                Err(StoreGetException::NotFound(NotFound { name }).into())
+            }
+
+            async fn put(&self, _item: Item) -> Result<(), RpcError> {
+                Ok(())
+            }
+
+            async fn ping(&self) {}
+        }
+
+        let fut = std::pin::pin!(Server.get("a".into()));
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        assert_eq!(
+            fut.poll(&mut cx),
+            std::task::Poll::Ready(Err(RpcError::NotFound("a".into())))
+        );
+    }
+
+    #[test]
+    fn test_service_stream_impl() {
+        use std::future::Future;
+
+        use self::streaming::streaming::{Chunk, Summary, Transfer};
+
+        struct Server;
+
+        impl Transfer for Server {
+            async fn get(&self, _req: Summary) -> Result<Chunk, RpcError> {
+                Ok(Chunk::default())
+            }
+
+            async fn upload(&self, req: Streaming<Chunk>) -> Result<Summary, RpcError> {
+                Ok(Summary {
+                    size: req.0.iter().map(|c| c.data.len() as i64).sum(),
+                })
+            }
+
+            async fn download(&self, _req: Summary) -> Result<Streaming<Chunk>, RpcError> {
+                Ok(Streaming(vec![]))
+            }
+
+            async fn sync(&self, req: Streaming<Chunk>) -> Result<Streaming<Chunk>, RpcError> {
+                Ok(req)
+            }
+        }
+
+        let chunks = Streaming(vec![
+            Chunk { data: "ab".into() },
+            Chunk { data: "c".into() },
+        ]);
+        let fut = std::pin::pin!(Server.upload(chunks));
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        assert_eq!(
+            fut.poll(&mut cx),
+            std::task::Poll::Ready(Ok(Summary { size: 3 }))
+        );
+    }
+}
+
+#[test]
+fn test_field_id_baseline() {
+    let file_path = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("test_data")
+        .join("field_id_baseline")
+        .join("user.thrift");
+
+    let dir = tempdir().unwrap();
+    let baseline = dir.path().join("field_ids.txt");
+    let out_path = dir.path().join("user.rs");
+    let compile = || {
+        crate::Builder::thrift()
+            .ignore_unused(false)
+            .field_id_baseline(&baseline)
+            .compile_with_config(
+                vec![IdlService::from_path(file_path.clone())],
+                crate::Output::File(out_path.clone()),
+            )
+    };
+
+    // without a baseline, the current ids are recorded
+    compile();
+    let recorded = fs::read_to_string(&baseline).unwrap();
+    assert_eq!(
+        recorded,
+        "user::Group::members = 2\nuser::Group::name = 1\nuser::User::email = 3\nuser::User::id = 1\nuser::User::name = 2\n"
+    );
+    assert!(!fs::read_to_string(&out_path)
+        .unwrap()
+        .contains("const _: ()"));
+
+    assert_eq!(rustc_errors(&out_path), "");
+
+    // `email` had the id 4, and `age` was removed
+    fs::write(
+        &baseline,
+        recorded.replace("email = 3", "email = 4") + "user::User::age = 5\n",
+    )
+    .unwrap();
+    compile();
+    let generated = fs::read_to_string(&out_path).unwrap();
+    assert_eq!(generated.matches("const _: ()").count(), 2);
+    let errors = rustc_errors(&out_path);
+    assert!(errors.contains("the id of `user::User::email` changed from 4 to 3"));
+    assert!(errors.contains("`user::User::age` with id 5 was removed or renamed"));
+    assert_eq!(fs::read_to_string(&baseline).unwrap().lines().count(), 6);
+
+    // `Team` was removed, or renamed
+    fs::write(&baseline, recorded.clone() + "user::Team::name = 1\n").unwrap();
+    let removed = std::panic::catch_unwind(compile).unwrap_err();
+    assert!(removed
+        .downcast_ref::<String>()
+        .unwrap()
+        .contains("the messages `user::Team` of the field id baseline were removed or renamed"));
+}
+
+/// The errors of compiling the generated `file` as a library depending on
+/// `pilota`, empty if it compiles.
+fn rustc_errors(file: &Path) -> String {
+    let deps = std::env::current_exe()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .to_owned();
+    let pilota = fs::read_dir(&deps)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_string_lossy();
+            name.starts_with("libpilota-") && name.ends_with(".rlib")
+        })
+        .max_by_key(|path| fs::metadata(path).unwrap().modified().unwrap())
+        .unwrap();
+    let out_dir = tempdir().unwrap();
+    let output = Command::new(std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned()))
+        .args([
+            "--edition",
+            "2021",
+            "--crate-type",
+            "lib",
+            "--emit",
+            "metadata",
+        ])
+        .arg("-L")
+        .arg(format!("dependency={}", deps.display()))
+        .arg("--extern")
+        .arg(format!("pilota={}", pilota.display()))
+        .arg("--out-dir")
+        .arg(out_dir.path())
+        .arg(file)
+        .output()
+        .unwrap();
+    if output.status.success() {
+        String::new()
+    } else {
+        String::from_utf8(output.stderr).unwrap()
     }
 }
@@ -1,4 +1,93 @@
+use std::{
+    io::ErrorKind,
+    path::{Component, Path, PathBuf},
+    sync::Arc,
+};
+
+use normpath::PathExt;
+use rustc_hash::FxHashMap;
+
+use crate::symbol::FileId;
+
 pub fn error_abort(msg: String) -> ! {
     eprintln!("{}", msg);
     std::process::exit(1);
 }
+
+/// The absolute normalized `path`, which has to exist.
+pub(crate) fn normalize_path(path: &Path) -> std::io::Result<PathBuf> {
+    path.normalize().map(|path| path.into_path_buf())
+}
+
+/// The absolute normalized `path` of a file loader, which is normalized
+/// lexically when it doesn't exist on the disk.
+pub(crate) fn normalize_loader_path(path: &Path) -> std::io::Result<PathBuf> {
+    match normalize_path(path) {
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            let mut normalized = if path.is_absolute() {
+                PathBuf::new()
+            } else {
+                std::env::current_dir()?
+            };
+            for component in path.components() {
+                match component {
+                    Component::CurDir => {}
+                    Component::ParentDir => {
+                        normalized.pop();
+                    }
+                    c => normalized.push(c),
+                }
+            }
+            Ok(normalized)
+        }
+        result => result,
+    }
+}
+
+/// The FNV-1a hash of `bytes`, which unlike the std hashers doesn't change
+/// between rust versions and platforms.
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ *b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// The hash of the source of each file.
+pub(crate) fn fingerprints(texts: &FxHashMap<FileId, Arc<str>>) -> FxHashMap<FileId, u64> {
+    texts
+        .iter()
+        .map(|(file_id, text)| (*file_id, fnv1a(text.as_bytes())))
+        .collect()
+}
+
+/// The modules of `std` which are re-exported from `alloc` rather than `core`.
+const ALLOC_MODULES: &[&str] = &[
+    "borrow",
+    "boxed",
+    "collections",
+    "rc",
+    "string",
+    "sync",
+    "vec",
+];
+
+/// Rewrites the `::std::` paths of the generated `code` to the `::core::`
+/// or `::alloc::` ones, for the `no_std` crates.
+pub(crate) fn no_std_paths(code: &str) -> String {
+    let mut out = String::with_capacity(code.len());
+    let mut rest = code;
+    while let Some(pos) = rest.find("::std::") {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos + "::std::".len()..];
+        let module = rest
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .next()
+            .unwrap_or_default();
+        // `format!` and `vec!` are macros of `alloc`
+        let is_alloc = ALLOC_MODULES.contains(&module)
+            || (["format", "vec"].contains(&module) && rest[module.len()..].starts_with('!'));
+        out.push_str(if is_alloc { "::alloc::" } else { "::core::" });
+    }
+    out.push_str(rest);
+    out
+}
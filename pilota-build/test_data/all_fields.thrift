@@ -0,0 +1,20 @@
+struct Attachment {
+    1: required string name,
+    2: required binary content,
+    3: optional binary thumbnail,
+}
+
+struct Mail {
+    1: required string subject,
+    2: required list<Attachment> attachments,
+    3: optional binary signature,
+}
+
+union Body {
+    1: string text,
+    2: binary raw,
+}
+
+service MailService {
+    void send(1: Mail mail, 2: binary key),
+}
@@ -0,0 +1,1630 @@
+pub mod fuzz {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod fuzz {
+
+        impl<'a> ::pilota::arbitrary::Arbitrary<'a> for Status {
+            fn arbitrary(
+                u: &mut ::pilota::arbitrary::Unstructured<'a>,
+            ) -> ::pilota::arbitrary::Result<Self> {
+                u.choose(&[Self::ACTIVE, Self::INACTIVE]).cloned()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Status(i32);
+
+        impl Status {
+            pub const ACTIVE: Self = Self(1);
+            pub const INACTIVE: Self = Self(2);
+
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+
+            pub fn to_string(&self) -> ::std::string::String {
+                match self {
+                    Self(1) => ::std::string::String::from("ACTIVE"),
+                    Self(2) => ::std::string::String::from("INACTIVE"),
+                    Self(val) => val.to_string(),
+                }
+            }
+        }
+
+        impl ::std::convert::From<i32> for Status {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::std::convert::From<Status> for i32 {
+            fn from(value: Status) -> i32 {
+                value.0
+            }
+        }
+
+        impl ::pilota::thrift::Message for Status {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_i32(self.inner())?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                    |err| {
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Status, value: {}", value),
+                        )
+                    },
+                )?)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                        |err| {
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("invalid enum value for Status, value: {}", value),
+                            )
+                        },
+                    )?)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.i32_len(self.inner())
+            }
+        }
+        #[derive(Debug, Default, ::pilota::arbitrary::Arbitrary, Clone, PartialEq)]
+        pub struct Msg {
+            #[arbitrary(with = ::pilota::arbitrary::faststr)]
+            pub name: ::pilota::FastStr,
+
+            pub id: i64,
+            #[arbitrary(with = |u| ::pilota::arbitrary::option(u, |u| ::pilota::arbitrary::collect(u, ::pilota::arbitrary::faststr)))]
+            pub tags: ::std::option::Option<::std::vec::Vec<::pilota::FastStr>>,
+            #[arbitrary(with = |u| ::pilota::arbitrary::collect_map(u, ::pilota::arbitrary::faststr, ::pilota::arbitrary::bytes))]
+            pub blobs: ::pilota::AHashMap<::pilota::FastStr, ::pilota::Bytes>,
+            #[arbitrary(with = |u| ::pilota::arbitrary::collect(u, ::pilota::arbitrary::Arbitrary::arbitrary))]
+            pub ids: ::pilota::AHashSet<i32>,
+
+            pub next: ::std::option::Option<::std::boxed::Box<Msg>>,
+
+            pub status: Status,
+
+            pub choice: ::std::option::Option<Choice>,
+
+            pub inners: ::std::vec::Vec<Inner>,
+
+            pub unknown: ::std::option::Option<super::unknown::WithUnknown>,
+        }
+        impl ::pilota::thrift::Message for Msg {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Msg" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.name).clone())?;
+                __protocol.write_i64_field(2, *&self.id)?;
+                if let Some(value) = self.tags.as_ref() {
+                    __protocol.write_list_field(
+                        3,
+                        ::pilota::thrift::TType::Binary,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_faststr((val).clone())?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                __protocol.write_map_field(
+                    4,
+                    ::pilota::thrift::TType::Binary,
+                    ::pilota::thrift::TType::Binary,
+                    &&self.blobs,
+                    |__protocol, key| {
+                        __protocol.write_faststr((key).clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                    |__protocol, val| {
+                        __protocol.write_bytes(val.clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                __protocol.write_set_field(
+                    5,
+                    ::pilota::thrift::TType::I32,
+                    &&self.ids,
+                    |__protocol, val| {
+                        __protocol.write_i32(*val)?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                if let Some(value) = self.next.as_ref() {
+                    __protocol.write_struct_field(6, value, ::pilota::thrift::TType::Struct)?;
+                }
+                __protocol.write_i32_field(7, (&self.status).inner())?;
+                if let Some(value) = self.choice.as_ref() {
+                    __protocol.write_struct_field(8, value, ::pilota::thrift::TType::Struct)?;
+                }
+                __protocol.write_list_field(
+                    9,
+                    ::pilota::thrift::TType::Struct,
+                    &&self.inners,
+                    |__protocol, val| {
+                        __protocol.write_struct(val)?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                if let Some(value) = self.unknown.as_ref() {
+                    __protocol.write_struct_field(10, value, ::pilota::thrift::TType::Struct)?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut var_6 = None;
+                let mut var_7 = None;
+                let mut var_8 = None;
+                let mut var_9 = None;
+                let mut var_10 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_2 = Some(__protocol.read_i64()?);
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_3 = Some(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<::pilota::FastStr> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_faststr()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(4) if field_ident.field_type == ::pilota::thrift::TType::Map => {
+                                var_4 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_bytes()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
+                            Some(5) if field_ident.field_type == ::pilota::thrift::TType::Set => {
+                                var_5 = Some({
+                                    let list_ident = __protocol.read_set_begin()?;
+                                    let mut val =
+                                        ::pilota::AHashSet::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    __protocol.read_set_end()?;
+                                    val
+                                });
+                            }
+                            Some(6)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_6 = Some(::std::boxed::Box::new(
+                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                ));
+                            }
+                            Some(7) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_7 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(8)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_8 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(9) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_9 = Some(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<Inner> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(10)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_10 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Msg",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("name"),
+                                2 => ::std::option::Option::Some("id"),
+                                3 => ::std::option::Option::Some("tags"),
+                                4 => ::std::option::Option::Some("blobs"),
+                                5 => ::std::option::Option::Some("ids"),
+                                6 => ::std::option::Option::Some("next"),
+                                7 => ::std::option::Option::Some("status"),
+                                8 => ::std::option::Option::Some("choice"),
+                                9 => ::std::option::Option::Some("inners"),
+                                10 => ::std::option::Option::Some("unknown"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field id is required".to_string(),
+                    ));
+                };
+                let Some(var_4) = var_4 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field blobs is required".to_string(),
+                    ));
+                };
+                let Some(var_5) = var_5 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field ids is required".to_string(),
+                    ));
+                };
+                let Some(var_7) = var_7 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field status is required".to_string(),
+                    ));
+                };
+                let Some(var_9) = var_9 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field inners is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    name: var_1,
+                    id: var_2,
+                    tags: var_3,
+                    blobs: var_4,
+                    ids: var_5,
+                    next: var_6,
+                    status: var_7,
+                    choice: var_8,
+                    inners: var_9,
+                    unknown: var_10,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut var_6 = None;
+                    let mut var_7 = None;
+                    let mut var_8 = None;
+                    let mut var_9 = None;
+                    let mut var_10 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                    loop {
+
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_1 = Some(__protocol.read_faststr().await?);
+
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+                    var_2 = Some(__protocol.read_i64().await?);
+
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_3 = Some({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(__protocol.read_faststr().await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+
+                },Some(4) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+                    var_4 = Some({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_faststr().await?, __protocol.read_bytes().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
+
+                },Some(5) if field_ident.field_type == ::pilota::thrift::TType::Set  => {
+                    var_5 = Some({let list_ident = __protocol.read_set_begin().await?;
+                    let mut val = ::pilota::AHashSet::with_capacity(list_ident.size);
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                    __protocol.read_set_end().await?;
+                    val});
+
+                },Some(6) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_6 = Some(::std::boxed::Box::new(<Msg as ::pilota::thrift::Message>::decode_async(__protocol).await?));
+
+                },Some(7) if field_ident.field_type == ::pilota::thrift::TType::I32  => {
+                    var_7 = Some(<Status as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(8) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_8 = Some(<Choice as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(9) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_9 = Some({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(<Inner as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+
+                },Some(10) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_10 = Some(<super::unknown::WithUnknown as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },
+                    _ => {
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Msg",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("name"),2 => ::std::option::Option::Some("id"),3 => ::std::option::Option::Some("tags"),4 => ::std::option::Option::Some("blobs"),5 => ::std::option::Option::Some("ids"),6 => ::std::option::Option::Some("next"),7 => ::std::option::Option::Some("status"),8 => ::std::option::Option::Some("choice"),9 => ::std::option::Option::Some("inners"),10 => ::std::option::Option::Some("unknown"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_4) = var_4 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field blobs is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_5) = var_5 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field ids is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_7) = var_7 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field status is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_9) = var_9 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field inners is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        name: var_1,
+                        id: var_2,
+                        tags: var_3,
+                        blobs: var_4,
+                        ids: var_5,
+                        next: var_6,
+                        status: var_7,
+                        choice: var_8,
+                        inners: var_9,
+                        unknown: var_10,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Msg" })
+                    + __protocol.faststr_field_len(Some(1), &self.name)
+                    + __protocol.i64_field_len(Some(2), *&self.id)
+                    + self.tags.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(3),
+                            ::pilota::thrift::TType::Binary,
+                            value,
+                            |__protocol, el| __protocol.faststr_len(el),
+                        )
+                    })
+                    + __protocol.map_field_len(
+                        Some(4),
+                        ::pilota::thrift::TType::Binary,
+                        ::pilota::thrift::TType::Binary,
+                        &self.blobs,
+                        |__protocol, key| __protocol.faststr_len(key),
+                        |__protocol, val| __protocol.bytes_len(val),
+                    )
+                    + __protocol.set_field_len(
+                        Some(5),
+                        ::pilota::thrift::TType::I32,
+                        &self.ids,
+                        |__protocol, el| __protocol.i32_len(*el),
+                    )
+                    + self
+                        .next
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(6), value))
+                    + __protocol.i32_field_len(Some(7), (&self.status).inner())
+                    + self
+                        .choice
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(8), value))
+                    + __protocol.list_field_len(
+                        Some(9),
+                        ::pilota::thrift::TType::Struct,
+                        &self.inners,
+                        |__protocol, el| __protocol.struct_len(el),
+                    )
+                    + self
+                        .unknown
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(10), value))
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            Default,
+            ::pilota::arbitrary::Arbitrary,
+            Clone,
+            PartialEq,
+        )]
+        pub struct Inner {
+            #[arbitrary(with = ::pilota::arbitrary::faststr)]
+            pub s: ::pilota::FastStr,
+            #[arbitrary(with = |u| ::pilota::arbitrary::option(u, ::pilota::arbitrary::bytes))]
+            pub b: ::std::option::Option<::pilota::Bytes>,
+        }
+        impl ::pilota::thrift::Message for Inner {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Inner" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.s).clone())?;
+                if let Some(value) = self.b.as_ref() {
+                    __protocol.write_bytes_field(2, (value).clone())?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_2 = Some(__protocol.read_bytes()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Inner",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("s"),
+                                2 => ::std::option::Option::Some("b"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field s is required".to_string(),
+                    ));
+                };
+
+                let data = Self { s: var_1, b: var_2 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                Some(2)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_2 = Some(__protocol.read_bytes().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Inner",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("s"),
+                                    2 => ::std::option::Option::Some("b"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field s is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { s: var_1, b: var_2 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Inner" })
+                    + __protocol.faststr_field_len(Some(1), &self.s)
+                    + self
+                        .b
+                        .as_ref()
+                        .map_or(0, |value| __protocol.bytes_field_len(Some(2), value))
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+
+        impl<'a> ::pilota::arbitrary::Arbitrary<'a> for Choice {
+            fn arbitrary(
+                u: &mut ::pilota::arbitrary::Unstructured<'a>,
+            ) -> ::pilota::arbitrary::Result<Self> {
+                ::std::result::Result::Ok(match u.choose_index(3)? {
+                    0 => Self::S(::pilota::arbitrary::faststr(u)?),
+                    1 => Self::I(::pilota::arbitrary::Arbitrary::arbitrary(u)?),
+                    2 => Self::B(::pilota::arbitrary::bytes(u)?),
+                    _ => unreachable!(),
+                })
+            }
+        }
+
+        impl ::std::default::Default for Choice {
+            fn default() -> Self {
+                Choice::S(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum Choice {
+            S(::pilota::FastStr),
+
+            I(i32),
+
+            B(::pilota::Bytes),
+        }
+
+        impl ::pilota::thrift::Message for Choice {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(&::pilota::thrift::TStructIdentifier { name: "Choice" })?;
+                match self {
+                    Choice::S(ref value) => {
+                        __protocol.write_faststr_field(1, (value).clone())?;
+                    }
+                    Choice::I(ref value) => {
+                        __protocol.write_i32_field(2, *value)?;
+                    }
+                    Choice::B(ref value) => {
+                        __protocol.write_bytes_field(3, (value).clone())?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_faststr()?;
+                                __protocol.faststr_len(&field_ident);
+                                ret = Some(Choice::S(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(2) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_i32()?;
+                                __protocol.i32_len(*&field_ident);
+                                ret = Some(Choice::I(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(3) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_bytes()?;
+                                __protocol.bytes_len(&field_ident);
+                                ret = Some(Choice::B(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_faststr().await?;
+
+                                    ret = Some(Choice::S(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            Some(2) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_i32().await?;
+
+                                    ret = Some(Choice::I(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            Some(3) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_bytes().await?;
+
+                                    ret = Some(Choice::B(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Choice" })
+                    + match self {
+                        Choice::S(ref value) => __protocol.faststr_field_len(Some(1), value),
+                        Choice::I(ref value) => __protocol.i32_field_len(Some(2), *value),
+                        Choice::B(ref value) => __protocol.bytes_field_len(Some(3), value),
+                    }
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+    }
+
+    pub mod unknown {
+
+        impl<'a> ::pilota::arbitrary::Arbitrary<'a> for UnknownChoice {
+            fn arbitrary(
+                u: &mut ::pilota::arbitrary::Unstructured<'a>,
+            ) -> ::pilota::arbitrary::Result<Self> {
+                ::std::result::Result::Ok(match u.choose_index(2)? {
+                    0 => Self::S(::pilota::arbitrary::faststr(u)?),
+                    1 => Self::I(::pilota::arbitrary::Arbitrary::arbitrary(u)?),
+                    _ => unreachable!(),
+                })
+            }
+        }
+
+        impl ::std::default::Default for UnknownChoice {
+            fn default() -> Self {
+                UnknownChoice::S(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum UnknownChoice {
+            S(::pilota::FastStr),
+
+            I(i32),
+            _UnknownFields(::pilota::LinkedBytes),
+        }
+
+        impl ::pilota::thrift::Message for UnknownChoice {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "UnknownChoice",
+                })?;
+                match self {
+                    UnknownChoice::S(ref value) => {
+                        __protocol.write_faststr_field(1, (value).clone())?;
+                    }
+                    UnknownChoice::I(ref value) => {
+                        __protocol.write_i32_field(2, *value)?;
+                    }
+                    UnknownChoice::_UnknownFields(ref value) => {
+                        for bytes in value.list.iter() {
+                            __protocol.write_bytes_without_len(bytes.clone());
+                        }
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let mut __pilota_offset = 0;
+                    let __pilota_begin_ptr = __protocol.buf().chunk().as_ptr();
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __pilota_offset += __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __pilota_offset +=
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_faststr()?;
+                                __pilota_offset += __protocol.faststr_len(&field_ident);
+                                ret = Some(UnknownChoice::S(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(2) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_i32()?;
+                                __pilota_offset += __protocol.i32_len(*&field_ident);
+                                ret = Some(UnknownChoice::I(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __pilota_offset += __protocol.skip(field_ident.field_type)?;
+                            if ret.is_none() {
+                                unsafe {
+                                    let mut __pilota_linked_bytes = ::pilota::LinkedBytes::new();
+                                    __pilota_linked_bytes.push_back(
+                                        __protocol
+                                            .get_bytes(Some(__pilota_begin_ptr), __pilota_offset)?,
+                                    );
+                                    ret =
+                                        Some(UnknownChoice::_UnknownFields(__pilota_linked_bytes));
+                                }
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_faststr().await?;
+
+                                    ret = Some(UnknownChoice::S(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            Some(2) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_i32().await?;
+
+                                    ret = Some(UnknownChoice::I(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "UnknownChoice",
+                }) + match self {
+                    UnknownChoice::S(ref value) => __protocol.faststr_field_len(Some(1), value),
+                    UnknownChoice::I(ref value) => __protocol.i32_field_len(Some(2), *value),
+                    UnknownChoice::_UnknownFields(ref value) => value.size(),
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+
+        impl<'a> ::pilota::arbitrary::Arbitrary<'a> for WithUnknown {
+            fn arbitrary(
+                u: &mut ::pilota::arbitrary::Unstructured<'a>,
+            ) -> ::pilota::arbitrary::Result<Self> {
+                ::std::result::Result::Ok(Self {
+                    name: ::pilota::arbitrary::faststr(u)?,
+                    values: ::pilota::arbitrary::Arbitrary::arbitrary(u)?,
+                    _unknown_fields: ::pilota::LinkedBytes::new(),
+                })
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct WithUnknown {
+            pub name: ::pilota::FastStr,
+
+            pub values: ::std::option::Option<::std::vec::Vec<i32>>,
+            pub _unknown_fields: ::pilota::LinkedBytes,
+        }
+        impl ::pilota::thrift::Message for WithUnknown {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "WithUnknown",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.name).clone())?;
+                if let Some(value) = self.values.as_ref() {
+                    __protocol.write_list_field(
+                        2,
+                        ::pilota::thrift::TType::I32,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_i32(*val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                for bytes in self._unknown_fields.list.iter() {
+                    __protocol.write_bytes_without_len(bytes.clone());
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut _unknown_fields = ::pilota::LinkedBytes::new();
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let mut __pilota_offset = 0;
+                        let __pilota_begin_ptr = __protocol.buf().chunk().as_ptr();
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __pilota_offset += __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __pilota_offset +=
+                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_2 = Some(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<i32> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            _ => {
+                                __pilota_offset += __protocol.skip(field_ident.field_type)?;
+                                _unknown_fields.push_back(
+                                    __protocol
+                                        .get_bytes(Some(__pilota_begin_ptr), __pilota_offset)?,
+                                );
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __pilota_offset += __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "WithUnknown",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("name"),
+                                2 => ::std::option::Option::Some("values"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    name: var_1,
+                    values: var_2,
+                    _unknown_fields,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
+                                {
+                                    var_2 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val =
+                                            ::std::vec::Vec::with_capacity(list_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "WithUnknown",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("name"),
+                                    2 => ::std::option::Option::Some("values"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        name: var_1,
+                        values: var_2,
+                        _unknown_fields: ::pilota::LinkedBytes::new(),
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "WithUnknown",
+                }) + __protocol.faststr_field_len(Some(1), &self.name)
+                    + self.values.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(2),
+                            ::pilota::thrift::TType::I32,
+                            value,
+                            |__protocol, el| __protocol.i32_len(*el),
+                        )
+                    })
+                    + self._unknown_fields.size()
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+    }
+}
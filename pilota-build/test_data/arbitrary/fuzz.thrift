@@ -0,0 +1,30 @@
+include "unknown.thrift"
+
+enum Status {
+    ACTIVE = 1,
+    INACTIVE = 2,
+}
+
+union Choice {
+    1: string s,
+    2: i32 i,
+    3: binary b,
+}
+
+struct Inner {
+    1: required string s,
+    2: optional binary b,
+}
+
+struct Msg {
+    1: required string name,
+    2: required i64 id,
+    3: optional list<string> tags,
+    4: required map<string, binary> blobs,
+    5: required set<i32> ids,
+    6: optional Msg next,
+    7: required Status status,
+    8: optional Choice choice,
+    9: required list<Inner> inners,
+    10: optional unknown.WithUnknown unknown,
+}
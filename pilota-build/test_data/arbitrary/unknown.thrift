@@ -0,0 +1,9 @@
+struct WithUnknown {
+    1: required string name,
+    2: optional list<i32> values,
+}
+
+union UnknownChoice {
+    1: string s,
+    2: i32 i,
+}
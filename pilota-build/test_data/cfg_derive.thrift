@@ -0,0 +1,13 @@
+struct A {
+    1: i32 a,
+}
+
+enum E {
+    X = 1,
+}
+
+union U {
+    1: i32 a,
+}
+
+typedef i32 T
@@ -0,0 +1,9 @@
+enum legacy_status {
+    ACTIVE_NOW = 1,
+}
+
+struct legacy_item {
+    1: required string userName,
+    2: required i32 type,
+    3: optional legacy_status itemStatus,
+}
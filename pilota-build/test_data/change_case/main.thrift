@@ -0,0 +1,6 @@
+include "legacy.thrift"
+
+struct order_info {
+    1: required string orderName,
+    2: required legacy.legacy_item legacyItem,
+}
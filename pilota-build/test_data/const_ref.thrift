@@ -0,0 +1,13 @@
+const i32 BASE = 1
+const i32 DERIVED = BASE
+const i32 OTHER = 2
+const string UNUSED = "unused"
+
+struct A {
+    1: required i32 a = OTHER,
+}
+
+struct B {
+    1: required i32 b = DERIVED,
+    2: optional A a,
+}
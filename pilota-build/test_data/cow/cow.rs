@@ -0,0 +1,2648 @@
+pub mod cow {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod cow {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Inner<'cow> {
+            pub name: ::std::borrow::Cow<'cow, str>,
+        }
+        impl<'cow> ::pilota::thrift::Message for Inner<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Inner" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_string_field(1, &self.name)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(::std::borrow::Cow::Owned(__protocol.read_string()?));
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Inner",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("name"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name is required".to_string(),
+                    ));
+                };
+
+                let data = Self { name: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(::std::borrow::Cow::Owned(
+                                        __protocol.read_string().await?,
+                                    ));
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Inner",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("name"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { name: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Inner" })
+                    + __protocol.string_field_len(Some(1), &&self.name)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct DocServicePlainArgsRecv {
+            pub id: i64,
+        }
+        impl ::pilota::thrift::Message for DocServicePlainArgsRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainArgsRecv",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.id)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "DocServicePlainArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("id"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field id is required".to_string(),
+                    ));
+                };
+
+                let data = Self { id: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I64 =>
+                                {
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "DocServicePlainArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { id: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainArgsRecv",
+                }) + __protocol.i64_field_len(Some(1), *&self.id)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for DocServicePlainResultSend {
+            fn default() -> Self {
+                DocServicePlainResultSend::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum DocServicePlainResultSend {
+            Ok(Plain),
+        }
+
+        impl ::pilota::thrift::Message for DocServicePlainResultSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainResultSend",
+                })?;
+                match self {
+                    DocServicePlainResultSend::Ok(ref value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(DocServicePlainResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Plain as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(DocServicePlainResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainResultSend",
+                }) + match self {
+                    DocServicePlainResultSend::Ok(ref value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct DocServiceGetArgsRecv<'cow> {
+            pub title: ::std::borrow::Cow<'cow, str>,
+
+            pub tags: ::std::borrow::Cow<'cow, [::pilota::FastStr]>,
+        }
+        impl<'cow> ::pilota::thrift::Message for DocServiceGetArgsRecv<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetArgsRecv",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_string_field(1, &self.title)?;
+                __protocol.write_list_field(
+                    2,
+                    ::pilota::thrift::TType::Binary,
+                    &&self.tags,
+                    |__protocol, val| {
+                        __protocol.write_faststr((val).clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(::std::borrow::Cow::Owned(__protocol.read_string()?));
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_2 = Some(::std::borrow::Cow::Owned(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<::pilota::FastStr> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_faststr()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                }));
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "DocServiceGetArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("title"),
+                                2 => ::std::option::Option::Some("tags"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field title is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field tags is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    title: var_1,
+                    tags: var_2,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(::std::borrow::Cow::Owned(
+                                        __protocol.read_string().await?,
+                                    ));
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
+                                {
+                                    var_2 = Some(::std::borrow::Cow::Owned({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val =
+                                            ::std::vec::Vec::with_capacity(list_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_faststr().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    }));
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "DocServiceGetArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("title"),
+                                    2 => ::std::option::Option::Some("tags"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field title is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field tags is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        title: var_1,
+                        tags: var_2,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetArgsRecv",
+                }) + __protocol.string_field_len(Some(1), &&self.title)
+                    + __protocol.list_field_len(
+                        Some(2),
+                        ::pilota::thrift::TType::Binary,
+                        &self.tags,
+                        |__protocol, el| __protocol.faststr_len(el),
+                    )
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl<'cow> ::std::default::Default for DocServiceGetResultSend<'cow> {
+            fn default() -> Self {
+                DocServiceGetResultSend::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum DocServiceGetResultSend<'cow> {
+            Ok(Doc<'cow>),
+        }
+
+        impl<'cow> ::pilota::thrift::Message for DocServiceGetResultSend<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetResultSend",
+                })?;
+                match self {
+                    DocServiceGetResultSend::Ok(ref value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(DocServiceGetResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Doc<'cow> as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(DocServiceGetResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetResultSend",
+                }) + match self {
+                    DocServiceGetResultSend::Ok(ref value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Plain {
+            pub id: i64,
+        }
+        impl ::pilota::thrift::Message for Plain {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Plain" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.id)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Plain",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("id"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field id is required".to_string(),
+                    ));
+                };
+
+                let data = Self { id: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I64 =>
+                                {
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Plain",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { id: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Plain" })
+                    + __protocol.i64_field_len(Some(1), *&self.id)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Alias<'cow>(pub Inner<'cow>);
+
+        impl<'cow> ::std::ops::Deref for Alias<'cow> {
+            type Target = Inner<'cow>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<'cow> From<Inner<'cow>> for Alias<'cow> {
+            fn from(v: Inner<'cow>) -> Self {
+                Self(v)
+            }
+        }
+
+        impl<'cow> ::pilota::thrift::Message for Alias<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct((&**self))?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                ::std::result::Result::Ok(Alias(::pilota::thrift::Message::decode(__protocol)?))
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    ::std::result::Result::Ok(Alias(
+                        <Inner<'cow> as ::pilota::thrift::Message>::decode_async(__protocol)
+                            .await?,
+                    ))
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_len(&**self)
+            }
+        }
+        pub trait DocService {}
+
+        impl ::std::default::Default for DocServicePlainResultRecv {
+            fn default() -> Self {
+                DocServicePlainResultRecv::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum DocServicePlainResultRecv {
+            Ok(Plain),
+        }
+
+        impl ::pilota::thrift::Message for DocServicePlainResultRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainResultRecv",
+                })?;
+                match self {
+                    DocServicePlainResultRecv::Ok(ref value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(DocServicePlainResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Plain as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(DocServicePlainResultRecv::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainResultRecv",
+                }) + match self {
+                    DocServicePlainResultRecv::Ok(ref value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl<'cow> ::std::default::Default for DocServiceGetResultRecv<'cow> {
+            fn default() -> Self {
+                DocServiceGetResultRecv::Ok(::std::default::Default::default())
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum DocServiceGetResultRecv<'cow> {
+            Ok(Doc<'cow>),
+        }
+
+        impl<'cow> ::pilota::thrift::Message for DocServiceGetResultRecv<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetResultRecv",
+                })?;
+                match self {
+                    DocServiceGetResultRecv::Ok(ref value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(DocServiceGetResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Doc<'cow> as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(DocServiceGetResultRecv::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetResultRecv",
+                }) + match self {
+                    DocServiceGetResultRecv::Ok(ref value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        pub const DEFAULT_INNER: Inner<'static> = Inner {
+            name: ::std::borrow::Cow::Borrowed("default"),
+        };
+        impl<'cow> ::std::default::Default for Part<'cow> {
+            fn default() -> Self {
+                Part::Inner(::std::default::Default::default())
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum Part<'cow> {
+            Inner(Inner<'cow>),
+
+            Index(i32),
+        }
+
+        impl<'cow> ::pilota::thrift::Message for Part<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(&::pilota::thrift::TStructIdentifier { name: "Part" })?;
+                match self {
+                    Part::Inner(ref value) => {
+                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                    Part::Index(ref value) => {
+                        __protocol.write_i32_field(2, *value)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(Part::Inner(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(2) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_i32()?;
+                                __protocol.i32_len(*&field_ident);
+                                ret = Some(Part::Index(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Inner<'cow> as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(Part::Inner(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            Some(2) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_i32().await?;
+
+                                    ret = Some(Part::Index(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Part" })
+                    + match self {
+                        Part::Inner(ref value) => __protocol.struct_field_len(Some(1), value),
+                        Part::Index(ref value) => __protocol.i32_field_len(Some(2), *value),
+                    }
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct DocServicePlainArgsSend {
+            pub id: i64,
+        }
+        impl ::pilota::thrift::Message for DocServicePlainArgsSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainArgsSend",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.id)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "DocServicePlainArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("id"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field id is required".to_string(),
+                    ));
+                };
+
+                let data = Self { id: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I64 =>
+                                {
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "DocServicePlainArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { id: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainArgsSend",
+                }) + __protocol.i64_field_len(Some(1), *&self.id)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct DocServiceGetArgsSend<'cow> {
+            pub title: ::std::borrow::Cow<'cow, str>,
+
+            pub tags: ::std::borrow::Cow<'cow, [::pilota::FastStr]>,
+        }
+        impl<'cow> ::pilota::thrift::Message for DocServiceGetArgsSend<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetArgsSend",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_string_field(1, &self.title)?;
+                __protocol.write_list_field(
+                    2,
+                    ::pilota::thrift::TType::Binary,
+                    &&self.tags,
+                    |__protocol, val| {
+                        __protocol.write_faststr((val).clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(::std::borrow::Cow::Owned(__protocol.read_string()?));
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_2 = Some(::std::borrow::Cow::Owned(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<::pilota::FastStr> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_faststr()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                }));
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "DocServiceGetArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("title"),
+                                2 => ::std::option::Option::Some("tags"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field title is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field tags is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    title: var_1,
+                    tags: var_2,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(::std::borrow::Cow::Owned(
+                                        __protocol.read_string().await?,
+                                    ));
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
+                                {
+                                    var_2 = Some(::std::borrow::Cow::Owned({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val =
+                                            ::std::vec::Vec::with_capacity(list_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_faststr().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    }));
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "DocServiceGetArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("title"),
+                                    2 => ::std::option::Option::Some("tags"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field title is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field tags is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        title: var_1,
+                        tags: var_2,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetArgsSend",
+                }) + __protocol.string_field_len(Some(1), &&self.title)
+                    + __protocol.list_field_len(
+                        Some(2),
+                        ::pilota::thrift::TType::Binary,
+                        &self.tags,
+                        |__protocol, el| __protocol.faststr_len(el),
+                    )
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl<'cow> ::std::default::Default for Doc<'cow> {
+            fn default() -> Self {
+                Doc {
+                    title: ::std::default::Default::default(),
+                    author: Some(::std::borrow::Cow::Owned("anonymous".to_string())),
+                    body: ::std::default::Default::default(),
+                    tags: ::std::default::Default::default(),
+                    scores: ::std::default::Default::default(),
+                    meta: ::std::default::Default::default(),
+                    version: ::std::default::Default::default(),
+                    inner: ::std::default::Default::default(),
+                    children: ::std::default::Default::default(),
+                    alias: ::std::default::Default::default(),
+                    part: ::std::default::Default::default(),
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct Doc<'cow> {
+            pub title: ::std::borrow::Cow<'cow, str>,
+
+            pub author: ::std::option::Option<::std::borrow::Cow<'cow, str>>,
+
+            pub body: ::std::borrow::Cow<'cow, [u8]>,
+
+            pub tags: ::std::borrow::Cow<'cow, [::pilota::FastStr]>,
+
+            pub scores: ::std::option::Option<::std::borrow::Cow<'cow, [i32]>>,
+
+            pub meta:
+                ::std::option::Option<::pilota::AHashMap<::pilota::FastStr, ::pilota::FastStr>>,
+
+            pub version: i64,
+
+            pub inner: ::std::option::Option<Inner<'cow>>,
+
+            pub children: ::std::option::Option<::std::borrow::Cow<'cow, [Inner<'cow>]>>,
+
+            pub alias: ::std::option::Option<Alias<'cow>>,
+
+            pub part: ::std::option::Option<Part<'cow>>,
+        }
+        impl<'cow> ::pilota::thrift::Message for Doc<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Doc" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_string_field(1, &self.title)?;
+                if let Some(value) = self.author.as_ref() {
+                    __protocol.write_string_field(2, value)?;
+                }
+                __protocol.write_bytes_vec_field(3, &self.body)?;
+                __protocol.write_list_field(
+                    4,
+                    ::pilota::thrift::TType::Binary,
+                    &&self.tags,
+                    |__protocol, val| {
+                        __protocol.write_faststr((val).clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                if let Some(value) = self.scores.as_ref() {
+                    __protocol.write_list_field(
+                        5,
+                        ::pilota::thrift::TType::I32,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_i32(*val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                if let Some(value) = self.meta.as_ref() {
+                    __protocol.write_map_field(
+                        6,
+                        ::pilota::thrift::TType::Binary,
+                        ::pilota::thrift::TType::Binary,
+                        &value,
+                        |__protocol, key| {
+                            __protocol.write_faststr((key).clone())?;
+                            ::std::result::Result::Ok(())
+                        },
+                        |__protocol, val| {
+                            __protocol.write_faststr((val).clone())?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                __protocol.write_i64_field(7, *&self.version)?;
+                if let Some(value) = self.inner.as_ref() {
+                    __protocol.write_struct_field(8, value, ::pilota::thrift::TType::Struct)?;
+                }
+                if let Some(value) = self.children.as_ref() {
+                    __protocol.write_list_field(
+                        9,
+                        ::pilota::thrift::TType::Struct,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_struct(val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                if let Some(value) = self.alias.as_ref() {
+                    __protocol.write_struct_field(10, value, ::pilota::thrift::TType::Struct)?;
+                }
+                if let Some(value) = self.part.as_ref() {
+                    __protocol.write_struct_field(11, value, ::pilota::thrift::TType::Struct)?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut var_6 = None;
+                let mut var_7 = None;
+                let mut var_8 = None;
+                let mut var_9 = None;
+                let mut var_10 = None;
+                let mut var_11 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(::std::borrow::Cow::Owned(__protocol.read_string()?));
+                            }
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_2 = Some(::std::borrow::Cow::Owned(__protocol.read_string()?));
+                            }
+                            Some(3)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_3 =
+                                    Some(::std::borrow::Cow::Owned(__protocol.read_bytes_vec()?));
+                            }
+                            Some(4) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_4 = Some(::std::borrow::Cow::Owned(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<::pilota::FastStr> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_faststr()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                }));
+                            }
+                            Some(5) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_5 = Some(::std::borrow::Cow::Owned(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<i32> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                }));
+                            }
+                            Some(6) if field_ident.field_type == ::pilota::thrift::TType::Map => {
+                                var_6 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
+                            Some(7) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_7 = Some(__protocol.read_i64()?);
+                            }
+                            Some(8)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_8 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(9) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_9 = Some(::std::borrow::Cow::Owned(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<Inner<'cow>> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                }));
+                            }
+                            Some(10)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_10 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(11)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_11 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Doc",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("title"),
+                                2 => ::std::option::Option::Some("author"),
+                                3 => ::std::option::Option::Some("body"),
+                                4 => ::std::option::Option::Some("tags"),
+                                5 => ::std::option::Option::Some("scores"),
+                                6 => ::std::option::Option::Some("meta"),
+                                7 => ::std::option::Option::Some("version"),
+                                8 => ::std::option::Option::Some("inner"),
+                                9 => ::std::option::Option::Some("children"),
+                                10 => ::std::option::Option::Some("alias"),
+                                11 => ::std::option::Option::Some("part"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field title is required".to_string(),
+                    ));
+                };
+                let Some(var_3) = var_3 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field body is required".to_string(),
+                    ));
+                };
+                let Some(var_4) = var_4 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field tags is required".to_string(),
+                    ));
+                };
+                let Some(var_7) = var_7 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field version is required".to_string(),
+                    ));
+                };
+
+                if var_2.is_none() {
+                    var_2 = Some(::std::borrow::Cow::Owned("anonymous".to_string()));
+                }
+
+                let data = Self {
+                    title: var_1,
+                    author: var_2,
+                    body: var_3,
+                    tags: var_4,
+                    scores: var_5,
+                    meta: var_6,
+                    version: var_7,
+                    inner: var_8,
+                    children: var_9,
+                    alias: var_10,
+                    part: var_11,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut var_6 = None;
+                    let mut var_7 = None;
+                    let mut var_8 = None;
+                    let mut var_9 = None;
+                    let mut var_10 = None;
+                    let mut var_11 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                    loop {
+
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_1 = Some(::std::borrow::Cow::Owned(__protocol.read_string().await?));
+
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_2 = Some(::std::borrow::Cow::Owned(__protocol.read_string().await?));
+
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_3 = Some(::std::borrow::Cow::Owned(__protocol.read_bytes_vec().await?));
+
+                },Some(4) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_4 = Some(::std::borrow::Cow::Owned({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(__protocol.read_faststr().await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        }));
+
+                },Some(5) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_5 = Some(::std::borrow::Cow::Owned({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(__protocol.read_i32().await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        }));
+
+                },Some(6) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+                    var_6 = Some({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_faststr().await?, __protocol.read_faststr().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
+
+                },Some(7) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+                    var_7 = Some(__protocol.read_i64().await?);
+
+                },Some(8) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_8 = Some(<Inner<'cow> as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(9) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_9 = Some(::std::borrow::Cow::Owned({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(<Inner<'cow> as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        }));
+
+                },Some(10) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_10 = Some(<Alias<'cow> as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(11) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_11 = Some(<Part<'cow> as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },
+                    _ => {
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Doc",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("title"),2 => ::std::option::Option::Some("author"),3 => ::std::option::Option::Some("body"),4 => ::std::option::Option::Some("tags"),5 => ::std::option::Option::Some("scores"),6 => ::std::option::Option::Some("meta"),7 => ::std::option::Option::Some("version"),8 => ::std::option::Option::Some("inner"),9 => ::std::option::Option::Some("children"),10 => ::std::option::Option::Some("alias"),11 => ::std::option::Option::Some("part"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field title is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field body is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_4) = var_4 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field tags is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_7) = var_7 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field version is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    if var_2.is_none() {
+                        var_2 = Some(::std::borrow::Cow::Owned("anonymous".to_string()));
+                    }
+
+                    let data = Self {
+                        title: var_1,
+                        author: var_2,
+                        body: var_3,
+                        tags: var_4,
+                        scores: var_5,
+                        meta: var_6,
+                        version: var_7,
+                        inner: var_8,
+                        children: var_9,
+                        alias: var_10,
+                        part: var_11,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Doc" })
+                    + __protocol.string_field_len(Some(1), &&self.title)
+                    + self
+                        .author
+                        .as_ref()
+                        .map_or(0, |value| __protocol.string_field_len(Some(2), &value))
+                    + __protocol.bytes_vec_field_len(Some(3), &self.body)
+                    + __protocol.list_field_len(
+                        Some(4),
+                        ::pilota::thrift::TType::Binary,
+                        &self.tags,
+                        |__protocol, el| __protocol.faststr_len(el),
+                    )
+                    + self.scores.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(5),
+                            ::pilota::thrift::TType::I32,
+                            value,
+                            |__protocol, el| __protocol.i32_len(*el),
+                        )
+                    })
+                    + self.meta.as_ref().map_or(0, |value| {
+                        __protocol.map_field_len(
+                            Some(6),
+                            ::pilota::thrift::TType::Binary,
+                            ::pilota::thrift::TType::Binary,
+                            value,
+                            |__protocol, key| __protocol.faststr_len(key),
+                            |__protocol, val| __protocol.faststr_len(val),
+                        )
+                    })
+                    + __protocol.i64_field_len(Some(7), *&self.version)
+                    + self
+                        .inner
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(8), value))
+                    + self.children.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(9),
+                            ::pilota::thrift::TType::Struct,
+                            value,
+                            |__protocol, el| __protocol.struct_len(el),
+                        )
+                    })
+                    + self
+                        .alias
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(10), value))
+                    + self
+                        .part
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(11), value))
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+    }
+}
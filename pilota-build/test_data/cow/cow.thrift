@@ -0,0 +1,35 @@
+struct Inner {
+    1: required string name,
+}
+
+typedef Inner Alias
+
+union Part {
+    1: Inner inner,
+    2: i32 index,
+}
+
+const Inner DEFAULT_INNER = {"name": "default"}
+
+struct Doc {
+    1: required string title,
+    2: optional string author = "anonymous",
+    3: required binary body,
+    4: required list<string> tags,
+    5: optional list<i32> scores,
+    6: optional map<string, string> meta,
+    7: required i64 version,
+    8: optional Inner inner,
+    9: optional list<Inner> children,
+    10: optional Alias alias,
+    11: optional Part part,
+}
+
+struct Plain {
+    1: required i64 id,
+}
+
+service DocService {
+    Doc get(1: string title, 2: list<string> tags),
+    Plain plain(1: i64 id),
+}
@@ -0,0 +1,4231 @@
+pub mod cow_plugins {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod cow {
+
+        impl<'cow> ::pilota::schema::Schema for Inner<'cow> {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "Inner",
+                    fields: &[::pilota::schema::FieldSchema {
+                        name: "name",
+                        id: 1,
+                        ty: ::pilota::schema::TypeSchema::String,
+                        required: true,
+                    }],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl<'cow> Inner<'cow> {
+            pub fn stable_hash(&self) -> u64 {
+                ::pilota::hash::stable_hash(self)
+            }
+        }
+
+        impl<'cow> ::pilota::hash::StableHash for Inner<'cow> {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                ::pilota::hash::StableHash::write_stable(&1i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.name, hasher);
+            }
+        }
+
+        impl<'cow> Inner<'cow> {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(1);
+                map.insert(
+                    "name".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.name),
+                );
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    name: ::pilota::field_map::required(&mut map, "name")?,
+                })
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::ToValue for Inner<'cow> {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::FromValue for Inner<'cow> {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+
+        impl<'cow> Inner<'cow> {
+            pub fn visit_fields<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                visitor: &mut V,
+            ) {
+                ::pilota::visit::VisitValue::visit_value(&self.name, "name", visitor);
+            }
+        }
+
+        impl<'cow> ::pilota::visit::VisitValue for Inner<'cow> {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                self.visit_fields(visitor);
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            Default,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            PartialEq,
+        )]
+        pub struct Inner<'cow> {
+            #[arbitrary(with = |u| ::pilota::arbitrary::cow(u, ::pilota::arbitrary::Arbitrary::arbitrary))]
+            pub name: ::std::borrow::Cow<'cow, str>,
+        }
+        impl<'cow> ::std::clone::Clone for Inner<'cow> {
+            fn clone(&self) -> Self {
+                Self {
+                    name: ::std::clone::Clone::clone(&self.name),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::thrift::Message for Inner<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Inner" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_string_field(1, &self.name)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(::std::borrow::Cow::Owned(__protocol.read_string()?));
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Inner",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("name"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name is required".to_string(),
+                    ));
+                };
+
+                let data = Self { name: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(::std::borrow::Cow::Owned(
+                                        __protocol.read_string().await?,
+                                    ));
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Inner",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("name"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { name: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Inner" })
+                    + __protocol.string_field_len(Some(1), &&self.name)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::pilota::schema::Schema for DocServicePlainArgsRecv {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "DocServicePlainArgsRecv",
+                    fields: &[::pilota::schema::FieldSchema {
+                        name: "id",
+                        id: 1,
+                        ty: ::pilota::schema::TypeSchema::I64,
+                        required: true,
+                    }],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl DocServicePlainArgsRecv {
+            pub fn stable_hash(&self) -> u64 {
+                ::pilota::hash::stable_hash(self)
+            }
+        }
+
+        impl ::pilota::hash::StableHash for DocServicePlainArgsRecv {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                ::pilota::hash::StableHash::write_stable(&1i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.id, hasher);
+            }
+        }
+
+        impl DocServicePlainArgsRecv {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(1);
+                map.insert(
+                    "id".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.id),
+                );
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    id: ::pilota::field_map::required(&mut map, "id")?,
+                })
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for DocServicePlainArgsRecv {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for DocServicePlainArgsRecv {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+
+        impl DocServicePlainArgsRecv {
+            pub fn visit_fields<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                visitor: &mut V,
+            ) {
+                ::pilota::visit::VisitValue::visit_value(&self.id, "id", visitor);
+            }
+        }
+
+        impl ::pilota::visit::VisitValue for DocServicePlainArgsRecv {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                self.visit_fields(visitor);
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            Default,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            Clone,
+            PartialEq,
+        )]
+        pub struct DocServicePlainArgsRecv {
+            pub id: i64,
+        }
+        impl ::pilota::thrift::Message for DocServicePlainArgsRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainArgsRecv",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.id)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "DocServicePlainArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("id"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field id is required".to_string(),
+                    ));
+                };
+
+                let data = Self { id: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I64 =>
+                                {
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "DocServicePlainArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { id: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainArgsRecv",
+                }) + __protocol.i64_field_len(Some(1), *&self.id)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for DocServicePlainResultSend {
+            fn default() -> Self {
+                DocServicePlainResultSend::Ok(::std::default::Default::default())
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for DocServicePlainResultSend {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                match self {
+                    Self::Ok(value) => ::pilota::field_map::Value::Message(
+                        ::std::iter::once((
+                            "Ok".to_string(),
+                            ::pilota::field_map::ToValue::to_value(value),
+                        ))
+                        .collect(),
+                    ),
+                }
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for DocServicePlainResultSend {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                let map = match value {
+                    ::pilota::field_map::Value::Message(map) => map,
+                    value => {
+                        return ::std::result::Result::Err(
+                            ::pilota::field_map::FieldMapError::invalid("a message", value),
+                        )
+                    }
+                };
+
+                if map.len() != 1 {
+                    return ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid(
+                            "one variant of `DocServicePlainResultSend`",
+                            ::pilota::field_map::Value::Message(map),
+                        ),
+                    );
+                }
+                let (variant, value) = map.into_iter().next().unwrap();
+                match variant.as_str() {
+                    "Ok" => ::std::result::Result::Ok(Self::Ok(::pilota::field_map::from_field(
+                        "Ok", value,
+                    )?)),
+                    _ => ::std::result::Result::Err(::pilota::field_map::FieldMapError::invalid(
+                        "a variant of `DocServicePlainResultSend`",
+                        ::pilota::field_map::Value::Message(
+                            ::std::iter::once((variant, value)).collect(),
+                        ),
+                    )),
+                }
+            }
+        }
+
+        impl ::pilota::hash::StableHash for DocServicePlainResultSend {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                match self {
+                    Self::Ok(value) => {
+                        ::pilota::hash::StableHash::write_stable(&0i32, hasher);
+                        ::pilota::hash::StableHash::write_stable(value, hasher);
+                    }
+                }
+            }
+        }
+
+        impl ::pilota::schema::Schema for DocServicePlainResultSend {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "DocServicePlainResultSend",
+                    fields: &[::pilota::schema::FieldSchema {
+                        name: "Ok",
+                        id: 0,
+                        ty: ::pilota::schema::TypeSchema::Message(
+                            <Plain as ::pilota::schema::Schema>::schema,
+                        ),
+                        required: false,
+                    }],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl ::pilota::visit::VisitValue for DocServicePlainResultSend {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                match self {
+                    Self::Ok(value) => {
+                        ::pilota::visit::VisitValue::visit_value(value, "Ok", visitor)
+                    }
+                }
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            Clone,
+            PartialEq,
+        )]
+        pub enum DocServicePlainResultSend {
+            Ok(Plain),
+        }
+
+        impl ::pilota::thrift::Message for DocServicePlainResultSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainResultSend",
+                })?;
+                match self {
+                    DocServicePlainResultSend::Ok(ref value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(DocServicePlainResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Plain as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(DocServicePlainResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainResultSend",
+                }) + match self {
+                    DocServicePlainResultSend::Ok(ref value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl<'cow> ::pilota::schema::Schema for DocServiceGetArgsRecv<'cow> {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "DocServiceGetArgsRecv",
+                    fields: &[
+                        ::pilota::schema::FieldSchema {
+                            name: "title",
+                            id: 1,
+                            ty: ::pilota::schema::TypeSchema::String,
+                            required: true,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "tags",
+                            id: 2,
+                            ty: ::pilota::schema::TypeSchema::List(
+                                &::pilota::schema::TypeSchema::String,
+                            ),
+                            required: true,
+                        },
+                    ],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl<'cow> DocServiceGetArgsRecv<'cow> {
+            pub fn stable_hash(&self) -> u64 {
+                ::pilota::hash::stable_hash(self)
+            }
+        }
+
+        impl<'cow> ::pilota::hash::StableHash for DocServiceGetArgsRecv<'cow> {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                ::pilota::hash::StableHash::write_stable(&1i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.title, hasher);
+                ::pilota::hash::StableHash::write_stable(&2i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.tags, hasher);
+            }
+        }
+
+        impl<'cow> DocServiceGetArgsRecv<'cow> {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(2);
+                map.insert(
+                    "title".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.title),
+                );
+                map.insert(
+                    "tags".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.tags),
+                );
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    title: ::pilota::field_map::required(&mut map, "title")?,
+                    tags: ::pilota::field_map::required(&mut map, "tags")?,
+                })
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::ToValue for DocServiceGetArgsRecv<'cow> {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::FromValue for DocServiceGetArgsRecv<'cow> {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+
+        impl<'cow> DocServiceGetArgsRecv<'cow> {
+            pub fn visit_fields<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                visitor: &mut V,
+            ) {
+                ::pilota::visit::VisitValue::visit_value(&self.title, "title", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.tags, "tags", visitor);
+            }
+        }
+
+        impl<'cow> ::pilota::visit::VisitValue for DocServiceGetArgsRecv<'cow> {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                self.visit_fields(visitor);
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            Default,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            PartialEq,
+        )]
+        pub struct DocServiceGetArgsRecv<'cow> {
+            #[arbitrary(with = |u| ::pilota::arbitrary::cow(u, ::pilota::arbitrary::Arbitrary::arbitrary))]
+            pub title: ::std::borrow::Cow<'cow, str>,
+            #[arbitrary(with = |u| ::pilota::arbitrary::cow(u, |u| ::pilota::arbitrary::collect(u, ::pilota::arbitrary::faststr)))]
+            pub tags: ::std::borrow::Cow<'cow, [::pilota::FastStr]>,
+        }
+        impl<'cow> ::std::clone::Clone for DocServiceGetArgsRecv<'cow> {
+            fn clone(&self) -> Self {
+                Self {
+                    title: ::std::clone::Clone::clone(&self.title),
+                    tags: ::std::clone::Clone::clone(&self.tags),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::thrift::Message for DocServiceGetArgsRecv<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetArgsRecv",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_string_field(1, &self.title)?;
+                __protocol.write_list_field(
+                    2,
+                    ::pilota::thrift::TType::Binary,
+                    &&self.tags,
+                    |__protocol, val| {
+                        __protocol.write_faststr((val).clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(::std::borrow::Cow::Owned(__protocol.read_string()?));
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_2 = Some(::std::borrow::Cow::Owned(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<::pilota::FastStr> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_faststr()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                }));
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "DocServiceGetArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("title"),
+                                2 => ::std::option::Option::Some("tags"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field title is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field tags is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    title: var_1,
+                    tags: var_2,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(::std::borrow::Cow::Owned(
+                                        __protocol.read_string().await?,
+                                    ));
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
+                                {
+                                    var_2 = Some(::std::borrow::Cow::Owned({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val =
+                                            ::std::vec::Vec::with_capacity(list_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_faststr().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    }));
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "DocServiceGetArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("title"),
+                                    2 => ::std::option::Option::Some("tags"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field title is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field tags is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        title: var_1,
+                        tags: var_2,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetArgsRecv",
+                }) + __protocol.string_field_len(Some(1), &&self.title)
+                    + __protocol.list_field_len(
+                        Some(2),
+                        ::pilota::thrift::TType::Binary,
+                        &self.tags,
+                        |__protocol, el| __protocol.faststr_len(el),
+                    )
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl<'cow> ::std::default::Default for DocServiceGetResultSend<'cow> {
+            fn default() -> Self {
+                DocServiceGetResultSend::Ok(::std::default::Default::default())
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::ToValue for DocServiceGetResultSend<'cow> {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                match self {
+                    Self::Ok(value) => ::pilota::field_map::Value::Message(
+                        ::std::iter::once((
+                            "Ok".to_string(),
+                            ::pilota::field_map::ToValue::to_value(value),
+                        ))
+                        .collect(),
+                    ),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::FromValue for DocServiceGetResultSend<'cow> {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                let map = match value {
+                    ::pilota::field_map::Value::Message(map) => map,
+                    value => {
+                        return ::std::result::Result::Err(
+                            ::pilota::field_map::FieldMapError::invalid("a message", value),
+                        )
+                    }
+                };
+
+                if map.len() != 1 {
+                    return ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid(
+                            "one variant of `DocServiceGetResultSend`",
+                            ::pilota::field_map::Value::Message(map),
+                        ),
+                    );
+                }
+                let (variant, value) = map.into_iter().next().unwrap();
+                match variant.as_str() {
+                    "Ok" => ::std::result::Result::Ok(Self::Ok(::pilota::field_map::from_field(
+                        "Ok", value,
+                    )?)),
+                    _ => ::std::result::Result::Err(::pilota::field_map::FieldMapError::invalid(
+                        "a variant of `DocServiceGetResultSend`",
+                        ::pilota::field_map::Value::Message(
+                            ::std::iter::once((variant, value)).collect(),
+                        ),
+                    )),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::hash::StableHash for DocServiceGetResultSend<'cow> {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                match self {
+                    Self::Ok(value) => {
+                        ::pilota::hash::StableHash::write_stable(&0i32, hasher);
+                        ::pilota::hash::StableHash::write_stable(value, hasher);
+                    }
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::schema::Schema for DocServiceGetResultSend<'cow> {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "DocServiceGetResultSend",
+                    fields: &[::pilota::schema::FieldSchema {
+                        name: "Ok",
+                        id: 0,
+                        ty: ::pilota::schema::TypeSchema::Message(
+                            <Doc as ::pilota::schema::Schema>::schema,
+                        ),
+                        required: false,
+                    }],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl<'cow> ::pilota::visit::VisitValue for DocServiceGetResultSend<'cow> {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                match self {
+                    Self::Ok(value) => {
+                        ::pilota::visit::VisitValue::visit_value(value, "Ok", visitor)
+                    }
+                }
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            Hash,
+            Debug,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            PartialEq,
+        )]
+        pub enum DocServiceGetResultSend<'cow> {
+            Ok(Doc<'cow>),
+        }
+
+        impl<'cow> ::std::clone::Clone for DocServiceGetResultSend<'cow> {
+            fn clone(&self) -> Self {
+                match self {
+                    Self::Ok(value) => Self::Ok(::std::clone::Clone::clone(value)),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::thrift::Message for DocServiceGetResultSend<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetResultSend",
+                })?;
+                match self {
+                    DocServiceGetResultSend::Ok(ref value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(DocServiceGetResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Doc<'cow> as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(DocServiceGetResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetResultSend",
+                }) + match self {
+                    DocServiceGetResultSend::Ok(ref value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::pilota::schema::Schema for Plain {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "Plain",
+                    fields: &[::pilota::schema::FieldSchema {
+                        name: "id",
+                        id: 1,
+                        ty: ::pilota::schema::TypeSchema::I64,
+                        required: true,
+                    }],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl Plain {
+            pub fn stable_hash(&self) -> u64 {
+                ::pilota::hash::stable_hash(self)
+            }
+        }
+
+        impl ::pilota::hash::StableHash for Plain {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                ::pilota::hash::StableHash::write_stable(&1i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.id, hasher);
+            }
+        }
+
+        impl Plain {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(1);
+                map.insert(
+                    "id".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.id),
+                );
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    id: ::pilota::field_map::required(&mut map, "id")?,
+                })
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for Plain {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for Plain {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+
+        impl Plain {
+            pub fn visit_fields<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                visitor: &mut V,
+            ) {
+                ::pilota::visit::VisitValue::visit_value(&self.id, "id", visitor);
+            }
+        }
+
+        impl ::pilota::visit::VisitValue for Plain {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                self.visit_fields(visitor);
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            Default,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            Clone,
+            PartialEq,
+        )]
+        pub struct Plain {
+            pub id: i64,
+        }
+        impl ::pilota::thrift::Message for Plain {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Plain" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.id)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Plain",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("id"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field id is required".to_string(),
+                    ));
+                };
+
+                let data = Self { id: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I64 =>
+                                {
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Plain",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { id: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Plain" })
+                    + __protocol.i64_field_len(Some(1), *&self.id)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl<'cow> ::pilota::field_map::ToValue for Alias<'cow> {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::ToValue::to_value(&self.0)
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::FromValue for Alias<'cow> {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::pilota::field_map::FromValue::from_value(value).map(Self)
+            }
+        }
+
+        impl<'cow> ::pilota::hash::StableHash for Alias<'cow> {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                ::pilota::hash::StableHash::write_stable(&self.0, hasher)
+            }
+        }
+
+        impl<'cow> ::pilota::visit::VisitValue for Alias<'cow> {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                ::pilota::visit::VisitValue::visit_value(&self.0, name, visitor)
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            Default,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            PartialEq,
+        )]
+        pub struct Alias<'cow>(pub Inner<'cow>);
+
+        impl<'cow> ::std::ops::Deref for Alias<'cow> {
+            type Target = Inner<'cow>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl<'cow> From<Inner<'cow>> for Alias<'cow> {
+            fn from(v: Inner<'cow>) -> Self {
+                Self(v)
+            }
+        }
+
+        impl<'cow> ::std::clone::Clone for Alias<'cow> {
+            fn clone(&self) -> Self {
+                Self(::std::clone::Clone::clone(&self.0))
+            }
+        }
+
+        impl<'cow> ::pilota::thrift::Message for Alias<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct((&**self))?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                ::std::result::Result::Ok(Alias(::pilota::thrift::Message::decode(__protocol)?))
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    ::std::result::Result::Ok(Alias(
+                        <Inner<'cow> as ::pilota::thrift::Message>::decode_async(__protocol)
+                            .await?,
+                    ))
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_len(&**self)
+            }
+        }
+        pub trait DocService {
+            fn get<'cow>(
+                &self,
+                title: ::pilota::FastStr,
+                tags: ::std::vec::Vec<::pilota::FastStr>,
+            ) -> impl ::std::future::Future<
+                Output = ::std::result::Result<Doc<'cow>, crate::test::tests::RpcError>,
+            > + Send;
+            fn plain(
+                &self,
+                id: i64,
+            ) -> impl ::std::future::Future<
+                Output = ::std::result::Result<Plain, crate::test::tests::RpcError>,
+            > + Send;
+        }
+
+        impl ::std::default::Default for DocServicePlainResultRecv {
+            fn default() -> Self {
+                DocServicePlainResultRecv::Ok(::std::default::Default::default())
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for DocServicePlainResultRecv {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                match self {
+                    Self::Ok(value) => ::pilota::field_map::Value::Message(
+                        ::std::iter::once((
+                            "Ok".to_string(),
+                            ::pilota::field_map::ToValue::to_value(value),
+                        ))
+                        .collect(),
+                    ),
+                }
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for DocServicePlainResultRecv {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                let map = match value {
+                    ::pilota::field_map::Value::Message(map) => map,
+                    value => {
+                        return ::std::result::Result::Err(
+                            ::pilota::field_map::FieldMapError::invalid("a message", value),
+                        )
+                    }
+                };
+
+                if map.len() != 1 {
+                    return ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid(
+                            "one variant of `DocServicePlainResultRecv`",
+                            ::pilota::field_map::Value::Message(map),
+                        ),
+                    );
+                }
+                let (variant, value) = map.into_iter().next().unwrap();
+                match variant.as_str() {
+                    "Ok" => ::std::result::Result::Ok(Self::Ok(::pilota::field_map::from_field(
+                        "Ok", value,
+                    )?)),
+                    _ => ::std::result::Result::Err(::pilota::field_map::FieldMapError::invalid(
+                        "a variant of `DocServicePlainResultRecv`",
+                        ::pilota::field_map::Value::Message(
+                            ::std::iter::once((variant, value)).collect(),
+                        ),
+                    )),
+                }
+            }
+        }
+
+        impl ::pilota::hash::StableHash for DocServicePlainResultRecv {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                match self {
+                    Self::Ok(value) => {
+                        ::pilota::hash::StableHash::write_stable(&0i32, hasher);
+                        ::pilota::hash::StableHash::write_stable(value, hasher);
+                    }
+                }
+            }
+        }
+
+        impl ::pilota::schema::Schema for DocServicePlainResultRecv {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "DocServicePlainResultRecv",
+                    fields: &[::pilota::schema::FieldSchema {
+                        name: "Ok",
+                        id: 0,
+                        ty: ::pilota::schema::TypeSchema::Message(
+                            <Plain as ::pilota::schema::Schema>::schema,
+                        ),
+                        required: false,
+                    }],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl ::pilota::visit::VisitValue for DocServicePlainResultRecv {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                match self {
+                    Self::Ok(value) => {
+                        ::pilota::visit::VisitValue::visit_value(value, "Ok", visitor)
+                    }
+                }
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            Clone,
+            PartialEq,
+        )]
+        pub enum DocServicePlainResultRecv {
+            Ok(Plain),
+        }
+
+        impl ::pilota::thrift::Message for DocServicePlainResultRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainResultRecv",
+                })?;
+                match self {
+                    DocServicePlainResultRecv::Ok(ref value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(DocServicePlainResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Plain as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(DocServicePlainResultRecv::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainResultRecv",
+                }) + match self {
+                    DocServicePlainResultRecv::Ok(ref value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl<'cow> ::std::default::Default for DocServiceGetResultRecv<'cow> {
+            fn default() -> Self {
+                DocServiceGetResultRecv::Ok(::std::default::Default::default())
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::ToValue for DocServiceGetResultRecv<'cow> {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                match self {
+                    Self::Ok(value) => ::pilota::field_map::Value::Message(
+                        ::std::iter::once((
+                            "Ok".to_string(),
+                            ::pilota::field_map::ToValue::to_value(value),
+                        ))
+                        .collect(),
+                    ),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::FromValue for DocServiceGetResultRecv<'cow> {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                let map = match value {
+                    ::pilota::field_map::Value::Message(map) => map,
+                    value => {
+                        return ::std::result::Result::Err(
+                            ::pilota::field_map::FieldMapError::invalid("a message", value),
+                        )
+                    }
+                };
+
+                if map.len() != 1 {
+                    return ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid(
+                            "one variant of `DocServiceGetResultRecv`",
+                            ::pilota::field_map::Value::Message(map),
+                        ),
+                    );
+                }
+                let (variant, value) = map.into_iter().next().unwrap();
+                match variant.as_str() {
+                    "Ok" => ::std::result::Result::Ok(Self::Ok(::pilota::field_map::from_field(
+                        "Ok", value,
+                    )?)),
+                    _ => ::std::result::Result::Err(::pilota::field_map::FieldMapError::invalid(
+                        "a variant of `DocServiceGetResultRecv`",
+                        ::pilota::field_map::Value::Message(
+                            ::std::iter::once((variant, value)).collect(),
+                        ),
+                    )),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::hash::StableHash for DocServiceGetResultRecv<'cow> {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                match self {
+                    Self::Ok(value) => {
+                        ::pilota::hash::StableHash::write_stable(&0i32, hasher);
+                        ::pilota::hash::StableHash::write_stable(value, hasher);
+                    }
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::schema::Schema for DocServiceGetResultRecv<'cow> {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "DocServiceGetResultRecv",
+                    fields: &[::pilota::schema::FieldSchema {
+                        name: "Ok",
+                        id: 0,
+                        ty: ::pilota::schema::TypeSchema::Message(
+                            <Doc as ::pilota::schema::Schema>::schema,
+                        ),
+                        required: false,
+                    }],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl<'cow> ::pilota::visit::VisitValue for DocServiceGetResultRecv<'cow> {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                match self {
+                    Self::Ok(value) => {
+                        ::pilota::visit::VisitValue::visit_value(value, "Ok", visitor)
+                    }
+                }
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            Hash,
+            Debug,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            PartialEq,
+        )]
+        pub enum DocServiceGetResultRecv<'cow> {
+            Ok(Doc<'cow>),
+        }
+
+        impl<'cow> ::std::clone::Clone for DocServiceGetResultRecv<'cow> {
+            fn clone(&self) -> Self {
+                match self {
+                    Self::Ok(value) => Self::Ok(::std::clone::Clone::clone(value)),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::thrift::Message for DocServiceGetResultRecv<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetResultRecv",
+                })?;
+                match self {
+                    DocServiceGetResultRecv::Ok(ref value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(DocServiceGetResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Doc<'cow> as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(DocServiceGetResultRecv::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetResultRecv",
+                }) + match self {
+                    DocServiceGetResultRecv::Ok(ref value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        pub const DEFAULT_INNER: Inner<'static> = Inner {
+            name: ::std::borrow::Cow::Borrowed("default"),
+        };
+        impl<'cow> ::std::default::Default for Part<'cow> {
+            fn default() -> Self {
+                Part::Inner(::std::default::Default::default())
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::ToValue for Part<'cow> {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                match self {
+                    Self::Inner(value) => ::pilota::field_map::Value::Message(
+                        ::std::iter::once((
+                            "inner".to_string(),
+                            ::pilota::field_map::ToValue::to_value(value),
+                        ))
+                        .collect(),
+                    ),
+                    Self::Index(value) => ::pilota::field_map::Value::Message(
+                        ::std::iter::once((
+                            "index".to_string(),
+                            ::pilota::field_map::ToValue::to_value(value),
+                        ))
+                        .collect(),
+                    ),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::FromValue for Part<'cow> {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                let map = match value {
+                    ::pilota::field_map::Value::Message(map) => map,
+                    value => {
+                        return ::std::result::Result::Err(
+                            ::pilota::field_map::FieldMapError::invalid("a message", value),
+                        )
+                    }
+                };
+
+                if map.len() != 1 {
+                    return ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid(
+                            "one variant of `Part`",
+                            ::pilota::field_map::Value::Message(map),
+                        ),
+                    );
+                }
+                let (variant, value) = map.into_iter().next().unwrap();
+                match variant.as_str() {
+                    "inner" => ::std::result::Result::Ok(Self::Inner(
+                        ::pilota::field_map::from_field("inner", value)?,
+                    )),
+                    "index" => ::std::result::Result::Ok(Self::Index(
+                        ::pilota::field_map::from_field("index", value)?,
+                    )),
+                    _ => ::std::result::Result::Err(::pilota::field_map::FieldMapError::invalid(
+                        "a variant of `Part`",
+                        ::pilota::field_map::Value::Message(
+                            ::std::iter::once((variant, value)).collect(),
+                        ),
+                    )),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::hash::StableHash for Part<'cow> {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                match self {
+                    Self::Inner(value) => {
+                        ::pilota::hash::StableHash::write_stable(&1i32, hasher);
+                        ::pilota::hash::StableHash::write_stable(value, hasher);
+                    }
+                    Self::Index(value) => {
+                        ::pilota::hash::StableHash::write_stable(&2i32, hasher);
+                        ::pilota::hash::StableHash::write_stable(value, hasher);
+                    }
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::schema::Schema for Part<'cow> {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "Part",
+                    fields: &[
+                        ::pilota::schema::FieldSchema {
+                            name: "inner",
+                            id: 1,
+                            ty: ::pilota::schema::TypeSchema::Message(
+                                <Inner as ::pilota::schema::Schema>::schema,
+                            ),
+                            required: false,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "index",
+                            id: 2,
+                            ty: ::pilota::schema::TypeSchema::I32,
+                            required: false,
+                        },
+                    ],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl<'cow> ::pilota::visit::VisitValue for Part<'cow> {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                match self {
+                    Self::Inner(value) => {
+                        ::pilota::visit::VisitValue::visit_value(value, "inner", visitor)
+                    }
+                    Self::Index(value) => {
+                        ::pilota::visit::VisitValue::visit_value(value, "index", visitor)
+                    }
+                }
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            PartialEq,
+        )]
+        pub enum Part<'cow> {
+            Inner(Inner<'cow>),
+
+            Index(i32),
+        }
+
+        impl<'cow> ::std::clone::Clone for Part<'cow> {
+            fn clone(&self) -> Self {
+                match self {
+                    Self::Inner(value) => Self::Inner(::std::clone::Clone::clone(value)),
+                    Self::Index(value) => Self::Index(::std::clone::Clone::clone(value)),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::thrift::Message for Part<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(&::pilota::thrift::TStructIdentifier { name: "Part" })?;
+                match self {
+                    Part::Inner(ref value) => {
+                        __protocol.write_struct_field(1, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                    Part::Index(ref value) => {
+                        __protocol.write_i32_field(2, *value)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(Part::Inner(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(2) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_i32()?;
+                                __protocol.i32_len(*&field_ident);
+                                ret = Some(Part::Index(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Inner<'cow> as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(Part::Inner(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            Some(2) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_i32().await?;
+
+                                    ret = Some(Part::Index(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Part" })
+                    + match self {
+                        Part::Inner(ref value) => __protocol.struct_field_len(Some(1), value),
+                        Part::Index(ref value) => __protocol.i32_field_len(Some(2), *value),
+                    }
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::pilota::schema::Schema for DocServicePlainArgsSend {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "DocServicePlainArgsSend",
+                    fields: &[::pilota::schema::FieldSchema {
+                        name: "id",
+                        id: 1,
+                        ty: ::pilota::schema::TypeSchema::I64,
+                        required: true,
+                    }],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl DocServicePlainArgsSend {
+            pub fn stable_hash(&self) -> u64 {
+                ::pilota::hash::stable_hash(self)
+            }
+        }
+
+        impl ::pilota::hash::StableHash for DocServicePlainArgsSend {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                ::pilota::hash::StableHash::write_stable(&1i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.id, hasher);
+            }
+        }
+
+        impl DocServicePlainArgsSend {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(1);
+                map.insert(
+                    "id".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.id),
+                );
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    id: ::pilota::field_map::required(&mut map, "id")?,
+                })
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for DocServicePlainArgsSend {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for DocServicePlainArgsSend {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+
+        impl DocServicePlainArgsSend {
+            pub fn visit_fields<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                visitor: &mut V,
+            ) {
+                ::pilota::visit::VisitValue::visit_value(&self.id, "id", visitor);
+            }
+        }
+
+        impl ::pilota::visit::VisitValue for DocServicePlainArgsSend {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                self.visit_fields(visitor);
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            Default,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            Clone,
+            PartialEq,
+        )]
+        pub struct DocServicePlainArgsSend {
+            pub id: i64,
+        }
+        impl ::pilota::thrift::Message for DocServicePlainArgsSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainArgsSend",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.id)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "DocServicePlainArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("id"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field id is required".to_string(),
+                    ));
+                };
+
+                let data = Self { id: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I64 =>
+                                {
+                                    var_1 = Some(__protocol.read_i64().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "DocServicePlainArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { id: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServicePlainArgsSend",
+                }) + __protocol.i64_field_len(Some(1), *&self.id)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl<'cow> ::pilota::schema::Schema for DocServiceGetArgsSend<'cow> {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "DocServiceGetArgsSend",
+                    fields: &[
+                        ::pilota::schema::FieldSchema {
+                            name: "title",
+                            id: 1,
+                            ty: ::pilota::schema::TypeSchema::String,
+                            required: true,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "tags",
+                            id: 2,
+                            ty: ::pilota::schema::TypeSchema::List(
+                                &::pilota::schema::TypeSchema::String,
+                            ),
+                            required: true,
+                        },
+                    ],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl<'cow> DocServiceGetArgsSend<'cow> {
+            pub fn stable_hash(&self) -> u64 {
+                ::pilota::hash::stable_hash(self)
+            }
+        }
+
+        impl<'cow> ::pilota::hash::StableHash for DocServiceGetArgsSend<'cow> {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                ::pilota::hash::StableHash::write_stable(&1i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.title, hasher);
+                ::pilota::hash::StableHash::write_stable(&2i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.tags, hasher);
+            }
+        }
+
+        impl<'cow> DocServiceGetArgsSend<'cow> {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(2);
+                map.insert(
+                    "title".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.title),
+                );
+                map.insert(
+                    "tags".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.tags),
+                );
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    title: ::pilota::field_map::required(&mut map, "title")?,
+                    tags: ::pilota::field_map::required(&mut map, "tags")?,
+                })
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::ToValue for DocServiceGetArgsSend<'cow> {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::FromValue for DocServiceGetArgsSend<'cow> {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+
+        impl<'cow> DocServiceGetArgsSend<'cow> {
+            pub fn visit_fields<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                visitor: &mut V,
+            ) {
+                ::pilota::visit::VisitValue::visit_value(&self.title, "title", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.tags, "tags", visitor);
+            }
+        }
+
+        impl<'cow> ::pilota::visit::VisitValue for DocServiceGetArgsSend<'cow> {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                self.visit_fields(visitor);
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            Default,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            PartialEq,
+        )]
+        pub struct DocServiceGetArgsSend<'cow> {
+            #[arbitrary(with = |u| ::pilota::arbitrary::cow(u, ::pilota::arbitrary::Arbitrary::arbitrary))]
+            pub title: ::std::borrow::Cow<'cow, str>,
+            #[arbitrary(with = |u| ::pilota::arbitrary::cow(u, |u| ::pilota::arbitrary::collect(u, ::pilota::arbitrary::faststr)))]
+            pub tags: ::std::borrow::Cow<'cow, [::pilota::FastStr]>,
+        }
+        impl<'cow> ::std::clone::Clone for DocServiceGetArgsSend<'cow> {
+            fn clone(&self) -> Self {
+                Self {
+                    title: ::std::clone::Clone::clone(&self.title),
+                    tags: ::std::clone::Clone::clone(&self.tags),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::thrift::Message for DocServiceGetArgsSend<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetArgsSend",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_string_field(1, &self.title)?;
+                __protocol.write_list_field(
+                    2,
+                    ::pilota::thrift::TType::Binary,
+                    &&self.tags,
+                    |__protocol, val| {
+                        __protocol.write_faststr((val).clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(::std::borrow::Cow::Owned(__protocol.read_string()?));
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_2 = Some(::std::borrow::Cow::Owned(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<::pilota::FastStr> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_faststr()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                }));
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "DocServiceGetArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("title"),
+                                2 => ::std::option::Option::Some("tags"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field title is required".to_string(),
+                    ));
+                };
+                let Some(var_2) = var_2 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field tags is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    title: var_1,
+                    tags: var_2,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(::std::borrow::Cow::Owned(
+                                        __protocol.read_string().await?,
+                                    ));
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
+                                {
+                                    var_2 = Some(::std::borrow::Cow::Owned({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val =
+                                            ::std::vec::Vec::with_capacity(list_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_faststr().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    }));
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "DocServiceGetArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("title"),
+                                    2 => ::std::option::Option::Some("tags"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field title is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field tags is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        title: var_1,
+                        tags: var_2,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "DocServiceGetArgsSend",
+                }) + __protocol.string_field_len(Some(1), &&self.title)
+                    + __protocol.list_field_len(
+                        Some(2),
+                        ::pilota::thrift::TType::Binary,
+                        &self.tags,
+                        |__protocol, el| __protocol.faststr_len(el),
+                    )
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl<'cow> ::std::default::Default for Doc<'cow> {
+            fn default() -> Self {
+                Doc {
+                    title: ::std::default::Default::default(),
+                    author: Some(::std::borrow::Cow::Owned("anonymous".to_string())),
+                    body: ::std::default::Default::default(),
+                    tags: ::std::default::Default::default(),
+                    scores: ::std::default::Default::default(),
+                    meta: ::std::default::Default::default(),
+                    version: ::std::default::Default::default(),
+                    inner: ::std::default::Default::default(),
+                    children: ::std::default::Default::default(),
+                    alias: ::std::default::Default::default(),
+                    part: ::std::default::Default::default(),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::schema::Schema for Doc<'cow> {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "Doc",
+                    fields: &[
+                        ::pilota::schema::FieldSchema {
+                            name: "title",
+                            id: 1,
+                            ty: ::pilota::schema::TypeSchema::String,
+                            required: true,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "author",
+                            id: 2,
+                            ty: ::pilota::schema::TypeSchema::String,
+                            required: false,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "body",
+                            id: 3,
+                            ty: ::pilota::schema::TypeSchema::List(
+                                &::pilota::schema::TypeSchema::U8,
+                            ),
+                            required: true,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "tags",
+                            id: 4,
+                            ty: ::pilota::schema::TypeSchema::List(
+                                &::pilota::schema::TypeSchema::String,
+                            ),
+                            required: true,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "scores",
+                            id: 5,
+                            ty: ::pilota::schema::TypeSchema::List(
+                                &::pilota::schema::TypeSchema::I32,
+                            ),
+                            required: false,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "meta",
+                            id: 6,
+                            ty: ::pilota::schema::TypeSchema::Map(
+                                &::pilota::schema::TypeSchema::String,
+                                &::pilota::schema::TypeSchema::String,
+                            ),
+                            required: false,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "version",
+                            id: 7,
+                            ty: ::pilota::schema::TypeSchema::I64,
+                            required: true,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "inner",
+                            id: 8,
+                            ty: ::pilota::schema::TypeSchema::Message(
+                                <Inner as ::pilota::schema::Schema>::schema,
+                            ),
+                            required: false,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "children",
+                            id: 9,
+                            ty: ::pilota::schema::TypeSchema::List(
+                                &::pilota::schema::TypeSchema::Message(
+                                    <Inner as ::pilota::schema::Schema>::schema,
+                                ),
+                            ),
+                            required: false,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "alias",
+                            id: 10,
+                            ty: ::pilota::schema::TypeSchema::Message(
+                                <Inner as ::pilota::schema::Schema>::schema,
+                            ),
+                            required: false,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "part",
+                            id: 11,
+                            ty: ::pilota::schema::TypeSchema::Message(
+                                <Part as ::pilota::schema::Schema>::schema,
+                            ),
+                            required: false,
+                        },
+                    ],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl<'cow> ::std::hash::Hash for Doc<'cow> {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                ::std::hash::Hash::hash(&self.title, state);
+                ::std::hash::Hash::hash(&self.author, state);
+                ::std::hash::Hash::hash(&self.body, state);
+                ::std::hash::Hash::hash(&self.tags, state);
+                ::std::hash::Hash::hash(&self.scores, state);
+                ::std::hash::Hash::hash(&self.meta.is_some(), state);
+                if let ::std::option::Option::Some(value) = &self.meta {
+                    ::pilota::hash::hash_unordered(value.iter(), state);
+                }
+                ::std::hash::Hash::hash(&self.version, state);
+                ::std::hash::Hash::hash(&self.inner, state);
+                ::std::hash::Hash::hash(&self.children, state);
+                ::std::hash::Hash::hash(&self.alias, state);
+                ::std::hash::Hash::hash(&self.part, state);
+            }
+        }
+
+        impl<'cow> Doc<'cow> {
+            pub fn stable_hash(&self) -> u64 {
+                ::pilota::hash::stable_hash(self)
+            }
+        }
+
+        impl<'cow> ::pilota::hash::StableHash for Doc<'cow> {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                ::pilota::hash::StableHash::write_stable(&1i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.title, hasher);
+                ::pilota::hash::StableHash::write_stable(&2i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.author, hasher);
+                ::pilota::hash::StableHash::write_stable(&3i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.body, hasher);
+                ::pilota::hash::StableHash::write_stable(&4i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.tags, hasher);
+                ::pilota::hash::StableHash::write_stable(&5i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.scores, hasher);
+                ::pilota::hash::StableHash::write_stable(&6i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.meta, hasher);
+                ::pilota::hash::StableHash::write_stable(&7i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.version, hasher);
+                ::pilota::hash::StableHash::write_stable(&8i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.inner, hasher);
+                ::pilota::hash::StableHash::write_stable(&9i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.children, hasher);
+                ::pilota::hash::StableHash::write_stable(&10i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.alias, hasher);
+                ::pilota::hash::StableHash::write_stable(&11i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.part, hasher);
+            }
+        }
+
+        impl<'cow> Doc<'cow> {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(11);
+                map.insert(
+                    "title".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.title),
+                );
+                if let ::std::option::Option::Some(value) = &self.author {
+                    map.insert(
+                        "author".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                map.insert(
+                    "body".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.body),
+                );
+                map.insert(
+                    "tags".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.tags),
+                );
+                if let ::std::option::Option::Some(value) = &self.scores {
+                    map.insert(
+                        "scores".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.meta {
+                    map.insert(
+                        "meta".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                map.insert(
+                    "version".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.version),
+                );
+                if let ::std::option::Option::Some(value) = &self.inner {
+                    map.insert(
+                        "inner".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.children {
+                    map.insert(
+                        "children".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.alias {
+                    map.insert(
+                        "alias".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.part {
+                    map.insert(
+                        "part".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    title: ::pilota::field_map::required(&mut map, "title")?,
+                    author: ::pilota::field_map::optional(&mut map, "author")?,
+                    body: ::pilota::field_map::required(&mut map, "body")?,
+                    tags: ::pilota::field_map::required(&mut map, "tags")?,
+                    scores: ::pilota::field_map::optional(&mut map, "scores")?,
+                    meta: ::pilota::field_map::optional(&mut map, "meta")?,
+                    version: ::pilota::field_map::required(&mut map, "version")?,
+                    inner: ::pilota::field_map::optional(&mut map, "inner")?,
+                    children: ::pilota::field_map::optional(&mut map, "children")?,
+                    alias: ::pilota::field_map::optional(&mut map, "alias")?,
+                    part: ::pilota::field_map::optional(&mut map, "part")?,
+                })
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::ToValue for Doc<'cow> {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl<'cow> ::pilota::field_map::FromValue for Doc<'cow> {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+
+        impl<'cow> Doc<'cow> {
+            pub fn visit_fields<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                visitor: &mut V,
+            ) {
+                ::pilota::visit::VisitValue::visit_value(&self.title, "title", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.author, "author", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.body, "body", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.tags, "tags", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.scores, "scores", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.meta, "meta", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.version, "version", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.inner, "inner", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.children, "children", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.alias, "alias", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.part, "part", visitor);
+            }
+        }
+
+        impl<'cow> ::pilota::visit::VisitValue for Doc<'cow> {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                self.visit_fields(visitor);
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(
+            Debug,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            ::pilota::arbitrary::Arbitrary,
+            PartialEq,
+        )]
+        pub struct Doc<'cow> {
+            #[arbitrary(with = |u| ::pilota::arbitrary::cow(u, ::pilota::arbitrary::Arbitrary::arbitrary))]
+            pub title: ::std::borrow::Cow<'cow, str>,
+            #[arbitrary(with = |u| ::pilota::arbitrary::option(u, |u| ::pilota::arbitrary::cow(u, ::pilota::arbitrary::Arbitrary::arbitrary)))]
+            pub author: ::std::option::Option<::std::borrow::Cow<'cow, str>>,
+            #[arbitrary(with = |u| ::pilota::arbitrary::cow(u, ::pilota::arbitrary::Arbitrary::arbitrary))]
+            pub body: ::std::borrow::Cow<'cow, [u8]>,
+            #[arbitrary(with = |u| ::pilota::arbitrary::cow(u, |u| ::pilota::arbitrary::collect(u, ::pilota::arbitrary::faststr)))]
+            pub tags: ::std::borrow::Cow<'cow, [::pilota::FastStr]>,
+            #[arbitrary(with = |u| ::pilota::arbitrary::option(u, |u| ::pilota::arbitrary::cow(u, ::pilota::arbitrary::Arbitrary::arbitrary)))]
+            pub scores: ::std::option::Option<::std::borrow::Cow<'cow, [i32]>>,
+            #[arbitrary(with = |u| ::pilota::arbitrary::option(u, |u| ::pilota::arbitrary::collect_map(u, ::pilota::arbitrary::faststr, ::pilota::arbitrary::faststr)))]
+            pub meta:
+                ::std::option::Option<::pilota::AHashMap<::pilota::FastStr, ::pilota::FastStr>>,
+
+            pub version: i64,
+
+            pub inner: ::std::option::Option<Inner<'cow>>,
+            #[arbitrary(with = |u| ::pilota::arbitrary::option(u, |u| ::pilota::arbitrary::cow(u, ::pilota::arbitrary::Arbitrary::arbitrary)))]
+            pub children: ::std::option::Option<::std::borrow::Cow<'cow, [Inner<'cow>]>>,
+
+            pub alias: ::std::option::Option<Alias<'cow>>,
+
+            pub part: ::std::option::Option<Part<'cow>>,
+        }
+        impl<'cow> ::std::clone::Clone for Doc<'cow> {
+            fn clone(&self) -> Self {
+                Self {
+                    title: ::std::clone::Clone::clone(&self.title),
+                    author: ::std::clone::Clone::clone(&self.author),
+                    body: ::std::clone::Clone::clone(&self.body),
+                    tags: ::std::clone::Clone::clone(&self.tags),
+                    scores: ::std::clone::Clone::clone(&self.scores),
+                    meta: ::std::clone::Clone::clone(&self.meta),
+                    version: ::std::clone::Clone::clone(&self.version),
+                    inner: ::std::clone::Clone::clone(&self.inner),
+                    children: ::std::clone::Clone::clone(&self.children),
+                    alias: ::std::clone::Clone::clone(&self.alias),
+                    part: ::std::clone::Clone::clone(&self.part),
+                }
+            }
+        }
+
+        impl<'cow> ::pilota::thrift::Message for Doc<'cow> {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Doc" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_string_field(1, &self.title)?;
+                if let Some(value) = self.author.as_ref() {
+                    __protocol.write_string_field(2, value)?;
+                }
+                __protocol.write_bytes_vec_field(3, &self.body)?;
+                __protocol.write_list_field(
+                    4,
+                    ::pilota::thrift::TType::Binary,
+                    &&self.tags,
+                    |__protocol, val| {
+                        __protocol.write_faststr((val).clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                if let Some(value) = self.scores.as_ref() {
+                    __protocol.write_list_field(
+                        5,
+                        ::pilota::thrift::TType::I32,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_i32(*val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                if let Some(value) = self.meta.as_ref() {
+                    __protocol.write_map_field(
+                        6,
+                        ::pilota::thrift::TType::Binary,
+                        ::pilota::thrift::TType::Binary,
+                        &value,
+                        |__protocol, key| {
+                            __protocol.write_faststr((key).clone())?;
+                            ::std::result::Result::Ok(())
+                        },
+                        |__protocol, val| {
+                            __protocol.write_faststr((val).clone())?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                __protocol.write_i64_field(7, *&self.version)?;
+                if let Some(value) = self.inner.as_ref() {
+                    __protocol.write_struct_field(8, value, ::pilota::thrift::TType::Struct)?;
+                }
+                if let Some(value) = self.children.as_ref() {
+                    __protocol.write_list_field(
+                        9,
+                        ::pilota::thrift::TType::Struct,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_struct(val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                if let Some(value) = self.alias.as_ref() {
+                    __protocol.write_struct_field(10, value, ::pilota::thrift::TType::Struct)?;
+                }
+                if let Some(value) = self.part.as_ref() {
+                    __protocol.write_struct_field(11, value, ::pilota::thrift::TType::Struct)?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut var_6 = None;
+                let mut var_7 = None;
+                let mut var_8 = None;
+                let mut var_9 = None;
+                let mut var_10 = None;
+                let mut var_11 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(::std::borrow::Cow::Owned(__protocol.read_string()?));
+                            }
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_2 = Some(::std::borrow::Cow::Owned(__protocol.read_string()?));
+                            }
+                            Some(3)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_3 =
+                                    Some(::std::borrow::Cow::Owned(__protocol.read_bytes_vec()?));
+                            }
+                            Some(4) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_4 = Some(::std::borrow::Cow::Owned(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<::pilota::FastStr> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_faststr()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                }));
+                            }
+                            Some(5) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_5 = Some(::std::borrow::Cow::Owned(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<i32> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                }));
+                            }
+                            Some(6) if field_ident.field_type == ::pilota::thrift::TType::Map => {
+                                var_6 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
+                            Some(7) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_7 = Some(__protocol.read_i64()?);
+                            }
+                            Some(8)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_8 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(9) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_9 = Some(::std::borrow::Cow::Owned(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<Inner<'cow>> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                }));
+                            }
+                            Some(10)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_10 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(11)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_11 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Doc",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("title"),
+                                2 => ::std::option::Option::Some("author"),
+                                3 => ::std::option::Option::Some("body"),
+                                4 => ::std::option::Option::Some("tags"),
+                                5 => ::std::option::Option::Some("scores"),
+                                6 => ::std::option::Option::Some("meta"),
+                                7 => ::std::option::Option::Some("version"),
+                                8 => ::std::option::Option::Some("inner"),
+                                9 => ::std::option::Option::Some("children"),
+                                10 => ::std::option::Option::Some("alias"),
+                                11 => ::std::option::Option::Some("part"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field title is required".to_string(),
+                    ));
+                };
+                let Some(var_3) = var_3 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field body is required".to_string(),
+                    ));
+                };
+                let Some(var_4) = var_4 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field tags is required".to_string(),
+                    ));
+                };
+                let Some(var_7) = var_7 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field version is required".to_string(),
+                    ));
+                };
+
+                if var_2.is_none() {
+                    var_2 = Some(::std::borrow::Cow::Owned("anonymous".to_string()));
+                }
+
+                let data = Self {
+                    title: var_1,
+                    author: var_2,
+                    body: var_3,
+                    tags: var_4,
+                    scores: var_5,
+                    meta: var_6,
+                    version: var_7,
+                    inner: var_8,
+                    children: var_9,
+                    alias: var_10,
+                    part: var_11,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut var_6 = None;
+                    let mut var_7 = None;
+                    let mut var_8 = None;
+                    let mut var_9 = None;
+                    let mut var_10 = None;
+                    let mut var_11 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                    loop {
+
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_1 = Some(::std::borrow::Cow::Owned(__protocol.read_string().await?));
+
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_2 = Some(::std::borrow::Cow::Owned(__protocol.read_string().await?));
+
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_3 = Some(::std::borrow::Cow::Owned(__protocol.read_bytes_vec().await?));
+
+                },Some(4) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_4 = Some(::std::borrow::Cow::Owned({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(__protocol.read_faststr().await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        }));
+
+                },Some(5) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_5 = Some(::std::borrow::Cow::Owned({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(__protocol.read_i32().await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        }));
+
+                },Some(6) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+                    var_6 = Some({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_faststr().await?, __protocol.read_faststr().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
+
+                },Some(7) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+                    var_7 = Some(__protocol.read_i64().await?);
+
+                },Some(8) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_8 = Some(<Inner<'cow> as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(9) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_9 = Some(::std::borrow::Cow::Owned({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(<Inner<'cow> as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        }));
+
+                },Some(10) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_10 = Some(<Alias<'cow> as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(11) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_11 = Some(<Part<'cow> as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },
+                    _ => {
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Doc",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("title"),2 => ::std::option::Option::Some("author"),3 => ::std::option::Option::Some("body"),4 => ::std::option::Option::Some("tags"),5 => ::std::option::Option::Some("scores"),6 => ::std::option::Option::Some("meta"),7 => ::std::option::Option::Some("version"),8 => ::std::option::Option::Some("inner"),9 => ::std::option::Option::Some("children"),10 => ::std::option::Option::Some("alias"),11 => ::std::option::Option::Some("part"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field title is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field body is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_4) = var_4 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field tags is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_7) = var_7 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field version is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    if var_2.is_none() {
+                        var_2 = Some(::std::borrow::Cow::Owned("anonymous".to_string()));
+                    }
+
+                    let data = Self {
+                        title: var_1,
+                        author: var_2,
+                        body: var_3,
+                        tags: var_4,
+                        scores: var_5,
+                        meta: var_6,
+                        version: var_7,
+                        inner: var_8,
+                        children: var_9,
+                        alias: var_10,
+                        part: var_11,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Doc" })
+                    + __protocol.string_field_len(Some(1), &&self.title)
+                    + self
+                        .author
+                        .as_ref()
+                        .map_or(0, |value| __protocol.string_field_len(Some(2), &value))
+                    + __protocol.bytes_vec_field_len(Some(3), &self.body)
+                    + __protocol.list_field_len(
+                        Some(4),
+                        ::pilota::thrift::TType::Binary,
+                        &self.tags,
+                        |__protocol, el| __protocol.faststr_len(el),
+                    )
+                    + self.scores.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(5),
+                            ::pilota::thrift::TType::I32,
+                            value,
+                            |__protocol, el| __protocol.i32_len(*el),
+                        )
+                    })
+                    + self.meta.as_ref().map_or(0, |value| {
+                        __protocol.map_field_len(
+                            Some(6),
+                            ::pilota::thrift::TType::Binary,
+                            ::pilota::thrift::TType::Binary,
+                            value,
+                            |__protocol, key| __protocol.faststr_len(key),
+                            |__protocol, val| __protocol.faststr_len(val),
+                        )
+                    })
+                    + __protocol.i64_field_len(Some(7), *&self.version)
+                    + self
+                        .inner
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(8), value))
+                    + self.children.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(9),
+                            ::pilota::thrift::TType::Struct,
+                            value,
+                            |__protocol, el| __protocol.struct_len(el),
+                        )
+                    })
+                    + self
+                        .alias
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(10), value))
+                    + self
+                        .part
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(11), value))
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+    }
+}
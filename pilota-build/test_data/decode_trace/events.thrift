@@ -0,0 +1,15 @@
+struct Event {
+    1: required i64 id,
+    2: optional string name,
+}
+
+struct EventV2 {
+    1: required i64 id,
+    2: optional string name,
+    3: optional list<string> labels,
+}
+
+union Payload {
+    1: Event event,
+    2: string raw,
+}
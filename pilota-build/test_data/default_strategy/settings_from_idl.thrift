@@ -0,0 +1,11 @@
+struct Settings {
+    1: required i32 retries = 3,
+    2: required string region = "eu",
+    3: required map<string, i32> limits = {"requests": 100},
+    4: optional bool verbose = true,
+    5: optional i64 timeout,
+}
+
+struct Plain {
+    1: required i32 count,
+}
@@ -0,0 +1,833 @@
+pub mod gallery {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod media {
+
+        pub mod gallery {
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+            #[repr(transparent)]
+            pub struct Layout(i32);
+
+            impl Layout {
+                pub const GRID: Self = Self(0);
+                pub const LIST: Self = Self(1);
+
+                pub fn inner(&self) -> i32 {
+                    self.0
+                }
+
+                pub fn to_string(&self) -> ::std::string::String {
+                    match self {
+                        Self(0) => ::std::string::String::from("GRID"),
+                        Self(1) => ::std::string::String::from("LIST"),
+                        Self(val) => val.to_string(),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<i32> for Layout {
+                fn from(value: i32) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl ::std::convert::From<Layout> for i32 {
+                fn from(value: Layout) -> i32 {
+                    value.0
+                }
+            }
+
+            impl ::pilota::thrift::Message for Layout {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TOutputProtocolExt;
+                    __protocol.write_i32(self.inner())?;
+                    ::std::result::Result::Ok(())
+                }
+
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
+                {
+                    #[allow(unused_imports)]
+                    use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                    let value = __protocol.read_i32()?;
+                    ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                        |err| {
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("invalid enum value for Layout, value: {}", value),
+                            )
+                        },
+                    )?)
+                }
+
+                fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                    __protocol: &'a mut T,
+                ) -> ::std::pin::Pin<
+                    ::std::boxed::Box<
+                        dyn ::std::future::Future<
+                                Output = ::std::result::Result<
+                                    Self,
+                                    ::pilota::thrift::ThriftException,
+                                >,
+                            > + Send
+                            + 'a,
+                    >,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        let value = __protocol.read_i32().await?;
+                        ::std::result::Result::Ok(
+                            ::std::convert::TryFrom::try_from(value).map_err(|err| {
+                                ::pilota::thrift::new_protocol_exception(
+                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                    format!("invalid enum value for Layout, value: {}", value),
+                                )
+                            })?,
+                        )
+                    })
+                }
+
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TLengthProtocolExt;
+                    __protocol.i32_len(self.inner())
+                }
+            }
+            impl ::std::default::Default for Cover {
+                fn default() -> Self {
+                    Cover::Image(::std::default::Default::default())
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+            pub enum Cover {
+                #[doc = "[`Image`](super::image::Image)"]
+                Image(super::image::Image),
+
+                Text(::pilota::FastStr),
+            }
+
+            impl ::pilota::thrift::Message for Cover {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TOutputProtocolExt;
+                    __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                        name: "Cover",
+                    })?;
+                    match self {
+                        Cover::Image(ref value) => {
+                            __protocol.write_struct_field(
+                                1,
+                                value,
+                                ::pilota::thrift::TType::Struct,
+                            )?;
+                        }
+                        Cover::Text(ref value) => {
+                            __protocol.write_faststr_field(2, (value).clone())?;
+                        }
+                    }
+                    __protocol.write_field_stop()?;
+                    __protocol.write_struct_end()?;
+                    ::std::result::Result::Ok(())
+                }
+
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
+                {
+                    #[allow(unused_imports)]
+                    use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                    let mut ret = None;
+                    __protocol.read_struct_begin()?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        match field_ident.id {
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        ::pilota::thrift::Message::decode(__protocol)?;
+                                    __protocol.struct_len(&field_ident);
+                                    ret = Some(Cover::Image(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            Some(2) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_faststr()?;
+                                    __protocol.faststr_len(&field_ident);
+                                    ret = Some(Cover::Text(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end()?;
+                    __protocol.read_struct_end()?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                }
+
+                fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                    __protocol: &'a mut T,
+                ) -> ::std::pin::Pin<
+                    ::std::boxed::Box<
+                        dyn ::std::future::Future<
+                                Output = ::std::result::Result<
+                                    Self,
+                                    ::pilota::thrift::ThriftException,
+                                >,
+                            > + Send
+                            + 'a,
+                    >,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        let mut ret = None;
+                        __protocol.read_struct_begin().await?;
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            match field_ident.id {
+                                Some(1) => {
+                                    if ret.is_none() {
+                                        let field_ident = <super::image::Image as ::pilota::thrift::Message>::decode_async(__protocol).await?;
+
+                                        ret = Some(Cover::Image(field_ident));
+                                    } else {
+                                        return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                    }
+                                }
+                                Some(2) => {
+                                    if ret.is_none() {
+                                        let field_ident = __protocol.read_faststr().await?;
+
+                                        ret = Some(Cover::Text(field_ident));
+                                    } else {
+                                        return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                    }
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+                        }
+                        __protocol.read_field_end().await?;
+                        __protocol.read_struct_end().await?;
+                        if let Some(ret) = ret {
+                            ::std::result::Result::Ok(ret)
+                        } else {
+                            ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "received empty union from remote Message",
+                            ))
+                        }
+                    })
+                }
+
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TLengthProtocolExt;
+                    __protocol
+                        .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Cover" })
+                        + match self {
+                            Cover::Image(ref value) => __protocol.struct_field_len(Some(1), value),
+                            Cover::Text(ref value) => __protocol.faststr_field_len(Some(2), value),
+                        }
+                        + __protocol.field_stop_len()
+                        + __protocol.struct_end_len()
+                }
+            }
+            #[derive(Debug, Default, Clone, PartialEq)]
+            pub struct Gallery {
+                pub title: ::pilota::FastStr,
+                #[doc = "[`Layout`](Layout)"]
+                pub layout: Layout,
+                #[doc = "[`Image`](super::image::Image)"]
+                pub images: ::std::vec::Vec<super::image::Image>,
+                #[doc = "[`Image`](super::image::Image)"]
+                pub by_name: ::std::option::Option<
+                    ::pilota::AHashMap<::pilota::FastStr, super::image::Image>,
+                >,
+            }
+            impl ::pilota::thrift::Message for Gallery {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TOutputProtocolExt;
+                    let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Gallery" };
+
+                    __protocol.write_struct_begin(&struct_ident)?;
+                    __protocol.write_faststr_field(1, (&self.title).clone())?;
+                    __protocol.write_i32_field(2, (&self.layout).inner())?;
+                    __protocol.write_list_field(
+                        3,
+                        ::pilota::thrift::TType::Struct,
+                        &&self.images,
+                        |__protocol, val| {
+                            __protocol.write_struct(val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                    if let Some(value) = self.by_name.as_ref() {
+                        __protocol.write_map_field(
+                            4,
+                            ::pilota::thrift::TType::Binary,
+                            ::pilota::thrift::TType::Struct,
+                            &value,
+                            |__protocol, key| {
+                                __protocol.write_faststr((key).clone())?;
+                                ::std::result::Result::Ok(())
+                            },
+                            |__protocol, val| {
+                                __protocol.write_struct(val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                    }
+                    __protocol.write_field_stop()?;
+                    __protocol.write_struct_end()?;
+                    ::std::result::Result::Ok(())
+                }
+
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
+                {
+                    #[allow(unused_imports)]
+                    use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin()?;
+                    if let ::std::result::Result::Err(mut err) = (|| {
+                        loop {
+                            let field_ident = __protocol.read_field_begin()?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                __protocol.field_stop_len();
+                                break;
+                            } else {
+                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(__protocol.read_faststr()?);
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_2 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                                }
+                                Some(3)
+                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
+                                {
+                                    var_3 = Some(unsafe {
+                                        let list_ident = __protocol.read_list_begin()?;
+                                        let mut val: ::std::vec::Vec<super::image::Image> =
+                                            ::std::vec::Vec::with_capacity(list_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = (|| {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.as_mut_ptr().offset(i as isize).write(
+                                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        })(
+                                        ) {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        val.set_len(list_ident.size);
+                                        __protocol.read_list_end()?;
+                                        val
+                                    });
+                                }
+                                Some(4)
+                                    if field_ident.field_type == ::pilota::thrift::TType::Map =>
+                                {
+                                    var_4 = Some({
+                                        let map_ident = __protocol.read_map_begin()?;
+                                        let mut val =
+                                            ::pilota::AHashMap::with_capacity(map_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = (|| {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_faststr()?,
+                                                    ::pilota::thrift::Message::decode(__protocol)?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        })(
+                                        ) {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        __protocol.read_map_end()?;
+                                        val
+                                    });
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type)?;
+                                }
+                            }
+
+                            __protocol.read_field_end()?;
+                            __protocol.field_end_len();
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    })() {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Gallery",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("title"),
+                                    2 => ::std::option::Option::Some("layout"),
+                                    3 => ::std::option::Option::Some("images"),
+                                    4 => ::std::option::Option::Some("by_name"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end()?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field title is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_2) = var_2 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field layout is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field images is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        title: var_1,
+                        layout: var_2,
+                        images: var_3,
+                        by_name: var_4,
+                    };
+                    ::std::result::Result::Ok(data)
+                }
+
+                fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                    __protocol: &'a mut T,
+                ) -> ::std::pin::Pin<
+                    ::std::boxed::Box<
+                        dyn ::std::future::Future<
+                                Output = ::std::result::Result<
+                                    Self,
+                                    ::pilota::thrift::ThriftException,
+                                >,
+                            > + Send
+                            + 'a,
+                    >,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        let mut var_1 = None;
+                        let mut var_2 = None;
+                        let mut var_3 = None;
+                        let mut var_4 = None;
+
+                        let mut __pilota_decoding_field_id = None;
+
+                        __protocol.read_struct_begin().await?;
+                        if let ::std::result::Result::Err(mut err) = async {
+                    loop {
+
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_1 = Some(__protocol.read_faststr().await?);
+
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32  => {
+                    var_2 = Some(<Layout as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_3 = Some({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(<super::image::Image as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+
+                },Some(4) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+                    var_4 = Some({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_faststr().await?, <super::image::Image as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
+
+                },
+                    _ => {
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Gallery",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("title"),2 => ::std::option::Option::Some("layout"),3 => ::std::option::Option::Some("images"),4 => ::std::option::Option::Some("by_name"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+                        __protocol.read_struct_end().await?;
+
+                        let Some(var_1) = var_1 else {
+                            return ::std::result::Result::Err(
+                                ::pilota::thrift::new_protocol_exception(
+                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                    "field title is required".to_string(),
+                                ),
+                            );
+                        };
+                        let Some(var_2) = var_2 else {
+                            return ::std::result::Result::Err(
+                                ::pilota::thrift::new_protocol_exception(
+                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                    "field layout is required".to_string(),
+                                ),
+                            );
+                        };
+                        let Some(var_3) = var_3 else {
+                            return ::std::result::Result::Err(
+                                ::pilota::thrift::new_protocol_exception(
+                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                    "field images is required".to_string(),
+                                ),
+                            );
+                        };
+
+                        let data = Self {
+                            title: var_1,
+                            layout: var_2,
+                            images: var_3,
+                            by_name: var_4,
+                        };
+                        ::std::result::Result::Ok(data)
+                    })
+                }
+
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TLengthProtocolExt;
+                    __protocol
+                        .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Gallery" })
+                        + __protocol.faststr_field_len(Some(1), &self.title)
+                        + __protocol.i32_field_len(Some(2), (&self.layout).inner())
+                        + __protocol.list_field_len(
+                            Some(3),
+                            ::pilota::thrift::TType::Struct,
+                            &self.images,
+                            |__protocol, el| __protocol.struct_len(el),
+                        )
+                        + self.by_name.as_ref().map_or(0, |value| {
+                            __protocol.map_field_len(
+                                Some(4),
+                                ::pilota::thrift::TType::Binary,
+                                ::pilota::thrift::TType::Struct,
+                                value,
+                                |__protocol, key| __protocol.faststr_len(key),
+                                |__protocol, val| __protocol.struct_len(val),
+                            )
+                        })
+                        + __protocol.field_stop_len()
+                        + __protocol.struct_end_len()
+                }
+            }
+        }
+
+        pub mod image {
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct Image {
+                pub url: ::pilota::FastStr,
+            }
+            impl ::pilota::thrift::Message for Image {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TOutputProtocolExt;
+                    let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Image" };
+
+                    __protocol.write_struct_begin(&struct_ident)?;
+                    __protocol.write_faststr_field(1, (&self.url).clone())?;
+                    __protocol.write_field_stop()?;
+                    __protocol.write_struct_end()?;
+                    ::std::result::Result::Ok(())
+                }
+
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
+                {
+                    #[allow(unused_imports)]
+                    use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin()?;
+                    if let ::std::result::Result::Err(mut err) = (|| {
+                        loop {
+                            let field_ident = __protocol.read_field_begin()?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                __protocol.field_stop_len();
+                                break;
+                            } else {
+                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(__protocol.read_faststr()?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type)?;
+                                }
+                            }
+
+                            __protocol.read_field_end()?;
+                            __protocol.field_end_len();
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    })() {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Image",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("url"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end()?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field url is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { url: var_1 };
+                    ::std::result::Result::Ok(data)
+                }
+
+                fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                    __protocol: &'a mut T,
+                ) -> ::std::pin::Pin<
+                    ::std::boxed::Box<
+                        dyn ::std::future::Future<
+                                Output = ::std::result::Result<
+                                    Self,
+                                    ::pilota::thrift::ThriftException,
+                                >,
+                            > + Send
+                            + 'a,
+                    >,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        let mut var_1 = None;
+
+                        let mut __pilota_decoding_field_id = None;
+
+                        __protocol.read_struct_begin().await?;
+                        if let ::std::result::Result::Err(mut err) = async {
+                            loop {
+                                let field_ident = __protocol.read_field_begin().await?;
+                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                    break;
+                                } else {
+                                }
+                                __pilota_decoding_field_id = field_ident.id;
+                                match field_ident.id {
+                                    Some(1)
+                                        if field_ident.field_type
+                                            == ::pilota::thrift::TType::Binary =>
+                                    {
+                                        var_1 = Some(__protocol.read_faststr().await?);
+                                    }
+                                    _ => {
+                                        __protocol.skip(field_ident.field_type).await?;
+                                    }
+                                }
+
+                                __protocol.read_field_end().await?;
+                            }
+                            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                        }
+                        .await
+                        {
+                            if let Some(field_id) = __pilota_decoding_field_id {
+                                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                    message: "Image",
+                                    name: match field_id {
+                                        1 => ::std::option::Option::Some("url"),
+                                        _ => ::std::option::Option::None,
+                                    },
+                                    id: field_id,
+                                });
+                            }
+                            return ::std::result::Result::Err(err);
+                        };
+                        __protocol.read_struct_end().await?;
+
+                        let Some(var_1) = var_1 else {
+                            return ::std::result::Result::Err(
+                                ::pilota::thrift::new_protocol_exception(
+                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                    "field url is required".to_string(),
+                                ),
+                            );
+                        };
+
+                        let data = Self { url: var_1 };
+                        ::std::result::Result::Ok(data)
+                    })
+                }
+
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TLengthProtocolExt;
+                    __protocol
+                        .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Image" })
+                        + __protocol.faststr_field_len(Some(1), &self.url)
+                        + __protocol.field_stop_len()
+                        + __protocol.struct_end_len()
+                }
+            }
+        }
+    }
+}
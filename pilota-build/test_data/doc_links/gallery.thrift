@@ -0,0 +1,20 @@
+include "image.thrift"
+
+namespace rs media.gallery
+
+enum Layout {
+    GRID,
+    LIST,
+}
+
+struct Gallery {
+    1: required string title,
+    2: required Layout layout,
+    3: required list<image.Image> images,
+    4: optional map<string, image.Image> by_name,
+}
+
+union Cover {
+    1: image.Image image,
+    2: string text,
+}
@@ -0,0 +1,5 @@
+namespace rs media.image
+
+struct Image {
+    1: required string url,
+}
@@ -0,0 +1,9 @@
+service Base {
+    void ping(),
+}
+
+service Child extends Base {
+}
+
+service Empty {
+}
@@ -0,0 +1,14 @@
+enum Status {
+    ACTIVE = 1,
+    INACTIVE = 2,
+}
+
+union Payload {
+    1: string text,
+    2: i64 number,
+}
+
+struct Event {
+    1: required Status status,
+    2: required Payload payload,
+}
@@ -0,0 +1,14 @@
+enum Status {
+    UNKNOWN = -1,
+    ACTIVE,
+    INACTIVE,
+    BANNED = 10,
+    ALSO_BANNED = 10,
+}
+
+enum Empty {
+}
+
+struct Account {
+    1: required Status status,
+}
@@ -0,0 +1,17 @@
+enum Status {
+    ACTIVE,
+    INACTIVE,
+    BANNED = 10,
+    DELETED,
+    ALSO_BANNED = 10,
+}
+
+union Payload {
+    1: string text,
+    2: binary data,
+}
+
+struct Account {
+    1: required Status status,
+    2: optional Payload payload,
+}
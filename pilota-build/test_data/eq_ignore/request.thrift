@@ -0,0 +1,19 @@
+struct Request {
+    1: required string path,
+    2: required i64 request_id (pilota.eq_ignore = "true"),
+    3: optional i64 timestamp (pilota.eq_ignore = "true"),
+    4: optional i32 priority,
+}
+
+struct Trace {
+    1: required string id (pilota.eq_ignore = "true"),
+}
+
+struct Batch {
+    1: required list<Request> requests,
+    2: required map<string, i32> counts,
+}
+
+struct Plain {
+    1: required string name,
+}
@@ -0,0 +1,16 @@
+enum Currency {
+    EUR = 1,
+    USD = 2,
+}
+
+struct Money {
+    1: required i64 cents,
+    2: required string currency,
+}
+
+struct Invoice {
+    1: required Money total,
+    2: required list<Money> lines,
+    3: optional map<string, Money> fees,
+    4: required Currency currency,
+}
@@ -0,0 +1,21 @@
+struct Timestamp {
+    1: required i64 seconds,
+    2: required i32 nanos,
+}
+
+struct Duration {
+    1: required i64 seconds,
+}
+
+struct Event {
+    1: required Timestamp at,
+    2: optional list<Timestamp> history,
+}
+
+struct Audit {
+    1: required map<string, Timestamp> seen,
+}
+
+service EventService {
+    Timestamp latest(1: Event event),
+}
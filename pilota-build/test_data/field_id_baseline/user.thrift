@@ -0,0 +1,12 @@
+namespace rs user
+
+struct User {
+    1: required i64 id,
+    2: required string name,
+    3: optional string email,
+}
+
+struct Group {
+    1: required string name,
+    2: required list<User> members,
+}
@@ -0,0 +1,2227 @@
+pub mod config {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod config {
+
+        impl ::pilota::field_map::ToValue for Level {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                match self.inner() {
+                    1 => ::pilota::field_map::Value::String("LOW".to_string()),
+                    2 => ::pilota::field_map::Value::String("HIGH".to_string()),
+                    value => ::pilota::field_map::Value::I64(value as i64),
+                }
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for Level {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::String(variant) => match variant.as_str() {
+                        "LOW" => ::std::result::Result::Ok(Self::LOW),
+                        "HIGH" => ::std::result::Result::Ok(Self::HIGH),
+                        _ => {
+                            ::std::result::Result::Err(::pilota::field_map::FieldMapError::invalid(
+                                "a variant of `Level`",
+                                ::pilota::field_map::Value::String(variant),
+                            ))
+                        }
+                    },
+                    value => {
+                        <i32 as ::pilota::field_map::FromValue>::from_value(value).map(Self::from)
+                    }
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Level(i32);
+
+        impl Level {
+            pub const LOW: Self = Self(1);
+            pub const HIGH: Self = Self(2);
+
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+
+            pub fn to_string(&self) -> ::std::string::String {
+                match self {
+                    Self(1) => ::std::string::String::from("LOW"),
+                    Self(2) => ::std::string::String::from("HIGH"),
+                    Self(val) => val.to_string(),
+                }
+            }
+        }
+
+        impl ::std::convert::From<i32> for Level {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::std::convert::From<Level> for i32 {
+            fn from(value: Level) -> i32 {
+                value.0
+            }
+        }
+
+        impl ::pilota::thrift::Message for Level {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_i32(self.inner())?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                    |err| {
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Level, value: {}", value),
+                        )
+                    },
+                )?)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                        |err| {
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("invalid enum value for Level, value: {}", value),
+                            )
+                        },
+                    )?)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.i32_len(self.inner())
+            }
+        }
+        impl ConfigServiceGetArgsSend {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(1);
+                map.insert(
+                    "name".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.name),
+                );
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    name: ::pilota::field_map::required(&mut map, "name")?,
+                })
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for ConfigServiceGetArgsSend {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for ConfigServiceGetArgsSend {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct ConfigServiceGetArgsSend {
+            pub name: ::pilota::FastStr,
+        }
+        impl ::pilota::thrift::Message for ConfigServiceGetArgsSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "ConfigServiceGetArgsSend",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.name).clone())?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ConfigServiceGetArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("name"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name is required".to_string(),
+                    ));
+                };
+
+                let data = Self { name: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ConfigServiceGetArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("name"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { name: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "ConfigServiceGetArgsSend",
+                }) + __protocol.faststr_field_len(Some(1), &self.name)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl Limit {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(2);
+                map.insert(
+                    "name".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.name),
+                );
+                if let ::std::option::Option::Some(value) = &self.max {
+                    map.insert(
+                        "max".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    name: ::pilota::field_map::required(&mut map, "name")?,
+                    max: ::pilota::field_map::optional(&mut map, "max")?,
+                })
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for Limit {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for Limit {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Limit {
+            pub name: ::pilota::FastStr,
+
+            pub max: ::std::option::Option<i32>,
+        }
+        impl ::pilota::thrift::Message for Limit {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Limit" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.name).clone())?;
+                if let Some(value) = self.max.as_ref() {
+                    __protocol.write_i32_field(2, *value)?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_2 = Some(__protocol.read_i32()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Limit",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("name"),
+                                2 => ::std::option::Option::Some("max"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    name: var_1,
+                    max: var_2,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_2 = Some(__protocol.read_i32().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Limit",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("name"),
+                                    2 => ::std::option::Option::Some("max"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        name: var_1,
+                        max: var_2,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Limit" })
+                    + __protocol.faststr_field_len(Some(1), &self.name)
+                    + self
+                        .max
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i32_field_len(Some(2), *value))
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ConfigServiceGetArgsRecv {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(1);
+                map.insert(
+                    "name".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.name),
+                );
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    name: ::pilota::field_map::required(&mut map, "name")?,
+                })
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for ConfigServiceGetArgsRecv {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for ConfigServiceGetArgsRecv {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct ConfigServiceGetArgsRecv {
+            pub name: ::pilota::FastStr,
+        }
+        impl ::pilota::thrift::Message for ConfigServiceGetArgsRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier {
+                    name: "ConfigServiceGetArgsRecv",
+                };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.name).clone())?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ConfigServiceGetArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("name"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name is required".to_string(),
+                    ));
+                };
+
+                let data = Self { name: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(__protocol.read_faststr().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ConfigServiceGetArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("name"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { name: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "ConfigServiceGetArgsRecv",
+                }) + __protocol.faststr_field_len(Some(1), &self.name)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for ConfigServiceGetResultSend {
+            fn default() -> Self {
+                ConfigServiceGetResultSend::Ok(::std::default::Default::default())
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for ConfigServiceGetResultSend {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                match self {
+                    Self::Ok(value) => ::pilota::field_map::Value::Message(
+                        ::std::iter::once((
+                            "Ok".to_string(),
+                            ::pilota::field_map::ToValue::to_value(value),
+                        ))
+                        .collect(),
+                    ),
+                }
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for ConfigServiceGetResultSend {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                let map = match value {
+                    ::pilota::field_map::Value::Message(map) => map,
+                    value => {
+                        return ::std::result::Result::Err(
+                            ::pilota::field_map::FieldMapError::invalid("a message", value),
+                        )
+                    }
+                };
+
+                if map.len() != 1 {
+                    return ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid(
+                            "one variant of `ConfigServiceGetResultSend`",
+                            ::pilota::field_map::Value::Message(map),
+                        ),
+                    );
+                }
+                let (variant, value) = map.into_iter().next().unwrap();
+                match variant.as_str() {
+                    "Ok" => ::std::result::Result::Ok(Self::Ok(::pilota::field_map::from_field(
+                        "Ok", value,
+                    )?)),
+                    _ => ::std::result::Result::Err(::pilota::field_map::FieldMapError::invalid(
+                        "a variant of `ConfigServiceGetResultSend`",
+                        ::pilota::field_map::Value::Message(
+                            ::std::iter::once((variant, value)).collect(),
+                        ),
+                    )),
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum ConfigServiceGetResultSend {
+            Ok(Config),
+        }
+
+        impl ::pilota::thrift::Message for ConfigServiceGetResultSend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "ConfigServiceGetResultSend",
+                })?;
+                match self {
+                    ConfigServiceGetResultSend::Ok(ref value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ConfigServiceGetResultSend::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Config as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(ConfigServiceGetResultSend::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "ConfigServiceGetResultSend",
+                }) + match self {
+                    ConfigServiceGetResultSend::Ok(ref value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl Config {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(12);
+                map.insert(
+                    "version".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.version),
+                );
+                if let ::std::option::Option::Some(value) = &self.description {
+                    map.insert(
+                        "description".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                map.insert(
+                    "level".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.level),
+                );
+                map.insert(
+                    "timeout".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.timeout),
+                );
+                map.insert(
+                    "default_limit".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.default_limit),
+                );
+                if let ::std::option::Option::Some(value) = &self.limits {
+                    map.insert(
+                        "limits".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                map.insert(
+                    "weights".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.weights),
+                );
+                if let ::std::option::Option::Some(value) = &self.ports {
+                    map.insert(
+                        "ports".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                map.insert(
+                    "token".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.token),
+                );
+                if let ::std::option::Option::Some(value) = &self.backend {
+                    map.insert(
+                        "backend".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                map.insert(
+                    "enabled".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.enabled),
+                );
+                if let ::std::option::Option::Some(value) = &self.levels {
+                    map.insert(
+                        "levels".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    version: ::pilota::field_map::required(&mut map, "version")?,
+                    description: ::pilota::field_map::optional(&mut map, "description")?,
+                    level: ::pilota::field_map::required(&mut map, "level")?,
+                    timeout: ::pilota::field_map::required(&mut map, "timeout")?,
+                    default_limit: ::pilota::field_map::required(&mut map, "default_limit")?,
+                    limits: ::pilota::field_map::optional(&mut map, "limits")?,
+                    weights: ::pilota::field_map::required(&mut map, "weights")?,
+                    ports: ::pilota::field_map::optional(&mut map, "ports")?,
+                    token: ::pilota::field_map::required(&mut map, "token")?,
+                    backend: ::pilota::field_map::optional(&mut map, "backend")?,
+                    enabled: ::pilota::field_map::required(&mut map, "enabled")?,
+                    levels: ::pilota::field_map::optional(&mut map, "levels")?,
+                })
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for Config {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for Config {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct Config {
+            pub version: i64,
+
+            pub description: ::std::option::Option<::pilota::FastStr>,
+
+            pub level: Level,
+
+            pub timeout: Millis,
+
+            pub default_limit: Limit,
+
+            pub limits: ::std::option::Option<::std::vec::Vec<Limit>>,
+
+            pub weights: ::pilota::AHashMap<::pilota::FastStr, f64>,
+
+            pub ports: ::std::option::Option<::pilota::AHashSet<i32>>,
+
+            pub token: ::pilota::Bytes,
+
+            pub backend: ::std::option::Option<Backend>,
+
+            pub enabled: bool,
+
+            pub levels: ::std::option::Option<::std::vec::Vec<Level>>,
+        }
+        impl ::pilota::thrift::Message for Config {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Config" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i64_field(1, *&self.version)?;
+                if let Some(value) = self.description.as_ref() {
+                    __protocol.write_faststr_field(2, (value).clone())?;
+                }
+                __protocol.write_i32_field(3, (&self.level).inner())?;
+                __protocol.write_struct_field(4, &self.timeout, ::pilota::thrift::TType::I64)?;
+                __protocol.write_struct_field(
+                    5,
+                    &self.default_limit,
+                    ::pilota::thrift::TType::Struct,
+                )?;
+                if let Some(value) = self.limits.as_ref() {
+                    __protocol.write_list_field(
+                        6,
+                        ::pilota::thrift::TType::Struct,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_struct(val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                __protocol.write_map_field(
+                    7,
+                    ::pilota::thrift::TType::Binary,
+                    ::pilota::thrift::TType::Double,
+                    &&self.weights,
+                    |__protocol, key| {
+                        __protocol.write_faststr((key).clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                    |__protocol, val| {
+                        __protocol.write_double(*val)?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                if let Some(value) = self.ports.as_ref() {
+                    __protocol.write_set_field(
+                        8,
+                        ::pilota::thrift::TType::I32,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_i32(*val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                __protocol.write_bytes_field(9, (&self.token).clone())?;
+                if let Some(value) = self.backend.as_ref() {
+                    __protocol.write_struct_field(10, value, ::pilota::thrift::TType::Struct)?;
+                }
+                __protocol.write_bool_field(11, *&self.enabled)?;
+                if let Some(value) = self.levels.as_ref() {
+                    __protocol.write_list_field(
+                        12,
+                        ::pilota::thrift::TType::I32,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_struct(val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut var_6 = None;
+                let mut var_7 = None;
+                let mut var_8 = None;
+                let mut var_9 = None;
+                let mut var_10 = None;
+                let mut var_11 = None;
+                let mut var_12 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_1 = Some(__protocol.read_i64()?);
+                            }
+                            Some(2)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_2 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_3 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(4) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_4 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(5)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_5 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(6) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_6 = Some(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<Limit> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(7) if field_ident.field_type == ::pilota::thrift::TType::Map => {
+                                var_7 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_double()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
+                            Some(8) if field_ident.field_type == ::pilota::thrift::TType::Set => {
+                                var_8 = Some({
+                                    let list_ident = __protocol.read_set_begin()?;
+                                    let mut val =
+                                        ::pilota::AHashSet::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    __protocol.read_set_end()?;
+                                    val
+                                });
+                            }
+                            Some(9)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_9 = Some(__protocol.read_bytes()?);
+                            }
+                            Some(10)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_10 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(11) if field_ident.field_type == ::pilota::thrift::TType::Bool => {
+                                var_11 = Some(__protocol.read_bool()?);
+                            }
+                            Some(12) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_12 = Some(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<Level> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Config",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("version"),
+                                2 => ::std::option::Option::Some("description"),
+                                3 => ::std::option::Option::Some("level"),
+                                4 => ::std::option::Option::Some("timeout"),
+                                5 => ::std::option::Option::Some("default_limit"),
+                                6 => ::std::option::Option::Some("limits"),
+                                7 => ::std::option::Option::Some("weights"),
+                                8 => ::std::option::Option::Some("ports"),
+                                9 => ::std::option::Option::Some("token"),
+                                10 => ::std::option::Option::Some("backend"),
+                                11 => ::std::option::Option::Some("enabled"),
+                                12 => ::std::option::Option::Some("levels"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field version is required".to_string(),
+                    ));
+                };
+                let Some(var_3) = var_3 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field level is required".to_string(),
+                    ));
+                };
+                let Some(var_4) = var_4 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field timeout is required".to_string(),
+                    ));
+                };
+                let Some(var_5) = var_5 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field default_limit is required".to_string(),
+                    ));
+                };
+                let Some(var_7) = var_7 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field weights is required".to_string(),
+                    ));
+                };
+                let Some(var_9) = var_9 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field token is required".to_string(),
+                    ));
+                };
+                let Some(var_11) = var_11 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field enabled is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    version: var_1,
+                    description: var_2,
+                    level: var_3,
+                    timeout: var_4,
+                    default_limit: var_5,
+                    limits: var_6,
+                    weights: var_7,
+                    ports: var_8,
+                    token: var_9,
+                    backend: var_10,
+                    enabled: var_11,
+                    levels: var_12,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut var_6 = None;
+                    let mut var_7 = None;
+                    let mut var_8 = None;
+                    let mut var_9 = None;
+                    let mut var_10 = None;
+                    let mut var_11 = None;
+                    let mut var_12 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                    loop {
+
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+                    var_1 = Some(__protocol.read_i64().await?);
+
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_2 = Some(__protocol.read_faststr().await?);
+
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::I32  => {
+                    var_3 = Some(<Level as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(4) if field_ident.field_type == ::pilota::thrift::TType::I64  => {
+                    var_4 = Some(<Millis as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(5) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_5 = Some(<Limit as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(6) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_6 = Some({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(<Limit as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+
+                },Some(7) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+                    var_7 = Some({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_faststr().await?, __protocol.read_double().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
+
+                },Some(8) if field_ident.field_type == ::pilota::thrift::TType::Set  => {
+                    var_8 = Some({let list_ident = __protocol.read_set_begin().await?;
+                    let mut val = ::pilota::AHashSet::with_capacity(list_ident.size);
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                    __protocol.read_set_end().await?;
+                    val});
+
+                },Some(9) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_9 = Some(__protocol.read_bytes().await?);
+
+                },Some(10) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_10 = Some(<Backend as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(11) if field_ident.field_type == ::pilota::thrift::TType::Bool  => {
+                    var_11 = Some(__protocol.read_bool().await?);
+
+                },Some(12) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_12 = Some({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(<Level as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+
+                },
+                    _ => {
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Config",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("version"),2 => ::std::option::Option::Some("description"),3 => ::std::option::Option::Some("level"),4 => ::std::option::Option::Some("timeout"),5 => ::std::option::Option::Some("default_limit"),6 => ::std::option::Option::Some("limits"),7 => ::std::option::Option::Some("weights"),8 => ::std::option::Option::Some("ports"),9 => ::std::option::Option::Some("token"),10 => ::std::option::Option::Some("backend"),11 => ::std::option::Option::Some("enabled"),12 => ::std::option::Option::Some("levels"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field version is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field level is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_4) = var_4 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field timeout is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_5) = var_5 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field default_limit is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_7) = var_7 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field weights is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_9) = var_9 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field token is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_11) = var_11 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field enabled is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        version: var_1,
+                        description: var_2,
+                        level: var_3,
+                        timeout: var_4,
+                        default_limit: var_5,
+                        limits: var_6,
+                        weights: var_7,
+                        ports: var_8,
+                        token: var_9,
+                        backend: var_10,
+                        enabled: var_11,
+                        levels: var_12,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Config" })
+                    + __protocol.i64_field_len(Some(1), *&self.version)
+                    + self
+                        .description
+                        .as_ref()
+                        .map_or(0, |value| __protocol.faststr_field_len(Some(2), value))
+                    + __protocol.i32_field_len(Some(3), (&self.level).inner())
+                    + __protocol.struct_field_len(Some(4), &self.timeout)
+                    + __protocol.struct_field_len(Some(5), &self.default_limit)
+                    + self.limits.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(6),
+                            ::pilota::thrift::TType::Struct,
+                            value,
+                            |__protocol, el| __protocol.struct_len(el),
+                        )
+                    })
+                    + __protocol.map_field_len(
+                        Some(7),
+                        ::pilota::thrift::TType::Binary,
+                        ::pilota::thrift::TType::Double,
+                        &self.weights,
+                        |__protocol, key| __protocol.faststr_len(key),
+                        |__protocol, val| __protocol.double_len(*val),
+                    )
+                    + self.ports.as_ref().map_or(0, |value| {
+                        __protocol.set_field_len(
+                            Some(8),
+                            ::pilota::thrift::TType::I32,
+                            value,
+                            |__protocol, el| __protocol.i32_len(*el),
+                        )
+                    })
+                    + __protocol.bytes_field_len(Some(9), &self.token)
+                    + self
+                        .backend
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(10), value))
+                    + __protocol.bool_field_len(Some(11), *&self.enabled)
+                    + self.levels.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(12),
+                            ::pilota::thrift::TType::I32,
+                            value,
+                            |__protocol, el| __protocol.struct_len(el),
+                        )
+                    })
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Backend {
+            fn default() -> Self {
+                Backend::Url(::std::default::Default::default())
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for Backend {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                match self {
+                    Self::Url(value) => ::pilota::field_map::Value::Message(
+                        ::std::iter::once((
+                            "url".to_string(),
+                            ::pilota::field_map::ToValue::to_value(value),
+                        ))
+                        .collect(),
+                    ),
+                    Self::Limit(value) => ::pilota::field_map::Value::Message(
+                        ::std::iter::once((
+                            "limit".to_string(),
+                            ::pilota::field_map::ToValue::to_value(value),
+                        ))
+                        .collect(),
+                    ),
+                }
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for Backend {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                let map = match value {
+                    ::pilota::field_map::Value::Message(map) => map,
+                    value => {
+                        return ::std::result::Result::Err(
+                            ::pilota::field_map::FieldMapError::invalid("a message", value),
+                        )
+                    }
+                };
+
+                if map.len() != 1 {
+                    return ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid(
+                            "one variant of `Backend`",
+                            ::pilota::field_map::Value::Message(map),
+                        ),
+                    );
+                }
+                let (variant, value) = map.into_iter().next().unwrap();
+                match variant.as_str() {
+                    "url" => ::std::result::Result::Ok(Self::Url(::pilota::field_map::from_field(
+                        "url", value,
+                    )?)),
+                    "limit" => ::std::result::Result::Ok(Self::Limit(
+                        ::pilota::field_map::from_field("limit", value)?,
+                    )),
+                    _ => ::std::result::Result::Err(::pilota::field_map::FieldMapError::invalid(
+                        "a variant of `Backend`",
+                        ::pilota::field_map::Value::Message(
+                            ::std::iter::once((variant, value)).collect(),
+                        ),
+                    )),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum Backend {
+            Url(::pilota::FastStr),
+
+            Limit(Limit),
+        }
+
+        impl ::pilota::thrift::Message for Backend {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(&::pilota::thrift::TStructIdentifier { name: "Backend" })?;
+                match self {
+                    Backend::Url(ref value) => {
+                        __protocol.write_faststr_field(1, (value).clone())?;
+                    }
+                    Backend::Limit(ref value) => {
+                        __protocol.write_struct_field(2, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_faststr()?;
+                                __protocol.faststr_len(&field_ident);
+                                ret = Some(Backend::Url(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(2) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(Backend::Limit(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_faststr().await?;
+
+                                    ret = Some(Backend::Url(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            Some(2) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Limit as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(Backend::Limit(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Backend" })
+                    + match self {
+                        Backend::Url(ref value) => __protocol.faststr_field_len(Some(1), value),
+                        Backend::Limit(ref value) => __protocol.struct_field_len(Some(2), value),
+                    }
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        pub trait ConfigService {}
+
+        impl ::std::default::Default for ConfigServiceGetResultRecv {
+            fn default() -> Self {
+                ConfigServiceGetResultRecv::Ok(::std::default::Default::default())
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for ConfigServiceGetResultRecv {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                match self {
+                    Self::Ok(value) => ::pilota::field_map::Value::Message(
+                        ::std::iter::once((
+                            "Ok".to_string(),
+                            ::pilota::field_map::ToValue::to_value(value),
+                        ))
+                        .collect(),
+                    ),
+                }
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for ConfigServiceGetResultRecv {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                let map = match value {
+                    ::pilota::field_map::Value::Message(map) => map,
+                    value => {
+                        return ::std::result::Result::Err(
+                            ::pilota::field_map::FieldMapError::invalid("a message", value),
+                        )
+                    }
+                };
+
+                if map.len() != 1 {
+                    return ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid(
+                            "one variant of `ConfigServiceGetResultRecv`",
+                            ::pilota::field_map::Value::Message(map),
+                        ),
+                    );
+                }
+                let (variant, value) = map.into_iter().next().unwrap();
+                match variant.as_str() {
+                    "Ok" => ::std::result::Result::Ok(Self::Ok(::pilota::field_map::from_field(
+                        "Ok", value,
+                    )?)),
+                    _ => ::std::result::Result::Err(::pilota::field_map::FieldMapError::invalid(
+                        "a variant of `ConfigServiceGetResultRecv`",
+                        ::pilota::field_map::Value::Message(
+                            ::std::iter::once((variant, value)).collect(),
+                        ),
+                    )),
+                }
+            }
+        }
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum ConfigServiceGetResultRecv {
+            Ok(Config),
+        }
+
+        impl ::pilota::thrift::Message for ConfigServiceGetResultRecv {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_struct_begin(&::pilota::thrift::TStructIdentifier {
+                    name: "ConfigServiceGetResultRecv",
+                })?;
+                match self {
+                    ConfigServiceGetResultRecv::Ok(ref value) => {
+                        __protocol.write_struct_field(0, value, ::pilota::thrift::TType::Struct)?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(0) => {
+                            if ret.is_none() {
+                                let field_ident = ::pilota::thrift::Message::decode(__protocol)?;
+                                __protocol.struct_len(&field_ident);
+                                ret = Some(ConfigServiceGetResultRecv::Ok(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(0) => {
+                                if ret.is_none() {
+                                    let field_ident =
+                                        <Config as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?;
+
+                                    ret = Some(ConfigServiceGetResultRecv::Ok(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier {
+                    name: "ConfigServiceGetResultRecv",
+                }) + match self {
+                    ConfigServiceGetResultRecv::Ok(ref value) => {
+                        __protocol.struct_field_len(Some(0), value)
+                    }
+                } + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::pilota::field_map::ToValue for Millis {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::ToValue::to_value(&self.0)
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for Millis {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::pilota::field_map::FromValue::from_value(value).map(Self)
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Millis(pub i64);
+
+        impl ::std::ops::Deref for Millis {
+            type Target = i64;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl From<i64> for Millis {
+            fn from(v: i64) -> Self {
+                Self(v)
+            }
+        }
+
+        impl ::pilota::thrift::Message for Millis {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_i64(*(&**self))?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                ::std::result::Result::Ok(Millis(__protocol.read_i64()?))
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    ::std::result::Result::Ok(Millis(__protocol.read_i64().await?))
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.i64_len(*&**self)
+            }
+        }
+    }
+}
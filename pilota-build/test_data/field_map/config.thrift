@@ -0,0 +1,37 @@
+namespace rs config
+
+enum Level {
+    LOW = 1,
+    HIGH = 2,
+}
+
+typedef i64 Millis
+
+struct Limit {
+    1: required string name,
+    2: optional i32 max,
+}
+
+union Backend {
+    1: string url,
+    2: Limit limit,
+}
+
+struct Config {
+    1: required i64 version,
+    2: optional string description,
+    3: required Level level,
+    4: required Millis timeout,
+    5: required Limit default_limit,
+    6: optional list<Limit> limits,
+    7: required map<string, double> weights,
+    8: optional set<i32> ports,
+    9: required binary token,
+    10: optional Backend backend,
+    11: required bool enabled,
+    12: optional list<Level> levels,
+}
+
+service ConfigService {
+    Config get(1: string name),
+}
@@ -0,0 +1,7 @@
+namespace rs member
+
+struct Member {
+    3: required i64 userId,
+    7: optional string type,
+    12: optional list<string> roles,
+}
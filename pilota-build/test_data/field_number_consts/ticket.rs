@@ -0,0 +1,205 @@
+pub mod ticket {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod ticket {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Ticket {
+            pub title: ::pilota::FastStr,
+
+            pub owner_id: i64,
+
+            pub labels: ::std::vec::Vec<::pilota::FastStr>,
+
+            pub assignee: ::std::option::Option<ticket::Assignee>,
+        }
+        impl Ticket {
+            pub const TITLE_FIELD_NUMBER: u32 = 1;
+            pub const OWNER_ID_FIELD_NUMBER: u32 = 19000;
+            pub const LABELS_FIELD_NUMBER: u32 = 536870911;
+            pub const USER_ID_FIELD_NUMBER: u32 = 4;
+            pub const TEAM_FIELD_NUMBER: u32 = 5;
+        }
+
+        impl ::pilota::prost::Message for Ticket {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::faststr::encoded_len(1, &self.title)
+                    + ::pilota::prost::encoding::int64::encoded_len(19000, &self.owner_id)
+                    + ::pilota::prost::encoding::faststr::encoded_len_repeated(
+                        536870911,
+                        &self.labels,
+                    )
+                    + self.assignee.as_ref().map_or(0, |msg| msg.encoded_len())
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::faststr::encode(1, &self.title, buf);
+                ::pilota::prost::encoding::int64::encode(19000, &self.owner_id, buf);
+                ::pilota::prost::encoding::faststr::encode_repeated(536870911, &self.labels, buf);
+                if let Some(_pilota_inner_value) = self.assignee.as_ref() {
+                    _pilota_inner_value.encode(buf);
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Ticket);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.title;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(title));
+                            error
+                        })
+                    }
+                    19000 => {
+                        let mut _inner_pilota_value = &mut self.owner_id;
+                        ::pilota::prost::encoding::int64::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(owner_id));
+                            error
+                        })
+                    }
+                    536870911 => {
+                        let mut _inner_pilota_value = &mut self.labels;
+                        ::pilota::prost::encoding::faststr::merge_repeated(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(labels));
+                            error
+                        })
+                    }
+                    4 | 5 => {
+                        let mut _inner_pilota_value = &mut self.assignee;
+                        ticket::Assignee::merge(&mut _inner_pilota_value, tag, wire_type, buf, ctx)
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(assignee));
+                                error
+                            })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+
+        pub mod ticket {
+
+            impl ::std::default::Default for Assignee {
+                fn default() -> Self {
+                    Assignee::UserId(::std::default::Default::default())
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+            pub enum Assignee {
+                UserId(i64),
+
+                Team(::pilota::FastStr),
+            }
+            impl Assignee {
+                pub fn encode<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    match self {
+                        Assignee::UserId(value) => {
+                            ::pilota::prost::encoding::int64::encode(4, &*value, buf);
+                        }
+                        Assignee::Team(value) => {
+                            ::pilota::prost::encoding::faststr::encode(5, &*value, buf);
+                        }
+                    }
+                }
+
+                #[inline]
+                pub fn encoded_len(&self) -> usize {
+                    match self {
+                        Assignee::UserId(value) => {
+                            ::pilota::prost::encoding::int64::encoded_len(4, &*value)
+                        }
+                        Assignee::Team(value) => {
+                            ::pilota::prost::encoding::faststr::encoded_len(5, &*value)
+                        }
+                    }
+                }
+
+                #[inline]
+                pub fn merge<B>(
+                    field: &mut ::core::option::Option<Self>,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    match tag {
+                        4 => match field {
+                            ::core::option::Option::Some(Assignee::UserId(ref mut value)) => {
+                                ::pilota::prost::encoding::int64::merge(
+                                    wire_type, value, buf, ctx,
+                                )?;
+                            }
+                            _ => {
+                                let mut owned_value = ::core::default::Default::default();
+                                let value = &mut owned_value;
+                                ::pilota::prost::encoding::int64::merge(
+                                    wire_type, value, buf, ctx,
+                                )?;
+                                *field =
+                                    ::core::option::Option::Some(Assignee::UserId(owned_value));
+                            }
+                        },
+                        5 => match field {
+                            ::core::option::Option::Some(Assignee::Team(ref mut value)) => {
+                                ::pilota::prost::encoding::faststr::merge(
+                                    wire_type, value, buf, ctx,
+                                )?;
+                            }
+                            _ => {
+                                let mut owned_value = ::core::default::Default::default();
+                                let value = &mut owned_value;
+                                ::pilota::prost::encoding::faststr::merge(
+                                    wire_type, value, buf, ctx,
+                                )?;
+                                *field = ::core::option::Option::Some(Assignee::Team(owned_value));
+                            }
+                        },
+                        _ => {
+                            unreachable!(concat!("invalid ", stringify!(Assignee), " tag: {}"), tag)
+                        }
+                    };
+                    ::core::result::Result::Ok(())
+                }
+            }
+        }
+    }
+}
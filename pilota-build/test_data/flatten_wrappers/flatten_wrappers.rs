@@ -0,0 +1,1215 @@
+pub mod flatten_wrappers {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod flatten_wrappers {
+
+        impl ::pilota::schema::Schema for Pair {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "Pair",
+                    fields: &[
+                        ::pilota::schema::FieldSchema {
+                            name: "key",
+                            id: 1,
+                            ty: ::pilota::schema::TypeSchema::String,
+                            required: true,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "value",
+                            id: 2,
+                            ty: ::pilota::schema::TypeSchema::String,
+                            required: true,
+                        },
+                    ],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl ::std::convert::From<Pair> for crate::test::tests::flatten_wrappers_prost_stub::Pair {
+            fn from(value: Pair) -> Self {
+                Self {
+                    key: value.key.into(),
+                    value: value.value.into(),
+                }
+            }
+        }
+
+        impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::Pair> for Pair {
+            fn from(value: crate::test::tests::flatten_wrappers_prost_stub::Pair) -> Self {
+                Self {
+                    key: value.key.into(),
+                    value: value.value.into(),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Pair {
+            pub key: ::pilota::FastStr,
+
+            pub value: ::pilota::FastStr,
+        }
+        impl ::pilota::prost::Message for Pair {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::faststr::encoded_len(1, &self.key)
+                    + ::pilota::prost::encoding::faststr::encoded_len(2, &self.value)
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::faststr::encode(1, &self.key, buf);
+                ::pilota::prost::encoding::faststr::encode(2, &self.value, buf);
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Pair);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.key;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(key));
+                            error
+                        })
+                    }
+                    2 => {
+                        let mut _inner_pilota_value = &mut self.value;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(value));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+
+        impl ::pilota::schema::Schema for User {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                        name: "User",
+                        fields: &[::pilota::schema::FieldSchema {
+                name: "nickname",
+                id: 1,
+                ty: ::pilota::schema::TypeSchema::String,
+                required: false,
+            },
+::pilota::schema::FieldSchema {
+                name: "age",
+                id: 2,
+                ty: ::pilota::schema::TypeSchema::I64,
+                required: false,
+            },
+::pilota::schema::FieldSchema {
+                name: "name",
+                id: 3,
+                ty: ::pilota::schema::TypeSchema::String,
+                required: false,
+            },
+::pilota::schema::FieldSchema {
+                name: "aliases",
+                id: 4,
+                ty: ::pilota::schema::TypeSchema::List(&::pilota::schema::TypeSchema::Message(<super::google::protobuf::StringValue as ::pilota::schema::Schema>::schema)),
+                required: true,
+            },
+::pilota::schema::FieldSchema {
+                name: "pair",
+                id: 5,
+                ty: ::pilota::schema::TypeSchema::Message(<Pair as ::pilota::schema::Schema>::schema),
+                required: false,
+            },],
+                    };
+                &SCHEMA
+            }
+        }
+
+        impl ::std::convert::From<User> for crate::test::tests::flatten_wrappers_prost_stub::User {
+            fn from(value: User) -> Self {
+                Self {
+                    nickname: value.nickname.map(|v| {
+                        crate::test::tests::flatten_wrappers_prost_stub::StringValue {
+                            value: v.into(),
+                        }
+                    }),
+                    age: value.age.map(|v| {
+                        crate::test::tests::flatten_wrappers_prost_stub::Int64Value {
+                            value: v.into(),
+                        }
+                    }),
+                    name: value.name.map(|v| {
+                        crate::test::tests::flatten_wrappers_prost_stub::Name { value: v.into() }
+                    }),
+                    aliases: value
+                        .aliases
+                        .into_iter()
+                        .map(::std::convert::Into::into)
+                        .collect(),
+                    pair: value.pair.map(|v| v.into()),
+                }
+            }
+        }
+
+        impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::User> for User {
+            fn from(value: crate::test::tests::flatten_wrappers_prost_stub::User) -> Self {
+                Self {
+                    nickname: value.nickname.map(|v| v.value.into()),
+                    age: value.age.map(|v| v.value.into()),
+                    name: value.name.map(|v| v.value.into()),
+                    aliases: value
+                        .aliases
+                        .into_iter()
+                        .map(::std::convert::Into::into)
+                        .collect(),
+                    pair: value.pair.map(|v| v.into()),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct User {
+            pub nickname: ::std::option::Option<::pilota::FastStr>,
+
+            pub age: ::std::option::Option<i64>,
+
+            pub name: ::std::option::Option<::pilota::FastStr>,
+
+            pub aliases: ::std::vec::Vec<super::google::protobuf::StringValue>,
+
+            pub pair: ::std::option::Option<Pair>,
+        }
+        impl ::pilota::prost::Message for User {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + self.nickname.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::wrapper::encoded_len(1, value, |value| {
+                        ::pilota::prost::encoding::faststr::encoded_len(1, value)
+                    })
+                }) + self.age.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::wrapper::encoded_len(2, value, |value| {
+                        ::pilota::prost::encoding::int64::encoded_len(1, value)
+                    })
+                }) + self.name.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::wrapper::encoded_len(3, value, |value| {
+                        ::pilota::prost::encoding::faststr::encoded_len(1, value)
+                    })
+                }) + ::pilota::prost::encoding::message::encoded_len_repeated(4, &self.aliases)
+                    + self.pair.as_ref().map_or(0, |msg| {
+                        ::pilota::prost::encoding::message::encoded_len(5, msg)
+                    })
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                if let Some(value) = self.nickname.as_ref() {
+                    ::pilota::prost::encoding::wrapper::encode(
+                        1,
+                        value,
+                        buf,
+                        |value, buf| ::pilota::prost::encoding::faststr::encode(1, value, buf),
+                        |value| ::pilota::prost::encoding::faststr::encoded_len(1, value),
+                    );
+                }
+                if let Some(value) = self.age.as_ref() {
+                    ::pilota::prost::encoding::wrapper::encode(
+                        2,
+                        value,
+                        buf,
+                        |value, buf| ::pilota::prost::encoding::int64::encode(1, value, buf),
+                        |value| ::pilota::prost::encoding::int64::encoded_len(1, value),
+                    );
+                }
+                if let Some(value) = self.name.as_ref() {
+                    ::pilota::prost::encoding::wrapper::encode(
+                        3,
+                        value,
+                        buf,
+                        |value, buf| ::pilota::prost::encoding::faststr::encode(1, value, buf),
+                        |value| ::pilota::prost::encoding::faststr::encoded_len(1, value),
+                    );
+                }
+                for msg in &self.aliases {
+                    ::pilota::prost::encoding::message::encode(4, msg, buf);
+                }
+                if let Some(_pilota_inner_value) = self.pair.as_ref() {
+                    ::pilota::prost::encoding::message::encode(5, _pilota_inner_value, buf);
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(User);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.nickname;
+                        ::pilota::prost::encoding::wrapper::merge(
+                            1,
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                            ::pilota::prost::encoding::faststr::merge,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(nickname));
+                            error
+                        })
+                    }
+                    2 => {
+                        let mut _inner_pilota_value = &mut self.age;
+                        ::pilota::prost::encoding::wrapper::merge(
+                            1,
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                            ::pilota::prost::encoding::int64::merge,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(age));
+                            error
+                        })
+                    }
+                    3 => {
+                        let mut _inner_pilota_value = &mut self.name;
+                        ::pilota::prost::encoding::wrapper::merge(
+                            1,
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                            ::pilota::prost::encoding::faststr::merge,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(name));
+                            error
+                        })
+                    }
+                    4 => {
+                        let mut _inner_pilota_value = &mut self.aliases;
+                        ::pilota::prost::encoding::message::merge_repeated(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(aliases));
+                            error
+                        })
+                    }
+                    5 => {
+                        let mut _inner_pilota_value = &mut self.pair;
+                        ::pilota::prost::encoding::message::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(pair));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+
+        impl ::pilota::schema::Schema for Name {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "Name",
+                    fields: &[::pilota::schema::FieldSchema {
+                        name: "value",
+                        id: 1,
+                        ty: ::pilota::schema::TypeSchema::String,
+                        required: true,
+                    }],
+                };
+                &SCHEMA
+            }
+        }
+
+        impl ::std::convert::From<Name> for crate::test::tests::flatten_wrappers_prost_stub::Name {
+            fn from(value: Name) -> Self {
+                Self {
+                    value: value.value.into(),
+                }
+            }
+        }
+
+        impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::Name> for Name {
+            fn from(value: crate::test::tests::flatten_wrappers_prost_stub::Name) -> Self {
+                Self {
+                    value: value.value.into(),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Name {
+            pub value: ::pilota::FastStr,
+        }
+        impl ::pilota::prost::Message for Name {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::faststr::encoded_len(1, &self.value)
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::faststr::encode(1, &self.value, buf);
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Name);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.value;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(value));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+    }
+
+    pub mod google {
+
+        pub mod protobuf {
+
+            impl ::pilota::schema::Schema for DoubleValue {
+                fn schema() -> &'static ::pilota::schema::MessageSchema {
+                    static SCHEMA: ::pilota::schema::MessageSchema =
+                        ::pilota::schema::MessageSchema {
+                            name: "DoubleValue",
+                            fields: &[::pilota::schema::FieldSchema {
+                                name: "value",
+                                id: 1,
+                                ty: ::pilota::schema::TypeSchema::F64,
+                                required: true,
+                            }],
+                        };
+                    &SCHEMA
+                }
+            }
+
+            impl ::std::convert::From<DoubleValue>
+                for crate::test::tests::flatten_wrappers_prost_stub::DoubleValue
+            {
+                fn from(value: DoubleValue) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::DoubleValue>
+                for DoubleValue
+            {
+                fn from(
+                    value: crate::test::tests::flatten_wrappers_prost_stub::DoubleValue,
+                ) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Debug, Default, Clone, PartialEq)]
+            pub struct DoubleValue {
+                pub value: f64,
+            }
+            impl ::pilota::prost::Message for DoubleValue {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + ::pilota::prost::encoding::double::encoded_len(1, &self.value)
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    ::pilota::prost::encoding::double::encode(1, &self.value, buf);
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(DoubleValue);
+                    match tag {
+                        1 => {
+                            let mut _inner_pilota_value = &mut self.value;
+                            ::pilota::prost::encoding::double::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(value));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+
+            impl ::pilota::schema::Schema for StringValue {
+                fn schema() -> &'static ::pilota::schema::MessageSchema {
+                    static SCHEMA: ::pilota::schema::MessageSchema =
+                        ::pilota::schema::MessageSchema {
+                            name: "StringValue",
+                            fields: &[::pilota::schema::FieldSchema {
+                                name: "value",
+                                id: 1,
+                                ty: ::pilota::schema::TypeSchema::String,
+                                required: true,
+                            }],
+                        };
+                    &SCHEMA
+                }
+            }
+
+            impl ::std::convert::From<StringValue>
+                for crate::test::tests::flatten_wrappers_prost_stub::StringValue
+            {
+                fn from(value: StringValue) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::StringValue>
+                for StringValue
+            {
+                fn from(
+                    value: crate::test::tests::flatten_wrappers_prost_stub::StringValue,
+                ) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct StringValue {
+                pub value: ::pilota::FastStr,
+            }
+            impl ::pilota::prost::Message for StringValue {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + ::pilota::prost::encoding::faststr::encoded_len(1, &self.value)
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    ::pilota::prost::encoding::faststr::encode(1, &self.value, buf);
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(StringValue);
+                    match tag {
+                        1 => {
+                            let mut _inner_pilota_value = &mut self.value;
+                            ::pilota::prost::encoding::faststr::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(value));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+
+            impl ::pilota::schema::Schema for Int32Value {
+                fn schema() -> &'static ::pilota::schema::MessageSchema {
+                    static SCHEMA: ::pilota::schema::MessageSchema =
+                        ::pilota::schema::MessageSchema {
+                            name: "Int32Value",
+                            fields: &[::pilota::schema::FieldSchema {
+                                name: "value",
+                                id: 1,
+                                ty: ::pilota::schema::TypeSchema::I32,
+                                required: true,
+                            }],
+                        };
+                    &SCHEMA
+                }
+            }
+
+            impl ::std::convert::From<Int32Value>
+                for crate::test::tests::flatten_wrappers_prost_stub::Int32Value
+            {
+                fn from(value: Int32Value) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::Int32Value>
+                for Int32Value
+            {
+                fn from(
+                    value: crate::test::tests::flatten_wrappers_prost_stub::Int32Value,
+                ) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct Int32Value {
+                pub value: i32,
+            }
+            impl ::pilota::prost::Message for Int32Value {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + ::pilota::prost::encoding::int32::encoded_len(1, &self.value)
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    ::pilota::prost::encoding::int32::encode(1, &self.value, buf);
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(Int32Value);
+                    match tag {
+                        1 => {
+                            let mut _inner_pilota_value = &mut self.value;
+                            ::pilota::prost::encoding::int32::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(value));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+
+            impl ::pilota::schema::Schema for FloatValue {
+                fn schema() -> &'static ::pilota::schema::MessageSchema {
+                    static SCHEMA: ::pilota::schema::MessageSchema =
+                        ::pilota::schema::MessageSchema {
+                            name: "FloatValue",
+                            fields: &[::pilota::schema::FieldSchema {
+                                name: "value",
+                                id: 1,
+                                ty: ::pilota::schema::TypeSchema::F32,
+                                required: true,
+                            }],
+                        };
+                    &SCHEMA
+                }
+            }
+
+            impl ::std::convert::From<FloatValue>
+                for crate::test::tests::flatten_wrappers_prost_stub::FloatValue
+            {
+                fn from(value: FloatValue) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::FloatValue>
+                for FloatValue
+            {
+                fn from(
+                    value: crate::test::tests::flatten_wrappers_prost_stub::FloatValue,
+                ) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Debug, Default, Clone, PartialEq)]
+            pub struct FloatValue {
+                pub value: f32,
+            }
+            impl ::pilota::prost::Message for FloatValue {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + ::pilota::prost::encoding::float::encoded_len(1, &self.value)
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    ::pilota::prost::encoding::float::encode(1, &self.value, buf);
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(FloatValue);
+                    match tag {
+                        1 => {
+                            let mut _inner_pilota_value = &mut self.value;
+                            ::pilota::prost::encoding::float::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(value));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+
+            impl ::pilota::schema::Schema for BytesValue {
+                fn schema() -> &'static ::pilota::schema::MessageSchema {
+                    static SCHEMA: ::pilota::schema::MessageSchema =
+                        ::pilota::schema::MessageSchema {
+                            name: "BytesValue",
+                            fields: &[::pilota::schema::FieldSchema {
+                                name: "value",
+                                id: 1,
+                                ty: ::pilota::schema::TypeSchema::Bytes,
+                                required: true,
+                            }],
+                        };
+                    &SCHEMA
+                }
+            }
+
+            impl ::std::convert::From<BytesValue>
+                for crate::test::tests::flatten_wrappers_prost_stub::BytesValue
+            {
+                fn from(value: BytesValue) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::BytesValue>
+                for BytesValue
+            {
+                fn from(
+                    value: crate::test::tests::flatten_wrappers_prost_stub::BytesValue,
+                ) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct BytesValue {
+                pub value: ::pilota::Bytes,
+            }
+            impl ::pilota::prost::Message for BytesValue {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + ::pilota::prost::encoding::bytes::encoded_len(1, &self.value)
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    ::pilota::prost::encoding::bytes::encode(1, &self.value, buf);
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(BytesValue);
+                    match tag {
+                        1 => {
+                            let mut _inner_pilota_value = &mut self.value;
+                            ::pilota::prost::encoding::bytes::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(value));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+
+            impl ::pilota::schema::Schema for UInt32Value {
+                fn schema() -> &'static ::pilota::schema::MessageSchema {
+                    static SCHEMA: ::pilota::schema::MessageSchema =
+                        ::pilota::schema::MessageSchema {
+                            name: "UInt32Value",
+                            fields: &[::pilota::schema::FieldSchema {
+                                name: "value",
+                                id: 1,
+                                ty: ::pilota::schema::TypeSchema::U32,
+                                required: true,
+                            }],
+                        };
+                    &SCHEMA
+                }
+            }
+
+            impl ::std::convert::From<UInt32Value>
+                for crate::test::tests::flatten_wrappers_prost_stub::UInt32Value
+            {
+                fn from(value: UInt32Value) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::UInt32Value>
+                for UInt32Value
+            {
+                fn from(
+                    value: crate::test::tests::flatten_wrappers_prost_stub::UInt32Value,
+                ) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct UInt32Value {
+                pub value: u32,
+            }
+            impl ::pilota::prost::Message for UInt32Value {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + ::pilota::prost::encoding::uint32::encoded_len(1, &self.value)
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    ::pilota::prost::encoding::uint32::encode(1, &self.value, buf);
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(UInt32Value);
+                    match tag {
+                        1 => {
+                            let mut _inner_pilota_value = &mut self.value;
+                            ::pilota::prost::encoding::uint32::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(value));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+
+            impl ::pilota::schema::Schema for Int64Value {
+                fn schema() -> &'static ::pilota::schema::MessageSchema {
+                    static SCHEMA: ::pilota::schema::MessageSchema =
+                        ::pilota::schema::MessageSchema {
+                            name: "Int64Value",
+                            fields: &[::pilota::schema::FieldSchema {
+                                name: "value",
+                                id: 1,
+                                ty: ::pilota::schema::TypeSchema::I64,
+                                required: true,
+                            }],
+                        };
+                    &SCHEMA
+                }
+            }
+
+            impl ::std::convert::From<Int64Value>
+                for crate::test::tests::flatten_wrappers_prost_stub::Int64Value
+            {
+                fn from(value: Int64Value) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::Int64Value>
+                for Int64Value
+            {
+                fn from(
+                    value: crate::test::tests::flatten_wrappers_prost_stub::Int64Value,
+                ) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct Int64Value {
+                pub value: i64,
+            }
+            impl ::pilota::prost::Message for Int64Value {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + ::pilota::prost::encoding::int64::encoded_len(1, &self.value)
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    ::pilota::prost::encoding::int64::encode(1, &self.value, buf);
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(Int64Value);
+                    match tag {
+                        1 => {
+                            let mut _inner_pilota_value = &mut self.value;
+                            ::pilota::prost::encoding::int64::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(value));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+
+            impl ::pilota::schema::Schema for BoolValue {
+                fn schema() -> &'static ::pilota::schema::MessageSchema {
+                    static SCHEMA: ::pilota::schema::MessageSchema =
+                        ::pilota::schema::MessageSchema {
+                            name: "BoolValue",
+                            fields: &[::pilota::schema::FieldSchema {
+                                name: "value",
+                                id: 1,
+                                ty: ::pilota::schema::TypeSchema::Bool,
+                                required: true,
+                            }],
+                        };
+                    &SCHEMA
+                }
+            }
+
+            impl ::std::convert::From<BoolValue>
+                for crate::test::tests::flatten_wrappers_prost_stub::BoolValue
+            {
+                fn from(value: BoolValue) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::BoolValue>
+                for BoolValue
+            {
+                fn from(value: crate::test::tests::flatten_wrappers_prost_stub::BoolValue) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct BoolValue {
+                pub value: bool,
+            }
+            impl ::pilota::prost::Message for BoolValue {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + ::pilota::prost::encoding::bool::encoded_len(1, &self.value)
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    ::pilota::prost::encoding::bool::encode(1, &self.value, buf);
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(BoolValue);
+                    match tag {
+                        1 => {
+                            let mut _inner_pilota_value = &mut self.value;
+                            ::pilota::prost::encoding::bool::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(value));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+
+            impl ::pilota::schema::Schema for UInt64Value {
+                fn schema() -> &'static ::pilota::schema::MessageSchema {
+                    static SCHEMA: ::pilota::schema::MessageSchema =
+                        ::pilota::schema::MessageSchema {
+                            name: "UInt64Value",
+                            fields: &[::pilota::schema::FieldSchema {
+                                name: "value",
+                                id: 1,
+                                ty: ::pilota::schema::TypeSchema::U64,
+                                required: true,
+                            }],
+                        };
+                    &SCHEMA
+                }
+            }
+
+            impl ::std::convert::From<UInt64Value>
+                for crate::test::tests::flatten_wrappers_prost_stub::UInt64Value
+            {
+                fn from(value: UInt64Value) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+
+            impl ::std::convert::From<crate::test::tests::flatten_wrappers_prost_stub::UInt64Value>
+                for UInt64Value
+            {
+                fn from(
+                    value: crate::test::tests::flatten_wrappers_prost_stub::UInt64Value,
+                ) -> Self {
+                    Self {
+                        value: value.value.into(),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct UInt64Value {
+                pub value: u64,
+            }
+            impl ::pilota::prost::Message for UInt64Value {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + ::pilota::prost::encoding::uint64::encoded_len(1, &self.value)
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    ::pilota::prost::encoding::uint64::encode(1, &self.value, buf);
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(UInt64Value);
+                    match tag {
+                        1 => {
+                            let mut _inner_pilota_value = &mut self.value;
+                            ::pilota::prost::encoding::uint64::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(value));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+struct Small {
+    1: required i32 a,
+}
+
+struct Large {
+    1: required i32 a,
+    2: required i32 b,
+    3: required i32 c,
+}
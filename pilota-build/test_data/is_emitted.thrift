@@ -0,0 +1,16 @@
+struct Used {
+    1: required i32 id,
+    2: required Money price,
+}
+
+struct Unused {
+    1: required i32 id,
+}
+
+struct Money {
+    1: required i64 cents,
+}
+
+service S {
+    Used get(1: i32 id),
+}
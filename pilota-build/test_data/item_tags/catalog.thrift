@@ -0,0 +1,11 @@
+namespace rs catalog
+
+struct Product {
+    1: required string sku,
+    2: optional i64 price,
+    3: optional list<Product> related,
+}
+
+service CatalogService {
+    Product get_product(1: string sku),
+}
@@ -0,0 +1,10 @@
+typedef list<string> Labels
+
+struct Tagged {
+    1: required list<i32> ids,
+    2: required map<string, i64> counts,
+    3: optional set<string> tags,
+    4: required Labels labels,
+    5: optional list<i32> scores,
+    6: required string name,
+}
@@ -0,0 +1,15 @@
+struct Node {
+    1: required string name,
+    2: optional Node next,
+    3: required list<Node> children,
+}
+
+union Expr {
+    1: i64 lit,
+    2: list<Expr> args,
+    3: Node node,
+}
+
+struct Leaf {
+    1: required i32 value,
+}
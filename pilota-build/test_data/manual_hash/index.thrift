@@ -0,0 +1,25 @@
+struct Key {
+    1: required string name,
+    2: required i32 version,
+}
+
+struct Index {
+    1: required map<string, i32> counts,
+    2: required set<i64> ids,
+    3: optional map<Key, list<string>> by_key,
+    4: optional string label,
+}
+
+struct Shard {
+    1: required i32 id,
+    2: required Index index,
+}
+
+union Entry {
+    1: set<string> tags,
+    2: i64 id,
+}
+
+struct Scores {
+    1: required map<string, double> scores,
+}
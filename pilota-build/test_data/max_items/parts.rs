@@ -0,0 +1,7 @@
+pub mod parts {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod parts {
+        include!("parts/mod.rs");
+    }
+}
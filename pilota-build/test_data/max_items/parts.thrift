@@ -0,0 +1,29 @@
+struct Order {
+    1: required i64 id,
+    2: required list<Line> lines,
+    3: optional Customer customer,
+}
+
+struct Line {
+    1: required Product product,
+    2: required i32 quantity,
+}
+
+struct Product {
+    1: required string name,
+    2: required Price price,
+}
+
+struct Price {
+    1: required i64 cents,
+    2: required Currency currency,
+}
+
+enum Currency {
+    EUR,
+    USD,
+}
+
+struct Customer {
+    1: required string name,
+}
@@ -0,0 +1,2 @@
+include!("part_1.rs");
+include!("part_2.rs");
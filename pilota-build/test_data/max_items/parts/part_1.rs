@@ -0,0 +1,703 @@
+#[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+#[repr(transparent)]
+pub struct Currency(i32);
+
+impl Currency {
+    pub const EUR: Self = Self(0);
+    pub const USD: Self = Self(1);
+
+    pub fn inner(&self) -> i32 {
+        self.0
+    }
+
+    pub fn to_string(&self) -> ::std::string::String {
+        match self {
+            Self(0) => ::std::string::String::from("EUR"),
+            Self(1) => ::std::string::String::from("USD"),
+            Self(val) => val.to_string(),
+        }
+    }
+}
+
+impl ::std::convert::From<i32> for Currency {
+    fn from(value: i32) -> Self {
+        Self(value)
+    }
+}
+
+impl ::std::convert::From<Currency> for i32 {
+    fn from(value: Currency) -> i32 {
+        value.0
+    }
+}
+
+impl ::pilota::thrift::Message for Currency {
+    fn encode<T: ::pilota::thrift::TOutputProtocol>(
+        &self,
+        __protocol: &mut T,
+    ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TOutputProtocolExt;
+        __protocol.write_i32(self.inner())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn decode<T: ::pilota::thrift::TInputProtocol>(
+        __protocol: &mut T,
+    ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::{thrift::TLengthProtocolExt, Buf};
+        let value = __protocol.read_i32()?;
+        ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(|err| {
+            ::pilota::thrift::new_protocol_exception(
+                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                format!("invalid enum value for Currency, value: {}", value),
+            )
+        })?)
+    }
+
+    fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+        __protocol: &'a mut T,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<
+            dyn ::std::future::Future<
+                    Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                > + Send
+                + 'a,
+        >,
+    > {
+        ::std::boxed::Box::pin(async move {
+            let value = __protocol.read_i32().await?;
+            ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(|err| {
+                ::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    format!("invalid enum value for Currency, value: {}", value),
+                )
+            })?)
+        })
+    }
+
+    fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TLengthProtocolExt;
+        __protocol.i32_len(self.inner())
+    }
+}
+#[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+pub struct Customer {
+    pub name: ::pilota::FastStr,
+}
+impl ::pilota::thrift::Message for Customer {
+    fn encode<T: ::pilota::thrift::TOutputProtocol>(
+        &self,
+        __protocol: &mut T,
+    ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TOutputProtocolExt;
+        let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Customer" };
+
+        __protocol.write_struct_begin(&struct_ident)?;
+        __protocol.write_faststr_field(1, (&self.name).clone())?;
+        __protocol.write_field_stop()?;
+        __protocol.write_struct_end()?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn decode<T: ::pilota::thrift::TInputProtocol>(
+        __protocol: &mut T,
+    ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+        let mut var_1 = None;
+
+        let mut __pilota_decoding_field_id = None;
+
+        __protocol.read_struct_begin()?;
+        if let ::std::result::Result::Err(mut err) = (|| {
+            loop {
+                let field_ident = __protocol.read_field_begin()?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                    __protocol.field_stop_len();
+                    break;
+                } else {
+                    __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary => {
+                        var_1 = Some(__protocol.read_faststr()?);
+                    }
+                    _ => {
+                        __protocol.skip(field_ident.field_type)?;
+                    }
+                }
+
+                __protocol.read_field_end()?;
+                __protocol.field_end_len();
+            }
+            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+        })() {
+            if let Some(field_id) = __pilota_decoding_field_id {
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "Customer",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("name"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
+            }
+            return ::std::result::Result::Err(err);
+        };
+        __protocol.read_struct_end()?;
+
+        let Some(var_1) = var_1 else {
+            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                "field name is required".to_string(),
+            ));
+        };
+
+        let data = Self { name: var_1 };
+        ::std::result::Result::Ok(data)
+    }
+
+    fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+        __protocol: &'a mut T,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<
+            dyn ::std::future::Future<
+                    Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                > + Send
+                + 'a,
+        >,
+    > {
+        ::std::boxed::Box::pin(async move {
+            let mut var_1 = None;
+
+            let mut __pilota_decoding_field_id = None;
+
+            __protocol.read_struct_begin().await?;
+            if let ::std::result::Result::Err(mut err) = async {
+                loop {
+                    let field_ident = __protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    } else {
+                    }
+                    __pilota_decoding_field_id = field_ident.id;
+                    match field_ident.id {
+                        Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary => {
+                            var_1 = Some(__protocol.read_faststr().await?);
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+
+                    __protocol.read_field_end().await?;
+                }
+                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+            }
+            .await
+            {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Customer",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("name"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+            __protocol.read_struct_end().await?;
+
+            let Some(var_1) = var_1 else {
+                return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    "field name is required".to_string(),
+                ));
+            };
+
+            let data = Self { name: var_1 };
+            ::std::result::Result::Ok(data)
+        })
+    }
+
+    fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TLengthProtocolExt;
+        __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Customer" })
+            + __protocol.faststr_field_len(Some(1), &self.name)
+            + __protocol.field_stop_len()
+            + __protocol.struct_end_len()
+    }
+}
+#[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+pub struct Line {
+    pub product: Product,
+
+    pub quantity: i32,
+}
+impl ::pilota::thrift::Message for Line {
+    fn encode<T: ::pilota::thrift::TOutputProtocol>(
+        &self,
+        __protocol: &mut T,
+    ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TOutputProtocolExt;
+        let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Line" };
+
+        __protocol.write_struct_begin(&struct_ident)?;
+        __protocol.write_struct_field(1, &self.product, ::pilota::thrift::TType::Struct)?;
+        __protocol.write_i32_field(2, *&self.quantity)?;
+        __protocol.write_field_stop()?;
+        __protocol.write_struct_end()?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn decode<T: ::pilota::thrift::TInputProtocol>(
+        __protocol: &mut T,
+    ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+        let mut var_1 = None;
+        let mut var_2 = None;
+
+        let mut __pilota_decoding_field_id = None;
+
+        __protocol.read_struct_begin()?;
+        if let ::std::result::Result::Err(mut err) = (|| {
+            loop {
+                let field_ident = __protocol.read_field_begin()?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                    __protocol.field_stop_len();
+                    break;
+                } else {
+                    __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct => {
+                        var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                    }
+                    Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                        var_2 = Some(__protocol.read_i32()?);
+                    }
+                    _ => {
+                        __protocol.skip(field_ident.field_type)?;
+                    }
+                }
+
+                __protocol.read_field_end()?;
+                __protocol.field_end_len();
+            }
+            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+        })() {
+            if let Some(field_id) = __pilota_decoding_field_id {
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "Line",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("product"),
+                        2 => ::std::option::Option::Some("quantity"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
+            }
+            return ::std::result::Result::Err(err);
+        };
+        __protocol.read_struct_end()?;
+
+        let Some(var_1) = var_1 else {
+            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                "field product is required".to_string(),
+            ));
+        };
+        let Some(var_2) = var_2 else {
+            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                "field quantity is required".to_string(),
+            ));
+        };
+
+        let data = Self {
+            product: var_1,
+            quantity: var_2,
+        };
+        ::std::result::Result::Ok(data)
+    }
+
+    fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+        __protocol: &'a mut T,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<
+            dyn ::std::future::Future<
+                    Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                > + Send
+                + 'a,
+        >,
+    > {
+        ::std::boxed::Box::pin(async move {
+            let mut var_1 = None;
+            let mut var_2 = None;
+
+            let mut __pilota_decoding_field_id = None;
+
+            __protocol.read_struct_begin().await?;
+            if let ::std::result::Result::Err(mut err) = async {
+                loop {
+                    let field_ident = __protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    } else {
+                    }
+                    __pilota_decoding_field_id = field_ident.id;
+                    match field_ident.id {
+                        Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct => {
+                            var_1 = Some(
+                                <Product as ::pilota::thrift::Message>::decode_async(__protocol)
+                                    .await?,
+                            );
+                        }
+                        Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                            var_2 = Some(__protocol.read_i32().await?);
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+
+                    __protocol.read_field_end().await?;
+                }
+                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+            }
+            .await
+            {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Line",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("product"),
+                            2 => ::std::option::Option::Some("quantity"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+            __protocol.read_struct_end().await?;
+
+            let Some(var_1) = var_1 else {
+                return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    "field product is required".to_string(),
+                ));
+            };
+            let Some(var_2) = var_2 else {
+                return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    "field quantity is required".to_string(),
+                ));
+            };
+
+            let data = Self {
+                product: var_1,
+                quantity: var_2,
+            };
+            ::std::result::Result::Ok(data)
+        })
+    }
+
+    fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TLengthProtocolExt;
+        __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Line" })
+            + __protocol.struct_field_len(Some(1), &self.product)
+            + __protocol.i32_field_len(Some(2), *&self.quantity)
+            + __protocol.field_stop_len()
+            + __protocol.struct_end_len()
+    }
+}
+#[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+pub struct Order {
+    pub id: i64,
+
+    pub lines: ::std::vec::Vec<Line>,
+
+    pub customer: ::std::option::Option<Customer>,
+}
+impl ::pilota::thrift::Message for Order {
+    fn encode<T: ::pilota::thrift::TOutputProtocol>(
+        &self,
+        __protocol: &mut T,
+    ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TOutputProtocolExt;
+        let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Order" };
+
+        __protocol.write_struct_begin(&struct_ident)?;
+        __protocol.write_i64_field(1, *&self.id)?;
+        __protocol.write_list_field(
+            2,
+            ::pilota::thrift::TType::Struct,
+            &&self.lines,
+            |__protocol, val| {
+                __protocol.write_struct(val)?;
+                ::std::result::Result::Ok(())
+            },
+        )?;
+        if let Some(value) = self.customer.as_ref() {
+            __protocol.write_struct_field(3, value, ::pilota::thrift::TType::Struct)?;
+        }
+        __protocol.write_field_stop()?;
+        __protocol.write_struct_end()?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn decode<T: ::pilota::thrift::TInputProtocol>(
+        __protocol: &mut T,
+    ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+        let mut var_1 = None;
+        let mut var_2 = None;
+        let mut var_3 = None;
+
+        let mut __pilota_decoding_field_id = None;
+
+        __protocol.read_struct_begin()?;
+        if let ::std::result::Result::Err(mut err) = (|| {
+            loop {
+                let field_ident = __protocol.read_field_begin()?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                    __protocol.field_stop_len();
+                    break;
+                } else {
+                    __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                        var_1 = Some(__protocol.read_i64()?);
+                    }
+                    Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                        var_2 = Some(unsafe {
+                            let list_ident = __protocol.read_list_begin()?;
+                            let mut val: ::std::vec::Vec<Line> =
+                                ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+                            if let ::std::result::Result::Err(mut err) = (|| {
+                                for i in 0..list_ident.size {
+                                    __pilota_decoding_index = i;
+                                    val.as_mut_ptr()
+                                        .offset(i as isize)
+                                        .write(::pilota::thrift::Message::decode(__protocol)?);
+                                }
+                                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                            })(
+                            ) {
+                                err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                    __pilota_decoding_index,
+                                ));
+                                return ::std::result::Result::Err(err);
+                            }
+                            val.set_len(list_ident.size);
+                            __protocol.read_list_end()?;
+                            val
+                        });
+                    }
+                    Some(3) if field_ident.field_type == ::pilota::thrift::TType::Struct => {
+                        var_3 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                    }
+                    _ => {
+                        __protocol.skip(field_ident.field_type)?;
+                    }
+                }
+
+                __protocol.read_field_end()?;
+                __protocol.field_end_len();
+            }
+            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+        })() {
+            if let Some(field_id) = __pilota_decoding_field_id {
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "Order",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("id"),
+                        2 => ::std::option::Option::Some("lines"),
+                        3 => ::std::option::Option::Some("customer"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
+            }
+            return ::std::result::Result::Err(err);
+        };
+        __protocol.read_struct_end()?;
+
+        let Some(var_1) = var_1 else {
+            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                "field id is required".to_string(),
+            ));
+        };
+        let Some(var_2) = var_2 else {
+            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                "field lines is required".to_string(),
+            ));
+        };
+
+        let data = Self {
+            id: var_1,
+            lines: var_2,
+            customer: var_3,
+        };
+        ::std::result::Result::Ok(data)
+    }
+
+    fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+        __protocol: &'a mut T,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<
+            dyn ::std::future::Future<
+                    Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                > + Send
+                + 'a,
+        >,
+    > {
+        ::std::boxed::Box::pin(async move {
+            let mut var_1 = None;
+            let mut var_2 = None;
+            let mut var_3 = None;
+
+            let mut __pilota_decoding_field_id = None;
+
+            __protocol.read_struct_begin().await?;
+            if let ::std::result::Result::Err(mut err) = async {
+                loop {
+                    let field_ident = __protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    } else {
+                    }
+                    __pilota_decoding_field_id = field_ident.id;
+                    match field_ident.id {
+                        Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                            var_1 = Some(__protocol.read_i64().await?);
+                        }
+                        Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                            var_2 = Some({
+                                let list_ident = __protocol.read_list_begin().await?;
+                                let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                                let mut __pilota_decoding_index = 0;
+                                if let ::std::result::Result::Err(mut err) =
+                                    async {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.push(
+                                                <Line as ::pilota::thrift::Message>::decode_async(
+                                                    __protocol,
+                                                )
+                                                .await?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    }
+                                    .await
+                                {
+                                    err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                        __pilota_decoding_index,
+                                    ));
+                                    return ::std::result::Result::Err(err);
+                                }
+                                __protocol.read_list_end().await?;
+                                val
+                            });
+                        }
+                        Some(3) if field_ident.field_type == ::pilota::thrift::TType::Struct => {
+                            var_3 = Some(
+                                <Customer as ::pilota::thrift::Message>::decode_async(__protocol)
+                                    .await?,
+                            );
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+
+                    __protocol.read_field_end().await?;
+                }
+                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+            }
+            .await
+            {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Order",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("id"),
+                            2 => ::std::option::Option::Some("lines"),
+                            3 => ::std::option::Option::Some("customer"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+            __protocol.read_struct_end().await?;
+
+            let Some(var_1) = var_1 else {
+                return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    "field id is required".to_string(),
+                ));
+            };
+            let Some(var_2) = var_2 else {
+                return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    "field lines is required".to_string(),
+                ));
+            };
+
+            let data = Self {
+                id: var_1,
+                lines: var_2,
+                customer: var_3,
+            };
+            ::std::result::Result::Ok(data)
+        })
+    }
+
+    fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TLengthProtocolExt;
+        __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Order" })
+            + __protocol.i64_field_len(Some(1), *&self.id)
+            + __protocol.list_field_len(
+                Some(2),
+                ::pilota::thrift::TType::Struct,
+                &self.lines,
+                |__protocol, el| __protocol.struct_len(el),
+            )
+            + self
+                .customer
+                .as_ref()
+                .map_or(0, |value| __protocol.struct_field_len(Some(3), value))
+            + __protocol.field_stop_len()
+            + __protocol.struct_end_len()
+    }
+}
@@ -0,0 +1,378 @@
+#[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+pub struct Price {
+    pub cents: i64,
+
+    pub currency: Currency,
+}
+impl ::pilota::thrift::Message for Price {
+    fn encode<T: ::pilota::thrift::TOutputProtocol>(
+        &self,
+        __protocol: &mut T,
+    ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TOutputProtocolExt;
+        let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Price" };
+
+        __protocol.write_struct_begin(&struct_ident)?;
+        __protocol.write_i64_field(1, *&self.cents)?;
+        __protocol.write_i32_field(2, (&self.currency).inner())?;
+        __protocol.write_field_stop()?;
+        __protocol.write_struct_end()?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn decode<T: ::pilota::thrift::TInputProtocol>(
+        __protocol: &mut T,
+    ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+        let mut var_1 = None;
+        let mut var_2 = None;
+
+        let mut __pilota_decoding_field_id = None;
+
+        __protocol.read_struct_begin()?;
+        if let ::std::result::Result::Err(mut err) = (|| {
+            loop {
+                let field_ident = __protocol.read_field_begin()?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                    __protocol.field_stop_len();
+                    break;
+                } else {
+                    __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                        var_1 = Some(__protocol.read_i64()?);
+                    }
+                    Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                        var_2 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                    }
+                    _ => {
+                        __protocol.skip(field_ident.field_type)?;
+                    }
+                }
+
+                __protocol.read_field_end()?;
+                __protocol.field_end_len();
+            }
+            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+        })() {
+            if let Some(field_id) = __pilota_decoding_field_id {
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "Price",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("cents"),
+                        2 => ::std::option::Option::Some("currency"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
+            }
+            return ::std::result::Result::Err(err);
+        };
+        __protocol.read_struct_end()?;
+
+        let Some(var_1) = var_1 else {
+            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                "field cents is required".to_string(),
+            ));
+        };
+        let Some(var_2) = var_2 else {
+            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                "field currency is required".to_string(),
+            ));
+        };
+
+        let data = Self {
+            cents: var_1,
+            currency: var_2,
+        };
+        ::std::result::Result::Ok(data)
+    }
+
+    fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+        __protocol: &'a mut T,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<
+            dyn ::std::future::Future<
+                    Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                > + Send
+                + 'a,
+        >,
+    > {
+        ::std::boxed::Box::pin(async move {
+            let mut var_1 = None;
+            let mut var_2 = None;
+
+            let mut __pilota_decoding_field_id = None;
+
+            __protocol.read_struct_begin().await?;
+            if let ::std::result::Result::Err(mut err) = async {
+                loop {
+                    let field_ident = __protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    } else {
+                    }
+                    __pilota_decoding_field_id = field_ident.id;
+                    match field_ident.id {
+                        Some(1) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                            var_1 = Some(__protocol.read_i64().await?);
+                        }
+                        Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                            var_2 = Some(
+                                <Currency as ::pilota::thrift::Message>::decode_async(__protocol)
+                                    .await?,
+                            );
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+
+                    __protocol.read_field_end().await?;
+                }
+                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+            }
+            .await
+            {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Price",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("cents"),
+                            2 => ::std::option::Option::Some("currency"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+            __protocol.read_struct_end().await?;
+
+            let Some(var_1) = var_1 else {
+                return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    "field cents is required".to_string(),
+                ));
+            };
+            let Some(var_2) = var_2 else {
+                return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    "field currency is required".to_string(),
+                ));
+            };
+
+            let data = Self {
+                cents: var_1,
+                currency: var_2,
+            };
+            ::std::result::Result::Ok(data)
+        })
+    }
+
+    fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TLengthProtocolExt;
+        __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Price" })
+            + __protocol.i64_field_len(Some(1), *&self.cents)
+            + __protocol.i32_field_len(Some(2), (&self.currency).inner())
+            + __protocol.field_stop_len()
+            + __protocol.struct_end_len()
+    }
+}
+#[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+pub struct Product {
+    pub name: ::pilota::FastStr,
+
+    pub price: Price,
+}
+impl ::pilota::thrift::Message for Product {
+    fn encode<T: ::pilota::thrift::TOutputProtocol>(
+        &self,
+        __protocol: &mut T,
+    ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TOutputProtocolExt;
+        let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Product" };
+
+        __protocol.write_struct_begin(&struct_ident)?;
+        __protocol.write_faststr_field(1, (&self.name).clone())?;
+        __protocol.write_struct_field(2, &self.price, ::pilota::thrift::TType::Struct)?;
+        __protocol.write_field_stop()?;
+        __protocol.write_struct_end()?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn decode<T: ::pilota::thrift::TInputProtocol>(
+        __protocol: &mut T,
+    ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+        #[allow(unused_imports)]
+        use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+        let mut var_1 = None;
+        let mut var_2 = None;
+
+        let mut __pilota_decoding_field_id = None;
+
+        __protocol.read_struct_begin()?;
+        if let ::std::result::Result::Err(mut err) = (|| {
+            loop {
+                let field_ident = __protocol.read_field_begin()?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                    __protocol.field_stop_len();
+                    break;
+                } else {
+                    __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary => {
+                        var_1 = Some(__protocol.read_faststr()?);
+                    }
+                    Some(2) if field_ident.field_type == ::pilota::thrift::TType::Struct => {
+                        var_2 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                    }
+                    _ => {
+                        __protocol.skip(field_ident.field_type)?;
+                    }
+                }
+
+                __protocol.read_field_end()?;
+                __protocol.field_end_len();
+            }
+            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+        })() {
+            if let Some(field_id) = __pilota_decoding_field_id {
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "Product",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("name"),
+                        2 => ::std::option::Option::Some("price"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
+            }
+            return ::std::result::Result::Err(err);
+        };
+        __protocol.read_struct_end()?;
+
+        let Some(var_1) = var_1 else {
+            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                "field name is required".to_string(),
+            ));
+        };
+        let Some(var_2) = var_2 else {
+            return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                "field price is required".to_string(),
+            ));
+        };
+
+        let data = Self {
+            name: var_1,
+            price: var_2,
+        };
+        ::std::result::Result::Ok(data)
+    }
+
+    fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+        __protocol: &'a mut T,
+    ) -> ::std::pin::Pin<
+        ::std::boxed::Box<
+            dyn ::std::future::Future<
+                    Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                > + Send
+                + 'a,
+        >,
+    > {
+        ::std::boxed::Box::pin(async move {
+            let mut var_1 = None;
+            let mut var_2 = None;
+
+            let mut __pilota_decoding_field_id = None;
+
+            __protocol.read_struct_begin().await?;
+            if let ::std::result::Result::Err(mut err) = async {
+                loop {
+                    let field_ident = __protocol.read_field_begin().await?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        break;
+                    } else {
+                    }
+                    __pilota_decoding_field_id = field_ident.id;
+                    match field_ident.id {
+                        Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary => {
+                            var_1 = Some(__protocol.read_faststr().await?);
+                        }
+                        Some(2) if field_ident.field_type == ::pilota::thrift::TType::Struct => {
+                            var_2 = Some(
+                                <Price as ::pilota::thrift::Message>::decode_async(__protocol)
+                                    .await?,
+                            );
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type).await?;
+                        }
+                    }
+
+                    __protocol.read_field_end().await?;
+                }
+                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+            }
+            .await
+            {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Product",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("name"),
+                            2 => ::std::option::Option::Some("price"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+            __protocol.read_struct_end().await?;
+
+            let Some(var_1) = var_1 else {
+                return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    "field name is required".to_string(),
+                ));
+            };
+            let Some(var_2) = var_2 else {
+                return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                    "field price is required".to_string(),
+                ));
+            };
+
+            let data = Self {
+                name: var_1,
+                price: var_2,
+            };
+            ::std::result::Result::Ok(data)
+        })
+    }
+
+    fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+        #[allow(unused_imports)]
+        use ::pilota::thrift::TLengthProtocolExt;
+        __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Product" })
+            + __protocol.faststr_field_len(Some(1), &self.name)
+            + __protocol.struct_field_len(Some(2), &self.price)
+            + __protocol.field_stop_len()
+            + __protocol.struct_end_len()
+    }
+}
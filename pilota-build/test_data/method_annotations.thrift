@@ -0,0 +1,9 @@
+struct Req {
+    1: required string id,
+}
+
+service Gateway {
+    Req get(1: Req req) (http.method = "GET", http.path = "/items/:id"),
+    Req search(1: Req req) (http.path = "/search", http.path = "/find", pilota.name = "find"),
+    void ping(),
+}
@@ -0,0 +1,13 @@
+exception E {
+    1: string msg,
+}
+
+service A {
+    void ping(),
+    string get(1: string key) (pilota.method_item_name = "Fetch"),
+}
+
+service B {
+    void ping(),
+    void only_b() throws (1: E e),
+}
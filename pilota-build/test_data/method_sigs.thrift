@@ -0,0 +1,16 @@
+exception E {
+    1: string msg,
+}
+
+struct Req {
+    1: string q,
+}
+
+service Base {
+    void ping(),
+}
+
+service S extends Base {
+    Req echo(1: Req req, 2: i32 times) throws (1: E e),
+    oneway void fire(1: i64 id),
+}
@@ -1,5 +1,5 @@
 pub mod must_gen_items {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod must_gen_items {
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
@@ -60,10 +60,14 @@ pub mod must_gen_items {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `A` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "A",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("a"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -115,10 +119,14 @@ pub mod must_gen_items {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `A` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "A",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("a"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -0,0 +1,10 @@
+enum Level {
+    LOW = 1,
+    HIGH = 2,
+}
+
+struct Limits {
+    1: required i32 percent (pilota.range = "0..=100"),
+    2: optional i64 offset (pilota.range = "-10..0"),
+    3: required Level level,
+}
@@ -0,0 +1,9 @@
+struct foo_bar {
+    1: required i32 my_field,
+    2: required i32 myField,
+    3: required i32 other,
+}
+
+struct FooBar {
+    1: required i32 a,
+}
@@ -0,0 +1,12 @@
+typedef string Email
+typedef binary Avatar
+typedef Email PrimaryEmail
+typedef list<i64> Scores
+typedef i32 Age
+
+struct Profile {
+    1: required PrimaryEmail email,
+    2: optional Avatar avatar,
+    3: required Scores scores,
+    4: required Age age,
+}
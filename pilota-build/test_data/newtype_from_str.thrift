@@ -0,0 +1,11 @@
+typedef string Email (pilota.range = "3..=254")
+
+typedef string Nickname
+
+typedef i64 UserId
+
+struct User {
+    1: required UserId id,
+    2: required Email email,
+    3: optional Nickname nickname,
+}
@@ -0,0 +1,470 @@
+pub mod defaults {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod defaults {
+
+        ::pilota::lazy_static::lazy_static! {
+            pub static ref LIMITS: ::alloc::collections::BTreeMap<&'static str, ::alloc::vec::Vec<i32>> = {
+                {
+            let mut map = ::alloc::collections::BTreeMap::new();
+            map.insert("a", ::alloc::vec![1i32,2i32]);
+            map
+        }
+            };
+        }
+
+        impl ::core::default::Default for Config {
+            fn default() -> Self {
+                Config {
+                    weights: Some({
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(::pilota::FastStr::from_static_str("a"), 1i32);
+                        map
+                    }),
+                    tags: ::alloc::collections::BTreeSet::from([
+                        ::pilota::FastStr::from_static_str("t"),
+                    ]),
+                    names: ::alloc::vec![::pilota::FastStr::from_static_str("x")],
+                    name: Some(::pilota::FastStr::from_static_str("config")),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub struct Config {
+            pub weights:
+                ::core::option::Option<::alloc::collections::BTreeMap<::pilota::FastStr, i32>>,
+
+            pub tags: ::alloc::collections::BTreeSet<::pilota::FastStr>,
+
+            pub names: ::alloc::vec::Vec<::pilota::FastStr>,
+
+            pub name: ::core::option::Option<::pilota::FastStr>,
+        }
+        impl ::pilota::thrift::Message for Config {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::core::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Config" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                if let Some(value) = self.weights.as_ref() {
+                    __protocol.write_btree_map_field(
+                        1,
+                        ::pilota::thrift::TType::Binary,
+                        ::pilota::thrift::TType::I32,
+                        &value,
+                        |__protocol, key| {
+                            __protocol.write_faststr((key).clone())?;
+                            ::core::result::Result::Ok(())
+                        },
+                        |__protocol, val| {
+                            __protocol.write_i32(*val)?;
+                            ::core::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                __protocol.write_btree_set_field(
+                    2,
+                    ::pilota::thrift::TType::Binary,
+                    &&self.tags,
+                    |__protocol, val| {
+                        __protocol.write_faststr((val).clone())?;
+                        ::core::result::Result::Ok(())
+                    },
+                )?;
+                __protocol.write_list_field(
+                    3,
+                    ::pilota::thrift::TType::Binary,
+                    &&self.names,
+                    |__protocol, val| {
+                        __protocol.write_faststr((val).clone())?;
+                        ::core::result::Result::Ok(())
+                    },
+                )?;
+                if let Some(value) = self.name.as_ref() {
+                    __protocol.write_faststr_field(4, (value).clone())?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::core::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::core::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = Some(::pilota::FastStr::from_static_str("config"));
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::core::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::Map => {
+                                var_1 = Some({
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::alloc::collections::BTreeMap::new();
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::core::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_i32()?,
+                                            );
+                                        }
+                                        ::core::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::core::result::Result::Err(err);
+                                    }
+                                    __protocol.read_map_end()?;
+                                    val
+                                });
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::Set => {
+                                var_2 = Some({
+                                    let list_ident = __protocol.read_set_begin()?;
+                                    let mut val = ::alloc::collections::BTreeSet::new();
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::core::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_faststr()?);
+                                        }
+                                        ::core::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::core::result::Result::Err(err);
+                                    }
+                                    __protocol.read_set_end()?;
+                                    val
+                                });
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_3 = Some(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::alloc::vec::Vec<::pilota::FastStr> =
+                                        ::alloc::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::core::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_faststr()?);
+                                        }
+                                        ::core::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::core::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(4)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_4 = Some(__protocol.read_faststr()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::core::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Config",
+                            name: match field_id {
+                                1 => ::core::option::Option::Some("weights"),
+                                2 => ::core::option::Option::Some("tags"),
+                                3 => ::core::option::Option::Some("names"),
+                                4 => ::core::option::Option::Some("name"),
+                                _ => ::core::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::core::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                if var_1.is_none() {
+                    var_1 = Some({
+                        let mut map = ::alloc::collections::BTreeMap::new();
+                        map.insert(::pilota::FastStr::from_static_str("a"), 1i32);
+                        map
+                    });
+                }
+                let var_2 = var_2.unwrap_or_else(|| {
+                    ::alloc::collections::BTreeSet::from([::pilota::FastStr::from_static_str("t")])
+                });
+                let var_3 =
+                    var_3.unwrap_or_else(|| ::alloc::vec![::pilota::FastStr::from_static_str("x")]);
+
+                let data = Self {
+                    weights: var_1,
+                    tags: var_2,
+                    names: var_3,
+                    name: var_4,
+                };
+                ::core::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::core::pin::Pin<
+                ::alloc::boxed::Box<
+                    dyn ::core::future::Future<
+                            Output = ::core::result::Result<
+                                Self,
+                                ::pilota::thrift::ThriftException,
+                            >,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::alloc::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = Some(::pilota::FastStr::from_static_str("config"));
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::core::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::Map =>
+                                {
+                                    var_1 = Some({
+                                        let map_ident = __protocol.read_map_begin().await?;
+                                        let mut val = ::alloc::collections::BTreeMap::new();
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::core::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_faststr().await?,
+                                                    __protocol.read_i32().await?,
+                                                );
+                                            }
+                                            ::core::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::core::result::Result::Err(err);
+                                        }
+                                        __protocol.read_map_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::Set =>
+                                {
+                                    var_2 = Some({
+                                        let list_ident = __protocol.read_set_begin().await?;
+                                        let mut val = ::alloc::collections::BTreeSet::new();
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::core::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(__protocol.read_faststr().await?);
+                                            }
+                                            ::core::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::core::result::Result::Err(err);
+                                        }
+                                        __protocol.read_set_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(3)
+                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
+                                {
+                                    var_3 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val =
+                                            ::alloc::vec::Vec::with_capacity(list_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::core::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_faststr().await?);
+                                            }
+                                            ::core::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::core::result::Result::Err(err);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(4)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_4 = Some(__protocol.read_faststr().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::core::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Config",
+                                name: match field_id {
+                                    1 => ::core::option::Option::Some("weights"),
+                                    2 => ::core::option::Option::Some("tags"),
+                                    3 => ::core::option::Option::Some("names"),
+                                    4 => ::core::option::Option::Some("name"),
+                                    _ => ::core::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::core::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    if var_1.is_none() {
+                        var_1 = Some({
+                            let mut map = ::alloc::collections::BTreeMap::new();
+                            map.insert(::pilota::FastStr::from_static_str("a"), 1i32);
+                            map
+                        });
+                    }
+                    let var_2 = var_2.unwrap_or_else(|| {
+                        ::alloc::collections::BTreeSet::from([::pilota::FastStr::from_static_str(
+                            "t",
+                        )])
+                    });
+                    let var_3 = var_3
+                        .unwrap_or_else(|| ::alloc::vec![::pilota::FastStr::from_static_str("x")]);
+
+                    let data = Self {
+                        weights: var_1,
+                        tags: var_2,
+                        names: var_3,
+                        name: var_4,
+                    };
+                    ::core::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Config" })
+                    + self.weights.as_ref().map_or(0, |value| {
+                        __protocol.btree_map_field_len(
+                            Some(1),
+                            ::pilota::thrift::TType::Binary,
+                            ::pilota::thrift::TType::I32,
+                            value,
+                            |__protocol, key| __protocol.faststr_len(key),
+                            |__protocol, val| __protocol.i32_len(*val),
+                        )
+                    })
+                    + __protocol.btree_set_field_len(
+                        Some(2),
+                        ::pilota::thrift::TType::Binary,
+                        &self.tags,
+                        |__protocol, el| __protocol.faststr_len(el),
+                    )
+                    + __protocol.list_field_len(
+                        Some(3),
+                        ::pilota::thrift::TType::Binary,
+                        &self.names,
+                        |__protocol, el| __protocol.faststr_len(el),
+                    )
+                    + self
+                        .name
+                        .as_ref()
+                        .map_or(0, |value| __protocol.faststr_field_len(Some(4), value))
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        pub const NAMES: [&'static str; 2] = ["x", "y"];
+    }
+}
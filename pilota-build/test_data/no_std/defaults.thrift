@@ -0,0 +1,9 @@
+const map<string, list<i32>> LIMITS = {"a": [1, 2]}
+const list<string> NAMES = ["x", "y"]
+
+struct Config {
+    1: optional map<string, i32> weights = {"a": 1},
+    2: required set<string> tags = ["t"],
+    3: required list<string> names = ["x"],
+    4: optional string name = "config",
+}
@@ -0,0 +1,6 @@
+struct Settings {
+    1: optional i32 retries = 3,
+    2: string region = "cn",
+    3: required i64 timeout = 100,
+    4: optional string label,
+}
@@ -0,0 +1,19 @@
+struct Attachment {
+    1: required string name,
+    2: required binary data,
+}
+
+union Body {
+    1: string text,
+    2: binary raw,
+    3: Attachment attachment,
+}
+
+struct Mail {
+    1: required i64 id,
+    2: required string subject,
+    3: optional binary signature,
+    4: required Attachment attachment,
+    5: optional list<string> to,
+    6: optional Body body,
+}
@@ -0,0 +1,12 @@
+namespace rs shop.api
+
+include "model.thrift"
+
+struct Request {
+    1: required model.Item item,
+}
+
+// collides with `model.Price`, so neither is re-exported
+struct Price {
+    1: required double amount,
+}
@@ -0,0 +1,9 @@
+namespace rs shop.model
+
+struct Item {
+    1: required string name,
+}
+
+struct Price {
+    1: required i64 cents,
+}
@@ -0,0 +1,866 @@
+pub mod storefront {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub use self::shop::api::Request;
+    pub use self::shop::model::Item;
+    pub use self::shop::Order;
+
+    pub mod shop {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Order {
+            pub request: api::Request,
+        }
+        impl ::pilota::thrift::Message for Order {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Order" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_struct_field(1, &self.request, ::pilota::thrift::TType::Struct)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Order",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("request"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field request is required".to_string(),
+                    ));
+                };
+
+                let data = Self { request: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(
+                                        <api::Request as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    );
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Order",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("request"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field request is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { request: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Order" })
+                    + __protocol.struct_field_len(Some(1), &self.request)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        pub use self::api::Request;
+        pub use self::model::Item;
+
+        pub mod api {
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct Request {
+                pub item: super::model::Item,
+            }
+            impl ::pilota::thrift::Message for Request {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TOutputProtocolExt;
+                    let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Request" };
+
+                    __protocol.write_struct_begin(&struct_ident)?;
+                    __protocol.write_struct_field(
+                        1,
+                        &self.item,
+                        ::pilota::thrift::TType::Struct,
+                    )?;
+                    __protocol.write_field_stop()?;
+                    __protocol.write_struct_end()?;
+                    ::std::result::Result::Ok(())
+                }
+
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
+                {
+                    #[allow(unused_imports)]
+                    use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin()?;
+                    if let ::std::result::Result::Err(mut err) = (|| {
+                        loop {
+                            let field_ident = __protocol.read_field_begin()?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                __protocol.field_stop_len();
+                                break;
+                            } else {
+                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_1 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type)?;
+                                }
+                            }
+
+                            __protocol.read_field_end()?;
+                            __protocol.field_end_len();
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    })() {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Request",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("item"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end()?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field item is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { item: var_1 };
+                    ::std::result::Result::Ok(data)
+                }
+
+                fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                    __protocol: &'a mut T,
+                ) -> ::std::pin::Pin<
+                    ::std::boxed::Box<
+                        dyn ::std::future::Future<
+                                Output = ::std::result::Result<
+                                    Self,
+                                    ::pilota::thrift::ThriftException,
+                                >,
+                            > + Send
+                            + 'a,
+                    >,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        let mut var_1 = None;
+
+                        let mut __pilota_decoding_field_id = None;
+
+                        __protocol.read_struct_begin().await?;
+                        if let ::std::result::Result::Err(mut err) = async {
+                    loop {
+
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_1 = Some(<super::model::Item as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },
+                    _ => {
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Request",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("item"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+                        __protocol.read_struct_end().await?;
+
+                        let Some(var_1) = var_1 else {
+                            return ::std::result::Result::Err(
+                                ::pilota::thrift::new_protocol_exception(
+                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                    "field item is required".to_string(),
+                                ),
+                            );
+                        };
+
+                        let data = Self { item: var_1 };
+                        ::std::result::Result::Ok(data)
+                    })
+                }
+
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TLengthProtocolExt;
+                    __protocol
+                        .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Request" })
+                        + __protocol.struct_field_len(Some(1), &self.item)
+                        + __protocol.field_stop_len()
+                        + __protocol.struct_end_len()
+                }
+            }
+            #[derive(PartialOrd, Debug, Default, Clone, PartialEq)]
+            pub struct Price {
+                pub amount: f64,
+            }
+            impl ::pilota::thrift::Message for Price {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TOutputProtocolExt;
+                    let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Price" };
+
+                    __protocol.write_struct_begin(&struct_ident)?;
+                    __protocol.write_double_field(1, *&self.amount)?;
+                    __protocol.write_field_stop()?;
+                    __protocol.write_struct_end()?;
+                    ::std::result::Result::Ok(())
+                }
+
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
+                {
+                    #[allow(unused_imports)]
+                    use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin()?;
+                    if let ::std::result::Result::Err(mut err) = (|| {
+                        loop {
+                            let field_ident = __protocol.read_field_begin()?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                __protocol.field_stop_len();
+                                break;
+                            } else {
+                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Double =>
+                                {
+                                    var_1 = Some(__protocol.read_double()?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type)?;
+                                }
+                            }
+
+                            __protocol.read_field_end()?;
+                            __protocol.field_end_len();
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    })() {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Price",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("amount"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end()?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field amount is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { amount: var_1 };
+                    ::std::result::Result::Ok(data)
+                }
+
+                fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                    __protocol: &'a mut T,
+                ) -> ::std::pin::Pin<
+                    ::std::boxed::Box<
+                        dyn ::std::future::Future<
+                                Output = ::std::result::Result<
+                                    Self,
+                                    ::pilota::thrift::ThriftException,
+                                >,
+                            > + Send
+                            + 'a,
+                    >,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        let mut var_1 = None;
+
+                        let mut __pilota_decoding_field_id = None;
+
+                        __protocol.read_struct_begin().await?;
+                        if let ::std::result::Result::Err(mut err) = async {
+                            loop {
+                                let field_ident = __protocol.read_field_begin().await?;
+                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                    break;
+                                } else {
+                                }
+                                __pilota_decoding_field_id = field_ident.id;
+                                match field_ident.id {
+                                    Some(1)
+                                        if field_ident.field_type
+                                            == ::pilota::thrift::TType::Double =>
+                                    {
+                                        var_1 = Some(__protocol.read_double().await?);
+                                    }
+                                    _ => {
+                                        __protocol.skip(field_ident.field_type).await?;
+                                    }
+                                }
+
+                                __protocol.read_field_end().await?;
+                            }
+                            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                        }
+                        .await
+                        {
+                            if let Some(field_id) = __pilota_decoding_field_id {
+                                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                    message: "Price",
+                                    name: match field_id {
+                                        1 => ::std::option::Option::Some("amount"),
+                                        _ => ::std::option::Option::None,
+                                    },
+                                    id: field_id,
+                                });
+                            }
+                            return ::std::result::Result::Err(err);
+                        };
+                        __protocol.read_struct_end().await?;
+
+                        let Some(var_1) = var_1 else {
+                            return ::std::result::Result::Err(
+                                ::pilota::thrift::new_protocol_exception(
+                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                    "field amount is required".to_string(),
+                                ),
+                            );
+                        };
+
+                        let data = Self { amount: var_1 };
+                        ::std::result::Result::Ok(data)
+                    })
+                }
+
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TLengthProtocolExt;
+                    __protocol
+                        .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Price" })
+                        + __protocol.double_field_len(Some(1), *&self.amount)
+                        + __protocol.field_stop_len()
+                        + __protocol.struct_end_len()
+                }
+            }
+        }
+
+        pub mod model {
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct Price {
+                pub cents: i64,
+            }
+            impl ::pilota::thrift::Message for Price {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TOutputProtocolExt;
+                    let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Price" };
+
+                    __protocol.write_struct_begin(&struct_ident)?;
+                    __protocol.write_i64_field(1, *&self.cents)?;
+                    __protocol.write_field_stop()?;
+                    __protocol.write_struct_end()?;
+                    ::std::result::Result::Ok(())
+                }
+
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
+                {
+                    #[allow(unused_imports)]
+                    use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin()?;
+                    if let ::std::result::Result::Err(mut err) = (|| {
+                        loop {
+                            let field_ident = __protocol.read_field_begin()?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                __protocol.field_stop_len();
+                                break;
+                            } else {
+                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I64 =>
+                                {
+                                    var_1 = Some(__protocol.read_i64()?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type)?;
+                                }
+                            }
+
+                            __protocol.read_field_end()?;
+                            __protocol.field_end_len();
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    })() {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Price",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("cents"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end()?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field cents is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { cents: var_1 };
+                    ::std::result::Result::Ok(data)
+                }
+
+                fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                    __protocol: &'a mut T,
+                ) -> ::std::pin::Pin<
+                    ::std::boxed::Box<
+                        dyn ::std::future::Future<
+                                Output = ::std::result::Result<
+                                    Self,
+                                    ::pilota::thrift::ThriftException,
+                                >,
+                            > + Send
+                            + 'a,
+                    >,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        let mut var_1 = None;
+
+                        let mut __pilota_decoding_field_id = None;
+
+                        __protocol.read_struct_begin().await?;
+                        if let ::std::result::Result::Err(mut err) = async {
+                            loop {
+                                let field_ident = __protocol.read_field_begin().await?;
+                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                    break;
+                                } else {
+                                }
+                                __pilota_decoding_field_id = field_ident.id;
+                                match field_ident.id {
+                                    Some(1)
+                                        if field_ident.field_type
+                                            == ::pilota::thrift::TType::I64 =>
+                                    {
+                                        var_1 = Some(__protocol.read_i64().await?);
+                                    }
+                                    _ => {
+                                        __protocol.skip(field_ident.field_type).await?;
+                                    }
+                                }
+
+                                __protocol.read_field_end().await?;
+                            }
+                            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                        }
+                        .await
+                        {
+                            if let Some(field_id) = __pilota_decoding_field_id {
+                                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                    message: "Price",
+                                    name: match field_id {
+                                        1 => ::std::option::Option::Some("cents"),
+                                        _ => ::std::option::Option::None,
+                                    },
+                                    id: field_id,
+                                });
+                            }
+                            return ::std::result::Result::Err(err);
+                        };
+                        __protocol.read_struct_end().await?;
+
+                        let Some(var_1) = var_1 else {
+                            return ::std::result::Result::Err(
+                                ::pilota::thrift::new_protocol_exception(
+                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                    "field cents is required".to_string(),
+                                ),
+                            );
+                        };
+
+                        let data = Self { cents: var_1 };
+                        ::std::result::Result::Ok(data)
+                    })
+                }
+
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TLengthProtocolExt;
+                    __protocol
+                        .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Price" })
+                        + __protocol.i64_field_len(Some(1), *&self.cents)
+                        + __protocol.field_stop_len()
+                        + __protocol.struct_end_len()
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct Item {
+                pub name: ::pilota::FastStr,
+            }
+            impl ::pilota::thrift::Message for Item {
+                fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                    &self,
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TOutputProtocolExt;
+                    let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Item" };
+
+                    __protocol.write_struct_begin(&struct_ident)?;
+                    __protocol.write_faststr_field(1, (&self.name).clone())?;
+                    __protocol.write_field_stop()?;
+                    __protocol.write_struct_end()?;
+                    ::std::result::Result::Ok(())
+                }
+
+                fn decode<T: ::pilota::thrift::TInputProtocol>(
+                    __protocol: &mut T,
+                ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException>
+                {
+                    #[allow(unused_imports)]
+                    use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin()?;
+                    if let ::std::result::Result::Err(mut err) = (|| {
+                        loop {
+                            let field_ident = __protocol.read_field_begin()?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                __protocol.field_stop_len();
+                                break;
+                            } else {
+                                __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_1 = Some(__protocol.read_faststr()?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type)?;
+                                }
+                            }
+
+                            __protocol.read_field_end()?;
+                            __protocol.field_end_len();
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    })() {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Item",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("name"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end()?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { name: var_1 };
+                    ::std::result::Result::Ok(data)
+                }
+
+                fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                    __protocol: &'a mut T,
+                ) -> ::std::pin::Pin<
+                    ::std::boxed::Box<
+                        dyn ::std::future::Future<
+                                Output = ::std::result::Result<
+                                    Self,
+                                    ::pilota::thrift::ThriftException,
+                                >,
+                            > + Send
+                            + 'a,
+                    >,
+                > {
+                    ::std::boxed::Box::pin(async move {
+                        let mut var_1 = None;
+
+                        let mut __pilota_decoding_field_id = None;
+
+                        __protocol.read_struct_begin().await?;
+                        if let ::std::result::Result::Err(mut err) = async {
+                            loop {
+                                let field_ident = __protocol.read_field_begin().await?;
+                                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                    break;
+                                } else {
+                                }
+                                __pilota_decoding_field_id = field_ident.id;
+                                match field_ident.id {
+                                    Some(1)
+                                        if field_ident.field_type
+                                            == ::pilota::thrift::TType::Binary =>
+                                    {
+                                        var_1 = Some(__protocol.read_faststr().await?);
+                                    }
+                                    _ => {
+                                        __protocol.skip(field_ident.field_type).await?;
+                                    }
+                                }
+
+                                __protocol.read_field_end().await?;
+                            }
+                            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                        }
+                        .await
+                        {
+                            if let Some(field_id) = __pilota_decoding_field_id {
+                                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                    message: "Item",
+                                    name: match field_id {
+                                        1 => ::std::option::Option::Some("name"),
+                                        _ => ::std::option::Option::None,
+                                    },
+                                    id: field_id,
+                                });
+                            }
+                            return ::std::result::Result::Err(err);
+                        };
+                        __protocol.read_struct_end().await?;
+
+                        let Some(var_1) = var_1 else {
+                            return ::std::result::Result::Err(
+                                ::pilota::thrift::new_protocol_exception(
+                                    ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                    "field name is required".to_string(),
+                                ),
+                            );
+                        };
+
+                        let data = Self { name: var_1 };
+                        ::std::result::Result::Ok(data)
+                    })
+                }
+
+                fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                    #[allow(unused_imports)]
+                    use ::pilota::thrift::TLengthProtocolExt;
+                    __protocol
+                        .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Item" })
+                        + __protocol.faststr_field_len(Some(1), &self.name)
+                        + __protocol.field_stop_len()
+                        + __protocol.struct_end_len()
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+namespace rs shop
+
+include "api.thrift"
+
+struct Order {
+    1: required api.Request request,
+}
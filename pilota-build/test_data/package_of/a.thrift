@@ -0,0 +1,11 @@
+namespace rs a.api
+
+include "b.thrift"
+
+struct A {
+    1: required b.B b,
+}
+
+service S {
+    void ping(1: A a),
+}
@@ -0,0 +1,3 @@
+struct B {
+    1: required i32 id,
+}
@@ -0,0 +1,17 @@
+struct Inner {
+    1: required i32 id,
+}
+
+struct Score {
+    1: optional i32 points,
+    2: optional bool verified,
+    3: optional i64 timestamp,
+    4: optional double ratio,
+    5: optional i16 rank,
+    6: optional byte level,
+    7: optional string comment,
+    8: optional Inner inner,
+    9: optional list<i32> history,
+    10: optional i32 bonus = 5,
+    11: required i32 id,
+}
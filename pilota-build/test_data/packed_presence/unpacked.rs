@@ -0,0 +1,893 @@
+pub mod unpacked {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod scores {
+
+        impl Inner {
+            pub fn stable_hash(&self) -> u64 {
+                ::pilota::hash::stable_hash(self)
+            }
+        }
+
+        impl ::pilota::hash::StableHash for Inner {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                ::pilota::hash::StableHash::write_stable(&1i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.id, hasher);
+            }
+        }
+
+        impl Inner {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(1);
+                map.insert(
+                    "id".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.id),
+                );
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    id: ::pilota::field_map::required(&mut map, "id")?,
+                })
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for Inner {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for Inner {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+
+        impl Inner {
+            pub fn visit_fields<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                visitor: &mut V,
+            ) {
+                ::pilota::visit::VisitValue::visit_value(&self.id, "id", visitor);
+            }
+        }
+
+        impl ::pilota::visit::VisitValue for Inner {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                self.visit_fields(visitor);
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Inner {
+            pub id: i32,
+        }
+        impl ::pilota::thrift::Message for Inner {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Inner" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i32_field(1, *&self.id)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_1 = Some(__protocol.read_i32()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Inner",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("id"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field id is required".to_string(),
+                    ));
+                };
+
+                let data = Self { id: var_1 };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_1 = Some(__protocol.read_i32().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Inner",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self { id: var_1 };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Inner" })
+                    + __protocol.i32_field_len(Some(1), *&self.id)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Score {
+            fn default() -> Self {
+                Score {
+                    points: ::std::default::Default::default(),
+                    verified: ::std::default::Default::default(),
+                    timestamp: ::std::default::Default::default(),
+                    ratio: ::std::default::Default::default(),
+                    rank: ::std::default::Default::default(),
+                    level: ::std::default::Default::default(),
+                    comment: ::std::default::Default::default(),
+                    inner: ::std::default::Default::default(),
+                    history: ::std::default::Default::default(),
+                    bonus: Some(5i32),
+                    id: ::std::default::Default::default(),
+                }
+            }
+        }
+
+        impl Score {
+            pub fn stable_hash(&self) -> u64 {
+                ::pilota::hash::stable_hash(self)
+            }
+        }
+
+        impl ::pilota::hash::StableHash for Score {
+            fn write_stable(&self, hasher: &mut ::pilota::hash::StableHasher) {
+                ::pilota::hash::StableHash::write_stable(&1i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.points, hasher);
+                ::pilota::hash::StableHash::write_stable(&2i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.verified, hasher);
+                ::pilota::hash::StableHash::write_stable(&3i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.timestamp, hasher);
+                ::pilota::hash::StableHash::write_stable(&4i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.ratio, hasher);
+                ::pilota::hash::StableHash::write_stable(&5i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.rank, hasher);
+                ::pilota::hash::StableHash::write_stable(&6i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.level, hasher);
+                ::pilota::hash::StableHash::write_stable(&7i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.comment, hasher);
+                ::pilota::hash::StableHash::write_stable(&8i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.inner, hasher);
+                ::pilota::hash::StableHash::write_stable(&9i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.history, hasher);
+                ::pilota::hash::StableHash::write_stable(&10i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.bonus, hasher);
+                ::pilota::hash::StableHash::write_stable(&11i32, hasher);
+                ::pilota::hash::StableHash::write_stable(&self.id, hasher);
+            }
+        }
+
+        impl Score {
+            pub fn to_field_map(&self) -> ::pilota::field_map::FieldMap {
+                let mut map = ::pilota::field_map::FieldMap::with_capacity(11);
+                if let ::std::option::Option::Some(value) = &self.points {
+                    map.insert(
+                        "points".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.verified {
+                    map.insert(
+                        "verified".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.timestamp {
+                    map.insert(
+                        "timestamp".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.ratio {
+                    map.insert(
+                        "ratio".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.rank {
+                    map.insert(
+                        "rank".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.level {
+                    map.insert(
+                        "level".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.comment {
+                    map.insert(
+                        "comment".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.inner {
+                    map.insert(
+                        "inner".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.history {
+                    map.insert(
+                        "history".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                if let ::std::option::Option::Some(value) = &self.bonus {
+                    map.insert(
+                        "bonus".to_string(),
+                        ::pilota::field_map::ToValue::to_value(value),
+                    );
+                }
+                map.insert(
+                    "id".to_string(),
+                    ::pilota::field_map::ToValue::to_value(&self.id),
+                );
+                map
+            }
+
+            pub fn from_field_map(
+                mut map: ::pilota::field_map::FieldMap,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                ::std::result::Result::Ok(Self {
+                    points: ::pilota::field_map::optional(&mut map, "points")?,
+                    verified: ::pilota::field_map::optional(&mut map, "verified")?,
+                    timestamp: ::pilota::field_map::optional(&mut map, "timestamp")?,
+                    ratio: ::pilota::field_map::optional(&mut map, "ratio")?,
+                    rank: ::pilota::field_map::optional(&mut map, "rank")?,
+                    level: ::pilota::field_map::optional(&mut map, "level")?,
+                    comment: ::pilota::field_map::optional(&mut map, "comment")?,
+                    inner: ::pilota::field_map::optional(&mut map, "inner")?,
+                    history: ::pilota::field_map::optional(&mut map, "history")?,
+                    bonus: ::pilota::field_map::optional(&mut map, "bonus")?,
+                    id: ::pilota::field_map::required(&mut map, "id")?,
+                })
+            }
+        }
+
+        impl ::pilota::field_map::ToValue for Score {
+            fn to_value(&self) -> ::pilota::field_map::Value {
+                ::pilota::field_map::Value::Message(self.to_field_map())
+            }
+        }
+
+        impl ::pilota::field_map::FromValue for Score {
+            fn from_value(
+                value: ::pilota::field_map::Value,
+            ) -> ::std::result::Result<Self, ::pilota::field_map::FieldMapError> {
+                match value {
+                    ::pilota::field_map::Value::Message(map) => Self::from_field_map(map),
+                    value => ::std::result::Result::Err(
+                        ::pilota::field_map::FieldMapError::invalid("a message", value),
+                    ),
+                }
+            }
+        }
+
+        impl Score {
+            pub fn visit_fields<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                visitor: &mut V,
+            ) {
+                ::pilota::visit::VisitValue::visit_value(&self.points, "points", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.verified, "verified", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.timestamp, "timestamp", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.ratio, "ratio", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.rank, "rank", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.level, "level", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.comment, "comment", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.inner, "inner", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.history, "history", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.bonus, "bonus", visitor);
+                ::pilota::visit::VisitValue::visit_value(&self.id, "id", visitor);
+            }
+        }
+
+        impl ::pilota::visit::VisitValue for Score {
+            fn visit_value<V: ::pilota::visit::FieldVisitor + ?::std::marker::Sized>(
+                &self,
+                name: &'static str,
+                visitor: &mut V,
+            ) {
+                visitor.enter_message(name);
+                self.visit_fields(visitor);
+                visitor.exit_message(name);
+            }
+        }
+        #[derive(PartialOrd, Debug, Clone, PartialEq)]
+        pub struct Score {
+            pub points: ::std::option::Option<i32>,
+
+            pub verified: ::std::option::Option<bool>,
+
+            pub timestamp: ::std::option::Option<i64>,
+
+            pub ratio: ::std::option::Option<f64>,
+
+            pub rank: ::std::option::Option<i16>,
+
+            pub level: ::std::option::Option<i8>,
+
+            pub comment: ::std::option::Option<::pilota::FastStr>,
+
+            pub inner: ::std::option::Option<Inner>,
+
+            pub history: ::std::option::Option<::std::vec::Vec<i32>>,
+
+            pub bonus: ::std::option::Option<i32>,
+
+            pub id: i32,
+        }
+        impl ::pilota::thrift::Message for Score {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Score" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                if let Some(value) = self.points.as_ref() {
+                    __protocol.write_i32_field(1, *value)?;
+                }
+                if let Some(value) = self.verified.as_ref() {
+                    __protocol.write_bool_field(2, *value)?;
+                }
+                if let Some(value) = self.timestamp.as_ref() {
+                    __protocol.write_i64_field(3, *value)?;
+                }
+                if let Some(value) = self.ratio.as_ref() {
+                    __protocol.write_double_field(4, *value)?;
+                }
+                if let Some(value) = self.rank.as_ref() {
+                    __protocol.write_i16_field(5, *value)?;
+                }
+                if let Some(value) = self.level.as_ref() {
+                    __protocol.write_i8_field(6, *value)?;
+                }
+                if let Some(value) = self.comment.as_ref() {
+                    __protocol.write_faststr_field(7, (value).clone())?;
+                }
+                if let Some(value) = self.inner.as_ref() {
+                    __protocol.write_struct_field(8, value, ::pilota::thrift::TType::Struct)?;
+                }
+                if let Some(value) = self.history.as_ref() {
+                    __protocol.write_list_field(
+                        9,
+                        ::pilota::thrift::TType::I32,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_i32(*val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                if let Some(value) = self.bonus.as_ref() {
+                    __protocol.write_i32_field(10, *value)?;
+                }
+                __protocol.write_i32_field(11, *&self.id)?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+                let mut var_6 = None;
+                let mut var_7 = None;
+                let mut var_8 = None;
+                let mut var_9 = None;
+                let mut var_10 = Some(5i32);
+                let mut var_11 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_1 = Some(__protocol.read_i32()?);
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::Bool => {
+                                var_2 = Some(__protocol.read_bool()?);
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_3 = Some(__protocol.read_i64()?);
+                            }
+                            Some(4)
+                                if field_ident.field_type == ::pilota::thrift::TType::Double =>
+                            {
+                                var_4 = Some(__protocol.read_double()?);
+                            }
+                            Some(5) if field_ident.field_type == ::pilota::thrift::TType::I16 => {
+                                var_5 = Some(__protocol.read_i16()?);
+                            }
+                            Some(6) if field_ident.field_type == ::pilota::thrift::TType::I8 => {
+                                var_6 = Some(__protocol.read_i8()?);
+                            }
+                            Some(7)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_7 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(8)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_8 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(9) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_9 = Some(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<i32> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(10) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_10 = Some(__protocol.read_i32()?);
+                            }
+                            Some(11) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_11 = Some(__protocol.read_i32()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Score",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("points"),
+                                2 => ::std::option::Option::Some("verified"),
+                                3 => ::std::option::Option::Some("timestamp"),
+                                4 => ::std::option::Option::Some("ratio"),
+                                5 => ::std::option::Option::Some("rank"),
+                                6 => ::std::option::Option::Some("level"),
+                                7 => ::std::option::Option::Some("comment"),
+                                8 => ::std::option::Option::Some("inner"),
+                                9 => ::std::option::Option::Some("history"),
+                                10 => ::std::option::Option::Some("bonus"),
+                                11 => ::std::option::Option::Some("id"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_11) = var_11 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field id is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    points: var_1,
+                    verified: var_2,
+                    timestamp: var_3,
+                    ratio: var_4,
+                    rank: var_5,
+                    level: var_6,
+                    comment: var_7,
+                    inner: var_8,
+                    history: var_9,
+                    bonus: var_10,
+                    id: var_11,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+                    let mut var_6 = None;
+                    let mut var_7 = None;
+                    let mut var_8 = None;
+                    let mut var_9 = None;
+                    let mut var_10 = Some(5i32);
+                    let mut var_11 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_1 = Some(__protocol.read_i32().await?);
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::Bool =>
+                                {
+                                    var_2 = Some(__protocol.read_bool().await?);
+                                }
+                                Some(3)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I64 =>
+                                {
+                                    var_3 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(4)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Double =>
+                                {
+                                    var_4 = Some(__protocol.read_double().await?);
+                                }
+                                Some(5)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I16 =>
+                                {
+                                    var_5 = Some(__protocol.read_i16().await?);
+                                }
+                                Some(6)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I8 =>
+                                {
+                                    var_6 = Some(__protocol.read_i8().await?);
+                                }
+                                Some(7)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_7 = Some(__protocol.read_faststr().await?);
+                                }
+                                Some(8)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Struct =>
+                                {
+                                    var_8 = Some(
+                                        <Inner as ::pilota::thrift::Message>::decode_async(
+                                            __protocol,
+                                        )
+                                        .await?,
+                                    );
+                                }
+                                Some(9)
+                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
+                                {
+                                    var_9 = Some({
+                                        let list_ident = __protocol.read_list_begin().await?;
+                                        let mut val =
+                                            ::std::vec::Vec::with_capacity(list_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        __protocol.read_list_end().await?;
+                                        val
+                                    });
+                                }
+                                Some(10)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_10 = Some(__protocol.read_i32().await?);
+                                }
+                                Some(11)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_11 = Some(__protocol.read_i32().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Score",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("points"),
+                                    2 => ::std::option::Option::Some("verified"),
+                                    3 => ::std::option::Option::Some("timestamp"),
+                                    4 => ::std::option::Option::Some("ratio"),
+                                    5 => ::std::option::Option::Some("rank"),
+                                    6 => ::std::option::Option::Some("level"),
+                                    7 => ::std::option::Option::Some("comment"),
+                                    8 => ::std::option::Option::Some("inner"),
+                                    9 => ::std::option::Option::Some("history"),
+                                    10 => ::std::option::Option::Some("bonus"),
+                                    11 => ::std::option::Option::Some("id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_11) = var_11 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field id is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        points: var_1,
+                        verified: var_2,
+                        timestamp: var_3,
+                        ratio: var_4,
+                        rank: var_5,
+                        level: var_6,
+                        comment: var_7,
+                        inner: var_8,
+                        history: var_9,
+                        bonus: var_10,
+                        id: var_11,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Score" })
+                    + self
+                        .points
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i32_field_len(Some(1), *value))
+                    + self
+                        .verified
+                        .as_ref()
+                        .map_or(0, |value| __protocol.bool_field_len(Some(2), *value))
+                    + self
+                        .timestamp
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i64_field_len(Some(3), *value))
+                    + self
+                        .ratio
+                        .as_ref()
+                        .map_or(0, |value| __protocol.double_field_len(Some(4), *value))
+                    + self
+                        .rank
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i16_field_len(Some(5), *value))
+                    + self
+                        .level
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i8_field_len(Some(6), *value))
+                    + self
+                        .comment
+                        .as_ref()
+                        .map_or(0, |value| __protocol.faststr_field_len(Some(7), value))
+                    + self
+                        .inner
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(8), value))
+                    + self.history.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(9),
+                            ::pilota::thrift::TType::I32,
+                            value,
+                            |__protocol, el| __protocol.i32_len(*el),
+                        )
+                    })
+                    + self
+                        .bonus
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i32_field_len(Some(10), *value))
+                    + __protocol.i32_field_len(Some(11), *&self.id)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+    }
+}
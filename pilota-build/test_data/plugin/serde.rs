@@ -1,5 +1,5 @@
 pub mod serde {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod serde {
         #[derive(
@@ -79,10 +79,15 @@ pub mod serde {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `A` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "A",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("a"),
+                                2 => ::std::option::Option::Some("b"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -154,10 +159,15 @@ pub mod serde {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `A` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "A",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("a"),
+                                    2 => ::std::option::Option::Some("b"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -0,0 +1,14 @@
+struct Inner {
+    1: required string x,
+    2: required i32 y,
+}
+
+struct Outer {
+    1: required string id,
+    2: required Inner inner(pilota.serde_flatten = "true"),
+}
+
+struct Extra {
+    1: required string id,
+    2: required map<string, string> rest(pilota.serde_flatten = "true"),
+}
@@ -0,0 +1,3 @@
+struct Money {
+    1: required i64 cents,
+}
@@ -0,0 +1,6 @@
+include "money.thrift"
+
+struct Item {
+    1: required string name,
+    2: required money.Money price,
+}
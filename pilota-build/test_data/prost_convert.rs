@@ -0,0 +1,477 @@
+pub mod prost_convert {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    impl ::std::convert::From<Inner> for crate::test::tests::prost_stub::Inner {
+        fn from(value: Inner) -> Self {
+            Self {
+                name: value.name.into(),
+            }
+        }
+    }
+
+    impl ::std::convert::From<crate::test::tests::prost_stub::Inner> for Inner {
+        fn from(value: crate::test::tests::prost_stub::Inner) -> Self {
+            Self {
+                name: value.name.into(),
+            }
+        }
+    }
+    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+    pub struct Inner {
+        pub name: ::pilota::FastStr,
+    }
+    impl ::pilota::prost::Message for Inner {
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::pilota::prost::encoding::faststr::encoded_len(1, &self.name)
+        }
+
+        #[allow(unused_variables)]
+        fn encode_raw<B>(&self, buf: &mut B)
+        where
+            B: ::pilota::prost::bytes::BufMut,
+        {
+            ::pilota::prost::encoding::faststr::encode(1, &self.name, buf);
+        }
+
+        #[allow(unused_variables)]
+        fn merge_field<B>(
+            &mut self,
+            tag: u32,
+            wire_type: ::pilota::prost::encoding::WireType,
+            buf: &mut B,
+            ctx: ::pilota::prost::encoding::DecodeContext,
+        ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+        where
+            B: ::pilota::prost::bytes::Buf,
+        {
+            const STRUCT_NAME: &'static str = stringify!(Inner);
+            match tag {
+                1 => {
+                    let mut _inner_pilota_value = &mut self.name;
+                    ::pilota::prost::encoding::faststr::merge(
+                        wire_type,
+                        _inner_pilota_value,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push(STRUCT_NAME, stringify!(name));
+                        error
+                    })
+                }
+                _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+            }
+        }
+    }
+
+    impl ::std::convert::From<Outer> for crate::test::tests::prost_stub::Outer {
+        fn from(value: Outer) -> Self {
+            Self {
+                id: value.id.into(),
+                name: value.name.into(),
+                data: value.data.into(),
+                tags: value
+                    .tags
+                    .into_iter()
+                    .map(::std::convert::Into::into)
+                    .collect(),
+                counts: value
+                    .counts
+                    .into_iter()
+                    .map(|(k, v)| (k.into(), v.into()))
+                    .collect(),
+                kind: value.kind.into(),
+                inner: value.inner.map(|v| v.into()),
+                version: value.version.map(|v| v.into()),
+                children: value
+                    .children
+                    .into_iter()
+                    .map(::std::convert::Into::into)
+                    .collect(),
+                value: value.value.map(|v| v.into()),
+            }
+        }
+    }
+
+    impl ::std::convert::From<crate::test::tests::prost_stub::Outer> for Outer {
+        fn from(value: crate::test::tests::prost_stub::Outer) -> Self {
+            Self {
+                id: value.id.into(),
+                name: value.name.into(),
+                data: value.data.into(),
+                tags: value
+                    .tags
+                    .into_iter()
+                    .map(::std::convert::Into::into)
+                    .collect(),
+                counts: value
+                    .counts
+                    .into_iter()
+                    .map(|(k, v)| (k.into(), v.into()))
+                    .collect(),
+                kind: value.kind.into(),
+                inner: value.inner.map(|v| v.into()),
+                version: value.version.map(|v| v.into()),
+                children: value
+                    .children
+                    .into_iter()
+                    .map(::std::convert::Into::into)
+                    .collect(),
+                value: value.value.map(|v| v.into()),
+            }
+        }
+    }
+    #[derive(Debug, Default, Clone, PartialEq)]
+    pub struct Outer {
+        pub id: i32,
+
+        pub name: ::pilota::FastStr,
+
+        pub data: ::pilota::Bytes,
+
+        pub tags: ::std::vec::Vec<::pilota::FastStr>,
+
+        pub counts: ::pilota::AHashMap<::pilota::FastStr, i64>,
+
+        pub kind: Kind,
+
+        pub inner: ::std::option::Option<Inner>,
+
+        pub version: ::std::option::Option<i64>,
+
+        pub children: ::std::vec::Vec<Inner>,
+
+        pub value: ::std::option::Option<outer::Value>,
+    }
+    impl ::pilota::prost::Message for Outer {
+        #[inline]
+        fn encoded_len(&self) -> usize {
+            0 + ::pilota::prost::encoding::int32::encoded_len(1, &self.id)
+                + ::pilota::prost::encoding::faststr::encoded_len(2, &self.name)
+                + ::pilota::prost::encoding::bytes::encoded_len(3, &self.data)
+                + ::pilota::prost::encoding::faststr::encoded_len_repeated(4, &self.tags)
+                + ::pilota::prost::encoding::hash_map::encoded_len(
+                    ::pilota::prost::encoding::faststr::encoded_len,
+                    ::pilota::prost::encoding::int64::encoded_len,
+                    5,
+                    &self.counts,
+                )
+                + ::pilota::prost::encoding::int32::encoded_len(6, &self.kind)
+                + self.inner.as_ref().map_or(0, |msg| {
+                    ::pilota::prost::encoding::message::encoded_len(7, msg)
+                })
+                + self.version.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::int64::encoded_len(8, value)
+                })
+                + ::pilota::prost::encoding::message::encoded_len_repeated(9, &self.children)
+                + self.value.as_ref().map_or(0, |msg| msg.encoded_len())
+        }
+
+        #[allow(unused_variables)]
+        fn encode_raw<B>(&self, buf: &mut B)
+        where
+            B: ::pilota::prost::bytes::BufMut,
+        {
+            ::pilota::prost::encoding::int32::encode(1, &self.id, buf);
+            ::pilota::prost::encoding::faststr::encode(2, &self.name, buf);
+            ::pilota::prost::encoding::bytes::encode(3, &self.data, buf);
+            ::pilota::prost::encoding::faststr::encode_repeated(4, &self.tags, buf);
+            ::pilota::prost::encoding::hash_map::encode(
+                ::pilota::prost::encoding::faststr::encode,
+                ::pilota::prost::encoding::faststr::encoded_len,
+                ::pilota::prost::encoding::int64::encode,
+                ::pilota::prost::encoding::int64::encoded_len,
+                5,
+                &self.counts,
+                buf,
+            );
+            ::pilota::prost::encoding::int32::encode(6, &self.kind, buf);
+            if let Some(_pilota_inner_value) = self.inner.as_ref() {
+                ::pilota::prost::encoding::message::encode(7, _pilota_inner_value, buf);
+            }
+            if let Some(_pilota_inner_value) = self.version.as_ref() {
+                ::pilota::prost::encoding::int64::encode(8, _pilota_inner_value, buf);
+            };
+            for msg in &self.children {
+                ::pilota::prost::encoding::message::encode(9, msg, buf);
+            }
+            if let Some(_pilota_inner_value) = self.value.as_ref() {
+                _pilota_inner_value.encode(buf);
+            }
+        }
+
+        #[allow(unused_variables)]
+        fn merge_field<B>(
+            &mut self,
+            tag: u32,
+            wire_type: ::pilota::prost::encoding::WireType,
+            buf: &mut B,
+            ctx: ::pilota::prost::encoding::DecodeContext,
+        ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+        where
+            B: ::pilota::prost::bytes::Buf,
+        {
+            const STRUCT_NAME: &'static str = stringify!(Outer);
+            match tag {
+                1 => {
+                    let mut _inner_pilota_value = &mut self.id;
+                    ::pilota::prost::encoding::int32::merge(
+                        wire_type,
+                        _inner_pilota_value,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push(STRUCT_NAME, stringify!(id));
+                        error
+                    })
+                }
+                2 => {
+                    let mut _inner_pilota_value = &mut self.name;
+                    ::pilota::prost::encoding::faststr::merge(
+                        wire_type,
+                        _inner_pilota_value,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push(STRUCT_NAME, stringify!(name));
+                        error
+                    })
+                }
+                3 => {
+                    let mut _inner_pilota_value = &mut self.data;
+                    ::pilota::prost::encoding::bytes::merge(
+                        wire_type,
+                        _inner_pilota_value,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push(STRUCT_NAME, stringify!(data));
+                        error
+                    })
+                }
+                4 => {
+                    let mut _inner_pilota_value = &mut self.tags;
+                    ::pilota::prost::encoding::faststr::merge_repeated(
+                        wire_type,
+                        _inner_pilota_value,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push(STRUCT_NAME, stringify!(tags));
+                        error
+                    })
+                }
+                5 => {
+                    let mut _inner_pilota_value = &mut self.counts;
+                    ::pilota::prost::encoding::hash_map::merge(
+                        ::pilota::prost::encoding::faststr::merge,
+                        ::pilota::prost::encoding::int64::merge,
+                        &mut _inner_pilota_value,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push(STRUCT_NAME, stringify!(counts));
+                        error
+                    })
+                }
+                6 => {
+                    let mut _inner_pilota_value = &mut self.kind;
+                    ::pilota::prost::encoding::int32::merge(
+                        wire_type,
+                        _inner_pilota_value,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push(STRUCT_NAME, stringify!(kind));
+                        error
+                    })
+                }
+                7 => {
+                    let mut _inner_pilota_value = &mut self.inner;
+                    ::pilota::prost::encoding::message::merge(
+                        wire_type,
+                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push(STRUCT_NAME, stringify!(inner));
+                        error
+                    })
+                }
+                8 => {
+                    let mut _inner_pilota_value = &mut self.version;
+                    ::pilota::prost::encoding::int64::merge(
+                        wire_type,
+                        _inner_pilota_value.get_or_insert_with(::core::default::Default::default),
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push(STRUCT_NAME, stringify!(version));
+                        error
+                    })
+                }
+                9 => {
+                    let mut _inner_pilota_value = &mut self.children;
+                    ::pilota::prost::encoding::message::merge_repeated(
+                        wire_type,
+                        _inner_pilota_value,
+                        buf,
+                        ctx,
+                    )
+                    .map_err(|mut error| {
+                        error.push(STRUCT_NAME, stringify!(children));
+                        error
+                    })
+                }
+                10 | 11 => {
+                    let mut _inner_pilota_value = &mut self.value;
+                    outer::Value::merge(&mut _inner_pilota_value, tag, wire_type, buf, ctx).map_err(
+                        |mut error| {
+                            error.push(STRUCT_NAME, stringify!(value));
+                            error
+                        },
+                    )
+                }
+                _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+            }
+        }
+    }
+    #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+    #[repr(transparent)]
+    pub struct Kind(i32);
+
+    impl Kind {
+        pub const KIND_UNSPECIFIED: Self = Self(0);
+        pub const KIND_A: Self = Self(1);
+
+        pub fn inner(&self) -> i32 {
+            self.0
+        }
+
+        pub fn to_string(&self) -> ::std::string::String {
+            match self {
+                Self(0) => ::std::string::String::from("KIND_UNSPECIFIED"),
+                Self(1) => ::std::string::String::from("KIND_A"),
+                Self(val) => val.to_string(),
+            }
+        }
+    }
+
+    impl ::std::convert::From<i32> for Kind {
+        fn from(value: i32) -> Self {
+            Self(value)
+        }
+    }
+
+    impl ::std::convert::From<Kind> for i32 {
+        fn from(value: Kind) -> i32 {
+            value.0
+        }
+    }
+
+    pub mod outer {
+
+        impl ::std::default::Default for Value {
+            fn default() -> Self {
+                Value::S(::std::default::Default::default())
+            }
+        }
+
+        impl ::std::convert::From<Value> for crate::test::tests::prost_stub::outer::Value {
+            fn from(value: Value) -> Self {
+                match value {
+                    Value::S(v) => Self::S(v.into()),
+                    Value::Msg(v) => Self::Msg(v.into()),
+                }
+            }
+        }
+
+        impl ::std::convert::From<crate::test::tests::prost_stub::outer::Value> for Value {
+            fn from(value: crate::test::tests::prost_stub::outer::Value) -> Self {
+                match value {
+                    crate::test::tests::prost_stub::outer::Value::S(v) => Self::S(v.into()),
+                    crate::test::tests::prost_stub::outer::Value::Msg(v) => Self::Msg(v.into()),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum Value {
+            S(::pilota::FastStr),
+
+            Msg(super::Inner),
+        }
+        impl Value {
+            pub fn encode<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                match self {
+                    Value::S(value) => {
+                        ::pilota::prost::encoding::faststr::encode(10, &*value, buf);
+                    }
+                    Value::Msg(value) => {
+                        ::pilota::prost::encoding::message::encode(11, (&*value), buf);
+                    }
+                }
+            }
+
+            #[inline]
+            pub fn encoded_len(&self) -> usize {
+                match self {
+                    Value::S(value) => ::pilota::prost::encoding::faststr::encoded_len(10, &*value),
+                    Value::Msg(value) => {
+                        ::pilota::prost::encoding::message::encoded_len(11, &*value)
+                    }
+                }
+            }
+
+            #[inline]
+            pub fn merge<B>(
+                field: &mut ::core::option::Option<Self>,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                match tag {
+                    10 => match field {
+                        ::core::option::Option::Some(Value::S(ref mut value)) => {
+                            ::pilota::prost::encoding::faststr::merge(wire_type, value, buf, ctx)?;
+                        }
+                        _ => {
+                            let mut owned_value = ::core::default::Default::default();
+                            let value = &mut owned_value;
+                            ::pilota::prost::encoding::faststr::merge(wire_type, value, buf, ctx)?;
+                            *field = ::core::option::Option::Some(Value::S(owned_value));
+                        }
+                    },
+                    11 => match field {
+                        ::core::option::Option::Some(Value::Msg(ref mut value)) => {
+                            ::pilota::prost::encoding::message::merge(wire_type, value, buf, ctx)?;
+                        }
+                        _ => {
+                            let mut owned_value = ::core::default::Default::default();
+                            let value = &mut owned_value;
+                            ::pilota::prost::encoding::message::merge(wire_type, value, buf, ctx)?;
+                            *field = ::core::option::Option::Some(Value::Msg(owned_value));
+                        }
+                    },
+                    _ => unreachable!(concat!("invalid ", stringify!(Value), " tag: {}"), tag),
+                };
+                ::core::result::Result::Ok(())
+            }
+        }
+    }
+}
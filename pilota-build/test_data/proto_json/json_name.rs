@@ -0,0 +1,222 @@
+pub mod json_name {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod proto_json {
+        #[derive(
+            PartialOrd,
+            Hash,
+            Eq,
+            Ord,
+            Debug,
+            Default,
+            ::pilota::serde::Serialize,
+            ::pilota::serde::Deserialize,
+            Clone,
+            PartialEq,
+        )]
+        pub struct User {
+            #[serde(rename = "userId")]
+            pub user_id: i64,
+            #[serde(rename = "nick")]
+            pub display_name: ::pilota::FastStr,
+            #[serde(rename = "emailAddresses")]
+            pub email_addresses: ::std::vec::Vec<::pilota::FastStr>,
+
+            pub contact: ::std::option::Option<user::Contact>,
+        }
+        impl ::pilota::prost::Message for User {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::int64::encoded_len(1, &self.user_id)
+                    + ::pilota::prost::encoding::faststr::encoded_len(2, &self.display_name)
+                    + ::pilota::prost::encoding::faststr::encoded_len_repeated(
+                        3,
+                        &self.email_addresses,
+                    )
+                    + self.contact.as_ref().map_or(0, |msg| msg.encoded_len())
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::int64::encode(1, &self.user_id, buf);
+                ::pilota::prost::encoding::faststr::encode(2, &self.display_name, buf);
+                ::pilota::prost::encoding::faststr::encode_repeated(3, &self.email_addresses, buf);
+                if let Some(_pilota_inner_value) = self.contact.as_ref() {
+                    _pilota_inner_value.encode(buf);
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(User);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.user_id;
+                        ::pilota::prost::encoding::int64::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(user_id));
+                            error
+                        })
+                    }
+                    2 => {
+                        let mut _inner_pilota_value = &mut self.display_name;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(display_name));
+                            error
+                        })
+                    }
+                    3 => {
+                        let mut _inner_pilota_value = &mut self.email_addresses;
+                        ::pilota::prost::encoding::faststr::merge_repeated(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(email_addresses));
+                            error
+                        })
+                    }
+                    4 | 5 => {
+                        let mut _inner_pilota_value = &mut self.contact;
+                        user::Contact::merge(&mut _inner_pilota_value, tag, wire_type, buf, ctx)
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(contact));
+                                error
+                            })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+
+        pub mod user {
+
+            impl ::std::default::Default for Contact {
+                fn default() -> Self {
+                    Contact::PhoneNumber(::std::default::Default::default())
+                }
+            }
+            #[derive(
+                PartialOrd,
+                Hash,
+                Eq,
+                Ord,
+                Debug,
+                ::pilota::serde::Serialize,
+                ::pilota::serde::Deserialize,
+                Clone,
+                PartialEq,
+            )]
+            pub enum Contact {
+                #[serde(rename = "phoneNumber")]
+                PhoneNumber(::pilota::FastStr),
+                #[serde(rename = "address")]
+                PostalAddress(::pilota::FastStr),
+            }
+            impl Contact {
+                pub fn encode<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    match self {
+                        Contact::PhoneNumber(value) => {
+                            ::pilota::prost::encoding::faststr::encode(4, &*value, buf);
+                        }
+                        Contact::PostalAddress(value) => {
+                            ::pilota::prost::encoding::faststr::encode(5, &*value, buf);
+                        }
+                    }
+                }
+
+                #[inline]
+                pub fn encoded_len(&self) -> usize {
+                    match self {
+                        Contact::PhoneNumber(value) => {
+                            ::pilota::prost::encoding::faststr::encoded_len(4, &*value)
+                        }
+                        Contact::PostalAddress(value) => {
+                            ::pilota::prost::encoding::faststr::encoded_len(5, &*value)
+                        }
+                    }
+                }
+
+                #[inline]
+                pub fn merge<B>(
+                    field: &mut ::core::option::Option<Self>,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    match tag {
+                        4 => match field {
+                            ::core::option::Option::Some(Contact::PhoneNumber(ref mut value)) => {
+                                ::pilota::prost::encoding::faststr::merge(
+                                    wire_type, value, buf, ctx,
+                                )?;
+                            }
+                            _ => {
+                                let mut owned_value = ::core::default::Default::default();
+                                let value = &mut owned_value;
+                                ::pilota::prost::encoding::faststr::merge(
+                                    wire_type, value, buf, ctx,
+                                )?;
+                                *field =
+                                    ::core::option::Option::Some(Contact::PhoneNumber(owned_value));
+                            }
+                        },
+                        5 => match field {
+                            ::core::option::Option::Some(Contact::PostalAddress(ref mut value)) => {
+                                ::pilota::prost::encoding::faststr::merge(
+                                    wire_type, value, buf, ctx,
+                                )?;
+                            }
+                            _ => {
+                                let mut owned_value = ::core::default::Default::default();
+                                let value = &mut owned_value;
+                                ::pilota::prost::encoding::faststr::merge(
+                                    wire_type, value, buf, ctx,
+                                )?;
+                                *field = ::core::option::Option::Some(Contact::PostalAddress(
+                                    owned_value,
+                                ));
+                            }
+                        },
+                        _ => {
+                            unreachable!(concat!("invalid ", stringify!(Contact), " tag: {}"), tag)
+                        }
+                    };
+                    ::core::result::Result::Ok(())
+                }
+            }
+        }
+    }
+}
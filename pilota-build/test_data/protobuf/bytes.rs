@@ -1,5 +1,5 @@
 pub mod bytes {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
     #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
     pub struct A {
         pub a: ::std::option::Option<::pilota::Bytes>,
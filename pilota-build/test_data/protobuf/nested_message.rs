@@ -1,5 +1,5 @@
 pub mod nested_message {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
     #[derive(Debug, Default, Clone, PartialEq)]
     pub struct Tt1 {
         pub t2: tt1::T2,
@@ -16,6 +16,10 @@ pub mod nested_message {
                 + ::pilota::prost::encoding::message::encoded_len(4, &self.t4)
         }
 
+        fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {
+            ("TT1", &[(1, "t2"), (2, "t3"), (4, "t4")])
+        }
+
         #[allow(unused_variables)]
         fn encode_raw<B>(&self, buf: &mut B)
         where
@@ -129,6 +133,10 @@ pub mod nested_message {
                 0 + ::pilota::prost::encoding::message::encoded_len(1, &self.t3)
             }
 
+            fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {
+                ("T2", &[(1, "t3")])
+            }
+
             #[allow(unused_variables)]
             fn encode_raw<B>(&self, buf: &mut B)
             where
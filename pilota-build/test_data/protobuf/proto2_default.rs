@@ -0,0 +1,365 @@
+pub mod proto2_default {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod proto2_default {
+
+        impl ::std::default::Default for Defaults {
+            fn default() -> Self {
+                Defaults {
+                    retries: Some(3i32),
+                    offset: Some(-42i64),
+                    port: Some(8080u32),
+                    mask: Some(18446744073709551615u64),
+                    ratio: Some(0.5f32),
+                    limit: Some(f64::INFINITY),
+                    enabled: Some(true),
+                    greeting: Some(::pilota::FastStr::from_static_str("say \"hi\"\n")),
+                    magic: Some(::pilota::Bytes::from_static(&[1, 255, 112, 98])),
+                    tag: Some(::pilota::Bytes::from_static("a\tb".as_bytes())),
+                    level: Some(Level::HIGH),
+                    timeout: 30i64,
+                    plain: ::std::default::Default::default(),
+                }
+            }
+        }
+        #[derive(PartialOrd, Debug, Clone, PartialEq)]
+        pub struct Defaults {
+            pub retries: ::std::option::Option<i32>,
+
+            pub offset: ::std::option::Option<i64>,
+
+            pub port: ::std::option::Option<u32>,
+
+            pub mask: ::std::option::Option<u64>,
+
+            pub ratio: ::std::option::Option<f32>,
+
+            pub limit: ::std::option::Option<f64>,
+
+            pub enabled: ::std::option::Option<bool>,
+
+            pub greeting: ::std::option::Option<::pilota::FastStr>,
+
+            pub magic: ::std::option::Option<::pilota::Bytes>,
+
+            pub tag: ::std::option::Option<::pilota::Bytes>,
+
+            pub level: ::std::option::Option<Level>,
+
+            pub timeout: i64,
+
+            pub plain: ::std::option::Option<i32>,
+        }
+        impl ::pilota::prost::Message for Defaults {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + self.retries.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::int32::encoded_len(1, value)
+                }) + self.offset.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::int64::encoded_len(2, value)
+                }) + self.port.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::uint32::encoded_len(3, value)
+                }) + self.mask.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::fixed64::encoded_len(4, value)
+                }) + self.ratio.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::float::encoded_len(5, value)
+                }) + self.limit.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::double::encoded_len(6, value)
+                }) + self.enabled.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::bool::encoded_len(7, value)
+                }) + self.greeting.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::faststr::encoded_len(8, value)
+                }) + self.magic.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::bytes::encoded_len(9, value)
+                }) + self.tag.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::bytes::encoded_len(10, value)
+                }) + self.level.as_ref().map_or(0, |value| {
+                    ::pilota::prost::encoding::int32::encoded_len(11, value)
+                }) + ::pilota::prost::encoding::int64::encoded_len(12, &self.timeout)
+                    + self.plain.as_ref().map_or(0, |value| {
+                        ::pilota::prost::encoding::int32::encoded_len(13, value)
+                    })
+            }
+
+            fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {
+                ("Defaults", &[(12, "timeout")])
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                if let Some(_pilota_inner_value) = self.retries.as_ref() {
+                    ::pilota::prost::encoding::int32::encode(1, _pilota_inner_value, buf);
+                };
+                if let Some(_pilota_inner_value) = self.offset.as_ref() {
+                    ::pilota::prost::encoding::int64::encode(2, _pilota_inner_value, buf);
+                };
+                if let Some(_pilota_inner_value) = self.port.as_ref() {
+                    ::pilota::prost::encoding::uint32::encode(3, _pilota_inner_value, buf);
+                };
+                if let Some(_pilota_inner_value) = self.mask.as_ref() {
+                    ::pilota::prost::encoding::fixed64::encode(4, _pilota_inner_value, buf);
+                };
+                if let Some(_pilota_inner_value) = self.ratio.as_ref() {
+                    ::pilota::prost::encoding::float::encode(5, _pilota_inner_value, buf);
+                };
+                if let Some(_pilota_inner_value) = self.limit.as_ref() {
+                    ::pilota::prost::encoding::double::encode(6, _pilota_inner_value, buf);
+                };
+                if let Some(_pilota_inner_value) = self.enabled.as_ref() {
+                    ::pilota::prost::encoding::bool::encode(7, _pilota_inner_value, buf);
+                };
+                if let Some(_pilota_inner_value) = self.greeting.as_ref() {
+                    ::pilota::prost::encoding::faststr::encode(8, _pilota_inner_value, buf);
+                };
+                if let Some(_pilota_inner_value) = self.magic.as_ref() {
+                    ::pilota::prost::encoding::bytes::encode(9, _pilota_inner_value, buf);
+                };
+                if let Some(_pilota_inner_value) = self.tag.as_ref() {
+                    ::pilota::prost::encoding::bytes::encode(10, _pilota_inner_value, buf);
+                };
+                if let Some(_pilota_inner_value) = self.level.as_ref() {
+                    ::pilota::prost::encoding::int32::encode(11, _pilota_inner_value, buf);
+                };
+                ::pilota::prost::encoding::int64::encode(12, &self.timeout, buf);
+                if let Some(_pilota_inner_value) = self.plain.as_ref() {
+                    ::pilota::prost::encoding::int32::encode(13, _pilota_inner_value, buf);
+                };
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Defaults);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.retries;
+                        ::pilota::prost::encoding::int32::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(retries));
+                            error
+                        })
+                    }
+                    2 => {
+                        let mut _inner_pilota_value = &mut self.offset;
+                        ::pilota::prost::encoding::int64::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(offset));
+                            error
+                        })
+                    }
+                    3 => {
+                        let mut _inner_pilota_value = &mut self.port;
+                        ::pilota::prost::encoding::uint32::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(port));
+                            error
+                        })
+                    }
+                    4 => {
+                        let mut _inner_pilota_value = &mut self.mask;
+                        ::pilota::prost::encoding::fixed64::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(mask));
+                            error
+                        })
+                    }
+                    5 => {
+                        let mut _inner_pilota_value = &mut self.ratio;
+                        ::pilota::prost::encoding::float::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(ratio));
+                            error
+                        })
+                    }
+                    6 => {
+                        let mut _inner_pilota_value = &mut self.limit;
+                        ::pilota::prost::encoding::double::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(limit));
+                            error
+                        })
+                    }
+                    7 => {
+                        let mut _inner_pilota_value = &mut self.enabled;
+                        ::pilota::prost::encoding::bool::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(enabled));
+                            error
+                        })
+                    }
+                    8 => {
+                        let mut _inner_pilota_value = &mut self.greeting;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(greeting));
+                            error
+                        })
+                    }
+                    9 => {
+                        let mut _inner_pilota_value = &mut self.magic;
+                        ::pilota::prost::encoding::bytes::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(magic));
+                            error
+                        })
+                    }
+                    10 => {
+                        let mut _inner_pilota_value = &mut self.tag;
+                        ::pilota::prost::encoding::bytes::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(tag));
+                            error
+                        })
+                    }
+                    11 => {
+                        let mut _inner_pilota_value = &mut self.level;
+                        ::pilota::prost::encoding::int32::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(level));
+                            error
+                        })
+                    }
+                    12 => {
+                        let mut _inner_pilota_value = &mut self.timeout;
+                        ::pilota::prost::encoding::int64::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(timeout));
+                            error
+                        })
+                    }
+                    13 => {
+                        let mut _inner_pilota_value = &mut self.plain;
+                        ::pilota::prost::encoding::int32::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(plain));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Level(i32);
+
+        impl Level {
+            pub const LOW: Self = Self(0);
+            pub const MEDIUM: Self = Self(1);
+            pub const HIGH: Self = Self(2);
+
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+
+            pub fn to_string(&self) -> ::std::string::String {
+                match self {
+                    Self(0) => ::std::string::String::from("LOW"),
+                    Self(1) => ::std::string::String::from("MEDIUM"),
+                    Self(2) => ::std::string::String::from("HIGH"),
+                    Self(val) => val.to_string(),
+                }
+            }
+        }
+
+        impl ::std::convert::From<i32> for Level {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::std::convert::From<Level> for i32 {
+            fn from(value: Level) -> i32 {
+                value.0
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+pub mod proto2_required {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod proto2_required {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Device {
+            pub id: ::pilota::FastStr,
+
+            pub model: ::std::option::Option<::pilota::FastStr>,
+        }
+        impl ::pilota::prost::Message for Device {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::faststr::encoded_len(1, &self.id)
+                    + self.model.as_ref().map_or(0, |value| {
+                        ::pilota::prost::encoding::faststr::encoded_len(2, value)
+                    })
+            }
+
+            fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {
+                ("Device", &[(1, "id")])
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::faststr::encode(1, &self.id, buf);
+                if let Some(_pilota_inner_value) = self.model.as_ref() {
+                    ::pilota::prost::encoding::faststr::encode(2, _pilota_inner_value, buf);
+                };
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Device);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.id;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(id));
+                            error
+                        })
+                    }
+                    2 => {
+                        let mut _inner_pilota_value = &mut self.model;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(model));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Login {
+            pub user_id: i64,
+
+            pub device: Device,
+
+            pub token: ::std::option::Option<::pilota::FastStr>,
+
+            pub scopes: ::std::vec::Vec<::pilota::FastStr>,
+        }
+        impl ::pilota::prost::Message for Login {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::int64::encoded_len(1, &self.user_id)
+                    + ::pilota::prost::encoding::message::encoded_len(2, &self.device)
+                    + self.token.as_ref().map_or(0, |value| {
+                        ::pilota::prost::encoding::faststr::encoded_len(3, value)
+                    })
+                    + ::pilota::prost::encoding::faststr::encoded_len_repeated(4, &self.scopes)
+            }
+
+            fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {
+                ("Login", &[(1, "user_id"), (2, "device")])
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::int64::encode(1, &self.user_id, buf);
+                ::pilota::prost::encoding::message::encode(2, (&self.device), buf);
+                if let Some(_pilota_inner_value) = self.token.as_ref() {
+                    ::pilota::prost::encoding::faststr::encode(3, _pilota_inner_value, buf);
+                };
+                ::pilota::prost::encoding::faststr::encode_repeated(4, &self.scopes, buf);
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Login);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.user_id;
+                        ::pilota::prost::encoding::int64::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(user_id));
+                            error
+                        })
+                    }
+                    2 => {
+                        let mut _inner_pilota_value = &mut self.device;
+                        ::pilota::prost::encoding::message::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(device));
+                            error
+                        })
+                    }
+                    3 => {
+                        let mut _inner_pilota_value = &mut self.token;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(token));
+                            error
+                        })
+                    }
+                    4 => {
+                        let mut _inner_pilota_value = &mut self.scopes;
+                        ::pilota::prost::encoding::faststr::merge_repeated(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(scopes));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+    }
+}
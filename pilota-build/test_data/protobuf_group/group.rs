@@ -0,0 +1,385 @@
+pub mod group {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod group {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct SearchResponse {
+            pub result: ::std::vec::Vec<search_response::Result>,
+
+            pub meta: ::std::option::Option<search_response::Meta>,
+
+            pub query: ::std::option::Option<::pilota::FastStr>,
+        }
+        impl ::pilota::prost::Message for SearchResponse {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::group::encoded_len_repeated(1, &self.result)
+                    + self.meta.as_ref().map_or(0, |msg| {
+                        ::pilota::prost::encoding::group::encoded_len(6, msg)
+                    })
+                    + self.query.as_ref().map_or(0, |value| {
+                        ::pilota::prost::encoding::faststr::encoded_len(8, value)
+                    })
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                for msg in &self.result {
+                    ::pilota::prost::encoding::group::encode(1, msg, buf);
+                }
+                if let Some(_pilota_inner_value) = self.meta.as_ref() {
+                    ::pilota::prost::encoding::group::encode(6, _pilota_inner_value, buf);
+                }
+                if let Some(_pilota_inner_value) = self.query.as_ref() {
+                    ::pilota::prost::encoding::faststr::encode(8, _pilota_inner_value, buf);
+                };
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(SearchResponse);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.result;
+                        ::pilota::prost::encoding::group::merge_repeated(
+                            tag,
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(result));
+                            error
+                        })
+                    }
+                    6 => {
+                        let mut _inner_pilota_value = &mut self.meta;
+                        ::pilota::prost::encoding::group::merge(
+                            tag,
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(meta));
+                            error
+                        })
+                    }
+                    8 => {
+                        let mut _inner_pilota_value = &mut self.query;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(query));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Snippet {
+            pub range: ::std::option::Option<snippet::Range>,
+        }
+        impl ::pilota::prost::Message for Snippet {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + self.range.as_ref().map_or(0, |msg| {
+                    ::pilota::prost::encoding::group::encoded_len(1, msg)
+                })
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                if let Some(_pilota_inner_value) = self.range.as_ref() {
+                    ::pilota::prost::encoding::group::encode(1, _pilota_inner_value, buf);
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Snippet);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.range;
+                        ::pilota::prost::encoding::group::merge(
+                            tag,
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(range));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+
+        pub mod search_response {
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct Result {
+                pub url: ::pilota::FastStr,
+
+                pub title: ::std::option::Option<::pilota::FastStr>,
+
+                pub snippet: ::std::option::Option<super::Snippet>,
+            }
+            impl ::pilota::prost::Message for Result {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + ::pilota::prost::encoding::faststr::encoded_len(2, &self.url)
+                        + self.title.as_ref().map_or(0, |value| {
+                            ::pilota::prost::encoding::faststr::encoded_len(3, value)
+                        })
+                        + self.snippet.as_ref().map_or(0, |msg| {
+                            ::pilota::prost::encoding::message::encoded_len(4, msg)
+                        })
+                }
+
+                fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {
+                    ("Result", &[(2, "url")])
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    ::pilota::prost::encoding::faststr::encode(2, &self.url, buf);
+                    if let Some(_pilota_inner_value) = self.title.as_ref() {
+                        ::pilota::prost::encoding::faststr::encode(3, _pilota_inner_value, buf);
+                    };
+                    if let Some(_pilota_inner_value) = self.snippet.as_ref() {
+                        ::pilota::prost::encoding::message::encode(4, _pilota_inner_value, buf);
+                    }
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(Result);
+                    match tag {
+                        2 => {
+                            let mut _inner_pilota_value = &mut self.url;
+                            ::pilota::prost::encoding::faststr::merge(
+                                wire_type,
+                                _inner_pilota_value,
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(url));
+                                error
+                            })
+                        }
+                        3 => {
+                            let mut _inner_pilota_value = &mut self.title;
+                            ::pilota::prost::encoding::faststr::merge(
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(title));
+                                error
+                            })
+                        }
+                        4 => {
+                            let mut _inner_pilota_value = &mut self.snippet;
+                            ::pilota::prost::encoding::message::merge(
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(snippet));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct Meta {
+                pub total: ::std::option::Option<i64>,
+            }
+            impl ::pilota::prost::Message for Meta {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + self.total.as_ref().map_or(0, |value| {
+                        ::pilota::prost::encoding::int64::encoded_len(7, value)
+                    })
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    if let Some(_pilota_inner_value) = self.total.as_ref() {
+                        ::pilota::prost::encoding::int64::encode(7, _pilota_inner_value, buf);
+                    };
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(Meta);
+                    match tag {
+                        7 => {
+                            let mut _inner_pilota_value = &mut self.total;
+                            ::pilota::prost::encoding::int64::merge(
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(total));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+        }
+
+        pub mod snippet {
+            #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+            pub struct Range {
+                pub start: ::std::option::Option<i32>,
+
+                pub end: ::std::option::Option<i32>,
+            }
+            impl ::pilota::prost::Message for Range {
+                #[inline]
+                fn encoded_len(&self) -> usize {
+                    0 + self.start.as_ref().map_or(0, |value| {
+                        ::pilota::prost::encoding::int32::encoded_len(2, value)
+                    }) + self.end.as_ref().map_or(0, |value| {
+                        ::pilota::prost::encoding::int32::encoded_len(3, value)
+                    })
+                }
+
+                #[allow(unused_variables)]
+                fn encode_raw<B>(&self, buf: &mut B)
+                where
+                    B: ::pilota::prost::bytes::BufMut,
+                {
+                    if let Some(_pilota_inner_value) = self.start.as_ref() {
+                        ::pilota::prost::encoding::int32::encode(2, _pilota_inner_value, buf);
+                    };
+                    if let Some(_pilota_inner_value) = self.end.as_ref() {
+                        ::pilota::prost::encoding::int32::encode(3, _pilota_inner_value, buf);
+                    };
+                }
+
+                #[allow(unused_variables)]
+                fn merge_field<B>(
+                    &mut self,
+                    tag: u32,
+                    wire_type: ::pilota::prost::encoding::WireType,
+                    buf: &mut B,
+                    ctx: ::pilota::prost::encoding::DecodeContext,
+                ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+                where
+                    B: ::pilota::prost::bytes::Buf,
+                {
+                    const STRUCT_NAME: &'static str = stringify!(Range);
+                    match tag {
+                        2 => {
+                            let mut _inner_pilota_value = &mut self.start;
+                            ::pilota::prost::encoding::int32::merge(
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(start));
+                                error
+                            })
+                        }
+                        3 => {
+                            let mut _inner_pilota_value = &mut self.end;
+                            ::pilota::prost::encoding::int32::merge(
+                                wire_type,
+                                _inner_pilota_value
+                                    .get_or_insert_with(::core::default::Default::default),
+                                buf,
+                                ctx,
+                            )
+                            .map_err(|mut error| {
+                                error.push(STRUCT_NAME, stringify!(end));
+                                error
+                            })
+                        }
+                        _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                    }
+                }
+            }
+        }
+    }
+}
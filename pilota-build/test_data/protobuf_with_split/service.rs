@@ -1,5 +1,5 @@
 pub mod service {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod service {
         include!("service/mod.rs");
@@ -0,0 +1,18 @@
+namespace rs registry
+
+struct Doc {
+    1: required i64 id,
+    2: optional string title,
+}
+
+struct Page {
+    1: required list<Doc> docs,
+}
+
+exception NotFound {
+    1: required string message,
+}
+
+service DocService {
+    Page List(1: i64 limit) throws (1: NotFound err),
+}
@@ -0,0 +1,8 @@
+include "common.thrift"
+
+namespace rs checkout
+
+struct Order {
+    1: required common.Money total,
+    2: optional list<common.Money> refunds,
+}
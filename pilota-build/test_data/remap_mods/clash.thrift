@@ -0,0 +1,12 @@
+include "common.thrift"
+
+namespace rs checkout
+
+struct Money {
+    1: required i64 cents,
+}
+
+struct Order {
+    1: required common.Money total,
+    2: required Money tip,
+}
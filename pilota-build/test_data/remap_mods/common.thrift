@@ -0,0 +1,6 @@
+namespace rs a.b.c
+
+struct Money {
+    1: required i64 cents,
+    2: required string currency,
+}
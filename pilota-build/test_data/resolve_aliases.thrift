@@ -0,0 +1,7 @@
+typedef map<string, i32> Scores
+typedef Scores Board
+typedef list<Board> Boards
+
+struct Player {
+    1: required Boards boards,
+}
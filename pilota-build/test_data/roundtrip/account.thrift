@@ -0,0 +1,19 @@
+struct Account {
+    1: required i64 id,
+    2: required string name,
+    3: optional list<string> tags,
+    4: required Address address,
+}
+
+struct Address {
+    1: required string city = "Paris",
+    2: optional i32 zip,
+}
+
+exception AccountNotFound {
+    1: required i64 id,
+}
+
+service AccountService {
+    Account get(1: i64 id) throws (1: AccountNotFound err),
+}
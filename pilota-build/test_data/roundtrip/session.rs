@@ -0,0 +1,190 @@
+pub mod session {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod session {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Session {
+            pub token: ::pilota::FastStr,
+
+            pub expires_at: ::std::option::Option<i64>,
+
+            pub scopes: ::std::vec::Vec<::pilota::FastStr>,
+
+            pub device: Device,
+        }
+        impl ::pilota::prost::Message for Session {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::faststr::encoded_len(1, &self.token)
+                    + self.expires_at.as_ref().map_or(0, |value| {
+                        ::pilota::prost::encoding::int64::encoded_len(2, value)
+                    })
+                    + ::pilota::prost::encoding::faststr::encoded_len_repeated(3, &self.scopes)
+                    + ::pilota::prost::encoding::message::encoded_len(4, &self.device)
+            }
+
+            fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {
+                ("Session", &[(1, "token"), (4, "device")])
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::faststr::encode(1, &self.token, buf);
+                if let Some(_pilota_inner_value) = self.expires_at.as_ref() {
+                    ::pilota::prost::encoding::int64::encode(2, _pilota_inner_value, buf);
+                };
+                ::pilota::prost::encoding::faststr::encode_repeated(3, &self.scopes, buf);
+                ::pilota::prost::encoding::message::encode(4, (&self.device), buf);
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Session);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.token;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(token));
+                            error
+                        })
+                    }
+                    2 => {
+                        let mut _inner_pilota_value = &mut self.expires_at;
+                        ::pilota::prost::encoding::int64::merge(
+                            wire_type,
+                            _inner_pilota_value
+                                .get_or_insert_with(::core::default::Default::default),
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(expires_at));
+                            error
+                        })
+                    }
+                    3 => {
+                        let mut _inner_pilota_value = &mut self.scopes;
+                        ::pilota::prost::encoding::faststr::merge_repeated(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(scopes));
+                            error
+                        })
+                    }
+                    4 => {
+                        let mut _inner_pilota_value = &mut self.device;
+                        ::pilota::prost::encoding::message::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(device));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+
+        #[cfg(test)]
+        mod session_roundtrip {
+            use ::pilota::prost::Message as _;
+
+            #[test]
+            fn roundtrip() {
+                let msg = <super::Session as ::std::default::Default>::default();
+                let decoded = super::Session::decode(&*msg.encode_to_vec()).unwrap();
+                assert_eq!(decoded, msg);
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Device {
+            pub id: ::pilota::FastStr,
+        }
+        impl ::pilota::prost::Message for Device {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::faststr::encoded_len(1, &self.id)
+            }
+
+            fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {
+                ("Device", &[(1, "id")])
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::faststr::encode(1, &self.id, buf);
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Device);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.id;
+                        ::pilota::prost::encoding::faststr::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(id));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+
+        #[cfg(test)]
+        mod device_roundtrip {
+            use ::pilota::prost::Message as _;
+
+            #[test]
+            fn roundtrip() {
+                let msg = <super::Device as ::std::default::Default>::default();
+                let decoded = super::Device::decode(&*msg.encode_to_vec()).unwrap();
+                assert_eq!(decoded, msg);
+            }
+        }
+    }
+}
@@ -0,0 +1,815 @@
+pub mod catalog {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod catalog {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Kind(i32);
+
+        impl Kind {
+            pub const BOOK: Self = Self(1);
+            pub const MUSIC: Self = Self(2);
+
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+
+            pub fn to_string(&self) -> ::std::string::String {
+                match self {
+                    Self(1) => ::std::string::String::from("BOOK"),
+                    Self(2) => ::std::string::String::from("MUSIC"),
+                    Self(val) => val.to_string(),
+                }
+            }
+        }
+
+        impl ::std::convert::From<i32> for Kind {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::std::convert::From<Kind> for i32 {
+            fn from(value: Kind) -> i32 {
+                value.0
+            }
+        }
+
+        impl ::pilota::thrift::Message for Kind {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_i32(self.inner())?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let value = __protocol.read_i32()?;
+                ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                    |err| {
+                        ::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            format!("invalid enum value for Kind, value: {}", value),
+                        )
+                    },
+                )?)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let value = __protocol.read_i32().await?;
+                    ::std::result::Result::Ok(::std::convert::TryFrom::try_from(value).map_err(
+                        |err| {
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                format!("invalid enum value for Kind, value: {}", value),
+                            )
+                        },
+                    )?)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.i32_len(self.inner())
+            }
+        }
+        impl ::pilota::schema::Schema for Product {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "Product",
+                    fields: &[
+                        ::pilota::schema::FieldSchema {
+                            name: "name",
+                            id: 1,
+                            ty: ::pilota::schema::TypeSchema::String,
+                            required: true,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "kind",
+                            id: 2,
+                            ty: ::pilota::schema::TypeSchema::Enum("Kind"),
+                            required: false,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "index",
+                            id: 3,
+                            ty: ::pilota::schema::TypeSchema::Map(
+                                &::pilota::schema::TypeSchema::String,
+                                &::pilota::schema::TypeSchema::List(
+                                    &::pilota::schema::TypeSchema::I64,
+                                ),
+                            ),
+                            required: true,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "related",
+                            id: 4,
+                            ty: ::pilota::schema::TypeSchema::List(
+                                &::pilota::schema::TypeSchema::Message(
+                                    <Product as ::pilota::schema::Schema>::schema,
+                                ),
+                            ),
+                            required: false,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "media",
+                            id: 5,
+                            ty: ::pilota::schema::TypeSchema::Message(
+                                <Media as ::pilota::schema::Schema>::schema,
+                            ),
+                            required: false,
+                        },
+                    ],
+                };
+                &SCHEMA
+            }
+        }
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct Product {
+            pub name: ::pilota::FastStr,
+
+            pub kind: ::std::option::Option<Kind>,
+
+            pub index: Index,
+
+            pub related: ::std::option::Option<::std::vec::Vec<Product>>,
+
+            pub media: ::std::option::Option<Media>,
+        }
+        impl ::pilota::thrift::Message for Product {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Product" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_faststr_field(1, (&self.name).clone())?;
+                if let Some(value) = self.kind.as_ref() {
+                    __protocol.write_i32_field(2, (value).inner())?;
+                }
+                __protocol.write_struct_field(3, &self.index, ::pilota::thrift::TType::Map)?;
+                if let Some(value) = self.related.as_ref() {
+                    __protocol.write_list_field(
+                        4,
+                        ::pilota::thrift::TType::Struct,
+                        &value,
+                        |__protocol, val| {
+                            __protocol.write_struct(val)?;
+                            ::std::result::Result::Ok(())
+                        },
+                    )?;
+                }
+                if let Some(value) = self.media.as_ref() {
+                    __protocol.write_struct_field(5, value, ::pilota::thrift::TType::Struct)?;
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+                let mut var_5 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_1 = Some(__protocol.read_faststr()?);
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_2 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::Map => {
+                                var_3 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            Some(4) if field_ident.field_type == ::pilota::thrift::TType::List => {
+                                var_4 = Some(unsafe {
+                                    let list_ident = __protocol.read_list_begin()?;
+                                    let mut val: ::std::vec::Vec<Product> =
+                                        ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    val.set_len(list_ident.size);
+                                    __protocol.read_list_end()?;
+                                    val
+                                });
+                            }
+                            Some(5)
+                                if field_ident.field_type == ::pilota::thrift::TType::Struct =>
+                            {
+                                var_5 = Some(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Product",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("name"),
+                                2 => ::std::option::Option::Some("kind"),
+                                3 => ::std::option::Option::Some("index"),
+                                4 => ::std::option::Option::Some("related"),
+                                5 => ::std::option::Option::Some("media"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name is required".to_string(),
+                    ));
+                };
+                let Some(var_3) = var_3 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field index is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    name: var_1,
+                    kind: var_2,
+                    index: var_3,
+                    related: var_4,
+                    media: var_5,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+                    let mut var_5 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                    loop {
+
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_1 = Some(__protocol.read_faststr().await?);
+
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::I32  => {
+                    var_2 = Some(<Kind as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+                    var_3 = Some(<Index as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },Some(4) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_4 = Some({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(<Product as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+
+                },Some(5) if field_ident.field_type == ::pilota::thrift::TType::Struct  => {
+                    var_5 = Some(<Media as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+
+                },
+                    _ => {
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Product",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("name"),2 => ::std::option::Option::Some("kind"),3 => ::std::option::Option::Some("index"),4 => ::std::option::Option::Some("related"),5 => ::std::option::Option::Some("media"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_3) = var_3 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field index is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        name: var_1,
+                        kind: var_2,
+                        index: var_3,
+                        related: var_4,
+                        media: var_5,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol
+                    .struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Product" })
+                    + __protocol.faststr_field_len(Some(1), &self.name)
+                    + self.kind.as_ref().map_or(0, |value| {
+                        __protocol.i32_field_len(Some(2), (value).inner())
+                    })
+                    + __protocol.struct_field_len(Some(3), &self.index)
+                    + self.related.as_ref().map_or(0, |value| {
+                        __protocol.list_field_len(
+                            Some(4),
+                            ::pilota::thrift::TType::Struct,
+                            value,
+                            |__protocol, el| __protocol.struct_len(el),
+                        )
+                    })
+                    + self
+                        .media
+                        .as_ref()
+                        .map_or(0, |value| __protocol.struct_field_len(Some(5), value))
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        impl ::std::default::Default for Media {
+            fn default() -> Self {
+                Media::Url(::std::default::Default::default())
+            }
+        }
+
+        impl ::pilota::schema::Schema for Media {
+            fn schema() -> &'static ::pilota::schema::MessageSchema {
+                static SCHEMA: ::pilota::schema::MessageSchema = ::pilota::schema::MessageSchema {
+                    name: "Media",
+                    fields: &[
+                        ::pilota::schema::FieldSchema {
+                            name: "url",
+                            id: 1,
+                            ty: ::pilota::schema::TypeSchema::String,
+                            required: false,
+                        },
+                        ::pilota::schema::FieldSchema {
+                            name: "data",
+                            id: 2,
+                            ty: ::pilota::schema::TypeSchema::Bytes,
+                            required: false,
+                        },
+                    ],
+                };
+                &SCHEMA
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Clone, PartialEq)]
+        pub enum Media {
+            Url(::pilota::FastStr),
+
+            Data(::pilota::Bytes),
+        }
+
+        impl ::pilota::thrift::Message for Media {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol
+                    .write_struct_begin(&::pilota::thrift::TStructIdentifier { name: "Media" })?;
+                match self {
+                    Media::Url(ref value) => {
+                        __protocol.write_faststr_field(1, (value).clone())?;
+                    }
+                    Media::Data(ref value) => {
+                        __protocol.write_bytes_field(2, (value).clone())?;
+                    }
+                }
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                let mut ret = None;
+                __protocol.read_struct_begin()?;
+                loop {
+                    let field_ident = __protocol.read_field_begin()?;
+                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                        __protocol.field_stop_len();
+                        break;
+                    } else {
+                        __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                    }
+                    match field_ident.id {
+                        Some(1) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_faststr()?;
+                                __protocol.faststr_len(&field_ident);
+                                ret = Some(Media::Url(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        Some(2) => {
+                            if ret.is_none() {
+                                let field_ident = __protocol.read_bytes()?;
+                                __protocol.bytes_len(&field_ident);
+                                ret = Some(Media::Data(field_ident));
+                            } else {
+                                return ::std::result::Result::Err(
+                                    ::pilota::thrift::new_protocol_exception(
+                                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                        "received multiple fields for union from remote Message",
+                                    ),
+                                );
+                            }
+                        }
+                        _ => {
+                            __protocol.skip(field_ident.field_type)?;
+                        }
+                    }
+                }
+                __protocol.read_field_end()?;
+                __protocol.read_struct_end()?;
+                if let Some(ret) = ret {
+                    ::std::result::Result::Ok(ret)
+                } else {
+                    ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "received empty union from remote Message",
+                    ))
+                }
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut ret = None;
+                    __protocol.read_struct_begin().await?;
+                    loop {
+                        let field_ident = __protocol.read_field_begin().await?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            break;
+                        } else {
+                        }
+                        match field_ident.id {
+                            Some(1) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_faststr().await?;
+
+                                    ret = Some(Media::Url(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            Some(2) => {
+                                if ret.is_none() {
+                                    let field_ident = __protocol.read_bytes().await?;
+
+                                    ret = Some(Media::Data(field_ident));
+                                } else {
+                                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                            "received multiple fields for union from remote Message"
+                                        ));
+                                }
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type).await?;
+                            }
+                        }
+                    }
+                    __protocol.read_field_end().await?;
+                    __protocol.read_struct_end().await?;
+                    if let Some(ret) = ret {
+                        ::std::result::Result::Ok(ret)
+                    } else {
+                        ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                            ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                            "received empty union from remote Message",
+                        ))
+                    }
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Media" })
+                    + match self {
+                        Media::Url(ref value) => __protocol.faststr_field_len(Some(1), value),
+                        Media::Data(ref value) => __protocol.bytes_field_len(Some(2), value),
+                    }
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct Index(pub ::pilota::AHashMap<::pilota::FastStr, ::std::vec::Vec<i64>>);
+
+        impl ::std::ops::Deref for Index {
+            type Target = ::pilota::AHashMap<::pilota::FastStr, ::std::vec::Vec<i64>>;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl From<::pilota::AHashMap<::pilota::FastStr, ::std::vec::Vec<i64>>> for Index {
+            fn from(v: ::pilota::AHashMap<::pilota::FastStr, ::std::vec::Vec<i64>>) -> Self {
+                Self(v)
+            }
+        }
+
+        impl ::pilota::thrift::Message for Index {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                __protocol.write_map(
+                    ::pilota::thrift::TType::Binary,
+                    ::pilota::thrift::TType::List,
+                    &(&**self),
+                    |__protocol, key| {
+                        __protocol.write_faststr((key).clone())?;
+                        ::std::result::Result::Ok(())
+                    },
+                    |__protocol, val| {
+                        __protocol.write_list(
+                            ::pilota::thrift::TType::I64,
+                            &val,
+                            |__protocol, val| {
+                                __protocol.write_i64(*val)?;
+                                ::std::result::Result::Ok(())
+                            },
+                        )?;
+                        ::std::result::Result::Ok(())
+                    },
+                )?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+                ::std::result::Result::Ok(Index({
+                    let map_ident = __protocol.read_map_begin()?;
+                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                    let mut __pilota_decoding_index = 0;
+                    if let ::std::result::Result::Err(mut err) = (|| {
+                        for i in 0..map_ident.size {
+                            __pilota_decoding_index = i;
+                            val.insert(__protocol.read_faststr()?, unsafe {
+                            let list_ident = __protocol.read_list_begin()?;
+                            let mut val: ::std::vec::Vec<i64> = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.as_mut_ptr().offset(i as isize).write(__protocol.read_i64()?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            val.set_len(list_ident.size);
+                            __protocol.read_list_end()?;
+                            val
+                        });
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    })() {
+                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                            __pilota_decoding_index,
+                        ));
+                        return ::std::result::Result::Err(err);
+                    }
+                    __protocol.read_map_end()?;
+                    val
+                }))
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    ::std::result::Result::Ok(Index({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+                        if let ::std::result::Result::Err(mut err) = async {
+                            for i in 0..map_ident.size {
+                                __pilota_decoding_index = i;
+                                val.insert(__protocol.read_faststr().await?, {
+                                    let list_ident = __protocol.read_list_begin().await?;
+                                    let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = async {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.push(__protocol.read_i64().await?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    }
+                                    .await
+                                    {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    __protocol.read_list_end().await?;
+                                    val
+                                });
+                            }
+                            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                        }
+                        .await
+                        {
+                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                __pilota_decoding_index,
+                            ));
+                            return ::std::result::Result::Err(err);
+                        }
+                        __protocol.read_map_end().await?;
+                        val
+                    }))
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.map_len(
+                    ::pilota::thrift::TType::Binary,
+                    ::pilota::thrift::TType::List,
+                    &**self,
+                    |__protocol, key| __protocol.faststr_len(key),
+                    |__protocol, val| {
+                        __protocol.list_len(::pilota::thrift::TType::I64, val, |__protocol, el| {
+                            __protocol.i64_len(*el)
+                        })
+                    },
+                )
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+enum Kind {
+    BOOK = 1,
+    MUSIC = 2,
+}
+
+typedef map<string, list<i64>> Index
+
+union Media {
+    1: string url,
+    2: binary data,
+}
+
+struct Product {
+    1: required string name,
+    2: optional Kind kind,
+    3: required Index index,
+    4: optional list<Product> related,
+    5: optional Media media,
+}
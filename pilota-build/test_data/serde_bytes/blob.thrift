@@ -0,0 +1,6 @@
+struct Blob {
+    1: required binary data,
+    2: optional binary checksum,
+    3: required binary digest(pilota.serde_bytes = "hex"),
+    4: optional binary raw(pilota.serde_bytes = "raw"),
+}
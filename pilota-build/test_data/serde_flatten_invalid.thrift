@@ -0,0 +1,3 @@
+struct A {
+    1: required list<string> values(pilota.serde_flatten = "true"),
+}
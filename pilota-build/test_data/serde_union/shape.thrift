@@ -0,0 +1,13 @@
+struct Circle {
+    1: required double radius,
+}
+
+struct Rect {
+    1: required i32 width,
+    2: required i32 height,
+}
+
+union Shape {
+    1: Circle circle,
+    2: Rect rect,
+}
@@ -0,0 +1,13 @@
+exception NotFound {
+    1: required string name,
+}
+
+struct Item {
+    1: required string name,
+}
+
+service Store {
+    Item get(1: string name) throws (1: NotFound not_found),
+    void put(1: Item item),
+    oneway void ping(),
+}
@@ -0,0 +1,135 @@
+pub mod streaming {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod streaming {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Chunk {
+            pub data: ::pilota::Bytes,
+        }
+        impl ::pilota::prost::Message for Chunk {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::bytes::encoded_len(1, &self.data)
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::bytes::encode(1, &self.data, buf);
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Chunk);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.data;
+                        ::pilota::prost::encoding::bytes::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(data));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Summary {
+            pub size: i64,
+        }
+        impl ::pilota::prost::Message for Summary {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::int64::encoded_len(1, &self.size)
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::int64::encode(1, &self.size, buf);
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Summary);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.size;
+                        ::pilota::prost::encoding::int64::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(size));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+
+        pub trait Transfer {
+            fn get(
+                &self,
+                req: Summary,
+            ) -> impl ::std::future::Future<
+                Output = ::std::result::Result<Chunk, crate::test::tests::RpcError>,
+            > + Send;
+            fn upload(
+                &self,
+                req: crate::test::tests::Streaming<Chunk>,
+            ) -> impl ::std::future::Future<
+                Output = ::std::result::Result<Summary, crate::test::tests::RpcError>,
+            > + Send;
+            fn download(
+                &self,
+                req: Summary,
+            ) -> impl ::std::future::Future<
+                Output = ::std::result::Result<
+                    crate::test::tests::Streaming<Chunk>,
+                    crate::test::tests::RpcError,
+                >,
+            > + Send;
+            fn sync(
+                &self,
+                req: crate::test::tests::Streaming<Chunk>,
+            ) -> impl ::std::future::Future<
+                Output = ::std::result::Result<
+                    crate::test::tests::Streaming<Chunk>,
+                    crate::test::tests::RpcError,
+                >,
+            > + Send;
+        }
+    }
+}
@@ -0,0 +1,19 @@
+struct Request {
+    1: required string message,
+}
+
+struct Response {
+    1: required string message,
+}
+
+exception EchoError {
+    1: required string reason,
+}
+
+struct Standalone {
+    1: required i32 id,
+}
+
+service Echo {
+    Response echo(1: Request req) throws (1: EchoError err),
+}
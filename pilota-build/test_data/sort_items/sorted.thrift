@@ -0,0 +1,28 @@
+namespace rs sorted
+
+const i32 MAX_ITEMS = 10
+
+struct Zoo {
+    1: required list<Animal> animals,
+    2: optional Keeper keeper,
+}
+
+enum Kind {
+    CAT,
+    DOG,
+}
+
+struct Animal {
+    1: required Kind kind,
+    2: required string name,
+}
+
+typedef Zoo Park
+
+struct Keeper {
+    1: required string name,
+}
+
+service ZooService {
+    Zoo get(1: i32 id),
+}
@@ -0,0 +1,19 @@
+typedef string Tag
+
+enum Kind {
+    ALPHA = 1,
+    BETA = 2,
+}
+
+union Payload {
+    1: string text,
+    2: binary raw,
+}
+
+struct Fixture {
+    1: required string name,
+    2: required map<string, list<i64>> counts,
+    3: required set<Tag> tags,
+    4: required Kind kind,
+    5: optional Payload payload,
+}
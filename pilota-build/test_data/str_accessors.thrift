@@ -0,0 +1,12 @@
+typedef string Email
+
+struct Profile {
+    1: required string name,
+    2: optional string nickname,
+    3: required Email email,
+    4: optional Email backup_email,
+    5: required binary avatar,
+    6: optional binary thumbnail (pilota.rust_type = "vec"),
+    7: required list<string> tags,
+    8: required i32 age,
+}
@@ -0,0 +1,12 @@
+enum Color {
+    Red = 1,
+    Green = 2,
+}
+
+struct Palette {
+    1: required string name,
+    2: optional Color primary,
+    3: optional list<i32> shades,
+    4: optional set<string> labels,
+    5: optional map<string, Color> named,
+}
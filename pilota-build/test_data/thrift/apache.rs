@@ -1,5 +1,5 @@
 pub mod apache {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod apache {
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
@@ -257,8 +257,22 @@ pub mod apache {
                                     let list_ident = __protocol.read_set_begin()?;
                                     let mut val =
                                         ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_faststr()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_faststr()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_set_end()?;
                                     val
@@ -276,10 +290,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<i32> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -297,10 +325,23 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `LargeDeltas` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "LargeDeltas",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("b1"),
+                                10 => ::std::option::Option::Some("b10"),
+                                100 => ::std::option::Option::Some("b100"),
+                                500 => ::std::option::Option::Some("check_true"),
+                                1000 => ::std::option::Option::Some("b1000"),
+                                1500 => ::std::option::Option::Some("check_false"),
+                                2000 => ::std::option::Option::Some("vertwo2000"),
+                                2500 => ::std::option::Option::Some("a_set2500"),
+                                3000 => ::std::option::Option::Some("vertwo3000"),
+                                4000 => ::std::option::Option::Some("big_numbers"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -383,9 +424,17 @@ pub mod apache {
                 },Some(2500) if field_ident.field_type == ::pilota::thrift::TType::Set  => {
                     var_2500 = Some({let list_ident = __protocol.read_set_begin().await?;
                     let mut val = ::pilota::AHashSet::with_capacity(list_ident.size);
-                    for _ in 0..list_ident.size {
-                        val.insert(__protocol.read_faststr().await?);
-                    };
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_faststr().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                     __protocol.read_set_end().await?;
                     val});
 
@@ -396,9 +445,17 @@ pub mod apache {
                     var_4000 = Some({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push(__protocol.read_i32().await?);
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
@@ -417,7 +474,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `LargeDeltas` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "LargeDeltas",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("b1"),10 => ::std::option::Option::Some("b10"),100 => ::std::option::Option::Some("b100"),500 => ::std::option::Option::Some("check_true"),1000 => ::std::option::Option::Some("b1000"),1500 => ::std::option::Option::Some("check_false"),2000 => ::std::option::Option::Some("vertwo2000"),2500 => ::std::option::Option::Some("a_set2500"),3000 => ::std::option::Option::Some("vertwo3000"),4000 => ::std::option::Option::Some("big_numbers"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -554,7 +618,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestI32ArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestI32ArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -613,7 +684,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestI32ArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestI32ArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -700,8 +778,22 @@ pub mod apache {
                                     let list_ident = __protocol.read_set_begin()?;
                                     let mut val =
                                         ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_set_end()?;
                                     val
@@ -718,7 +810,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestSetArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestSetArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -767,8 +866,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_set_begin().await?;
                                         let mut val =
                                             ::pilota::AHashSet::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.insert(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_set_end().await?;
                                         val
@@ -786,7 +900,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestSetArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestSetArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1059,7 +1180,13 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestVoidArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestVoidArgsSend",
+                            name: match field_id {
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -1104,7 +1231,13 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestVoidArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestVoidArgsSend",
+                                name: match field_id {
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1184,7 +1317,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestStructArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestStructArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -1249,7 +1389,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestStructArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestStructArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1501,10 +1648,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `BoolTest` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "BoolTest",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("b"),
+                                2 => ::std::option::Option::Some("s"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -1563,10 +1715,15 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `BoolTest` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "BoolTest",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("b"),
+                                    2 => ::std::option::Option::Some("s"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1652,7 +1809,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestI64ArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestI64ArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -1711,7 +1875,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestI64ArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestI64ArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -2049,7 +2220,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestStringArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestStringArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -2109,7 +2287,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestStringArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestStringArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -2203,8 +2388,25 @@ pub mod apache {
                                 let field_ident = {
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, __protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_i32()?,
+                                                __protocol.read_i32()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -2269,11 +2471,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_i32().await?,
-                                                __protocol.read_i32().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_i32().await?,
+                                                    __protocol.read_i32().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -2410,10 +2627,17 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Xtruct3` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Xtruct3",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("string_thing"),
+                                4 => ::std::option::Option::Some("changed"),
+                                9 => ::std::option::Option::Some("i32_thing"),
+                                11 => ::std::option::Option::Some("i64_thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -2489,10 +2713,17 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Xtruct3` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Xtruct3",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("string_thing"),
+                                    4 => ::std::option::Option::Some("changed"),
+                                    9 => ::std::option::Option::Some("i32_thing"),
+                                    11 => ::std::option::Option::Some("i64_thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -3016,7 +3247,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestExceptionArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestExceptionArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("arg"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -3076,7 +3314,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestExceptionArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestExceptionArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("arg"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -3179,27 +3424,48 @@ pub mod apache {
                     match field_ident.id {
                         Some(0) => {
                             if ret.is_none() {
-                                let field_ident = {
-                                    let map_ident = __protocol.read_map_begin()?;
-                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, {
-                                            let map_ident = __protocol.read_map_begin()?;
-                                            let mut val =
-                                                ::pilota::AHashMap::with_capacity(map_ident.size);
-                                            for _ in 0..map_ident.size {
-                                                val.insert(
-                                                    __protocol.read_i32()?,
-                                                    __protocol.read_i32()?,
-                                                );
-                                            }
-                                            __protocol.read_map_end()?;
-                                            val
-                                        });
-                                    }
-                                    __protocol.read_map_end()?;
-                                    val
-                                };
+                                let field_ident =
+                                    {
+                                        let map_ident = __protocol.read_map_begin()?;
+                                        let mut val =
+                                            ::pilota::AHashMap::with_capacity(map_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) =
+                                            (|| {
+                                                for i in 0..map_ident.size {
+                                                    __pilota_decoding_index = i;
+                                                    val.insert(__protocol.read_i32()?, {
+                        let map_ident = __protocol.read_map_begin()?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32()?, __protocol.read_i32()?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end()?;
+                        val
+                    });
+                                                }
+                                                ::std::result::Result::Ok::<
+                                                    _,
+                                                    ::pilota::thrift::ThriftException,
+                                                >(())
+                                            })()
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        __protocol.read_map_end()?;
+                                        val
+                                    };
                                 __protocol.map_len(
                                     ::pilota::thrift::TType::I32,
                                     ::pilota::thrift::TType::Map,
@@ -3268,21 +3534,40 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(__protocol.read_i32().await?, {
-                                                let map_ident = __protocol.read_map_begin().await?;
-                                                let mut val = ::pilota::AHashMap::with_capacity(
-                                                    map_ident.size,
-                                                );
-                                                for _ in 0..map_ident.size {
-                                                    val.insert(
-                                                        __protocol.read_i32().await?,
-                                                        __protocol.read_i32().await?,
-                                                    );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) =
+                                            async {
+                                                for i in 0..map_ident.size {
+                                                    __pilota_decoding_index = i;
+                                                    val.insert(__protocol.read_i32().await?, {
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?, __protocol.read_i32().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
                                                 }
-                                                __protocol.read_map_end().await?;
-                                                val
-                                            });
+                                                ::std::result::Result::Ok::<
+                                                    _,
+                                                    ::pilota::thrift::ThriftException,
+                                                >(())
+                                            }
+                                            .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -3405,10 +3690,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<i32> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -3431,10 +3730,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ListTypeVersioningV1` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ListTypeVersioningV1",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("myints"),
+                                2 => ::std::option::Option::Some("hello"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -3480,8 +3784,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_list_begin().await?;
                                         let mut val =
                                             ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_list_end().await?;
                                         val
@@ -3505,7 +3824,15 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ListTypeVersioningV1` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ListTypeVersioningV1",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("myints"),
+                                    2 => ::std::option::Option::Some("hello"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -3599,8 +3926,22 @@ pub mod apache {
                                     let list_ident = __protocol.read_set_begin()?;
                                     let mut val =
                                         ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_set_end()?;
                                     val
@@ -3663,8 +4004,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_set_begin().await?;
                                         let mut val =
                                             ::pilota::AHashSet::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.insert(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_set_end().await?;
                                         val
@@ -3985,17 +4341,52 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<::pilota::AHashSet<i32>> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write({
-                                            let list_ident = __protocol.read_set_begin()?;
-                                            let mut val =
-                                                ::pilota::AHashSet::with_capacity(list_ident.size);
-                                            for _ in 0..list_ident.size {
-                                                val.insert(__protocol.read_i32()?);
-                                            }
-                                            __protocol.read_set_end()?;
-                                            val
-                                        });
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write({
+                                                let list_ident = __protocol.read_set_begin()?;
+                                                let mut val = ::pilota::AHashSet::with_capacity(
+                                                    list_ident.size,
+                                                );
+                                                let mut __pilota_decoding_index = 0;
+                                                if let ::std::result::Result::Err(mut err) =
+                                                    (|| {
+                                                        for i in 0..list_ident.size {
+                                                            __pilota_decoding_index = i;
+                                                            val.insert(__protocol.read_i32()?);
+                                                        }
+                                                        ::std::result::Result::Ok::<
+                                                            _,
+                                                            ::pilota::thrift::ThriftException,
+                                                        >(
+                                                            ()
+                                                        )
+                                                    })(
+                                                    )
+                                                {
+                                                    err.prepend_path(
+                                                        ::pilota::thrift::PathSegment::Index(
+                                                            __pilota_decoding_index,
+                                                        ),
+                                                    );
+                                                    return ::std::result::Result::Err(err);
+                                                }
+                                                __protocol.read_set_end()?;
+                                                val
+                                            });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -4006,17 +4397,52 @@ pub mod apache {
                                 var_2 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, {
-                                            let list_ident = __protocol.read_set_begin()?;
-                                            let mut val =
-                                                ::pilota::AHashSet::with_capacity(list_ident.size);
-                                            for _ in 0..list_ident.size {
-                                                val.insert(__protocol.read_faststr()?);
-                                            }
-                                            __protocol.read_set_end()?;
-                                            val
-                                        });
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?, {
+                                                let list_ident = __protocol.read_set_begin()?;
+                                                let mut val = ::pilota::AHashSet::with_capacity(
+                                                    list_ident.size,
+                                                );
+                                                let mut __pilota_decoding_index = 0;
+                                                if let ::std::result::Result::Err(mut err) =
+                                                    (|| {
+                                                        for i in 0..list_ident.size {
+                                                            __pilota_decoding_index = i;
+                                                            val.insert(__protocol.read_faststr()?);
+                                                        }
+                                                        ::std::result::Result::Ok::<
+                                                            _,
+                                                            ::pilota::thrift::ThriftException,
+                                                        >(
+                                                            ()
+                                                        )
+                                                    })(
+                                                    )
+                                                {
+                                                    err.prepend_path(
+                                                        ::pilota::thrift::PathSegment::Index(
+                                                            __pilota_decoding_index,
+                                                        ),
+                                                    );
+                                                    return ::std::result::Result::Err(err);
+                                                }
+                                                __protocol.read_set_end()?;
+                                                val
+                                            });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -4031,27 +4457,52 @@ pub mod apache {
                                             ::pilota::AHashSet<::pilota::FastStr>,
                                         >,
                                     > = ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write({
-                                            let map_ident = __protocol.read_map_begin()?;
-                                            let mut val =
-                                                ::pilota::AHashMap::with_capacity(map_ident.size);
-                                            for _ in 0..map_ident.size {
-                                                val.insert(__protocol.read_i32()?, {
-                                                    let list_ident = __protocol.read_set_begin()?;
-                                                    let mut val = ::pilota::AHashSet::with_capacity(
-                                                        list_ident.size,
-                                                    );
-                                                    for _ in 0..list_ident.size {
-                                                        val.insert(__protocol.read_faststr()?);
-                                                    }
-                                                    __protocol.read_set_end()?;
-                                                    val
-                                                });
-                                            }
-                                            __protocol.read_map_end()?;
-                                            val
-                                        });
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write({
+                        let map_ident = __protocol.read_map_begin()?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32()?, {let list_ident = __protocol.read_set_begin()?;
+                    let mut val = ::pilota::AHashSet::with_capacity(list_ident.size);
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_faststr()?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                    __protocol.read_set_end()?;
+                    val});
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end()?;
+                        val
+                    });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -4069,10 +4520,16 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `NestedMixedx2` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "NestedMixedx2",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("int_set_list"),
+                                2 => ::std::option::Option::Some("map_int_strset"),
+                                3 => ::std::option::Option::Some("map_int_strset_list"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -4105,121 +4562,158 @@ pub mod apache {
 
                     __protocol.read_struct_begin().await?;
                     if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
+                    loop {
+
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_1 = Some({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push({let list_ident = __protocol.read_set_begin().await?;
+                    let mut val = ::pilota::AHashSet::with_capacity(list_ident.size);
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                    __protocol.read_set_end().await?;
+                    val});
                             }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
-                                {
-                                    var_1 = Some({
-                                        let list_ident = __protocol.read_list_begin().await?;
-                                        let mut val =
-                                            ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push({
-                                                let list_ident =
-                                                    __protocol.read_set_begin().await?;
-                                                let mut val = ::pilota::AHashSet::with_capacity(
-                                                    list_ident.size,
-                                                );
-                                                for _ in 0..list_ident.size {
-                                                    val.insert(__protocol.read_i32().await?);
-                                                }
-                                                __protocol.read_set_end().await?;
-                                                val
-                                            });
-                                        }
-                                        __protocol.read_list_end().await?;
-                                        val
-                                    });
-                                }
-                                Some(2)
-                                    if field_ident.field_type == ::pilota::thrift::TType::Map =>
-                                {
-                                    var_2 = Some({
-                                        let map_ident = __protocol.read_map_begin().await?;
-                                        let mut val =
-                                            ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(__protocol.read_i32().await?, {
-                                                let list_ident =
-                                                    __protocol.read_set_begin().await?;
-                                                let mut val = ::pilota::AHashSet::with_capacity(
-                                                    list_ident.size,
-                                                );
-                                                for _ in 0..list_ident.size {
-                                                    val.insert(__protocol.read_faststr().await?);
-                                                }
-                                                __protocol.read_set_end().await?;
-                                                val
-                                            });
-                                        }
-                                        __protocol.read_map_end().await?;
-                                        val
-                                    });
-                                }
-                                Some(3)
-                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
-                                {
-                                    var_3 = Some({
-                                        let list_ident = __protocol.read_list_begin().await?;
-                                        let mut val =
-                                            ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push({
-                                                let map_ident = __protocol.read_map_begin().await?;
-                                                let mut val = ::pilota::AHashMap::with_capacity(
-                                                    map_ident.size,
-                                                );
-                                                for _ in 0..map_ident.size {
-                                                    val.insert(__protocol.read_i32().await?, {
-                                                        let list_ident =
-                                                            __protocol.read_set_begin().await?;
-                                                        let mut val =
-                                                            ::pilota::AHashSet::with_capacity(
-                                                                list_ident.size,
-                                                            );
-                                                        for _ in 0..list_ident.size {
-                                                            val.insert(
-                                                                __protocol.read_faststr().await?,
-                                                            );
-                                                        }
-                                                        __protocol.read_set_end().await?;
-                                                        val
-                                                    });
-                                                }
-                                                __protocol.read_map_end().await?;
-                                                val
-                                            });
-                                        }
-                                        __protocol.read_list_end().await?;
-                                        val
-                                    });
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+                    var_2 = Some({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?, {let list_ident = __protocol.read_set_begin().await?;
+                    let mut val = ::pilota::AHashSet::with_capacity(list_ident.size);
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_faststr().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                    __protocol.read_set_end().await?;
+                    val});
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
+
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_3 = Some({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?, {let list_ident = __protocol.read_set_begin().await?;
+                    let mut val = ::pilota::AHashSet::with_capacity(list_ident.size);
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_faststr().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                    __protocol.read_set_end().await?;
+                    val});
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
                             }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
 
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `NestedMixedx2` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
+                },
+                    _ => {
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "NestedMixedx2",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("int_set_list"),2 => ::std::option::Option::Some("map_int_strset"),3 => ::std::option::Option::Some("map_int_strset_list"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
                     __protocol.read_struct_end().await?;
 
                     let data = Self {
@@ -4498,7 +4992,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestMultiExceptionArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestMultiExceptionArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("arg0"),
+                                2 => ::std::option::Option::Some("arg1"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -4574,7 +5076,15 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestMultiExceptionArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestMultiExceptionArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("arg0"),
+                                    2 => ::std::option::Option::Some("arg1"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -4846,11 +5356,25 @@ pub mod apache {
                                 var_4 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_i16()?,
-                                            __protocol.read_faststr()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_i16()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -4873,7 +5397,19 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestMultiArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestMultiArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("arg0"),
+                                2 => ::std::option::Option::Some("arg1"),
+                                3 => ::std::option::Option::Some("arg2"),
+                                4 => ::std::option::Option::Some("arg3"),
+                                5 => ::std::option::Option::Some("arg4"),
+                                6 => ::std::option::Option::Some("arg5"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -4979,11 +5515,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_i16().await?,
-                                                __protocol.read_faststr().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_i16().await?,
+                                                    __protocol.read_faststr().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -5021,7 +5572,19 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestMultiArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestMultiArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("arg0"),
+                                    2 => ::std::option::Option::Some("arg1"),
+                                    3 => ::std::option::Option::Some("arg2"),
+                                    4 => ::std::option::Option::Some("arg3"),
+                                    5 => ::std::option::Option::Some("arg4"),
+                                    6 => ::std::option::Option::Some("arg5"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -5177,10 +5740,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Bools` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Bools",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("im_true"),
+                                2 => ::std::option::Option::Some("im_false"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -5241,10 +5809,15 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Bools` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Bools",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("im_true"),
+                                    2 => ::std::option::Option::Some("im_false"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -5348,11 +5921,25 @@ pub mod apache {
                                 var_2 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_bytes()?,
-                                            __protocol.read_i32()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_bytes()?,
+                                                __protocol.read_i32()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -5369,10 +5956,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `OptionalBinary` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "OptionalBinary",
+                            name: match field_id {
+                                2 => ::std::option::Option::Some("bin_map"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -5422,11 +6013,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_bytes().await?,
-                                                __protocol.read_i32().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_bytes().await?,
+                                                    __protocol.read_i32().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -5444,10 +6050,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `OptionalBinary` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "OptionalBinary",
+                                name: match field_id {
+                                    2 => ::std::option::Option::Some("bin_map"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -5542,7 +6152,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestEnumArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestEnumArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -5606,7 +6223,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestEnumArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestEnumArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -5698,8 +6322,25 @@ pub mod apache {
                                 var_1 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, __protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_i32()?,
+                                                __protocol.read_i32()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -5716,7 +6357,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestMapArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestMapArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -5765,11 +6413,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_i32().await?,
-                                                __protocol.read_i32().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_i32().await?,
+                                                    __protocol.read_i32().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -5787,7 +6450,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestMapArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestMapArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -5883,7 +6553,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestBinaryArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestBinaryArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -5943,7 +6620,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestBinaryArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestBinaryArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -6031,7 +6715,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestTypedefArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestTypedefArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -6095,7 +6786,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestTypedefArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestTypedefArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -6184,7 +6882,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestByteArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestByteArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -6243,7 +6948,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestByteArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestByteArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -6334,11 +7046,25 @@ pub mod apache {
                                 var_1 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_faststr()?,
-                                            __protocol.read_faststr()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -6355,7 +7081,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestStringMapArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestStringMapArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -6404,11 +7137,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_faststr().await?,
-                                                __protocol.read_faststr().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_faststr().await?,
+                                                    __protocol.read_faststr().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -6426,7 +7174,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestStringMapArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestStringMapArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -6513,10 +7268,13 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `EmptyStruct` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "EmptyStruct",
+                            name: match field_id {
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -6561,10 +7319,13 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `EmptyStruct` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "EmptyStruct",
+                                name: match field_id {
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -6642,7 +7403,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestUuidArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestUuidArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -6701,7 +7469,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestUuidArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestUuidArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -6805,30 +7580,40 @@ pub mod apache {
                         Some(0) => {
                             if ret.is_none() {
                                 let field_ident = {
-                                    let map_ident = __protocol.read_map_begin()?;
-                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            ::pilota::thrift::Message::decode(__protocol)?,
-                                            {
-                                                let map_ident = __protocol.read_map_begin()?;
-                                                let mut val = ::pilota::AHashMap::with_capacity(
-                                                    map_ident.size,
-                                                );
-                                                for _ in 0..map_ident.size {
-                                                    val.insert(
-                                                        ::pilota::thrift::Message::decode(
-                                                            __protocol,
-                                                        )?,
-                                                        ::pilota::thrift::Message::decode(
-                                                            __protocol,
-                                                        )?,
-                                                    );
-                                                }
-                                                __protocol.read_map_end()?;
-                                                val
-                                            },
-                                        );
+                                    let map_ident = __protocol.read_map_begin()?;
+                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(::pilota::thrift::Message::decode(__protocol)?, {
+                        let map_ident = __protocol.read_map_begin()?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(::pilota::thrift::Message::decode(__protocol)?, ::pilota::thrift::Message::decode(__protocol)?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end()?;
+                        val
+                    });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -6901,17 +7686,33 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(<UserId as ::pilota::thrift::Message>::decode_async(__protocol).await?, {
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(<UserId as ::pilota::thrift::Message>::decode_async(__protocol).await?, {
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                        for _ in 0..map_ident.size {
-                            val.insert(<Numberz as ::pilota::thrift::Message>::decode_async(__protocol).await?, <Insanity as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                        }
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(<Numberz as ::pilota::thrift::Message>::decode_async(__protocol).await?, <Insanity as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end().await?;
                         val
                     });
-                                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                                         __protocol.read_map_end().await?;
                                         val
                                     };
@@ -7034,20 +7835,54 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<::std::vec::Vec<i32>> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(unsafe {
-                                            let list_ident = __protocol.read_list_begin()?;
-                                            let mut val: ::std::vec::Vec<i32> =
-                                                ::std::vec::Vec::with_capacity(list_ident.size);
-                                            for i in 0..list_ident.size {
-                                                val.as_mut_ptr()
-                                                    .offset(i as isize)
-                                                    .write(__protocol.read_i32()?);
-                                            }
-                                            val.set_len(list_ident.size);
-                                            __protocol.read_list_end()?;
-                                            val
-                                        });
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(unsafe {
+                                                let list_ident = __protocol.read_list_begin()?;
+                                                let mut val: ::std::vec::Vec<i32> =
+                                                    ::std::vec::Vec::with_capacity(list_ident.size);
+                                                let mut __pilota_decoding_index = 0;
+                                                if let ::std::result::Result::Err(mut err) =
+                                                    (|| {
+                                                        for i in 0..list_ident.size {
+                                                            __pilota_decoding_index = i;
+                                                            val.as_mut_ptr()
+                                                                .offset(i as isize)
+                                                                .write(__protocol.read_i32()?);
+                                                        }
+                                                        ::std::result::Result::Ok::<
+                                                            _,
+                                                            ::pilota::thrift::ThriftException,
+                                                        >(
+                                                            ()
+                                                        )
+                                                    })(
+                                                    )
+                                                {
+                                                    err.prepend_path(
+                                                        ::pilota::thrift::PathSegment::Index(
+                                                            __pilota_decoding_index,
+                                                        ),
+                                                    );
+                                                    return ::std::result::Result::Err(err);
+                                                }
+                                                val.set_len(list_ident.size);
+                                                __protocol.read_list_end()?;
+                                                val
+                                            });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -7065,10 +7900,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `NestedListsI32x2` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "NestedListsI32x2",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("integerlist"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -7110,18 +7949,40 @@ pub mod apache {
                                         let list_ident = __protocol.read_list_begin().await?;
                                         let mut val =
                                             ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push({
-                                                let list_ident =
-                                                    __protocol.read_list_begin().await?;
-                                                let mut val =
-                                                    ::std::vec::Vec::with_capacity(list_ident.size);
-                                                for _ in 0..list_ident.size {
-                                                    val.push(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) =
+                                            async {
+                                                for i in 0..list_ident.size {
+                                                    __pilota_decoding_index = i;
+                                                    val.push({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(__protocol.read_i32().await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
                                                 }
-                                                __protocol.read_list_end().await?;
-                                                val
-                                            });
+                                                ::std::result::Result::Ok::<
+                                                    _,
+                                                    ::pilota::thrift::ThriftException,
+                                                >(())
+                                            }
+                                            .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_list_end().await?;
                                         val
@@ -7139,10 +8000,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `NestedListsI32x2` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "NestedListsI32x2",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("integerlist"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -7233,7 +8098,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestI32ArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestI32ArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -7292,7 +8164,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestI32ArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestI32ArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -7382,10 +8261,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<i32> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -7449,8 +8342,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_list_begin().await?;
                                         let mut val =
                                             ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_list_end().await?;
                                         val
@@ -7549,7 +8457,13 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestVoidArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestVoidArgsRecv",
+                            name: match field_id {
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -7594,7 +8508,13 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestVoidArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestVoidArgsRecv",
+                                name: match field_id {
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -7873,10 +8793,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `StructA` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "StructA",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("s"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -7936,10 +8860,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `StructA` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "StructA",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("s"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -8324,11 +9252,25 @@ pub mod apache {
                                 var_1 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::std::collections::BTreeMap::new();
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            ::pilota::thrift::Message::decode(__protocol)?,
-                                            ::pilota::thrift::Message::decode(__protocol)?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -8339,10 +9281,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<Xtruct> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(::pilota::thrift::Message::decode(__protocol)?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -8360,10 +9316,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Insanity` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Insanity",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("userMap"),
+                                2 => ::std::option::Option::Some("xtructs"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -8410,9 +9371,17 @@ pub mod apache {
                     var_1 = Some({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert(<Numberz as ::pilota::thrift::Message>::decode_async(__protocol).await?, <UserId as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                        }
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(<Numberz as ::pilota::thrift::Message>::decode_async(__protocol).await?, <UserId as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end().await?;
                         val
                     });
@@ -8421,9 +9390,17 @@ pub mod apache {
                     var_2 = Some({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push(<Xtruct as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
@@ -8442,7 +9419,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Insanity` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Insanity",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("userMap"),2 => ::std::option::Option::Some("xtructs"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -8691,11 +9675,25 @@ pub mod apache {
                                 let field_ident = {
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_faststr()?,
-                                            __protocol.read_faststr()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -8760,11 +9758,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_faststr().await?,
-                                                __protocol.read_faststr().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_faststr().await?,
+                                                    __protocol.read_faststr().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -8874,7 +9887,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestExceptionArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestExceptionArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("arg"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -8934,7 +9954,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestExceptionArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestExceptionArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("arg"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -9169,10 +10196,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<::pilota::FastStr> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(__protocol.read_faststr()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_faststr()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -9195,10 +10236,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ListTypeVersioningV2` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ListTypeVersioningV2",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("strings"),
+                                2 => ::std::option::Option::Some("hello"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -9244,8 +10290,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_list_begin().await?;
                                         let mut val =
                                             ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push(__protocol.read_faststr().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_faststr().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_list_end().await?;
                                         val
@@ -9269,7 +10330,15 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ListTypeVersioningV2` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ListTypeVersioningV2",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("strings"),
+                                    2 => ::std::option::Option::Some("hello"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -9611,7 +10680,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestInsanityArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestInsanityArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("argument"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -9676,7 +10752,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestInsanityArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestInsanityArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("argument"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -9763,10 +10846,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<Bonk> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(::pilota::thrift::Message::decode(__protocol)?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -9784,10 +10881,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ListBonks` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ListBonks",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("bonk"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -9814,52 +10915,62 @@ pub mod apache {
 
                     __protocol.read_struct_begin().await?;
                     if let ::std::result::Result::Err(mut err) = async {
-                        loop {
-                            let field_ident = __protocol.read_field_begin().await?;
-                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                                break;
-                            } else {
-                            }
-                            __pilota_decoding_field_id = field_ident.id;
-                            match field_ident.id {
-                                Some(1)
-                                    if field_ident.field_type == ::pilota::thrift::TType::List =>
-                                {
-                                    var_1 = Some({
-                                        let list_ident = __protocol.read_list_begin().await?;
-                                        let mut val =
-                                            ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push(
-                                                <Bonk as ::pilota::thrift::Message>::decode_async(
-                                                    __protocol,
-                                                )
-                                                .await?,
-                                            );
-                                        }
-                                        __protocol.read_list_end().await?;
-                                        val
-                                    });
-                                }
-                                _ => {
-                                    __protocol.skip(field_ident.field_type).await?;
-                                }
+                    loop {
+
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
+
+                }
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_1 = Some({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(<Bonk as ::pilota::thrift::Message>::decode_async(__protocol).await?);
                             }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
 
-                            __protocol.read_field_end().await?;
-                        }
-                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
-                    }
-                    .await
-                    {
-                        if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `ListBonks` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
-                        }
-                        return ::std::result::Result::Err(err);
-                    };
+                },
+                    _ => {
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                if let Some(field_id) = __pilota_decoding_field_id {
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "ListBonks",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("bonk"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
+                }
+                return ::std::result::Result::Err(err);
+            };
                     __protocol.read_struct_end().await?;
 
                     let data = Self { bonk: var_1 };
@@ -9941,10 +11052,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<i32> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -9962,7 +11087,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestListArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestListArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -10011,8 +11143,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_list_begin().await?;
                                         let mut val =
                                             ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_list_end().await?;
                                         val
@@ -10030,7 +11177,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestListArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestListArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -10228,7 +11382,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestNestArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestNestArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -10293,7 +11454,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestNestArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestNestArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -10413,11 +11581,25 @@ pub mod apache {
                                 var_4 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_i16()?,
-                                            __protocol.read_faststr()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_i16()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -10440,7 +11622,19 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestMultiArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestMultiArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("arg0"),
+                                2 => ::std::option::Option::Some("arg1"),
+                                3 => ::std::option::Option::Some("arg2"),
+                                4 => ::std::option::Option::Some("arg3"),
+                                5 => ::std::option::Option::Some("arg4"),
+                                6 => ::std::option::Option::Some("arg5"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -10546,11 +11740,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_i16().await?,
-                                                __protocol.read_faststr().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_i16().await?,
+                                                    __protocol.read_faststr().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -10588,7 +11797,19 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestMultiArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestMultiArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("arg0"),
+                                    2 => ::std::option::Option::Some("arg1"),
+                                    3 => ::std::option::Option::Some("arg2"),
+                                    4 => ::std::option::Option::Some("arg3"),
+                                    5 => ::std::option::Option::Some("arg4"),
+                                    6 => ::std::option::Option::Some("arg5"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -10764,10 +11985,17 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Xtruct` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Xtruct",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("string_thing"),
+                                4 => ::std::option::Option::Some("byte_thing"),
+                                9 => ::std::option::Option::Some("i32_thing"),
+                                11 => ::std::option::Option::Some("i64_thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -10843,10 +12071,17 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Xtruct` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Xtruct",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("string_thing"),
+                                    4 => ::std::option::Option::Some("byte_thing"),
+                                    9 => ::std::option::Option::Some("i32_thing"),
+                                    11 => ::std::option::Option::Some("i64_thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -10946,7 +12181,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestDoubleArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestDoubleArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -11006,7 +12248,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestDoubleArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestDoubleArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -11094,7 +12343,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestEnumArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestEnumArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -11158,7 +12414,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestEnumArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestEnumArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -11389,7 +12652,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestBoolArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestBoolArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -11448,7 +12718,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestBoolArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestBoolArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -11539,8 +12816,25 @@ pub mod apache {
                                 var_1 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, __protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_i32()?,
+                                                __protocol.read_i32()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -11557,7 +12851,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestMapArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestMapArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -11606,11 +12907,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_i32().await?,
-                                                __protocol.read_i32().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_i32().await?,
+                                                    __protocol.read_i32().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -11628,7 +12944,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestMapArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestMapArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -11868,7 +13191,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestBinaryArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestBinaryArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -11928,7 +13258,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestBinaryArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestBinaryArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -12031,27 +13368,48 @@ pub mod apache {
                     match field_ident.id {
                         Some(0) => {
                             if ret.is_none() {
-                                let field_ident = {
-                                    let map_ident = __protocol.read_map_begin()?;
-                                    let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, {
-                                            let map_ident = __protocol.read_map_begin()?;
-                                            let mut val =
-                                                ::pilota::AHashMap::with_capacity(map_ident.size);
-                                            for _ in 0..map_ident.size {
-                                                val.insert(
-                                                    __protocol.read_i32()?,
-                                                    __protocol.read_i32()?,
-                                                );
-                                            }
-                                            __protocol.read_map_end()?;
-                                            val
-                                        });
-                                    }
-                                    __protocol.read_map_end()?;
-                                    val
-                                };
+                                let field_ident =
+                                    {
+                                        let map_ident = __protocol.read_map_begin()?;
+                                        let mut val =
+                                            ::pilota::AHashMap::with_capacity(map_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) =
+                                            (|| {
+                                                for i in 0..map_ident.size {
+                                                    __pilota_decoding_index = i;
+                                                    val.insert(__protocol.read_i32()?, {
+                        let map_ident = __protocol.read_map_begin()?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32()?, __protocol.read_i32()?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end()?;
+                        val
+                    });
+                                                }
+                                                ::std::result::Result::Ok::<
+                                                    _,
+                                                    ::pilota::thrift::ThriftException,
+                                                >(())
+                                            })()
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        __protocol.read_map_end()?;
+                                        val
+                                    };
                                 __protocol.map_len(
                                     ::pilota::thrift::TType::I32,
                                     ::pilota::thrift::TType::Map,
@@ -12120,21 +13478,40 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(__protocol.read_i32().await?, {
-                                                let map_ident = __protocol.read_map_begin().await?;
-                                                let mut val = ::pilota::AHashMap::with_capacity(
-                                                    map_ident.size,
-                                                );
-                                                for _ in 0..map_ident.size {
-                                                    val.insert(
-                                                        __protocol.read_i32().await?,
-                                                        __protocol.read_i32().await?,
-                                                    );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) =
+                                            async {
+                                                for i in 0..map_ident.size {
+                                                    __pilota_decoding_index = i;
+                                                    val.insert(__protocol.read_i32().await?, {
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?, __protocol.read_i32().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
                                                 }
-                                                __protocol.read_map_end().await?;
-                                                val
-                                            });
+                                                ::std::result::Result::Ok::<
+                                                    _,
+                                                    ::pilota::thrift::ThriftException,
+                                                >(())
+                                            }
+                                            .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -12272,10 +13649,16 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `VersioningTestV1` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "VersioningTestV1",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("begin_in_both"),
+                                3 => ::std::option::Option::Some("old_string"),
+                                12 => ::std::option::Option::Some("end_in_both"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -12344,10 +13727,16 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `VersioningTestV1` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "VersioningTestV1",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("begin_in_both"),
+                                    3 => ::std::option::Option::Some("old_string"),
+                                    12 => ::std::option::Option::Some("end_in_both"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -12441,7 +13830,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestByteArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestByteArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -12500,7 +13896,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestByteArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestByteArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -12590,8 +13993,22 @@ pub mod apache {
                                     let list_ident = __protocol.read_set_begin()?;
                                     let mut val =
                                         ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_set_end()?;
                                     val
@@ -12654,8 +14071,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_set_begin().await?;
                                         let mut val =
                                             ::pilota::AHashSet::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.insert(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_set_end().await?;
                                         val
@@ -12763,10 +14195,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `OneField` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "OneField",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("field"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -12824,10 +14260,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `OneField` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "OneField",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("field"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -13070,33 +14510,56 @@ pub mod apache {
                                     let mut val: ::std::vec::Vec<
                                         ::std::vec::Vec<::std::vec::Vec<i32>>,
                                     > = ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(unsafe {
-                                            let list_ident = __protocol.read_list_begin()?;
-                                            let mut val: ::std::vec::Vec<::std::vec::Vec<i32>> =
-                                                ::std::vec::Vec::with_capacity(list_ident.size);
-                                            for i in 0..list_ident.size {
-                                                val.as_mut_ptr().offset(i as isize).write(unsafe {
-                                                    let list_ident =
-                                                        __protocol.read_list_begin()?;
-                                                    let mut val: ::std::vec::Vec<i32> =
-                                                        ::std::vec::Vec::with_capacity(
-                                                            list_ident.size,
-                                                        );
-                                                    for i in 0..list_ident.size {
-                                                        val.as_mut_ptr()
-                                                            .offset(i as isize)
-                                                            .write(__protocol.read_i32()?);
-                                                    }
-                                                    val.set_len(list_ident.size);
-                                                    __protocol.read_list_end()?;
-                                                    val
-                                                });
-                                            }
-                                            val.set_len(list_ident.size);
-                                            __protocol.read_list_end()?;
-                                            val
-                                        });
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(unsafe {
+                            let list_ident = __protocol.read_list_begin()?;
+                            let mut val: ::std::vec::Vec<::std::vec::Vec<i32>> = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.as_mut_ptr().offset(i as isize).write(unsafe {
+                            let list_ident = __protocol.read_list_begin()?;
+                            let mut val: ::std::vec::Vec<i32> = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.as_mut_ptr().offset(i as isize).write(__protocol.read_i32()?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            val.set_len(list_ident.size);
+                            __protocol.read_list_end()?;
+                            val
+                        });
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            val.set_len(list_ident.size);
+                            __protocol.read_list_end()?;
+                            val
+                        });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -13114,10 +14577,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `NestedListsI32x3` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "NestedListsI32x3",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("integerlist"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -13159,30 +14626,56 @@ pub mod apache {
                                         let list_ident = __protocol.read_list_begin().await?;
                                         let mut val =
                                             ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push({
-                                                let list_ident =
-                                                    __protocol.read_list_begin().await?;
-                                                let mut val =
-                                                    ::std::vec::Vec::with_capacity(list_ident.size);
-                                                for _ in 0..list_ident.size {
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) =
+                                            async {
+                                                for i in 0..list_ident.size {
+                                                    __pilota_decoding_index = i;
                                                     val.push({
-                                                        let list_ident =
-                                                            __protocol.read_list_begin().await?;
-                                                        let mut val =
-                                                            ::std::vec::Vec::with_capacity(
-                                                                list_ident.size,
-                                                            );
-                                                        for _ in 0..list_ident.size {
-                                                            val.push(__protocol.read_i32().await?);
-                                                        }
-                                                        __protocol.read_list_end().await?;
-                                                        val
-                                                    });
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(__protocol.read_i32().await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
                                                 }
-                                                __protocol.read_list_end().await?;
-                                                val
-                                            });
+                                                ::std::result::Result::Ok::<
+                                                    _,
+                                                    ::pilota::thrift::ThriftException,
+                                                >(())
+                                            }
+                                            .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_list_end().await?;
                                         val
@@ -13200,10 +14693,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `NestedListsI32x3` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "NestedListsI32x3",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("integerlist"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -13735,10 +15232,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Bonk` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Bonk",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("message"),
+                                2 => ::std::option::Option::Some("r#type"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -13800,10 +15302,15 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Bonk` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Bonk",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("message"),
+                                    2 => ::std::option::Option::Some("r#type"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -13902,10 +15409,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `StructB` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "StructB",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("aa"),
+                                2 => ::std::option::Option::Some("ab"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -13985,10 +15497,15 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `StructB` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "StructB",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("aa"),
+                                    2 => ::std::option::Option::Some("ab"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -14227,7 +15744,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestOnewayArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestOnewayArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("secondsToSleep"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -14288,7 +15812,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestOnewayArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestOnewayArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("secondsToSleep"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -14384,8 +15915,25 @@ pub mod apache {
                                 let field_ident = {
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, __protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_i32()?,
+                                                __protocol.read_i32()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -14450,11 +15998,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_i32().await?,
-                                                __protocol.read_i32().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_i32().await?,
+                                                    __protocol.read_i32().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -14811,8 +16374,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_set_begin()?;
                                     let mut val =
                                         ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(::pilota::thrift::Message::decode(__protocol)?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(::pilota::thrift::Message::decode(
+                                                __protocol,
+                                            )?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_set_end()?;
                                     val
@@ -14837,53 +16416,115 @@ pub mod apache {
                                             >,
                                         >,
                                     > = ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write({
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write({
                         let map_ident = __protocol.read_map_begin()?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert({let list_ident = __protocol.read_set_begin()?;
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert({let list_ident = __protocol.read_set_begin()?;
                     let mut val = ::std::collections::BTreeSet::new();
-                    for _ in 0..list_ident.size {
-                        val.insert(__protocol.read_i32()?);
-                    };
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32()?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                     __protocol.read_set_end()?;
                     val}, {
                         let map_ident = __protocol.read_map_begin()?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert(__protocol.read_i32()?, {let list_ident = __protocol.read_set_begin()?;
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32()?, {let list_ident = __protocol.read_set_begin()?;
                     let mut val = ::std::collections::BTreeSet::new();
-                    for _ in 0..list_ident.size {
-                        val.insert(unsafe {
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(unsafe {
                             let list_ident = __protocol.read_list_begin()?;
                             let mut val: ::std::vec::Vec<::std::collections::BTreeMap<Insanity, ::pilota::FastStr>> = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for i in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.as_mut_ptr().offset(i as isize).write({
                         let map_ident = __protocol.read_map_begin()?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert(::pilota::thrift::Message::decode(__protocol)?, __protocol.read_faststr()?);
-                        }
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(::pilota::thrift::Message::decode(__protocol)?, __protocol.read_faststr()?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end()?;
                         val
                     });
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             val.set_len(list_ident.size);
                             __protocol.read_list_end()?;
                             val
                         });
-                    };
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                     __protocol.read_set_end()?;
                     val});
-                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end()?;
                         val
                     });
-                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end()?;
                         val
                     });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -14909,10 +16550,18 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `CrazyNesting` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "CrazyNesting",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("string_field"),
+                                2 => ::std::option::Option::Some("set_field"),
+                                3 => ::std::option::Option::Some("list_field"),
+                                4 => ::std::option::Option::Some("binary_field"),
+                                5 => ::std::option::Option::Some("uuid_field"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -14974,9 +16623,17 @@ pub mod apache {
                 },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Set  => {
                     var_2 = Some({let list_ident = __protocol.read_set_begin().await?;
                     let mut val = ::pilota::AHashSet::with_capacity(list_ident.size);
-                    for _ in 0..list_ident.size {
-                        val.insert(<Insanity as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                    };
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(<Insanity as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                     __protocol.read_set_end().await?;
                     val});
 
@@ -14984,53 +16641,109 @@ pub mod apache {
                     var_3 = Some({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert({let list_ident = __protocol.read_set_begin().await?;
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert({let list_ident = __protocol.read_set_begin().await?;
                     let mut val = ::std::collections::BTreeSet::new();
-                    for _ in 0..list_ident.size {
-                        val.insert(__protocol.read_i32().await?);
-                    };
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                     __protocol.read_set_end().await?;
                     val}, {
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert(__protocol.read_i32().await?, {let list_ident = __protocol.read_set_begin().await?;
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?, {let list_ident = __protocol.read_set_begin().await?;
                     let mut val = ::std::collections::BTreeSet::new();
-                    for _ in 0..list_ident.size {
-                        val.insert({
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert(<Insanity as ::pilota::thrift::Message>::decode_async(__protocol).await?, __protocol.read_faststr().await?);
-                        }
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(<Insanity as ::pilota::thrift::Message>::decode_async(__protocol).await?, __protocol.read_faststr().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end().await?;
                         val
                     });
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
-                    };
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                     __protocol.read_set_end().await?;
                     val});
-                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end().await?;
                         val
                     });
-                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end().await?;
                         val
                     });
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
@@ -15055,7 +16768,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `CrazyNesting` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "CrazyNesting",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("string_field"),2 => ::std::option::Option::Some("set_field"),3 => ::std::option::Option::Some("list_field"),4 => ::std::option::Option::Some("binary_field"),5 => ::std::option::Option::Some("uuid_field"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -15362,7 +17082,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `SecondServiceSecondtestStringArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "SecondServiceSecondtestStringArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -15422,7 +17149,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `SecondServiceSecondtestStringArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "SecondServiceSecondtestStringArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -15889,10 +17623,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Xception` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Xception",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("errorCode"),
+                                2 => ::std::option::Option::Some("message"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -15954,10 +17693,15 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Xception` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Xception",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("errorCode"),
+                                    2 => ::std::option::Option::Some("message"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -16046,7 +17790,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestMapMapArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestMapMapArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("hello"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -16105,7 +17856,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestMapMapArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestMapMapArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("hello"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -16199,11 +17957,25 @@ pub mod apache {
                                 var_7 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_faststr()?,
-                                            __protocol.read_faststr()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -16220,10 +17992,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `GuessProtocolStruct` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "GuessProtocolStruct",
+                            name: match field_id {
+                                7 => ::std::option::Option::Some("map_field"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -16265,11 +18041,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_faststr().await?,
-                                                __protocol.read_faststr().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_faststr().await?,
+                                                    __protocol.read_faststr().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -16287,7 +18078,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `GuessProtocolStruct` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "GuessProtocolStruct",
+                                name: match field_id {
+                                    7 => ::std::option::Option::Some("map_field"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -16373,8 +18171,22 @@ pub mod apache {
                                     let list_ident = __protocol.read_set_begin()?;
                                     let mut val =
                                         ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_set_end()?;
                                     val
@@ -16391,7 +18203,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestSetArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestSetArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -16440,8 +18259,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_set_begin().await?;
                                         let mut val =
                                             ::pilota::AHashSet::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.insert(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_set_end().await?;
                                         val
@@ -16459,7 +18293,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestSetArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestSetArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -16553,7 +18394,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestStructArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestStructArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -16618,7 +18466,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestStructArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestStructArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -16712,7 +18567,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestInsanityArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestInsanityArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("argument"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -16777,7 +18639,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestInsanityArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestInsanityArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("argument"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -16882,35 +18751,56 @@ pub mod apache {
                                     let mut val: ::std::vec::Vec<
                                         ::std::vec::Vec<::std::vec::Vec<Bonk>>,
                                     > = ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(unsafe {
-                                            let list_ident = __protocol.read_list_begin()?;
-                                            let mut val: ::std::vec::Vec<::std::vec::Vec<Bonk>> =
-                                                ::std::vec::Vec::with_capacity(list_ident.size);
-                                            for i in 0..list_ident.size {
-                                                val.as_mut_ptr().offset(i as isize).write(unsafe {
-                                                    let list_ident =
-                                                        __protocol.read_list_begin()?;
-                                                    let mut val: ::std::vec::Vec<Bonk> =
-                                                        ::std::vec::Vec::with_capacity(
-                                                            list_ident.size,
-                                                        );
-                                                    for i in 0..list_ident.size {
-                                                        val.as_mut_ptr().offset(i as isize).write(
-                                                            ::pilota::thrift::Message::decode(
-                                                                __protocol,
-                                                            )?,
-                                                        );
-                                                    }
-                                                    val.set_len(list_ident.size);
-                                                    __protocol.read_list_end()?;
-                                                    val
-                                                });
-                                            }
-                                            val.set_len(list_ident.size);
-                                            __protocol.read_list_end()?;
-                                            val
-                                        });
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(unsafe {
+                            let list_ident = __protocol.read_list_begin()?;
+                            let mut val: ::std::vec::Vec<::std::vec::Vec<Bonk>> = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.as_mut_ptr().offset(i as isize).write(unsafe {
+                            let list_ident = __protocol.read_list_begin()?;
+                            let mut val: ::std::vec::Vec<Bonk> = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.as_mut_ptr().offset(i as isize).write(::pilota::thrift::Message::decode(__protocol)?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            val.set_len(list_ident.size);
+                            __protocol.read_list_end()?;
+                            val
+                        });
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            val.set_len(list_ident.size);
+                            __protocol.read_list_end()?;
+                            val
+                        });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -16928,10 +18818,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `NestedListsBonk` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "NestedListsBonk",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("bonk"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -16974,25 +18868,49 @@ pub mod apache {
                     var_1 = Some({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push(<Bonk as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
@@ -17011,7 +18929,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `NestedListsBonk` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "NestedListsBonk",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("bonk"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -17108,7 +19033,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestI64ArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestI64ArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -17167,7 +19099,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestI64ArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestI64ArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -17254,10 +19193,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<i32> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -17275,7 +19228,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestListArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestListArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -17324,8 +19284,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_list_begin().await?;
                                         let mut val =
                                             ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_list_end().await?;
                                         val
@@ -17343,7 +19318,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestListArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestListArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -17437,7 +19419,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestStringArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestStringArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -17497,7 +19486,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestStringArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestStringArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -17587,7 +19583,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestNestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestNestArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -17652,7 +19655,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestNestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestNestArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -17903,10 +19913,16 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Xtruct2` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Xtruct2",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("byte_thing"),
+                                2 => ::std::option::Option::Some("struct_thing"),
+                                3 => ::std::option::Option::Some("i32_thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -17980,10 +19996,16 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Xtruct2` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Xtruct2",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("byte_thing"),
+                                    2 => ::std::option::Option::Some("struct_thing"),
+                                    3 => ::std::option::Option::Some("i32_thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -18079,7 +20101,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestDoubleArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestDoubleArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -18139,7 +20168,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestDoubleArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestDoubleArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -18371,7 +20407,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestBoolArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestBoolArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -18430,7 +20473,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestBoolArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestBoolArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -18524,11 +20574,25 @@ pub mod apache {
                                 let field_ident = {
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_faststr()?,
-                                            __protocol.read_faststr()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -18593,11 +20657,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_faststr().await?,
-                                                __protocol.read_faststr().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_faststr().await?,
+                                                    __protocol.read_faststr().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -18958,10 +21037,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<i32> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -18973,8 +21066,22 @@ pub mod apache {
                                     let list_ident = __protocol.read_set_begin()?;
                                     let mut val =
                                         ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_set_end()?;
                                     val
@@ -18984,8 +21091,25 @@ pub mod apache {
                                 var_10 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, __protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_i32()?,
+                                                __protocol.read_i32()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -19010,10 +21134,25 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `VersioningTestV2` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "VersioningTestV2",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("begin_in_both"),
+                                2 => ::std::option::Option::Some("newint"),
+                                3 => ::std::option::Option::Some("newbyte"),
+                                4 => ::std::option::Option::Some("newshort"),
+                                5 => ::std::option::Option::Some("newlong"),
+                                6 => ::std::option::Option::Some("newdouble"),
+                                7 => ::std::option::Option::Some("newstruct"),
+                                8 => ::std::option::Option::Some("newlist"),
+                                9 => ::std::option::Option::Some("newset"),
+                                10 => ::std::option::Option::Some("newmap"),
+                                11 => ::std::option::Option::Some("newstring"),
+                                12 => ::std::option::Option::Some("end_in_both"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -19121,8 +21260,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_list_begin().await?;
                                         let mut val =
                                             ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_list_end().await?;
                                         val
@@ -19135,8 +21289,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_set_begin().await?;
                                         let mut val =
                                             ::pilota::AHashSet::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.insert(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_set_end().await?;
                                         val
@@ -19149,11 +21318,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_i32().await?,
-                                                __protocol.read_i32().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_i32().await?,
+                                                    __protocol.read_i32().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -19182,10 +21366,25 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `VersioningTestV2` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "VersioningTestV2",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("begin_in_both"),
+                                    2 => ::std::option::Option::Some("newint"),
+                                    3 => ::std::option::Option::Some("newbyte"),
+                                    4 => ::std::option::Option::Some("newshort"),
+                                    5 => ::std::option::Option::Some("newlong"),
+                                    6 => ::std::option::Option::Some("newdouble"),
+                                    7 => ::std::option::Option::Some("newstruct"),
+                                    8 => ::std::option::Option::Some("newlist"),
+                                    9 => ::std::option::Option::Some("newset"),
+                                    10 => ::std::option::Option::Some("newmap"),
+                                    11 => ::std::option::Option::Some("newstring"),
+                                    12 => ::std::option::Option::Some("end_in_both"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -19828,28 +22027,38 @@ pub mod apache {
                                 let field_ident = {
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            ::pilota::thrift::Message::decode(__protocol)?,
-                                            {
-                                                let map_ident = __protocol.read_map_begin()?;
-                                                let mut val = ::pilota::AHashMap::with_capacity(
-                                                    map_ident.size,
-                                                );
-                                                for _ in 0..map_ident.size {
-                                                    val.insert(
-                                                        ::pilota::thrift::Message::decode(
-                                                            __protocol,
-                                                        )?,
-                                                        ::pilota::thrift::Message::decode(
-                                                            __protocol,
-                                                        )?,
-                                                    );
-                                                }
-                                                __protocol.read_map_end()?;
-                                                val
-                                            },
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(::pilota::thrift::Message::decode(__protocol)?, {
+                        let map_ident = __protocol.read_map_begin()?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(::pilota::thrift::Message::decode(__protocol)?, ::pilota::thrift::Message::decode(__protocol)?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end()?;
+                        val
+                    });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -19922,17 +22131,33 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(<UserId as ::pilota::thrift::Message>::decode_async(__protocol).await?, {
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(<UserId as ::pilota::thrift::Message>::decode_async(__protocol).await?, {
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                        for _ in 0..map_ident.size {
-                            val.insert(<Numberz as ::pilota::thrift::Message>::decode_async(__protocol).await?, <Insanity as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                        }
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(<Numberz as ::pilota::thrift::Message>::decode_async(__protocol).await?, <Insanity as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end().await?;
                         val
                     });
-                                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                                         __protocol.read_map_end().await?;
                                         val
                                     };
@@ -20055,20 +22280,54 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<::std::vec::Vec<f64>> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(unsafe {
-                                            let list_ident = __protocol.read_list_begin()?;
-                                            let mut val: ::std::vec::Vec<f64> =
-                                                ::std::vec::Vec::with_capacity(list_ident.size);
-                                            for i in 0..list_ident.size {
-                                                val.as_mut_ptr()
-                                                    .offset(i as isize)
-                                                    .write(__protocol.read_double()?);
-                                            }
-                                            val.set_len(list_ident.size);
-                                            __protocol.read_list_end()?;
-                                            val
-                                        });
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(unsafe {
+                                                let list_ident = __protocol.read_list_begin()?;
+                                                let mut val: ::std::vec::Vec<f64> =
+                                                    ::std::vec::Vec::with_capacity(list_ident.size);
+                                                let mut __pilota_decoding_index = 0;
+                                                if let ::std::result::Result::Err(mut err) =
+                                                    (|| {
+                                                        for i in 0..list_ident.size {
+                                                            __pilota_decoding_index = i;
+                                                            val.as_mut_ptr()
+                                                                .offset(i as isize)
+                                                                .write(__protocol.read_double()?);
+                                                        }
+                                                        ::std::result::Result::Ok::<
+                                                            _,
+                                                            ::pilota::thrift::ThriftException,
+                                                        >(
+                                                            ()
+                                                        )
+                                                    })(
+                                                    )
+                                                {
+                                                    err.prepend_path(
+                                                        ::pilota::thrift::PathSegment::Index(
+                                                            __pilota_decoding_index,
+                                                        ),
+                                                    );
+                                                    return ::std::result::Result::Err(err);
+                                                }
+                                                val.set_len(list_ident.size);
+                                                __protocol.read_list_end()?;
+                                                val
+                                            });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -20086,10 +22345,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `NestedListsDoublex2` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "NestedListsDoublex2",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("doublelist"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -20131,18 +22394,40 @@ pub mod apache {
                                         let list_ident = __protocol.read_list_begin().await?;
                                         let mut val =
                                             ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push({
-                                                let list_ident =
-                                                    __protocol.read_list_begin().await?;
-                                                let mut val =
-                                                    ::std::vec::Vec::with_capacity(list_ident.size);
-                                                for _ in 0..list_ident.size {
-                                                    val.push(__protocol.read_double().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) =
+                                            async {
+                                                for i in 0..list_ident.size {
+                                                    __pilota_decoding_index = i;
+                                                    val.push({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(__protocol.read_double().await?);
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
                                                 }
-                                                __protocol.read_list_end().await?;
-                                                val
-                                            });
+                                                ::std::result::Result::Ok::<
+                                                    _,
+                                                    ::pilota::thrift::ThriftException,
+                                                >(())
+                                            }
+                                            .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_list_end().await?;
                                         val
@@ -20160,7 +22445,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `NestedListsDoublex2` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "NestedListsDoublex2",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("doublelist"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -20253,10 +22545,24 @@ pub mod apache {
                                     let list_ident = __protocol.read_list_begin()?;
                                     let mut val: ::std::vec::Vec<i32> =
                                         ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr()
-                                            .offset(i as isize)
-                                            .write(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr()
+                                                .offset(i as isize)
+                                                .write(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -20320,8 +22626,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_list_begin().await?;
                                         let mut val =
                                             ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push(__protocol.read_i32().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.push(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_list_end().await?;
                                         val
@@ -20438,7 +22759,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestMultiExceptionArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestMultiExceptionArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("arg0"),
+                                2 => ::std::option::Option::Some("arg1"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -20514,7 +22843,15 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestMultiExceptionArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestMultiExceptionArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("arg0"),
+                                    2 => ::std::option::Option::Some("arg1"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -20748,11 +23085,21 @@ pub mod apache {
                 ::std::result::Result::Ok(MapType({
                     let map_ident = __protocol.read_map_begin()?;
                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                    for _ in 0..map_ident.size {
-                        val.insert(
-                            __protocol.read_faststr()?,
-                            ::pilota::thrift::Message::decode(__protocol)?,
-                        );
+                    let mut __pilota_decoding_index = 0;
+                    if let ::std::result::Result::Err(mut err) = (|| {
+                        for i in 0..map_ident.size {
+                            __pilota_decoding_index = i;
+                            val.insert(
+                                __protocol.read_faststr()?,
+                                ::pilota::thrift::Message::decode(__protocol)?,
+                            );
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    })() {
+                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                            __pilota_decoding_index,
+                        ));
+                        return ::std::result::Result::Err(err);
                     }
                     __protocol.read_map_end()?;
                     val
@@ -20773,12 +23120,24 @@ pub mod apache {
                     ::std::result::Result::Ok(MapType({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                        for _ in 0..map_ident.size {
-                            val.insert(
-                                __protocol.read_faststr().await?,
-                                <Bonk as ::pilota::thrift::Message>::decode_async(__protocol)
-                                    .await?,
-                            );
+                        let mut __pilota_decoding_index = 0;
+                        if let ::std::result::Result::Err(mut err) = async {
+                            for i in 0..map_ident.size {
+                                __pilota_decoding_index = i;
+                                val.insert(
+                                    __protocol.read_faststr().await?,
+                                    <Bonk as ::pilota::thrift::Message>::decode_async(__protocol)
+                                        .await?,
+                                );
+                            }
+                            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                        }
+                        .await
+                        {
+                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                __pilota_decoding_index,
+                            ));
+                            return ::std::result::Result::Err(err);
                         }
                         __protocol.read_map_end().await?;
                         val
@@ -20866,8 +23225,22 @@ pub mod apache {
                                     let list_ident = __protocol.read_set_begin()?;
                                     let mut val =
                                         ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_faststr()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_faststr()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_set_end()?;
                                     val
@@ -20884,10 +23257,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `OptionalSetDefaultTest` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "OptionalSetDefaultTest",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("with_default"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -20937,8 +23314,23 @@ pub mod apache {
                                         let list_ident = __protocol.read_set_begin().await?;
                                         let mut val =
                                             ::pilota::AHashSet::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.insert(__protocol.read_faststr().await?);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(__protocol.read_faststr().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_set_end().await?;
                                         val
@@ -20956,7 +23348,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `OptionalSetDefaultTest` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "OptionalSetDefaultTest",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("with_default"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -21189,7 +23588,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestOnewayArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestOnewayArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("secondsToSleep"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -21250,7 +23656,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestOnewayArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestOnewayArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("secondsToSleep"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -21506,11 +23919,25 @@ pub mod apache {
                                 let field_ident = {
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            ::pilota::thrift::Message::decode(__protocol)?,
-                                            ::pilota::thrift::Message::decode(__protocol)?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                                ::pilota::thrift::Message::decode(__protocol)?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -21631,9 +24058,17 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(<Numberz as ::pilota::thrift::Message>::decode_async(__protocol).await?, <UserId as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                                        }
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(<Numberz as ::pilota::thrift::Message>::decode_async(__protocol).await?, <UserId as ::pilota::thrift::Message>::decode_async(__protocol).await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                                         __protocol.read_map_end().await?;
                                         val
                                     };
@@ -21807,7 +24242,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestTypedefArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestTypedefArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -21871,7 +24313,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestTypedefArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestTypedefArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -21961,7 +24410,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `SecondServiceSecondtestStringArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "SecondServiceSecondtestStringArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -22021,7 +24477,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `SecondServiceSecondtestStringArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "SecondServiceSecondtestStringArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -22112,11 +24575,25 @@ pub mod apache {
                                 var_1 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_faststr()?,
-                                            __protocol.read_faststr()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -22133,7 +24610,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestStringMapArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestStringMapArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -22182,11 +24666,26 @@ pub mod apache {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_faststr().await?,
-                                                __protocol.read_faststr().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_faststr().await?,
+                                                    __protocol.read_faststr().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -22204,7 +24703,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestStringMapArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestStringMapArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -22309,10 +24815,15 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Xception2` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Xception2",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("errorCode"),
+                                2 => ::std::option::Option::Some("struct_thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -22379,10 +24890,15 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Xception2` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Xception2",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("errorCode"),
+                                    2 => ::std::option::Option::Some("struct_thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -22471,7 +24987,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestUuidArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestUuidArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("thing"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -22530,7 +25053,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestUuidArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestUuidArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("thing"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -22618,7 +25148,14 @@ pub mod apache {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `ThriftTestTestMapMapArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ThriftTestTestMapMapArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("hello"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -22677,7 +25214,14 @@ pub mod apache {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ThriftTestTestMapMapArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ThriftTestTestMapMapArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("hello"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
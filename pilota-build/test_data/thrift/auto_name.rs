@@ -1,5 +1,5 @@
 pub mod auto_name {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod auto_name {
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
@@ -60,10 +60,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `TEST` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "TEST",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("Id"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -123,10 +127,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `TEST` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "TEST",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("Id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -361,10 +369,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `servicetestArgsSend` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "servicetestArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -429,7 +441,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `servicetestArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "servicetestArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -510,10 +529,13 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `TestException` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "TestException",
+                            name: match field_id {
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -558,10 +580,13 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `TestException` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "TestException",
+                                name: match field_id {
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -642,10 +667,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `servicetestArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "servicetestArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -710,7 +739,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `servicetestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "servicetestArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -982,10 +1018,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ServiceTest2ArgsSend` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ServiceTest2ArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("r#type"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -1050,7 +1090,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServiceTest2ArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ServiceTest2ArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("r#type"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1140,10 +1187,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ServiceTestArgsSend` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ServiceTestArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("Req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -1208,7 +1259,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServiceTestArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ServiceTestArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("Req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -2095,10 +2153,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ServiceTest2ArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ServiceTest2ArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("r#type"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -2163,7 +2225,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServiceTest2ArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ServiceTest2ArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("r#type"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -2253,10 +2322,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ServiceTestArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ServiceTestArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("Req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -2321,7 +2394,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServiceTestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ServiceTestArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("Req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -2600,10 +2680,15 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Test` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Test",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("ID"),
+                                2 => ::std::option::Option::Some("Id"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -2679,10 +2764,15 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Test` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Test",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("ID"),
+                                    2 => ::std::option::Option::Some("Id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -2938,10 +3028,15 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ServicetestArgsSend` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ServicetestArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("req"),
+                                2 => ::std::option::Option::Some("Req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -3027,7 +3122,15 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServicetestArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ServicetestArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    2 => ::std::option::Option::Some("Req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -3218,10 +3321,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `serviceTest2ArgsSend` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "serviceTest2ArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("self_"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -3286,7 +3393,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `serviceTest2ArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "serviceTest2ArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("self_"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -3376,10 +3490,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `serviceTestArgsSend` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "serviceTestArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("Req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -3444,7 +3562,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `serviceTestArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "serviceTestArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("Req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -3869,10 +3994,15 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `ServicetestArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "ServicetestArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("req"),
+                                2 => ::std::option::Option::Some("Req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -3958,7 +4088,15 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ServicetestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ServicetestArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    2 => ::std::option::Option::Some("Req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -4242,10 +4380,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `serviceTest2ArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "serviceTest2ArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("self_"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -4310,7 +4452,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `serviceTest2ArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "serviceTest2ArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("self_"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -4400,10 +4549,14 @@ pub mod auto_name {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `serviceTestArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "serviceTestArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("Req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -4468,7 +4621,14 @@ pub mod auto_name {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `serviceTestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "serviceTestArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("Req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1,5 +1,5 @@
 pub mod binary_bytes {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod binary_bytes {
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
@@ -69,10 +69,15 @@ pub mod binary_bytes {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `A` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "A",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("bytes"),
+                                2 => ::std::option::Option::Some("vec"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -148,10 +153,15 @@ pub mod binary_bytes {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `A` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "A",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("bytes"),
+                                    2 => ::std::option::Option::Some("vec"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -0,0 +1,5 @@
+struct Sample {
+    1: required list<i32> values (pilota.rust_type = "boxed_slice"),
+    2: optional list<string> names (pilota.rust_type = "boxed_slice"),
+    3: required list<i64> defaults = [1, 2] (pilota.rust_type = "boxed_slice"),
+}
@@ -1,5 +1,5 @@
 pub mod btree {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod btree {
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
@@ -51,10 +51,13 @@ pub mod btree {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `A` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "A",
+                            name: match field_id {
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -99,10 +102,13 @@ pub mod btree {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `A` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "A",
+                                name: match field_id {
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -187,19 +193,43 @@ pub mod btree {
                 ::std::result::Result::Ok(TypeA({
                     let map_ident = __protocol.read_map_begin()?;
                     let mut val = ::std::collections::BTreeMap::new();
-                    for _ in 0..map_ident.size {
-                        val.insert(
-                            {
-                                let list_ident = __protocol.read_set_begin()?;
-                                let mut val = ::std::collections::BTreeSet::new();
-                                for _ in 0..list_ident.size {
-                                    val.insert(__protocol.read_i32()?);
-                                }
-                                __protocol.read_set_end()?;
-                                val
-                            },
-                            __protocol.read_faststr()?,
-                        );
+                    let mut __pilota_decoding_index = 0;
+                    if let ::std::result::Result::Err(mut err) = (|| {
+                        for i in 0..map_ident.size {
+                            __pilota_decoding_index = i;
+                            val.insert(
+                                {
+                                    let list_ident = __protocol.read_set_begin()?;
+                                    let mut val = ::std::collections::BTreeSet::new();
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
+                                    }
+                                    __protocol.read_set_end()?;
+                                    val
+                                },
+                                __protocol.read_faststr()?,
+                            );
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    })() {
+                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                            __pilota_decoding_index,
+                        ));
+                        return ::std::result::Result::Err(err);
                     }
                     __protocol.read_map_end()?;
                     val
@@ -220,19 +250,46 @@ pub mod btree {
                     ::std::result::Result::Ok(TypeA({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert(
-                                {
-                                    let list_ident = __protocol.read_set_begin().await?;
-                                    let mut val = ::std::collections::BTreeSet::new();
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_i32().await?);
-                                    }
-                                    __protocol.read_set_end().await?;
-                                    val
-                                },
-                                __protocol.read_faststr().await?,
-                            );
+                        let mut __pilota_decoding_index = 0;
+                        if let ::std::result::Result::Err(mut err) = async {
+                            for i in 0..map_ident.size {
+                                __pilota_decoding_index = i;
+                                val.insert(
+                                    {
+                                        let list_ident = __protocol.read_set_begin().await?;
+                                        let mut val = ::std::collections::BTreeSet::new();
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(__protocol.read_i32().await?);
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        __protocol.read_set_end().await?;
+                                        val
+                                    },
+                                    __protocol.read_faststr().await?,
+                                );
+                            }
+                            ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                        }
+                        .await
+                        {
+                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                __pilota_decoding_index,
+                            ));
+                            return ::std::result::Result::Err(err);
                         }
                         __protocol.read_map_end().await?;
                         val
@@ -391,24 +448,58 @@ pub mod btree {
                                 var_1 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::std::collections::BTreeMap::new();
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, unsafe {
-                                            let list_ident = __protocol.read_list_begin()?;
-                                            let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
-                                                ::std::vec::Vec::with_capacity(list_ident.size);
-                                            for i in 0..list_ident.size {
-                                                val.as_mut_ptr().offset(i as isize).write(
-                                                    ::std::sync::Arc::new(
-                                                        ::pilota::thrift::Message::decode(
-                                                            __protocol,
-                                                        )?,
-                                                    ),
-                                                );
-                                            }
-                                            val.set_len(list_ident.size);
-                                            __protocol.read_list_end()?;
-                                            val
-                                        });
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?, unsafe {
+                                                let list_ident = __protocol.read_list_begin()?;
+                                                let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
+                                                    ::std::vec::Vec::with_capacity(list_ident.size);
+                                                let mut __pilota_decoding_index = 0;
+                                                if let ::std::result::Result::Err(mut err) =
+                                                    (|| {
+                                                        for i in 0..list_ident.size {
+                                                            __pilota_decoding_index = i;
+                                                            val.as_mut_ptr()
+                                                                .offset(i as isize)
+                                                                .write(::std::sync::Arc::new(
+                                                                ::pilota::thrift::Message::decode(
+                                                                    __protocol,
+                                                                )?,
+                                                            ));
+                                                        }
+                                                        ::std::result::Result::Ok::<
+                                                            _,
+                                                            ::pilota::thrift::ThriftException,
+                                                        >(
+                                                            ()
+                                                        )
+                                                    })(
+                                                    )
+                                                {
+                                                    err.prepend_path(
+                                                        ::pilota::thrift::PathSegment::Index(
+                                                            __pilota_decoding_index,
+                                                        ),
+                                                    );
+                                                    return ::std::result::Result::Err(err);
+                                                }
+                                                val.set_len(list_ident.size);
+                                                __protocol.read_list_end()?;
+                                                val
+                                            });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -418,8 +509,22 @@ pub mod btree {
                                 var_2 = Some({
                                     let list_ident = __protocol.read_set_begin()?;
                                     let mut val = ::std::collections::BTreeSet::new();
-                                    for _ in 0..list_ident.size {
-                                        val.insert(__protocol.read_i32()?);
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?);
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_set_end()?;
                                     val
@@ -429,47 +534,83 @@ pub mod btree {
                                 var_3 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::std::collections::BTreeMap::new();
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            unsafe {
-                                                let list_ident = __protocol.read_list_begin()?;
-                                                let mut val: ::std::vec::Vec<
-                                                    ::std::collections::BTreeMap<
-                                                        ::std::collections::BTreeSet<i32>,
-                                                        i32,
-                                                    >,
-                                                > = ::std::vec::Vec::with_capacity(list_ident.size);
-                                                for i in 0..list_ident.size {
-                                                    val.as_mut_ptr().offset(i as isize).write({
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(unsafe {
+                            let list_ident = __protocol.read_list_begin()?;
+                            let mut val: ::std::vec::Vec<::std::collections::BTreeMap<::std::collections::BTreeSet<i32>, i32>> = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.as_mut_ptr().offset(i as isize).write({
                         let map_ident = __protocol.read_map_begin()?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert({let list_ident = __protocol.read_set_begin()?;
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert({let list_ident = __protocol.read_set_begin()?;
                     let mut val = ::std::collections::BTreeSet::new();
-                    for _ in 0..list_ident.size {
-                        val.insert(__protocol.read_i32()?);
-                    };
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32()?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                     __protocol.read_set_end()?;
                     val}, __protocol.read_i32()?);
-                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end()?;
                         val
                     });
-                                                }
-                                                val.set_len(list_ident.size);
-                                                __protocol.read_list_end()?;
-                                                val
-                                            },
-                                            {
-                                                let list_ident = __protocol.read_set_begin()?;
-                                                let mut val = ::std::collections::BTreeSet::new();
-                                                for _ in 0..list_ident.size {
-                                                    val.insert(__protocol.read_i32()?);
-                                                }
-                                                __protocol.read_set_end()?;
-                                                val
-                                            },
-                                        );
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            val.set_len(list_ident.size);
+                            __protocol.read_list_end()?;
+                            val
+                        }, {let list_ident = __protocol.read_set_begin()?;
+                    let mut val = ::std::collections::BTreeSet::new();
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = (|| {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32()?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                    __protocol.read_set_end()?;
+                    val});
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -486,10 +627,16 @@ pub mod btree {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `B` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "B",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("m"),
+                                2 => ::std::option::Option::Some("s"),
+                                3 => ::std::option::Option::Some("m2"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -557,17 +704,33 @@ pub mod btree {
                     var_1 = Some({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert(__protocol.read_i32().await?, {
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?, {
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
-                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end().await?;
                         val
                     });
@@ -575,9 +738,17 @@ pub mod btree {
                 },Some(2) if field_ident.field_type == ::pilota::thrift::TType::Set  => {
                     var_2 = Some({let list_ident = __protocol.read_set_begin().await?;
                     let mut val = ::std::collections::BTreeSet::new();
-                    for _ in 0..list_ident.size {
-                        val.insert(__protocol.read_i32().await?);
-                    };
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                     __protocol.read_set_end().await?;
                     val});
 
@@ -585,37 +756,77 @@ pub mod btree {
                     var_3 = Some({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert({
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::std::collections::BTreeMap::new();
-                        for _ in 0..map_ident.size {
-                            val.insert({let list_ident = __protocol.read_set_begin().await?;
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert({let list_ident = __protocol.read_set_begin().await?;
                     let mut val = ::std::collections::BTreeSet::new();
-                    for _ in 0..list_ident.size {
-                        val.insert(__protocol.read_i32().await?);
-                    };
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                     __protocol.read_set_end().await?;
                     val}, __protocol.read_i32().await?);
-                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end().await?;
                         val
                     });
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         }, {let list_ident = __protocol.read_set_begin().await?;
                     let mut val = ::std::collections::BTreeSet::new();
-                    for _ in 0..list_ident.size {
-                        val.insert(__protocol.read_i32().await?);
-                    };
+                    let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?);
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                     __protocol.read_set_end().await?;
                     val});
-                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end().await?;
                         val
                     });
@@ -634,7 +845,14 @@ pub mod btree {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `B` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "B",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("m"),2 => ::std::option::Option::Some("s"),3 => ::std::option::Option::Some("m2"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -785,10 +1003,13 @@ pub mod btree {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Vec` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Vec",
+                            name: match field_id {
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -833,10 +1054,13 @@ pub mod btree {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Vec` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Vec",
+                                name: match field_id {
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1,5 +1,5 @@
 pub mod const_val {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod const_val {
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
@@ -91,6 +91,26 @@ pub mod const_val {
                 __protocol.i32_len(self.inner())
             }
         }
+        pub static TEST_NESTED: ::std::sync::LazyLock<
+            ::pilota::AHashMap<
+                &'static str,
+                &'static ::pilota::AHashMap<&'static str, ::std::vec::Vec<i32>>,
+            >,
+        > = ::std::sync::LazyLock::new(|| {
+            let mut map = ::pilota::AHashMap::with_capacity(1);
+            map.insert("outer", {
+                pub static INNER_MAP: ::std::sync::LazyLock<
+                    ::pilota::AHashMap<&'static str, ::std::vec::Vec<i32>>,
+                > = ::std::sync::LazyLock::new(|| {
+                    let mut map = ::pilota::AHashMap::with_capacity(1);
+                    map.insert("inner", ::std::vec![3i32]);
+                    map
+                });
+
+                &*INNER_MAP
+            });
+            map
+        });
         pub const TEST_STRUCT: Test = Test { name: None };
         pub static TEST_MAP: ::std::sync::LazyLock<::pilota::AHashMap<Index, &'static str>> =
             ::std::sync::LazyLock::new(|| {
@@ -99,7 +119,73 @@ pub mod const_val {
                 map.insert(Index::B, "world");
                 map
             });
+
+        pub static TEST_MAP_OF_LIST_OF_MAP: ::std::sync::LazyLock<
+            ::pilota::AHashMap<
+                &'static str,
+                ::std::vec::Vec<&'static ::pilota::AHashMap<&'static str, i32>>,
+            >,
+        > = ::std::sync::LazyLock::new(|| {
+            let mut map = ::pilota::AHashMap::with_capacity(1);
+            map.insert(
+                "a",
+                ::std::vec![{
+                    pub static INNER_MAP: ::std::sync::LazyLock<
+                        ::pilota::AHashMap<&'static str, i32>,
+                    > = ::std::sync::LazyLock::new(|| {
+                        let mut map = ::pilota::AHashMap::with_capacity(1);
+                        map.insert("x", 1i32);
+                        map
+                    });
+
+                    &*INNER_MAP
+                }],
+            );
+            map
+        });
+
+        pub static TEST_MAP_OF_LIST: ::std::sync::LazyLock<
+            ::pilota::AHashMap<&'static str, ::std::vec::Vec<i32>>,
+        > = ::std::sync::LazyLock::new(|| {
+            let mut map = ::pilota::AHashMap::with_capacity(2);
+            map.insert("a", ::std::vec![1i32, 2i32]);
+            map.insert("b", ::std::vec![]);
+            map
+        });
         pub const TEST_LIST: [&'static str; 2] = ["hello", "world"];
+        pub static TEST_LIST_OF_LIST: ::std::sync::LazyLock<[::std::vec::Vec<i32>; 2]> =
+            ::std::sync::LazyLock::new(|| [::std::vec![1i32], ::std::vec![2i32, 3i32]]);
+
+        pub static TEST_LIST_OF_MAP: ::std::sync::LazyLock<
+            [&'static ::pilota::AHashMap<&'static str, i32>; 2],
+        > = ::std::sync::LazyLock::new(|| {
+            [
+                {
+                    pub static INNER_MAP: ::std::sync::LazyLock<
+                        ::pilota::AHashMap<&'static str, i32>,
+                    > = ::std::sync::LazyLock::new(|| {
+                        let mut map = ::pilota::AHashMap::with_capacity(2);
+                        map.insert("a", 1i32);
+                        map.insert("b", 2i32);
+                        map
+                    });
+
+                    &*INNER_MAP
+                },
+                {
+                    pub static INNER_MAP: ::std::sync::LazyLock<
+                        ::pilota::AHashMap<&'static str, i32>,
+                    > = ::std::sync::LazyLock::new(|| {
+                        let mut map = ::pilota::AHashMap::with_capacity(0);
+
+                        map
+                    });
+
+                    &*INNER_MAP
+                },
+            ]
+        });
+
         pub static TEST_MAP_LIST: ::std::sync::LazyLock<
             ::pilota::AHashMap<i32, ::std::vec::Vec<&'static str>>,
         > = ::std::sync::LazyLock::new(|| {
@@ -169,11 +255,25 @@ pub mod const_val {
                                 var_1 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_faststr()?,
-                                            __protocol.read_faststr()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -190,10 +290,14 @@ pub mod const_val {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Test` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Test",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("name"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -235,11 +339,26 @@ pub mod const_val {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_faststr().await?,
-                                                __protocol.read_faststr().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_faststr().await?,
+                                                    __protocol.read_faststr().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -257,10 +376,14 @@ pub mod const_val {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Test` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Test",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("name"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
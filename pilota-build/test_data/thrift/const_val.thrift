@@ -24,4 +24,23 @@ const map<i32, list<string>> TEST_MAP_LIST = {
 }
 
 const Test TEST_STRUCT = {
-}
\ No newline at end of file
+}
+const map<string, list<i32>> TEST_MAP_OF_LIST = {
+    "a": [1, 2],
+    "b": [],
+}
+
+const list<map<string, i32>> TEST_LIST_OF_MAP = [
+    {"a": 1, "b": 2},
+    {},
+]
+
+const map<string, map<string, list<i32>>> TEST_NESTED = {
+    "outer": {"inner": [3]},
+}
+
+const map<string, list<map<string, i32>>> TEST_MAP_OF_LIST_OF_MAP = {
+    "a": [{"x": 1}],
+}
+
+const list<list<i32>> TEST_LIST_OF_LIST = [[1], [2, 3]]
@@ -11,4 +11,11 @@ struct B {
 
 struct C {
     3: required string a,
-}
\ No newline at end of file
+}
+struct D {
+    1: required list<B> bs,
+}
+struct E {
+    1: required map<string, B> bm,
+    2: required set<C> cs,
+}
@@ -1,5 +1,5 @@
 pub mod default_value {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod default_value {
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
@@ -168,10 +168,15 @@ pub mod default_value {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `C` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "C",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("off"),
+                                2 => ::std::option::Option::Some("test_byte"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -233,10 +238,15 @@ pub mod default_value {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `C` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "C",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("off"),
+                                    2 => ::std::option::Option::Some("test_byte"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -491,11 +501,25 @@ pub mod default_value {
                                 var_7 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_faststr()?,
-                                            __protocol.read_faststr()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -525,11 +549,25 @@ pub mod default_value {
                                 var_12 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            ::pilota::OrderedFloat(__protocol.read_double()?),
-                                            __protocol.read_double()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                ::pilota::OrderedFloat(__protocol.read_double()?),
+                                                __protocol.read_double()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -540,10 +578,24 @@ pub mod default_value {
                                     let list_ident = __protocol.read_set_begin()?;
                                     let mut val =
                                         ::pilota::AHashSet::with_capacity(list_ident.size);
-                                    for _ in 0..list_ident.size {
-                                        val.insert(::pilota::OrderedFloat(
-                                            __protocol.read_double()?,
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(::pilota::OrderedFloat(
+                                                __protocol.read_double()?,
+                                            ));
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
                                         ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_set_end()?;
                                     val
@@ -556,11 +608,25 @@ pub mod default_value {
                                 var_15 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(
-                                            __protocol.read_faststr()?,
-                                            __protocol.read_faststr()?,
-                                        );
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(
+                                                __protocol.read_faststr()?,
+                                                __protocol.read_faststr()?,
+                                            );
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -577,10 +643,28 @@ pub mod default_value {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `A` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "A",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("faststr"),
+                                2 => ::std::option::Option::Some("string"),
+                                3 => ::std::option::Option::Some("a"),
+                                4 => ::std::option::Option::Some("test_b"),
+                                5 => ::std::option::Option::Some("test_b2"),
+                                6 => ::std::option::Option::Some("test_b3"),
+                                7 => ::std::option::Option::Some("map"),
+                                8 => ::std::option::Option::Some("test_double"),
+                                9 => ::std::option::Option::Some("test_double2"),
+                                10 => ::std::option::Option::Some("alias_str"),
+                                11 => ::std::option::Option::Some("empty"),
+                                12 => ::std::option::Option::Some("test_map"),
+                                13 => ::std::option::Option::Some("test_set"),
+                                14 => ::std::option::Option::Some("a2"),
+                                15 => ::std::option::Option::Some("map2"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -712,11 +796,26 @@ pub mod default_value {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_faststr().await?,
-                                                __protocol.read_faststr().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_faststr().await?,
+                                                    __protocol.read_faststr().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -753,13 +852,28 @@ pub mod default_value {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                ::pilota::OrderedFloat(
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    ::pilota::OrderedFloat(
+                                                        __protocol.read_double().await?,
+                                                    ),
                                                     __protocol.read_double().await?,
-                                                ),
-                                                __protocol.read_double().await?,
-                                            );
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -772,10 +886,25 @@ pub mod default_value {
                                         let list_ident = __protocol.read_set_begin().await?;
                                         let mut val =
                                             ::pilota::AHashSet::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.insert(::pilota::OrderedFloat(
-                                                __protocol.read_double().await?,
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(::pilota::OrderedFloat(
+                                                    __protocol.read_double().await?,
+                                                ));
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
                                             ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_set_end().await?;
                                         val
@@ -793,11 +922,26 @@ pub mod default_value {
                                         let map_ident = __protocol.read_map_begin().await?;
                                         let mut val =
                                             ::pilota::AHashMap::with_capacity(map_ident.size);
-                                        for _ in 0..map_ident.size {
-                                            val.insert(
-                                                __protocol.read_faststr().await?,
-                                                __protocol.read_faststr().await?,
-                                            );
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = async {
+                                            for i in 0..map_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.insert(
+                                                    __protocol.read_faststr().await?,
+                                                    __protocol.read_faststr().await?,
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        }
+                                        .await
+                                        {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
                                         }
                                         __protocol.read_map_end().await?;
                                         val
@@ -815,10 +959,28 @@ pub mod default_value {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `A` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "A",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("faststr"),
+                                    2 => ::std::option::Option::Some("string"),
+                                    3 => ::std::option::Option::Some("a"),
+                                    4 => ::std::option::Option::Some("test_b"),
+                                    5 => ::std::option::Option::Some("test_b2"),
+                                    6 => ::std::option::Option::Some("test_b3"),
+                                    7 => ::std::option::Option::Some("map"),
+                                    8 => ::std::option::Option::Some("test_double"),
+                                    9 => ::std::option::Option::Some("test_double2"),
+                                    10 => ::std::option::Option::Some("alias_str"),
+                                    11 => ::std::option::Option::Some("empty"),
+                                    12 => ::std::option::Option::Some("test_map"),
+                                    13 => ::std::option::Option::Some("test_set"),
+                                    14 => ::std::option::Option::Some("a2"),
+                                    15 => ::std::option::Option::Some("map2"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -0,0 +1,8 @@
+(pilota.string_type = "String", pilota.default_requiredness = "required")
+
+struct Profile {
+    1: string name,
+    2: string nickname (pilota.string_type = "FastStr"),
+    3: optional string bio,
+    4: list<string> tags,
+}
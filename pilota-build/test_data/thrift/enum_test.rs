@@ -1,5 +1,5 @@
 pub mod enum_test {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod enum_test {
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
@@ -151,7 +151,14 @@ pub mod enum_test {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "TestTestEnumVarTypeNameConflictArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -216,7 +223,14 @@ pub mod enum_test {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "TestTestEnumVarTypeNameConflictArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -304,10 +318,14 @@ pub mod enum_test {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `TestTestEnumArgsSend` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "TestTestEnumArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -369,7 +387,14 @@ pub mod enum_test {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTestEnumArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "TestTestEnumArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -543,7 +568,14 @@ pub mod enum_test {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "TestTestEnumVarTypeNameConflictArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -608,7 +640,14 @@ pub mod enum_test {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTestEnumVarTypeNameConflictArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "TestTestEnumVarTypeNameConflictArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -844,10 +883,14 @@ pub mod enum_test {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `TestTestEnumArgsRecv` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "TestTestEnumArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -909,7 +952,14 @@ pub mod enum_test {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestTestEnumArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "TestTestEnumArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1157,10 +1207,16 @@ pub mod enum_test {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Request` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Request",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("Index"),
+                                2 => ::std::option::Option::Some("index"),
+                                3 => ::std::option::Option::Some("_enum"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -1250,10 +1306,16 @@ pub mod enum_test {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Request` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Request",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("Index"),
+                                    2 => ::std::option::Option::Some("index"),
+                                    3 => ::std::option::Option::Some("_enum"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -0,0 +1,26 @@
+enum Level {
+    LOW = 1,
+    HIGH = 2,
+}
+
+const i64 BIG = 4294967296
+
+const map<i64, string> NAMES = {
+    -1: "negative",
+    0: "zero",
+    4294967296: "big",
+}
+
+const map<i64, string> NAMED_KEYS = {
+    BIG: "big",
+}
+
+const map<Level, i32> WEIGHTS = {
+    Level.LOW: 1,
+    2: 10,
+}
+
+struct Limits {
+    1: required map<i64, string> names = {1: "one"},
+    2: required map<Level, i32> weights = {Level.HIGH: 3},
+}
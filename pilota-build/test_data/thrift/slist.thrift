@@ -0,0 +1,5 @@
+struct Person {
+    1: required slist names,
+    2: optional map<string, slist> aliases,
+    3: optional list<slist> groups,
+}
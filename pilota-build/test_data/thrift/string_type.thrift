@@ -0,0 +1,8 @@
+const string GREETING = "hello"
+
+struct Names {
+    1: required string fast,
+    2: required string owned (pilota.string_type = "String"),
+    3: optional string fast_default = "a" (pilota.string_type = "FastStr"),
+    4: optional string owned_default = GREETING (pilota.string_type = "String"),
+}
@@ -0,0 +1,13 @@
+typedef i32 A
+typedef A B
+typedef B C
+
+const C LIMIT = 5
+const list<C> LIMITS = [1, 2]
+
+struct S {
+    1: required C c = 3,
+    2: optional C d,
+    3: required list<C> cs,
+    4: required map<C, B> m,
+}
@@ -1,5 +1,5 @@
 pub mod underscore {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod underscore {
 
@@ -203,7 +203,14 @@ pub mod underscore {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `Test_UnderscoredArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Test_UnderscoredArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("param"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -263,7 +270,14 @@ pub mod underscore {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `Test_UnderscoredArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Test_UnderscoredArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("param"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -353,7 +367,14 @@ pub mod underscore {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `Test_UnderscoredArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Test_UnderscoredArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("param"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -413,7 +434,14 @@ pub mod underscore {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `Test_UnderscoredArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Test_UnderscoredArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("param"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
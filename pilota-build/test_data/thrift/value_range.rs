@@ -0,0 +1,357 @@
+pub mod value_range {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod value_range {
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
+        pub struct Limits {
+            percent: i32,
+
+            offset: ::std::option::Option<i64>,
+
+            level: ::std::option::Option<i8>,
+
+            pub name: ::pilota::FastStr,
+        }
+        impl Limits {
+            pub fn new(
+                percent: i32,
+                offset: ::std::option::Option<i64>,
+                level: ::std::option::Option<i8>,
+                name: ::pilota::FastStr,
+            ) -> ::std::result::Result<Self, ::pilota::RangeError> {
+                {
+                    let value = percent;
+                    if !(0i128..=100i128).contains(&(value as i128)) {
+                        return ::std::result::Result::Err(::pilota::RangeError {
+                            field: "percent",
+                            range: "0..=100",
+                            value: value as i128,
+                        });
+                    }
+                }
+                if let ::std::option::Option::Some(value) = offset {
+                    if !(-10i128..0i128).contains(&(value as i128)) {
+                        return ::std::result::Result::Err(::pilota::RangeError {
+                            field: "offset",
+                            range: "-10..0",
+                            value: value as i128,
+                        });
+                    }
+                }
+                if let ::std::option::Option::Some(value) = level {
+                    if !(1i128..).contains(&(value as i128)) {
+                        return ::std::result::Result::Err(::pilota::RangeError {
+                            field: "level",
+                            range: "1..",
+                            value: value as i128,
+                        });
+                    }
+                }
+                ::std::result::Result::Ok(Self {
+                    percent,
+                    offset,
+                    level,
+                    name,
+                })
+            }
+
+            pub fn percent(&self) -> i32 {
+                self.percent
+            }
+
+            pub fn set_percent(
+                &mut self,
+                percent: i32,
+            ) -> ::std::result::Result<(), ::pilota::RangeError> {
+                {
+                    let value = percent;
+                    if !(0i128..=100i128).contains(&(value as i128)) {
+                        return ::std::result::Result::Err(::pilota::RangeError {
+                            field: "percent",
+                            range: "0..=100",
+                            value: value as i128,
+                        });
+                    }
+                }
+                self.percent = percent;
+                ::std::result::Result::Ok(())
+            }
+
+            pub fn offset(&self) -> ::std::option::Option<i64> {
+                self.offset
+            }
+
+            pub fn set_offset(
+                &mut self,
+                offset: ::std::option::Option<i64>,
+            ) -> ::std::result::Result<(), ::pilota::RangeError> {
+                if let ::std::option::Option::Some(value) = offset {
+                    if !(-10i128..0i128).contains(&(value as i128)) {
+                        return ::std::result::Result::Err(::pilota::RangeError {
+                            field: "offset",
+                            range: "-10..0",
+                            value: value as i128,
+                        });
+                    }
+                }
+                self.offset = offset;
+                ::std::result::Result::Ok(())
+            }
+
+            pub fn level(&self) -> ::std::option::Option<i8> {
+                self.level
+            }
+
+            pub fn set_level(
+                &mut self,
+                level: ::std::option::Option<i8>,
+            ) -> ::std::result::Result<(), ::pilota::RangeError> {
+                if let ::std::option::Option::Some(value) = level {
+                    if !(1i128..).contains(&(value as i128)) {
+                        return ::std::result::Result::Err(::pilota::RangeError {
+                            field: "level",
+                            range: "1..",
+                            value: value as i128,
+                        });
+                    }
+                }
+                self.level = level;
+                ::std::result::Result::Ok(())
+            }
+        }
+
+        impl ::pilota::thrift::Message for Limits {
+            fn encode<T: ::pilota::thrift::TOutputProtocol>(
+                &self,
+                __protocol: &mut T,
+            ) -> ::std::result::Result<(), ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TOutputProtocolExt;
+                let struct_ident = ::pilota::thrift::TStructIdentifier { name: "Limits" };
+
+                __protocol.write_struct_begin(&struct_ident)?;
+                __protocol.write_i32_field(1, *&self.percent)?;
+                if let Some(value) = self.offset.as_ref() {
+                    __protocol.write_i64_field(2, *value)?;
+                }
+                if let Some(value) = self.level.as_ref() {
+                    __protocol.write_i8_field(3, *value)?;
+                }
+                __protocol.write_faststr_field(4, (&self.name).clone())?;
+                __protocol.write_field_stop()?;
+                __protocol.write_struct_end()?;
+                ::std::result::Result::Ok(())
+            }
+
+            fn decode<T: ::pilota::thrift::TInputProtocol>(
+                __protocol: &mut T,
+            ) -> ::std::result::Result<Self, ::pilota::thrift::ThriftException> {
+                #[allow(unused_imports)]
+                use ::pilota::{thrift::TLengthProtocolExt, Buf};
+
+                let mut var_1 = None;
+                let mut var_2 = None;
+                let mut var_3 = None;
+                let mut var_4 = None;
+
+                let mut __pilota_decoding_field_id = None;
+
+                __protocol.read_struct_begin()?;
+                if let ::std::result::Result::Err(mut err) = (|| {
+                    loop {
+                        let field_ident = __protocol.read_field_begin()?;
+                        if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                            __protocol.field_stop_len();
+                            break;
+                        } else {
+                            __protocol.field_begin_len(field_ident.field_type, field_ident.id);
+                        }
+                        __pilota_decoding_field_id = field_ident.id;
+                        match field_ident.id {
+                            Some(1) if field_ident.field_type == ::pilota::thrift::TType::I32 => {
+                                var_1 = Some(__protocol.read_i32()?);
+                            }
+                            Some(2) if field_ident.field_type == ::pilota::thrift::TType::I64 => {
+                                var_2 = Some(__protocol.read_i64()?);
+                            }
+                            Some(3) if field_ident.field_type == ::pilota::thrift::TType::I8 => {
+                                var_3 = Some(__protocol.read_i8()?);
+                            }
+                            Some(4)
+                                if field_ident.field_type == ::pilota::thrift::TType::Binary =>
+                            {
+                                var_4 = Some(__protocol.read_faststr()?);
+                            }
+                            _ => {
+                                __protocol.skip(field_ident.field_type)?;
+                            }
+                        }
+
+                        __protocol.read_field_end()?;
+                        __protocol.field_end_len();
+                    }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                })() {
+                    if let Some(field_id) = __pilota_decoding_field_id {
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Limits",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("percent"),
+                                2 => ::std::option::Option::Some("offset"),
+                                3 => ::std::option::Option::Some("level"),
+                                4 => ::std::option::Option::Some("name"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
+                    }
+                    return ::std::result::Result::Err(err);
+                };
+                __protocol.read_struct_end()?;
+
+                let Some(var_1) = var_1 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field percent is required".to_string(),
+                    ));
+                };
+                let Some(var_4) = var_4 else {
+                    return ::std::result::Result::Err(::pilota::thrift::new_protocol_exception(
+                        ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                        "field name is required".to_string(),
+                    ));
+                };
+
+                let data = Self {
+                    percent: var_1,
+                    offset: var_2,
+                    level: var_3,
+                    name: var_4,
+                };
+                ::std::result::Result::Ok(data)
+            }
+
+            fn decode_async<'a, T: ::pilota::thrift::TAsyncInputProtocol>(
+                __protocol: &'a mut T,
+            ) -> ::std::pin::Pin<
+                ::std::boxed::Box<
+                    dyn ::std::future::Future<
+                            Output = ::std::result::Result<Self, ::pilota::thrift::ThriftException>,
+                        > + Send
+                        + 'a,
+                >,
+            > {
+                ::std::boxed::Box::pin(async move {
+                    let mut var_1 = None;
+                    let mut var_2 = None;
+                    let mut var_3 = None;
+                    let mut var_4 = None;
+
+                    let mut __pilota_decoding_field_id = None;
+
+                    __protocol.read_struct_begin().await?;
+                    if let ::std::result::Result::Err(mut err) = async {
+                        loop {
+                            let field_ident = __protocol.read_field_begin().await?;
+                            if field_ident.field_type == ::pilota::thrift::TType::Stop {
+                                break;
+                            } else {
+                            }
+                            __pilota_decoding_field_id = field_ident.id;
+                            match field_ident.id {
+                                Some(1)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I32 =>
+                                {
+                                    var_1 = Some(__protocol.read_i32().await?);
+                                }
+                                Some(2)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I64 =>
+                                {
+                                    var_2 = Some(__protocol.read_i64().await?);
+                                }
+                                Some(3)
+                                    if field_ident.field_type == ::pilota::thrift::TType::I8 =>
+                                {
+                                    var_3 = Some(__protocol.read_i8().await?);
+                                }
+                                Some(4)
+                                    if field_ident.field_type
+                                        == ::pilota::thrift::TType::Binary =>
+                                {
+                                    var_4 = Some(__protocol.read_faststr().await?);
+                                }
+                                _ => {
+                                    __protocol.skip(field_ident.field_type).await?;
+                                }
+                            }
+
+                            __protocol.read_field_end().await?;
+                        }
+                        ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                    }
+                    .await
+                    {
+                        if let Some(field_id) = __pilota_decoding_field_id {
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Limits",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("percent"),
+                                    2 => ::std::option::Option::Some("offset"),
+                                    3 => ::std::option::Option::Some("level"),
+                                    4 => ::std::option::Option::Some("name"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
+                        }
+                        return ::std::result::Result::Err(err);
+                    };
+                    __protocol.read_struct_end().await?;
+
+                    let Some(var_1) = var_1 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field percent is required".to_string(),
+                            ),
+                        );
+                    };
+                    let Some(var_4) = var_4 else {
+                        return ::std::result::Result::Err(
+                            ::pilota::thrift::new_protocol_exception(
+                                ::pilota::thrift::ProtocolExceptionKind::InvalidData,
+                                "field name is required".to_string(),
+                            ),
+                        );
+                    };
+
+                    let data = Self {
+                        percent: var_1,
+                        offset: var_2,
+                        level: var_3,
+                        name: var_4,
+                    };
+                    ::std::result::Result::Ok(data)
+                })
+            }
+
+            fn size<T: ::pilota::thrift::TLengthProtocol>(&self, __protocol: &mut T) -> usize {
+                #[allow(unused_imports)]
+                use ::pilota::thrift::TLengthProtocolExt;
+                __protocol.struct_begin_len(&::pilota::thrift::TStructIdentifier { name: "Limits" })
+                    + __protocol.i32_field_len(Some(1), *&self.percent)
+                    + self
+                        .offset
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i64_field_len(Some(2), *value))
+                    + self
+                        .level
+                        .as_ref()
+                        .map_or(0, |value| __protocol.i8_field_len(Some(3), *value))
+                    + __protocol.faststr_field_len(Some(4), &self.name)
+                    + __protocol.field_stop_len()
+                    + __protocol.struct_end_len()
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+struct Limits {
+    1: required i32 percent (pilota.range = "0..=100"),
+    2: optional i64 offset (pilota.range = "-10..0"),
+    3: optional byte level (pilota.range = "1.."),
+    4: required string name,
+}
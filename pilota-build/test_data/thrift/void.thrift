@@ -1,3 +1,5 @@
 service Test {
    void test_123();
-}
\ No newline at end of file
+   void ping();
+   oneway void fire(1: i32 id);
+}
@@ -1,5 +1,5 @@
 pub mod wrapper_arc {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod wrapper_arc {
         #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq)]
@@ -51,10 +51,13 @@ pub mod wrapper_arc {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `A` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "A",
+                            name: match field_id {
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -99,10 +102,13 @@ pub mod wrapper_arc {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `A` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "A",
+                                name: match field_id {
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -327,7 +333,14 @@ pub mod wrapper_arc {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `TestServiceTestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "TestServiceTestArgsRecv",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -392,7 +405,14 @@ pub mod wrapper_arc {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestServiceTestArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "TestServiceTestArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -665,24 +685,58 @@ pub mod wrapper_arc {
                                     let mut val: ::std::vec::Vec<
                                         ::std::vec::Vec<::std::sync::Arc<A>>,
                                     > = ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(unsafe {
-                                            let list_ident = __protocol.read_list_begin()?;
-                                            let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
-                                                ::std::vec::Vec::with_capacity(list_ident.size);
-                                            for i in 0..list_ident.size {
-                                                val.as_mut_ptr().offset(i as isize).write(
-                                                    ::std::sync::Arc::new(
-                                                        ::pilota::thrift::Message::decode(
-                                                            __protocol,
-                                                        )?,
-                                                    ),
-                                                );
-                                            }
-                                            val.set_len(list_ident.size);
-                                            __protocol.read_list_end()?;
-                                            val
-                                        });
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..list_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.as_mut_ptr().offset(i as isize).write(unsafe {
+                                                let list_ident = __protocol.read_list_begin()?;
+                                                let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
+                                                    ::std::vec::Vec::with_capacity(list_ident.size);
+                                                let mut __pilota_decoding_index = 0;
+                                                if let ::std::result::Result::Err(mut err) =
+                                                    (|| {
+                                                        for i in 0..list_ident.size {
+                                                            __pilota_decoding_index = i;
+                                                            val.as_mut_ptr()
+                                                                .offset(i as isize)
+                                                                .write(::std::sync::Arc::new(
+                                                                ::pilota::thrift::Message::decode(
+                                                                    __protocol,
+                                                                )?,
+                                                            ));
+                                                        }
+                                                        ::std::result::Result::Ok::<
+                                                            _,
+                                                            ::pilota::thrift::ThriftException,
+                                                        >(
+                                                            ()
+                                                        )
+                                                    })(
+                                                    )
+                                                {
+                                                    err.prepend_path(
+                                                        ::pilota::thrift::PathSegment::Index(
+                                                            __pilota_decoding_index,
+                                                        ),
+                                                    );
+                                                    return ::std::result::Result::Err(err);
+                                                }
+                                                val.set_len(list_ident.size);
+                                                __protocol.read_list_end()?;
+                                                val
+                                            });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     val.set_len(list_ident.size);
                                     __protocol.read_list_end()?;
@@ -693,24 +747,58 @@ pub mod wrapper_arc {
                                 var_3 = Some({
                                     let map_ident = __protocol.read_map_begin()?;
                                     let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                    for _ in 0..map_ident.size {
-                                        val.insert(__protocol.read_i32()?, unsafe {
-                                            let list_ident = __protocol.read_list_begin()?;
-                                            let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
-                                                ::std::vec::Vec::with_capacity(list_ident.size);
-                                            for i in 0..list_ident.size {
-                                                val.as_mut_ptr().offset(i as isize).write(
-                                                    ::std::sync::Arc::new(
-                                                        ::pilota::thrift::Message::decode(
-                                                            __protocol,
-                                                        )?,
-                                                    ),
-                                                );
-                                            }
-                                            val.set_len(list_ident.size);
-                                            __protocol.read_list_end()?;
-                                            val
-                                        });
+                                    let mut __pilota_decoding_index = 0;
+                                    if let ::std::result::Result::Err(mut err) = (|| {
+                                        for i in 0..map_ident.size {
+                                            __pilota_decoding_index = i;
+                                            val.insert(__protocol.read_i32()?, unsafe {
+                                                let list_ident = __protocol.read_list_begin()?;
+                                                let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
+                                                    ::std::vec::Vec::with_capacity(list_ident.size);
+                                                let mut __pilota_decoding_index = 0;
+                                                if let ::std::result::Result::Err(mut err) =
+                                                    (|| {
+                                                        for i in 0..list_ident.size {
+                                                            __pilota_decoding_index = i;
+                                                            val.as_mut_ptr()
+                                                                .offset(i as isize)
+                                                                .write(::std::sync::Arc::new(
+                                                                ::pilota::thrift::Message::decode(
+                                                                    __protocol,
+                                                                )?,
+                                                            ));
+                                                        }
+                                                        ::std::result::Result::Ok::<
+                                                            _,
+                                                            ::pilota::thrift::ThriftException,
+                                                        >(
+                                                            ()
+                                                        )
+                                                    })(
+                                                    )
+                                                {
+                                                    err.prepend_path(
+                                                        ::pilota::thrift::PathSegment::Index(
+                                                            __pilota_decoding_index,
+                                                        ),
+                                                    );
+                                                    return ::std::result::Result::Err(err);
+                                                }
+                                                val.set_len(list_ident.size);
+                                                __protocol.read_list_end()?;
+                                                val
+                                            });
+                                        }
+                                        ::std::result::Result::Ok::<
+                                            _,
+                                            ::pilota::thrift::ThriftException,
+                                        >(())
+                                    })(
+                                    ) {
+                                        err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                            __pilota_decoding_index,
+                                        ));
+                                        return ::std::result::Result::Err(err);
                                     }
                                     __protocol.read_map_end()?;
                                     val
@@ -727,10 +815,16 @@ pub mod wrapper_arc {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Test` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Test",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("ID"),
+                                2 => ::std::option::Option::Some("Name2"),
+                                3 => ::std::option::Option::Some("Name3"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -801,17 +895,33 @@ pub mod wrapper_arc {
                     var_2 = Some({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
@@ -820,17 +930,33 @@ pub mod wrapper_arc {
                     var_3 = Some({
                         let map_ident = __protocol.read_map_begin().await?;
                         let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                        for _ in 0..map_ident.size {
-                            val.insert(__protocol.read_i32().await?, {
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?, {
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
-                        }
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                         __protocol.read_map_end().await?;
                         val
                     });
@@ -849,7 +975,14 @@ pub mod wrapper_arc {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Test` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Test",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("ID"),2 => ::std::option::Option::Some("Name2"),3 => ::std::option::Option::Some("Name3"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -986,7 +1119,14 @@ pub mod wrapper_arc {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!("decode struct `TestServiceTestArgsSend` field(#{}) failed, caused by: ", field_id));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "TestServiceTestArgsSend",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("req"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -1051,7 +1191,14 @@ pub mod wrapper_arc {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `TestServiceTestArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "TestServiceTestArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -1,5 +1,5 @@
 pub mod wrapper_arc {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod wrapper_arc {
         include!("wrapper_arc/mod.rs");
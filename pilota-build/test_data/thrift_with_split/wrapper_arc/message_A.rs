@@ -47,10 +47,13 @@ impl ::pilota::thrift::Message for A {
             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
         })() {
             if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `A` field(#{}) failed, caused by: ",
-                    field_id
-                ));
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "A",
+                    name: match field_id {
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
             }
             return ::std::result::Result::Err(err);
         };
@@ -95,10 +98,13 @@ impl ::pilota::thrift::Message for A {
             .await
             {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!(
-                        "decode struct `A` field(#{}) failed, caused by: ",
-                        field_id
-                    ));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "A",
+                        name: match field_id {
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -91,22 +91,49 @@ impl ::pilota::thrift::Message for Test {
                             let list_ident = __protocol.read_list_begin()?;
                             let mut val: ::std::vec::Vec<::std::vec::Vec<::std::sync::Arc<A>>> =
                                 ::std::vec::Vec::with_capacity(list_ident.size);
-                            for i in 0..list_ident.size {
-                                val.as_mut_ptr().offset(i as isize).write(unsafe {
-                                    let list_ident = __protocol.read_list_begin()?;
-                                    let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
-                                        ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(
-                                            ::std::sync::Arc::new(
-                                                ::pilota::thrift::Message::decode(__protocol)?,
-                                            ),
-                                        );
-                                    }
-                                    val.set_len(list_ident.size);
-                                    __protocol.read_list_end()?;
-                                    val
-                                });
+                            let mut __pilota_decoding_index = 0;
+                            if let ::std::result::Result::Err(mut err) = (|| {
+                                for i in 0..list_ident.size {
+                                    __pilota_decoding_index = i;
+                                    val.as_mut_ptr().offset(i as isize).write(unsafe {
+                                        let list_ident = __protocol.read_list_begin()?;
+                                        let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
+                                            ::std::vec::Vec::with_capacity(list_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = (|| {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.as_mut_ptr().offset(i as isize).write(
+                                                    ::std::sync::Arc::new(
+                                                        ::pilota::thrift::Message::decode(
+                                                            __protocol,
+                                                        )?,
+                                                    ),
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        })(
+                                        ) {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        val.set_len(list_ident.size);
+                                        __protocol.read_list_end()?;
+                                        val
+                                    });
+                                }
+                                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                            })(
+                            ) {
+                                err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                    __pilota_decoding_index,
+                                ));
+                                return ::std::result::Result::Err(err);
                             }
                             val.set_len(list_ident.size);
                             __protocol.read_list_end()?;
@@ -117,22 +144,49 @@ impl ::pilota::thrift::Message for Test {
                         var_3 = Some({
                             let map_ident = __protocol.read_map_begin()?;
                             let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                            for _ in 0..map_ident.size {
-                                val.insert(__protocol.read_i32()?, unsafe {
-                                    let list_ident = __protocol.read_list_begin()?;
-                                    let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
-                                        ::std::vec::Vec::with_capacity(list_ident.size);
-                                    for i in 0..list_ident.size {
-                                        val.as_mut_ptr().offset(i as isize).write(
-                                            ::std::sync::Arc::new(
-                                                ::pilota::thrift::Message::decode(__protocol)?,
-                                            ),
-                                        );
-                                    }
-                                    val.set_len(list_ident.size);
-                                    __protocol.read_list_end()?;
-                                    val
-                                });
+                            let mut __pilota_decoding_index = 0;
+                            if let ::std::result::Result::Err(mut err) = (|| {
+                                for i in 0..map_ident.size {
+                                    __pilota_decoding_index = i;
+                                    val.insert(__protocol.read_i32()?, unsafe {
+                                        let list_ident = __protocol.read_list_begin()?;
+                                        let mut val: ::std::vec::Vec<::std::sync::Arc<A>> =
+                                            ::std::vec::Vec::with_capacity(list_ident.size);
+                                        let mut __pilota_decoding_index = 0;
+                                        if let ::std::result::Result::Err(mut err) = (|| {
+                                            for i in 0..list_ident.size {
+                                                __pilota_decoding_index = i;
+                                                val.as_mut_ptr().offset(i as isize).write(
+                                                    ::std::sync::Arc::new(
+                                                        ::pilota::thrift::Message::decode(
+                                                            __protocol,
+                                                        )?,
+                                                    ),
+                                                );
+                                            }
+                                            ::std::result::Result::Ok::<
+                                                _,
+                                                ::pilota::thrift::ThriftException,
+                                            >(())
+                                        })(
+                                        ) {
+                                            err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                                __pilota_decoding_index,
+                                            ));
+                                            return ::std::result::Result::Err(err);
+                                        }
+                                        val.set_len(list_ident.size);
+                                        __protocol.read_list_end()?;
+                                        val
+                                    });
+                                }
+                                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                            })(
+                            ) {
+                                err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                    __pilota_decoding_index,
+                                ));
+                                return ::std::result::Result::Err(err);
                             }
                             __protocol.read_map_end()?;
                             val
@@ -149,10 +203,16 @@ impl ::pilota::thrift::Message for Test {
             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
         })() {
             if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `Test` field(#{}) failed, caused by: ",
-                    field_id
-                ));
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "Test",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("ID"),
+                        2 => ::std::option::Option::Some("Name2"),
+                        3 => ::std::option::Option::Some("Name3"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
             }
             return ::std::result::Result::Err(err);
         };
@@ -204,83 +264,113 @@ impl ::pilota::thrift::Message for Test {
 
             __protocol.read_struct_begin().await?;
             if let ::std::result::Result::Err(mut err) = async {
-                loop {
-                    let field_ident = __protocol.read_field_begin().await?;
-                    if field_ident.field_type == ::pilota::thrift::TType::Stop {
-                        break;
-                    } else {
-                    }
-                    __pilota_decoding_field_id = field_ident.id;
-                    match field_ident.id {
-                        Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary => {
-                            var_1 = Some(__protocol.read_faststr().await?);
-                        }
-                        Some(2) if field_ident.field_type == ::pilota::thrift::TType::List => {
-                            var_2 = Some({
-                                let list_ident = __protocol.read_list_begin().await?;
-                                let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                                for _ in 0..list_ident.size {
-                                    val.push({
-                                        let list_ident = __protocol.read_list_begin().await?;
-                                        let mut val =
-                                            ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push(::std::sync::Arc::new(
-                                                <A as ::pilota::thrift::Message>::decode_async(
-                                                    __protocol,
-                                                )
-                                                .await?,
-                                            ));
-                                        }
-                                        __protocol.read_list_end().await?;
-                                        val
-                                    });
-                                }
-                                __protocol.read_list_end().await?;
-                                val
-                            });
-                        }
-                        Some(3) if field_ident.field_type == ::pilota::thrift::TType::Map => {
-                            var_3 = Some({
-                                let map_ident = __protocol.read_map_begin().await?;
-                                let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
-                                for _ in 0..map_ident.size {
-                                    val.insert(__protocol.read_i32().await?, {
-                                        let list_ident = __protocol.read_list_begin().await?;
-                                        let mut val =
-                                            ::std::vec::Vec::with_capacity(list_ident.size);
-                                        for _ in 0..list_ident.size {
-                                            val.push(::std::sync::Arc::new(
-                                                <A as ::pilota::thrift::Message>::decode_async(
-                                                    __protocol,
-                                                )
-                                                .await?,
-                                            ));
-                                        }
-                                        __protocol.read_list_end().await?;
-                                        val
-                                    });
-                                }
-                                __protocol.read_map_end().await?;
-                                val
-                            });
-                        }
-                        _ => {
-                            __protocol.skip(field_ident.field_type).await?;
-                        }
-                    }
+                    loop {
+
+
+                let field_ident = __protocol.read_field_begin().await?;
+                if field_ident.field_type == ::pilota::thrift::TType::Stop {
+
+                    break;
+                } else {
 
-                    __protocol.read_field_end().await?;
                 }
-                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                __pilota_decoding_field_id = field_ident.id;
+                match field_ident.id {
+                    Some(1) if field_ident.field_type == ::pilota::thrift::TType::Binary  => {
+                    var_1 = Some(__protocol.read_faststr().await?);
+
+                },Some(2) if field_ident.field_type == ::pilota::thrift::TType::List  => {
+                    var_2 = Some({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push({
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+
+                },Some(3) if field_ident.field_type == ::pilota::thrift::TType::Map  => {
+                    var_3 = Some({
+                        let map_ident = __protocol.read_map_begin().await?;
+                        let mut val = ::pilota::AHashMap::with_capacity(map_ident.size);
+                        let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..map_ident.size {
+                    __pilota_decoding_index = i;
+                    val.insert(__protocol.read_i32().await?, {
+                            let list_ident = __protocol.read_list_begin().await?;
+                            let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
+                                val.push(::std::sync::Arc::new(<A as ::pilota::thrift::Message>::decode_async(__protocol).await?));
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
             }
-            .await
-            {
+                            __protocol.read_list_end().await?;
+                            val
+                        });
+                }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
+                        __protocol.read_map_end().await?;
+                        val
+                    });
+
+                },
+                    _ => {
+                        __protocol.skip(field_ident.field_type).await?;
+
+                    },
+                }
+
+                __protocol.read_field_end().await?;
+
+
+            };
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!(
-                        "decode struct `Test` field(#{}) failed, caused by: ",
-                        field_id
-                    ));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Test",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("ID"),2 => ::std::option::Option::Some("Name2"),3 => ::std::option::Option::Some("Name3"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
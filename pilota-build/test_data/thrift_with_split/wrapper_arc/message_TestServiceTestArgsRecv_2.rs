@@ -56,10 +56,14 @@ impl ::pilota::thrift::Message for TestServiceTestArgsRecv {
             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
         })() {
             if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `TestServiceTestArgsRecv` field(#{}) failed, caused by: ",
-                    field_id
-                ));
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "TestServiceTestArgsRecv",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("req"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
             }
             return ::std::result::Result::Err(err);
         };
@@ -119,10 +123,14 @@ impl ::pilota::thrift::Message for TestServiceTestArgsRecv {
             .await
             {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!(
-                        "decode struct `TestServiceTestArgsRecv` field(#{}) failed, caused by: ",
-                        field_id
-                    ));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "TestServiceTestArgsRecv",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("req"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -58,10 +58,14 @@ impl ::pilota::thrift::Message for TestServiceTestArgsSend {
             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
         })() {
             if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `TestServiceTestArgsSend` field(#{}) failed, caused by: ",
-                    field_id
-                ));
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "TestServiceTestArgsSend",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("req"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
             }
             return ::std::result::Result::Err(err);
         };
@@ -121,10 +125,14 @@ impl ::pilota::thrift::Message for TestServiceTestArgsSend {
             .await
             {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!(
-                        "decode struct `TestServiceTestArgsSend` field(#{}) failed, caused by: ",
-                        field_id
-                    ));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "TestServiceTestArgsSend",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("req"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -56,10 +56,14 @@ impl ::pilota::thrift::Message for testServiceTestArgsRecv {
             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
         })() {
             if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `testServiceTestArgsRecv` field(#{}) failed, caused by: ",
-                    field_id
-                ));
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "testServiceTestArgsRecv",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("req"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
             }
             return ::std::result::Result::Err(err);
         };
@@ -119,10 +123,14 @@ impl ::pilota::thrift::Message for testServiceTestArgsRecv {
             .await
             {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!(
-                        "decode struct `testServiceTestArgsRecv` field(#{}) failed, caused by: ",
-                        field_id
-                    ));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "testServiceTestArgsRecv",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("req"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -58,10 +58,14 @@ impl ::pilota::thrift::Message for testServiceTestArgsSend {
             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
         })() {
             if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `testServiceTestArgsSend` field(#{}) failed, caused by: ",
-                    field_id
-                ));
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "testServiceTestArgsSend",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("req"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
             }
             return ::std::result::Result::Err(err);
         };
@@ -121,10 +125,14 @@ impl ::pilota::thrift::Message for testServiceTestArgsSend {
             .await
             {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!(
-                        "decode struct `testServiceTestArgsSend` field(#{}) failed, caused by: ",
-                        field_id
-                    ));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "testServiceTestArgsSend",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("req"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -1,5 +1,5 @@
 pub mod gen {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod article {
 
@@ -97,10 +97,17 @@ pub mod gen {
                         ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     })() {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `Image` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "Image",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("id"),
+                                    2 => ::std::option::Option::Some("url"),
+                                    3 => ::std::option::Option::Some("cdn"),
+                                    4 => ::std::option::Option::Some("common_data"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -209,7 +216,14 @@ pub mod gen {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Image` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Image",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("id"),2 => ::std::option::Option::Some("url"),3 => ::std::option::Option::Some("cdn"),4 => ::std::option::Option::Some("common_data"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -359,10 +373,16 @@ pub mod gen {
                             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                         })() {
                             if let Some(field_id) = __pilota_decoding_field_id {
-                                err.prepend_msg(&format!(
-                                    "decode struct `Cdn` field(#{}) failed, caused by: ",
-                                    field_id
-                                ));
+                                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                    message: "Cdn",
+                                    name: match field_id {
+                                        1 => ::std::option::Option::Some("id"),
+                                        2 => ::std::option::Option::Some("url"),
+                                        3 => ::std::option::Option::Some("common_data"),
+                                        _ => ::std::option::Option::None,
+                                    },
+                                    id: field_id,
+                                });
                             }
                             return ::std::result::Result::Err(err);
                         };
@@ -458,7 +478,14 @@ pub mod gen {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Cdn` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Cdn",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("id"),2 => ::std::option::Option::Some("url"),3 => ::std::option::Option::Some("common_data"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -614,10 +641,18 @@ pub mod gen {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `Author` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "Author",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("id"),
+                                2 => ::std::option::Option::Some("username"),
+                                3 => ::std::option::Option::Some("email"),
+                                4 => ::std::option::Option::Some("avatar"),
+                                5 => ::std::option::Option::Some("common_data"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -726,7 +761,14 @@ pub mod gen {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Author` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Author",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("id"),2 => ::std::option::Option::Some("username"),3 => ::std::option::Option::Some("email"),4 => ::std::option::Option::Some("avatar"),5 => ::std::option::Option::Some("common_data"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -874,10 +916,16 @@ pub mod gen {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 })() {
                     if let Some(field_id) = __pilota_decoding_field_id {
-                        err.prepend_msg(&format!(
-                            "decode struct `CommonData` field(#{}) failed, caused by: ",
-                            field_id
-                        ));
+                        err.prepend_path(::pilota::thrift::PathSegment::Field {
+                            message: "CommonData",
+                            name: match field_id {
+                                1 => ::std::option::Option::Some("id"),
+                                2 => ::std::option::Option::Some("name"),
+                                3 => ::std::option::Option::Some("description"),
+                                _ => ::std::option::Option::None,
+                            },
+                            id: field_id,
+                        });
                     }
                     return ::std::result::Result::Err(err);
                 };
@@ -966,10 +1014,16 @@ pub mod gen {
                     .await
                     {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `CommonData` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "CommonData",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("id"),
+                                    2 => ::std::option::Option::Some("name"),
+                                    3 => ::std::option::Option::Some("description"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
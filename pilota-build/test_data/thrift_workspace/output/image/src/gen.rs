@@ -1,5 +1,5 @@
 pub mod gen {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod article {
 
@@ -66,7 +66,14 @@ pub mod gen {
                         ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     })() {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ImageServiceGetImageArgsRecv` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ImageServiceGetImageArgsRecv",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -134,7 +141,14 @@ pub mod gen {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `ImageServiceGetImageArgsRecv` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "ImageServiceGetImageArgsRecv",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("req"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -377,10 +391,14 @@ pub mod gen {
                         ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     })() {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `GetImageResponse` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "GetImageResponse",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("image"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -448,7 +466,14 @@ pub mod gen {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `GetImageResponse` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "GetImageResponse",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("image"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -540,7 +565,14 @@ pub mod gen {
                         ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     })() {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!("decode struct `ImageServiceGetImageArgsSend` field(#{}) failed, caused by: ", field_id));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "ImageServiceGetImageArgsSend",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("req"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -608,7 +640,14 @@ pub mod gen {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `ImageServiceGetImageArgsSend` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "ImageServiceGetImageArgsSend",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("req"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -699,10 +738,14 @@ pub mod gen {
                         ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                     })() {
                         if let Some(field_id) = __pilota_decoding_field_id {
-                            err.prepend_msg(&format!(
-                                "decode struct `GetImageRequest` field(#{}) failed, caused by: ",
-                                field_id
-                            ));
+                            err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                message: "GetImageRequest",
+                                name: match field_id {
+                                    1 => ::std::option::Option::Some("id"),
+                                    _ => ::std::option::Option::None,
+                                },
+                                id: field_id,
+                            });
                         }
                         return ::std::result::Result::Err(err);
                     };
@@ -767,7 +810,14 @@ pub mod gen {
                         .await
                         {
                             if let Some(field_id) = __pilota_decoding_field_id {
-                                err.prepend_msg(&format!("decode struct `GetImageRequest` field(#{}) failed, caused by: ", field_id));
+                                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                                    message: "GetImageRequest",
+                                    name: match field_id {
+                                        1 => ::std::option::Option::Some("id"),
+                                        _ => ::std::option::Option::None,
+                                    },
+                                    id: field_id,
+                                });
                             }
                             return ::std::result::Result::Err(err);
                         };
@@ -92,10 +92,21 @@ impl ::pilota::thrift::Message for Article {
                             let list_ident = __protocol.read_list_begin()?;
                             let mut val: ::std::vec::Vec<::common::article::image::Image> =
                                 ::std::vec::Vec::with_capacity(list_ident.size);
-                            for i in 0..list_ident.size {
-                                val.as_mut_ptr()
-                                    .offset(i as isize)
-                                    .write(::pilota::thrift::Message::decode(__protocol)?);
+                            let mut __pilota_decoding_index = 0;
+                            if let ::std::result::Result::Err(mut err) = (|| {
+                                for i in 0..list_ident.size {
+                                    __pilota_decoding_index = i;
+                                    val.as_mut_ptr()
+                                        .offset(i as isize)
+                                        .write(::pilota::thrift::Message::decode(__protocol)?);
+                                }
+                                ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                            })(
+                            ) {
+                                err.prepend_path(::pilota::thrift::PathSegment::Index(
+                                    __pilota_decoding_index,
+                                ));
+                                return ::std::result::Result::Err(err);
                             }
                             val.set_len(list_ident.size);
                             __protocol.read_list_end()?;
@@ -116,10 +127,20 @@ impl ::pilota::thrift::Message for Article {
             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
         })() {
             if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `Article` field(#{}) failed, caused by: ",
-                    field_id
-                ));
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "Article",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("id"),
+                        2 => ::std::option::Option::Some("title"),
+                        3 => ::std::option::Option::Some("content"),
+                        4 => ::std::option::Option::Some("author"),
+                        5 => ::std::option::Option::Some("status"),
+                        6 => ::std::option::Option::Some("images"),
+                        7 => ::std::option::Option::Some("common_data"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
             }
             return ::std::result::Result::Err(err);
         };
@@ -234,9 +255,17 @@ impl ::pilota::thrift::Message for Article {
                     var_6 = Some({
                             let list_ident = __protocol.read_list_begin().await?;
                             let mut val = ::std::vec::Vec::with_capacity(list_ident.size);
-                            for _ in 0..list_ident.size {
+                            let mut __pilota_decoding_index = 0;
+            if let ::std::result::Result::Err(mut err) = async {
+                    for i in 0..list_ident.size {
+                                __pilota_decoding_index = i;
                                 val.push(<::common::article::image::Image as ::pilota::thrift::Message>::decode_async(__protocol).await?);
-                            };
+                            }
+                    ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
+                }.await {
+                err.prepend_path(::pilota::thrift::PathSegment::Index(__pilota_decoding_index));
+                return ::std::result::Result::Err(err);
+            }
                             __protocol.read_list_end().await?;
                             val
                         });
@@ -258,7 +287,14 @@ impl ::pilota::thrift::Message for Article {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Article` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Article",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("id"),2 => ::std::option::Option::Some("title"),3 => ::std::option::Option::Some("content"),4 => ::std::option::Option::Some("author"),5 => ::std::option::Option::Some("status"),6 => ::std::option::Option::Some("images"),7 => ::std::option::Option::Some("common_data"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
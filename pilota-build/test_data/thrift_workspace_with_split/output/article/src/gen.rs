@@ -1,5 +1,5 @@
 pub mod gen {
-    #![allow(warnings, clippy::all)]
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
 
     pub mod article {
         include!("article/mod.rs");
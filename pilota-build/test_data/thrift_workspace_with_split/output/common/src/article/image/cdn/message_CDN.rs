@@ -68,10 +68,16 @@ impl ::pilota::thrift::Message for Cdn {
             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
         })() {
             if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `Cdn` field(#{}) failed, caused by: ",
-                    field_id
-                ));
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "Cdn",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("id"),
+                        2 => ::std::option::Option::Some("url"),
+                        3 => ::std::option::Option::Some("common_data"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
             }
             return ::std::result::Result::Err(err);
         };
@@ -158,7 +164,14 @@ impl ::pilota::thrift::Message for Cdn {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Cdn` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Cdn",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("id"),2 => ::std::option::Option::Some("url"),3 => ::std::option::Option::Some("common_data"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -75,10 +75,17 @@ impl ::pilota::thrift::Message for Image {
             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
         })() {
             if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `Image` field(#{}) failed, caused by: ",
-                    field_id
-                ));
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "Image",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("id"),
+                        2 => ::std::option::Option::Some("url"),
+                        3 => ::std::option::Option::Some("cdn"),
+                        4 => ::std::option::Option::Some("common_data"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
             }
             return ::std::result::Result::Err(err);
         };
@@ -176,7 +183,14 @@ impl ::pilota::thrift::Message for Image {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Image` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Image",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("id"),2 => ::std::option::Option::Some("url"),3 => ::std::option::Option::Some("cdn"),4 => ::std::option::Option::Some("common_data"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
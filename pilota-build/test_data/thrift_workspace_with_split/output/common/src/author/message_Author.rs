@@ -82,10 +82,18 @@ impl ::pilota::thrift::Message for Author {
             ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
         })() {
             if let Some(field_id) = __pilota_decoding_field_id {
-                err.prepend_msg(&format!(
-                    "decode struct `Author` field(#{}) failed, caused by: ",
-                    field_id
-                ));
+                err.prepend_path(::pilota::thrift::PathSegment::Field {
+                    message: "Author",
+                    name: match field_id {
+                        1 => ::std::option::Option::Some("id"),
+                        2 => ::std::option::Option::Some("username"),
+                        3 => ::std::option::Option::Some("email"),
+                        4 => ::std::option::Option::Some("avatar"),
+                        5 => ::std::option::Option::Some("common_data"),
+                        _ => ::std::option::Option::None,
+                    },
+                    id: field_id,
+                });
             }
             return ::std::result::Result::Err(err);
         };
@@ -194,7 +202,14 @@ impl ::pilota::thrift::Message for Author {
                     ::std::result::Result::Ok::<_, ::pilota::thrift::ThriftException>(())
                 }.await {
                 if let Some(field_id) = __pilota_decoding_field_id {
-                    err.prepend_msg(&format!("decode struct `Author` field(#{}) failed, caused by: ", field_id));
+                    err.prepend_path(::pilota::thrift::PathSegment::Field {
+                        message: "Author",
+                        name: match field_id {
+                            1 => ::std::option::Option::Some("id"),2 => ::std::option::Option::Some("username"),3 => ::std::option::Option::Some("email"),4 => ::std::option::Option::Some("avatar"),5 => ::std::option::Option::Some("common_data"),
+                            _ => ::std::option::Option::None,
+                        },
+                        id: field_id,
+                    });
                 }
                 return ::std::result::Result::Err(err);
             };
@@ -0,0 +1,6 @@
+typedef X Y
+typedef Y X
+
+struct S {
+    1: required X x,
+}
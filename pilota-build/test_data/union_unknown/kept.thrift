@@ -0,0 +1,3 @@
+union KeptUnion {
+    1: string a,
+}
@@ -0,0 +1,22 @@
+include "kept.thrift"
+
+union Union {
+    1: string a,
+    2: binary b,
+    3: i32 c,
+}
+
+// a newer version of `Union`
+union UnionV2 {
+    1: string a,
+    2: binary b,
+    3: i32 c,
+    4: i64 d,
+}
+
+union Empty {}
+
+struct A {
+    1: Union u,
+    2: kept.KeptUnion k,
+}
@@ -0,0 +1,9 @@
+enum Level {
+    LOW = 1,
+    HIGH = 2,
+}
+
+struct Alert {
+    1: required Level level,
+    2: optional list<Level> history,
+}
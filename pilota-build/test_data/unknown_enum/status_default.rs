@@ -0,0 +1,146 @@
+pub mod status_default {
+    #![allow(warnings, clippy::all, non_snake_case, unused)]
+
+    pub mod status_default {
+        #[derive(Debug, Default, Clone, PartialEq)]
+        pub struct Job {
+            pub status: Status,
+
+            pub history: ::std::vec::Vec<Status>,
+
+            pub tasks: ::pilota::AHashMap<::pilota::FastStr, Status>,
+        }
+        impl ::pilota::prost::Message for Job {
+            #[inline]
+            fn encoded_len(&self) -> usize {
+                0 + ::pilota::prost::encoding::enumeration::encoded_len(1, &self.status)
+                    + ::pilota::prost::encoding::enumeration::encoded_len_repeated(2, &self.history)
+                    + ::pilota::prost::encoding::hash_map::encoded_len(
+                        ::pilota::prost::encoding::faststr::encoded_len,
+                        ::pilota::prost::encoding::enumeration::encoded_len,
+                        3,
+                        &self.tasks,
+                    )
+            }
+
+            #[allow(unused_variables)]
+            fn encode_raw<B>(&self, buf: &mut B)
+            where
+                B: ::pilota::prost::bytes::BufMut,
+            {
+                ::pilota::prost::encoding::enumeration::encode(1, &self.status, buf);
+                ::pilota::prost::encoding::enumeration::encode_repeated(2, &self.history, buf);
+                ::pilota::prost::encoding::hash_map::encode(
+                    ::pilota::prost::encoding::faststr::encode,
+                    ::pilota::prost::encoding::faststr::encoded_len,
+                    ::pilota::prost::encoding::enumeration::encode,
+                    ::pilota::prost::encoding::enumeration::encoded_len,
+                    3,
+                    &self.tasks,
+                    buf,
+                );
+            }
+
+            #[allow(unused_variables)]
+            fn merge_field<B>(
+                &mut self,
+                tag: u32,
+                wire_type: ::pilota::prost::encoding::WireType,
+                buf: &mut B,
+                ctx: ::pilota::prost::encoding::DecodeContext,
+            ) -> ::core::result::Result<(), ::pilota::prost::DecodeError>
+            where
+                B: ::pilota::prost::bytes::Buf,
+            {
+                const STRUCT_NAME: &'static str = stringify!(Job);
+                match tag {
+                    1 => {
+                        let mut _inner_pilota_value = &mut self.status;
+                        ::pilota::prost::encoding::enumeration::merge(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(status));
+                            error
+                        })
+                    }
+                    2 => {
+                        let mut _inner_pilota_value = &mut self.history;
+                        ::pilota::prost::encoding::enumeration::merge_repeated(
+                            wire_type,
+                            _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(history));
+                            error
+                        })
+                    }
+                    3 => {
+                        let mut _inner_pilota_value = &mut self.tasks;
+                        ::pilota::prost::encoding::hash_map::merge(
+                            ::pilota::prost::encoding::faststr::merge,
+                            ::pilota::prost::encoding::enumeration::merge,
+                            &mut _inner_pilota_value,
+                            buf,
+                            ctx,
+                        )
+                        .map_err(|mut error| {
+                            error.push(STRUCT_NAME, stringify!(tasks));
+                            error
+                        })
+                    }
+                    _ => ::pilota::prost::encoding::skip_field(wire_type, tag, buf, ctx),
+                }
+            }
+        }
+        #[derive(PartialOrd, Hash, Eq, Ord, Debug, Default, Clone, PartialEq, Copy)]
+        #[repr(transparent)]
+        pub struct Status(i32);
+
+        impl Status {
+            pub const UNKNOWN: Self = Self(0);
+            pub const ACTIVE: Self = Self(1);
+
+            pub fn inner(&self) -> i32 {
+                self.0
+            }
+
+            pub fn to_string(&self) -> ::std::string::String {
+                match self {
+                    Self(0) => ::std::string::String::from("UNKNOWN"),
+                    Self(1) => ::std::string::String::from("ACTIVE"),
+                    Self(val) => val.to_string(),
+                }
+            }
+        }
+
+        impl ::std::convert::From<i32> for Status {
+            fn from(value: i32) -> Self {
+                Self(value)
+            }
+        }
+
+        impl ::std::convert::From<Status> for i32 {
+            fn from(value: Status) -> i32 {
+                value.0
+            }
+        }
+
+        impl ::pilota::prost::encoding::enumeration::DecodeEnum for Status {
+            fn decode_value(
+                value: i32,
+            ) -> ::core::result::Result<Self, ::pilota::prost::DecodeError> {
+                match value {
+                    0 => ::core::result::Result::Ok(Self(value)),
+                    1 => ::core::result::Result::Ok(Self(value)),
+                    _ => ::core::result::Result::Ok(Self::UNKNOWN),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,12 @@
+enum Status {
+    Unset,
+    Active = 5,
+    Paused,
+    Stopped = 2,
+    Archived,
+}
+
+union Shape {
+    1: i32 circle,
+    2: i32 square,
+}
@@ -0,0 +1,29 @@
+namespace rs profile
+
+enum Role {
+    ADMIN = 1,
+    GUEST = 2,
+}
+
+typedef string Email
+
+struct Address {
+    1: required string city,
+    2: optional string street,
+}
+
+union Contact {
+    1: Email email,
+    2: i64 phone,
+}
+
+struct Profile {
+    1: required string name,
+    2: required i32 age,
+    3: optional Address address,
+    4: required list<string> aliases,
+    5: required map<string, i64> scores,
+    6: optional Contact contact,
+    7: required Role role,
+    8: required binary avatar,
+}
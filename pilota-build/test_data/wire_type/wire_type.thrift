@@ -0,0 +1,26 @@
+namespace rs wire_type
+
+enum Status {
+    UNKNOWN = 0,
+    ACTIVE = 1,
+}
+
+typedef i64 Timestamp
+
+struct Inner {
+    1: required i32 a,
+}
+
+struct Sample {
+    1: required bool flag,
+    2: required i64 id,
+    3: required Timestamp at,
+    4: required double ratio,
+    5: required string name,
+    6: required binary payload,
+    7: required Status status,
+    8: required Inner inner,
+    9: required list<i32> values,
+    10: required set<string> tags,
+    11: required map<string, i32> counts,
+}
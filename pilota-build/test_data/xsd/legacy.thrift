@@ -0,0 +1,8 @@
+(pilota.default_requiredness = "required")
+
+struct Legacy xsd_all {
+    1: i32 id xsd_optional,
+    2: required string name xsd_optional,
+    3: optional i64 version xsd_nillable,
+    4: bool enabled,
+}
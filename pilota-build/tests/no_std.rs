@@ -0,0 +1,6 @@
+//! The code generated with `no_std` builds in a `no_std` crate.
+#![no_std]
+
+extern crate alloc;
+
+include!("../test_data/no_std/defaults.rs");
@@ -15,5 +15,9 @@ pub struct Field {
     pub attribute: Attribute,
     pub ty: Type,
     pub default: Option<ConstValue>,
+    /// The legacy `xsd_optional` modifier
+    pub xsd_optional: bool,
+    /// The legacy `xsd_nillable` modifier
+    pub xsd_nillable: bool,
     pub annotations: Annotations,
 }
@@ -81,6 +81,9 @@ pub struct File {
     pub path: Arc<PathBuf>,
     pub package: Option<Path>,
     pub items: Vec<Item>,
+    /// Annotations at the head of the document, before any item, applying to
+    /// the whole file, e.g. `(pilota.string_type = "String")`
+    pub document_annotations: Annotations,
 }
 
 impl PartialEq for File {
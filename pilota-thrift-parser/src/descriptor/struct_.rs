@@ -37,6 +37,8 @@ struct_like!(Exception);
 #[derive(Debug)]
 pub struct StructLike {
     pub name: Ident,
+    /// The legacy `xsd_all` modifier
+    pub xsd_all: bool,
     pub fields: Vec<Field>,
     pub annotations: Annotations,
 }
@@ -28,6 +28,8 @@ pub enum Ty {
     I64,
     Double,
     Uuid,
+    /// The deprecated list of strings
+    Slist,
     List {
         value: Arc<Type>,
         cpp_type: Option<CppType>,
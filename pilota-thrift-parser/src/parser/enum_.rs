@@ -10,8 +10,8 @@ use super::super::{
     parser::*,
 };
 
-impl Parser for EnumValue {
-    fn parse(input: &str) -> IResult<&str, EnumValue> {
+impl ParseIn for EnumValue {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, EnumValue> {
         map(
             tuple((
                 Ident::parse,
@@ -22,7 +22,7 @@ impl Parser for EnumValue {
                 )),
                 opt(blank),
                 opt(Annotations::parse),
-                opt(list_separator),
+                |input| element_separator(cx, input),
                 opt(blank),
             )),
             |(name, _, value, _, annotations, _, _)| EnumValue {
@@ -34,8 +34,8 @@ impl Parser for EnumValue {
     }
 }
 
-impl Parser for Enum {
-    fn parse(input: &str) -> IResult<&str, Enum> {
+impl ParseIn for Enum {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, Enum> {
         map(
             tuple((
                 tag("enum"),
@@ -44,7 +44,7 @@ impl Parser for Enum {
                 opt(blank),
                 tag("{"),
                 opt(blank),
-                many0(EnumValue::parse),
+                many0(|input| EnumValue::parse_in(cx, input)),
                 opt(blank),
                 tag("}"),
                 opt(blank),
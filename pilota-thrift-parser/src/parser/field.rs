@@ -2,7 +2,7 @@ use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::digit1,
-    combinator::{map, opt},
+    combinator::{map, not, opt, peek},
     sequence::tuple,
     IResult,
 };
@@ -21,8 +21,8 @@ impl Parser for Attribute {
     }
 }
 
-impl Parser for Field {
-    fn parse(input: &str) -> IResult<&str, Field> {
+impl ParseIn for Field {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, Field> {
         // 1: required i32 name = 123;
         map(
             tuple((
@@ -41,16 +41,44 @@ impl Parser for Field {
                     |(_, _, default)| default,
                 )),
                 opt(blank),
+                opt(tuple((
+                    tag("xsd_optional"),
+                    peek(not(alphanumeric_or_underscore)),
+                    opt(blank),
+                ))),
+                opt(tuple((
+                    tag("xsd_nillable"),
+                    peek(not(alphanumeric_or_underscore)),
+                    opt(blank),
+                ))),
                 opt(Annotations::parse),
                 opt(blank),
-                opt(list_separator),
+                |input| element_separator(cx, input),
             )),
-            |(id, _, attribute, _, r#type, _, name, _, default, _, annotations, _, _)| Field {
+            |(
+                id,
+                _,
+                attribute,
+                _,
+                r#type,
+                _,
+                name,
+                _,
+                default,
+                _,
+                xsd_optional,
+                xsd_nillable,
+                annotations,
+                _,
+                _,
+            )| Field {
                 id,
                 attribute: attribute.unwrap_or_default(),
                 ty: r#type,
                 name,
                 default,
+                xsd_optional: xsd_optional.is_some(),
+                xsd_nillable: xsd_nillable.is_some(),
                 annotations: annotations.unwrap_or_default(),
             },
         )(input)
@@ -61,6 +89,7 @@ impl Parser for Field {
 mod tests {
 
     use super::*;
+    use crate::descriptor::Ty;
 
     #[test]
     fn test_field() {
@@ -71,6 +100,44 @@ mod tests {
         .1;
     }
 
+    #[test]
+    fn test_field_slist() {
+        let f = Field::parse("1: required slist names,").unwrap().1;
+        assert!(matches!(f.ty.0, Ty::Slist));
+
+        let f = Field::parse("2: optional map<string, slist> aliases,")
+            .unwrap()
+            .1;
+        assert!(matches!(&f.ty.0, Ty::Map { value, .. } if matches!(value.0, Ty::Slist)));
+
+        // a type merely starting with `slist`
+        let f = Field::parse("3: optional slists.Names names,").unwrap().1;
+        assert!(matches!(f.ty.0, Ty::Path(_)));
+    }
+
+    #[test]
+    fn test_field_xsd() {
+        let f = Field::parse(r#"1: i32 count = 1 xsd_optional xsd_nillable (foo = "1"),"#)
+            .unwrap()
+            .1;
+        assert!(f.xsd_optional && f.xsd_nillable);
+        assert_eq!(f.annotations.len(), 1);
+
+        let f = Field::parse("2: required string name xsd_optional,")
+            .unwrap()
+            .1;
+        assert!(matches!(f.attribute, Attribute::Required));
+        assert!(f.xsd_optional && !f.xsd_nillable);
+
+        // words merely starting with the modifiers
+        let (rest, f) = Field::parse("3: i32 level xsd_optionality,").unwrap();
+        assert!(!f.xsd_optional);
+        assert_eq!(rest, "xsd_optionality,");
+        let (rest, f) = Field::parse("4: i32 level xsd_nillables,").unwrap();
+        assert!(!f.xsd_nillable);
+        assert_eq!(rest, "xsd_nillables,");
+    }
+
     #[test]
     fn test_field2() {
         let _f =
@@ -8,12 +8,12 @@ use nom::{
 
 use super::super::{
     descriptor::{Annotations, Field, Function, Ident, Type},
-    parser::{blank, list_separator, Parser},
+    parser::{blank, element_separator, ParseContext, ParseIn, Parser},
     Attribute,
 };
 
-impl Parser for Function {
-    fn parse(input: &str) -> IResult<&str, Function> {
+impl ParseIn for Function {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, Function> {
         map(
             tuple((
                 map(opt(tuple((tag("oneway"), blank))), |x| x.is_some()),
@@ -23,7 +23,7 @@ impl Parser for Function {
                 opt(blank),
                 tag("("),
                 opt(many1(map(
-                    tuple((opt(blank), Field::parse)),
+                    tuple((opt(blank), |input| Field::parse_in(cx, input))),
                     |(_, field)| field,
                 ))),
                 opt(blank),
@@ -34,7 +34,10 @@ impl Parser for Function {
                         tag("throws"),
                         opt(blank),
                         tag("("),
-                        many1(map(tuple((opt(blank), Field::parse)), |(_, field)| field)),
+                        many1(map(
+                            tuple((opt(blank), |input| Field::parse_in(cx, input))),
+                            |(_, field)| field,
+                        )),
                         opt(blank),
                         tag(")"),
                     )),
@@ -42,7 +45,7 @@ impl Parser for Function {
                 )),
                 opt(blank),
                 opt(Annotations::parse),
-                opt(list_separator),
+                |input| element_separator(cx, input),
             )),
             |(oneway, r#type, _, name, _, _, arguments, _, _, _, throws, _, annotations, _)| {
                 let mut args = arguments.unwrap_or_default();
@@ -17,19 +17,22 @@ mod thrift;
 mod ty;
 mod typedef;
 
-use std::sync::Arc;
+use std::{cell::RefCell, sync::Arc};
 
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_till, take_until},
     character::complete::{multispace1, one_of, satisfy},
-    combinator::{map, opt},
+    combinator::{map, opt, peek, recognize},
     multi::{many0, many1, separated_list1},
     sequence::{preceded, terminated, tuple},
     IResult,
 };
 
-use super::descriptor::{Ident, Path};
+use super::descriptor::{
+    Enum, EnumValue, Exception, Field, File, Function, Ident, Item, Path, Service, Struct,
+    StructLike, Union,
+};
 
 /// combinator for parsing thrift idl
 pub trait Parser: Sized {
@@ -37,6 +40,26 @@ pub trait Parser: Sized {
     fn parse(input: &str) -> IResult<&str, Self>;
 }
 
+/// A parser of the definitions holding lists, whose separators are parsed
+/// in the mode of `cx`.
+pub(crate) trait ParseIn: Sized {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, Self>;
+}
+
+macro_rules! lenient_parser {
+    ($($ty: ty),+) => {
+        $(impl Parser for $ty {
+            fn parse(input: &str) -> IResult<&str, Self> {
+                Self::parse_in(&ParseContext::new(ParseMode::Lenient), input)
+            }
+        })+
+    };
+}
+
+lenient_parser!(
+    Field, EnumValue, Enum, Function, Service, StructLike, Struct, Union, Exception, Item, File
+);
+
 impl Parser for Path {
     fn parse(input: &str) -> IResult<&str, Self> {
         map(
@@ -52,6 +75,105 @@ pub(crate) fn list_separator(input: &str) -> IResult<&str, char> {
     map(tuple((one_of(",;"), opt(blank))), |(sep, _)| sep)(input)
 }
 
+/// How the separators between fields, arguments, enum values and functions
+/// are parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    /// A separator after the last element of a list is allowed. Repeated
+    /// separators, and separators between definitions, are skipped with a
+    /// warning.
+    #[default]
+    Lenient,
+    /// Elements are separated by at most one separator, and the last one of
+    /// a list is not followed by any.
+    Strict,
+}
+
+/// A separator skipped in [`ParseMode::Lenient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// 1-based line of the separator
+    pub line: usize,
+    pub message: &'static str,
+}
+
+/// The mode of a parse, and the separators it skipped.
+pub(crate) struct ParseContext {
+    mode: ParseMode,
+    /// The skipped separators, by the length of the input left after them
+    warnings: RefCell<Vec<(usize, &'static str)>>,
+}
+
+impl ParseContext {
+    pub(crate) fn new(mode: ParseMode) -> Self {
+        ParseContext {
+            mode,
+            warnings: RefCell::default(),
+        }
+    }
+
+    /// The warnings of the separators skipped parsing `input`.
+    pub(crate) fn into_warnings(self, input: &str) -> Vec<Warning> {
+        let mut warnings = self.warnings.into_inner();
+        // nom backtracks, so the same separator may be skipped more than once
+        warnings.sort_unstable_by_key(|(rest, _)| std::cmp::Reverse(*rest));
+        warnings.dedup_by_key(|(rest, _)| *rest);
+        warnings
+            .into_iter()
+            .map(|(rest, message)| Warning {
+                line: input[..input.len() - rest].matches('\n').count() + 1,
+                message,
+            })
+            .collect()
+    }
+}
+
+/// Skips the separators `input` starts with, which must not be there in
+/// strict mode.
+fn extra_separators<'a>(
+    cx: &ParseContext,
+    input: &'a str,
+    message: &'static str,
+) -> IResult<&'a str, ()> {
+    let (rest, extra) = recognize(many0(list_separator))(input)?;
+    if !extra.is_empty() {
+        match cx.mode {
+            ParseMode::Strict => {
+                return Err(nom::Err::Failure(nom::error::Error::new(
+                    input,
+                    nom::error::ErrorKind::Verify,
+                )))
+            }
+            ParseMode::Lenient => cx.warnings.borrow_mut().push((input.len(), message)),
+        }
+    }
+    Ok((rest, ()))
+}
+
+/// The separator after a field, an argument, an enum value or a function.
+pub(crate) fn element_separator<'a>(
+    cx: &ParseContext,
+    input: &'a str,
+) -> IResult<&'a str, Option<char>> {
+    let (rest, sep) = opt(list_separator)(input)?;
+    if sep.is_none() {
+        return Ok((rest, None));
+    }
+    let (rest, _) = extra_separators(cx, rest, "redundant separator")?;
+    if cx.mode == ParseMode::Strict && peek(preceded(opt(blank), one_of("})")))(rest).is_ok() {
+        return Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Verify,
+        )));
+    }
+    Ok((rest, sep))
+}
+
+/// The separators after a definition, besides the one it may end with.
+pub(crate) fn definition_separators<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, ()> {
+    extra_separators(cx, input, "separator after a definition")
+}
+
 fn comment(input: &str) -> IResult<&str, &str> {
     alt((
         preceded(tag("//"), take_till(|c| c == '\n')),
@@ -10,8 +10,8 @@ use super::super::{
     parser::*,
 };
 
-impl Parser for Service {
-    fn parse(input: &str) -> IResult<&str, Service> {
+impl ParseIn for Service {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, Service> {
         map(
             tuple((
                 tag("service"),
@@ -23,7 +23,10 @@ impl Parser for Service {
                 )),
                 opt(blank),
                 tag("{"),
-                many0(map(tuple((opt(blank), Function::parse)), |(_, f)| f)),
+                many0(map(
+                    tuple((opt(blank), |input| Function::parse_in(cx, input))),
+                    |(_, f)| f,
+                )),
                 opt(blank),
                 tag("}"),
                 opt(blank),
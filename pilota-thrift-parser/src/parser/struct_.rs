@@ -1,6 +1,6 @@
 use nom::{
     bytes::complete::tag,
-    combinator::{map, opt},
+    combinator::{map, not, opt, peek},
     IResult,
 };
 
@@ -9,19 +9,21 @@ use super::super::{
     parser::*,
 };
 
-impl Parser for Struct {
-    fn parse(input: &str) -> IResult<&str, Struct> {
+impl ParseIn for Struct {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, Struct> {
         map(
-            tuple((tag("struct"), blank, StructLike::parse)),
+            tuple((tag("struct"), blank, |input| {
+                StructLike::parse_in(cx, input)
+            })),
             |(_, _, st)| Struct(st),
         )(input)
     }
 }
 
-impl Parser for Union {
-    fn parse(input: &str) -> IResult<&str, Union> {
+impl ParseIn for Union {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, Union> {
         let u: IResult<&str, Union> = map(
-            tuple((tag("union"), blank, StructLike::parse)),
+            tuple((tag("union"), blank, |input| StructLike::parse_in(cx, input))),
             |(_, _, st)| Union(st),
         )(input);
 
@@ -29,31 +31,42 @@ impl Parser for Union {
     }
 }
 
-impl Parser for Exception {
-    fn parse(input: &str) -> IResult<&str, Exception> {
+impl ParseIn for Exception {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, Exception> {
         map(
-            tuple((tag("exception"), blank, StructLike::parse)),
+            tuple((tag("exception"), blank, |input| {
+                StructLike::parse_in(cx, input)
+            })),
             |(_, _, st)| Exception(st),
         )(input)
     }
 }
 
-impl Parser for StructLike {
-    fn parse(input: &str) -> IResult<&str, StructLike> {
+impl ParseIn for StructLike {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, StructLike> {
         let (r, a) = map(
             tuple((
                 Ident::parse,
                 opt(blank),
+                opt(tuple((
+                    tag("xsd_all"),
+                    peek(not(alphanumeric_or_underscore)),
+                    opt(blank),
+                ))),
                 tag("{"),
-                many0(map(tuple((opt(blank), Field::parse)), |(_, field)| field)),
+                many0(map(
+                    tuple((opt(blank), |input| Field::parse_in(cx, input))),
+                    |(_, field)| field,
+                )),
                 opt(blank),
                 tag("}"),
                 opt(blank),
                 opt(Annotations::parse),
                 opt(list_separator),
             )),
-            |(name, _, _, fields, _, _, _, annotations, _)| StructLike {
+            |(name, _, xsd_all, _, fields, _, _, _, annotations, _)| StructLike {
                 name,
+                xsd_all: xsd_all.is_some(),
                 fields,
                 annotations: annotations.unwrap_or_default(),
             },
@@ -82,6 +95,17 @@ mod tests {
         Struct::parse(str).unwrap();
     }
 
+    #[test]
+    fn test_struct_xsd_all() {
+        let str = r#"struct Legacy xsd_all {
+            1: i32 id xsd_optional,
+        }"#;
+        let s = Struct::parse(str).unwrap().1;
+        assert!(s.xsd_all && s.fields[0].xsd_optional);
+
+        assert!(Struct::parse("struct Legacy xsd_allowed {}").is_err());
+    }
+
     #[test]
     fn test_struct3() {
         let str = r#"struct TestComment {
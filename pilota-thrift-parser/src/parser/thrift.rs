@@ -3,20 +3,21 @@ use nom::{
     character::complete::satisfy,
     combinator::{eof, map, opt, peek, recognize},
     multi::many_till,
-    sequence::tuple,
+    sequence::{preceded, tuple},
     IResult,
 };
 
 use super::super::{
     descriptor::{
-        Constant, CppInclude, Enum, Exception, File, Include, Service, Struct, Typedef, Union,
+        Annotations, Constant, CppInclude, Enum, Exception, File, Include, Service, Struct,
+        Typedef, Union,
     },
     parser::*,
 };
 use crate::{Item, Namespace};
 
-impl Parser for Item {
-    fn parse(input: &str) -> IResult<&str, Self> {
+impl ParseIn for Item {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, Self> {
         let (input, keyword) = peek(recognize(tuple((
             satisfy(|c| c.is_ascii_alphabetic()),
             take_while(|c: char| c.is_ascii_alphanumeric() || c == '_'),
@@ -26,6 +27,10 @@ impl Parser for Item {
                 let (rest, item) = $variant::parse(input)?;
                 Ok((rest, Self::$variant(item)))
             }};
+            ($variant: ident in cx) => {{
+                let (rest, item) = $variant::parse_in(cx, input)?;
+                Ok((rest, Self::$variant(item)))
+            }};
         }
         match keyword {
             "include" => unpack!(Include),
@@ -33,11 +38,11 @@ impl Parser for Item {
             "namespace" => unpack!(Namespace),
             "typedef" => unpack!(Typedef),
             "const" => unpack!(Constant),
-            "enum" => unpack!(Enum),
-            "struct" => unpack!(Struct),
-            "union" => unpack!(Union),
-            "exception" => unpack!(Exception),
-            "service" => unpack!(Service),
+            "enum" => unpack!(Enum in cx),
+            "struct" => unpack!(Struct in cx),
+            "union" => unpack!(Union in cx),
+            "exception" => unpack!(Exception in cx),
+            "service" => unpack!(Service in cx),
             _ => Err(nom::Err::Failure(nom::error::Error::new(
                 input,
                 nom::error::ErrorKind::Fail,
@@ -46,14 +51,22 @@ impl Parser for Item {
     }
 }
 
-impl Parser for File {
-    fn parse(input: &str) -> IResult<&str, File> {
+impl ParseIn for File {
+    fn parse_in<'a>(cx: &ParseContext, input: &'a str) -> IResult<&'a str, File> {
         let mut t: File = Default::default();
 
+        let (input, document_annotations) = opt(preceded(opt(blank), Annotations::parse))(input)?;
+        t.document_annotations = document_annotations.unwrap_or_default();
+
         let (remain, items) = many_till(
             map(
-                tuple((opt(blank), Item::parse, opt(blank))),
-                |(_, item, _)| item,
+                tuple((
+                    opt(blank),
+                    |input| Item::parse_in(cx, input),
+                    opt(blank),
+                    |input| definition_separators(cx, input),
+                )),
+                |(_, item, _, _)| item,
             ),
             eof,
         )(input)?;
@@ -82,6 +95,16 @@ impl Parser for File {
     }
 }
 
+impl File {
+    /// Parses `input` in `mode`, with the warnings of the separators skipped
+    /// in lenient mode. [`Parser::parse`] is lenient.
+    pub fn parse_with_mode(input: &str, mode: ParseMode) -> IResult<&str, (File, Vec<Warning>)> {
+        let cx = ParseContext::new(mode);
+        let (remain, file) = File::parse_in(&cx, input)?;
+        Ok((remain, (file, cx.into_warnings(input))))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -179,4 +202,84 @@ service Service {
         assert!(remain.is_empty());
         assert_eq!(res.items.len(), 6);
     }
+
+    #[test]
+    fn test_document_annotations() {
+        let body = r#"
+// defaults for the whole file
+(pilota.string_type = "String", pilota.default_requiredness = "required")
+
+namespace rs doc
+
+struct A {
+    1: string a,
+}"#;
+        let (remain, res) = File::parse(body).unwrap();
+        assert!(remain.is_empty());
+        assert_eq!(res.items.len(), 2);
+        assert_eq!(res.document_annotations.len(), 2);
+        assert_eq!(res.document_annotations[0].key, "pilota.string_type");
+
+        let (_, res) = File::parse("struct A {}").unwrap();
+        assert!(res.document_annotations.is_empty());
+    }
+
+    #[test]
+    fn test_parse_mode() {
+        let body = r#"enum Status {
+    OK = 0,
+    ERROR = 1,
+}"#;
+        let (remain, (res, warnings)) = File::parse_with_mode(body, ParseMode::Lenient).unwrap();
+        assert!(remain.is_empty());
+        assert_eq!(res.items.len(), 1);
+        assert!(warnings.is_empty());
+        assert!(File::parse_with_mode(body, ParseMode::Strict).is_err());
+
+        let body = r#"struct A {
+    1: i32 a,,
+    2: i32 b;
+};;
+
+enum B { X = 1 };
+
+service S {
+    void f(1: i32 a; 2: i32 b)
+}"#;
+        let (remain, (res, warnings)) = File::parse_with_mode(body, ParseMode::Lenient).unwrap();
+        assert!(remain.is_empty());
+        assert_eq!(res.items.len(), 3);
+        assert_eq!(
+            warnings,
+            vec![
+                Warning {
+                    line: 2,
+                    message: "redundant separator",
+                },
+                Warning {
+                    line: 4,
+                    message: "separator after a definition",
+                },
+                Warning {
+                    line: 6,
+                    message: "separator after a definition",
+                },
+            ]
+        );
+        assert!(File::parse_with_mode(body, ParseMode::Strict).is_err());
+
+        // not a separator
+        assert!(File::parse_with_mode("struct A { 1: i32 a,. }", ParseMode::Lenient).is_err());
+
+        let body = r#"struct A {
+    1: i32 a, 2: i32 b
+}
+
+service S {
+    void f(1: i32 a, 2: i32 b),
+    void g()
+}"#;
+        let (_, (res, _)) = File::parse_with_mode(body, ParseMode::Strict).unwrap();
+        assert_eq!(res.items.len(), 2);
+    }
 }
@@ -85,6 +85,10 @@ impl Parser for Ty {
                 tuple((tag("uuid"), peek(not(alphanumeric_or_underscore)))),
                 |_| Ty::Uuid,
             ),
+            map(
+                tuple((tag("slist"), peek(not(alphanumeric_or_underscore)))),
+                |_| Ty::Slist,
+            ),
             map(
                 tuple((
                     tag("list"),
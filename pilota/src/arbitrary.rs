@@ -0,0 +1,61 @@
+//! Support for the `Arbitrary` impls generated by `pilota-build` for types
+//! that `arbitrary` does not know about.
+
+pub use arbitrary::{Arbitrary, Error, Result, Unstructured};
+use faststr::FastStr;
+use ordered_float::OrderedFloat;
+
+pub fn faststr(u: &mut Unstructured<'_>) -> Result<FastStr> {
+    String::arbitrary(u).map(FastStr::from)
+}
+
+pub fn bytes(u: &mut Unstructured<'_>) -> Result<bytes::Bytes> {
+    Vec::<u8>::arbitrary(u).map(bytes::Bytes::from)
+}
+
+pub fn ordered_f64(u: &mut Unstructured<'_>) -> Result<OrderedFloat<f64>> {
+    f64::arbitrary(u).map(OrderedFloat)
+}
+
+pub fn option<'a, T>(
+    u: &mut Unstructured<'a>,
+    mut f: impl FnMut(&mut Unstructured<'a>) -> Result<T>,
+) -> Result<Option<T>> {
+    Ok(if u.arbitrary()? { Some(f(u)?) } else { None })
+}
+
+pub fn arc<'a, T>(
+    u: &mut Unstructured<'a>,
+    mut f: impl FnMut(&mut Unstructured<'a>) -> Result<T>,
+) -> Result<std::sync::Arc<T>> {
+    f(u).map(std::sync::Arc::new)
+}
+
+/// An owned `Cow` of the value generated by `f`, so it has any lifetime.
+pub fn cow<'a, 'b, B: ToOwned + ?Sized>(
+    u: &mut Unstructured<'a>,
+    mut f: impl FnMut(&mut Unstructured<'a>) -> Result<B::Owned>,
+) -> Result<std::borrow::Cow<'b, B>> {
+    f(u).map(std::borrow::Cow::Owned)
+}
+
+/// Collects elements generated by `f`, stopping the same way the `Vec` impl
+/// of `arbitrary` does.
+pub fn collect<'a, T, C: FromIterator<T>>(
+    u: &mut Unstructured<'a>,
+    mut f: impl FnMut(&mut Unstructured<'a>) -> Result<T>,
+) -> Result<C> {
+    let mut items = Vec::new();
+    while u.arbitrary().unwrap_or(false) {
+        items.push(f(u)?);
+    }
+    Ok(items.into_iter().collect())
+}
+
+pub fn collect_map<'a, K, V, C: FromIterator<(K, V)>>(
+    u: &mut Unstructured<'a>,
+    mut k: impl FnMut(&mut Unstructured<'a>) -> Result<K>,
+    mut v: impl FnMut(&mut Unstructured<'a>) -> Result<V>,
+) -> Result<C> {
+    collect(u, |u| Ok((k(u)?, v(u)?)))
+}
@@ -0,0 +1,428 @@
+//! A dynamic representation of the generated messages, to manipulate them
+//! without knowing their schema, e.g. in config-driven code.
+//!
+//! With the `FieldMapPlugin` of `pilota-build`, messages get
+//! `to_field_map` and `from_field_map`, converting them from and to a
+//! [`FieldMap`] of their field names to [`Value`]s. Nested messages are
+//! [`Value::Message`]s, enums are the [`Value::String`] of their name, and
+//! unions a [`Value::Message`] with the name of the variant as the only key.
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    hash::Hash,
+    sync::Arc,
+};
+
+use ahash::{AHashMap, AHashSet};
+use bytes::Bytes;
+use faststr::FastStr;
+use ordered_float::OrderedFloat;
+
+/// The fields of a message, by their name in the idl. Unset optional fields
+/// are absent.
+pub type FieldMap = HashMap<String, Value>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    /// Every integer. `u64`s above `i64::MAX` wrap around.
+    I64(i64),
+    F64(f64),
+    String(String),
+    Bytes(Bytes),
+    List(Vec<Value>),
+    Map(Vec<(Value, Value)>),
+    Message(FieldMap),
+}
+
+impl Value {
+    fn kind(&self) -> &'static str {
+        match self {
+            Value::Bool(_) => "a bool",
+            Value::I64(_) => "an integer",
+            Value::F64(_) => "a float",
+            Value::String(_) => "a string",
+            Value::Bytes(_) => "bytes",
+            Value::List(_) => "a list",
+            Value::Map(_) => "a map",
+            Value::Message(_) => "a message",
+        }
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq)]
+pub enum FieldMapError {
+    #[error("missing required field `{0}`")]
+    MissingField(&'static str),
+    #[error("expected {expected}, found {}", found.kind())]
+    InvalidValue {
+        expected: &'static str,
+        found: Value,
+    },
+    #[error("invalid field `{field}`: {source}")]
+    InField {
+        field: &'static str,
+        #[source]
+        source: Box<FieldMapError>,
+    },
+}
+
+impl FieldMapError {
+    pub fn invalid(expected: &'static str, found: Value) -> Self {
+        FieldMapError::InvalidValue { expected, found }
+    }
+}
+
+pub trait ToValue {
+    fn to_value(&self) -> Value;
+}
+
+pub trait FromValue: Sized {
+    fn from_value(value: Value) -> Result<Self, FieldMapError>;
+}
+
+/// Takes the required field `name` out of `map`.
+pub fn required<T: FromValue>(map: &mut FieldMap, name: &'static str) -> Result<T, FieldMapError> {
+    match map.remove(name) {
+        Some(value) => from_field(name, value),
+        None => Err(FieldMapError::MissingField(name)),
+    }
+}
+
+/// Takes the optional field `name` out of `map`.
+pub fn optional<T: FromValue>(
+    map: &mut FieldMap,
+    name: &'static str,
+) -> Result<Option<T>, FieldMapError> {
+    map.remove(name)
+        .map(|value| from_field(name, value))
+        .transpose()
+}
+
+/// Converts the value of the field `name`.
+pub fn from_field<T: FromValue>(name: &'static str, value: Value) -> Result<T, FieldMapError> {
+    T::from_value(value).map_err(|e| FieldMapError::InField {
+        field: name,
+        source: Box::new(e),
+    })
+}
+
+impl ToValue for bool {
+    fn to_value(&self) -> Value {
+        Value::Bool(*self)
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        match value {
+            Value::Bool(b) => Ok(b),
+            value => Err(FieldMapError::invalid("a bool", value)),
+        }
+    }
+}
+
+macro_rules! impl_int {
+    ($($ty:ty),*) => {
+        $(
+            impl ToValue for $ty {
+                fn to_value(&self) -> Value {
+                    Value::I64(*self as i64)
+                }
+            }
+
+            impl FromValue for $ty {
+                fn from_value(value: Value) -> Result<Self, FieldMapError> {
+                    let expected = concat!("`", stringify!($ty), "`");
+                    match value {
+                        Value::I64(v) => {
+                            <$ty>::try_from(v).map_err(|_| FieldMapError::invalid(expected, value))
+                        }
+                        value => Err(FieldMapError::invalid(expected, value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, u8, u32);
+
+impl ToValue for u64 {
+    fn to_value(&self) -> Value {
+        Value::I64(*self as i64)
+    }
+}
+
+impl FromValue for u64 {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        match value {
+            Value::I64(v) => Ok(v as u64),
+            value => Err(FieldMapError::invalid("`u64`", value)),
+        }
+    }
+}
+
+impl ToValue for f32 {
+    fn to_value(&self) -> Value {
+        Value::F64(*self as f64)
+    }
+}
+
+impl FromValue for f32 {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        f64::from_value(value).map(|v| v as f32)
+    }
+}
+
+impl ToValue for f64 {
+    fn to_value(&self) -> Value {
+        Value::F64(*self)
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        match value {
+            Value::F64(v) => Ok(v),
+            Value::I64(v) => Ok(v as f64),
+            value => Err(FieldMapError::invalid("a float", value)),
+        }
+    }
+}
+
+impl ToValue for OrderedFloat<f64> {
+    fn to_value(&self) -> Value {
+        Value::F64(self.0)
+    }
+}
+
+impl FromValue for OrderedFloat<f64> {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        f64::from_value(value).map(OrderedFloat)
+    }
+}
+
+impl ToValue for String {
+    fn to_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        match value {
+            Value::String(s) => Ok(s),
+            value => Err(FieldMapError::invalid("a string", value)),
+        }
+    }
+}
+
+impl ToValue for FastStr {
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl FromValue for FastStr {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        String::from_value(value).map(FastStr::from)
+    }
+}
+
+impl ToValue for Cow<'_, str> {
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl FromValue for Cow<'_, str> {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        String::from_value(value).map(Cow::Owned)
+    }
+}
+
+impl ToValue for Bytes {
+    fn to_value(&self) -> Value {
+        Value::Bytes(self.clone())
+    }
+}
+
+impl FromValue for Bytes {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        match value {
+            Value::Bytes(b) => Ok(b),
+            value => Err(FieldMapError::invalid("bytes", value)),
+        }
+    }
+}
+
+/// Uuids are their 16 bytes.
+impl ToValue for [u8; 16] {
+    fn to_value(&self) -> Value {
+        Value::Bytes(Bytes::copy_from_slice(self))
+    }
+}
+
+impl FromValue for [u8; 16] {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        match value {
+            Value::Bytes(b) if b.len() == 16 => Ok(b[..].try_into().unwrap()),
+            value => Err(FieldMapError::invalid("16 bytes", value)),
+        }
+    }
+}
+
+fn from_list<T: FromValue, C: FromIterator<T>>(value: Value) -> Result<C, FieldMapError> {
+    match value {
+        Value::List(values) => values.into_iter().map(T::from_value).collect(),
+        value => Err(FieldMapError::invalid("a list", value)),
+    }
+}
+
+fn from_map<K: FromValue, V: FromValue, C: FromIterator<(K, V)>>(
+    value: Value,
+) -> Result<C, FieldMapError> {
+    match value {
+        Value::Map(entries) => entries
+            .into_iter()
+            .map(|(k, v)| Ok((K::from_value(k)?, V::from_value(v)?)))
+            .collect(),
+        value => Err(FieldMapError::invalid("a map", value)),
+    }
+}
+
+impl<T: ToValue> ToValue for Vec<T> {
+    fn to_value(&self) -> Value {
+        Value::List(self.iter().map(ToValue::to_value).collect())
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        from_list(value)
+    }
+}
+
+impl<T: ToValue + Clone> ToValue for Cow<'_, [T]> {
+    fn to_value(&self) -> Value {
+        Value::List(self.iter().map(ToValue::to_value).collect())
+    }
+}
+
+impl<T: FromValue + Clone> FromValue for Cow<'_, [T]> {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        from_list(value).map(Cow::Owned)
+    }
+}
+
+impl<T: ToValue> ToValue for AHashSet<T> {
+    fn to_value(&self) -> Value {
+        Value::List(self.iter().map(ToValue::to_value).collect())
+    }
+}
+
+impl<T: FromValue + Hash + Eq> FromValue for AHashSet<T> {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        from_list(value)
+    }
+}
+
+impl<T: ToValue> ToValue for BTreeSet<T> {
+    fn to_value(&self) -> Value {
+        Value::List(self.iter().map(ToValue::to_value).collect())
+    }
+}
+
+impl<T: FromValue + Ord> FromValue for BTreeSet<T> {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        from_list(value)
+    }
+}
+
+impl<K: ToValue, V: ToValue> ToValue for AHashMap<K, V> {
+    fn to_value(&self) -> Value {
+        Value::Map(
+            self.iter()
+                .map(|(k, v)| (k.to_value(), v.to_value()))
+                .collect(),
+        )
+    }
+}
+
+impl<K: FromValue + Hash + Eq, V: FromValue> FromValue for AHashMap<K, V> {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        from_map(value)
+    }
+}
+
+impl<K: ToValue, V: ToValue> ToValue for BTreeMap<K, V> {
+    fn to_value(&self) -> Value {
+        Value::Map(
+            self.iter()
+                .map(|(k, v)| (k.to_value(), v.to_value()))
+                .collect(),
+        )
+    }
+}
+
+impl<K: FromValue + Ord, V: FromValue> FromValue for BTreeMap<K, V> {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        from_map(value)
+    }
+}
+
+impl<T: ToValue> ToValue for Arc<T> {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T: FromValue> FromValue for Arc<T> {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        T::from_value(value).map(Arc::new)
+    }
+}
+
+impl<T: ToValue> ToValue for Box<T> {
+    fn to_value(&self) -> Value {
+        (**self).to_value()
+    }
+}
+
+impl<T: FromValue> FromValue for Box<T> {
+    fn from_value(value: Value) -> Result<Self, FieldMapError> {
+        T::from_value(value).map(Box::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_int_out_of_range() {
+        assert_eq!(i8::from_value(Value::I64(-128)), Ok(-128));
+        assert!(matches!(
+            i8::from_value(Value::I64(128)),
+            Err(FieldMapError::InvalidValue { .. })
+        ));
+        assert_eq!(u64::from_value(u64::MAX.to_value()), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn test_field_error() {
+        let mut map = FieldMap::new();
+        map.insert("a".into(), Value::String("x".into()));
+        assert_eq!(
+            required::<i32>(&mut map, "a").unwrap_err().to_string(),
+            "invalid field `a`: expected `i32`, found a string"
+        );
+        assert_eq!(
+            required::<i32>(&mut map, "a").unwrap_err(),
+            FieldMapError::MissingField("a")
+        );
+        assert_eq!(optional::<i32>(&mut map, "a"), Ok(None));
+    }
+}
@@ -0,0 +1,321 @@
+//! Hashing of the generated types containing `AHashMap`s and `AHashSet`s,
+//! which can't derive `Hash`.
+//!
+//! With `manual_hash` enabled in `pilota-build`, these types implement `Hash`
+//! by hashing their maps and sets with [`hash_unordered`].
+//!
+//! The [`StableHash`] of the messages, implemented with the `StableHashPlugin`
+//! of `pilota-build`, is the same across runs, platforms and versions
+//! instead, e.g. to name the snapshots of test fixtures.
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Arc,
+};
+
+use ahash::{AHashMap, AHashSet};
+use bytes::Bytes;
+use faststr::FastStr;
+use ordered_float::OrderedFloat;
+
+/// Hashes the entries of an unordered collection, e.g. `map.iter()`, the
+/// same whatever their iteration order.
+///
+/// Each entry is hashed on its own, and the sorted hashes are hashed into
+/// `state`. Sorting the hashes instead of the entries needs no `Ord` on them,
+/// so messages, which don't have a canonical order nor encoding, can be
+/// keys too.
+pub fn hash_unordered<T, I, H>(entries: I, state: &mut H)
+where
+    T: Hash,
+    I: IntoIterator<Item = T>,
+    H: Hasher,
+{
+    let mut hashes = entries
+        .into_iter()
+        .map(|entry| {
+            let mut hasher = DefaultHasher::new();
+            entry.hash(&mut hasher);
+            hasher.finish()
+        })
+        .collect::<Vec<_>>();
+    hashes.sort_unstable();
+    hashes.hash(state);
+}
+
+/// The 64-bit FNV-1a hash of the bytes written to it.
+#[derive(Clone, Copy, Debug)]
+pub struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl StableHasher {
+    pub fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 ^= *b as u64;
+            self.0 = self.0.wrapping_mul(0x100_0000_01b3);
+        }
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Values writing the same bytes to a [`StableHasher`] whatever their memory
+/// layout, with the numbers in little endian, and the entries of the
+/// unordered maps and sets canonicalized by sorting their hashes.
+pub trait StableHash {
+    fn write_stable(&self, hasher: &mut StableHasher);
+}
+
+/// The stable hash of `value`.
+pub fn stable_hash<T: StableHash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = StableHasher::default();
+    value.write_stable(&mut hasher);
+    hasher.finish()
+}
+
+impl StableHash for bool {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        hasher.write(&[*self as u8])
+    }
+}
+
+macro_rules! impl_num {
+    ($($ty:ty),*) => {
+        $(
+            impl StableHash for $ty {
+                fn write_stable(&self, hasher: &mut StableHasher) {
+                    hasher.write(&self.to_le_bytes())
+                }
+            }
+        )*
+    };
+}
+
+impl_num!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl StableHash for f32 {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        hasher.write(&self.to_bits().to_le_bytes())
+    }
+}
+
+impl StableHash for f64 {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        hasher.write(&self.to_bits().to_le_bytes())
+    }
+}
+
+impl StableHash for OrderedFloat<f64> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        self.0.write_stable(hasher)
+    }
+}
+
+/// Strings and bytes are prefixed with their length, so `("ab", "c")` and
+/// `("a", "bc")` differ.
+fn write_bytes(bytes: &[u8], hasher: &mut StableHasher) {
+    (bytes.len() as u64).write_stable(hasher);
+    hasher.write(bytes)
+}
+
+impl StableHash for str {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        write_bytes(self.as_bytes(), hasher)
+    }
+}
+
+impl StableHash for String {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        write_bytes(self.as_bytes(), hasher)
+    }
+}
+
+impl StableHash for FastStr {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        write_bytes(self.as_bytes(), hasher)
+    }
+}
+
+impl StableHash for Cow<'_, str> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        write_bytes(self.as_bytes(), hasher)
+    }
+}
+
+impl StableHash for Bytes {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        write_bytes(self, hasher)
+    }
+}
+
+/// Uuids are their 16 bytes.
+impl StableHash for [u8; 16] {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        hasher.write(self)
+    }
+}
+
+impl<T: StableHash> StableHash for [T] {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        (self.len() as u64).write_stable(hasher);
+        self.iter().for_each(|el| el.write_stable(hasher))
+    }
+}
+
+impl<T: StableHash> StableHash for Vec<T> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        self[..].write_stable(hasher)
+    }
+}
+
+impl<T: StableHash + Clone> StableHash for Cow<'_, [T]> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        self[..].write_stable(hasher)
+    }
+}
+
+impl<T: StableHash> StableHash for BTreeSet<T> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        (self.len() as u64).write_stable(hasher);
+        self.iter().for_each(|el| el.write_stable(hasher))
+    }
+}
+
+impl<K: StableHash, V: StableHash> StableHash for BTreeMap<K, V> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        (self.len() as u64).write_stable(hasher);
+        self.iter().for_each(|(k, v)| {
+            k.write_stable(hasher);
+            v.write_stable(hasher);
+        })
+    }
+}
+
+/// Writes the sorted hashes of the `entries`, the same whatever their
+/// iteration order.
+fn write_unordered<I: ExactSizeIterator>(
+    entries: I,
+    write: impl Fn(I::Item, &mut StableHasher),
+    hasher: &mut StableHasher,
+) {
+    (entries.len() as u64).write_stable(hasher);
+    let mut hashes = entries
+        .map(|entry| {
+            let mut hasher = StableHasher::default();
+            write(entry, &mut hasher);
+            hasher.finish()
+        })
+        .collect::<Vec<_>>();
+    hashes.sort_unstable();
+    hashes.iter().for_each(|hash| hash.write_stable(hasher))
+}
+
+impl<T: StableHash> StableHash for AHashSet<T> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        write_unordered(self.iter(), |el, hasher| el.write_stable(hasher), hasher)
+    }
+}
+
+impl<K: StableHash, V: StableHash> StableHash for AHashMap<K, V> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        write_unordered(
+            self.iter(),
+            |(k, v), hasher| {
+                k.write_stable(hasher);
+                v.write_stable(hasher);
+            },
+            hasher,
+        )
+    }
+}
+
+/// Unset values differ from every set one.
+impl<T: StableHash> StableHash for Option<T> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        match self {
+            Some(value) => {
+                true.write_stable(hasher);
+                value.write_stable(hasher)
+            }
+            None => false.write_stable(hasher),
+        }
+    }
+}
+
+impl<T: StableHash + ?Sized> StableHash for Arc<T> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        (**self).write_stable(hasher)
+    }
+}
+
+impl<T: StableHash + ?Sized> StableHash for Box<T> {
+    fn write_stable(&self, hasher: &mut StableHasher) {
+        (**self).write_stable(hasher)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ahash::{AHashMap, AHashSet};
+
+    use super::*;
+
+    fn hash_of<T: IntoIterator<Item = impl Hash>>(entries: T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        hash_unordered(entries, &mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_insertion_order() {
+        let mut a = AHashMap::new();
+        let mut b = AHashMap::new();
+        for i in 0..100 {
+            a.insert(i, i.to_string());
+            b.insert(99 - i, (99 - i).to_string());
+        }
+        assert_eq!(hash_of(&a), hash_of(&b));
+
+        b.insert(0, "changed".to_string());
+        assert_ne!(hash_of(&a), hash_of(&b));
+
+        let set = (0..10).collect::<AHashSet<_>>();
+        assert_eq!(hash_of(&set), hash_of((0..10).rev()));
+        assert_ne!(hash_of(&set), hash_of(0..9));
+    }
+
+    #[test]
+    fn test_stable_hash() {
+        let mut hasher = StableHasher::default();
+        hasher.write(b"a");
+        assert_eq!(hasher.finish(), 0xaf63_dc4c_8601_ec8c);
+
+        let a = (0..100)
+            .map(|i| (i, i.to_string()))
+            .collect::<AHashMap<_, _>>();
+        let b = (0..100)
+            .rev()
+            .map(|i| (i, i.to_string()))
+            .collect::<AHashMap<_, _>>();
+        assert_eq!(stable_hash(&a), stable_hash(&b));
+        assert_ne!(
+            stable_hash(&a),
+            stable_hash(&AHashMap::<i32, String>::new())
+        );
+
+        assert_ne!(
+            stable_hash(&vec!["ab".to_string(), "c".to_string()]),
+            stable_hash(&vec!["a".to_string(), "bc".to_string()])
+        );
+        assert_ne!(stable_hash(&Some(0u8)), stable_hash(&None::<u8>));
+    }
+}
@@ -3,8 +3,16 @@
 )]
 #![cfg_attr(not(doctest), doc = include_str!("../README.md"))]
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+pub mod field_map;
+pub mod hash;
 pub mod prost;
+pub mod registry;
+pub mod schema;
+pub mod serde;
 pub mod thrift;
+pub mod visit;
 
 // reexport
 pub use ahash::{AHashMap, AHashSet};
@@ -13,7 +21,6 @@ pub use bytes::*;
 pub use faststr::FastStr;
 pub use lazy_static;
 pub use ordered_float::OrderedFloat;
-pub use serde;
 pub use thiserror::Error as ThisError;
 pub use tokio::io::AsyncRead;
 
@@ -24,3 +31,12 @@ pub enum EnumConvertError<Num> {
     #[error("invalid value `{0}` for enum `{1}`")]
     InvalidNum(Num, &'static str),
 }
+
+/// A value out of the `pilota.range` annotated on its field.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("value `{value}` of field `{field}` is out of range `{range}`")]
+pub struct RangeError {
+    pub field: &'static str,
+    pub range: &'static str,
+    pub value: i128,
+}
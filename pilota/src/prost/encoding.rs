@@ -10,7 +10,7 @@ use core::{cmp::min, convert::TryFrom, mem, str, u32, usize};
 
 use ::bytes::{Buf, BufMut, Bytes};
 
-use super::{DecodeError, Message};
+use super::{message::RequiredFields, DecodeError, Message};
 
 /// Encodes an integer value into LEB128 variable length format, and writes it
 /// to the buffer. The buffer must have enough remaining space (maximum 10
@@ -195,6 +195,10 @@ pub struct DecodeContext {
     /// crate with the `no-recursion-limit` feature.
     #[cfg(not(feature = "no-recursion-limit"))]
     recurse_count: u32,
+
+    /// Whether the proto2 `required` fields are checked, when decoding a new
+    /// message rather than merging into an existing one.
+    pub(crate) check_required: bool,
 }
 
 #[cfg(not(feature = "no-recursion-limit"))]
@@ -203,6 +207,7 @@ impl Default for DecodeContext {
     fn default() -> DecodeContext {
         DecodeContext {
             recurse_count: super::RECURSION_LIMIT,
+            check_required: false,
         }
     }
 }
@@ -219,13 +224,25 @@ impl DecodeContext {
     pub(crate) fn enter_recursion(&self) -> DecodeContext {
         DecodeContext {
             recurse_count: self.recurse_count - 1,
+            check_required: self.check_required,
         }
     }
 
     #[cfg(feature = "no-recursion-limit")]
     #[inline]
     pub(crate) fn enter_recursion(&self) -> DecodeContext {
-        DecodeContext {}
+        DecodeContext {
+            check_required: self.check_required,
+        }
+    }
+
+    /// Checks the proto2 `required` fields of the decoded messages.
+    #[inline]
+    pub(crate) fn checking_required(self) -> DecodeContext {
+        DecodeContext {
+            check_required: true,
+            ..self
+        }
     }
 
     /// Checks whether the recursion limit has been reached in the stack of
@@ -696,6 +713,57 @@ pub mod int32 {
         }
     }
 }
+
+/// The enums with a policy for their unknown values, encoded like `int32` and
+/// decoded with [`DecodeEnum`](enumeration::DecodeEnum).
+pub mod enumeration {
+    pub use super::int32::{encode, encode_repeated, encoded_len, encoded_len_repeated};
+    use crate::prost::encoding::*;
+
+    /// Converts a decoded value to the enum, failing or replacing it if it is
+    /// unknown.
+    pub trait DecodeEnum: Sized {
+        fn decode_value(value: i32) -> Result<Self, DecodeError>;
+    }
+
+    pub fn merge<B, T: DecodeEnum>(
+        wire_type: WireType,
+        value: &mut T,
+        buf: &mut B,
+        _ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        check_wire_type(WireType::Varint, wire_type)?;
+        *value = T::decode_value(decode_varint(buf)? as i32)?;
+        Ok(())
+    }
+
+    pub fn merge_repeated<B, T: DecodeEnum>(
+        wire_type: WireType,
+        values: &mut Vec<T>,
+        buf: &mut B,
+        ctx: DecodeContext,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+    {
+        if wire_type == WireType::LengthDelimited {
+            // Packed.
+            merge_loop(values, buf, ctx, |values, buf, _ctx| {
+                values.push(T::decode_value(decode_varint(buf)? as i32)?);
+                Ok(())
+            })
+        } else {
+            // Unpacked.
+            check_wire_type(WireType::Varint, wire_type)?;
+            values.push(T::decode_value(decode_varint(buf)? as i32)?);
+            Ok(())
+        }
+    }
+}
+
 varint!(i64, int64);
 varint!(u32, uint32);
 varint!(u64, uint64);
@@ -1337,15 +1405,18 @@ pub mod message {
     {
         check_wire_type(WireType::LengthDelimited, wire_type)?;
         ctx.limit_reached()?;
+        let mut required = RequiredFields::of::<M>(&ctx);
         merge_loop(
             msg,
             buf,
             ctx.enter_recursion(),
             |msg: &mut M, buf: &mut B, ctx| {
                 let (tag, wire_type) = decode_key(buf)?;
+                required.decoded(tag);
                 msg.merge_field(tag, wire_type, buf, ctx)
             },
-        )
+        )?;
+        required.check()
     }
 
     pub fn encode_repeated<M, B>(tag: u32, messages: &[M], buf: &mut B)
@@ -1398,6 +1469,64 @@ pub mod message {
     }
 }
 
+/// Encoding functions for a single-field message whose field is stored
+/// directly in the parent message. The message is still written on the wire,
+/// holding the value as its field `inner_tag`.
+pub mod wrapper {
+    use super::*;
+
+    pub fn encode<T, B, E, L>(tag: u32, value: &T, buf: &mut B, encode: E, encoded_len: L)
+    where
+        T: ?Sized,
+        B: BufMut,
+        E: FnOnce(&T, &mut B),
+        L: FnOnce(&T) -> usize,
+    {
+        encode_key(tag, WireType::LengthDelimited, buf);
+        encode_varint(encoded_len(value) as u64, buf);
+        encode(value, buf);
+    }
+
+    pub fn merge<T, B, M>(
+        inner_tag: u32,
+        wire_type: WireType,
+        value: &mut T,
+        buf: &mut B,
+        ctx: DecodeContext,
+        mut merge: M,
+    ) -> Result<(), DecodeError>
+    where
+        B: Buf,
+        M: FnMut(WireType, &mut T, &mut B, DecodeContext) -> Result<(), DecodeError>,
+    {
+        check_wire_type(WireType::LengthDelimited, wire_type)?;
+        ctx.limit_reached()?;
+        merge_loop(
+            value,
+            buf,
+            ctx.enter_recursion(),
+            |value: &mut T, buf: &mut B, ctx| {
+                let (tag, wire_type) = decode_key(buf)?;
+                if tag == inner_tag {
+                    merge(wire_type, value, buf, ctx)
+                } else {
+                    skip_field(wire_type, tag, buf, ctx)
+                }
+            },
+        )
+    }
+
+    #[inline]
+    pub fn encoded_len<T, L>(tag: u32, value: &T, encoded_len: L) -> usize
+    where
+        T: ?Sized,
+        L: FnOnce(&T) -> usize,
+    {
+        let len = encoded_len(value);
+        key_len(tag) + encoded_len_varint(len as u64) + len
+    }
+}
+
 pub mod group {
     use super::*;
 
@@ -1425,15 +1554,17 @@ pub mod group {
         check_wire_type(WireType::StartGroup, wire_type)?;
 
         ctx.limit_reached()?;
+        let mut required = RequiredFields::of::<M>(&ctx);
         loop {
             let (field_tag, field_wire_type) = decode_key(buf)?;
             if field_wire_type == WireType::EndGroup {
                 if field_tag != tag {
                     return Err(DecodeError::new("unexpected end group tag"));
                 }
-                return Ok(());
+                return required.check();
             }
 
+            required.decoded(field_tag);
             M::merge_field(msg, field_tag, field_wire_type, buf, ctx.enter_recursion())?;
         }
     }
@@ -41,6 +41,18 @@ pub trait Message: Debug + Send + Sync {
     /// Returns the encoded length of the message without a length delimiter.
     fn encoded_len(&self) -> usize;
 
+    /// The name of the message, and the tags and names of its proto2
+    /// `required` fields, which decoding the message fails without.
+    ///
+    /// Meant to be used only by `Message` implementations.
+    #[doc(hidden)]
+    fn required_fields() -> (&'static str, &'static [(u32, &'static str)])
+    where
+        Self: Sized,
+    {
+        ("", &[])
+    }
+
     /// Encodes the message to a buffer.
     ///
     /// An error will be returned if the buffer does not have sufficient
@@ -112,18 +124,31 @@ pub trait Message: Debug + Send + Sync {
         B: Buf,
         Self: Default,
     {
+        let ctx = DecodeContext::default().checking_required();
         let mut message = Self::default();
-        Self::merge(&mut message, &mut buf).map(|_| message)
+        let mut required = RequiredFields::of::<Self>(&ctx);
+        while buf.has_remaining() {
+            let (tag, wire_type) = decode_key(&mut buf)?;
+            required.decoded(tag);
+            message.merge_field(tag, wire_type, &mut buf, ctx.clone())?;
+        }
+        required.check()?;
+        Ok(message)
     }
 
     /// Decodes a length-delimited instance of the message from the buffer.
-    fn decode_length_delimited<B>(buf: B) -> Result<Self, DecodeError>
+    fn decode_length_delimited<B>(mut buf: B) -> Result<Self, DecodeError>
     where
         B: Buf,
         Self: Default,
     {
         let mut message = Self::default();
-        message.merge_length_delimited(buf)?;
+        message::merge(
+            WireType::LengthDelimited,
+            &mut message,
+            &mut buf,
+            DecodeContext::default().checking_required(),
+        )?;
         Ok(message)
     }
 
@@ -160,6 +185,44 @@ pub trait Message: Debug + Send + Sync {
     }
 }
 
+/// The proto2 `required` fields of a message not decoded yet, which are only
+/// tracked when decoding, not when merging into an existing message.
+pub(crate) struct RequiredFields {
+    message: &'static str,
+    fields: Vec<(u32, &'static str)>,
+}
+
+impl RequiredFields {
+    pub(crate) fn of<M: Message>(ctx: &DecodeContext) -> Self {
+        let (message, fields) = if ctx.check_required {
+            M::required_fields()
+        } else {
+            ("", &[][..])
+        };
+        Self {
+            message,
+            fields: fields.to_vec(),
+        }
+    }
+
+    pub(crate) fn decoded(&mut self, tag: u32) {
+        if !self.fields.is_empty() {
+            self.fields.retain(|(required, _)| *required != tag);
+        }
+    }
+
+    pub(crate) fn check(&self) -> Result<(), DecodeError> {
+        match self.fields.first() {
+            None => Ok(()),
+            Some((_, field)) => {
+                let mut error = DecodeError::new("missing required field");
+                error.push(self.message, field);
+                Err(error)
+            }
+        }
+    }
+}
+
 impl<M> Message for Box<M>
 where
     M: Message,
@@ -185,6 +248,9 @@ where
     fn encoded_len(&self) -> usize {
         (**self).encoded_len()
     }
+    fn required_fields() -> (&'static str, &'static [(u32, &'static str)]) {
+        M::required_fields()
+    }
 }
 
 #[cfg(test)]
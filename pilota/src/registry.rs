@@ -0,0 +1,102 @@
+//! A registry of the generated messages, to build and decode messages by
+//! their fully qualified name at runtime.
+//!
+//! With `message_registry` enabled in `pilota-build`, the generated code has a
+//! `message_registry()` function registering its messages. In a workspace,
+//! each crate has its own registry, which can be merged with
+//! [`MessageRegistry::merge`].
+
+use std::any::Any;
+
+use ahash::AHashMap;
+use bytes::Bytes;
+
+pub type AnyMessage = Box<dyn Any + Send>;
+
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// How to build and decode a message. Thrift messages are decoded from the
+/// binary protocol.
+#[derive(Clone, Copy)]
+pub struct MessageDescriptor {
+    /// The fully qualified name of the message, like `pkg.Message` or
+    /// `pkg.Outer.Inner` for nested protobuf messages
+    pub name: &'static str,
+    pub new: fn() -> AnyMessage,
+    pub decode: fn(Bytes) -> Result<AnyMessage, BoxError>,
+}
+
+impl std::fmt::Debug for MessageDescriptor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessageDescriptor")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum RegistryError {
+    #[error("message `{0}` is not registered")]
+    UnknownMessage(String),
+    #[error("decode message `{0}` failed, caused by: {1}")]
+    Decode(&'static str, #[source] BoxError),
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MessageRegistry {
+    messages: AHashMap<&'static str, MessageDescriptor>,
+}
+
+impl MessageRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `desc`, replacing the message registered with the same name.
+    pub fn register(&mut self, desc: MessageDescriptor) {
+        self.messages.insert(desc.name, desc);
+    }
+
+    /// Adds the messages of `other`, e.g. the registry of another crate of a
+    /// workspace.
+    pub fn merge(&mut self, other: MessageRegistry) {
+        self.messages.extend(other.messages);
+    }
+
+    pub fn get(&self, name: &str) -> Option<&MessageDescriptor> {
+        self.messages.get(name)
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.messages.contains_key(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// The names of the registered messages, in no particular order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.messages.keys().copied()
+    }
+
+    /// A default value of the message `name`.
+    pub fn new_message(&self, name: &str) -> Result<AnyMessage, RegistryError> {
+        self.get(name)
+            .map(|desc| (desc.new)())
+            .ok_or_else(|| RegistryError::UnknownMessage(name.to_string()))
+    }
+
+    /// Decodes `bytes` as the message `name`. Downcast the result to the
+    /// message type to use it.
+    pub fn decode(&self, name: &str, bytes: Bytes) -> Result<AnyMessage, RegistryError> {
+        let desc = self
+            .get(name)
+            .ok_or_else(|| RegistryError::UnknownMessage(name.to_string()))?;
+        (desc.decode)(bytes).map_err(|err| RegistryError::Decode(desc.name, err))
+    }
+}
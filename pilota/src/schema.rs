@@ -0,0 +1,92 @@
+//! Static descriptions of the fields of the generated messages, to validate
+//! them dynamically.
+//!
+//! With `schema` enabled in `pilota-build`, the generated messages and unions
+//! implement [`Schema`]. The messages and unions used by a field are referred
+//! to by their [`Schema::schema`], so recursive types are described too.
+
+use std::fmt;
+
+/// A message or a union. The variants of the unions are optional fields.
+#[derive(Debug, Clone, Copy)]
+pub struct MessageSchema {
+    /// The name of the message in the IDL
+    pub name: &'static str,
+    pub fields: &'static [FieldSchema],
+}
+
+impl MessageSchema {
+    pub fn field(&self, name: &str) -> Option<&FieldSchema> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    pub fn field_by_id(&self, id: i32) -> Option<&FieldSchema> {
+        self.fields.iter().find(|f| f.id == id)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FieldSchema {
+    /// The name of the field in the IDL
+    pub name: &'static str,
+    pub id: i32,
+    pub ty: TypeSchema,
+    pub required: bool,
+}
+
+/// The type of a field, with the typedefs resolved to the type they alias.
+#[derive(Clone, Copy)]
+pub enum TypeSchema {
+    Bool,
+    U8,
+    I8,
+    I16,
+    I32,
+    I64,
+    U32,
+    U64,
+    F32,
+    F64,
+    String,
+    Bytes,
+    Uuid,
+    Void,
+    List(&'static TypeSchema),
+    Set(&'static TypeSchema),
+    Map(&'static TypeSchema, &'static TypeSchema),
+    /// An enum, by its name in the IDL
+    Enum(&'static str),
+    Message(fn() -> &'static MessageSchema),
+}
+
+impl fmt::Debug for TypeSchema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool => f.write_str("Bool"),
+            Self::U8 => f.write_str("U8"),
+            Self::I8 => f.write_str("I8"),
+            Self::I16 => f.write_str("I16"),
+            Self::I32 => f.write_str("I32"),
+            Self::I64 => f.write_str("I64"),
+            Self::U32 => f.write_str("U32"),
+            Self::U64 => f.write_str("U64"),
+            Self::F32 => f.write_str("F32"),
+            Self::F64 => f.write_str("F64"),
+            Self::String => f.write_str("String"),
+            Self::Bytes => f.write_str("Bytes"),
+            Self::Uuid => f.write_str("Uuid"),
+            Self::Void => f.write_str("Void"),
+            Self::List(el) => f.debug_tuple("List").field(el).finish(),
+            Self::Set(el) => f.debug_tuple("Set").field(el).finish(),
+            Self::Map(k, v) => f.debug_tuple("Map").field(k).field(v).finish(),
+            Self::Enum(name) => f.debug_tuple("Enum").field(name).finish(),
+            // the fields of the message may refer to this type
+            Self::Message(schema) => f.debug_tuple("Message").field(&schema().name).finish(),
+        }
+    }
+}
+
+/// Implemented by the generated messages and unions with `schema` enabled.
+pub trait Schema {
+    fn schema() -> &'static MessageSchema;
+}
@@ -0,0 +1,299 @@
+//! `serde`, along with the adapters serializing the bytes fields of the
+//! generated messages with `#[serde(with = "...")]`.
+//!
+//! Each adapter works with `Bytes`, `Vec<u8>` and the other byte buffers,
+//! and has an `option` module for the optional fields.
+
+pub use ::serde::*;
+
+use ::serde::de::Error as _;
+
+/// Bytes as a base64 string with padding, like `"aGk="`.
+pub mod base64 {
+    use super::*;
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    /// The digit of each character of `ALPHABET`, `INVALID` for the others.
+    const DIGITS: [u8; 256] = {
+        let mut digits = [INVALID; 256];
+        let mut i = 0;
+        while i < ALPHABET.len() {
+            digits[ALPHABET[i] as usize] = i as u8;
+            i += 1;
+        }
+        digits
+    };
+
+    const INVALID: u8 = 0xff;
+
+    pub fn encode(bytes: &[u8]) -> String {
+        let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for chunk in bytes.chunks(3) {
+            let n = chunk
+                .iter()
+                .enumerate()
+                .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    encoded.push(ALPHABET[(n >> (18 - 6 * i)) as usize & 0x3f] as char);
+                } else {
+                    encoded.push('=');
+                }
+            }
+        }
+        encoded
+    }
+
+    pub fn decode(encoded: &str) -> Result<Vec<u8>, String> {
+        let encoded = encoded.as_bytes();
+        let chunks = encoded.chunks_exact(4);
+        if !chunks.remainder().is_empty() {
+            return Err(format!("invalid base64 length {}", encoded.len()));
+        }
+        let mut bytes = Vec::with_capacity(encoded.len() / 4 * 3);
+        for (i, chunk) in chunks.enumerate() {
+            let padding = chunk.iter().rev().take_while(|c| **c == b'=').count();
+            if padding > 2 || (padding > 0 && i != encoded.len() / 4 - 1) {
+                return Err("invalid base64 padding".into());
+            }
+            let mut n = 0u32;
+            for c in &chunk[..4 - padding] {
+                let digit = DIGITS[*c as usize];
+                if digit == INVALID {
+                    return Err(format!("invalid base64 character `{}`", *c as char));
+                }
+                n = n << 6 | digit as u32;
+            }
+            n <<= 6 * padding;
+            bytes.extend_from_slice(&n.to_be_bytes()[1..4 - padding]);
+        }
+        Ok(bytes)
+    }
+
+    pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+        bytes: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode(bytes.as_ref()))
+    }
+
+    pub fn deserialize<'de, T: From<Vec<u8>>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        decode(&encoded).map(T::from).map_err(D::Error::custom)
+    }
+
+    /// The adapter of the optional fields.
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+            bytes: &Option<T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match bytes {
+                Some(bytes) => serializer.serialize_some(&encode(bytes.as_ref())),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, T: From<Vec<u8>>, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<T>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|encoded| decode(&encoded).map(T::from).map_err(D::Error::custom))
+                .transpose()
+        }
+    }
+}
+
+/// Bytes as a lowercase hex string, like `"6869"`.
+pub mod hex {
+    use super::*;
+
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    pub fn encode(bytes: &[u8]) -> String {
+        let mut encoded = String::with_capacity(bytes.len() * 2);
+        for b in bytes {
+            encoded.push(DIGITS[(b >> 4) as usize] as char);
+            encoded.push(DIGITS[(b & 0xf) as usize] as char);
+        }
+        encoded
+    }
+
+    pub fn decode(encoded: &str) -> Result<Vec<u8>, String> {
+        let chunks = encoded.as_bytes().chunks_exact(2);
+        if !chunks.remainder().is_empty() {
+            return Err(format!("invalid hex length {}", encoded.len()));
+        }
+        let digit = |c: u8| (c as char).to_digit(16);
+        chunks
+            .enumerate()
+            .map(|(i, digits)| {
+                digit(digits[0])
+                    .zip(digit(digits[1]))
+                    .map(|(high, low)| (high << 4 | low) as u8)
+                    .ok_or_else(|| format!("invalid hex digits at {}", i * 2))
+            })
+            .collect()
+    }
+
+    pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+        bytes: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&encode(bytes.as_ref()))
+    }
+
+    pub fn deserialize<'de, T: From<Vec<u8>>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        let encoded = String::deserialize(deserializer)?;
+        decode(&encoded).map(T::from).map_err(D::Error::custom)
+    }
+
+    /// The adapter of the optional fields.
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+            bytes: &Option<T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match bytes {
+                Some(bytes) => serializer.serialize_some(&encode(bytes.as_ref())),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, T: From<Vec<u8>>, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<T>, D::Error> {
+            Option::<String>::deserialize(deserializer)?
+                .map(|encoded| decode(&encoded).map(T::from).map_err(D::Error::custom))
+                .transpose()
+        }
+    }
+}
+
+/// Bytes as the serde bytes type of the format, which is an array of numbers
+/// in the formats without one, like JSON.
+pub mod raw {
+    use std::fmt;
+
+    use ::serde::de::{SeqAccess, Visitor};
+
+    use super::*;
+
+    struct BytesVisitor;
+
+    impl<'de> Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("bytes")
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            Ok(v.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, v: Vec<u8>) -> Result<Self::Value, E> {
+            Ok(v)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+            while let Some(b) = seq.next_element()? {
+                bytes.push(b);
+            }
+            Ok(bytes)
+        }
+    }
+
+    struct RawBytes(Vec<u8>);
+
+    impl<'de> Deserialize<'de> for RawBytes {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_byte_buf(BytesVisitor).map(Self)
+        }
+    }
+
+    struct RawRef<'a>(&'a [u8]);
+
+    impl ser::Serialize for RawRef<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+
+    pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+        bytes: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(bytes.as_ref())
+    }
+
+    pub fn deserialize<'de, T: From<Vec<u8>>, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<T, D::Error> {
+        deserializer.deserialize_byte_buf(BytesVisitor).map(T::from)
+    }
+
+    /// The adapter of the optional fields.
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+            bytes: &Option<T>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match bytes {
+                Some(bytes) => serializer.serialize_some(&RawRef(bytes.as_ref())),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, T: From<Vec<u8>>, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<T>, D::Error> {
+            Ok(Option::<RawBytes>::deserialize(deserializer)?.map(|bytes| T::from(bytes.0)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base64() {
+        for (bytes, encoded) in [
+            (&b""[..], ""),
+            (b"h", "aA=="),
+            (b"hi", "aGk="),
+            (b"hi!", "aGkh"),
+            (b"\xff\x00\xfe\x01", "/wD+AQ=="),
+        ] {
+            assert_eq!(base64::encode(bytes), encoded);
+            assert_eq!(base64::decode(encoded).unwrap(), bytes);
+        }
+        assert!(base64::decode("aGk").is_err());
+        assert!(base64::decode("a===").is_err());
+        assert!(base64::decode("aA==aGkh").is_err());
+        assert!(base64::decode("aG!=").is_err());
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(hex::encode(b"\x00\xabz"), "00ab7a");
+        assert_eq!(hex::decode("00AB7a").unwrap(), b"\x00\xabz");
+        assert!(hex::decode("0").is_err());
+        assert!(hex::decode("zz").is_err());
+        assert!(hex::decode("0+").is_err());
+        assert_eq!(hex::encode(&[0xff, 0x10]), "ff10");
+    }
+}
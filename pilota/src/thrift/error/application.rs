@@ -5,7 +5,7 @@ use std::{
 
 use faststr::FastStr;
 
-use super::ThriftException;
+use super::{DecodePath, ThriftException};
 use crate::{
     msg_impl,
     thrift::{
@@ -38,6 +38,8 @@ pub struct ApplicationException {
     kind: ApplicationExceptionKind,
     /// Human-readable error message.
     message: FastStr,
+    /// The path to the value the error occurred at while decoding.
+    path: DecodePath,
 }
 
 impl ApplicationException {
@@ -49,6 +51,7 @@ impl ApplicationException {
         ApplicationException {
             kind,
             message: message.into(),
+            path: DecodePath::default(),
         }
     }
 
@@ -84,7 +87,7 @@ impl Display for ApplicationException {
             _ => "other error",
         };
 
-        write!(f, "{}: {}", error_text, self.message)
+        write!(f, "{}: {}", error_text, self.path_and_message())
     }
 }
 
@@ -152,7 +155,11 @@ impl Message for ApplicationException {
 
         protocol.read_struct_end()?;
 
-        Ok(ApplicationException { kind, message })
+        Ok(ApplicationException {
+            kind,
+            message,
+            path: DecodePath::default(),
+        })
     }
 
     async fn decode_async<T: TAsyncInputProtocol>(
@@ -194,7 +201,11 @@ impl Message for ApplicationException {
 
         protocol.read_struct_end().await?;
 
-        Ok(ApplicationException { kind, message })
+        Ok(ApplicationException {
+            kind,
+            message,
+            path: DecodePath::default(),
+        })
     }
 
     #[inline]
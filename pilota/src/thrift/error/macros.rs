@@ -21,5 +21,27 @@ macro_rules! msg_impl {
             s.push_str(self.message.as_str());
             self.message = s.into();
         }
+
+        /// Get the path to the value the error occurred at while decoding.
+        #[inline]
+        pub fn path(&self) -> &$crate::thrift::DecodePath {
+            &self.path
+        }
+
+        /// Prepend a segment to the path of the error.
+        ///
+        /// That means, the new path will be: `segment` + `old_path`.
+        pub fn prepend_path(&mut self, segment: $crate::thrift::PathSegment) {
+            self.path.prepend(segment);
+        }
+
+        /// The message prefixed with the path, if any.
+        fn path_and_message(&self) -> ::std::string::String {
+            if self.path.is_empty() {
+                self.message.to_string()
+            } else {
+                format!("{}: {}", self.path, self.message)
+            }
+        }
     };
 }
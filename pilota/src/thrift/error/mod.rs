@@ -1,5 +1,6 @@
 mod application;
 mod macros;
+mod path;
 mod protocol;
 mod transport;
 
@@ -10,6 +11,7 @@ use std::{
 
 pub use application::*;
 use faststr::FastStr;
+pub use path::*;
 pub use protocol::*;
 pub use transport::*;
 
@@ -112,6 +114,24 @@ impl ThriftException {
             ThriftException::Transport(e) => e.prepend_msg(message),
         }
     }
+
+    /// Get the path to the value the error occurred at while decoding.
+    pub fn path(&self) -> &DecodePath {
+        match self {
+            ThriftException::Application(e) => e.path(),
+            ThriftException::Protocol(e) => e.path(),
+            ThriftException::Transport(e) => e.path(),
+        }
+    }
+
+    /// Prepend a segment to the path of the error.
+    pub fn prepend_path(&mut self, segment: PathSegment) {
+        match self {
+            ThriftException::Application(e) => e.prepend_path(segment),
+            ThriftException::Protocol(e) => e.prepend_path(segment),
+            ThriftException::Transport(e) => e.prepend_path(segment),
+        }
+    }
 }
 
 /// Create a new `ThriftException` instance of type `Application` that wraps an
@@ -0,0 +1,100 @@
+use std::fmt::{self, Debug, Display, Formatter};
+
+/// A step of the path to the value a message failed to decode at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathSegment {
+    /// The field `id` of the struct `message`, named `name` unless it is
+    /// unknown to the struct.
+    Field {
+        message: &'static str,
+        name: Option<&'static str>,
+        id: i16,
+    },
+    /// The element `index` of a list or set, or the entry `index` of a map,
+    /// in the order they are read.
+    Index(usize),
+}
+
+/// The path to the value a message failed to decode at from the outermost
+/// struct, displayed like `A.b[3].c`. The fields unknown to their struct are
+/// displayed by their id, like `A.#4`.
+///
+/// The segments are prepended while the error goes up the decoders, so they
+/// are kept from the innermost one and read in reverse.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct DecodePath(Vec<PathSegment>);
+
+impl DecodePath {
+    /// The segments from the outermost struct.
+    #[inline]
+    pub fn segments(&self) -> impl DoubleEndedIterator<Item = &PathSegment> + ExactSizeIterator {
+        self.0.iter().rev()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Prepend the segment of the value containing the current path.
+    #[inline]
+    pub fn prepend(&mut self, segment: PathSegment) {
+        self.0.push(segment);
+    }
+}
+
+impl Display for DecodePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments().enumerate() {
+            match segment {
+                PathSegment::Field { message, name, id } => {
+                    if i == 0 {
+                        write!(f, "{message}")?;
+                    }
+                    match name {
+                        Some(name) => write!(f, ".{name}")?,
+                        None => write!(f, ".#{id}")?,
+                    }
+                }
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Debug for DecodePath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{self}\"")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let mut path = DecodePath::default();
+        assert_eq!(path.to_string(), "");
+
+        path.prepend(PathSegment::Field {
+            message: "C",
+            name: None,
+            id: 4,
+        });
+        path.prepend(PathSegment::Index(3));
+        path.prepend(PathSegment::Field {
+            message: "B",
+            name: Some("cs"),
+            id: 2,
+        });
+        path.prepend(PathSegment::Field {
+            message: "A",
+            name: Some("b"),
+            id: 1,
+        });
+        assert_eq!(path.to_string(), "A.b.cs[3].#4");
+        assert_eq!(path.segments().nth(2), Some(&PathSegment::Index(3)));
+    }
+}
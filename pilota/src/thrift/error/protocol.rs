@@ -2,6 +2,7 @@ use std::fmt::{self, Display, Formatter};
 
 use faststr::FastStr;
 
+use super::DecodePath;
 use crate::msg_impl;
 
 #[deprecated(
@@ -23,6 +24,8 @@ pub struct ProtocolException {
     kind: ProtocolExceptionKind,
     /// Human-readable error message.
     message: FastStr,
+    /// The path to the value the error occurred at while decoding.
+    path: DecodePath,
 }
 
 impl ProtocolException {
@@ -31,6 +34,7 @@ impl ProtocolException {
         ProtocolException {
             kind,
             message: message.into(),
+            path: DecodePath::default(),
         }
     }
 
@@ -61,7 +65,7 @@ impl Display for ProtocolException {
             ProtocolExceptionKind::DepthLimit => "maximum skip depth reached",
         };
 
-        write!(f, "{}: {}", error_text, self.message)
+        write!(f, "{}: {}", error_text, self.path_and_message())
     }
 }
 
@@ -5,6 +5,7 @@ use std::{
 
 use faststr::FastStr;
 
+use super::DecodePath;
 use crate::msg_impl;
 
 #[deprecated(
@@ -26,6 +27,8 @@ pub struct TransportException {
     io_error: io::Error,
     /// Human-readable error message.
     message: FastStr,
+    /// The path to the value the error occurred at while decoding.
+    path: DecodePath,
 }
 
 impl PartialEq for TransportException {
@@ -62,6 +65,7 @@ impl From<io::Error> for TransportException {
         TransportException {
             io_error: err,
             message,
+            path: DecodePath::default(),
         }
     }
 }
@@ -69,7 +73,7 @@ impl From<io::Error> for TransportException {
 impl Display for TransportException {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}: {}", self.kind(), self.message())
+        write!(f, "{}: {}", self.kind(), self.path_and_message())
     }
 }
 
@@ -87,6 +91,7 @@ impl Clone for TransportException {
             // Investigate how to do this in the future.
             io_error: io::Error::new(self.io_error().kind(), self.io_error().to_string()),
             message: self.message.clone(),
+            path: self.path.clone(),
         }
     }
 }
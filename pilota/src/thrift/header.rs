@@ -0,0 +1,892 @@
+//! The Thrift header protocol, which prepends a header carrying routing
+//! metadata to a payload encoded by another protocol.
+//!
+//! A frame is laid out as
+//!
+//! ```text
+//! LENGTH:u32 | MAGIC:u16 | FLAGS:u16 | SEQ_ID:u32 | HEADER_SIZE:u16 | HEADER | PAYLOAD
+//! ```
+//!
+//! where `LENGTH` counts every byte after itself and `HEADER_SIZE` is the
+//! size of `HEADER` in 4 byte words. The header holds the varint encoded
+//! protocol id of the payload, the transforms applied to it and the info
+//! sections, and is padded with zeros to a multiple of 4 bytes.
+
+use std::collections::BTreeMap;
+
+use bytes::{Buf, BufMut, Bytes};
+use faststr::FastStr;
+use integer_encoding::VarInt;
+
+use super::{
+    new_protocol_exception, Message, ProtocolExceptionKind, TFieldIdentifier, TInputProtocol,
+    TLengthProtocol, TListIdentifier, TMapIdentifier, TMessageIdentifier, TOutputProtocol,
+    TSetIdentifier, TStructIdentifier, TType, ThriftException,
+};
+use crate::{thrift::rw_ext::IOError, AHashMap};
+
+pub const HEADER_MAGIC: u16 = 0x0FFF;
+
+/// The size of the fixed part of a frame after `LENGTH`.
+const FIXED_LEN: usize = 2 + 2 + 4 + 2;
+
+pub const PROTOCOL_ID_BINARY: u32 = 0;
+pub const PROTOCOL_ID_COMPACT: u32 = 2;
+
+pub const TRANSFORM_ZLIB: u32 = 1;
+pub const TRANSFORM_SNAPPY: u32 = 3;
+
+const INFO_PADDING: u32 = 0;
+pub const INFO_KEYVALUE: u32 = 1;
+/// Key-value headers that should persist across the connection. They are read
+/// into the same map as [`INFO_KEYVALUE`], and kept in it for the following
+/// frames.
+pub const INFO_PKEYVALUE: u32 = 2;
+
+/// The map holding the key-value headers of a frame.
+pub trait HeaderMap: Default {
+    fn insert_header(&mut self, key: FastStr, value: FastStr);
+
+    fn headers(&self) -> impl Iterator<Item = (&FastStr, &FastStr)>;
+
+    fn headers_len(&self) -> usize;
+
+    fn clear(&mut self);
+}
+
+impl HeaderMap for AHashMap<FastStr, FastStr> {
+    fn insert_header(&mut self, key: FastStr, value: FastStr) {
+        self.insert(key, value);
+    }
+
+    fn headers(&self) -> impl Iterator<Item = (&FastStr, &FastStr)> {
+        self.iter()
+    }
+
+    fn headers_len(&self) -> usize {
+        self.len()
+    }
+
+    fn clear(&mut self) {
+        // `AHashMap` has no inherent `clear`, it's the one of the `HashMap` it
+        // derefs to
+        (**self).clear();
+    }
+}
+
+impl HeaderMap for BTreeMap<FastStr, FastStr> {
+    fn insert_header(&mut self, key: FastStr, value: FastStr) {
+        self.insert(key, value);
+    }
+
+    fn headers(&self) -> impl Iterator<Item = (&FastStr, &FastStr)> {
+        self.iter()
+    }
+
+    fn headers_len(&self) -> usize {
+        self.len()
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+impl HeaderMap for Vec<(FastStr, FastStr)> {
+    fn insert_header(&mut self, key: FastStr, value: FastStr) {
+        self.push((key, value));
+    }
+
+    fn headers(&self) -> impl Iterator<Item = (&FastStr, &FastStr)> {
+        self.iter().map(|(k, v)| (k, v))
+    }
+
+    fn headers_len(&self) -> usize {
+        self.len()
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+/// Wraps a protocol to read and write header framed payloads.
+///
+/// All the protocol methods are forwarded to the inner protocol, so generated
+/// code encodes and decodes over it unchanged. A frame is started with
+/// [`THeaderProtocol::write_header`] or [`THeaderProtocol::read_header`], or
+/// handled at once with [`THeaderProtocol::encode_frame`] and
+/// [`THeaderProtocol::decode_frame`].
+///
+/// Transforms are not supported: frames declaring any are rejected.
+pub struct THeaderProtocol<P, H = AHashMap<FastStr, FastStr>> {
+    inner: P,
+    protocol_id: u32,
+    pub flags: u16,
+    pub seq_id: u32,
+    pub headers: H,
+    /// The [`INFO_PKEYVALUE`] headers read so far.
+    persistent_headers: AHashMap<FastStr, FastStr>,
+}
+
+impl<P, H: HeaderMap> THeaderProtocol<P, H> {
+    /// `protocol_id` identifies the protocol of `inner`, e.g.
+    /// [`PROTOCOL_ID_BINARY`].
+    pub fn new(inner: P, protocol_id: u32) -> Self {
+        Self {
+            inner,
+            protocol_id,
+            flags: 0,
+            seq_id: 0,
+            headers: H::default(),
+            persistent_headers: AHashMap::default(),
+        }
+    }
+
+    pub fn with_headers(mut self, headers: H) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    pub fn inner(&mut self) -> &mut P {
+        &mut self.inner
+    }
+
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn encode_header(&self) -> Vec<u8> {
+        let mut header = Vec::new();
+        put_varint(&mut header, self.protocol_id);
+        // no transforms
+        put_varint(&mut header, 0);
+        if self.headers.headers_len() > 0 {
+            put_varint(&mut header, INFO_KEYVALUE);
+            put_varint(&mut header, self.headers.headers_len() as u32);
+            for (k, v) in self.headers.headers() {
+                put_varint(&mut header, k.len() as u32);
+                header.extend_from_slice(k.as_bytes());
+                put_varint(&mut header, v.len() as u32);
+                header.extend_from_slice(v.as_bytes());
+            }
+        }
+        header.resize(header.len().next_multiple_of(4), INFO_PADDING as u8);
+        header
+    }
+}
+
+fn put_varint(buf: &mut Vec<u8>, n: u32) {
+    let mut bytes = [0u8; 5];
+    let size = n.encode_var(&mut bytes);
+    buf.extend_from_slice(&bytes[..size]);
+}
+
+fn get_varint(buf: &mut Bytes) -> Result<u32, ThriftException> {
+    match u32::decode_var(buf) {
+        Some((n, size)) => {
+            buf.advance(size);
+            Ok(n)
+        }
+        None => Err(new_protocol_exception(
+            ProtocolExceptionKind::InvalidData,
+            "invalid varint in header",
+        )),
+    }
+}
+
+fn ensure_remaining(buf: &Bytes, len: usize) -> Result<(), ThriftException> {
+    if buf.remaining() < len {
+        return Err(IOError::NoRemaining(format!("{len} bytes of header frame")).into());
+    }
+    Ok(())
+}
+
+fn get_faststr(buf: &mut Bytes) -> Result<FastStr, ThriftException> {
+    let len = get_varint(buf)? as usize;
+    ensure_remaining(buf, len)?;
+    let bytes = buf.split_to(len);
+    FastStr::from_bytes(bytes)
+        .map_err(|e| new_protocol_exception(ProtocolExceptionKind::InvalidData, e.to_string()))
+}
+
+impl<P: TOutputProtocol, H: HeaderMap> THeaderProtocol<P, H> {
+    /// Writes the frame header of a payload of `payload_len` bytes. The payload
+    /// must be written right after.
+    pub fn write_header(&mut self, payload_len: usize) -> Result<(), ThriftException> {
+        let header = self.encode_header();
+        if header.len() / 4 > u16::MAX as usize {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::SizeLimit,
+                format!("header of {} bytes is too large", header.len()),
+            ));
+        }
+        let len = FIXED_LEN + header.len() + payload_len;
+        let len = u32::try_from(len).map_err(|_| {
+            new_protocol_exception(
+                ProtocolExceptionKind::SizeLimit,
+                format!("frame of {len} bytes is too large"),
+            )
+        })?;
+
+        let buf = self.inner.buf_mut();
+        buf.put_u32(len);
+        buf.put_u16(HEADER_MAGIC);
+        buf.put_u16(self.flags);
+        buf.put_u32(self.seq_id);
+        buf.put_u16((header.len() / 4) as u16);
+        buf.put_slice(&header);
+        Ok(())
+    }
+
+    /// Writes `msg` in a frame carrying the headers.
+    pub fn encode_frame<M: Message>(&mut self, msg: &M) -> Result<(), ThriftException> {
+        let payload_len = msg.size(&mut self.inner);
+        self.write_header(payload_len)?;
+        msg.encode(&mut self.inner)
+    }
+}
+
+impl<P, H> THeaderProtocol<P, H>
+where
+    P: TInputProtocol<Buf = Bytes>,
+    H: HeaderMap,
+{
+    /// Reads the frame header, filling `flags`, `seq_id` and `headers`, and
+    /// returns the length of the payload that follows.
+    ///
+    /// The headers of the previous frame are replaced, except for the
+    /// persistent ones.
+    pub fn read_header(&mut self) -> Result<usize, ThriftException> {
+        self.headers.clear();
+        for (k, v) in &self.persistent_headers {
+            self.headers.insert_header(k.clone(), v.clone());
+        }
+
+        let buf = self.inner.buf();
+        ensure_remaining(buf, 4 + FIXED_LEN)?;
+        let len = buf.get_u32() as usize;
+        let magic = buf.get_u16();
+        if magic != HEADER_MAGIC {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::BadVersion,
+                format!("invalid header magic {magic:#x}"),
+            ));
+        }
+        self.flags = buf.get_u16();
+        self.seq_id = buf.get_u32();
+        let header_len = buf.get_u16() as usize * 4;
+        if len < FIXED_LEN + header_len {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::InvalidData,
+                format!("header of {header_len} bytes exceeds frame of {len} bytes"),
+            ));
+        }
+        ensure_remaining(buf, len - FIXED_LEN)?;
+        let mut header = buf.split_to(header_len);
+
+        let protocol_id = get_varint(&mut header)?;
+        if protocol_id != self.protocol_id {
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::NotImplemented,
+                format!(
+                    "payload protocol {protocol_id} doesn't match {}",
+                    self.protocol_id
+                ),
+            ));
+        }
+
+        let transforms = get_varint(&mut header)?;
+        if transforms > 0 {
+            let id = get_varint(&mut header)?;
+            return Err(new_protocol_exception(
+                ProtocolExceptionKind::NotImplemented,
+                format!("unsupported header transform {id}"),
+            ));
+        }
+
+        while header.has_remaining() {
+            match get_varint(&mut header)? {
+                info @ (INFO_KEYVALUE | INFO_PKEYVALUE) => {
+                    let count = get_varint(&mut header)?;
+                    for _ in 0..count {
+                        let key = get_faststr(&mut header)?;
+                        let value = get_faststr(&mut header)?;
+                        if info == INFO_PKEYVALUE {
+                            self.persistent_headers.insert(key.clone(), value.clone());
+                        }
+                        self.headers.insert_header(key, value);
+                    }
+                }
+                // the rest of the header is padding, or infos we don't know
+                // how to skip
+                _ => break,
+            }
+        }
+
+        Ok(len - FIXED_LEN - header_len)
+    }
+
+    /// Reads a frame, filling the headers, and decodes its payload.
+    pub fn decode_frame<M: Message>(&mut self) -> Result<M, ThriftException> {
+        self.read_header()?;
+        M::decode(&mut self.inner)
+    }
+}
+
+impl<P: TLengthProtocol, H> TLengthProtocol for THeaderProtocol<P, H> {
+    #[inline]
+    fn message_begin_len(&mut self, identifier: &TMessageIdentifier) -> usize {
+        self.inner.message_begin_len(identifier)
+    }
+
+    #[inline]
+    fn message_end_len(&mut self) -> usize {
+        self.inner.message_end_len()
+    }
+
+    #[inline]
+    fn struct_begin_len(&mut self, identifier: &TStructIdentifier) -> usize {
+        self.inner.struct_begin_len(identifier)
+    }
+
+    #[inline]
+    fn struct_end_len(&mut self) -> usize {
+        self.inner.struct_end_len()
+    }
+
+    #[inline]
+    fn field_begin_len(&mut self, field_type: TType, id: Option<i16>) -> usize {
+        self.inner.field_begin_len(field_type, id)
+    }
+
+    #[inline]
+    fn field_end_len(&mut self) -> usize {
+        self.inner.field_end_len()
+    }
+
+    #[inline]
+    fn field_stop_len(&mut self) -> usize {
+        self.inner.field_stop_len()
+    }
+
+    #[inline]
+    fn bool_len(&mut self, b: bool) -> usize {
+        self.inner.bool_len(b)
+    }
+
+    #[inline]
+    fn bytes_len(&mut self, b: &[u8]) -> usize {
+        self.inner.bytes_len(b)
+    }
+
+    #[inline]
+    fn bytes_vec_len(&mut self, b: &[u8]) -> usize {
+        self.inner.bytes_vec_len(b)
+    }
+
+    #[inline]
+    fn byte_len(&mut self, b: u8) -> usize {
+        self.inner.byte_len(b)
+    }
+
+    #[inline]
+    fn uuid_len(&mut self, u: [u8; 16]) -> usize {
+        self.inner.uuid_len(u)
+    }
+
+    #[inline]
+    fn i8_len(&mut self, i: i8) -> usize {
+        self.inner.i8_len(i)
+    }
+
+    #[inline]
+    fn i16_len(&mut self, i: i16) -> usize {
+        self.inner.i16_len(i)
+    }
+
+    #[inline]
+    fn i32_len(&mut self, i: i32) -> usize {
+        self.inner.i32_len(i)
+    }
+
+    #[inline]
+    fn i64_len(&mut self, i: i64) -> usize {
+        self.inner.i64_len(i)
+    }
+
+    #[inline]
+    fn double_len(&mut self, d: f64) -> usize {
+        self.inner.double_len(d)
+    }
+
+    #[inline]
+    fn string_len(&mut self, s: &str) -> usize {
+        self.inner.string_len(s)
+    }
+
+    #[inline]
+    fn faststr_len(&mut self, s: &FastStr) -> usize {
+        self.inner.faststr_len(s)
+    }
+
+    #[inline]
+    fn list_begin_len(&mut self, identifier: TListIdentifier) -> usize {
+        self.inner.list_begin_len(identifier)
+    }
+
+    #[inline]
+    fn list_end_len(&mut self) -> usize {
+        self.inner.list_end_len()
+    }
+
+    #[inline]
+    fn set_begin_len(&mut self, identifier: TSetIdentifier) -> usize {
+        self.inner.set_begin_len(identifier)
+    }
+
+    #[inline]
+    fn set_end_len(&mut self) -> usize {
+        self.inner.set_end_len()
+    }
+
+    #[inline]
+    fn map_begin_len(&mut self, identifier: TMapIdentifier) -> usize {
+        self.inner.map_begin_len(identifier)
+    }
+
+    #[inline]
+    fn map_end_len(&mut self) -> usize {
+        self.inner.map_end_len()
+    }
+
+    #[inline]
+    fn zero_copy_len(&mut self) -> usize {
+        self.inner.zero_copy_len()
+    }
+
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset()
+    }
+}
+
+impl<P: TOutputProtocol, H> TOutputProtocol for THeaderProtocol<P, H> {
+    type BufMut = P::BufMut;
+
+    #[inline]
+    fn write_message_begin(
+        &mut self,
+        identifier: &TMessageIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.inner.write_message_begin(identifier)
+    }
+
+    #[inline]
+    fn write_message_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_message_end()
+    }
+
+    #[inline]
+    fn write_struct_begin(
+        &mut self,
+        identifier: &TStructIdentifier,
+    ) -> Result<(), ThriftException> {
+        self.inner.write_struct_begin(identifier)
+    }
+
+    #[inline]
+    fn write_struct_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_struct_end()
+    }
+
+    #[inline]
+    fn write_field_begin(&mut self, field_type: TType, id: i16) -> Result<(), ThriftException> {
+        self.inner.write_field_begin(field_type, id)
+    }
+
+    #[inline]
+    fn write_field_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_field_end()
+    }
+
+    #[inline]
+    fn write_field_stop(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_field_stop()
+    }
+
+    #[inline]
+    fn write_bool(&mut self, b: bool) -> Result<(), ThriftException> {
+        self.inner.write_bool(b)
+    }
+
+    #[inline]
+    fn write_bytes(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        self.inner.write_bytes(b)
+    }
+
+    #[inline]
+    fn write_bytes_without_len(&mut self, b: Bytes) -> Result<(), ThriftException> {
+        self.inner.write_bytes_without_len(b)
+    }
+
+    #[inline]
+    fn write_uuid(&mut self, u: [u8; 16]) -> Result<(), ThriftException> {
+        self.inner.write_uuid(u)
+    }
+
+    #[inline]
+    fn write_bytes_vec(&mut self, b: &[u8]) -> Result<(), ThriftException> {
+        self.inner.write_bytes_vec(b)
+    }
+
+    #[inline]
+    fn write_byte(&mut self, b: u8) -> Result<(), ThriftException> {
+        self.inner.write_byte(b)
+    }
+
+    #[inline]
+    fn write_i8(&mut self, i: i8) -> Result<(), ThriftException> {
+        self.inner.write_i8(i)
+    }
+
+    #[inline]
+    fn write_i16(&mut self, i: i16) -> Result<(), ThriftException> {
+        self.inner.write_i16(i)
+    }
+
+    #[inline]
+    fn write_i32(&mut self, i: i32) -> Result<(), ThriftException> {
+        self.inner.write_i32(i)
+    }
+
+    #[inline]
+    fn write_i64(&mut self, i: i64) -> Result<(), ThriftException> {
+        self.inner.write_i64(i)
+    }
+
+    #[inline]
+    fn write_double(&mut self, d: f64) -> Result<(), ThriftException> {
+        self.inner.write_double(d)
+    }
+
+    #[inline]
+    fn write_string(&mut self, s: &str) -> Result<(), ThriftException> {
+        self.inner.write_string(s)
+    }
+
+    #[inline]
+    fn write_faststr(&mut self, s: FastStr) -> Result<(), ThriftException> {
+        self.inner.write_faststr(s)
+    }
+
+    #[inline]
+    fn write_list_begin(&mut self, identifier: TListIdentifier) -> Result<(), ThriftException> {
+        self.inner.write_list_begin(identifier)
+    }
+
+    #[inline]
+    fn write_list_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_list_end()
+    }
+
+    #[inline]
+    fn write_set_begin(&mut self, identifier: TSetIdentifier) -> Result<(), ThriftException> {
+        self.inner.write_set_begin(identifier)
+    }
+
+    #[inline]
+    fn write_set_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_set_end()
+    }
+
+    #[inline]
+    fn write_map_begin(&mut self, identifier: TMapIdentifier) -> Result<(), ThriftException> {
+        self.inner.write_map_begin(identifier)
+    }
+
+    #[inline]
+    fn write_map_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.write_map_end()
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), ThriftException> {
+        self.inner.flush()
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn buf_mut(&mut self) -> &mut Self::BufMut {
+        self.inner.buf_mut()
+    }
+}
+
+impl<P: TInputProtocol, H> TInputProtocol for THeaderProtocol<P, H> {
+    type Buf = P::Buf;
+
+    #[inline]
+    fn read_message_begin(&mut self) -> Result<TMessageIdentifier, ThriftException> {
+        self.inner.read_message_begin()
+    }
+
+    #[inline]
+    fn read_message_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.read_message_end()
+    }
+
+    #[inline]
+    fn read_struct_begin(&mut self) -> Result<Option<TStructIdentifier>, ThriftException> {
+        self.inner.read_struct_begin()
+    }
+
+    #[inline]
+    fn read_struct_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.read_struct_end()
+    }
+
+    #[inline]
+    fn read_field_begin(&mut self) -> Result<TFieldIdentifier, ThriftException> {
+        self.inner.read_field_begin()
+    }
+
+    #[inline]
+    fn read_field_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.read_field_end()
+    }
+
+    #[inline]
+    fn read_bool(&mut self) -> Result<bool, ThriftException> {
+        self.inner.read_bool()
+    }
+
+    #[inline]
+    fn read_bytes(&mut self) -> Result<Bytes, ThriftException> {
+        self.inner.read_bytes()
+    }
+
+    #[inline]
+    fn read_uuid(&mut self) -> Result<[u8; 16], ThriftException> {
+        self.inner.read_uuid()
+    }
+
+    #[inline]
+    fn read_i8(&mut self) -> Result<i8, ThriftException> {
+        self.inner.read_i8()
+    }
+
+    #[inline]
+    fn read_i16(&mut self) -> Result<i16, ThriftException> {
+        self.inner.read_i16()
+    }
+
+    #[inline]
+    fn read_i32(&mut self) -> Result<i32, ThriftException> {
+        self.inner.read_i32()
+    }
+
+    #[inline]
+    fn read_i64(&mut self) -> Result<i64, ThriftException> {
+        self.inner.read_i64()
+    }
+
+    #[inline]
+    fn read_double(&mut self) -> Result<f64, ThriftException> {
+        self.inner.read_double()
+    }
+
+    #[inline]
+    fn read_string(&mut self) -> Result<String, ThriftException> {
+        self.inner.read_string()
+    }
+
+    #[inline]
+    fn read_faststr(&mut self) -> Result<FastStr, ThriftException> {
+        self.inner.read_faststr()
+    }
+
+    #[inline]
+    fn read_list_begin(&mut self) -> Result<TListIdentifier, ThriftException> {
+        self.inner.read_list_begin()
+    }
+
+    #[inline]
+    fn read_list_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.read_list_end()
+    }
+
+    #[inline]
+    fn read_set_begin(&mut self) -> Result<TSetIdentifier, ThriftException> {
+        self.inner.read_set_begin()
+    }
+
+    #[inline]
+    fn read_set_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.read_set_end()
+    }
+
+    #[inline]
+    fn read_map_begin(&mut self) -> Result<TMapIdentifier, ThriftException> {
+        self.inner.read_map_begin()
+    }
+
+    #[inline]
+    fn read_map_end(&mut self) -> Result<(), ThriftException> {
+        self.inner.read_map_end()
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, ThriftException> {
+        self.inner.read_byte()
+    }
+
+    #[inline]
+    fn read_bytes_vec(&mut self) -> Result<Vec<u8>, ThriftException> {
+        self.inner.read_bytes_vec()
+    }
+
+    #[inline]
+    fn get_bytes(&mut self, ptr: Option<*const u8>, len: usize) -> Result<Bytes, ThriftException> {
+        self.inner.get_bytes(ptr, len)
+    }
+
+    #[inline]
+    fn skip_till_depth(&mut self, field_type: TType, depth: i8) -> Result<usize, ThriftException> {
+        self.inner.skip_till_depth(field_type, depth)
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    fn buf(&mut self) -> &mut Self::Buf {
+        self.inner.buf()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bytes::BytesMut;
+
+    use super::*;
+    use crate::thrift::{binary::TBinaryProtocol, ApplicationException, ApplicationExceptionKind};
+
+    fn exception() -> ApplicationException {
+        ApplicationException::new(ApplicationExceptionKind::INTERNAL_ERROR, "internal")
+    }
+
+    fn headers(entries: &[(&'static str, &'static str)]) -> AHashMap<FastStr, FastStr> {
+        entries
+            .iter()
+            .map(|(k, v)| (FastStr::from_static_str(k), FastStr::from_static_str(v)))
+            .collect()
+    }
+
+    /// A frame with an empty payload and the given info sections.
+    fn frame(infos: &[(u32, &[(&str, &str)])]) -> BytesMut {
+        let mut header = Vec::new();
+        put_varint(&mut header, PROTOCOL_ID_BINARY);
+        put_varint(&mut header, 0);
+        for (info, entries) in infos {
+            put_varint(&mut header, *info);
+            put_varint(&mut header, entries.len() as u32);
+            for (k, v) in *entries {
+                put_varint(&mut header, k.len() as u32);
+                header.extend_from_slice(k.as_bytes());
+                put_varint(&mut header, v.len() as u32);
+                header.extend_from_slice(v.as_bytes());
+            }
+        }
+        header.resize(header.len().next_multiple_of(4), INFO_PADDING as u8);
+
+        let mut buf = BytesMut::new();
+        buf.put_u32((FIXED_LEN + header.len()) as u32);
+        buf.put_u16(HEADER_MAGIC);
+        buf.put_u16(0);
+        buf.put_u32(0);
+        buf.put_u16((header.len() / 4) as u16);
+        buf.put_slice(&header);
+        buf
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let headers = headers(&[("caller", "test"), ("trace-id", "0af7651916cd43dd")]);
+
+        let mut buf = BytesMut::new();
+        let mut protocol: THeaderProtocol<_> =
+            THeaderProtocol::new(TBinaryProtocol::new(&mut buf, true), PROTOCOL_ID_BINARY)
+                .with_headers(headers.clone());
+        protocol.seq_id = 7;
+        protocol.encode_frame(&exception()).unwrap();
+
+        // the header is padded to 4 bytes words
+        let header_len = u16::from_be_bytes([buf[12], buf[13]]) as usize * 4;
+        let payload_len = buf.len() - 14 - header_len;
+        assert_eq!(
+            payload_len,
+            exception().size(&mut TBinaryProtocol::new(&mut BytesMut::new(), true))
+        );
+
+        let mut data = buf.freeze();
+        let mut protocol: THeaderProtocol<_> =
+            THeaderProtocol::new(TBinaryProtocol::new(&mut data, false), PROTOCOL_ID_BINARY);
+        let decoded: ApplicationException = protocol.decode_frame().unwrap();
+        assert_eq!(decoded, exception());
+        assert_eq!(protocol.seq_id, 7);
+        assert_eq!(protocol.headers, headers);
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_headers_of_previous_frame_are_cleared() {
+        let mut buf = frame(&[
+            (INFO_KEYVALUE, &[("trace-id", "1")]),
+            (INFO_PKEYVALUE, &[("caller", "test")]),
+        ]);
+        buf.unsplit(frame(&[(INFO_KEYVALUE, &[("retry", "1")])]));
+        buf.unsplit(frame(&[]));
+
+        let mut data = buf.freeze();
+        let mut protocol: THeaderProtocol<_, Vec<(FastStr, FastStr)>> =
+            THeaderProtocol::new(TBinaryProtocol::new(&mut data, false), PROTOCOL_ID_BINARY);
+        protocol.read_header().unwrap();
+        assert_eq!(
+            protocol.headers,
+            [
+                ("trace-id".into(), "1".into()),
+                ("caller".into(), "test".into())
+            ]
+        );
+
+        // the persistent headers are kept
+        protocol.read_header().unwrap();
+        assert_eq!(
+            protocol.headers,
+            [
+                ("caller".into(), "test".into()),
+                ("retry".into(), "1".into())
+            ]
+        );
+        protocol.read_header().unwrap();
+        assert_eq!(protocol.headers, [("caller".into(), "test".into())]);
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_rejects_transforms() {
+        // a zlib compressed frame with an empty payload
+        let header = [PROTOCOL_ID_BINARY as u8, 1, TRANSFORM_ZLIB as u8, 0];
+        let mut buf = BytesMut::new();
+        buf.extend_from_slice(&(10 + header.len() as u32).to_be_bytes());
+        buf.extend_from_slice(&HEADER_MAGIC.to_be_bytes());
+        buf.extend_from_slice(&0u16.to_be_bytes());
+        buf.extend_from_slice(&0u32.to_be_bytes());
+        buf.extend_from_slice(&1u16.to_be_bytes());
+        buf.extend_from_slice(&header);
+
+        let mut data = buf.freeze();
+        let mut protocol: THeaderProtocol<_> =
+            THeaderProtocol::new(TBinaryProtocol::new(&mut data, false), PROTOCOL_ID_BINARY);
+        let err = protocol.read_header().unwrap_err();
+        assert!(err.to_string().contains("unsupported header transform 1"));
+    }
+}
@@ -3,6 +3,7 @@ pub mod binary_le;
 pub mod binary_unsafe;
 pub mod compact;
 pub mod error;
+pub mod header;
 pub mod rw_ext;
 pub mod unknown;
 pub mod varint_ext;
@@ -44,6 +45,21 @@ pub trait Message: Sized + Send {
     ) -> impl Future<Output = Result<Self, ThriftException>> + Send;
 
     fn size<T: TLengthProtocol>(&self, protocol: &mut T) -> usize;
+
+    /// Decodes a message encoded with the binary protocol from `buf`.
+    ///
+    /// String and binary fields are slices sharing `buf` rather than copies,
+    /// so they keep the whole buffer alive. Only numbers and containers are
+    /// copied out of it.
+    fn decode_binary_shared(mut buf: Bytes) -> Result<Self, ThriftException> {
+        Self::decode(&mut binary::TBinaryProtocol::new(&mut buf, true))
+    }
+
+    /// Decodes a message encoded with the compact protocol from `buf`, see
+    /// [`Message::decode_binary_shared`].
+    fn decode_compact_shared(mut buf: Bytes) -> Result<Self, ThriftException> {
+        Self::decode(&mut compact::TCompactInputProtocol::new(&mut buf))
+    }
 }
 
 impl<M: Message> Message for Box<M> {
@@ -0,0 +1,261 @@
+//! A generic traversal of the fields of the generated messages, e.g. to
+//! redact or collect some of their values, without the cost of converting
+//! them to a dynamic representation.
+//!
+//! With the `VisitPlugin` of `pilota-build`, messages get `visit_fields`,
+//! calling a [`FieldVisitor`] with the name in the idl and the
+//! [`FieldValue`] of each set field. The elements of lists and sets, and the
+//! keys and values of maps, are visited with the name of their field, except
+//! for the lists of `u8`, which are a single [`FieldValue::Bytes`]. Nested
+//! messages and unions are visited between [`FieldVisitor::enter_message`]
+//! and [`FieldVisitor::exit_message`], and enums are their value.
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use ahash::{AHashMap, AHashSet};
+use bytes::Bytes;
+use faststr::FastStr;
+use ordered_float::OrderedFloat;
+
+/// A scalar value of a field.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FieldValue<'a> {
+    Bool(bool),
+    /// Every integer and enum. `u64`s above `i64::MAX` wrap around.
+    I64(i64),
+    F64(f64),
+    String(&'a str),
+    Bytes(&'a [u8]),
+}
+
+pub trait FieldVisitor {
+    fn visit_field(&mut self, name: &'static str, value: FieldValue<'_>);
+
+    /// Called before visiting the fields of the message or union in the
+    /// field `name`.
+    fn enter_message(&mut self, name: &'static str) {
+        let _ = name;
+    }
+
+    /// Called after visiting the fields of the message or union in the field
+    /// `name`.
+    fn exit_message(&mut self, name: &'static str) {
+        let _ = name;
+    }
+}
+
+/// Implemented by the types of the fields, visiting their values as the
+/// field `name`.
+pub trait VisitValue {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V);
+
+    /// Visits the elements of a list as the field `name`, one by one unless
+    /// the type visits them at once, as `u8` visits its lists as bytes.
+    fn visit_slice<V: FieldVisitor + ?Sized>(slice: &[Self], name: &'static str, visitor: &mut V)
+    where
+        Self: Sized,
+    {
+        slice.iter().for_each(|el| el.visit_value(name, visitor))
+    }
+}
+
+impl VisitValue for bool {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::Bool(*self))
+    }
+}
+
+macro_rules! impl_int {
+    ($($ty:ty),*) => {
+        $(
+            impl VisitValue for $ty {
+                fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+                    visitor.visit_field(name, FieldValue::I64(*self as i64))
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, u32, u64);
+
+impl VisitValue for u8 {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::I64(*self as i64))
+    }
+
+    fn visit_slice<V: FieldVisitor + ?Sized>(slice: &[Self], name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::Bytes(slice))
+    }
+}
+
+impl VisitValue for f32 {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::F64(*self as f64))
+    }
+}
+
+impl VisitValue for f64 {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::F64(*self))
+    }
+}
+
+impl VisitValue for OrderedFloat<f64> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::F64(self.0))
+    }
+}
+
+impl VisitValue for String {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::String(self))
+    }
+}
+
+impl VisitValue for FastStr {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::String(self))
+    }
+}
+
+impl VisitValue for Cow<'_, str> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::String(self))
+    }
+}
+
+impl VisitValue for Bytes {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::Bytes(self))
+    }
+}
+
+/// Uuids are their 16 bytes.
+impl VisitValue for [u8; 16] {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        visitor.visit_field(name, FieldValue::Bytes(self))
+    }
+}
+
+impl<T: VisitValue> VisitValue for [T] {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        T::visit_slice(self, name, visitor)
+    }
+}
+
+impl<T: VisitValue> VisitValue for Vec<T> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        self[..].visit_value(name, visitor)
+    }
+}
+
+impl<T: VisitValue + Clone> VisitValue for Cow<'_, [T]> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        self[..].visit_value(name, visitor)
+    }
+}
+
+impl<T: VisitValue> VisitValue for AHashSet<T> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        self.iter().for_each(|el| el.visit_value(name, visitor))
+    }
+}
+
+impl<T: VisitValue> VisitValue for BTreeSet<T> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        self.iter().for_each(|el| el.visit_value(name, visitor))
+    }
+}
+
+impl<K: VisitValue, T: VisitValue> VisitValue for AHashMap<K, T> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        self.iter().for_each(|(k, v)| {
+            k.visit_value(name, visitor);
+            v.visit_value(name, visitor);
+        })
+    }
+}
+
+impl<K: VisitValue, T: VisitValue> VisitValue for BTreeMap<K, T> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        self.iter().for_each(|(k, v)| {
+            k.visit_value(name, visitor);
+            v.visit_value(name, visitor);
+        })
+    }
+}
+
+impl<T: VisitValue> VisitValue for Option<T> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        if let Some(value) = self {
+            value.visit_value(name, visitor)
+        }
+    }
+}
+
+impl<T: VisitValue + ?Sized> VisitValue for Arc<T> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        (**self).visit_value(name, visitor)
+    }
+}
+
+impl<T: VisitValue + ?Sized> VisitValue for Box<T> {
+    fn visit_value<V: FieldVisitor + ?Sized>(&self, name: &'static str, visitor: &mut V) {
+        (**self).visit_value(name, visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Collect(Vec<(&'static str, String)>);
+
+    impl FieldVisitor for Collect {
+        fn visit_field(&mut self, name: &'static str, value: FieldValue<'_>) {
+            self.0.push((name, format!("{value:?}")));
+        }
+    }
+
+    #[test]
+    fn test_visit_collections() {
+        let mut visitor = Collect::default();
+        let map: BTreeMap<FastStr, Vec<i32>> = [("a".into(), vec![1, 2])].into();
+        map.visit_value("map", &mut visitor);
+        Some(Arc::new(true)).visit_value("flag", &mut visitor);
+        None::<i32>.visit_value("unset", &mut visitor);
+        assert_eq!(
+            visitor.0,
+            [
+                ("map", r#"String("a")"#.to_string()),
+                ("map", "I64(1)".to_string()),
+                ("map", "I64(2)".to_string()),
+                ("flag", "Bool(true)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_visit_byte_lists() {
+        let mut visitor = Collect::default();
+        vec![1u8, 2].visit_value("bytes", &mut visitor);
+        Cow::<'static, [u8]>::Borrowed(b"ab").visit_value("cow", &mut visitor);
+        7u8.visit_value("byte", &mut visitor);
+        vec![vec![3u8]].visit_value("nested", &mut visitor);
+        assert_eq!(
+            visitor.0,
+            [
+                ("bytes", "Bytes([1, 2])".to_string()),
+                ("cow", "Bytes([97, 98])".to_string()),
+                ("byte", "I64(7)".to_string()),
+                ("nested", "Bytes([3])".to_string()),
+            ]
+        );
+    }
+}